@@ -65,6 +65,7 @@ impl OptionsBuilder {
             dump_buffer: None,
             heap_size: DEFAULT_HEAP_SIZE,
             parse_stats: false,
+            debug_scopes: false,
         })
     }
 
@@ -12,9 +12,13 @@ ICU_VERSION="2.1.1"
 
 cargo install --version "$ICU_VERSION" icu4x-datagen
 
+# Locales must match AVAILABLE_LOCALES in so2js/runtime/intl/locale.rs
+LOCALES="ar de en en-GB es fr hi it ja ko nl pl pt ru sv tr zh"
+
 icu4x-datagen \
   --markers $(cat "$CURRENT_DIR/markers.txt") \
-  --locales en \
+  --locales $LOCALES \
+  --include-collations search \
   --format baked \
   --out "$DATA_DIR" \
   --overwrite \
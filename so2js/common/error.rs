@@ -28,8 +28,8 @@ pub struct SourceInfo {
     line: usize,
     /// Column number in the source file.
     col: usize,
-    /// Extracted snippet from the source file.
-    snippet: String,
+    /// Extracted snippet from the source file, if the source text is available.
+    snippet: Option<String>,
 }
 
 impl SourceInfo {
@@ -38,7 +38,18 @@ impl SourceInfo {
             name,
             line,
             col,
-            snippet,
+            snippet: Some(snippet),
+        }
+    }
+
+    /// Source info for a location whose source text is not available, e.g. an original source
+    /// from a source map without `sourcesContent`.
+    pub fn new_without_snippet(name: String, line: usize, col: usize) -> Self {
+        Self {
+            name,
+            line,
+            col,
+            snippet: None,
         }
     }
 
@@ -143,23 +154,26 @@ impl ErrorFormatter {
             // Write source name and line/col numbers, after the indent and a divider
             self.builder.push_str("┌ ");
 
-            self.builder.push_str(&format!("{name}:{line}:{col}\n"));
+            self.builder.push_str(&format!("{name}:{line}:{col}"));
 
-            // Write a line of padding above the snippet
-            self.snippet_padding(indent);
-            self.builder.push('\n');
+            if let Some(snippet) = snippet {
+                // Write a line of padding above the snippet
+                self.builder.push('\n');
+                self.snippet_padding(indent);
+                self.builder.push('\n');
 
-            // Write the line number and separator before the snippet
-            self.builder.push_str(&format!("{line} | "));
+                // Write the line number and separator before the snippet
+                self.builder.push_str(&format!("{line} | "));
 
-            // Write the snippet itself
-            self.builder.push_str(&format!("{snippet}\n"));
+                // Write the snippet itself
+                self.builder.push_str(&format!("{snippet}\n"));
 
-            // Write a line of padding below the snippet
-            self.snippet_padding(indent);
+                // Write a line of padding below the snippet
+                self.snippet_padding(indent);
 
-            // Write the column indicator
-            self.builder.push('^');
+                // Write the column indicator
+                self.builder.push('^');
+            }
         }
 
         // Finally write the stack trace if one exists
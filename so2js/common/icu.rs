@@ -1,9 +1,8 @@
 use once_cell::sync::Lazy;
 
 use icu_casemap::{CaseMapper, CaseMapperBorrowed};
-use icu_collator::{options::CollatorOptions, Collator};
 use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
-use icu_locale::{locale, Locale};
+use icu_locale::{locale, Locale, LocaleCanonicalizer, LocaleExpander};
 use icu_normalizer::{
     ComposingNormalizer, ComposingNormalizerBorrowed, DecomposingNormalizer,
     DecomposingNormalizerBorrowed,
@@ -22,7 +21,7 @@ use crate::common::icu_data::{
 
 use super::icu_data::BakedDataProvider;
 
-const DEFAULT_LOCALE: Locale = locale!("en");
+pub const DEFAULT_LOCALE: Locale = locale!("en");
 
 #[allow(clippy::upper_case_acronyms)]
 pub struct ICU {
@@ -31,8 +30,8 @@ pub struct ICU {
     pub properties: Properties,
     pub properties_of_strings: PropertiesOfStrings,
     pub normalizers: Normalizers,
-    pub collator: Collator,
     pub case_mapper: CaseMapperBorrowed<'static>,
+    pub locales: Locales,
 }
 
 pub struct GeneralCategories {
@@ -163,6 +162,13 @@ pub struct Normalizers {
     pub nfkd: DecomposingNormalizerBorrowed<'static>,
 }
 
+pub struct Locales {
+    /// Canonicalizer which replaces deprecated and aliased subtags in locales
+    pub canonicalizer: &'static LocaleCanonicalizer,
+    /// Expander which adds or removes likely subtags from locales
+    pub expander: &'static LocaleExpander,
+}
+
 pub struct PropertiesOfStrings {
     /// The Basic_Emoji property of strings
     pub basic_emoji: CodePointInversionListAndStringList<'static>,
@@ -272,6 +278,12 @@ pub static ICU: Lazy<ICU> = Lazy::new(|| {
     static CASE_MAPPER: Lazy<CaseMapper> =
         Lazy::new(|| CaseMapper::try_new_unstable(&BakedDataProvider).unwrap());
 
+    // Locales
+    static LOCALE_CANONICALIZER: Lazy<LocaleCanonicalizer> =
+        Lazy::new(|| LocaleCanonicalizer::try_new_extended_unstable(&BakedDataProvider).unwrap());
+    static LOCALE_EXPANDER: Lazy<LocaleExpander> =
+        Lazy::new(|| LocaleExpander::try_new_extended_unstable(&BakedDataProvider).unwrap());
+
     ICU {
         general_categories: GeneralCategories {
             classifier: GENERAL_CATEGORIES_MAP.as_borrowed(),
@@ -348,12 +360,10 @@ pub static ICU: Lazy<ICU> = Lazy::new(|| {
             nfkc: NFKC.as_borrowed(),
             nfkd: NFKD.as_borrowed(),
         },
-        collator: Collator::try_new_unstable(
-            &BakedDataProvider,
-            DEFAULT_LOCALE.into(),
-            CollatorOptions::default(),
-        )
-        .unwrap(),
         case_mapper: CASE_MAPPER.as_borrowed(),
+        locales: Locales {
+            canonicalizer: &LOCALE_CANONICALIZER,
+            expander: &LOCALE_EXPANDER,
+        },
     }
 });
@@ -7,9 +7,11 @@ pub mod macros;
 pub mod math;
 pub mod memory;
 pub mod options;
+pub mod source_map;
 pub mod string;
 pub mod string_iterators;
 pub mod time;
+pub mod time_zone;
 pub mod unicode;
 pub mod unicode_property;
 pub mod varint;
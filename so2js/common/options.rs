@@ -25,6 +25,14 @@ pub struct Options {
 
     /// Whether to use colors when printing to the terminal
     pub parse_stats: bool,
+
+    /// Place every binding in a VM scope instead of a register, so that a debugger can read and
+    /// evaluate expressions against all variables. Slows down execution.
+    pub debug_scopes: bool,
+
+    /// Report original source positions in stack traces, error messages, and debugger locations
+    /// for code with a `sourceMappingURL` comment.
+    pub source_maps: bool,
 }
 
 impl Options {
@@ -58,6 +66,8 @@ impl OptionsBuilder {
             dump_buffer: None,
             heap_size: DEFAULT_HEAP_SIZE,
             parse_stats: false,
+            debug_scopes: false,
+            source_maps: true,
         })
     }
 
@@ -100,4 +110,14 @@ impl OptionsBuilder {
         self.0.parse_stats = parse_stats;
         self
     }
+
+    pub fn debug_scopes(mut self, debug_scopes: bool) -> Self {
+        self.0.debug_scopes = debug_scopes;
+        self
+    }
+
+    pub fn source_maps(mut self, source_maps: bool) -> Self {
+        self.0.source_maps = source_maps;
+        self
+    }
 }
@@ -0,0 +1,753 @@
+//! Source Map v3 (https://tc39.es/source-map/) parsing and lookup.
+//!
+//! Supports regular source maps and index maps with sections. Generated and original columns in
+//! source maps are measured in UTF-16 code units.
+
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use core::fmt;
+
+/// Prefixes of the comment that links generated code to its source map.
+const SOURCE_MAPPING_URL_PREFIXES: [&str; 2] = ["//# sourceMappingURL=", "//@ sourceMappingURL="];
+
+#[derive(Debug, PartialEq)]
+pub enum SourceMapError {
+    InvalidJson,
+    InvalidVersion,
+    InvalidMappings,
+    MissingField(&'static str),
+}
+
+impl fmt::Display for SourceMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SourceMapError::InvalidJson => write!(f, "Source map is not valid JSON"),
+            SourceMapError::InvalidVersion => write!(f, "Source map version must be 3"),
+            SourceMapError::InvalidMappings => write!(f, "Invalid source map mappings"),
+            SourceMapError::MissingField(field) => {
+                write!(f, "Source map is missing the \"{}\" field", field)
+            }
+        }
+    }
+}
+
+/// A single decoded mapping. All lines and columns are 0-indexed.
+#[derive(Clone, Copy, Debug)]
+struct Mapping {
+    generated_line: u32,
+    generated_column: u32,
+    /// Index into the sources, or None if this mapping does not have an original position
+    source: Option<u32>,
+    original_line: u32,
+    original_column: u32,
+    /// Index into the names
+    name: Option<u32>,
+}
+
+/// A position in the original source, with 1-indexed line and column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OriginalPosition {
+    /// Index of the original source in the source map
+    pub source_index: usize,
+    /// Path or URL of the original source
+    pub source: String,
+    pub line: usize,
+    pub column: usize,
+    /// Original name of the symbol at this position, if any
+    pub name: Option<String>,
+}
+
+pub struct SourceMap {
+    /// Resolved path or URL of each original source
+    sources: Vec<String>,
+    /// Contents of each original source, if included in the source map
+    sources_content: Vec<Option<String>>,
+    names: Vec<String>,
+    /// Mappings sorted by generated position
+    mappings: Vec<Mapping>,
+}
+
+impl SourceMap {
+    /// Parse a source map. Relative source paths are resolved against the directory of
+    /// `source_map_path`, after applying the source map's `sourceRoot`.
+    pub fn parse(json: &str, source_map_path: &str) -> Result<SourceMap, SourceMapError> {
+        let json = JsonParser::new(json).parse()?;
+
+        let mut source_map = SourceMap {
+            sources: vec![],
+            sources_content: vec![],
+            names: vec![],
+            mappings: vec![],
+        };
+        source_map.add_map(&json, source_map_path, 0, 0)?;
+        source_map
+            .mappings
+            .sort_by_key(|mapping| (mapping.generated_line, mapping.generated_column));
+
+        Ok(source_map)
+    }
+
+    /// Add a regular or index map, with generated positions offset by the given line and column.
+    fn add_map(
+        &mut self,
+        json: &Json,
+        source_map_path: &str,
+        line_offset: u32,
+        column_offset: u32,
+    ) -> Result<(), SourceMapError> {
+        if json.get("version").and_then(Json::as_number) != Some(3.0) {
+            return Err(SourceMapError::InvalidVersion);
+        }
+
+        // Index maps contain a list of sections, each with its own map
+        if let Some(sections) = json.get("sections") {
+            let sections = sections
+                .as_array()
+                .ok_or(SourceMapError::MissingField("sections"))?;
+
+            for section in sections {
+                let offset = section
+                    .get("offset")
+                    .ok_or(SourceMapError::MissingField("offset"))?;
+                let line = offset.get("line").and_then(Json::as_number);
+                let column = offset.get("column").and_then(Json::as_number);
+                let (line, column) = match (line, column) {
+                    (Some(line), Some(column)) => (line as u32, column as u32),
+                    _ => return Err(SourceMapError::MissingField("offset")),
+                };
+
+                let map = section
+                    .get("map")
+                    .ok_or(SourceMapError::MissingField("map"))?;
+
+                // The column offset only applies to the first line of the section
+                let column_offset = if line == 0 {
+                    column_offset + column
+                } else {
+                    column
+                };
+
+                self.add_map(map, source_map_path, line_offset + line, column_offset)?;
+            }
+
+            return Ok(());
+        }
+
+        let source_root = json.get("sourceRoot").and_then(Json::as_str).unwrap_or("");
+        let sources = json
+            .get("sources")
+            .and_then(Json::as_array)
+            .ok_or(SourceMapError::MissingField("sources"))?;
+        let sources_content = json.get("sourcesContent").and_then(Json::as_array);
+        let names = json.get("names").and_then(Json::as_array);
+        let mappings = json
+            .get("mappings")
+            .and_then(Json::as_str)
+            .ok_or(SourceMapError::MissingField("mappings"))?;
+
+        // Sources and names of this map are appended after those of any earlier sections
+        let source_base = self.sources.len() as u32;
+        let name_base = self.names.len() as u32;
+
+        for (i, source) in sources.iter().enumerate() {
+            let source = source.as_str().unwrap_or("");
+            let source = if source_root.is_empty() || is_absolute(source) {
+                source.to_owned()
+            } else if source_root.ends_with('/') {
+                [source_root, source].concat()
+            } else {
+                [source_root, "/", source].concat()
+            };
+
+            self.sources.push(resolve_path(source_map_path, &source));
+            self.sources_content.push(
+                sources_content
+                    .and_then(|contents| contents.get(i))
+                    .and_then(Json::as_str)
+                    .map(|content| content.to_owned()),
+            );
+        }
+
+        if let Some(names) = names {
+            for name in names {
+                self.names.push(name.as_str().unwrap_or("").to_owned());
+            }
+        }
+
+        self.decode_mappings(mappings, source_base, name_base, line_offset, column_offset)
+    }
+
+    /// Decode the Base64 VLQ encoded mappings field.
+    fn decode_mappings(
+        &mut self,
+        mappings: &str,
+        source_base: u32,
+        name_base: u32,
+        line_offset: u32,
+        column_offset: u32,
+    ) -> Result<(), SourceMapError> {
+        let num_sources = self.sources.len() as i64 - source_base as i64;
+        let num_names = self.names.len() as i64 - name_base as i64;
+
+        // All fields except the generated column are relative to the previous segment in the map
+        let mut source = 0;
+        let mut original_line = 0;
+        let mut original_column = 0;
+        let mut name = 0;
+
+        for (line, line_mappings) in mappings.split(';').enumerate() {
+            let generated_line = line_offset + line as u32;
+            let mut generated_column = if line == 0 { column_offset as i64 } else { 0 };
+
+            for segment in line_mappings.split(',') {
+                if segment.is_empty() {
+                    continue;
+                }
+
+                let mut fields = [0; 5];
+                let num_fields = decode_vlq_segment(segment, &mut fields)?;
+
+                generated_column += fields[0];
+                if generated_column < 0 {
+                    return Err(SourceMapError::InvalidMappings);
+                }
+
+                let mut mapping = Mapping {
+                    generated_line,
+                    generated_column: generated_column as u32,
+                    source: None,
+                    original_line: 0,
+                    original_column: 0,
+                    name: None,
+                };
+
+                match num_fields {
+                    1 => {}
+                    4 | 5 => {
+                        source += fields[1];
+                        original_line += fields[2];
+                        original_column += fields[3];
+
+                        if source < 0
+                            || source >= num_sources
+                            || original_line < 0
+                            || original_column < 0
+                        {
+                            return Err(SourceMapError::InvalidMappings);
+                        }
+
+                        mapping.source = Some(source_base + source as u32);
+                        mapping.original_line = original_line as u32;
+                        mapping.original_column = original_column as u32;
+
+                        if num_fields == 5 {
+                            name += fields[4];
+                            if name < 0 || name >= num_names {
+                                return Err(SourceMapError::InvalidMappings);
+                            }
+
+                            mapping.name = Some(name_base + name as u32);
+                        }
+                    }
+                    _ => return Err(SourceMapError::InvalidMappings),
+                }
+
+                self.mappings.push(mapping);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Index of the last mapping at or before a generated position on the same line.
+    fn find_mapping(&self, line: u32, column: u32) -> Option<usize> {
+        let index = self.mappings.partition_point(|mapping| {
+            (mapping.generated_line, mapping.generated_column) <= (line, column)
+        });
+
+        if index == 0 || self.mappings[index - 1].generated_line != line {
+            return None;
+        }
+
+        Some(index - 1)
+    }
+
+    /// Find the original position of a 0-indexed generated line and UTF-16 column.
+    pub fn original_position(&self, line: u32, column: u32) -> Option<OriginalPosition> {
+        let mapping = self.mappings[self.find_mapping(line, column)?];
+        let source = mapping.source?;
+
+        Some(OriginalPosition {
+            source_index: source as usize,
+            source: self.sources[source as usize].clone(),
+            line: mapping.original_line as usize + 1,
+            column: mapping.original_column as usize + 1,
+            name: mapping.name.map(|name| self.names[name as usize].clone()),
+        })
+    }
+
+    /// Return the mappings on a 0-indexed generated line, starting at the mapping for a UTF-16
+    /// column, as pairs of generated column and original name.
+    pub fn names_from(&self, line: u32, column: u32) -> impl Iterator<Item = (u32, Option<&str>)> {
+        let start = self
+            .find_mapping(line, column)
+            .unwrap_or_else(|| self.mappings.partition_point(|m| m.generated_line < line));
+
+        self.mappings[start..]
+            .iter()
+            .take_while(move |mapping| mapping.generated_line == line)
+            .map(move |mapping| {
+                let name = mapping.name.map(|name| self.names[name as usize].as_str());
+                (mapping.generated_column, name)
+            })
+    }
+
+    /// Return a 1-indexed line of an original source, if the source map contains the source's
+    /// contents.
+    pub fn source_line(&self, source_index: usize, line: usize) -> Option<&str> {
+        let content = self.sources_content.get(source_index)?.as_ref()?;
+        content
+            .split('\n')
+            .nth(line.checked_sub(1)?)
+            .map(|line| line.trim_end_matches('\r'))
+    }
+}
+
+/// Find the URL in the last `sourceMappingURL` comment of a source file, if any.
+pub fn find_source_mapping_url(contents: &[u8]) -> Option<&str> {
+    // The comment must be on its own line, so only search the lines of the source in reverse
+    contents
+        .split(|byte| *byte == b'\n')
+        .rev()
+        .filter_map(|line| core::str::from_utf8(line).ok())
+        .find_map(|line| {
+            let line = line.trim();
+            SOURCE_MAPPING_URL_PREFIXES
+                .iter()
+                .find_map(|prefix| line.strip_prefix(prefix))
+        })
+        .map(|url| url.trim())
+        .filter(|url| !url.is_empty() && !url.contains(char::is_whitespace))
+}
+
+/// Decode the contents of a `data:` URL, supporting both base64 and percent encoded data.
+/// Returns None if the URL is not a data URL or is malformed.
+pub fn decode_data_url(url: &str) -> Option<String> {
+    let (metadata, data) = url.strip_prefix("data:")?.split_once(',')?;
+
+    let bytes = if metadata.ends_with(";base64") {
+        decode_base64(data)?
+    } else {
+        decode_percent_encoding(data)?
+    };
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Resolve a path or URL relative to the directory of a base path. Absolute paths and URLs with a
+/// scheme are returned unchanged, other than stripping the `file://` scheme.
+pub fn resolve_path(base_path: &str, path: &str) -> String {
+    if let Some(path) = path.strip_prefix("file://") {
+        return path.to_owned();
+    }
+
+    if is_absolute(path) {
+        return path.to_owned();
+    }
+
+    let base_directory = match base_path.rfind('/') {
+        Some(index) => &base_path[..index],
+        None => "",
+    };
+
+    // Normalize `.` and `..` segments of the joined path
+    let mut segments: Vec<&str> = if base_directory.is_empty() {
+        vec![]
+    } else {
+        base_directory.split('/').collect()
+    };
+
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." if segments
+                .last()
+                .is_some_and(|last| !last.is_empty() && *last != "..") =>
+            {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    segments.join("/")
+}
+
+fn is_absolute(path: &str) -> bool {
+    path.starts_with('/') || path.contains("://")
+}
+
+fn decode_base64_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+fn decode_base64(data: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut num_bits = 0;
+
+    for byte in data.bytes().take_while(|byte| *byte != b'=') {
+        buffer = (buffer << 6) | decode_base64_digit(byte)? as u32;
+        num_bits += 6;
+
+        if num_bits >= 8 {
+            num_bits -= 8;
+            bytes.push((buffer >> num_bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+fn decode_percent_encoding(data: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len());
+    let mut iter = data.bytes();
+
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let high = (iter.next()? as char).to_digit(16)?;
+            let low = (iter.next()? as char).to_digit(16)?;
+            bytes.push((high * 16 + low) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Decode the Base64 VLQ fields of a single segment, returning the number of fields.
+fn decode_vlq_segment(segment: &str, fields: &mut [i64; 5]) -> Result<usize, SourceMapError> {
+    let mut num_fields = 0;
+    let mut value: i64 = 0;
+    let mut shift = 0;
+
+    for byte in segment.bytes() {
+        let digit = decode_base64_digit(byte).ok_or(SourceMapError::InvalidMappings)? as i64;
+
+        // Values larger than 32 bits are not valid
+        if shift > 30 {
+            return Err(SourceMapError::InvalidMappings);
+        }
+
+        value |= (digit & 0x1F) << shift;
+        shift += 5;
+
+        // The continuation bit is set on every digit but the last
+        if digit & 0x20 == 0 {
+            if num_fields == fields.len() {
+                return Err(SourceMapError::InvalidMappings);
+            }
+
+            // The lowest bit is the sign
+            let magnitude = value >> 1;
+            fields[num_fields] = if value & 1 != 0 {
+                -magnitude
+            } else {
+                magnitude
+            };
+            num_fields += 1;
+
+            value = 0;
+            shift = 0;
+        }
+    }
+
+    // The segment cannot end in the middle of a value
+    if shift != 0 {
+        return Err(SourceMapError::InvalidMappings);
+    }
+
+    Ok(num_fields)
+}
+
+/// A parsed JSON value. Only what is needed to read source maps.
+enum Json {
+    /// Booleans and null, which source maps never read
+    Other,
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(properties) => properties
+                .iter()
+                .rev()
+                .find(|(property_key, _)| property_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Json::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Maximum nesting depth of JSON arrays and objects, to avoid overflowing the stack
+const MAX_JSON_DEPTH: usize = 64;
+
+impl<'a> JsonParser<'a> {
+    fn new(json: &'a str) -> Self {
+        Self {
+            bytes: json.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<Json, SourceMapError> {
+        // Source maps may start with a `)]}'` line to prevent XSSI
+        if self.bytes.starts_with(b")]}'") {
+            self.pos = self
+                .bytes
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .unwrap_or(self.bytes.len());
+        }
+
+        let value = self.parse_value(0)?;
+
+        self.skip_whitespace();
+        if self.pos != self.bytes.len() {
+            return Err(SourceMapError::InvalidJson);
+        }
+
+        Ok(value)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), SourceMapError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(SourceMapError::InvalidJson);
+        }
+
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<Json, SourceMapError> {
+        if !self.bytes[self.pos..].starts_with(keyword.as_bytes()) {
+            return Err(SourceMapError::InvalidJson);
+        }
+
+        self.pos += keyword.len();
+        Ok(Json::Other)
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Json, SourceMapError> {
+        if depth > MAX_JSON_DEPTH {
+            return Err(SourceMapError::InvalidJson);
+        }
+
+        self.skip_whitespace();
+        match self.peek().ok_or(SourceMapError::InvalidJson)? {
+            b'{' => {
+                self.pos += 1;
+                let mut properties = vec![];
+
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                    return Ok(Json::Object(properties));
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let key = self.parse_string()?;
+                    self.expect(b':')?;
+                    let value = self.parse_value(depth + 1)?;
+                    properties.push((key, value));
+
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(Json::Object(properties));
+                        }
+                        _ => return Err(SourceMapError::InvalidJson),
+                    }
+                }
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut elements = vec![];
+
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                    return Ok(Json::Array(elements));
+                }
+
+                loop {
+                    elements.push(self.parse_value(depth + 1)?);
+
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(Json::Array(elements));
+                        }
+                        _ => return Err(SourceMapError::InvalidJson),
+                    }
+                }
+            }
+            b'"' => Ok(Json::String(self.parse_string()?)),
+            b't' => self.expect_keyword("true"),
+            b'f' => self.expect_keyword("false"),
+            b'n' => self.expect_keyword("null"),
+            b'-' | b'0'..=b'9' => {
+                let start = self.pos;
+                while self.peek().is_some_and(|byte| {
+                    byte.is_ascii_digit() || matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E')
+                }) {
+                    self.pos += 1;
+                }
+
+                // Only contains ASCII so is valid UTF-8
+                let number = core::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+                number
+                    .parse::<f64>()
+                    .map(Json::Number)
+                    .map_err(|_| SourceMapError::InvalidJson)
+            }
+            _ => Err(SourceMapError::InvalidJson),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, SourceMapError> {
+        if self.peek() != Some(b'"') {
+            return Err(SourceMapError::InvalidJson);
+        }
+        self.pos += 1;
+
+        let mut string = String::new();
+
+        loop {
+            // Copy the run of bytes up to the next quote or escape
+            let start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|byte| byte != b'"' && byte != b'\\')
+            {
+                self.pos += 1;
+            }
+
+            let run = core::str::from_utf8(&self.bytes[start..self.pos])
+                .map_err(|_| SourceMapError::InvalidJson)?;
+            string.push_str(run);
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(string);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escape = self.peek().ok_or(SourceMapError::InvalidJson)?;
+                    self.pos += 1;
+
+                    match escape {
+                        b'"' => string.push('"'),
+                        b'\\' => string.push('\\'),
+                        b'/' => string.push('/'),
+                        b'b' => string.push('\u{8}'),
+                        b'f' => string.push('\u{c}'),
+                        b'n' => string.push('\n'),
+                        b'r' => string.push('\r'),
+                        b't' => string.push('\t'),
+                        b'u' => {
+                            let code_unit = self.parse_hex_code_unit()?;
+
+                            // Combine surrogate pairs, replacing lone surrogates
+                            let code_point = if (0xD800..0xDC00).contains(&code_unit)
+                                && self.bytes[self.pos..].starts_with(b"\\u")
+                            {
+                                let saved_pos = self.pos;
+                                self.pos += 2;
+                                let low = self.parse_hex_code_unit()?;
+                                if (0xDC00..0xE000).contains(&low) {
+                                    0x10000 + ((code_unit - 0xD800) << 10) + (low - 0xDC00)
+                                } else {
+                                    self.pos = saved_pos;
+                                    code_unit
+                                }
+                            } else {
+                                code_unit
+                            };
+
+                            string.push(char::from_u32(code_point).unwrap_or('\u{FFFD}'));
+                        }
+                        _ => return Err(SourceMapError::InvalidJson),
+                    }
+                }
+                _ => return Err(SourceMapError::InvalidJson),
+            }
+        }
+    }
+
+    fn parse_hex_code_unit(&mut self) -> Result<u32, SourceMapError> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .and_then(|digits| core::str::from_utf8(digits).ok())
+            .ok_or(SourceMapError::InvalidJson)?;
+        let code_unit = u32::from_str_radix(digits, 16).map_err(|_| SourceMapError::InvalidJson)?;
+
+        self.pos += 4;
+        Ok(code_unit)
+    }
+}
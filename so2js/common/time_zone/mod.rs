@@ -0,0 +1,361 @@
+//! Time zones used for local time by Date and Intl.
+//!
+//! Named time zones are resolved from the embedded IANA time zone database when the `tzdb` feature
+//! is enabled, otherwise from the host through the `Sys` time zone hooks.
+
+use alloc::{format, rc::Rc, string::String, vec::Vec};
+
+use crate::runtime::Context;
+
+use self::tzif::Tzif;
+
+mod posix;
+#[cfg(feature = "tzdb")]
+mod tzdb;
+pub mod tzif;
+
+const MS_PER_SECOND: i64 = 1000;
+const MS_PER_MINUTE: i64 = 60 * MS_PER_SECOND;
+const MS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// Instants are clamped to a few days past the range of valid time values before looking up their
+/// offset. Offsets outside this range are never observable.
+const MAX_EPOCH_MILLIS: f64 = 8.64e15 + 10.0 * MS_PER_DAY;
+
+/// The offset from UTC of a time zone at a particular instant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeZoneOffset {
+    /// Offset from UTC in milliseconds, positive east of UTC
+    pub offset_millis: i64,
+    /// Whether daylight saving time is in effect
+    pub is_dst: bool,
+    /// Abbreviation of the time zone at this instant if known, e.g. "EST" or "CEST"
+    pub abbreviation: Option<String>,
+}
+
+impl TimeZoneOffset {
+    pub const UTC: TimeZoneOffset = TimeZoneOffset {
+        offset_millis: 0,
+        is_dst: false,
+        abbreviation: None,
+    };
+}
+
+#[derive(Clone, Debug)]
+pub enum TimeZone {
+    Utc,
+    /// A fixed offset from UTC in minutes
+    Offset(i64),
+    /// A named time zone whose rules are described by TZif data
+    Tzif(String, Rc<Tzif>),
+    /// A named time zone whose offsets are provided by the host's `Sys::time_zone_offset` hook
+    Host(String),
+}
+
+/// Time zone identifiers that are aliases of UTC, compared case insensitively.
+const UTC_TIME_ZONE_IDENTIFIERS: &[&str] = &[
+    "Etc/GMT",
+    "Etc/GMT+0",
+    "Etc/GMT-0",
+    "Etc/GMT0",
+    "Etc/Greenwich",
+    "Etc/UCT",
+    "Etc/Universal",
+    "Etc/UTC",
+    "Etc/Zulu",
+    "GMT",
+    "GMT+0",
+    "GMT-0",
+    "GMT0",
+    "Greenwich",
+    "UCT",
+    "Universal",
+    "UTC",
+    "Zulu",
+];
+
+/// The alias of UTC matching an identifier case insensitively, with its canonical casing.
+fn find_utc_time_zone_identifier(identifier: &str) -> Option<&'static str> {
+    UTC_TIME_ZONE_IDENTIFIERS
+        .iter()
+        .find(|utc_identifier| utc_identifier.eq_ignore_ascii_case(identifier))
+        .copied()
+}
+
+fn is_utc_time_zone_identifier(identifier: &str) -> bool {
+    find_utc_time_zone_identifier(identifier).is_some()
+}
+
+/// Parse a UTC offset time zone of the form `±HH`, `±HHMM`, or `±HH:MM`, returning the offset in
+/// minutes.
+///
+/// IsTimeZoneOffsetString (https://tc39.es/ecma402/#sec-istimezoneoffsetstring)
+fn parse_time_zone_offset(time_zone: &str) -> Option<i64> {
+    let bytes = time_zone.as_bytes();
+    let sign = match bytes.first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+
+    let digits = match &bytes[1..] {
+        [h1, h2] => [*h1, *h2, b'0', b'0'],
+        [h1, h2, m1, m2] | [h1, h2, b':', m1, m2] => [*h1, *h2, *m1, *m2],
+        _ => return None,
+    };
+
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let hours = ((digits[0] - b'0') * 10 + (digits[1] - b'0')) as i64;
+    let minutes = ((digits[2] - b'0') * 10 + (digits[3] - b'0')) as i64;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(sign * (hours * 60 + minutes))
+}
+
+impl TimeZone {
+    /// Resolve a time zone from its identifier, returning None if the time zone is not supported.
+    ///
+    /// Supports UTC and its aliases, UTC offsets, and named time zones from the embedded time zone
+    /// database or the host.
+    pub fn from_identifier(cx: Context, identifier: &str) -> Option<TimeZone> {
+        if let Some(offset_minutes) = parse_time_zone_offset(identifier) {
+            return Some(TimeZone::Offset(offset_minutes));
+        }
+
+        if is_utc_time_zone_identifier(identifier) {
+            return Some(TimeZone::Utc);
+        }
+
+        #[cfg(feature = "tzdb")]
+        if let Some((name, data)) = tzdb::find_zone(identifier) {
+            if let Some(tzif) = Tzif::parse(data) {
+                return Some(TimeZone::Tzif(String::from(name), Rc::new(tzif)));
+            }
+        }
+
+        let sys = cx.sys.as_ref()?;
+
+        if let Some(tzif) = sys
+            .load_time_zone_data(identifier)
+            .and_then(|data| Tzif::parse(&data))
+        {
+            return Some(TimeZone::Tzif(String::from(identifier), Rc::new(tzif)));
+        }
+
+        // Check that the host knows of this time zone
+        sys.time_zone_offset(identifier, 0.0)?;
+
+        Some(TimeZone::Host(String::from(identifier)))
+    }
+
+    /// The canonicalized identifier of this time zone. Offset time zones have the form `±HH:MM`.
+    pub fn identifier(&self) -> String {
+        match self {
+            TimeZone::Utc => String::from("UTC"),
+            TimeZone::Offset(offset_minutes) => {
+                let sign = if *offset_minutes < 0 { '-' } else { '+' };
+                let hours = offset_minutes.abs() / 60;
+                let minutes = offset_minutes.abs() % 60;
+
+                format!("{sign}{hours:02}:{minutes:02}")
+            }
+            TimeZone::Tzif(name, _) | TimeZone::Host(name) => name.clone(),
+        }
+    }
+
+    /// The offset from UTC of this time zone at an instant in milliseconds since the Unix epoch.
+    ///
+    /// GetNamedTimeZoneOffsetNanoseconds (https://tc39.es/ecma262/#sec-getnamedtimezoneoffsetnanoseconds)
+    pub fn offset_at(&self, cx: Context, epoch_millis: f64) -> TimeZoneOffset {
+        match self {
+            TimeZone::Utc => TimeZoneOffset::UTC,
+            TimeZone::Offset(offset_minutes) => TimeZoneOffset {
+                offset_millis: offset_minutes * MS_PER_MINUTE,
+                is_dst: false,
+                abbreviation: None,
+            },
+            TimeZone::Tzif(_, tzif) => {
+                let epoch_millis = epoch_millis.clamp(-MAX_EPOCH_MILLIS, MAX_EPOCH_MILLIS);
+                let epoch_seconds = f64::floor(epoch_millis / MS_PER_SECOND as f64) as i64;
+                tzif.offset_at(epoch_seconds)
+            }
+            TimeZone::Host(name) => cx
+                .sys
+                .as_ref()
+                .and_then(|sys| sys.time_zone_offset(name, epoch_millis))
+                .unwrap_or(TimeZoneOffset::UTC),
+        }
+    }
+
+    /// The offset from UTC of this time zone at a local time in milliseconds since the Unix
+    /// epoch.
+    ///
+    /// Local times that are repeated when clocks are turned back are treated as the earlier of the
+    /// two instants. Local times that are skipped when clocks are turned forward are interpreted
+    /// using the offset before the transition.
+    ///
+    /// GetNamedTimeZoneEpochNanoseconds (https://tc39.es/ecma262/#sec-getnamedtimezoneepochnanoseconds)
+    pub fn offset_at_local(&self, cx: Context, local_millis: f64) -> TimeZoneOffset {
+        match self {
+            TimeZone::Utc | TimeZone::Offset(_) => return self.offset_at(cx, local_millis),
+            TimeZone::Tzif(..) | TimeZone::Host(_) => {}
+        }
+
+        // Offsets are always less than a day, so all instants that could have this local time are
+        // covered by the offsets a day before and after.
+        let offset_before = self.offset_at(cx, local_millis - MS_PER_DAY);
+        let offset_after = self.offset_at(cx, local_millis + MS_PER_DAY);
+
+        let mut candidates = Vec::with_capacity(2);
+        for offset in [offset_before.clone(), offset_after] {
+            let epoch_millis = local_millis - offset.offset_millis as f64;
+            if self.offset_at(cx, epoch_millis).offset_millis == offset.offset_millis {
+                candidates.push((epoch_millis, offset));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, offset)| offset)
+            .unwrap_or(offset_before)
+    }
+
+    /// The first instant strictly after a time at which the offset from UTC changes, in seconds
+    /// since the Unix epoch. Returns None if the offset never changes after this time.
+    ///
+    /// GetNamedTimeZoneNextTransition (https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezonenexttransition)
+    pub fn next_transition(&self, cx: Context, epoch_seconds: i64) -> Option<i64> {
+        match self {
+            TimeZone::Utc | TimeZone::Offset(_) => None,
+            TimeZone::Tzif(_, tzif) => tzif.next_transition(epoch_seconds),
+            TimeZone::Host(_) => self.search_host_transition(cx, epoch_seconds, 1),
+        }
+    }
+
+    /// The last instant strictly before a time at which the offset from UTC changes, in seconds
+    /// since the Unix epoch. Returns None if the offset never changes before this time.
+    ///
+    /// GetNamedTimeZonePreviousTransition (https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezoneprevioustransition)
+    pub fn previous_transition(&self, cx: Context, epoch_seconds: i64) -> Option<i64> {
+        match self {
+            TimeZone::Utc | TimeZone::Offset(_) => None,
+            TimeZone::Tzif(_, tzif) => tzif.previous_transition(epoch_seconds),
+            TimeZone::Host(_) => self.search_host_transition(cx, epoch_seconds, -1),
+        }
+    }
+
+    /// Search for a transition of a time zone provided by the host, whose transitions are not
+    /// known in advance, in the direction of `sign`.
+    ///
+    /// Offsets are sampled a week apart then the transition is found by bisection, so transitions
+    /// less than a week apart may be missed. Only transitions within 200 years are found.
+    fn search_host_transition(&self, cx: Context, epoch_seconds: i64, sign: i64) -> Option<i64> {
+        const STEP_SECONDS: i64 = 7 * 24 * 60 * 60;
+        const MAX_STEPS: i64 = 200 * 53;
+
+        let offset_at = |seconds: i64| {
+            self.offset_at(cx, (seconds * MS_PER_SECOND) as f64)
+                .offset_millis
+        };
+
+        // Offset just after the time when searching forwards, or at the time when searching
+        // backwards since the transition must be strictly before the time.
+        let start = if sign > 0 {
+            epoch_seconds
+        } else {
+            epoch_seconds - 1
+        };
+        let start_offset = offset_at(start);
+
+        let mut near = start;
+        for _ in 0..MAX_STEPS {
+            let far = near + sign * STEP_SECONDS;
+            if offset_at(far) == start_offset {
+                near = far;
+                continue;
+            }
+
+            // The transition is the first second after `near` in the direction of the search
+            // whose offset differs from the starting offset.
+            let (mut low, mut high) = if sign > 0 { (near, far) } else { (far, near) };
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                let is_start_side = (offset_at(mid) == start_offset) == (sign > 0);
+                if is_start_side {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            return Some(high);
+        }
+
+        None
+    }
+}
+
+/// Find a named time zone that is available, returning its identifier with the canonical casing.
+/// Links to other time zones keep their own identifier.
+///
+/// GetAvailableNamedTimeZoneIdentifier (https://tc39.es/proposal-temporal/#sec-getavailablenamedtimezoneidentifier)
+pub fn available_named_time_zone_identifier(cx: Context, identifier: &str) -> Option<String> {
+    if parse_time_zone_offset(identifier).is_some() {
+        return None;
+    }
+
+    if let Some(utc_identifier) = find_utc_time_zone_identifier(identifier) {
+        return Some(String::from(utc_identifier));
+    }
+
+    TimeZone::from_identifier(cx, identifier).map(|time_zone| time_zone.identifier())
+}
+
+/// The primary identifier of an available named time zone, following links to the zone they
+/// refer to. All aliases of UTC have the primary identifier "UTC".
+pub fn primary_time_zone_identifier(cx: Context, identifier: &str) -> Option<String> {
+    if is_utc_time_zone_identifier(identifier) {
+        return Some(String::from("UTC"));
+    }
+
+    #[cfg(feature = "tzdb")]
+    if let Some(primary_identifier) = tzdb::primary_identifier(identifier) {
+        if is_utc_time_zone_identifier(primary_identifier) {
+            return Some(String::from("UTC"));
+        }
+
+        return Some(String::from(primary_identifier));
+    }
+
+    available_named_time_zone_identifier(cx, identifier)
+}
+
+/// Validate and canonicalize a time zone identifier, returning None if the time zone is not
+/// supported.
+///
+/// CanonicalizeTimeZoneName (https://tc39.es/ecma402/#sec-canonicalizetimezonename)
+pub fn canonicalize_time_zone(cx: Context, identifier: &str) -> Option<String> {
+    TimeZone::from_identifier(cx, identifier).map(|time_zone| time_zone.identifier())
+}
+
+/// Identifiers of all named time zones that are available, sorted by identifier. UTC is included
+/// but its aliases are not.
+pub fn available_time_zones() -> Vec<&'static str> {
+    #[cfg(feature = "tzdb")]
+    let mut time_zones: Vec<&'static str> = tzdb::zone_identifiers()
+        .filter(|identifier| !is_utc_time_zone_identifier(identifier))
+        .collect();
+    #[cfg(not(feature = "tzdb"))]
+    let mut time_zones = Vec::new();
+
+    time_zones.push("UTC");
+    time_zones.sort_unstable();
+
+    time_zones
+}
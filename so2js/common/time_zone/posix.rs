@@ -0,0 +1,382 @@
+use alloc::string::String;
+
+use crate::runtime::intrinsics::date_object::{
+    is_leap_year, week_day, year_from_time, year_month_day_to_days_since_unix_epoch, MS_PER_DAY,
+};
+
+use super::TimeZoneOffset;
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+/// A time zone described by a POSIX TZ string, e.g. "EST5EDT,M3.2.0,M11.1.0".
+///
+/// TZif footers use this format for all times after the last transition.
+/// (https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html#tag_08_03)
+#[derive(Debug)]
+pub struct PosixTimeZone {
+    std_abbreviation: String,
+    /// Offset from UTC of standard time in seconds, positive east of UTC
+    std_offset: i64,
+    dst: Option<PosixDst>,
+}
+
+#[derive(Debug)]
+struct PosixDst {
+    abbreviation: String,
+    /// Offset from UTC of daylight saving time in seconds, positive east of UTC
+    offset: i64,
+    /// Daylight saving time starts at this rule in local standard time
+    start: PosixRule,
+    /// Daylight saving time ends at this rule in local daylight saving time
+    end: PosixRule,
+}
+
+#[derive(Debug)]
+struct PosixRule {
+    date: PosixDate,
+    /// Seconds after the start of the day in local time, may be negative or past the end of day
+    time: i64,
+}
+
+#[derive(Debug)]
+enum PosixDate {
+    /// Jn: Julian day from 1 to 365, never counting February 29
+    JulianWithoutLeapDay(i64),
+    /// n: Zero-based Julian day from 0 to 365, counting February 29 in leap years
+    Julian(i64),
+    /// Mm.w.d: Day d (0 is Sunday) of week w (5 is the last week) in month m
+    MonthWeekDay { month: i64, week: i64, day: i64 },
+}
+
+/// Default rule for daylight saving time when a TZ string does not specify one, which matches the
+/// current rules in the United States.
+const DEFAULT_START_RULE: PosixRule = PosixRule {
+    date: PosixDate::MonthWeekDay {
+        month: 3,
+        week: 2,
+        day: 0,
+    },
+    time: 2 * SECONDS_PER_HOUR,
+};
+
+const DEFAULT_END_RULE: PosixRule = PosixRule {
+    date: PosixDate::MonthWeekDay {
+        month: 11,
+        week: 1,
+        day: 0,
+    },
+    time: 2 * SECONDS_PER_HOUR,
+};
+
+struct PosixParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl PosixParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// An abbreviation is either at least three alphabetic characters, or a quoted string of at
+    /// least three alphanumeric characters, '+', or '-' e.g. "<-03>".
+    fn parse_abbreviation(&mut self) -> Option<String> {
+        let is_quoted = self.eat(b'<');
+        let start = self.pos;
+
+        while let Some(byte) = self.peek() {
+            let is_valid = if is_quoted {
+                byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-'
+            } else {
+                byte.is_ascii_alphabetic()
+            };
+
+            if !is_valid {
+                break;
+            }
+
+            self.pos += 1;
+        }
+
+        let abbreviation = core::str::from_utf8(&self.bytes[start..self.pos]).ok()?;
+        if abbreviation.len() < 3 || (is_quoted && !self.eat(b'>')) {
+            return None;
+        }
+
+        Some(String::from(abbreviation))
+    }
+
+    fn parse_number(&mut self) -> Option<i64> {
+        let start = self.pos;
+        let mut value: i64 = 0;
+
+        while let Some(byte) = self.peek() {
+            if !byte.is_ascii_digit() {
+                break;
+            }
+
+            value = value.checked_mul(10)?.checked_add((byte - b'0') as i64)?;
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Parse [+-]hh[:mm[:ss]] in seconds, where hours must be at most `max_hours`.
+    fn parse_hms(&mut self, max_hours: i64) -> Option<i64> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+
+        let hours = self.parse_number()?;
+        if hours > max_hours {
+            return None;
+        }
+
+        let mut seconds = hours * SECONDS_PER_HOUR;
+        if self.eat(b':') {
+            let minutes = self.parse_number()?;
+            if minutes > 59 {
+                return None;
+            }
+
+            seconds += minutes * 60;
+
+            if self.eat(b':') {
+                let secs = self.parse_number()?;
+                if secs > 59 {
+                    return None;
+                }
+
+                seconds += secs;
+            }
+        }
+
+        Some(sign * seconds)
+    }
+
+    /// POSIX offsets are the time added to local time to get UTC, so are positive west of UTC.
+    /// Returns the offset from UTC which is positive east of UTC.
+    fn parse_offset(&mut self) -> Option<i64> {
+        Some(-self.parse_hms(24)?)
+    }
+
+    fn parse_rule(&mut self) -> Option<PosixRule> {
+        let date = if self.eat(b'J') {
+            let day = self.parse_number()?;
+            if !(1..=365).contains(&day) {
+                return None;
+            }
+
+            PosixDate::JulianWithoutLeapDay(day)
+        } else if self.eat(b'M') {
+            let month = self.parse_number()?;
+            if !self.eat(b'.') {
+                return None;
+            }
+
+            let week = self.parse_number()?;
+            if !self.eat(b'.') {
+                return None;
+            }
+
+            let day = self.parse_number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || day > 6 {
+                return None;
+            }
+
+            PosixDate::MonthWeekDay { month, week, day }
+        } else {
+            let day = self.parse_number()?;
+            if day > 365 {
+                return None;
+            }
+
+            PosixDate::Julian(day)
+        };
+
+        // Transition time defaults to 02:00:00 and may be negative or up to 167 hours, as
+        // allowed by the extension in RFC 8536.
+        let time = if self.eat(b'/') {
+            self.parse_hms(167)?
+        } else {
+            2 * SECONDS_PER_HOUR
+        };
+
+        Some(PosixRule { date, time })
+    }
+}
+
+impl PosixTimeZone {
+    /// Parse a POSIX TZ string, returning None if the string is malformed.
+    pub fn parse(string: &str) -> Option<PosixTimeZone> {
+        let mut parser = PosixParser {
+            bytes: string.as_bytes(),
+            pos: 0,
+        };
+
+        let std_abbreviation = parser.parse_abbreviation()?;
+        let std_offset = parser.parse_offset()?;
+
+        if parser.is_at_end() {
+            return Some(PosixTimeZone {
+                std_abbreviation,
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let dst_abbreviation = parser.parse_abbreviation()?;
+
+        // Daylight saving time is one hour ahead of standard time unless specified
+        let dst_offset = match parser.peek() {
+            Some(b',') | None => std_offset + SECONDS_PER_HOUR,
+            Some(_) => parser.parse_offset()?,
+        };
+
+        let (start, end) = if parser.eat(b',') {
+            let start = parser.parse_rule()?;
+            if !parser.eat(b',') {
+                return None;
+            }
+
+            (start, parser.parse_rule()?)
+        } else {
+            (DEFAULT_START_RULE, DEFAULT_END_RULE)
+        };
+
+        if !parser.is_at_end() {
+            return None;
+        }
+
+        Some(PosixTimeZone {
+            std_abbreviation,
+            std_offset,
+            dst: Some(PosixDst {
+                abbreviation: dst_abbreviation,
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// The offset from UTC at a time in seconds since the Unix epoch.
+    pub fn offset_at(&self, epoch_seconds: i64) -> TimeZoneOffset {
+        let dst = match &self.dst {
+            None => return self.std_time_zone_offset(),
+            Some(dst) => dst,
+        };
+
+        // Transitions are determined by the year in local standard time
+        let local_millis = (epoch_seconds + self.std_offset) as f64 * 1000.0;
+        let year = year_from_time(local_millis) as i64;
+
+        // Start is specified in local standard time and end in local daylight saving time
+        let start = dst.start.local_seconds_in_year(year) - self.std_offset;
+        let end = dst.end.local_seconds_in_year(year) - dst.offset;
+
+        // Daylight saving time may wrap around the end of the year in the southern hemisphere
+        let is_dst = if start <= end {
+            start <= epoch_seconds && epoch_seconds < end
+        } else {
+            epoch_seconds < end || start <= epoch_seconds
+        };
+
+        if is_dst {
+            TimeZoneOffset {
+                offset_millis: dst.offset * 1000,
+                is_dst: true,
+                abbreviation: Some(dst.abbreviation.clone()),
+            }
+        } else {
+            self.std_time_zone_offset()
+        }
+    }
+
+    /// Times of the transitions into and out of daylight saving time in the given year, in
+    /// seconds since the Unix epoch. Returns None if there is no daylight saving time, or its
+    /// offset is the same as standard time.
+    pub fn transitions_in_year(&self, year: i64) -> Option<[i64; 2]> {
+        let dst = self.dst.as_ref()?;
+        if dst.offset == self.std_offset {
+            return None;
+        }
+
+        let start = dst.start.local_seconds_in_year(year) - self.std_offset;
+        let end = dst.end.local_seconds_in_year(year) - dst.offset;
+
+        Some([start, end])
+    }
+
+    fn std_time_zone_offset(&self) -> TimeZoneOffset {
+        TimeZoneOffset {
+            offset_millis: self.std_offset * 1000,
+            is_dst: false,
+            abbreviation: Some(self.std_abbreviation.clone()),
+        }
+    }
+}
+
+impl PosixRule {
+    /// Local time of this rule in the given year, in seconds since the Unix epoch.
+    fn local_seconds_in_year(&self, year: i64) -> i64 {
+        let year_start = year_month_day_to_days_since_unix_epoch(year, 1, 1).unwrap_or(0);
+
+        let day = match self.date {
+            PosixDate::JulianWithoutLeapDay(day) => {
+                // February 29 is never counted, so days after February are shifted in leap years
+                let leap_day = if is_leap_year(year) && day > 59 { 1 } else { 0 };
+                year_start + day - 1 + leap_day
+            }
+            PosixDate::Julian(day) => year_start + day,
+            PosixDate::MonthWeekDay { month, week, day } => {
+                let month_start =
+                    year_month_day_to_days_since_unix_epoch(year, month, 1).unwrap_or(year_start);
+                let month_start_week_day = week_day(month_start as f64 * MS_PER_DAY) as i64;
+
+                // First occurrence of the week day in the month, then advance by weeks. The fifth
+                // week means the last occurrence in the month.
+                let mut date = 1 + (day - month_start_week_day).rem_euclid(7) + (week - 1) * 7;
+                let days_in_month = days_in_month(year, month);
+                while date > days_in_month {
+                    date -= 7;
+                }
+
+                month_start + date - 1
+            }
+        };
+
+        day * SECONDS_PER_DAY + self.time
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
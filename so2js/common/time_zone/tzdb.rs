@@ -0,0 +1,49 @@
+//! The embedded IANA time zone database, generated by `tzdb/generate_data.sh`.
+
+#![allow(dead_code)]
+
+include!("../../../tzdb/data/mod.rs");
+
+/// Find a zone by its identifier, compared case insensitively. Links are followed to the zone
+/// they refer to.
+///
+/// Returns the identifier with its canonical casing along with the TZif data of the zone. The
+/// identifier of a link is kept rather than replaced by the identifier of its target zone.
+pub fn find_zone(identifier: &str) -> Option<(&'static str, &'static [u8])> {
+    if let Some(data) = find_zone_data(identifier) {
+        return Some(data);
+    }
+
+    let (link, target) = LINKS
+        .iter()
+        .find(|(link, _)| link.eq_ignore_ascii_case(identifier))?;
+    let (_, data) = find_zone_data(target)?;
+
+    Some((link, data))
+}
+
+/// The identifier of the zone with an identifier, or the zone that a link refers to, compared
+/// case insensitively.
+pub fn primary_identifier(identifier: &str) -> Option<&'static str> {
+    if let Some((name, _)) = find_zone_data(identifier) {
+        return Some(name);
+    }
+
+    LINKS
+        .iter()
+        .find(|(link, _)| link.eq_ignore_ascii_case(identifier))
+        .map(|(_, target)| *target)
+}
+
+fn find_zone_data(identifier: &str) -> Option<(&'static str, &'static [u8])> {
+    let (name, start, end) = ZONES
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(identifier))?;
+
+    Some((name, &TZDB_DATA[*start as usize..*end as usize]))
+}
+
+/// Identifiers of all zones in the database, excluding links, sorted by identifier.
+pub fn zone_identifiers() -> impl Iterator<Item = &'static str> {
+    ZONES.iter().map(|(name, _, _)| *name)
+}
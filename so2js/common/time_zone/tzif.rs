@@ -0,0 +1,300 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::runtime::intrinsics::date_object::year_from_time;
+
+use super::{posix::PosixTimeZone, TimeZoneOffset};
+
+/// A local time type in TZif data, in effect from a transition until the next.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalTimeType {
+    /// Offset from UTC in seconds, positive east of UTC
+    pub offset_seconds: i64,
+    pub is_dst: bool,
+    pub abbreviation: String,
+}
+
+/// The rules of a time zone parsed from TZif data.
+///
+/// The Time Zone Information Format (TZif) (https://www.rfc-editor.org/rfc/rfc8536)
+#[derive(Debug)]
+pub struct Tzif {
+    /// Times of each transition in seconds since the Unix epoch, sorted in ascending order
+    transition_times: Vec<i64>,
+    /// Index of the local time type that starts at each transition
+    transition_types: Vec<u8>,
+    local_time_types: Vec<LocalTimeType>,
+    /// Rule for times after the last transition, from the footer of version 2+ data
+    footer: Option<PosixTimeZone>,
+}
+
+const HEADER_SIZE: usize = 44;
+
+/// Counts from a TZif header, in the order they appear.
+struct TzifHeader {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl TzifHeader {
+    fn parse(bytes: &[u8]) -> Option<TzifHeader> {
+        if bytes.len() < HEADER_SIZE || &bytes[0..4] != b"TZif" {
+            return None;
+        }
+
+        let count = |index: usize| {
+            let start = 20 + index * 4;
+            read_u32(&bytes[start..start + 4]) as usize
+        };
+
+        Some(TzifHeader {
+            version: bytes[4],
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        })
+    }
+
+    /// Size of the data block following this header, where times are `time_size` bytes.
+    fn data_block_size(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_i32(bytes: &[u8]) -> i32 {
+    i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_i64(bytes: &[u8]) -> i64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(&bytes[0..8]);
+    i64::from_be_bytes(buf)
+}
+
+impl Tzif {
+    /// Parse TZif data, returning None if the data is malformed.
+    pub fn parse(bytes: &[u8]) -> Option<Tzif> {
+        let v1_header = TzifHeader::parse(bytes)?;
+        let v1_end = HEADER_SIZE + v1_header.data_block_size(4);
+        if bytes.len() < v1_end {
+            return None;
+        }
+
+        // Version 1 data only has 32 bit times, and is superseded by the version 2+ data block
+        if v1_header.version == 0 {
+            return Self::parse_data_block(&v1_header, &bytes[HEADER_SIZE..v1_end], 4, None);
+        }
+
+        let header = TzifHeader::parse(&bytes[v1_end..])?;
+        let data_start = v1_end + HEADER_SIZE;
+        let data_end = data_start + header.data_block_size(8);
+        if bytes.len() < data_end {
+            return None;
+        }
+
+        // Footer is a POSIX TZ string surrounded by newlines, which may be empty
+        let footer = &bytes[data_end..];
+        if footer.first() != Some(&b'\n') {
+            return None;
+        }
+
+        let footer_end = footer[1..].iter().position(|byte| *byte == b'\n')? + 1;
+        let footer = match &footer[1..footer_end] {
+            [] => None,
+            tz_string => Some(PosixTimeZone::parse(core::str::from_utf8(tz_string).ok()?)?),
+        };
+
+        Self::parse_data_block(&header, &bytes[data_start..data_end], 8, footer)
+    }
+
+    fn parse_data_block(
+        header: &TzifHeader,
+        data: &[u8],
+        time_size: usize,
+        footer: Option<PosixTimeZone>,
+    ) -> Option<Tzif> {
+        if header.typecnt == 0 {
+            return None;
+        }
+
+        let (times, rest) = data.split_at(header.timecnt * time_size);
+        let (transition_types, rest) = rest.split_at(header.timecnt);
+        let (types, rest) = rest.split_at(header.typecnt * 6);
+        let abbreviations = &rest[..header.charcnt];
+
+        let transition_times = times
+            .chunks_exact(time_size)
+            .map(|time| {
+                if time_size == 4 {
+                    read_i32(time) as i64
+                } else {
+                    read_i64(time)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if transition_types
+            .iter()
+            .any(|index| *index as usize >= header.typecnt)
+        {
+            return None;
+        }
+
+        let mut local_time_types = Vec::with_capacity(header.typecnt);
+        for record in types.chunks_exact(6) {
+            // Abbreviations are NUL terminated strings starting at the given index
+            let abbreviation_start = record[5] as usize;
+            let abbreviation = abbreviations.get(abbreviation_start..)?;
+            let abbreviation_end = abbreviation.iter().position(|byte| *byte == 0)?;
+            let abbreviation = core::str::from_utf8(&abbreviation[..abbreviation_end]).ok()?;
+
+            local_time_types.push(LocalTimeType {
+                offset_seconds: read_i32(&record[0..4]) as i64,
+                is_dst: record[4] != 0,
+                abbreviation: String::from(abbreviation),
+            });
+        }
+
+        Some(Tzif {
+            transition_times,
+            transition_types: transition_types.to_vec(),
+            local_time_types,
+            footer,
+        })
+    }
+
+    /// The offset from UTC at a time in seconds since the Unix epoch.
+    pub fn offset_at(&self, epoch_seconds: i64) -> TimeZoneOffset {
+        // The footer is used for all times after the last transition, or for all times if there
+        // are no transitions.
+        if let Some(footer) = &self.footer {
+            if self
+                .transition_times
+                .last()
+                .is_none_or(|last| epoch_seconds >= *last)
+            {
+                return footer.offset_at(epoch_seconds);
+            }
+        }
+
+        // Number of transitions at or before this time
+        let index = self
+            .transition_times
+            .partition_point(|time| *time <= epoch_seconds);
+
+        // Times before the first transition use the first local time type
+        let local_time_type = if index == 0 {
+            &self.local_time_types[0]
+        } else {
+            &self.local_time_types[self.transition_types[index - 1] as usize]
+        };
+
+        TimeZoneOffset {
+            offset_millis: local_time_type.offset_seconds * 1000,
+            is_dst: local_time_type.is_dst,
+            abbreviation: Some(local_time_type.abbreviation.clone()),
+        }
+    }
+
+    /// Offset from UTC in seconds of the local time type that starts at a transition.
+    fn transition_offset(&self, index: usize) -> i64 {
+        self.local_time_types[self.transition_types[index] as usize].offset_seconds
+    }
+
+    /// Offset from UTC in seconds before a transition. Times before the first transition use the
+    /// first local time type.
+    fn offset_before_transition(&self, index: usize) -> i64 {
+        if index == 0 {
+            self.local_time_types[0].offset_seconds
+        } else {
+            self.transition_offset(index - 1)
+        }
+    }
+
+    /// Transitions of the footer rule in the years around a time, in ascending order. Only
+    /// transitions after the last explicit transition are included.
+    fn footer_transitions_near(&self, epoch_seconds: i64) -> Vec<i64> {
+        let footer = match &self.footer {
+            Some(footer) => footer,
+            None => return Vec::new(),
+        };
+
+        let year = year_from_time(epoch_seconds as f64 * 1000.0) as i64;
+        let last_transition = self.transition_times.last().copied().unwrap_or(i64::MIN);
+
+        let mut transitions = Vec::new();
+        for year in (year - 1)..=(year + 1) {
+            if let Some(year_transitions) = footer.transitions_in_year(year) {
+                transitions.extend(year_transitions);
+            }
+        }
+
+        transitions.retain(|time| *time > last_transition);
+        transitions.sort_unstable();
+
+        transitions
+    }
+
+    /// The time of the first transition strictly after a time where the offset from UTC changes,
+    /// in seconds since the Unix epoch. Returns None if there are no later transitions.
+    pub fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let start = self
+            .transition_times
+            .partition_point(|time| *time <= epoch_seconds);
+
+        for index in start..self.transition_times.len() {
+            if self.transition_offset(index) != self.offset_before_transition(index) {
+                return Some(self.transition_times[index]);
+            }
+        }
+
+        // Footer transitions are searched from the last explicit transition if it is later
+        let search_start = match self.transition_times.last() {
+            Some(last) if *last > epoch_seconds => *last,
+            _ => epoch_seconds,
+        };
+
+        self.footer_transitions_near(search_start)
+            .into_iter()
+            .find(|time| *time > epoch_seconds)
+    }
+
+    /// The time of the last transition strictly before a time where the offset from UTC changes,
+    /// in seconds since the Unix epoch. Returns None if there are no earlier transitions.
+    pub fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        if let Some(time) = self
+            .footer_transitions_near(epoch_seconds)
+            .into_iter()
+            .rev()
+            .find(|time| *time < epoch_seconds)
+        {
+            return Some(time);
+        }
+
+        let end = self
+            .transition_times
+            .partition_point(|time| *time < epoch_seconds);
+
+        (0..end)
+            .rev()
+            .find(|index| self.transition_offset(*index) != self.offset_before_transition(*index))
+            .map(|index| self.transition_times[index])
+    }
+}
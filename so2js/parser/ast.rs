@@ -439,6 +439,34 @@ pub enum Statement<'a> {
     Debugger(P<'a, Loc>),
 }
 
+impl Statement<'_> {
+    /// The source location of the statement.
+    pub fn loc(&self) -> Loc {
+        match self {
+            Statement::VarDecl(stmt) => stmt.loc,
+            Statement::FuncDecl(func) => func.loc,
+            Statement::ClassDecl(class) => class.loc,
+            Statement::Expr(stmt) => stmt.loc,
+            Statement::Block(stmt) => stmt.loc,
+            Statement::If(stmt) => stmt.loc,
+            Statement::Switch(stmt) => stmt.loc,
+            Statement::For(stmt) => stmt.loc,
+            Statement::ForEach(stmt) => stmt.loc,
+            Statement::While(stmt) => stmt.loc,
+            Statement::DoWhile(stmt) => stmt.loc,
+            Statement::With(stmt) => stmt.loc,
+            Statement::Try(stmt) => stmt.loc,
+            Statement::Throw(stmt) => stmt.loc,
+            Statement::Return(stmt) => stmt.loc,
+            Statement::Break(stmt) => stmt.loc,
+            Statement::Continue(stmt) => stmt.loc,
+            Statement::Labeled(stmt) => stmt.loc,
+            Statement::Empty(loc) => *loc.as_ref(),
+            Statement::Debugger(loc) => *loc.as_ref(),
+        }
+    }
+}
+
 #[derive(PartialEq)]
 pub enum VarKind {
     Var,
@@ -629,6 +629,7 @@ impl ScopeTree<'_> {
     ) {
         // Extract allocator
         let alloc = self.alloc;
+        let debug_scopes = self.options.debug_scopes;
 
         let vm_node_id = self.vm_nodes.len();
         let ast_node = self.get_ast_node_mut(ast_node_id);
@@ -721,10 +722,10 @@ impl ScopeTree<'_> {
                 // All private names must be placed in a VM scope
                 matches!(binding.kind(), BindingKind::PrivateName) ||
                 // We sometimes force bindings in VM scopes due to dynamic accesses.
-                // e.g. in the presence of `eval` or `with`
+                // e.g. in the presence of `eval` or `with`, or so that a debugger can read them.
                 //
                 // No need to place the global `this` in a VM scope unless it is captured.
-                (ast_node.supports_dynamic_access &&
+                ((ast_node.supports_dynamic_access || debug_scopes) &&
                     !(*name == "this" &&
                         (ast_node.kind == ScopeNodeKind::Global
                             || ast_node.kind == ScopeNodeKind::Module)))
@@ -159,6 +159,15 @@ impl AsyncGeneratorObject {
             .realm_ptr()
     }
 
+    pub fn closure_ptr(&self) -> HeapPtr<Closure> {
+        StackFrame::for_fp(self.current_fp().cast_mut()).closure()
+    }
+
+    /// Byte offset of the PC to resume into the BytecodeFunction of the suspended closure.
+    pub fn resume_pc_offset(&self) -> usize {
+        self.pc_to_resume_offset
+    }
+
     pub fn suspend_yield(
         &mut self,
         pc_to_resume_offset: usize,
@@ -279,6 +288,10 @@ impl AsyncGeneratorRequest {
         Ok(request)
     }
 
+    pub fn capability_ptr(&self) -> HeapPtr<PromiseCapability> {
+        self.capability
+    }
+
     pub fn completion_value(&self) -> Value {
         self.completion_value
     }
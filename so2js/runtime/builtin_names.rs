@@ -90,12 +90,17 @@ builtin_names!(
     (async_function, "AsyncFunction"),
     (async_generator, "AsyncGenerator"),
     (async_generator_function, "AsyncGeneratorFunction"),
+    (atomics, "Atomics"),
     (bigint, "BigInt"),
     (big_int64_array, "BigInt64Array"),
     (big_uint64_array, "BigUint64Array"),
     (boolean, "Boolean"),
+    (collator, "Collator"),
     (data_view, "DataView"),
     (date, "Date"),
+    (date_time_format, "DateTimeFormat"),
+    (display_names, "DisplayNames"),
+    (duration, "Duration"),
     (error, "Error"),
     (eval_error, "EvalError"),
     (finalization_registry, "FinalizationRegistry"),
@@ -106,26 +111,41 @@ builtin_names!(
     (generator, "Generator"),
     (generator_function, "GeneratorFunction"),
     (infinity, "Infinity"),
+    (instant, "Instant"),
     (int8_array, "Int8Array"),
     (int16_array, "Int16Array"),
     (int32_array, "Int32Array"),
+    (intl, "Intl"),
     (iterator, "Iterator"),
     (json, "JSON"),
+    (list_format, "ListFormat"),
+    (locale, "Locale"),
     (map, "Map"),
     (math, "Math"),
     (module, "Module"),
     (number, "Number"),
+    (number_format, "NumberFormat"),
     (object, "Object"),
+    (plain_date, "PlainDate"),
+    (plain_date_time, "PlainDateTime"),
+    (plain_month_day, "PlainMonthDay"),
+    (plain_time, "PlainTime"),
+    (plain_year_month, "PlainYearMonth"),
+    (plural_rules, "PluralRules"),
     (promise, "Promise"),
     (proxy, "Proxy"),
     (range_error, "RangeError"),
     (reference_error, "ReferenceError"),
     (reflect, "Reflect"),
     (regexp, "RegExp"),
+    (relative_time_format, "RelativeTimeFormat"),
+    (segmenter, "Segmenter"),
     (set, "Set"),
+    (shared_array_buffer, "SharedArrayBuffer"),
     (string, "String"),
     (symbol, "Symbol"),
     (syntax_error, "SyntaxError"),
+    (temporal, "Temporal"),
     (typed_array, "TypedArray"),
     (type_error, "TypeError"),
     (uint8_array, "Uint8Array"),
@@ -137,12 +157,14 @@ builtin_names!(
     (weak_map, "WeakMap"),
     (weak_ref, "WeakRef"),
     (weak_set, "WeakSet"),
+    (zoned_date_time, "ZonedDateTime"),
     (abs, "abs"),
     (acos, "acos"),
     (acosh, "acosh"),
     (add, "add"),
     (all, "all"),
     (all_settled, "allSettled"),
+    (and, "and"),
     (anonymous, "anonymous"),
     (any, "any"),
     (apply, "apply"),
@@ -152,18 +174,26 @@ builtin_names!(
     (asin, "asin"),
     (asinh, "asinh"),
     (assign, "assign"),
+    (async_, "async"),
     (async_iterator, "asyncIterator"),
     (at, "at"),
     (atan, "atan"),
     (atanh, "atanh"),
     (atan2, "atan2"),
+    (base_name, "baseName"),
     (bind, "bind"),
+    (blank, "blank"),
     (buffer, "buffer"),
     (byte_length, "byteLength"),
     (byte_offset, "byteOffset"),
+    (calendar, "calendar"),
+    (calendar_id, "calendarId"),
+    (calendar_name, "calendarName"),
     (call, "call"),
     (callee, "callee"),
     (caller, "caller"),
+    (capture_stack_trace, "captureStackTrace"),
+    (case_first, "caseFirst"),
     (catch, "catch"),
     (cause, "cause"),
     (cbrt, "cbrt"),
@@ -173,15 +203,32 @@ builtin_names!(
     (clear, "clear"),
     (clz32, "clz32"),
     (code_point_at, "codePointAt"),
+    (collation, "collation"),
+    (compact_display, "compactDisplay"),
+    (compare, "compare"),
+    (compare_exchange, "compareExchange"),
     (concat, "concat"),
     (configurable, "configurable"),
     (console, "console"),
     (construct, "construct"),
     (constructor, "constructor"),
+    (containing, "containing"),
     (copy_within, "copyWithin"),
     (cos, "cos"),
     (cosh, "cosh"),
     (create, "create"),
+    (currency, "currency"),
+    (currency_display, "currencyDisplay"),
+    (currency_sign, "currencySign"),
+    (date_style, "dateStyle"),
+    (day, "day"),
+    (day_of_week, "dayOfWeek"),
+    (day_of_year, "dayOfYear"),
+    (day_period, "dayPeriod"),
+    (days, "days"),
+    (days_in_month, "daysInMonth"),
+    (days_in_week, "daysInWeek"),
+    (days_in_year, "daysInYear"),
     (decode_uri, "decodeURI"),
     (decode_uri_component, "decodeURIComponent"),
     (default, "default"),
@@ -193,6 +240,8 @@ builtin_names!(
     (description, "description"),
     (detached, "detached"),
     (difference, "difference"),
+    (direction, "direction"),
+    (disambiguation, "disambiguation"),
     (done, "done"),
     (dot_all, "dotAll"),
     (drop, "drop"),
@@ -201,14 +250,21 @@ builtin_names!(
     (ends_with, "endsWith"),
     (entries, "entries"),
     (enumerable, "enumerable"),
+    (epoch_milliseconds, "epochMilliseconds"),
+    (epoch_nanoseconds, "epochNanoseconds"),
+    (equals, "equals"),
+    (era, "era"),
+    (era_year, "eraYear"),
     (errors, "errors"),
     (escape, "escape"),
     (eval, "eval"),
     (every, "every"),
+    (exchange, "exchange"),
     (exec, "exec"),
     (exp, "exp"),
     (expm1, "expm1"),
     (f16_round, "f16round"),
+    (fallback, "fallback"),
     (fill, "fill"),
     (filter, "filter"),
     (finally, "finally"),
@@ -222,27 +278,40 @@ builtin_names!(
     (floor, "floor"),
     (for_, "for"),
     (for_each, "forEach"),
+    (format, "format"),
+    (format_matcher, "formatMatcher"),
+    (format_range, "formatRange"),
+    (format_range_to_parts, "formatRangeToParts"),
+    (format_to_parts, "formatToParts"),
+    (fractional_second_digits, "fractionalSecondDigits"),
     (freeze, "freeze"),
     (from, "from"),
     (from_char_code, "fromCharCode"),
     (from_code_point, "fromCodePoint"),
     (from_entries, "fromEntries"),
+    (from_epoch_milliseconds, "fromEpochMilliseconds"),
+    (from_epoch_nanoseconds, "fromEpochNanoseconds"),
     (fround, "fround"),
     (fulfilled, "fulfilled"),
     (gc, "gc"),
     (get, "get"),
     (get_big_int64, "getBigInt64"),
     (get_big_uint64, "getBigUint64"),
+    (get_canonical_locales, "getCanonicalLocales"),
+    (get_column_number, "getColumnNumber"),
     (get_date, "getDate"),
     (get_day, "getDay"),
+    (get_file_name, "getFileName"),
     (get_float16, "getFloat16"),
     (get_float32, "getFloat32"),
     (get_float64, "getFloat64"),
     (get_full_year, "getFullYear"),
+    (get_function_name, "getFunctionName"),
     (get_hours, "getHours"),
     (get_int8, "getInt8"),
     (get_int16, "getInt16"),
     (get_int32, "getInt32"),
+    (get_line_number, "getLineNumber"),
     (get_milliseconds, "getMilliseconds"),
     (get_minutes, "getMinutes"),
     (get_month, "getMonth"),
@@ -254,6 +323,7 @@ builtin_names!(
     (get_seconds, "getSeconds"),
     (get_time, "getTime"),
     (get_timezone_offset, "getTimezoneOffset"),
+    (get_time_zone_transition, "getTimeZoneTransition"),
     (get_uint8, "getUint8"),
     (get_uint16, "getUint16"),
     (get_uint32, "getUint32"),
@@ -267,31 +337,46 @@ builtin_names!(
     (get_utc_seconds, "getUTCSeconds"),
     (global, "global"),
     (global_this, "globalThis"),
+    (granularity, "granularity"),
     (groups, "groups"),
     (group_by, "groupBy"),
+    (grow, "grow"),
+    (growable, "growable"),
     (has, "has"),
     (has_indices, "hasIndices"),
     (has_instance, "hasInstance"),
     (has_own, "hasOwn"),
     (has_own_property, "hasOwnProperty"),
+    (hour, "hour"),
+    (hour12, "hour12"),
+    (hour_cycle, "hourCycle"),
+    (hours, "hours"),
+    (hours_in_day, "hoursInDay"),
     (hypot, "hypot"),
     (ignore_case, "ignoreCase"),
+    (ignore_punctuation, "ignorePunctuation"),
     (imul, "imul"),
     (includes, "includes"),
     (index, "index"),
     (index_of, "indexOf"),
     (indices, "indices"),
+    (in_leap_year, "inLeapYear"),
     (input, "input"),
+    (instant_, "instant"),
     (intersection, "intersection"),
     (is, "is"),
     (is_array, "isArray"),
+    (is_async, "isAsync"),
     (is_concat_spreadable, "isConcatSpreadable"),
+    (is_constructor, "isConstructor"),
     (is_error, "isError"),
     (is_extensible, "isExtensible"),
     (is_finite, "isFinite"),
     (is_frozen, "isFrozen"),
     (is_integer, "isInteger"),
+    (is_lock_free, "isLockFree"),
     (is_nan, "isNaN"),
+    (is_native, "isNative"),
     (is_prototype_of, "isPrototypeOf"),
     (is_safe_integer, "isSafeInteger"),
     (is_sealed, "isSealed"),
@@ -300,14 +385,21 @@ builtin_names!(
     (is_superset_of, "isSupersetOf"),
     (is_view, "isView"),
     (is_well_formed, "isWellFormed"),
+    (is_word_like, "isWordLike"),
     (iterator_, "iterator"),
     (join, "join"),
     (keys, "keys"),
     (key_for, "keyFor"),
+    (language, "language"),
+    (language_display, "languageDisplay"),
+    (largest_unit, "largestUnit"),
     (last_index, "lastIndex"),
     (last_index_of, "lastIndexOf"),
     (length, "length"),
+    (load, "load"),
+    (locale_, "locale"),
     (locale_compare, "localeCompare"),
+    (locale_matcher, "localeMatcher"),
     (log, "log"),
     (log1p, "log1p"),
     (log10, "log10"),
@@ -317,28 +409,65 @@ builtin_names!(
     (match_all, "matchAll"),
     (max, "max"),
     (max_byte_length, "maxByteLength"),
+    (maximize, "maximize"),
+    (maximum_fraction_digits, "maximumFractionDigits"),
+    (maximum_significant_digits, "maximumSignificantDigits"),
     (message, "message"),
+    (microsecond, "microsecond"),
+    (microseconds, "microseconds"),
+    (millisecond, "millisecond"),
+    (milliseconds, "milliseconds"),
     (min, "min"),
+    (minimize, "minimize"),
+    (minimum_fraction_digits, "minimumFractionDigits"),
+    (minimum_integer_digits, "minimumIntegerDigits"),
+    (minimum_significant_digits, "minimumSignificantDigits"),
+    (minute, "minute"),
+    (minutes, "minutes"),
+    (month, "month"),
+    (month_code, "monthCode"),
+    (months, "months"),
+    (months_in_year, "monthsInYear"),
     (multiline, "multiline"),
     (name, "name"),
     (nan, "NaN"),
+    (nanosecond, "nanosecond"),
+    (nanoseconds, "nanoseconds"),
+    (negated, "negated"),
     (next, "next"),
     (nfc, "NFC"),
     (nfd, "NFD"),
     (nfkc, "NFKC"),
     (nfkd, "NFKD"),
     (normalize, "normalize"),
+    (not_equal, "not-equal"),
+    (notation, "notation"),
+    (notify, "notify"),
     (now, "now"),
     (number_, "number"),
+    (numbering_system, "numberingSystem"),
+    (numeric, "numeric"),
     (of, "of"),
+    (offset, "offset"),
+    (offset_nanoseconds, "offsetNanoseconds"),
+    (ok, "ok"),
+    (or, "or"),
+    (overflow, "overflow"),
     (own_keys, "ownKeys"),
     (pad_end, "padEnd"),
     (pad_start, "padStart"),
     (parse, "parse"),
     (parse_float, "parseFloat"),
     (parse_int, "parseInt"),
+    (pause, "pause"),
+    (plain_date_iso, "plainDateISO"),
+    (plain_date_time_iso, "plainDateTimeISO"),
+    (plain_time_, "plainTime"),
+    (plain_time_iso, "plainTimeISO"),
+    (plural_categories, "pluralCategories"),
     (pop, "pop"),
     (pow, "pow"),
+    (prepare_stack_trace, "prepareStackTrace"),
     (prevent_extensions, "preventExtensions"),
     (promise_, "promise"),
     (property_is_enumerable, "propertyIsEnumerable"),
@@ -351,23 +480,36 @@ builtin_names!(
     (reason, "reason"),
     (reduce, "reduce"),
     (reduce_right, "reduceRight"),
+    (region, "region"),
     (register, "register"),
     (reject, "reject"),
     (rejected, "rejected"),
+    (relative_to, "relativeTo"),
     (resize, "resize"),
     (resizable, "resizable"),
     (resolve, "resolve"),
     (repeat, "repeat"),
     (replace, "replace"),
     (replace_all, "replaceAll"),
+    (resolved_options, "resolvedOptions"),
     (return_, "return"),
     (reverse, "reverse"),
     (revocable, "revocable"),
     (revoke, "revoke"),
     (round, "round"),
+    (rounding_increment, "roundingIncrement"),
+    (rounding_mode, "roundingMode"),
+    (rounding_priority, "roundingPriority"),
     (run, "run"),
+    (script, "script"),
     (seal, "seal"),
     (search, "search"),
+    (second, "second"),
+    (seconds, "seconds"),
+    (segment, "segment"),
+    (select, "select"),
+    (select_range, "selectRange"),
+    (sensitivity, "sensitivity"),
     (set_, "set"),
     (set_big_int64, "setBigInt64"),
     (set_big_uint64, "setBigUint64"),
@@ -398,10 +540,13 @@ builtin_names!(
     (set_utc_seconds, "setUTCSeconds"),
     (shift, "shift"),
     (sign, "sign"),
+    (sign_display, "signDisplay"),
     (sin, "sin"),
+    (since, "since"),
     (sinh, "sinh"),
     (size, "size"),
     (slice, "slice"),
+    (smallest_unit, "smallestUnit"),
     (some, "some"),
     (sort, "sort"),
     (source, "source"),
@@ -410,13 +555,21 @@ builtin_names!(
     (splice, "splice"),
     (sqrt, "sqrt"),
     (stack, "stack"),
+    (stack_trace_limit, "stackTraceLimit"),
+    (start_of_day, "startOfDay"),
     (starts_with, "startsWith"),
     (status, "status"),
     (sticky, "sticky"),
+    (store, "store"),
     (string_, "string"),
     (stringify, "stringify"),
+    (style, "style"),
+    (sub, "sub"),
     (subarray, "subarray"),
     (substring, "substring"),
+    (subtract, "subtract"),
+    (supported_locales_of, "supportedLocalesOf"),
+    (supported_values_of, "supportedValuesOf"),
     (symmetric_difference, "symmetricDifference"),
     (take, "take"),
     (tan, "tan"),
@@ -425,10 +578,16 @@ builtin_names!(
     (then, "then"),
     (this, "this"),
     (throw, "throw"),
+    (timed_out, "timed-out"),
+    (time_style, "timeStyle"),
+    (time_zone, "timeZone"),
+    (time_zone_id, "timeZoneId"),
+    (time_zone_name, "timeZoneName"),
     (to_array, "toArray"),
     (to_date_string, "toDateString"),
     (to_exponential, "toExponential"),
     (to_fixed, "toFixed"),
+    (to_instant, "toInstant"),
     (to_iso_string, "toISOString"),
     (to_json, "toJSON"),
     (to_locale_date_string, "toLocaleDateString"),
@@ -437,6 +596,11 @@ builtin_names!(
     (to_locale_time_string, "toLocaleTimeString"),
     (to_locale_upper_case, "toLocaleUpperCase"),
     (to_lower_case, "toLowerCase"),
+    (to_plain_date, "toPlainDate"),
+    (to_plain_date_time, "toPlainDateTime"),
+    (to_plain_month_day, "toPlainMonthDay"),
+    (to_plain_time, "toPlainTime"),
+    (to_plain_year_month, "toPlainYearMonth"),
     (to_precision, "toPrecision"),
     (to_primitive, "toPrimitive"),
     (to_reversed, "toReversed"),
@@ -444,10 +608,15 @@ builtin_names!(
     (to_spliced, "toSpliced"),
     (to_string, "toString"),
     (to_string_tag, "toStringTag"),
+    (total, "total"),
+    (to_temporal_instant, "toTemporalInstant"),
     (to_time_string, "toTimeString"),
     (to_upper_case, "toUpperCase"),
     (to_utc_string, "toUTCString"),
     (to_well_formed, "toWellFormed"),
+    (to_zoned_date_time, "toZonedDateTime"),
+    (to_zoned_date_time_iso, "toZonedDateTimeISO"),
+    (trailing_zero_display, "trailingZeroDisplay"),
     (transfer, "transfer"),
     (transfer_to_fixed_length, "transferToFixedLength"),
     (trim, "trim"),
@@ -455,19 +624,38 @@ builtin_names!(
     (trim_start, "trimStart"),
     (trunc, "trunc"),
     (try_, "try"),
+    (type_, "type"),
     (undefined, "undefined"),
     (unicode, "unicode"),
     (unicode_sets, "unicodeSets"),
     (union, "union"),
+    (unit, "unit"),
+    (unit_display, "unitDisplay"),
     (unregister, "unregister"),
     (unscopables, "unscopables"),
     (unshift, "unshift"),
+    (until, "until"),
+    (usage, "usage"),
+    (use_grouping, "useGrouping"),
     (value, "value"),
     (values, "values"),
     (value_of, "valueOf"),
+    (wait, "wait"),
+    (wait_async, "waitAsync"),
+    (weekday, "weekday"),
+    (week_of_year, "weekOfYear"),
+    (weeks, "weeks"),
     (with, "with"),
+    (with_calendar, "withCalendar"),
+    (with_plain_time, "withPlainTime"),
     (with_resolvers, "withResolvers"),
-    (writable, "writable")
+    (with_time_zone, "withTimeZone"),
+    (writable, "writable"),
+    (xor, "xor"),
+    (year, "year"),
+    (year_of_week, "yearOfWeek"),
+    (years, "years"),
+    (zoned_date_time_iso, "zonedDateTimeISO")
 );
 
 /// Well-Known Symbols (https://tc39.es/ecma262/#sec-well-known-symbols)
@@ -553,5 +741,7 @@ builtin_symbols!(
     (constructor, ""),
     (on_finally, ""),
     // Symbols used for private properties of async generator resolve/reject functions
-    (async_generator, "")
+    (async_generator, ""),
+    // Symbols used for private properties of Intl bound functions
+    (intl_object, "")
 );
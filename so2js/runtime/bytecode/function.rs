@@ -33,8 +33,12 @@ use crate::{
 };
 
 use super::{
-    constant_table::ConstantTable, exception_handlers::ExceptionStackRootrs,
-    instruction::debug_format_instructions, source_map::BytecodeSourceMap,
+    constant_table::ConstantTable,
+    exception_handlers::ExceptionStackRootrs,
+    global_cache::{GlobalCache, GlobalCacheArray},
+    inline_cache::{InlineCache, InlineCacheArray},
+    instruction::debug_format_instructions,
+    source_map::BytecodeSourceMap,
 };
 
 // A closure is a pair of a function and its scope. Represents the instantiation of a function's
@@ -266,6 +270,12 @@ pub struct BytecodeFunction {
     ///
     /// `source_map` is None iff `source_file` is None
     source_map: Option<HeapPtr<ByteArray>>,
+    /// Inline caches for the named property accesses in this function. None if there are no named
+    /// property accesses.
+    inline_caches: Option<HeapPtr<InlineCacheArray>>,
+    /// Caches for the global variable accesses in this function. None if there are no global
+    /// variable accesses.
+    global_caches: Option<HeapPtr<GlobalCacheArray>>,
     /// This function may be a stub function back into the Rust runtime. If this is set then this
     /// function has an empty bytecode array and default values for many other fields.
     rust_runtime_function_id: Option<RustRuntimeFunctionId>,
@@ -294,6 +304,11 @@ impl BytecodeFunction {
         source_file: StackRoot<SourceFile>,
         source_map: StackRoot<ByteArray>,
     ) -> AllocResult<StackRoot<BytecodeFunction>> {
+        let inline_caches =
+            InlineCache::new_array_for_bytecode(cx, &bytecode)?.map(|caches| caches.to_stack(cx));
+        let global_caches =
+            GlobalCache::new_array_for_bytecode(cx, &bytecode)?.map(|caches| caches.to_stack(cx));
+
         let size = Self::calculate_size_in_bytes(bytecode.len());
         let mut object = cx.alloc_uninit_with_size::<BytecodeFunction>(size)?;
 
@@ -317,6 +332,8 @@ impl BytecodeFunction {
         set_uninit!(object.name, name.map(|n| *n));
         set_uninit!(object.source_file, Some(*source_file));
         set_uninit!(object.source_map, Some(*source_map));
+        set_uninit!(object.inline_caches, inline_caches.map(|c| *c));
+        set_uninit!(object.global_caches, global_caches.map(|c| *c));
         set_uninit!(object.rust_runtime_function_id, None);
         object.bytecode.init_from_slice(&bytecode);
 
@@ -364,6 +381,8 @@ impl BytecodeFunction {
         set_uninit!(object.name, name.map(|n| *n));
         set_uninit!(object.source_file, None);
         set_uninit!(object.source_map, None);
+        set_uninit!(object.inline_caches, None);
+        set_uninit!(object.global_caches, None);
         set_uninit!(object.rust_runtime_function_id, Some(function_id));
         object.bytecode.init_from_slice(&[]);
 
@@ -451,6 +470,11 @@ impl BytecodeFunction {
         self.name.map(|n| n.to_stack(cx))
     }
 
+    #[inline]
+    pub fn name_ptr(&self) -> Option<HeapPtr<StringValue>> {
+        self.name
+    }
+
     #[inline]
     pub fn source_file_ptr(&self) -> Option<HeapPtr<SourceFile>> {
         self.source_file
@@ -466,6 +490,16 @@ impl BytecodeFunction {
         self.source_map.map(BytecodeSourceMap::get_function_range)
     }
 
+    #[inline]
+    pub fn inline_caches_ptr(&self) -> Option<HeapPtr<InlineCacheArray>> {
+        self.inline_caches
+    }
+
+    #[inline]
+    pub fn global_caches_ptr(&self) -> Option<HeapPtr<GlobalCacheArray>> {
+        self.global_caches
+    }
+
     #[inline]
     pub fn rust_runtime_function_id(&self) -> Option<RustRuntimeFunctionId> {
         self.rust_runtime_function_id
@@ -547,5 +581,7 @@ impl HeapItem for HeapPtr<BytecodeFunction> {
         visitor.visit_pointer_opt(&mut self.name);
         visitor.visit_pointer_opt(&mut self.source_file);
         visitor.visit_pointer_opt(&mut self.source_map);
+        visitor.visit_pointer_opt(&mut self.inline_caches);
+        visitor.visit_pointer_opt(&mut self.global_caches);
     }
 }
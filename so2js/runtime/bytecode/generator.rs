@@ -20,7 +20,7 @@ use crate::{
     parser::{
         analyze::{AnalyzedFunctionResult, AnalyzedProgramResult},
         ast::{self, AstPtr, AstStr, LabelId, ProgramKind, ResolvedScope, TaggedResolvedScope},
-        loc::{Pos, NO_POS},
+        loc::{Loc, Pos, NO_POS},
         scope_tree::{
             AstScopeNode, Binding, BindingKind, ScopeNodeId, ScopeNodeKind, ScopeTree, VMLocation,
             VMScopeNode, ANONYMOUS_DEFAULT_EXPORT_NAME, ARGUMENTS_NAME, DEFAULT_EXPORT_NAME,
@@ -39,6 +39,7 @@ use crate::{
         },
         class_names::{ClassNames, HomeObjectLocation, Method},
         collections::{BsVec, BsVecField},
+        coverage, debugger,
         eval::expression::generate_template_object,
         gc::Escapable,
         global_names::GlobalNames,
@@ -119,6 +120,10 @@ impl<'a> BytecodeProgramGenerator<'a> {
     ) -> AllocResult<Self> {
         let source_file = SourceFile::new(cx, &source)?;
 
+        if cx.has_debugger() {
+            debugger::on_source_file_loaded(cx, source_file);
+        }
+
         // If we are dumping bytecode then we must collect all functions
         let all_functions = if cx.options.print_bytecode {
             Some(FunctionVecField::new_vec(cx, 4)?.to_stack(cx))
@@ -1134,6 +1139,9 @@ pub struct BytecodeFunctionGenerator<'a> {
 
     /// Queue of functions that still need to be generated.
     pending_functions_queue: PendingFunctionNodes<'a>,
+
+    /// Index of this function in the coverage state, if coverage is being collected.
+    coverage_function: Option<usize>,
 }
 
 impl<'a> BytecodeFunctionGenerator<'a> {
@@ -1160,6 +1168,21 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         let mut writer = BytecodeWriter::new();
         writer.write_source_map_header(&source_range);
 
+        // When collecting coverage the function starts by incrementing its function counter
+        let mut coverage_function = None;
+        if cx.is_collecting_coverage() {
+            let coverage_name = name.as_ref().map(|name| name.as_str().to_string());
+            if let Some((function, counter)) = coverage::register_function(
+                cx,
+                *source_file,
+                coverage_name.unwrap_or_default(),
+                source_range,
+            ) {
+                writer.coverage_counter_instruction(GenUInt::from_unsigned(counter as u32));
+                coverage_function = Some(function);
+            }
+        }
+
         Self {
             writer,
             cx,
@@ -1192,6 +1215,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             register_allocator: TemporaryRegisterAllocator::new(num_local_registers),
             exception_handler_builder: ExceptionStackRootrsBuilder::new(),
             pending_functions_queue: vec![],
+            coverage_function,
         }
     }
 
@@ -1300,7 +1324,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             parent: None,
         });
 
-        Ok(Self::new(
+        let generator = Self::new(
             cx,
             scope_tree,
             scope,
@@ -1318,7 +1342,13 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             /* is_class_constructor */ false,
             /* is_base_constructor */ false,
             /* async */ program.has_top_level_await,
-        ))
+        );
+
+        if let Some(coverage_function) = generator.coverage_function {
+            coverage::set_toplevel_function(cx, coverage_function);
+        }
+
+        Ok(generator)
     }
 
     fn new_for_default_constructor(
@@ -2277,6 +2307,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             ast::Toplevel::Statement(stmt) => {
                 // Ignore completion of toplevel statements, generate later toplevels even if abrupt
                 let _ = self.gen_statement(stmt)?;
+                self.gen_coverage_continuation_counter(stmt, program.loc.end, true);
             }
             ast::Toplevel::Import(_) => {
                 // No evaluation action is needed for import declarations. These are initialized
@@ -3494,6 +3525,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         }
 
         // Generate right expression, storing in (possibly temporary) dest
+        self.gen_coverage_branch_counter(expr.right.loc());
         self.gen_expression_with_dest(&expr.right, ExprDest::Fixed(temporary_dest))?;
 
         self.start_block(join_block);
@@ -3523,10 +3555,12 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         self.register_allocator.release(test);
         let dest = self.allocate_destination(dest)?;
 
+        self.gen_coverage_branch_counter(expr.conseq.loc());
         self.gen_expression_with_dest(&expr.conseq, ExprDest::Fixed(dest))?;
         self.write_jump_instruction(join_block)?;
 
         self.start_block(altern_block);
+        self.gen_coverage_branch_counter(expr.altern.loc());
         self.gen_expression_with_dest(&expr.altern, ExprDest::Fixed(dest))?;
 
         self.start_block(join_block);
@@ -7809,6 +7843,11 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         for stmt in stmts {
             let completion = self.gen_statement(stmt)?;
 
+            if self.coverage_function.is_some() {
+                let end = stmts.last().unwrap().loc().end;
+                self.gen_coverage_continuation_counter(stmt, end, !completion.is_abrupt());
+            }
+
             // An abrupt completion signals the end of the statement list
             if completion.is_abrupt() {
                 return Ok(completion);
@@ -7818,6 +7857,66 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         Ok(StmtCompletion::Normal)
     }
 
+    /// Emit a coverage counter at the start of a branch, if coverage is being collected.
+    fn gen_coverage_branch_counter(&mut self, loc: Loc) {
+        if let Some(function) = self.coverage_function {
+            let range = loc.to_range();
+            if let Some(counter) =
+                coverage::add_block(self.cx, function, range, /* is_branch */ true)
+            {
+                self.writer
+                    .coverage_counter_instruction(GenUInt::from_unsigned(counter as u32));
+            }
+        }
+    }
+
+    /// Code following a statement that may not complete normally, up until the end of the
+    /// enclosing statement list, is its own coverage block. The counter is only emitted if the
+    /// following code is reachable, otherwise the block is never counted.
+    fn gen_coverage_continuation_counter(
+        &mut self,
+        stmt: &ast::Statement,
+        end: Pos,
+        is_reachable: bool,
+    ) {
+        let function = match self.coverage_function {
+            Some(function) => function,
+            None => return,
+        };
+
+        let may_skip_following_code = matches!(
+            stmt,
+            ast::Statement::Block(_)
+                | ast::Statement::If(_)
+                | ast::Statement::Switch(_)
+                | ast::Statement::For(_)
+                | ast::Statement::ForEach(_)
+                | ast::Statement::While(_)
+                | ast::Statement::DoWhile(_)
+                | ast::Statement::With(_)
+                | ast::Statement::Try(_)
+                | ast::Statement::Throw(_)
+                | ast::Statement::Return(_)
+                | ast::Statement::Break(_)
+                | ast::Statement::Continue(_)
+                | ast::Statement::Labeled(_)
+        );
+
+        let start = stmt.loc().end;
+        if !may_skip_following_code || start >= end {
+            return;
+        }
+
+        if let Some(counter) =
+            coverage::add_block(self.cx, function, start..end, /* is_branch */ false)
+        {
+            if is_reachable {
+                self.writer
+                    .coverage_counter_instruction(GenUInt::from_unsigned(counter as u32));
+            }
+        }
+    }
+
     /// Many statements complete to undefined by default if no other value is found.
     fn gen_undefined_completion_if_necessary(&mut self) {
         if let Some(completion_dest) = self.statement_completion_dest {
@@ -7837,6 +7936,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             // If there is no alternative, branch between consequent and join block
             self.write_jump_false_for_expression(&stmt.test.expr, condition, join_block)?;
 
+            self.gen_coverage_branch_counter(stmt.conseq.loc());
             self.gen_statement(&stmt.conseq)?;
             self.start_block(join_block);
 
@@ -7851,13 +7951,16 @@ impl<'a> BytecodeFunctionGenerator<'a> {
             // joining at the join block.
             self.write_jump_false_for_expression(&stmt.test.expr, condition, altern_block)?;
 
+            self.gen_coverage_branch_counter(stmt.conseq.loc());
             let conseq_completion = self.gen_statement(&stmt.conseq)?;
             if !conseq_completion.is_abrupt() {
                 self.write_jump_instruction(join_block)?;
             }
 
             self.start_block(altern_block);
-            let altern_completion = self.gen_statement(stmt.altern.as_ref().unwrap())?;
+            let altern = stmt.altern.as_ref().unwrap();
+            self.gen_coverage_branch_counter(altern.loc());
+            let altern_completion = self.gen_statement(altern)?;
 
             self.start_block(join_block);
 
@@ -7946,6 +8049,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         // Write the case block bodies in order
         for (case, case_block_id) in stmt.cases.iter().zip(&case_block_ids) {
             self.start_block(*case_block_id);
+            self.gen_coverage_branch_counter(case.loc);
             self.gen_statement_list(&case.body)?;
 
             // Intentionally fall through to the next case body
@@ -8017,6 +8121,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         }
 
         // Evaluate the loop body
+        self.gen_coverage_branch_counter(stmt.body.loc());
         self.gen_statement(&stmt.body)?;
 
         // Start the update block, which is always emitted even if loop body has an abrupt
@@ -8163,6 +8268,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
                 )?;
 
                 // Then continues to the rest of the loop body
+                this.gen_coverage_branch_counter(stmt.body.loc());
                 this.gen_statement(&stmt.body)
             })?;
 
@@ -8361,6 +8467,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
         self.exit_has_assign_expr_context();
 
         // Otherwise proceed to the body of the loop
+        self.gen_coverage_branch_counter(stmt.body.loc());
         self.gen_statement(&stmt.body)?;
 
         // Finally end this iteration's scope and start a new iteration
@@ -8435,6 +8542,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
 
         self.write_jump_false_for_expression(&stmt.test.expr, test, join_block)?;
 
+        self.gen_coverage_branch_counter(stmt.body.loc());
         let body_completion = self.gen_statement(&stmt.body)?;
 
         // Always jump back to the condition at the start of the loop
@@ -8465,6 +8573,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
 
         // Execute the body at the start of the loop
         self.start_block(loop_start_block);
+        self.gen_coverage_branch_counter(stmt.body.loc());
         self.gen_statement(&stmt.body)?;
 
         // Then evaluate test and either break out of loop or continue to next iteration. Test is
@@ -8646,6 +8755,7 @@ impl<'a> BytecodeFunctionGenerator<'a> {
 
         // No need to write a jump from catch to next block after body, since either the finally
         // or join block will be emitted directly after the catch.
+        self.gen_coverage_branch_counter(catch_clause.body.loc);
         let catch_completion = self.gen_block_statement(&catch_clause.body)?;
         self.gen_scope_end(catch_scope);
 
@@ -8884,7 +8994,9 @@ impl<'a> BytecodeFunctionGenerator<'a> {
 
         // Then emit the body of the finally block
         self.gen_undefined_completion_if_necessary();
-        let finalizer_completion = self.gen_block_statement(stmt.finalizer.as_ref().unwrap())?;
+        let finalizer = stmt.finalizer.as_ref().unwrap();
+        self.gen_coverage_branch_counter(finalizer.loc);
+        let finalizer_completion = self.gen_block_statement(finalizer)?;
 
         // Emit the footer of the finally block, which checks the discriminant to determine the
         // incoming branch that was taken.
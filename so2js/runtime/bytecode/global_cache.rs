@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    collections::BsArray,
+    gc::{GcVisitorExt, HeapItem},
+    heap_item_descriptor::HeapItemKind,
+    realm::Realm,
+    scope::Scope,
+    shape::Shape,
+    string_value::FlatString,
+    Context, HeapPtr, PropertyKey, Value,
+};
+
+use super::{
+    inline_cache::{find_cache_index, instruction_end_offsets},
+    instruction::OpCode,
+};
+
+/// The cached location of a global variable.
+#[derive(Clone, Copy)]
+enum GlobalCacheEntry {
+    Uninitialized,
+    /// A lexical binding in one of the realm's global scopes. Lexical names are never removed from
+    /// a realm and cannot be redeclared, so these entries never become stale.
+    Lexical {
+        scope: HeapPtr<Scope>,
+        slot: u32,
+        is_immutable: bool,
+    },
+    /// An own property of the realm's global object at a slot in its named properties map.
+    ///
+    /// Only valid while the global object has the cached shape and no new global scopes have been
+    /// added to the realm, since a later script may declare a lexical name that shadows the
+    /// property.
+    Property {
+        shape: HeapPtr<Shape>,
+        slot: u32,
+        num_global_scopes: u32,
+    },
+}
+
+/// Cache for a single LoadGlobal, LoadGlobalOrUnresolved, or StoreGlobal instruction.
+#[derive(Clone)]
+pub struct GlobalCache {
+    /// Offset of the end of the instruction in the bytecode array. This is the value of the PC
+    /// (relative to the start of the bytecode) while the instruction is executing.
+    bytecode_offset: u32,
+    entry: GlobalCacheEntry,
+}
+
+/// Caches for all global variable accesses in a function, sorted by bytecode offset.
+pub type GlobalCacheArray = BsArray<GlobalCache>;
+
+impl GlobalCache {
+    /// Create a global cache for each global variable access in the given bytecode. Returns None
+    /// if the bytecode has no global variable accesses.
+    pub fn new_array_for_bytecode(
+        cx: Context,
+        bytecode: &[u8],
+    ) -> AllocResult<Option<HeapPtr<GlobalCacheArray>>> {
+        let caches = instruction_end_offsets(bytecode, |opcode| {
+            matches!(
+                opcode,
+                OpCode::LoadGlobal | OpCode::LoadGlobalOrUnresolved | OpCode::StoreGlobal
+            )
+        })
+        .into_iter()
+        .map(|bytecode_offset| GlobalCache {
+            bytecode_offset: bytecode_offset as u32,
+            entry: GlobalCacheEntry::Uninitialized,
+        })
+        .collect::<Vec<_>>();
+
+        if caches.is_empty() {
+            return Ok(None);
+        }
+
+        let array = GlobalCacheArray::new_from_slice(cx, HeapItemKind::GlobalCacheArray, &caches)?;
+
+        Ok(Some(array))
+    }
+
+    /// Load the value of a global variable through the cache. Returns None on a cache miss or if
+    /// the cached property is no longer a data property.
+    #[inline]
+    pub fn load(&self, realm: HeapPtr<Realm>) -> Option<Value> {
+        match self.entry {
+            GlobalCacheEntry::Uninitialized => None,
+            GlobalCacheEntry::Lexical { scope, slot, .. } => Some(scope.get_slot(slot as usize)),
+            GlobalCacheEntry::Property {
+                shape,
+                slot,
+                num_global_scopes,
+            } => {
+                if !is_valid_property_entry(realm, shape, num_global_scopes) {
+                    return None;
+                }
+
+                let named_properties = realm.global_object_ptr().named_properties();
+                let property = named_properties.get_at_entry_index(slot as usize)?;
+
+                if !property.is_data() {
+                    return None;
+                }
+
+                Some(property.value())
+            }
+        }
+    }
+
+    /// Store the value of a global variable through the cache. Returns whether the store
+    /// succeeded. Fails on a cache miss, for immutable lexical bindings, and if the cached property
+    /// is no longer a writable data property.
+    #[inline]
+    pub fn store(&self, realm: HeapPtr<Realm>, value: Value) -> bool {
+        match self.entry {
+            GlobalCacheEntry::Uninitialized => false,
+            GlobalCacheEntry::Lexical {
+                mut scope,
+                slot,
+                is_immutable,
+            } => {
+                // Assignments to constants must error in the slow path
+                if is_immutable {
+                    return false;
+                }
+
+                scope.set_slot(slot as usize, value);
+                true
+            }
+            GlobalCacheEntry::Property {
+                shape,
+                slot,
+                num_global_scopes,
+            } => {
+                if !is_valid_property_entry(realm, shape, num_global_scopes) {
+                    return false;
+                }
+
+                let mut named_properties = realm.global_object_ptr().named_properties();
+                match named_properties.get_at_entry_index_mut(slot as usize) {
+                    Some(property) if property.is_data() && property.is_writable() => {
+                        property.set_value(value);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Cache the current location of a global variable with the given name. Lexical bindings are
+    /// always cached, while global object properties are only cached if they are own data
+    /// properties of the global object.
+    ///
+    /// Does not allocate.
+    pub fn update(&mut self, realm: HeapPtr<Realm>, name: HeapPtr<FlatString>, key: PropertyKey) {
+        // Lexical names shadow global object properties
+        if let Some((scope, slot, is_immutable)) = realm.get_lexical_name_location(name) {
+            self.entry = GlobalCacheEntry::Lexical {
+                scope,
+                slot,
+                is_immutable,
+            };
+            return;
+        }
+
+        let global_object = realm.global_object_ptr();
+        let shape = match global_object.shape() {
+            Some(shape) if global_object.descriptor().is_ordinary_object() => shape,
+            _ => return,
+        };
+
+        let named_properties = global_object.named_properties();
+        if let Some(slot) = named_properties.get_entry_index(&key) {
+            let property = named_properties.get_at_entry_index(slot).unwrap();
+            if property.is_data() && !property.is_private() {
+                self.entry = GlobalCacheEntry::Property {
+                    shape,
+                    slot: slot as u32,
+                    num_global_scopes: realm.num_global_scopes() as u32,
+                };
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_valid_property_entry(
+    realm: HeapPtr<Realm>,
+    shape: HeapPtr<Shape>,
+    num_global_scopes: u32,
+) -> bool {
+    realm.num_global_scopes() == num_global_scopes as usize
+        && realm
+            .global_object_ptr()
+            .shape()
+            .is_some_and(|global_shape| global_shape.ptr_eq(&shape))
+}
+
+/// Find the index of the global cache for the instruction ending at the given bytecode offset.
+#[inline]
+pub fn find_global_cache_index(
+    caches: HeapPtr<GlobalCacheArray>,
+    bytecode_offset: usize,
+) -> Option<usize> {
+    find_cache_index(caches.as_slice(), bytecode_offset, |cache| {
+        cache.bytecode_offset
+    })
+}
+
+pub fn global_cache_array_byte_size(array: HeapPtr<GlobalCacheArray>) -> usize {
+    GlobalCacheArray::calculate_size_in_bytes(array.len())
+}
+
+pub fn global_cache_array_visit_pointers(
+    array: &mut HeapPtr<GlobalCacheArray>,
+    visitor: &mut impl GcVisitorExt,
+) {
+    array.visit_pointers(visitor);
+
+    for cache in array.as_mut_slice() {
+        match &mut cache.entry {
+            GlobalCacheEntry::Uninitialized => {}
+            GlobalCacheEntry::Lexical { scope, .. } => visitor.visit_pointer(scope),
+            GlobalCacheEntry::Property { shape, .. } => visitor.visit_pointer(shape),
+        }
+    }
+}
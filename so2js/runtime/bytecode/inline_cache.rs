@@ -0,0 +1,374 @@
+use alloc::vec::Vec;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    collections::BsArray,
+    gc::{GcVisitorExt, HeapItem},
+    heap_item_descriptor::HeapItemKind,
+    object_value::ObjectValue,
+    shape::Shape,
+    Context, HeapPtr, PropertyKey, Value,
+};
+
+use super::instruction::{InstructionIterator, OpCode};
+
+/// Maximum number of receiver shapes an inline cache can hold before it becomes megamorphic.
+pub const MAX_POLYMORPHIC_ENTRIES: usize = 4;
+
+/// A single cached property location, valid for receivers with a particular shape.
+#[derive(Clone, Copy)]
+pub struct InlineCacheEntry {
+    /// Shape of the receiver object.
+    receiver_shape: HeapPtr<Shape>,
+    /// The receiver's prototype if the property was found on the prototype, otherwise None if the
+    /// property is an own property of the receiver.
+    holder: Option<HeapPtr<ObjectValue>>,
+    /// Shape of the holder when the entry was cached. Only set if there is a holder.
+    holder_shape: Option<HeapPtr<Shape>>,
+    /// Slot of the property in the named properties map of the receiver or holder.
+    slot: u32,
+}
+
+impl InlineCacheEntry {
+    #[inline]
+    pub fn holder(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.holder
+    }
+
+    #[inline]
+    pub fn slot(&self) -> usize {
+        self.slot as usize
+    }
+}
+
+/// The state of an inline cache, determined by the number of distinct receiver shapes seen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InlineCacheState {
+    Uninitialized,
+    Monomorphic,
+    Polymorphic,
+    /// Too many shapes have been seen so the cache is no longer used.
+    Megamorphic,
+}
+
+/// Inline cache for a single GetNamedProperty or SetNamedProperty instruction.
+#[derive(Clone)]
+pub struct InlineCache {
+    /// Offset of the end of the instruction in the bytecode array. This is the value of the PC
+    /// (relative to the start of the bytecode) while the instruction is executing.
+    bytecode_offset: u32,
+    /// Number of entries that have been filled.
+    num_entries: u8,
+    /// Whether the cache has seen more shapes than it can hold.
+    is_megamorphic: bool,
+    entries: [Option<InlineCacheEntry>; MAX_POLYMORPHIC_ENTRIES],
+}
+
+/// Inline caches for all named property accesses in a function, sorted by bytecode offset.
+pub type InlineCacheArray = BsArray<InlineCache>;
+
+impl InlineCache {
+    fn new(bytecode_offset: usize) -> InlineCache {
+        InlineCache {
+            bytecode_offset: bytecode_offset as u32,
+            num_entries: 0,
+            is_megamorphic: false,
+            entries: [None; MAX_POLYMORPHIC_ENTRIES],
+        }
+    }
+
+    /// Create an inline cache for each named property access in the given bytecode. Returns None
+    /// if the bytecode has no named property accesses.
+    pub fn new_array_for_bytecode(
+        cx: Context,
+        bytecode: &[u8],
+    ) -> AllocResult<Option<HeapPtr<InlineCacheArray>>> {
+        let caches = instruction_end_offsets(bytecode, |opcode| {
+            matches!(opcode, OpCode::GetNamedProperty | OpCode::SetNamedProperty)
+        })
+        .into_iter()
+        .map(InlineCache::new)
+        .collect::<Vec<_>>();
+
+        if caches.is_empty() {
+            return Ok(None);
+        }
+
+        let array = InlineCacheArray::new_from_slice(cx, HeapItemKind::InlineCacheArray, &caches)?;
+
+        Ok(Some(array))
+    }
+
+    #[inline]
+    pub fn state(&self) -> InlineCacheState {
+        if self.is_megamorphic {
+            InlineCacheState::Megamorphic
+        } else {
+            match self.num_entries {
+                0 => InlineCacheState::Uninitialized,
+                1 => InlineCacheState::Monomorphic,
+                _ => InlineCacheState::Polymorphic,
+            }
+        }
+    }
+
+    fn add_entry(&mut self, entry: InlineCacheEntry) {
+        if self.is_megamorphic {
+            return;
+        }
+
+        // Replace a stale entry for the same receiver shape and holder, e.g. if the holder's shape
+        // has changed since the entry was cached.
+        let num_entries = self.num_entries as usize;
+        for existing_entry in self.entries[..num_entries].iter_mut().flatten() {
+            if existing_entry.receiver_shape.ptr_eq(&entry.receiver_shape)
+                && is_same_holder(existing_entry.holder, entry.holder)
+            {
+                *existing_entry = entry;
+                return;
+            }
+        }
+
+        if num_entries == MAX_POLYMORPHIC_ENTRIES {
+            // Stop caching and drop the existing entries so they do not keep shapes alive
+            self.is_megamorphic = true;
+            self.num_entries = 0;
+            self.entries = [None; MAX_POLYMORPHIC_ENTRIES];
+            return;
+        }
+
+        self.entries[num_entries] = Some(entry);
+        self.num_entries += 1;
+    }
+
+    /// Find the cached location of the property for the given receiver. The returned object is the
+    /// object whose named properties map holds the property and the slot is the property's index
+    /// in that map.
+    ///
+    /// Returns None on a cache miss.
+    #[inline]
+    fn lookup(&self, receiver: HeapPtr<ObjectValue>) -> Option<(HeapPtr<ObjectValue>, usize)> {
+        if self.num_entries == 0 {
+            return None;
+        }
+
+        let receiver_shape = cacheable_shape(receiver)?;
+
+        // Objects with different prototypes may share a shape, so there may be multiple entries for
+        // a single receiver shape.
+        for entry in self.entries[..self.num_entries as usize].iter().flatten() {
+            if !entry.receiver_shape.ptr_eq(&receiver_shape) {
+                continue;
+            }
+
+            match entry.holder {
+                None => return Some((receiver, entry.slot())),
+                Some(holder) => {
+                    // Prototype is not part of the shape so must be checked separately
+                    let is_valid_holder = receiver
+                        .prototype()
+                        .is_some_and(|prototype| prototype.ptr_eq(&holder))
+                        && holder
+                            .shape()
+                            .is_some_and(|shape| shape.ptr_eq(&entry.holder_shape.unwrap()));
+
+                    if is_valid_holder {
+                        return Some((holder, entry.slot()));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Load the value of a named property through the inline cache. Returns None on a cache miss or
+    /// if the cached property is no longer a data property.
+    #[inline]
+    pub fn load(&self, receiver: HeapPtr<ObjectValue>) -> Option<Value> {
+        let (holder, slot) = self.lookup(receiver)?;
+        let named_properties = holder.named_properties();
+        let property = named_properties.get_at_entry_index(slot)?;
+
+        if !property.is_data() {
+            return None;
+        }
+
+        Some(property.value())
+    }
+
+    /// Store the value of an own named property through the inline cache. Returns whether the
+    /// store succeeded. Fails on a cache miss or if the property is no longer a writable data
+    /// property.
+    #[inline]
+    pub fn store(&self, receiver: HeapPtr<ObjectValue>, value: Value) -> bool {
+        let (holder, slot) = match self.lookup(receiver) {
+            Some(location) => location,
+            None => return false,
+        };
+
+        // Only own properties are cached for stores
+        if !holder.ptr_eq(&receiver) {
+            return false;
+        }
+
+        let mut named_properties = holder.named_properties();
+        match named_properties.get_at_entry_index_mut(slot) {
+            Some(property) if property.is_data() && property.is_writable() => {
+                property.set_value(value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Add an entry for the location of a named property on the given receiver if the property can
+    /// be cached. Only data properties found on the receiver itself or on its direct prototype are
+    /// cached, and only own properties are cached for stores.
+    ///
+    /// Does not allocate.
+    pub fn update(&mut self, receiver: HeapPtr<ObjectValue>, key: PropertyKey, is_store: bool) {
+        if self.is_megamorphic || key.is_array_index() {
+            return;
+        }
+
+        let receiver_shape = match cacheable_shape(receiver) {
+            Some(shape) => shape,
+            None => return,
+        };
+
+        // Check for an own data property
+        let named_properties = receiver.named_properties();
+        if let Some(slot) = named_properties.get_entry_index(&key) {
+            let property = named_properties.get_at_entry_index(slot).unwrap();
+            if property.is_data() && !property.is_private() {
+                self.add_entry(InlineCacheEntry {
+                    receiver_shape,
+                    holder: None,
+                    holder_shape: None,
+                    slot: slot as u32,
+                });
+            }
+
+            return;
+        }
+
+        // Stores to properties not on the receiver may add a property or call a setter
+        if is_store {
+            return;
+        }
+
+        // Otherwise check for a data property on the direct prototype
+        let holder = match receiver.prototype() {
+            Some(prototype) => prototype,
+            None => return,
+        };
+
+        let holder_shape = match cacheable_shape(holder) {
+            Some(shape) => shape,
+            None => return,
+        };
+
+        let named_properties = holder.named_properties();
+        if let Some(slot) = named_properties.get_entry_index(&key) {
+            let property = named_properties.get_at_entry_index(slot).unwrap();
+            if property.is_data() && !property.is_private() {
+                self.add_entry(InlineCacheEntry {
+                    receiver_shape,
+                    holder: Some(holder),
+                    holder_shape: Some(holder_shape),
+                    slot: slot as u32,
+                });
+            }
+        }
+    }
+}
+
+/// Return the shape of an object if its named properties can be cached.
+#[inline]
+fn cacheable_shape(object: HeapPtr<ObjectValue>) -> Option<HeapPtr<Shape>> {
+    if object.descriptor().is_ordinary_object() {
+        object.shape()
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn is_same_holder(a: Option<HeapPtr<ObjectValue>>, b: Option<HeapPtr<ObjectValue>>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => a.ptr_eq(&b),
+        _ => false,
+    }
+}
+
+/// Find the index of the inline cache for the instruction ending at the given bytecode offset.
+#[inline]
+pub fn find_inline_cache_index(
+    caches: HeapPtr<InlineCacheArray>,
+    bytecode_offset: usize,
+) -> Option<usize> {
+    find_cache_index(caches.as_slice(), bytecode_offset, |cache| {
+        cache.bytecode_offset
+    })
+}
+
+/// Return the end offset of every instruction in the bytecode with a matching opcode, in order.
+/// Per-instruction caches are keyed by end offset since that is the value of the PC while the
+/// instruction is executing.
+pub fn instruction_end_offsets(
+    bytecode: &[u8],
+    mut predicate: impl FnMut(OpCode) -> bool,
+) -> Vec<usize> {
+    let mut offsets = Vec::new();
+
+    // The end offset of an instruction is the start offset of the next instruction, or the end of
+    // the bytecode for the last instruction.
+    let mut previous_matches = false;
+    for (instr, offset) in InstructionIterator::new(bytecode) {
+        if previous_matches {
+            offsets.push(offset);
+        }
+
+        previous_matches = predicate(instr.opcode());
+    }
+
+    if previous_matches {
+        offsets.push(bytecode.len());
+    }
+
+    offsets
+}
+
+/// Binary search a sorted slice of per-instruction caches for the cache with the given bytecode
+/// offset.
+#[inline]
+pub fn find_cache_index<T>(
+    caches: &[T],
+    bytecode_offset: usize,
+    get_offset: impl FnMut(&T) -> u32,
+) -> Option<usize> {
+    caches
+        .binary_search_by_key(&(bytecode_offset as u32), get_offset)
+        .ok()
+}
+
+pub fn inline_cache_array_byte_size(array: HeapPtr<InlineCacheArray>) -> usize {
+    InlineCacheArray::calculate_size_in_bytes(array.len())
+}
+
+pub fn inline_cache_array_visit_pointers(
+    array: &mut HeapPtr<InlineCacheArray>,
+    visitor: &mut impl GcVisitorExt,
+) {
+    array.visit_pointers(visitor);
+
+    for cache in array.as_mut_slice() {
+        for entry in cache.entries.iter_mut().flatten() {
+            visitor.visit_pointer(&mut entry.receiver_shape);
+            visitor.visit_pointer_opt(&mut entry.holder);
+            visitor.visit_pointer_opt(&mut entry.holder_shape);
+        }
+    }
+}
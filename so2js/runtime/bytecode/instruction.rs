@@ -1912,6 +1912,15 @@ define_instructions!(
             [2] options: Register,
         }
     }
+
+    /// Increment a coverage counter. Only emitted when code coverage is enabled.
+    CoverageCounter {
+        camel_case: CoverageCounterInstruction,
+        snake_case: coverage_counter_instruction,
+        operands: {
+            [0] counter: UInt,
+        }
+    }
 );
 
 bitflags! {
@@ -2028,9 +2037,14 @@ pub struct InstructionIterator<'a> {
 }
 
 impl<'a> InstructionIterator<'a> {
-    fn new(bytecode: &'a [u8]) -> Self {
+    pub fn new(bytecode: &'a [u8]) -> Self {
         Self { pos: 0, bytecode }
     }
+
+    /// Iterate over the instructions starting from the instruction at the given offset.
+    pub fn new_at_offset(bytecode: &'a [u8], pos: usize) -> Self {
+        Self { pos, bytecode }
+    }
 }
 
 impl<'a> Iterator for InstructionIterator<'a> {
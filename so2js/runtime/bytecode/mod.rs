@@ -3,6 +3,8 @@ mod constant_table_builder;
 pub mod exception_handlers;
 pub mod function;
 pub mod generator;
+pub mod global_cache;
+pub mod inline_cache;
 pub mod instruction;
 mod instruction_traits;
 mod operand;
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::ops::Range;
 
 use crate::{
@@ -40,7 +41,6 @@ impl BytecodeSourceMap {
     /// Look up the source position for the given bytecode offset.
     ///
     /// Return if the bytecode offset could not be mapped to any values (i.e. the map is empty)
-    // TODO: debugger
     pub fn get_source_position(
         source_map: HeapPtr<ByteArray>,
         bytecode_offset: usize,
@@ -63,6 +63,32 @@ impl BytecodeSourceMap {
         Some(current_source_position)
     }
 
+    /// Look up the source position of the instruction that ends at the given bytecode offset, only
+    /// if that instruction has its own entry in the source map.
+    ///
+    /// Instructions with their own entry are the locations where the debugger can pause.
+    pub fn get_exact_source_position(
+        source_map: HeapPtr<ByteArray>,
+        bytecode_offset: usize,
+    ) -> Option<usize> {
+        Self::iter(&source_map)
+            .take_while(|entry| entry.bytecode_offset <= bytecode_offset)
+            .find(|entry| entry.bytecode_offset == bytecode_offset)
+            .map(|entry| entry.source_position)
+    }
+
+    /// Find all entries whose source position falls within the given range, returned as pairs of
+    /// the bytecode offset directly following the instruction and the source position.
+    pub fn get_entries_in_range(
+        source_map: HeapPtr<ByteArray>,
+        range: Range<Pos>,
+    ) -> Vec<(usize, Pos)> {
+        Self::iter(&source_map)
+            .filter(|entry| range.contains(&entry.source_position))
+            .map(|entry| (entry.bytecode_offset, entry.source_position))
+            .collect()
+    }
+
     fn iter(source_map: &HeapPtr<ByteArray>) -> SourceMapIter<'_> {
         SourceMapIter::new(source_map.as_slice())
     }
@@ -202,11 +202,28 @@ impl StackFrame {
         unsafe { &mut *(self.fp.add(CLOSURE_SLOT_INDEX) as *mut HeapPtr<Closure>) }
     }
 
+    /// Highest bit of the argc slot indicates whether the function was called as a constructor.
+    const IS_CONSTRUCT_CALL_TAG: usize = 1 << (usize::BITS - 1);
+
     /// The number of arguments in this stack frame, not including the receiver or undefined args
     /// added due to underapplication.
     #[inline]
     pub fn argc(&self) -> usize {
-        unsafe { *self.fp.add(ARGC_SLOT_INDEX) }
+        let encoded_value = unsafe { *self.fp.add(ARGC_SLOT_INDEX) };
+        encoded_value & !Self::IS_CONSTRUCT_CALL_TAG
+    }
+
+    /// Whether the function in this stack frame was called as a constructor, e.g. with `new`.
+    #[inline]
+    pub fn is_construct_call(&self) -> bool {
+        let encoded_value = unsafe { *self.fp.add(ARGC_SLOT_INDEX) };
+        (encoded_value & Self::IS_CONSTRUCT_CALL_TAG) != 0
+    }
+
+    /// Mark the function in this stack frame as having been called as a constructor.
+    #[inline]
+    pub fn set_is_construct_call(&mut self) {
+        unsafe { *(self.fp.add(ARGC_SLOT_INDEX).cast_mut()) |= Self::IS_CONSTRUCT_CALL_TAG }
     }
 
     /// The receiver for the function call in this stack frame.
@@ -22,6 +22,7 @@ use crate::{
         async_generator_object::{async_generator_complete_step, AsyncGeneratorObject},
         boxed_value::BoxedValue,
         class_names::{new_class, ClassNames},
+        coverage, debugger,
         error::{
             err_assign_constant, err_cannot_set_property, err_not_defined, reference_error,
             stack_overflow_error, type_error, type_error_value,
@@ -54,6 +55,7 @@ use crate::{
         module::{execute::dynamic_import, source_text_module::SourceTextModule},
         object_value::{ObjectValue, VirtualObject},
         ordinary_object::{object_create_from_constructor, ordinary_object_create},
+        profiler,
         promise_object::{coerce_to_ordinary_promise, is_promise, resolve, PromiseObject},
         property::Property,
         proxy_object::ProxyObject,
@@ -73,8 +75,11 @@ use crate::{
 
 use super::{
     constant_table::ConstantTable,
+    exception_handlers::ExceptionStackRootr,
     function::{BytecodeFunction, Closure},
     generator::BytecodeScript,
+    global_cache::{find_global_cache_index, GlobalCacheArray},
+    inline_cache::{find_inline_cache_index, InlineCacheArray},
     instruction::{
         extra_wide_prefix_index_to_opcode_index, wide_prefix_index_to_opcode_index, AddInstruction,
         AsyncIteratorCloseFinishInstruction, AsyncIteratorCloseStartInstruction, AwaitInstruction,
@@ -83,14 +88,14 @@ use super::{
         CallWithReceiverInstruction, CheckIteratorResultObjectInstruction,
         CheckSuperAlreadyCalledInstruction, CheckTdzInstruction, CheckThisInitializedInstruction,
         ConstructInstruction, ConstructVarargsInstruction, CopyDataPropertiesInstruction,
-        DecInstruction, DefaultSuperCallInstruction, DefineNamedPropertyInstruction,
-        DefinePrivatePropertyFlags, DefinePrivatePropertyInstruction, DefinePropertyFlags,
-        DefinePropertyInstruction, DeleteBindingInstruction, DeletePropertyInstruction,
-        DivInstruction, DupScopeInstruction, DynamicImportInstruction, ErrorConstInstruction,
-        ErrorDeleteSuperPropertyInstruction, ErrorIteratorNoThrowMethodInstruction, EvalFlags,
-        ExpInstruction, ForInNextInstruction, GeneratorStartInstruction,
-        GetAsyncIteratorInstruction, GetIteratorInstruction, GetMethodInstruction,
-        GetNamedPropertyInstruction, GetNamedSuperPropertyInstruction,
+        CoverageCounterInstruction, DecInstruction, DefaultSuperCallInstruction,
+        DefineNamedPropertyInstruction, DefinePrivatePropertyFlags,
+        DefinePrivatePropertyInstruction, DefinePropertyFlags, DefinePropertyInstruction,
+        DeleteBindingInstruction, DeletePropertyInstruction, DivInstruction, DupScopeInstruction,
+        DynamicImportInstruction, ErrorConstInstruction, ErrorDeleteSuperPropertyInstruction,
+        ErrorIteratorNoThrowMethodInstruction, EvalFlags, ExpInstruction, ForInNextInstruction,
+        GeneratorStartInstruction, GetAsyncIteratorInstruction, GetIteratorInstruction,
+        GetMethodInstruction, GetNamedPropertyInstruction, GetNamedSuperPropertyInstruction,
         GetPrivatePropertyInstruction, GetPropertyInstruction, GetSuperConstructorInstruction,
         GetSuperPropertyInstruction, GreaterThanInstruction, GreaterThanOrEqualInstruction,
         ImportMetaInstruction, InInstruction, IncInstruction, InstanceOfInstruction, Instruction,
@@ -153,6 +158,10 @@ pub struct VM {
     /// if there is one.
     stack_trace_top: Option<StackFrame>,
 
+    /// The suspended async function or async generator that is currently being resumed from an
+    /// `await` by the task queue, if any. Used as the starting point for async stack traces.
+    async_resume_generator: Option<HeapPtr<ObjectValue>>,
+
     stack: Vec<StackSlotValue>,
 
     /// The number of stack frames currently on the stack.
@@ -233,6 +242,24 @@ impl VM {
         };
     }
 
+    pub fn async_resume_generator(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.async_resume_generator
+    }
+
+    /// Set the async function or async generator being resumed from an `await`, returning the
+    /// previous value so that it can be restored once the resumption completes.
+    pub fn set_async_resume_generator(
+        &mut self,
+        generator: Option<HeapPtr<ObjectValue>>,
+    ) -> Option<HeapPtr<ObjectValue>> {
+        core::mem::replace(&mut self.async_resume_generator, generator)
+    }
+
+    /// The number of stack frames currently on the stack.
+    pub fn num_stack_frames(&self) -> usize {
+        self.num_stack_frames
+    }
+
     pub fn debug_assert_stack_empty(&self) {
         debug_assert!(self.fp().is_null());
         debug_assert!(self.num_stack_frames == 0);
@@ -254,6 +281,7 @@ impl VM {
             sp: core::ptr::null_mut(),
             fp: core::ptr::null_mut(),
             stack_trace_top: None,
+            async_resume_generator: None,
             num_stack_frames: 0,
 
             stack,
@@ -447,12 +475,19 @@ impl VM {
     /// - By the time that a throw is possible, PC must be updated to point to the next instruction
     /// - References to the instruction cannot be held over any allocations, since the instruction
     ///   points into the managed heap and may be moved by a GC.
+    ///
+    /// The dispatch loop is only instrumented with debugger and profiler hooks when a debugger is
+    /// attached or a profiler is running, so there is no overhead when neither is in use.
     fn dispatch_loop(&mut self) -> EvalResult<()> {
-        js_stack_scope!(self.cx(), self.dispatch_loop_inner())
+        if self.cx().has_debugger() || self.cx().is_profiling() {
+            js_stack_scope!(self.cx(), self.dispatch_loop_inner::<true>())
+        } else {
+            js_stack_scope!(self.cx(), self.dispatch_loop_inner::<false>())
+        }
     }
 
     #[inline]
-    fn dispatch_loop_inner(&mut self) -> EvalResult<()> {
+    fn dispatch_loop_inner<const INSTRUMENTED: bool>(&mut self) -> EvalResult<()> {
         'dispatch: loop {
             macro_rules! create_dispatch_macros {
                 ($width:ident, $opcode_pc:expr) => {
@@ -613,12 +648,15 @@ impl VM {
                         maybe_throw!(coerce_to_ordinary_promise(self.cx(), argument_promise));
 
                     if return_promise_or_generator.as_object().is_promise() {
+                        let return_promise = return_promise_or_generator.cast::<PromiseObject>();
+
                         // Create a new generator object that holds the stack frame's state
                         let generator = maybe_throw_a!(GeneratorObject::new_for_async_function(
                             self.cx(),
                             pc_to_resume_offset,
                             fp_index,
                             (completion_value_index, completion_type_index),
+                            return_promise,
                             self.stack_frame().as_slice(),
                         ))
                         .to_stack(self.cx());
@@ -709,6 +747,11 @@ impl VM {
                 ($error_value:expr) => {{
                     let error_value = $error_value;
 
+                    if INSTRUMENTED && self.cx().has_debugger() {
+                        let is_caught = self.is_exception_caught();
+                        maybe_throw_a!(debugger::on_exception(self.cx(), error_value, is_caught));
+                    }
+
                     // Walk the stack, looking for an exception handler that covers the current
                     // address.
                     let mut stack_frame = self.stack_frame();
@@ -1268,10 +1311,20 @@ impl VM {
                         OpCode::DynamicImport => {
                             dispatch_or_throw!(DynamicImportInstruction, execute_dynamic_import)
                         }
+                        OpCode::CoverageCounter => {
+                            dispatch!(CoverageCounterInstruction, execute_coverage_counter)
+                        }
                     }
                 };
             }
 
+            // Give the debugger a chance to pause and the profiler a chance to sample before
+            // executing the next instruction
+            if INSTRUMENTED {
+                maybe_throw_a!(debugger::before_instruction(self.cx()));
+                profiler::before_instruction(self.cx());
+            }
+
             // PC starts pointing to the next opcode to execute
             let prefix_or_opcode_pc = self.pc();
             let prefix_or_opcode = unsafe { *prefix_or_opcode_pc.cast::<OpCode>() };
@@ -1330,6 +1383,36 @@ impl VM {
         unsafe { self.pc().offset_from(func_start_ptr) as usize }
     }
 
+    /// Find the inline cache for the currently executing instruction. Returns the inline cache
+    /// array of the current function along with the index of the instruction's inline cache.
+    #[inline]
+    fn current_inline_cache(&self) -> Option<(HeapPtr<InlineCacheArray>, usize)> {
+        let function = self.closure().function_ptr();
+        let inline_caches = function.inline_caches_ptr()?;
+
+        // PC is already set to the end of the current instruction
+        let bytecode_offset =
+            unsafe { self.pc().offset_from(function.bytecode().as_ptr()) as usize };
+        let index = find_inline_cache_index(inline_caches, bytecode_offset)?;
+
+        Some((inline_caches, index))
+    }
+
+    /// Return the global cache for the current instruction, if one exists. Must only be called
+    /// while executing a global variable access instruction.
+    #[inline]
+    fn current_global_cache(&self) -> Option<(HeapPtr<GlobalCacheArray>, usize)> {
+        let function = self.closure().function_ptr();
+        let global_caches = function.global_caches_ptr()?;
+
+        // PC is already set to the end of the current instruction
+        let bytecode_offset =
+            unsafe { self.pc().offset_from(function.bytecode().as_ptr()) as usize };
+        let index = find_global_cache_index(global_caches, bytecode_offset)?;
+
+        Some((global_caches, index))
+    }
+
     /// Push a value onto the stack. Note that the stack grows downwards.
     #[inline]
     fn push(&mut self, value: StackSlotValue) {
@@ -1574,6 +1657,11 @@ impl VM {
         receiver: StackRoot<Value>,
         arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
+        // Time between executions is spent outside of JS
+        if !self.is_executing() && self.cx().is_profiling() {
+            profiler::on_execution_start(self.cx());
+        }
+
         // Check whether the value is callable, potentially deferring to proxy.
         let closure_ptr = match self.check_value_is_callable(*function)? {
             CallableObject::Closure(closure) => closure,
@@ -2142,6 +2230,8 @@ impl VM {
         function: HeapPtr<BytecodeFunction>,
         new_target: HeapPtr<ObjectValue>,
     ) {
+        self.stack_frame().set_is_construct_call();
+
         if let Some(index) = function.new_target_index() {
             // Set the new.target register to the provided new target
             self.write_register(
@@ -2383,6 +2473,12 @@ impl VM {
         let rust_function = self.cx().rust_runtime_functions.get_function(function_id);
         let result = rust_function(self.cx, receiver, arguments);
 
+        // Sample while the builtin's stack frame is still on the stack, so that time spent in the
+        // builtin is attributed to it.
+        if self.cx().is_profiling() {
+            profiler::after_rust_runtime_call(self.cx());
+        }
+
         // Clean up the stack frame
         self.pop_stack_frame();
 
@@ -2581,6 +2677,15 @@ impl VM {
         name_constant_index: ConstantIndex<W>,
         error_on_unresolved: bool,
     ) -> EvalResult<()> {
+        // Fast path for globals whose location is in the global cache
+        if let Some((global_caches, index)) = self.current_global_cache() {
+            let realm = self.closure().function_ptr().realm_ptr();
+            if let Some(value) = global_caches.as_slice()[index].load(realm) {
+                self.write_register(dest, value);
+                return Ok(());
+            }
+        }
+
         let cx = self.cx();
         js_stack_scope!(cx, {
             let name = self.get_constant(name_constant_index);
@@ -2592,6 +2697,14 @@ impl VM {
 
             let global_object = self.closure().global_object(self.cx());
 
+            // Cache the location of the global before the full lookup, which may run arbitrary
+            // code if the global is an accessor property. Caches must be looked up again since
+            // they may have moved during allocation.
+            if let Some((mut global_caches, index)) = self.current_global_cache() {
+                let realm = self.closure().function_ptr().realm_ptr();
+                global_caches.as_mut_slice()[index].update(realm, *name.as_flat(), *name_key);
+            }
+
             // Must first check if it is a lexical name in one of the realm's global scopes
             let value = if let Some(value) = self
                 .closure()
@@ -2622,6 +2735,15 @@ impl VM {
         &mut self,
         instr: &StoreGlobalInstruction<W>,
     ) -> EvalResult<()> {
+        // Fast path for mutable globals whose location is in the global cache
+        if let Some((global_caches, index)) = self.current_global_cache() {
+            let realm = self.closure().function_ptr().realm_ptr();
+            let value = self.read_register(instr.value());
+            if global_caches.as_slice()[index].store(realm, value) {
+                return Ok(());
+            }
+        }
+
         let cx = self.cx();
         js_stack_scope!(cx, {
             let value = self.read_register_to_handle(instr.value());
@@ -2635,6 +2757,13 @@ impl VM {
 
             let mut global_object = self.closure().global_object(self.cx());
 
+            // Only existing globals are cached, so cache before the global is set. Caches must be
+            // looked up again since they may have moved during allocation.
+            if let Some((mut global_caches, index)) = self.current_global_cache() {
+                let realm = self.closure().function_ptr().realm_ptr();
+                global_caches.as_mut_slice()[index].update(realm, *name.as_flat(), *name_key);
+            }
+
             // First set the global lexical binding with the given name if it exists
             let success = if self.closure().realm(self.cx()).set_lexical_name(
                 self.cx(),
@@ -3722,6 +3851,17 @@ impl VM {
         &mut self,
         instr: &GetNamedPropertyInstruction<W>,
     ) -> EvalResult<()> {
+        // Fast path for objects whose property location is in the inline cache
+        if let Some((inline_caches, index)) = self.current_inline_cache() {
+            let object = self.read_register(instr.object());
+            if object.is_object() {
+                if let Some(value) = inline_caches.as_slice()[index].load(object.as_object()) {
+                    self.write_register(instr.dest(), value);
+                    return Ok(());
+                }
+            }
+        }
+
         js_stack_scope!(self.cx(), {
             let object = self.read_register_to_handle(instr.object());
 
@@ -3737,6 +3877,19 @@ impl VM {
 
             let coerced_object = to_object(self.cx(), object)?;
 
+            // Cache the location of the property before the full lookup, which may run arbitrary
+            // code if the property is an accessor. Caches must be looked up again since they may
+            // have moved during allocation.
+            if let Some((mut inline_caches, index)) = self.current_inline_cache() {
+                if object.is_object() {
+                    inline_caches.as_mut_slice()[index].update(
+                        *coerced_object,
+                        *property_key,
+                        /* is_store */ false,
+                    );
+                }
+            }
+
             // Result of ToObject is used as receiver in sloppy mode
             let receiver = if is_strict {
                 object
@@ -3757,6 +3910,17 @@ impl VM {
         &mut self,
         instr: &SetNamedPropertyInstruction<W>,
     ) -> EvalResult<()> {
+        // Fast path for writable own properties whose location is in the inline cache
+        if let Some((inline_caches, index)) = self.current_inline_cache() {
+            let object = self.read_register(instr.object());
+            if object.is_object() {
+                let value = self.read_register(instr.value());
+                if inline_caches.as_slice()[index].store(object.as_object(), value) {
+                    return Ok(());
+                }
+            }
+        }
+
         js_stack_scope!(self.cx(), {
             let object = self.read_register_to_handle(instr.object());
 
@@ -3773,6 +3937,18 @@ impl VM {
             let property_key = PropertyKey::string(self.cx(), key)?;
             let property_key = key.replace_into(property_key);
 
+            // Only existing own data properties are cached, so cache before the property is set.
+            // Caches must be looked up again since they may have moved during allocation.
+            if let Some((mut inline_caches, index)) = self.current_inline_cache() {
+                if object.is_object() {
+                    inline_caches.as_mut_slice()[index].update(
+                        *coerced_object,
+                        *property_key,
+                        /* is_store */ true,
+                    );
+                }
+            }
+
             if is_strict {
                 let success = coerced_object.set(self.cx(), property_key, value, object)?;
                 if !success {
@@ -4768,25 +4944,26 @@ impl VM {
         })
     }
 
-    /// Visit a stack frame while unwinding the stack for an exception.
     #[inline]
-    fn visit_frame_for_exception_unwinding(
-        &mut self,
+    fn execute_coverage_counter<W: Width>(&mut self, instr: &CoverageCounterInstruction<W>) {
+        let counter = instr.counter().value().to_usize();
+        coverage::increment_counter(self.cx(), counter);
+    }
+
+    /// Find the innermost exception handler in a stack frame that covers an instruction.
+    fn find_exception_handler(
         stack_frame: StackFrame,
         instr_addr: *const u8,
-        error_value: StackRoot<Value>,
-    ) -> bool {
+    ) -> Option<ExceptionStackRootr> {
         let func = stack_frame.closure().function_ptr();
-        if func.exception_handlers_ptr().is_none() {
-            return false;
-        }
+        let handlers = func.exception_handlers_ptr()?;
 
         // Find the offset of the instruction in the instruction stream
         let instr_offset = unsafe { instr_addr.offset_from(func.bytecode().as_ptr()) as usize };
 
         // Find the innermost matching exception handler
         let mut innermost_matching_handler = None;
-        for handler in func.exception_handlers_ptr().unwrap().iter() {
+        for handler in handlers.iter() {
             // The saved return address points to the start of the next instruction, so treat the
             // handler bounds as (exclusive, inclusive].
             if handler.start() < instr_offset && instr_offset <= handler.end() {
@@ -4802,7 +4979,20 @@ impl VM {
             }
         }
 
-        if let Some((handler, _)) = innermost_matching_handler {
+        innermost_matching_handler.map(|(handler, _)| handler)
+    }
+
+    /// Visit a stack frame while unwinding the stack for an exception.
+    #[inline]
+    fn visit_frame_for_exception_unwinding(
+        &mut self,
+        stack_frame: StackFrame,
+        instr_addr: *const u8,
+        error_value: StackRoot<Value>,
+    ) -> bool {
+        if let Some(handler) = Self::find_exception_handler(stack_frame, instr_addr) {
+            let func = stack_frame.closure().function_ptr();
+
             // Find the absolute address of the start of the handler block and start executing
             // instructions from this address.
             let handler_addr = unsafe { func.bytecode().as_ptr().add(handler.handler()) };
@@ -4823,9 +5013,35 @@ impl VM {
         false
     }
 
+    /// Whether an exception thrown at the current PC will be caught by an exception handler in
+    /// some stack frame, without unwinding the stack.
+    pub fn is_exception_caught(&self) -> bool {
+        let mut stack_frame = self.stack_frame();
+        if Self::find_exception_handler(stack_frame, self.pc()).is_some() {
+            return true;
+        }
+
+        while let Some(caller_stack_frame) = stack_frame.previous_frame() {
+            // Rust callers receive the error. Keep looking in the JS frames that called into Rust
+            // since they may catch the error once it is returned.
+            if !stack_frame.is_rust_caller()
+                && Self::find_exception_handler(caller_stack_frame, stack_frame.return_address())
+                    .is_some()
+            {
+                return true;
+            }
+
+            stack_frame = caller_stack_frame;
+        }
+
+        false
+    }
+
     /// Visit all heap roots in the VM during GC root collection. Rewrites the stack in place,
     /// taking care to rewrite the current PC and return addresses.
     pub fn visit_roots(&mut self, visitor: &mut impl GcVisitorExt) {
+        visitor.visit_pointer_opt(&mut self.async_resume_generator);
+
         if !self.is_executing() {
             return;
         }
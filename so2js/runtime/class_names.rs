@@ -15,7 +15,7 @@ use super::{
     collections::InlineArray,
     gc::{GcVisitorExt, HeapItem},
     heap_item_descriptor::HeapItemDescriptor,
-    string_value::FlatString,
+    string_value::{FlatString, StringValue},
     Context, EvalResult, HeapPtr, StackRoot, Value,
 };
 
@@ -163,6 +163,32 @@ impl Method {
     }
 }
 
+/// The name and prototype of the class with the given constructor, or None if the closure is not a
+/// class constructor.
+///
+/// The `prototype` property of a class constructor is defined by `new_class` as non-writable and
+/// non-configurable, so it always holds the prototype created when the class was evaluated.
+pub fn class_name_and_prototype(
+    cx: Context,
+    constructor: HeapPtr<Closure>,
+) -> Option<(HeapPtr<StringValue>, HeapPtr<ObjectValue>)> {
+    let function = constructor.function_ptr();
+    if !function.is_class_constructor() {
+        return None;
+    }
+
+    let name = function.name_ptr()?;
+    let prototype = constructor
+        .as_object()
+        .named_properties()
+        .get(&cx.names.prototype)?;
+    if !prototype.is_data() || !prototype.value().is_object() {
+        return None;
+    }
+
+    Some((name, prototype.value().as_object()))
+}
+
 /// ClassDefinitionEvaluation (https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation)
 pub fn new_class(
     mut cx: Context,
@@ -108,6 +108,12 @@ impl<K: Eq + Hash + Clone, V: Clone> BsIndexMap<K, V> {
         self.num_occupied
     }
 
+    /// Total number of entries that have been deleted but still occupy space in the entries array.
+    #[inline]
+    pub fn num_entries_deleted(&self) -> usize {
+        self.num_deleted
+    }
+
     /// Total number of entries that the IndexMap can hold.
     #[inline]
     pub fn capacity(&self) -> usize {
@@ -137,6 +143,40 @@ impl<K: Eq + Hash + Clone, V: Clone> BsIndexMap<K, V> {
             .map(|entry_index| &self.get_entry_unchecked(entry_index).as_occupied().value)
     }
 
+    /// Returns the index of the entry for the given key in the entries array, or None if the key is
+    /// not present. Entry indices are stable until the map is grown.
+    pub fn get_entry_index(&self, key: &K) -> Option<usize> {
+        self.find_index(key)
+    }
+
+    /// Returns the value stored at the given entry index, or None if the entry is deleted or has
+    /// not been used.
+    #[inline]
+    pub fn get_at_entry_index(&self, entry_index: usize) -> Option<&V> {
+        if entry_index >= self.num_entries_used() {
+            return None;
+        }
+
+        match self.get_entry_unchecked(entry_index) {
+            Entry::Occupied(entry) => Some(&entry.value),
+            Entry::Deleted { .. } => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value stored at the given entry index, or None if the
+    /// entry is deleted or has not been used.
+    #[inline]
+    pub fn get_at_entry_index_mut(&mut self, entry_index: usize) -> Option<&mut V> {
+        if entry_index >= self.num_entries_used() {
+            return None;
+        }
+
+        match self.get_entry_unchecked_mut(entry_index) {
+            Entry::Occupied(entry) => Some(&mut entry.value),
+            Entry::Deleted { .. } => None,
+        }
+    }
+
     /// Remove an entry from this map if the key is present. Return whether an entry was removed.
     ///
     /// The deleted entry remains and becomes unusable.
@@ -88,6 +88,20 @@ fn new_heap_source_info(
             return Ok(None);
         };
 
+    // Report the original source location if the source file has a source map
+    let line_offsets = source_file.line_offsets_ptr(cx)?;
+    let pos = line_offsets.as_slice()[line - 1] as usize + col - 1;
+    if let Some(original) = source_file.original_position(cx, pos)? {
+        let source_map = source_file.load_source_map(cx).unwrap();
+        let (name, line, col) = (original.source, original.line, original.column);
+        let source_info = match source_map.source_line(original.source_index, line) {
+            Some(snippet) => SourceInfo::new(name, line, col, snippet.to_string()),
+            None => SourceInfo::new_without_snippet(name, line, col),
+        };
+
+        return Ok(Some(source_info));
+    }
+
     let name = source_file.display_name(cx).to_string();
     let snippet = source_file.get_line(cx, line - 1)?;
 
@@ -8,13 +8,18 @@ use core::{
     ops::{Deref, DerefMut},
     ptr::NonNull,
 };
+use icu_locale::Locale;
 use rand::{rngs::StdRng, SeedableRng};
 
 use crate::runtime::intrinsics::rust_runtime::RustRuntimeFunction;
 use crate::runtime::intrinsics::rust_runtime::RustRuntimeFunctionId;
 use crate::{
     common::{
+        icu::DEFAULT_LOCALE,
         options::Options,
+        source_map::SourceMap,
+        time::get_current_unix_time,
+        time_zone::TimeZone,
         wtf_8::{Wtf8Str, Wtf8String},
     },
     eval_err, js_stack_scope,
@@ -30,10 +35,14 @@ use super::{
         vm::VM,
     },
     collections::{BsHashMap, BsHashMapField},
+    coverage::CoverageState,
+    debugger::DebuggerState,
     error::BsResult,
     gc::{AnyHeapItem, GcVisitorExt, HeapPtr, StackRootContext},
     heap_item_descriptor::{BaseDescriptors, HeapItemKind},
+    heap_statistics::HeapStatistics,
     interned_strings::InternedStrings,
+    intl::{locale::parse_language_tag, IntlCache},
     intrinsics::{intrinsics::Intrinsic, rust_runtime::RustRuntimeFunctionRegistry},
     module::{
         execute::execute_module,
@@ -42,14 +51,18 @@ use super::{
         source_text_module::SourceTextModule,
     },
     object_value::{NamedPropertiesMap, ObjectValue},
+    profiler::ProfilerState,
     realm::Realm,
+    shape::Shape,
+    shared_memory::AgentSharedMemory,
     string_value::FlatString,
     tasks::TaskQueue,
+    test_262_object::Test262Agent,
     value::SymbolValue,
     EvalResult, StackRoot, Value,
 };
 
-use so2js_gc::GcContext as SoGcContext;
+use so2js_gc::{GcContext as SoGcContext, GcPacing};
 
 /// Top level context for the JS runtime. Contains the heap, execution contexts, etc.
 /// Must never be moved, as there may be internal pointers held.
@@ -83,6 +96,40 @@ pub struct ContextCell {
     /// The virtual machine used to execute bytecode.
     pub vm: Option<Box<VM>>,
 
+    /// State of the attached debugger, if any.
+    pub debugger: Option<Box<DebuggerState>>,
+
+    /// State of the running CPU profiler, if any.
+    pub profiler: Option<Box<ProfilerState>>,
+
+    /// State of code coverage collection, if coverage has ever been started.
+    pub coverage: Option<Box<CoverageState>>,
+
+    /// Source maps that have been loaded for source files, indexed by the source file's source map
+    /// id. Source maps that failed to load are cached as `None`.
+    pub source_maps: Vec<Option<Rc<SourceMap>>>,
+
+    /// ICU objects used by Intl services, cached by their resolved locale and options.
+    pub intl_cache: IntlCache,
+
+    /// The default locale of the host environment, used by Intl services and locale-sensitive
+    /// methods when none of the requested locales are available.
+    pub default_locale: Locale,
+
+    /// The default time zone of the host environment, used for local time by Date and Intl
+    /// services.
+    pub default_time_zone: TimeZone,
+
+    /// Shared data blocks referenced by this agent and its pending Atomics.waitAsync waiters.
+    pub shared_memory: AgentSharedMemory,
+
+    /// Whether this agent can be suspended by Atomics.wait. The [[CanBlock]] field of the Agent
+    /// Record (https://tc39.es/ecma262/#sec-agents).
+    pub can_block: bool,
+
+    /// Host hooks used to implement the `$262.agent` API in test262 tests, if provided.
+    pub test_262_agent: Option<Rc<dyn Test262Agent>>,
+
     /// The initial realm for this context. Either provided by the host environment or set up during
     /// context initialization.
     initial_realm: HeapPtr<Realm>,
@@ -113,6 +160,9 @@ pub struct ContextCell {
     /// An empty, dense array properties object to use as the initial value for array properties
     pub default_array_properties: HeapPtr<ArrayProperties>,
 
+    /// The shape of an object with no named properties. Root of the shape transition tree.
+    pub root_shape: HeapPtr<Shape>,
+
     /// Options passed to this program.
     pub options: Rc<Options>,
 
@@ -153,13 +203,23 @@ impl Context {
     fn new(options: Rc<Options>, sys: Option<Box<dyn crate::sys::Sys>>) -> AllocResult<Context> {
         let mut cx_cell = Box::new(ContextCell {
             sys,
-            heap: so2js_gc::Heap::new(),
+            heap: so2js_gc::Heap::new_generational(so2js_gc::DEFAULT_NURSERY_SIZE),
             handle_context: StackRootContext::new(),
             global_symbol_registry: HeapPtr::uninit(),
             names: BuiltinNames::uninit(),
             well_known_symbols: BuiltinSymbols::uninit(),
             base_descriptors: BaseDescriptors::uninit_empty(),
             vm: None,
+            debugger: None,
+            profiler: None,
+            coverage: None,
+            source_maps: Vec::new(),
+            intl_cache: IntlCache::default(),
+            default_locale: DEFAULT_LOCALE,
+            default_time_zone: TimeZone::Utc,
+            shared_memory: AgentSharedMemory::new(),
+            can_block: true,
+            test_262_agent: None,
             initial_realm: HeapPtr::uninit(),
             task_queue: TaskQueue::new(),
             undefined: Value::undefined(),
@@ -175,6 +235,7 @@ impl Context {
             modules: HeapPtr::uninit(),
             default_named_properties: HeapPtr::uninit(),
             default_array_properties: HeapPtr::uninit(),
+            root_shape: HeapPtr::uninit(),
             options: options.clone(),
             has_finished_module_resolution: false,
             async_evaluation_counter: NonZeroUsize::MIN,
@@ -187,6 +248,12 @@ impl Context {
             runtime_functions_sorted_by_pointer: Vec::new(),
         });
 
+        // Mark on every core and sweep on a background thread
+        #[cfg(feature = "parallel_gc")]
+        cx_cell
+            .heap
+            .set_parallel_options(so2js_gc::ParallelGcOptions::all_cores());
+
         let mut cx = unsafe { Context::from_ptr(NonNull::new_unchecked(Box::leak(cx_cell))) };
         // pass it here
         cx.rust_runtime_functions.cx_ptr = cx.as_ptr();
@@ -197,6 +264,12 @@ impl Context {
         // Stop using deterministic PRNG
         cx.rand = StdRng::from_rng(&mut rand::rng());
 
+        // Local time is UTC unless the host provides a supported time zone
+        let host_time_zone = cx.sys.as_ref().and_then(|sys| sys.default_time_zone());
+        if let Some(time_zone) = host_time_zone {
+            cx.set_default_time_zone(&time_zone);
+        }
+
         // Annex B methods may not be included in the serialized heap so they must be initialized
         // separately.
         if options.annex_b {
@@ -222,6 +295,7 @@ impl Context {
             cx.default_array_properties = DenseArrayProperties::new(cx, 0)?.cast();
             cx.default_named_properties =
                 NamedPropertiesMap::new(cx, HeapItemKind::ObjectNamedPropertiesMap, 0)?;
+            cx.root_shape = Shape::new_root(cx)?;
             cx.initial_realm = *Realm::new(cx)?;
 
             Ok(())
@@ -621,6 +695,7 @@ impl Context {
         visitor.visit_pointer(&mut self.global_symbol_registry);
         self.interned_strings.visit_roots(visitor);
         visitor.visit_pointer(&mut self.modules);
+        visitor.visit_pointer(&mut self.root_shape);
     }
 
     /// Visit all heap roots that are guaranteed to point to the permanent semispace.
@@ -639,10 +714,23 @@ impl Context {
     fn visit_post_initialization_roots(&mut self, visitor: &mut impl GcVisitorExt) {
         self.handle_context.visit_roots(visitor);
         self.task_queue.visit_roots(visitor);
+        self.shared_memory.visit_roots(visitor);
 
         if let Some(vm) = &mut self.vm {
             vm.visit_roots(visitor);
         }
+
+        if let Some(debugger) = &mut self.debugger {
+            debugger.visit_roots(visitor);
+        }
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.visit_roots(visitor);
+        }
+
+        if let Some(coverage) = &mut self.coverage {
+            coverage.visit_roots(visitor);
+        }
     }
 
     #[cfg(feature = "gc_stress_test")]
@@ -663,6 +751,20 @@ impl Context {
         }
     }
 
+    /// Run a minor garbage collection, promoting all live young objects to the old generation
+    pub fn run_minor_gc(&mut self) {
+        let heap = &mut self.heap;
+        let cx: *mut Context = self;
+
+        // SAFETY: same aliasing rationale as run_gc.
+        unsafe { heap.minor_gc(&mut *cx) }
+    }
+
+    /// Collect statistics about the objects in the heap and recent garbage collections.
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        HeapStatistics::collect(*self)
+    }
+
     /// Run one incremental GC step. Returns true if GC still in progress.
     pub fn gc_step(&mut self) -> bool {
         let heap = &mut self.heap;
@@ -671,6 +773,54 @@ impl Context {
         // SAFETY: same aliasing rationale as run_gc.
         unsafe { heap.gc_step(&mut *cx) }
     }
+
+    /// Perform GC work until the given deadline, e.g. while the host is idle between frames. Starts
+    /// a new GC cycle first if one is due.
+    ///
+    /// The deadline is a unix time in milliseconds. Returns true if GC is still in progress.
+    pub fn idle_gc(&mut self, deadline_millis: f64) -> bool {
+        let heap = &mut self.heap;
+        let cx: *mut Context = self;
+
+        // SAFETY: same aliasing rationale as run_gc.
+        unsafe {
+            if heap.should_gc() {
+                heap.start_gc(&mut *cx);
+            }
+
+            heap.gc_step_until(&mut *cx, deadline_millis)
+        }
+    }
+
+    /// Set the policy for scheduling incremental GC work, e.g. to bound the length of GC pauses.
+    pub fn set_gc_pacing(&mut self, pacing: GcPacing) {
+        self.heap.set_pacing(pacing);
+    }
+
+    /// Set the default locale of the host environment from a language tag, e.g. "fr-CA". Returns
+    /// false and leaves the default locale unchanged if the tag is not a valid language tag.
+    pub fn set_default_locale(&mut self, tag: &str) -> bool {
+        match parse_language_tag(tag) {
+            Some(locale) => {
+                self.default_locale = locale;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Set the default time zone of the host environment from a time zone identifier, e.g.
+    /// "Europe/Paris" or "+01:00". Returns false and leaves the default time zone unchanged if the
+    /// time zone is not supported.
+    pub fn set_default_time_zone(&mut self, identifier: &str) -> bool {
+        match TimeZone::from_identifier(*self, identifier) {
+            Some(time_zone) => {
+                self.default_time_zone = time_zone;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl SoGcContext for Context {
@@ -678,7 +828,9 @@ impl SoGcContext for Context {
         self.visit_roots_for_gc(visitor);
     }
 
-    fn trace_object(&mut self, ptr: *mut u8, visitor: &mut impl so2js_gc::GcVisitor) {
+    /// With parallel marking the pointer is to a private copy of the heap item, so mutable access
+    /// through `visit_pointers` never touches the heap item that other threads may be tracing.
+    fn trace_object(&self, ptr: *mut u8, visitor: &mut impl so2js_gc::GcVisitor) {
         let mut heap_item = HeapPtr::<AnyHeapItem>::from_ptr(ptr as *mut AnyHeapItem);
         let kind = heap_item.descriptor().kind();
         heap_item.visit_pointers_for_kind(visitor, kind);
@@ -687,6 +839,27 @@ impl SoGcContext for Context {
     fn process_weak_refs(&mut self, _heap: &so2js_gc::Heap) {
         // TODO: Implement weak reference processing
     }
+
+    fn current_time_millis(&mut self) -> f64 {
+        get_current_unix_time(self)
+    }
+
+    #[cfg(feature = "heap_verify")]
+    fn verify_object(
+        &self,
+        object_ptr: *mut u8,
+        is_allocated: &dyn Fn(*const u8) -> bool,
+    ) -> Result<(), &'static str> {
+        super::heap_item_descriptor::HeapItemDescriptor::verify_item_descriptor(
+            object_ptr,
+            is_allocated,
+        )
+    }
+
+    #[cfg(feature = "heap_verify")]
+    fn report_heap_verify_error(&mut self, error: &so2js_gc::HeapVerifyError) {
+        panic!("{}", self.describe_heap_verify_error(error));
+    }
 }
 
 impl Deref for Context {
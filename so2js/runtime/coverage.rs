@@ -0,0 +1,567 @@
+//! Block-level code coverage.
+//!
+//! Coverage is started with `Context::start_coverage`. While coverage is enabled the bytecode
+//! generator registers every function it generates along with the source ranges of the blocks
+//! within the function, and emits a `CoverageCounter` instruction at the start of the function and
+//! at the start of each block. Blocks are the arms of branching statements and expressions, loop
+//! bodies, catch and finally clauses, and the code following a statement that may not complete
+//! normally (e.g. the rest of a function after an `if` statement containing a `return`).
+//!
+//! Only code generated while coverage is enabled is instrumented. Counters are only incremented
+//! while coverage is enabled, so instrumented code runs at close to full speed after coverage is
+//! stopped.
+//!
+//! Counts are collected with `Context::take_coverage`, and can be written in the JSON format of
+//! V8's `Profiler.takePreciseCoverage` (which is also the format of `NODE_V8_COVERAGE` files) or
+//! as an LCOV tracefile.
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Write},
+    ops::Range,
+};
+
+use crate::{
+    js_stack_scope_guard,
+    parser::loc::{calculate_line_offsets, find_line_col_for_pos, Pos},
+};
+
+use super::{
+    gc::GcVisitorExt, heap_snapshot::write_json_string, source_file::SourceFile, Context, HeapPtr,
+};
+
+/// A block of code with its own execution counter.
+struct Block {
+    range: Range<Pos>,
+    counter: usize,
+    /// Whether this block is one arm of a branch, as opposed to the entire function or the code
+    /// following a statement.
+    is_branch: bool,
+}
+
+struct FunctionInfo {
+    /// Index of the function's script
+    script: usize,
+    /// Name of the function, empty for anonymous functions and the toplevel function of a program
+    name: String,
+    is_toplevel: bool,
+    /// The blocks in the function. The first block covers the entire function.
+    blocks: Vec<Block>,
+}
+
+/// All coverage state for a context. Once created the state is kept for the lifetime of the
+/// context, since instrumented code refers to counters by index.
+pub struct CoverageState {
+    is_enabled: bool,
+    /// Execution count of each counter
+    counts: Vec<u32>,
+    /// Every source file containing a registered function
+    scripts: Vec<HeapPtr<SourceFile>>,
+    functions: Vec<FunctionInfo>,
+}
+
+impl CoverageState {
+    fn new() -> Self {
+        Self {
+            is_enabled: true,
+            counts: vec![],
+            scripts: vec![],
+            functions: vec![],
+        }
+    }
+
+    /// Allocate a new counter, returning None if the counter index does not fit in an instruction
+    /// operand.
+    fn new_counter(&mut self) -> Option<usize> {
+        let counter = self.counts.len();
+        if counter > u32::MAX as usize {
+            return None;
+        }
+
+        self.counts.push(0);
+
+        Some(counter)
+    }
+
+    pub fn visit_roots(&mut self, visitor: &mut impl GcVisitorExt) {
+        for script in &mut self.scripts {
+            visitor.visit_pointer(script);
+        }
+    }
+}
+
+impl Context {
+    /// Start collecting coverage for code that is generated from now on. Resets the counts of any
+    /// code that was already instrumented.
+    pub fn start_coverage(&mut self) {
+        match &mut self.coverage {
+            Some(state) => {
+                state.is_enabled = true;
+                state.counts.fill(0);
+            }
+            None => self.coverage = Some(Box::new(CoverageState::new())),
+        }
+    }
+
+    /// Stop collecting coverage. New code is no longer instrumented and counts are no longer
+    /// incremented.
+    pub fn stop_coverage(&mut self) {
+        if let Some(state) = &mut self.coverage {
+            state.is_enabled = false;
+        }
+    }
+
+    #[inline]
+    pub fn is_collecting_coverage(&self) -> bool {
+        self.coverage.as_ref().is_some_and(|state| state.is_enabled)
+    }
+
+    /// Return the coverage collected since coverage was started or last taken, then reset all
+    /// counts. Returns None if coverage is not being collected.
+    pub fn take_coverage(&mut self) -> Option<Coverage> {
+        if !self.is_collecting_coverage() {
+            return None;
+        }
+
+        let coverage = Coverage::new(*self, self.coverage.as_ref().unwrap());
+        self.coverage.as_mut().unwrap().counts.fill(0);
+
+        Some(coverage)
+    }
+}
+
+/// Called by the bytecode generator when it starts generating a function while coverage is
+/// enabled. Returns the index of the function and the counter for the entire function.
+pub fn register_function(
+    mut cx: Context,
+    source_file: HeapPtr<SourceFile>,
+    name: String,
+    range: Range<Pos>,
+) -> Option<(usize, usize)> {
+    let state = cx.coverage.as_mut()?;
+
+    let script = match state
+        .scripts
+        .iter()
+        .position(|script| script.ptr_eq(&source_file))
+    {
+        Some(script) => script,
+        None => {
+            state.scripts.push(source_file);
+            state.scripts.len() - 1
+        }
+    };
+
+    let counter = state.new_counter()?;
+
+    let function = state.functions.len();
+    state.functions.push(FunctionInfo {
+        script,
+        name,
+        is_toplevel: false,
+        blocks: vec![Block {
+            range,
+            counter,
+            is_branch: false,
+        }],
+    });
+
+    Some((function, counter))
+}
+
+/// Mark a registered function as the toplevel function of a program, which is anonymous in
+/// coverage results.
+pub fn set_toplevel_function(mut cx: Context, function: usize) {
+    if let Some(state) = &mut cx.coverage {
+        let function = &mut state.functions[function];
+        function.name.clear();
+        function.is_toplevel = true;
+    }
+}
+
+/// Called by the bytecode generator to add a block to a registered function. Returns the counter
+/// for the block.
+pub fn add_block(
+    mut cx: Context,
+    function: usize,
+    range: Range<Pos>,
+    is_branch: bool,
+) -> Option<usize> {
+    let state = cx.coverage.as_mut()?;
+    let counter = state.new_counter()?;
+
+    state.functions[function].blocks.push(Block {
+        range,
+        counter,
+        is_branch,
+    });
+
+    Some(counter)
+}
+
+/// Called by the VM when executing a `CoverageCounter` instruction.
+#[inline]
+pub fn increment_counter(mut cx: Context, counter: usize) {
+    if let Some(state) = &mut cx.coverage {
+        if state.is_enabled {
+            let count = &mut state.counts[counter];
+            *count = count.saturating_add(1);
+        }
+    }
+}
+
+/// A source range with an execution count. Offsets are byte offsets into the script's source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageRange {
+    pub start: usize,
+    pub end: usize,
+    pub count: u32,
+}
+
+pub struct FunctionCoverage {
+    /// Name of the function, empty for anonymous functions and the toplevel function of a program
+    pub function_name: String,
+    /// Whether this is the toplevel function of a script, module, or eval
+    pub is_toplevel: bool,
+    /// Ranges in the format of V8's block coverage. The first range covers the entire function,
+    /// and later ranges are sorted by start offset and only appear if their count differs from the
+    /// innermost range that contains them.
+    pub ranges: Vec<CoverageRange>,
+    /// Every branch in the function, e.g. each arm of an if statement, sorted by start offset.
+    pub branches: Vec<CoverageRange>,
+}
+
+pub struct ScriptCoverage {
+    /// Path of the source file
+    pub url: String,
+    pub functions: Vec<FunctionCoverage>,
+    source: Vec<u8>,
+    /// UTF-16 offset of each byte offset in the source, or empty if the source is ASCII
+    utf16_offsets: Vec<u32>,
+}
+
+impl ScriptCoverage {
+    /// Convert a byte offset in the source to an offset in UTF-16 code units, as used by V8.
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        if self.utf16_offsets.is_empty() {
+            offset
+        } else {
+            self.utf16_offsets[offset] as usize
+        }
+    }
+}
+
+/// Coverage for every script that was generated while coverage was enabled.
+pub struct Coverage {
+    pub scripts: Vec<ScriptCoverage>,
+}
+
+impl Coverage {
+    fn new(cx: Context, state: &CoverageState) -> Coverage {
+        js_stack_scope_guard!(cx);
+
+        let mut scripts = state
+            .scripts
+            .iter()
+            .map(|source_file| {
+                let source = source_file.contents_as_slice().to_vec();
+                let utf16_offsets = calculate_utf16_offsets(&source);
+
+                ScriptCoverage {
+                    url: source_file.path(cx).to_string(),
+                    functions: vec![],
+                    source,
+                    utf16_offsets,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for function in &state.functions {
+            let ranges = function
+                .blocks
+                .iter()
+                .map(|block| CoverageRange {
+                    start: block.range.start,
+                    end: block.range.end,
+                    count: state.counts[block.counter],
+                })
+                .collect::<Vec<_>>();
+
+            let mut branches = function
+                .blocks
+                .iter()
+                .zip(&ranges)
+                .filter(|(block, range)| block.is_branch && range.start != range.end)
+                .map(|(_, range)| range.clone())
+                .collect::<Vec<_>>();
+            branches.sort_by_key(|range| range.start);
+
+            scripts[function.script].functions.push(FunctionCoverage {
+                function_name: function.name.clone(),
+                is_toplevel: function.is_toplevel,
+                ranges: normalize_ranges(ranges),
+                branches,
+            });
+        }
+
+        Coverage { scripts }
+    }
+
+    /// Write coverage in the JSON format of V8's `Profiler.takePreciseCoverage` result. This is
+    /// also the format that Node writes to `NODE_V8_COVERAGE`, and can be read by tools such as
+    /// `c8` and `v8-to-istanbul`.
+    pub fn write_precise_coverage(&self, writer: &mut impl Write) -> fmt::Result {
+        writer.write_str("{\"result\":[")?;
+        for (i, script) in self.scripts.iter().enumerate() {
+            if i != 0 {
+                writer.write_str(",\n")?;
+            }
+
+            // Absolute paths are written as file URLs
+            write!(writer, "{{\"scriptId\":\"{}\",\"url\":", i + 1)?;
+            if script.url.starts_with('/') {
+                write_json_string(writer, &format!("file://{}", script.url))?;
+            } else {
+                write_json_string(writer, &script.url)?;
+            }
+
+            writer.write_str(",\"functions\":[")?;
+            for (j, function) in script.functions.iter().enumerate() {
+                if j != 0 {
+                    writer.write_char(',')?;
+                }
+
+                writer.write_str("{\"functionName\":")?;
+                write_json_string(writer, &function.function_name)?;
+
+                writer.write_str(",\"ranges\":[")?;
+                for (k, range) in function.ranges.iter().enumerate() {
+                    if k != 0 {
+                        writer.write_char(',')?;
+                    }
+
+                    write!(
+                        writer,
+                        "{{\"startOffset\":{},\"endOffset\":{},\"count\":{}}}",
+                        script.utf16_offset(range.start),
+                        script.utf16_offset(range.end),
+                        range.count
+                    )?;
+                }
+
+                writer.write_str("],\"isBlockCoverage\":true}")?;
+            }
+
+            writer.write_str("]}")?;
+        }
+
+        writer.write_str("]}\n")
+    }
+
+    /// Write coverage as an LCOV tracefile, with function, branch, and line coverage for each
+    /// script. The count of a line is the count of the innermost range containing the first
+    /// non-whitespace character on the line.
+    pub fn write_lcov(&self, writer: &mut impl Write) -> fmt::Result {
+        for script in &self.scripts {
+            writeln!(writer, "TN:")?;
+            writeln!(writer, "SF:{}", script.url)?;
+
+            let line_offsets = calculate_line_offsets(&script.source);
+            let line_of = |pos: usize| find_line_col_for_pos(pos, &line_offsets).0;
+
+            // Function coverage, leaving out the toplevel function of the program
+            let functions = script
+                .functions
+                .iter()
+                .filter(|function| !function.is_toplevel)
+                .enumerate();
+
+            let mut num_functions_hit = 0;
+            for (i, function) in functions.clone() {
+                let range = &function.ranges[0];
+                writeln!(
+                    writer,
+                    "FN:{},{}",
+                    line_of(range.start),
+                    lcov_function_name(function, i)
+                )?;
+            }
+            for (i, function) in functions.clone() {
+                let count = function.ranges[0].count;
+                writeln!(writer, "FNDA:{},{}", count, lcov_function_name(function, i))?;
+
+                if count != 0 {
+                    num_functions_hit += 1;
+                }
+            }
+            writeln!(writer, "FNF:{}", functions.count())?;
+            writeln!(writer, "FNH:{}", num_functions_hit)?;
+
+            // Branch coverage, treating each branch as its own block
+            let mut num_branches = 0;
+            let mut num_branches_hit = 0;
+            for function in &script.functions {
+                for branch in &function.branches {
+                    writeln!(
+                        writer,
+                        "BRDA:{},{},0,{}",
+                        line_of(branch.start),
+                        num_branches,
+                        branch.count
+                    )?;
+
+                    num_branches += 1;
+                    if branch.count != 0 {
+                        num_branches_hit += 1;
+                    }
+                }
+            }
+            writeln!(writer, "BRF:{}", num_branches)?;
+            writeln!(writer, "BRH:{}", num_branches_hit)?;
+
+            // Line coverage
+            let mut ranges = script
+                .functions
+                .iter()
+                .flat_map(|function| function.ranges.iter())
+                .collect::<Vec<_>>();
+            ranges.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+            let mut num_lines = 0;
+            let mut num_lines_hit = 0;
+            let mut enclosing_ranges: Vec<&CoverageRange> = vec![];
+            let mut next_range = 0;
+
+            for (i, line_start) in line_offsets.iter().enumerate() {
+                let line_end = line_offsets
+                    .get(i + 1)
+                    .map_or(script.source.len(), |offset| *offset as usize);
+                let line = &script.source[*line_start as usize..line_end];
+
+                // Blank lines are not counted
+                let pos = match line.iter().position(|byte| !byte.is_ascii_whitespace()) {
+                    Some(column) => *line_start as usize + column,
+                    None => continue,
+                };
+
+                // Find the innermost range containing the position. Ranges are properly nested so
+                // the enclosing ranges form a stack.
+                while next_range < ranges.len() && ranges[next_range].start <= pos {
+                    let range = ranges[next_range];
+                    while enclosing_ranges
+                        .last()
+                        .is_some_and(|enclosing| enclosing.end <= range.start)
+                    {
+                        enclosing_ranges.pop();
+                    }
+
+                    enclosing_ranges.push(range);
+                    next_range += 1;
+                }
+
+                while enclosing_ranges
+                    .last()
+                    .is_some_and(|enclosing| enclosing.end <= pos)
+                {
+                    enclosing_ranges.pop();
+                }
+
+                let count = match enclosing_ranges.last() {
+                    Some(range) => range.count,
+                    None => continue,
+                };
+
+                writeln!(writer, "DA:{},{}", i + 1, count)?;
+
+                num_lines += 1;
+                if count != 0 {
+                    num_lines_hit += 1;
+                }
+            }
+
+            writeln!(writer, "LF:{}", num_lines)?;
+            writeln!(writer, "LH:{}", num_lines_hit)?;
+            writeln!(writer, "end_of_record")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// LCOV identifies functions by name, so anonymous functions are given a unique name.
+fn lcov_function_name(function: &FunctionCoverage, index: usize) -> String {
+    if function.function_name.is_empty() {
+        format!("(anonymous_{})", index)
+    } else {
+        function.function_name.clone()
+    }
+}
+
+/// Convert the raw ranges of a function to V8's format. The function range stays first, empty
+/// ranges are removed, and ranges are only kept if their count differs from the innermost kept
+/// range that contains them. Nothing within a range that was never executed is kept.
+fn normalize_ranges(ranges: Vec<CoverageRange>) -> Vec<CoverageRange> {
+    let mut ranges = ranges.into_iter();
+    let function_range = ranges.next().unwrap();
+
+    let mut blocks = ranges
+        .filter(|range| range.start != range.end)
+        .collect::<Vec<_>>();
+    blocks.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut result = vec![function_range];
+    let mut enclosing_ranges = vec![0];
+
+    for block in blocks {
+        // The function range is never popped since it contains every block
+        while enclosing_ranges.len() > 1 {
+            let enclosing = &result[*enclosing_ranges.last().unwrap()];
+            if enclosing.start <= block.start && block.end <= enclosing.end {
+                break;
+            }
+
+            enclosing_ranges.pop();
+        }
+
+        let enclosing = &result[*enclosing_ranges.last().unwrap()];
+        if enclosing.count == 0 || enclosing.count == block.count {
+            continue;
+        }
+
+        enclosing_ranges.push(result.len());
+        result.push(block);
+    }
+
+    result
+}
+
+/// Return the UTF-16 offset of every byte offset in a WTF-8 source, including the end of the
+/// source. Returns an empty vector if the source is ASCII, since offsets are then identical.
+fn calculate_utf16_offsets(source: &[u8]) -> Vec<u32> {
+    if source.is_ascii() {
+        return vec![];
+    }
+
+    let mut offsets = Vec::with_capacity(source.len() + 1);
+    let mut utf16_offset = 0;
+
+    for byte in source {
+        offsets.push(utf16_offset);
+
+        // Only the leading byte of each code point is counted. Four byte sequences encode code
+        // points outside the BMP, which take two UTF-16 code units.
+        if byte & 0xC0 != 0x80 {
+            utf16_offset += if *byte >= 0xF0 { 2 } else { 1 };
+        }
+    }
+
+    offsets.push(utf16_offset);
+
+    offsets
+}
@@ -0,0 +1,786 @@
+//! Debugger support for stepping through bytecode execution.
+//!
+//! A `Debugger` is attached to a context with `Context::attach_debugger`. Execution pauses when a
+//! breakpoint is hit, when a step completes, when an exception is thrown (if enabled), or when a
+//! pause is requested. While paused the debugger is handed a `PausedState` which can be used to
+//! inspect the call stack and evaluate expressions in any stack frame.
+//!
+//! The VM only instruments its dispatch loop with debugger hooks when a debugger is attached at the
+//! time the dispatch loop is entered, so there is no overhead when no debugger is attached. Calls
+//! between bytecode functions stay within the same dispatch loop, so a debugger attached while JS
+//! is running does not see that code or anything it calls until the VM is next entered from Rust.
+//!
+//! Execution can only pause before instructions that have their own entry in the function's
+//! `BytecodeSourceMap`, which are the instructions that may throw. Breakpoints are resolved to the
+//! first such instruction at or after the requested line and column.
+
+use alloc::{boxed::Box, string::String, string::ToString, sync::Arc, vec, vec::Vec};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use hashbrown::HashMap;
+
+use crate::{
+    common::source_map::OriginalPosition,
+    js_stack_scope_guard,
+    parser::loc::{find_line_col_for_pos, Pos},
+    runtime::alloc_error::AllocResult,
+};
+
+use super::{
+    bytecode::{
+        function::{BytecodeFunction, Closure},
+        instruction::InstructionIterator,
+        source_map::BytecodeSourceMap,
+        stack_frame::StackFrame,
+    },
+    eval::eval::perform_debugger_eval,
+    gc::GcVisitorExt,
+    intrinsics::rust_runtime::return_undefined,
+    object_value::ObjectValue,
+    scope::ScopeKind,
+    source_file::SourceFile,
+    string_value::{FlatString, StringValue},
+    Context, EvalResult, HeapPtr, StackRoot, Value,
+};
+
+/// Unique identifier for a breakpoint within a context.
+pub type BreakpointId = u32;
+
+/// A debugger that is notified whenever execution pauses.
+pub trait Debugger {
+    /// Called when execution pauses. The paused state can be inspected until this returns, at
+    /// which point execution resumes according to the returned action.
+    fn on_pause(&mut self, cx: Context, paused: &PausedState) -> ResumeAction;
+
+    /// Called when a source file is created for newly compiled code, before any of the code runs.
+    fn on_source_file_loaded(&mut self, _cx: Context, _source_file: StackRoot<SourceFile>) {}
+}
+
+/// How execution should resume after a pause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResumeAction {
+    /// Continue until the next breakpoint, exception, or pause request.
+    Continue,
+    /// Pause at the next location on a different line, including in called functions.
+    StepInto,
+    /// Pause at the next location on a different line in the current function or its callers.
+    StepOver,
+    /// Pause at the next location in a caller of the current function.
+    StepOut,
+}
+
+/// Which thrown exceptions should pause execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseOnExceptions {
+    None,
+    /// Only pause for exceptions that will not be caught by any exception handler on the stack.
+    Uncaught,
+    All,
+}
+
+/// Why execution paused.
+pub enum PauseReason {
+    Breakpoint(BreakpointId),
+    Step,
+    Exception {
+        value: StackRoot<Value>,
+        is_caught: bool,
+    },
+    PauseRequested,
+}
+
+/// A handle that requests a pause before the next instruction is executed. May be sent to other
+/// threads, e.g. to pause on a request from a remote client while JS is running.
+#[derive(Clone)]
+pub struct PauseRequestHandle(Arc<AtomicBool>);
+
+impl PauseRequestHandle {
+    pub fn request_pause(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// A breakpoint set by source file and line, with an optional column.
+struct Breakpoint {
+    id: BreakpointId,
+    path: String,
+    /// 1-indexed line number
+    line: usize,
+    /// 1-indexed column number
+    column: Option<usize>,
+}
+
+/// The locations of all breakpoints within a single function.
+struct ResolvedBreakpoints {
+    /// Pairs of the bytecode offset of the start of an instruction and the breakpoint at that
+    /// instruction.
+    locations: Vec<(usize, BreakpointId)>,
+}
+
+/// An in progress step, started from the location where execution last paused.
+#[derive(Clone, Copy)]
+struct StepRequest {
+    action: ResumeAction,
+    /// Frame pointer of the stack frame that was paused
+    fp: *mut u8,
+    /// Number of stack frames on the stack when paused
+    depth: usize,
+    /// Line where execution was paused, if known
+    line: Option<usize>,
+}
+
+/// All debugger state for a context. Only exists while a debugger is attached.
+pub struct DebuggerState {
+    /// The attached debugger. Taken while the debugger is handling a pause.
+    debugger: Option<Box<dyn Debugger>>,
+    breakpoints: Vec<Breakpoint>,
+    next_breakpoint_id: BreakpointId,
+    /// Cache of breakpoint locations for functions that have been checked for breakpoints, keyed
+    /// by the address of the function. Cleared whenever breakpoints change and whenever the GC
+    /// visits roots, since functions may be moved or freed by the GC.
+    resolved_breakpoints: HashMap<usize, ResolvedBreakpoints>,
+    pause_on_exceptions: PauseOnExceptions,
+    step: Option<StepRequest>,
+    pause_requested: PauseRequestHandle,
+    /// Whether the debugger is currently handling a pause. Code evaluated while paused never
+    /// pauses again.
+    is_paused: bool,
+    /// The last exception that execution paused on, so that an exception is only reported once
+    /// as it propagates through Rust callers.
+    last_exception: Option<Value>,
+    /// Values kept alive on behalf of the debugger, e.g. objects referenced by a remote client.
+    /// Released values leave an empty slot.
+    retained_values: Vec<Option<Value>>,
+}
+
+impl DebuggerState {
+    fn new(debugger: Box<dyn Debugger>) -> Self {
+        Self {
+            debugger: Some(debugger),
+            breakpoints: vec![],
+            next_breakpoint_id: 0,
+            resolved_breakpoints: HashMap::new(),
+            pause_on_exceptions: PauseOnExceptions::None,
+            step: None,
+            pause_requested: PauseRequestHandle(Arc::new(AtomicBool::new(false))),
+            is_paused: false,
+            last_exception: None,
+            retained_values: vec![],
+        }
+    }
+
+    pub fn visit_roots(&mut self, visitor: &mut impl GcVisitorExt) {
+        // Resolved breakpoints do not keep functions alive. The cache is keyed by address, so it is
+        // dropped whenever functions may be moved or freed.
+        self.resolved_breakpoints.clear();
+
+        if let Some(last_exception) = &mut self.last_exception {
+            visitor.visit_value(last_exception);
+        }
+
+        for value in self.retained_values.iter_mut().flatten() {
+            visitor.visit_value(value);
+        }
+    }
+}
+
+impl Context {
+    /// Attach a debugger, replacing any debugger that is already attached while keeping existing
+    /// breakpoints.
+    ///
+    /// Only takes effect for code that starts executing after the debugger is attached. If attached
+    /// while JS is running, e.g. from a builtin or host callback, the JS that is already on the
+    /// stack and every function it calls run without breakpoints, steps, or pause requests until
+    /// the VM is next entered from Rust. Attach the debugger before evaluating code to debug it.
+    pub fn attach_debugger(&mut self, debugger: Box<dyn Debugger>) {
+        match &mut self.debugger {
+            Some(state) => state.debugger = Some(debugger),
+            None => self.debugger = Some(Box::new(DebuggerState::new(debugger))),
+        }
+    }
+
+    /// Detach the current debugger, clearing all breakpoints.
+    pub fn detach_debugger(&mut self) -> Option<Box<dyn Debugger>> {
+        self.debugger.take().and_then(|state| state.debugger)
+    }
+
+    #[inline]
+    pub fn has_debugger(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    fn debugger_state(&mut self) -> &mut DebuggerState {
+        self.debugger.as_mut().expect("no debugger attached")
+    }
+
+    /// Set a breakpoint at a 1-indexed line and optional 1-indexed column in the source file with
+    /// the given path. Columns are measured in bytes, matching the columns in stack traces.
+    ///
+    /// Must only be called while a debugger is attached.
+    pub fn set_breakpoint(
+        &mut self,
+        path: &str,
+        line: usize,
+        column: Option<usize>,
+    ) -> BreakpointId {
+        let state = self.debugger_state();
+
+        let id = state.next_breakpoint_id;
+        state.next_breakpoint_id += 1;
+
+        state.breakpoints.push(Breakpoint {
+            id,
+            path: path.to_string(),
+            line,
+            column,
+        });
+        state.resolved_breakpoints.clear();
+
+        id
+    }
+
+    /// Remove a breakpoint, returning whether the breakpoint existed.
+    pub fn remove_breakpoint(&mut self, id: BreakpointId) -> bool {
+        let state = self.debugger_state();
+
+        let num_breakpoints = state.breakpoints.len();
+        state.breakpoints.retain(|breakpoint| breakpoint.id != id);
+        state.resolved_breakpoints.clear();
+
+        state.breakpoints.len() != num_breakpoints
+    }
+
+    pub fn set_pause_on_exceptions(&mut self, pause_on_exceptions: PauseOnExceptions) {
+        self.debugger_state().pause_on_exceptions = pause_on_exceptions;
+    }
+
+    /// Pause before the next instruction that is executed.
+    pub fn request_pause(&mut self) {
+        self.debugger_state().pause_requested.request_pause();
+    }
+
+    /// A handle that can request a pause from any thread.
+    pub fn pause_request_handle(&mut self) -> PauseRequestHandle {
+        self.debugger_state().pause_requested.clone()
+    }
+
+    /// Keep a value alive until it is released, returning an id for the value.
+    pub fn retain_debugger_value(&mut self, value: Value) -> usize {
+        let retained_values = &mut self.debugger_state().retained_values;
+        retained_values.push(Some(value));
+        retained_values.len() - 1
+    }
+
+    /// Return a value retained with `retain_debugger_value`, if it has not been released.
+    pub fn get_debugger_value(&mut self, id: usize) -> Option<StackRoot<Value>> {
+        let value = (*self.debugger_state().retained_values.get(id)?)?;
+        Some(value.to_stack(*self))
+    }
+
+    pub fn release_debugger_value(&mut self, id: usize) {
+        if let Some(slot) = self.debugger_state().retained_values.get_mut(id) {
+            *slot = None;
+        }
+    }
+}
+
+/// Called by the VM before every instruction while a debugger is attached.
+pub fn before_instruction(mut cx: Context) -> AllocResult<()> {
+    let (has_breakpoints, step, pause_requested) = match &cx.debugger {
+        Some(state) if !state.is_paused => (
+            !state.breakpoints.is_empty(),
+            state.step,
+            state.pause_requested.0.swap(false, Ordering::Relaxed),
+        ),
+        _ => return Ok(()),
+    };
+
+    if pause_requested {
+        return pause(cx, PauseReason::PauseRequested);
+    }
+
+    if !has_breakpoints && step.is_none() {
+        return Ok(());
+    }
+
+    let stack_frame = cx.vm().stack_frame();
+    let function = stack_frame.closure().function_ptr();
+    if function.source_map_ptr().is_none() {
+        return Ok(());
+    }
+
+    let offset = cx.vm().pc() as usize - function.bytecode().as_ptr() as usize;
+
+    if has_breakpoints {
+        if let Some(id) = find_breakpoint_at(cx, function, offset)? {
+            return pause(cx, PauseReason::Breakpoint(id));
+        }
+    }
+
+    if let Some(step) = step {
+        // Can only pause at locations in the source map
+        let function = cx.vm().stack_frame().closure().function_ptr();
+        let end_offset = instruction_end_offset(function.bytecode(), offset);
+        let source_map = function.source_map_ptr().unwrap();
+        let position = match BytecodeSourceMap::get_exact_source_position(source_map, end_offset) {
+            Some(position) => position,
+            None => return Ok(()),
+        };
+
+        let depth = cx.vm().num_stack_frames();
+        let is_same_frame = depth == step.depth && stack_frame.fp().cast::<u8>() == step.fp;
+
+        let should_pause = match step.action {
+            ResumeAction::Continue => false,
+            ResumeAction::StepInto | ResumeAction::StepOver
+                if is_same_frame && step.line.is_some() =>
+            {
+                let source_file = function.source_file_ptr().unwrap().to_stack(cx);
+                Some(find_line(cx, source_file, position)?) != step.line
+            }
+            ResumeAction::StepInto => true,
+            ResumeAction::StepOver => depth <= step.depth,
+            ResumeAction::StepOut => depth < step.depth,
+        };
+
+        if should_pause {
+            return pause(cx, PauseReason::Step);
+        }
+    }
+
+    Ok(())
+}
+
+/// Called by the VM when an exception is thrown while a debugger is attached, before the stack is
+/// unwound.
+pub fn on_exception(mut cx: Context, value: StackRoot<Value>, is_caught: bool) -> AllocResult<()> {
+    let state = match &mut cx.debugger {
+        Some(state) if !state.is_paused => state,
+        _ => return Ok(()),
+    };
+
+    let should_pause = match state.pause_on_exceptions {
+        PauseOnExceptions::None => false,
+        PauseOnExceptions::Uncaught => !is_caught,
+        PauseOnExceptions::All => true,
+    };
+
+    // Exceptions are rethrown as they propagate through Rust callers, but should only be reported
+    // the first time they are thrown.
+    let is_rethrow = state
+        .last_exception
+        .is_some_and(|last_exception| last_exception.as_raw_bits() == value.as_raw_bits());
+
+    if !should_pause || is_rethrow {
+        return Ok(());
+    }
+
+    state.last_exception = Some(*value);
+
+    pause(cx, PauseReason::Exception { value, is_caught })
+}
+
+/// Called when a source file is created for newly compiled code while a debugger is attached.
+pub fn on_source_file_loaded(mut cx: Context, source_file: StackRoot<SourceFile>) {
+    // The debugger is not notified of code compiled while it is handling a pause
+    let mut debugger = match cx.debugger.as_mut().and_then(|state| state.debugger.take()) {
+        Some(debugger) => debugger,
+        None => return,
+    };
+
+    debugger.on_source_file_loaded(cx, source_file);
+
+    if let Some(state) = &mut cx.debugger {
+        if state.debugger.is_none() {
+            state.debugger = Some(debugger);
+        }
+    }
+}
+
+/// Pause execution and hand control to the debugger until it resumes.
+fn pause(mut cx: Context, reason: PauseReason) -> AllocResult<()> {
+    js_stack_scope_guard!(cx);
+
+    let is_exception = matches!(reason, PauseReason::Exception { .. });
+    let paused = PausedState::new(cx, reason, is_exception);
+
+    // Find the line of the paused location, which is the starting point for steps
+    let line = match paused.frames.first() {
+        Some(frame) => frame.location(cx)?.map(|location| location.line),
+        None => None,
+    };
+
+    let mut debugger = {
+        let state = cx.debugger.as_mut().unwrap();
+        let debugger = match state.debugger.take() {
+            Some(debugger) => debugger,
+            None => return Ok(()),
+        };
+
+        state.is_paused = true;
+        state.step = None;
+        state.pause_requested.0.store(false, Ordering::Relaxed);
+
+        debugger
+    };
+
+    let action = debugger.on_pause(cx, &paused);
+
+    // The debugger may have been detached while paused
+    let stack_frame = cx.vm().stack_frame();
+    let depth = cx.vm().num_stack_frames();
+    if let Some(state) = &mut cx.debugger {
+        state.is_paused = false;
+
+        if state.debugger.is_none() {
+            state.debugger = Some(debugger);
+        }
+
+        if action != ResumeAction::Continue {
+            state.step = Some(StepRequest {
+                action,
+                fp: stack_frame.fp().cast(),
+                depth,
+                line,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the breakpoint at the instruction that starts at the given offset in a function, if any.
+fn find_breakpoint_at(
+    mut cx: Context,
+    function: HeapPtr<BytecodeFunction>,
+    offset: usize,
+) -> AllocResult<Option<BreakpointId>> {
+    let state = cx.debugger.as_mut().unwrap();
+    if let Some(resolved) = state
+        .resolved_breakpoints
+        .get(&(function.as_ptr() as usize))
+    {
+        return Ok(find_location(resolved, offset));
+    }
+
+    // Breakpoints have not yet been resolved for this function. Resolving may allocate and move
+    // the function, so the function's address is only read afterwards.
+    let function = function.to_stack(cx);
+    let locations = resolve_breakpoints(cx, function)?;
+
+    let resolved = ResolvedBreakpoints { locations };
+    let location = find_location(&resolved, offset);
+
+    let state = cx.debugger.as_mut().unwrap();
+    state
+        .resolved_breakpoints
+        .insert(function.as_ptr() as usize, resolved);
+
+    Ok(location)
+}
+
+fn find_location(resolved: &ResolvedBreakpoints, offset: usize) -> Option<BreakpointId> {
+    resolved
+        .locations
+        .iter()
+        .find(|(location_offset, _)| *location_offset == offset)
+        .map(|(_, id)| *id)
+}
+
+/// Map every breakpoint in the function's source file to the start offsets of instructions in the
+/// function.
+///
+/// A breakpoint resolves to the instructions with the smallest source position that is at or after
+/// the breakpoint's line and column, and on the same line.
+fn resolve_breakpoints(
+    mut cx: Context,
+    function: StackRoot<BytecodeFunction>,
+) -> AllocResult<Vec<(usize, BreakpointId)>> {
+    let mut source_file = match function.source_file_ptr() {
+        Some(source_file) => source_file.to_stack(cx),
+        None => return Ok(vec![]),
+    };
+
+    let path = source_file.path(cx).to_string();
+    let line_offsets = source_file.line_offsets_ptr(cx)?;
+    let line_offsets = line_offsets.as_slice();
+
+    // Start offsets of each instruction, used to find the instruction ending at an offset
+    let bytecode = function.bytecode();
+    let instruction_offsets = InstructionIterator::new(bytecode)
+        .map(|(_, offset)| offset)
+        .collect::<Vec<_>>();
+
+    let source_map = function.source_map_ptr().unwrap();
+    let state = cx.debugger.as_mut().unwrap();
+
+    let mut locations = vec![];
+    for breakpoint in &state.breakpoints {
+        if breakpoint.path != path || breakpoint.line == 0 || breakpoint.line > line_offsets.len() {
+            continue;
+        }
+
+        let line_start = line_offsets[breakpoint.line - 1] as Pos;
+        let line_end = line_offsets
+            .get(breakpoint.line)
+            .map(|offset| *offset as Pos)
+            .unwrap_or(Pos::MAX);
+        let start = line_start
+            + breakpoint
+                .column
+                .map_or(0, |column| column.saturating_sub(1));
+
+        let entries = BytecodeSourceMap::get_entries_in_range(source_map, start..line_end);
+        let min_position = match entries.iter().map(|(_, position)| *position).min() {
+            Some(min_position) => min_position,
+            None => continue,
+        };
+
+        for (end_offset, position) in entries {
+            if position == min_position {
+                // Find the instruction that directly precedes the end offset
+                let index = instruction_offsets.partition_point(|offset| *offset < end_offset);
+                locations.push((instruction_offsets[index - 1], breakpoint.id));
+            }
+        }
+    }
+
+    Ok(locations)
+}
+
+/// Offset directly following the instruction that starts at the given offset.
+fn instruction_end_offset(bytecode: &[u8], offset: usize) -> usize {
+    InstructionIterator::new_at_offset(bytecode, offset)
+        .nth(1)
+        .map_or(bytecode.len(), |(_, next_offset)| next_offset)
+}
+
+/// Find the 1-indexed line of a source position.
+fn find_line(
+    cx: Context,
+    mut source_file: StackRoot<SourceFile>,
+    position: Pos,
+) -> AllocResult<usize> {
+    let line_offsets = source_file.line_offsets_ptr(cx)?;
+    Ok(find_line_col_for_pos(position, line_offsets.as_slice()).0)
+}
+
+/// The state of execution while paused, passed to `Debugger::on_pause`.
+pub struct PausedState {
+    reason: PauseReason,
+    /// Stack frames from innermost to outermost
+    frames: Vec<DebugFrame>,
+}
+
+impl PausedState {
+    /// Capture the current stack. If paused for an exception the PC points after the throwing
+    /// instruction, otherwise it points to the start of the next instruction to execute.
+    fn new(mut cx: Context, reason: PauseReason, pc_after_instruction: bool) -> Self {
+        let mut frames = vec![];
+
+        let mut stack_frame_opt = Some(cx.vm().stack_frame());
+        let mut pc = cx.vm().pc();
+        let mut is_top_frame = true;
+
+        while let Some(stack_frame) = stack_frame_opt {
+            // Skip the last frame if it's a dummy frame for the realm
+            if stack_frame.previous_frame().is_none() {
+                let function = stack_frame.closure().function_ptr();
+                if let Some(id) = function.rust_runtime_function_id() {
+                    if id == cx.rust_runtime_functions.get_id(return_undefined).unwrap() {
+                        break;
+                    }
+                }
+            }
+
+            let function = stack_frame.closure().function_ptr();
+            let bytecode_offset = if function.source_map_ptr().is_some() {
+                let offset = pc as usize - function.bytecode().as_ptr() as usize;
+                if is_top_frame && !pc_after_instruction {
+                    Some(instruction_end_offset(function.bytecode(), offset))
+                } else {
+                    Some(offset)
+                }
+            } else {
+                None
+            };
+
+            frames.push(DebugFrame {
+                stack_frame,
+                bytecode_offset,
+            });
+
+            pc = stack_frame.return_address();
+            stack_frame_opt = stack_frame.previous_frame();
+            is_top_frame = false;
+        }
+
+        Self { reason, frames }
+    }
+
+    pub fn reason(&self) -> &PauseReason {
+        &self.reason
+    }
+
+    /// Stack frames from innermost to outermost.
+    pub fn frames(&self) -> &[DebugFrame] {
+        &self.frames
+    }
+
+    /// Evaluate code in the scope of a stack frame, with the frame's `this` value.
+    ///
+    /// The code can access all variables that are stored in the frame's scope chain. Variables that
+    /// were only ever stored in registers are not visible by name, but can be found with
+    /// `DebugFrame::registers`. Every variable is stored in a scope when the context is created
+    /// with the `debug_scopes` option.
+    pub fn evaluate(
+        &self,
+        mut cx: Context,
+        frame_index: usize,
+        code: &str,
+    ) -> EvalResult<StackRoot<Value>> {
+        let frame = &self.frames[frame_index];
+        let code = cx.alloc_string(code)?.as_value();
+        let scope = frame.stack_frame.scope().to_stack(cx);
+        let receiver = frame.this(cx);
+
+        perform_debugger_eval(cx, code, scope, receiver)
+    }
+}
+
+/// A source location, with 1-indexed line and column.
+pub struct DebugLocation {
+    pub source_file: StackRoot<SourceFile>,
+    pub line: usize,
+    pub column: usize,
+    /// The location in the original source, if the source file has a source map.
+    pub original: Option<OriginalPosition>,
+}
+
+/// A paused stack frame.
+pub struct DebugFrame {
+    stack_frame: StackFrame,
+    /// Offset directly following the instruction executing in this frame. Only set if the
+    /// function has a source map.
+    bytecode_offset: Option<usize>,
+}
+
+impl DebugFrame {
+    pub fn closure(&self, cx: Context) -> StackRoot<Closure> {
+        self.stack_frame.closure().to_stack(cx)
+    }
+
+    pub fn function_name(&self, cx: Context) -> Option<StackRoot<StringValue>> {
+        self.stack_frame.closure().function_ptr().name(cx)
+    }
+
+    /// The source location being executed in this frame, if known.
+    pub fn location(&self, cx: Context) -> AllocResult<Option<DebugLocation>> {
+        let function = self.stack_frame.closure().function_ptr();
+        let (bytecode_offset, source_file, source_map) = match (
+            self.bytecode_offset,
+            function.source_file_ptr(),
+            function.source_map_ptr(),
+        ) {
+            (Some(offset), Some(source_file), Some(source_map)) => {
+                (offset, source_file, source_map)
+            }
+            _ => return Ok(None),
+        };
+
+        let position = match BytecodeSourceMap::get_source_position(source_map, bytecode_offset) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+
+        let mut source_file = source_file.to_stack(cx);
+        let line_offsets = source_file.line_offsets_ptr(cx)?;
+        let (line, column) = find_line_col_for_pos(position, line_offsets.as_slice());
+        let original = source_file.original_position(cx, position)?;
+
+        Ok(Some(DebugLocation {
+            source_file,
+            line,
+            column,
+            original,
+        }))
+    }
+
+    /// The `this` value of this frame.
+    pub fn this(&self, cx: Context) -> StackRoot<Value> {
+        self.stack_frame.receiver().to_stack(cx)
+    }
+
+    /// The arguments passed to this frame.
+    pub fn arguments(&self, cx: Context) -> Vec<StackRoot<Value>> {
+        self.stack_frame
+            .args()
+            .iter()
+            .map(|arg| arg.to_stack(cx))
+            .collect()
+    }
+
+    /// The values of all registers in this frame, in register order.
+    pub fn registers(&self, cx: Context) -> Vec<StackRoot<Value>> {
+        let mut stack_frame = self.stack_frame;
+
+        // Registers are stored in reverse order on the stack
+        stack_frame
+            .registers_mut()
+            .iter()
+            .rev()
+            .map(|value| value.to_stack(cx))
+            .collect()
+    }
+
+    /// The scope chain of this frame, from innermost to outermost.
+    pub fn scopes(&self, cx: Context) -> Vec<DebugScope> {
+        let mut scopes = vec![];
+        let mut scope_opt = Some(self.stack_frame.scope());
+
+        while let Some(scope) = scope_opt {
+            let scope_names = scope.scope_names_ptr();
+
+            let mut variables = vec![];
+            for i in 0..scope_names.len() {
+                if scope_names.is_private_name(i) {
+                    continue;
+                }
+
+                let value = if scope_names.is_module_binding(i) {
+                    scope.get_module_slot(i).get()
+                } else {
+                    scope.get_slot(i)
+                };
+
+                // Uninitialized bindings are in their TDZ
+                let value = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_stack(cx))
+                };
+
+                variables.push((scope_names.get_slot_name(i).to_stack(cx), value));
+            }
+
+            scopes.push(DebugScope {
+                kind: scope.kind(),
+                variables,
+                object: scope.object_ptr().map(|object| object.to_stack(cx)),
+            });
+
+            scope_opt = scope.parent();
+        }
+
+        scopes
+    }
+}
+
+/// A scope in the scope chain of a paused stack frame.
+pub struct DebugScope {
+    pub kind: ScopeKind,
+    /// All variables stored in the scope's slots. Variables in their TDZ have no value.
+    pub variables: Vec<(StackRoot<FlatString>, Option<StackRoot<Value>>)>,
+    /// The object holding the scope's bindings for global and with scopes
+    pub object: Option<StackRoot<ObjectValue>>,
+}
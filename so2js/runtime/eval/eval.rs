@@ -33,10 +33,42 @@ use crate::{
 };
 
 pub fn perform_eval(
+    cx: Context,
+    code: StackRoot<Value>,
+    is_strict_caller: bool,
+    direct_scope: Option<StackRoot<Scope>>,
+    flags: EvalFlags,
+) -> EvalResult<StackRoot<Value>> {
+    perform_eval_with_receiver(cx, code, is_strict_caller, direct_scope, None, flags)
+}
+
+/// Evaluate code on behalf of the debugger as if it were a direct eval in a paused stack frame
+/// with the given scope and receiver.
+///
+/// The code is always evaluated as strict code so that var declarations are not hoisted into the
+/// paused frame's scope.
+pub fn perform_debugger_eval(
+    cx: Context,
+    code: StackRoot<Value>,
+    scope: StackRoot<Scope>,
+    receiver: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    perform_eval_with_receiver(
+        cx,
+        code,
+        /* is_strict_caller */ true,
+        Some(scope),
+        Some(receiver),
+        EvalFlags::empty(),
+    )
+}
+
+fn perform_eval_with_receiver(
     mut cx: Context,
     code: StackRoot<Value>,
     is_strict_caller: bool,
     direct_scope: Option<StackRoot<Scope>>,
+    receiver: Option<StackRoot<Value>>,
     flags: EvalFlags,
 ) -> EvalResult<StackRoot<Value>> {
     if !code.is_string() {
@@ -104,7 +136,9 @@ pub fn perform_eval(
     let closure = Closure::new(cx, bytecode_function, eval_scope)?;
 
     // Determine the receiver for the eval function call
-    let receiver: StackRoot<Value> = if is_direct {
+    let receiver: StackRoot<Value> = if let Some(receiver) = receiver {
+        receiver
+    } else if is_direct {
         // Direct evals inherit their receiver from the caller
         cx.vm().receiver().to_stack(cx)
     } else {
@@ -9,6 +9,8 @@ use crate::runtime::{
         constant_table::ConstantTable,
         exception_handlers::ExceptionStackRootrs,
         function::{BytecodeFunction, Closure},
+        global_cache::global_cache_array_visit_pointers,
+        inline_cache::inline_cache_array_visit_pointers,
     },
     class_names::ClassNames,
     collections::{
@@ -21,12 +23,22 @@ use crate::runtime::{
     global_names::GlobalNames,
     heap_item_descriptor::{HeapItemDescriptor, HeapItemKind},
     interned_strings::InternedStringsSetField,
+    intl::{
+        collator_constructor::CollatorObject, date_time_format_constructor::DateTimeFormatObject,
+        display_names_constructor::DisplayNamesObject, list_format_constructor::ListFormatObject,
+        locale_constructor::LocaleObject, number_format_constructor::NumberFormatObject,
+        plural_rules_constructor::PluralRulesObject,
+        relative_time_format_constructor::RelativeTimeFormatObject,
+        segment_iterator::SegmentIterator, segmenter_constructor::SegmenterObject,
+        segments_object::SegmentsObject,
+    },
     intrinsics::{
         array_buffer_constructor::ArrayBufferObject,
         array_iterator::ArrayIterator,
         async_from_sync_iterator_prototype::AsyncFromSyncIterator,
         bigint_constructor::BigIntObject,
         boolean_constructor::BooleanObject,
+        call_site::CallSite,
         data_view_constructor::DataViewObject,
         date_object::DateObject,
         error_constructor::ErrorObject,
@@ -43,6 +55,15 @@ use crate::runtime::{
         set_object::{SetObject, SetObjectSetField},
         string_iterator::StringIterator,
         symbol_constructor::SymbolObject,
+        temporal::{
+            duration_constructor::DurationObject, instant_constructor::InstantObject,
+            plain_date_constructor::PlainDateObject,
+            plain_date_time_constructor::PlainDateTimeObject,
+            plain_month_day_constructor::PlainMonthDayObject,
+            plain_time_constructor::PlainTimeObject,
+            plain_year_month_constructor::PlainYearMonthObject,
+            zoned_date_time_constructor::ZonedDateTimeObject,
+        },
         typed_array::{
             BigInt64Array, BigUInt64Array, Float16Array, Float32Array, Float64Array, Int16Array,
             Int32Array, Int8Array, UInt16Array, UInt32Array, UInt8Array, UInt8ClampedArray,
@@ -67,6 +88,7 @@ use crate::runtime::{
     regexp::compiled_regexp::CompiledRegExpObject,
     scope::Scope,
     scope_names::ScopeNames,
+    shape::{Shape, ShapeTransitionsField},
     source_file::SourceFile,
     stack_trace::stack_frame_info_array_visit_pointers,
     string_object::StringObject,
@@ -119,6 +141,7 @@ impl HeapPtr<AnyHeapItem> {
             HeapItemKind::ArrayObject => self.cast::<ArrayObject>().visit_pointers(visitor),
             HeapItemKind::RegExpObject => self.cast::<RegExpObject>().visit_pointers(visitor),
             HeapItemKind::ErrorObject => self.cast::<ErrorObject>().visit_pointers(visitor),
+            HeapItemKind::CallSite => self.cast::<CallSite>().visit_pointers(visitor),
             HeapItemKind::DateObject => self.cast::<DateObject>().visit_pointers(visitor),
             HeapItemKind::SetObject => self.cast::<SetObject>().visit_pointers(visitor),
             HeapItemKind::MapObject => self.cast::<MapObject>().visit_pointers(visitor),
@@ -128,6 +151,45 @@ impl HeapPtr<AnyHeapItem> {
             HeapItemKind::FinalizationRegistryObject => self
                 .cast::<FinalizationRegistryObject>()
                 .visit_pointers(visitor),
+            HeapItemKind::CollatorObject => self.cast::<CollatorObject>().visit_pointers(visitor),
+            HeapItemKind::DateTimeFormatObject => {
+                self.cast::<DateTimeFormatObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::NumberFormatObject => {
+                self.cast::<NumberFormatObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::PluralRulesObject => {
+                self.cast::<PluralRulesObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::ListFormatObject => {
+                self.cast::<ListFormatObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::RelativeTimeFormatObject => self
+                .cast::<RelativeTimeFormatObject>()
+                .visit_pointers(visitor),
+            HeapItemKind::SegmenterObject => self.cast::<SegmenterObject>().visit_pointers(visitor),
+            HeapItemKind::SegmentsObject => self.cast::<SegmentsObject>().visit_pointers(visitor),
+            HeapItemKind::SegmentIterator => self.cast::<SegmentIterator>().visit_pointers(visitor),
+            HeapItemKind::LocaleObject => self.cast::<LocaleObject>().visit_pointers(visitor),
+            HeapItemKind::DisplayNamesObject => {
+                self.cast::<DisplayNamesObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::InstantObject => self.cast::<InstantObject>().visit_pointers(visitor),
+            HeapItemKind::ZonedDateTimeObject => {
+                self.cast::<ZonedDateTimeObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::PlainDateObject => self.cast::<PlainDateObject>().visit_pointers(visitor),
+            HeapItemKind::PlainTimeObject => self.cast::<PlainTimeObject>().visit_pointers(visitor),
+            HeapItemKind::PlainDateTimeObject => {
+                self.cast::<PlainDateTimeObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::PlainYearMonthObject => {
+                self.cast::<PlainYearMonthObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::PlainMonthDayObject => {
+                self.cast::<PlainMonthDayObject>().visit_pointers(visitor)
+            }
+            HeapItemKind::DurationObject => self.cast::<DurationObject>().visit_pointers(visitor),
             HeapItemKind::MappedArgumentsObject => {
                 self.cast::<MappedArgumentsObject>().visit_pointers(visitor)
             }
@@ -148,7 +210,7 @@ impl HeapPtr<AnyHeapItem> {
             HeapItemKind::Float16Array => self.cast::<Float16Array>().visit_pointers(visitor),
             HeapItemKind::Float32Array => self.cast::<Float32Array>().visit_pointers(visitor),
             HeapItemKind::Float64Array => self.cast::<Float64Array>().visit_pointers(visitor),
-            HeapItemKind::ArrayBufferObject => {
+            HeapItemKind::ArrayBufferObject | HeapItemKind::SharedArrayBufferObject => {
                 self.cast::<ArrayBufferObject>().visit_pointers(visitor)
             }
             HeapItemKind::DataViewObject => self.cast::<DataViewObject>().visit_pointers(visitor),
@@ -220,6 +282,7 @@ impl HeapPtr<AnyHeapItem> {
                 self.cast::<CompiledRegExpObject>().visit_pointers(visitor)
             }
             HeapItemKind::BoxedValue => self.cast::<BoxedValue>().visit_pointers(visitor),
+            HeapItemKind::Shape => self.cast::<Shape>().visit_pointers(visitor),
             HeapItemKind::ObjectNamedPropertiesMap => {
                 NamedPropertiesMapField::visit_pointers(self.cast_mut(), visitor)
             }
@@ -248,6 +311,9 @@ impl HeapPtr<AnyHeapItem> {
             HeapItemKind::ModuleCacheMap => {
                 ModuleCacheField::visit_pointers(self.cast_mut(), visitor)
             }
+            HeapItemKind::ShapeTransitionsMap => {
+                ShapeTransitionsField::visit_pointers(self.cast_mut(), visitor)
+            }
             HeapItemKind::ValueArray => value_array_visit_pointers(self.cast_mut(), visitor),
             HeapItemKind::ByteArray => byte_array_visit_pointers(self.cast_mut(), visitor),
             HeapItemKind::U32Array => u32_array_visit_pointers(self.cast_mut(), visitor),
@@ -264,6 +330,12 @@ impl HeapPtr<AnyHeapItem> {
                 .cast::<FinalizationRegistryCells>()
                 .visit_pointers(visitor),
             HeapItemKind::GlobalScopes => self.cast::<GlobalScopes>().visit_pointers(visitor),
+            HeapItemKind::InlineCacheArray => {
+                inline_cache_array_visit_pointers(self.cast_mut(), visitor)
+            }
+            HeapItemKind::GlobalCacheArray => {
+                global_cache_array_visit_pointers(self.cast_mut(), visitor)
+            }
             HeapItemKind::ValueVec => value_vec_visit_pointers(self.cast_mut(), visitor),
             HeapItemKind::Last => unreachable!("No objects are created with this descriptor"),
         }
@@ -51,13 +51,9 @@ pub trait GcVisitorExt: GcVisitor {
     #[inline]
     fn visit_value(&mut self, value: &mut Value) {
         if value.is_pointer() {
-            // Value stores a heap pointer; GC is non-moving so a temporary GcPtr is fine.
-            let mut gc_ptr: GcPtr<u8> = unsafe {
-                GcPtr::from_non_null(core::ptr::NonNull::new_unchecked(
-                    transmute::<&mut Value, &mut *mut u8>(value).cast(),
-                ))
-            };
-            self.visit(&mut gc_ptr);
+            // Pointer values are stored unencoded, so the value can be visited in place. This lets
+            // a moving collector update the value when the object is moved.
+            self.visit(unsafe { transmute::<&mut Value, &mut GcPtr<u8>>(value) });
         }
     }
 
@@ -65,12 +61,7 @@ pub trait GcVisitorExt: GcVisitor {
     #[inline]
     fn visit_weak_value(&mut self, value: &mut Value) {
         if value.is_pointer() {
-            let mut gc_ptr: GcPtr<u8> = unsafe {
-                GcPtr::from_non_null(core::ptr::NonNull::new_unchecked(
-                    transmute::<&mut Value, &mut *mut u8>(value).cast(),
-                ))
-            };
-            self.visit_weak(&mut gc_ptr);
+            self.visit_weak(unsafe { transmute::<&mut Value, &mut GcPtr<u8>>(value) });
         }
     }
 
@@ -109,6 +109,11 @@ impl<T: IsHeapItem> Deref for HeapPtr<T> {
 
 impl<T: IsHeapItem> DerefMut for HeapPtr<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        // All writes to heap items go through a mutable reference, any of which could store a
+        // pointer to a young object. Conservatively add old heap items to the remembered set. The
+        // heap does not record writes during a GC, so tracing never adds objects to the set.
+        so2js_gc::Heap::remember_written_object(self.0);
+
         unsafe { self.0.as_non_null().as_mut() }
     }
 }
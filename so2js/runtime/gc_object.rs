@@ -1,19 +1,27 @@
+use alloc::{format, vec::Vec};
+
+use so2js_gc::GcCycleKind;
+
 use crate::{
     js_stack_scope, must_a,
     runtime::{
-        abstract_operations::define_property_or_throw, alloc_error::AllocResult, PropertyDescriptor,
+        abstract_operations::{create_data_property_or_throw, define_property_or_throw},
+        alloc_error::AllocResult,
+        array_object::create_array_from_list,
+        ordinary_object::ordinary_object_create,
+        PropertyDescriptor,
     },
 };
 
 use super::{
     eval_result::EvalResult, intrinsics::intrinsics::Intrinsic, object_value::ObjectValue,
-    realm::Realm, Context, StackRoot, Value,
+    realm::Realm, Context, PropertyKey, StackRoot, Value,
 };
 
 pub struct GcObject;
 
 impl GcObject {
-    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+    pub fn new(mut cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
         let mut object = ObjectValue::new(
             cx,
             Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
@@ -22,6 +30,10 @@ impl GcObject {
 
         object.intrinsic_func(cx, cx.names.run(), Self::run, 0, realm)?;
 
+        let statistics_string = cx.alloc_string("statistics")?.as_string();
+        let statistics_key = PropertyKey::string_handle(cx, statistics_string)?;
+        object.intrinsic_func(cx, statistics_key, Self::statistics, 0, realm)?;
+
         Ok(object.to_stack(cx))
     }
 
@@ -49,4 +61,89 @@ impl GcObject {
         cx.run_gc();
         Ok(cx.undefined())
     }
+
+    /// Return an object describing the objects in the heap and recent garbage collections, with
+    /// the count and size of objects for each heap item kind.
+    pub fn statistics(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        // Collect statistics before allocating the result
+        let stats = cx.heap_statistics();
+
+        let result = ordinary_object_create(cx)?;
+        set_number_property(cx, result, "bytesAllocated", stats.bytes_allocated as f64)?;
+        set_number_property(
+            cx,
+            result,
+            "nurseryBytesAllocated",
+            stats.nursery_bytes_allocated as f64,
+        )?;
+        set_number_property(cx, result, "numObjects", stats.num_objects as f64)?;
+        set_number_property(cx, result, "numMajorGcs", stats.num_major_gcs as f64)?;
+        set_number_property(cx, result, "numMinorGcs", stats.num_minor_gcs as f64)?;
+        set_number_property(cx, result, "maxPauseMillis", stats.max_pause_millis)?;
+
+        // Map from heap item kind name to the count and size of objects of that kind
+        let kinds = ordinary_object_create(cx)?;
+        for kind_stats in &stats.kinds {
+            let kind_object = ordinary_object_create(cx)?;
+            set_number_property(cx, kind_object, "count", kind_stats.count as f64)?;
+            set_number_property(cx, kind_object, "bytes", kind_stats.bytes as f64)?;
+
+            let kind_name = format!("{:?}", kind_stats.kind);
+            set_property(cx, kinds, &kind_name, kind_object.as_value())?;
+        }
+        set_property(cx, result, "kinds", kinds.as_value())?;
+
+        let mut history = Vec::with_capacity(stats.gc_history.len());
+        for cycle in &stats.gc_history {
+            let cycle_object = ordinary_object_create(cx)?;
+
+            let kind_name = match cycle.kind {
+                GcCycleKind::Minor => "minor",
+                GcCycleKind::Major => "major",
+            };
+            let kind_name = cx.alloc_string(kind_name)?.as_value();
+            set_property(cx, cycle_object, "kind", kind_name)?;
+
+            set_number_property(cx, cycle_object, "pauseMillis", cycle.pause_millis)?;
+            set_number_property(cx, cycle_object, "maxPauseMillis", cycle.max_pause_millis)?;
+            set_number_property(cx, cycle_object, "bytesFreed", cycle.bytes_freed as f64)?;
+            set_number_property(cx, cycle_object, "objectsFreed", cycle.objects_freed as f64)?;
+            set_number_property(
+                cx,
+                cycle_object,
+                "bytesPromoted",
+                cycle.bytes_promoted as f64,
+            )?;
+
+            history.push(cycle_object.as_value());
+        }
+        let history = create_array_from_list(cx, &history)?;
+        set_property(cx, result, "history", history.as_value())?;
+
+        Ok(result.as_value())
+    }
+}
+
+fn set_property(
+    mut cx: Context,
+    object: StackRoot<ObjectValue>,
+    name: &str,
+    value: StackRoot<Value>,
+) -> EvalResult<()> {
+    let key_string = cx.alloc_string(name)?.as_string();
+    let key = PropertyKey::string_handle(cx, key_string)?;
+    create_data_property_or_throw(cx, object, key, value)
+}
+
+fn set_number_property(
+    cx: Context,
+    object: StackRoot<ObjectValue>,
+    name: &str,
+    value: f64,
+) -> EvalResult<()> {
+    set_property(cx, object, name, Value::from(value).to_stack(cx))
 }
@@ -9,6 +9,7 @@ use crate::{
         iterator::create_iter_result_object,
         object_value::ObjectValue,
         ordinary_object::{get_prototype_from_constructor, object_ordinary_init},
+        promise_object::PromiseObject,
         Context, StackRoot, HeapPtr,
     },
     set_uninit,
@@ -42,6 +43,9 @@ extend_object! {
         // For a generator the value is from Generator.prototype.{next, return, throw}.
         // For an async function the value is from resolve or reject.
         completion_indices: Option<(u32, u32)>,
+        // For an async function, the promise returned to the caller of the async function. Used
+        // to find the async functions awaiting this one when reconstructing async stack traces.
+        async_promise: Option<HeapPtr<PromiseObject>>,
         // The stack frame of the generator, containing all args, locals, and fixed slots in
         // between.
         stack_frame: InlineArray<StackSlotValue>,
@@ -108,6 +112,7 @@ impl GeneratorObject {
         pc_to_resume_offset: usize,
         fp_index: usize,
         completion_indices: Option<(u32, u32)>,
+        async_promise: Option<StackRoot<PromiseObject>>,
         stack_frame: &[StackSlotValue],
     ) -> AllocResult<HeapPtr<GeneratorObject>> {
         let size = Self::calculate_size_in_bytes(stack_frame.len());
@@ -120,6 +125,7 @@ impl GeneratorObject {
         set_uninit!(generator.pc_to_resume_offset, pc_to_resume_offset);
         set_uninit!(generator.fp_index, fp_index);
         set_uninit!(generator.completion_indices, completion_indices);
+        set_uninit!(generator.async_promise, async_promise.map(|p| *p));
         generator.stack_frame.init_from_slice(stack_frame);
 
         Ok(generator)
@@ -141,6 +147,7 @@ impl GeneratorObject {
             pc_to_resume_offset,
             fp_index,
             None,
+            None,
             stack_frame,
         )?)
     }
@@ -150,6 +157,7 @@ impl GeneratorObject {
         pc_to_resume_offset: usize,
         fp_index: usize,
         completion_indices: (u32, u32),
+        async_promise: StackRoot<PromiseObject>,
         stack_frame: &[StackSlotValue],
     ) -> AllocResult<HeapPtr<GeneratorObject>> {
         Self::new(
@@ -158,6 +166,7 @@ impl GeneratorObject {
             pc_to_resume_offset,
             fp_index,
             Some(completion_indices),
+            Some(async_promise),
             stack_frame,
         )
     }
@@ -197,6 +206,16 @@ impl GeneratorObject {
     pub fn closure_ptr(&self) -> HeapPtr<Closure> {
         StackFrame::for_fp(self.current_fp().cast_mut()).closure()
     }
+
+    /// Byte offset of the PC to resume into the BytecodeFunction of the suspended closure.
+    pub fn resume_pc_offset(&self) -> usize {
+        self.pc_to_resume_offset
+    }
+
+    /// The promise returned by the async function, or None if this is a regular generator.
+    pub fn async_promise_ptr(&self) -> Option<HeapPtr<PromiseObject>> {
+        self.async_promise
+    }
 }
 
 impl TGeneratorObject for StackRoot<GeneratorObject> {
@@ -321,6 +340,7 @@ impl HeapItem for HeapPtr<GeneratorObject> {
 
     fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
         self.visit_object_pointers(visitor);
+        visitor.visit_pointer_opt(&mut self.async_promise);
 
         if self.state.is_suspended() {
             let mut stack_frame = StackFrame::for_fp(self.current_fp().cast_mut());
@@ -17,6 +17,8 @@ use crate::{
             constant_table::ConstantTable,
             exception_handlers::ExceptionStackRootrs,
             function::{BytecodeFunction, Closure},
+            global_cache::global_cache_array_byte_size,
+            inline_cache::inline_cache_array_byte_size,
         },
         class_names::ClassNames,
         collections::{
@@ -30,12 +32,24 @@ use crate::{
         for_in_iterator::ForInIterator,
         generator_object::GeneratorObject,
         global_names::GlobalNames,
+        intl::{
+            collator_constructor::CollatorObject,
+            date_time_format_constructor::DateTimeFormatObject,
+            display_names_constructor::DisplayNamesObject,
+            list_format_constructor::ListFormatObject, locale_constructor::LocaleObject,
+            number_format_constructor::NumberFormatObject,
+            plural_rules_constructor::PluralRulesObject,
+            relative_time_format_constructor::RelativeTimeFormatObject,
+            segment_iterator::SegmentIterator, segmenter_constructor::SegmenterObject,
+            segments_object::SegmentsObject,
+        },
         intrinsics::{
             array_buffer_constructor::ArrayBufferObject,
             array_iterator::ArrayIterator,
             async_from_sync_iterator_prototype::AsyncFromSyncIterator,
             bigint_constructor::BigIntObject,
             boolean_constructor::BooleanObject,
+            call_site::CallSite,
             data_view_constructor::DataViewObject,
             date_object::DateObject,
             error_constructor::ErrorObject,
@@ -52,6 +66,15 @@ use crate::{
             set_object::{SetObject, SetObjectSetField},
             string_iterator::StringIterator,
             symbol_constructor::SymbolObject,
+            temporal::{
+                duration_constructor::DurationObject, instant_constructor::InstantObject,
+                plain_date_constructor::PlainDateObject,
+                plain_date_time_constructor::PlainDateTimeObject,
+                plain_month_day_constructor::PlainMonthDayObject,
+                plain_time_constructor::PlainTimeObject,
+                plain_year_month_constructor::PlainYearMonthObject,
+                zoned_date_time_constructor::ZonedDateTimeObject,
+            },
             typed_array::{
                 BigInt64Array, BigUInt64Array, Float16Array, Float32Array, Float64Array,
                 Int16Array, Int32Array, Int8Array, UInt16Array, UInt32Array, UInt8Array,
@@ -75,6 +98,7 @@ use crate::{
         rust_vtables::extract_virtual_object_vtable,
         scope::Scope,
         scope_names::ScopeNames,
+        shape::{Shape, ShapeTransitionsField},
         source_file::SourceFile,
         stack_trace::stack_frame_info_array_byte_size,
         string_object::StringObject,
@@ -130,6 +154,7 @@ pub enum HeapItemKind {
     ArrayObject,
     RegExpObject,
     ErrorObject,
+    CallSite,
     DateObject,
     SetObject,
     MapObject,
@@ -137,6 +162,25 @@ pub enum HeapItemKind {
     WeakSetObject,
     WeakMapObject,
     FinalizationRegistryObject,
+    CollatorObject,
+    DateTimeFormatObject,
+    NumberFormatObject,
+    PluralRulesObject,
+    ListFormatObject,
+    RelativeTimeFormatObject,
+    SegmenterObject,
+    SegmentsObject,
+    SegmentIterator,
+    LocaleObject,
+    DisplayNamesObject,
+    InstantObject,
+    ZonedDateTimeObject,
+    PlainDateObject,
+    PlainTimeObject,
+    PlainDateTimeObject,
+    PlainYearMonthObject,
+    PlainMonthDayObject,
+    DurationObject,
 
     MappedArgumentsObject,
     UnmappedArgumentsObject,
@@ -155,6 +199,7 @@ pub enum HeapItemKind {
     Float64Array,
 
     ArrayBufferObject,
+    SharedArrayBufferObject,
     DataViewObject,
 
     ArrayIterator,
@@ -208,6 +253,8 @@ pub enum HeapItemKind {
 
     BoxedValue,
 
+    Shape,
+
     // Hash maps
     ObjectNamedPropertiesMap,
     MapObjectValueMap,
@@ -219,6 +266,7 @@ pub enum HeapItemKind {
     InternedStringsSet,
     LexicalNamesMap,
     ModuleCacheMap,
+    ShapeTransitionsMap,
 
     // Arrays
     ValueArray,
@@ -229,6 +277,8 @@ pub enum HeapItemKind {
     StackFrameInfoArray,
     FinalizationRegistryCells,
     GlobalScopes,
+    InlineCacheArray,
+    GlobalCacheArray,
 
     // Vectors
     ValueVec,
@@ -248,6 +298,8 @@ bitflags! {
     pub struct DescFlags: u8 {
         /// Whether this heap item is an object value
         const IS_OBJECT = 1 << 0;
+        /// Whether this heap item is an object that uses the ordinary object internal methods
+        const IS_ORDINARY_OBJECT = 1 << 1;
     }
 }
 
@@ -276,6 +328,41 @@ impl HeapItemDescriptor {
         self.kind
     }
 
+    /// Check that a possibly corrupt heap item has a valid descriptor, for the heap verifier.
+    ///
+    /// The descriptor's kind is read as a raw byte so that an out of range kind is never
+    /// interpreted as a `HeapItemKind`.
+    #[cfg(feature = "heap_verify")]
+    pub fn verify_item_descriptor(
+        item_ptr: *const u8,
+        is_allocated: &dyn Fn(*const u8) -> bool,
+    ) -> Result<(), &'static str> {
+        // Every heap item starts with a pointer to its descriptor
+        let descriptor = unsafe { *(item_ptr as *const *const HeapItemDescriptor) };
+        if !is_allocated(descriptor as *const u8) {
+            return Err("descriptor is not allocated");
+        }
+
+        let descriptor_descriptor = unsafe { (*descriptor).descriptor.as_ptr() };
+        if !is_allocated(descriptor_descriptor as *const u8) {
+            return Err("descriptor's descriptor is not allocated");
+        }
+
+        let raw_kind = |descriptor: *const HeapItemDescriptor| unsafe {
+            *(core::ptr::addr_of!((*descriptor).kind) as *const u8)
+        };
+
+        if raw_kind(descriptor_descriptor) != HeapItemKind::Descriptor as u8 {
+            return Err("descriptor's descriptor is not the descriptor descriptor");
+        }
+
+        if raw_kind(descriptor) >= HeapItemKind::Last as u8 {
+            return Err("descriptor has an invalid kind");
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub const fn vtable(&self) -> VirtualObjectVtable {
         self.vtable
@@ -286,6 +373,13 @@ impl HeapItemDescriptor {
         self.flags.contains(DescFlags::IS_OBJECT)
     }
 
+    /// Whether this is an object with ordinary internal methods, meaning its named properties can
+    /// be accessed directly from the named properties map.
+    #[inline]
+    pub fn is_ordinary_object(&self) -> bool {
+        self.flags.contains(DescFlags::IS_ORDINARY_OBJECT)
+    }
+
     pub fn byte_size_for_item(&self, item: HeapPtr<AnyHeapItem>) -> usize {
         match self.kind() {
             HeapItemKind::Descriptor => item.cast::<HeapItemDescriptor>().byte_size(),
@@ -299,6 +393,7 @@ impl HeapItemDescriptor {
             HeapItemKind::ArrayObject => item.cast::<ArrayObject>().byte_size(),
             HeapItemKind::RegExpObject => item.cast::<RegExpObject>().byte_size(),
             HeapItemKind::ErrorObject => item.cast::<ErrorObject>().byte_size(),
+            HeapItemKind::CallSite => item.cast::<CallSite>().byte_size(),
             HeapItemKind::DateObject => item.cast::<DateObject>().byte_size(),
             HeapItemKind::SetObject => item.cast::<SetObject>().byte_size(),
             HeapItemKind::MapObject => item.cast::<MapObject>().byte_size(),
@@ -308,6 +403,27 @@ impl HeapItemDescriptor {
             HeapItemKind::FinalizationRegistryObject => {
                 item.cast::<FinalizationRegistryObject>().byte_size()
             }
+            HeapItemKind::CollatorObject => item.cast::<CollatorObject>().byte_size(),
+            HeapItemKind::DateTimeFormatObject => item.cast::<DateTimeFormatObject>().byte_size(),
+            HeapItemKind::NumberFormatObject => item.cast::<NumberFormatObject>().byte_size(),
+            HeapItemKind::PluralRulesObject => item.cast::<PluralRulesObject>().byte_size(),
+            HeapItemKind::ListFormatObject => item.cast::<ListFormatObject>().byte_size(),
+            HeapItemKind::RelativeTimeFormatObject => {
+                item.cast::<RelativeTimeFormatObject>().byte_size()
+            }
+            HeapItemKind::SegmenterObject => item.cast::<SegmenterObject>().byte_size(),
+            HeapItemKind::SegmentsObject => item.cast::<SegmentsObject>().byte_size(),
+            HeapItemKind::SegmentIterator => item.cast::<SegmentIterator>().byte_size(),
+            HeapItemKind::LocaleObject => item.cast::<LocaleObject>().byte_size(),
+            HeapItemKind::DisplayNamesObject => item.cast::<DisplayNamesObject>().byte_size(),
+            HeapItemKind::InstantObject => item.cast::<InstantObject>().byte_size(),
+            HeapItemKind::ZonedDateTimeObject => item.cast::<ZonedDateTimeObject>().byte_size(),
+            HeapItemKind::PlainDateObject => item.cast::<PlainDateObject>().byte_size(),
+            HeapItemKind::PlainTimeObject => item.cast::<PlainTimeObject>().byte_size(),
+            HeapItemKind::PlainDateTimeObject => item.cast::<PlainDateTimeObject>().byte_size(),
+            HeapItemKind::PlainYearMonthObject => item.cast::<PlainYearMonthObject>().byte_size(),
+            HeapItemKind::PlainMonthDayObject => item.cast::<PlainMonthDayObject>().byte_size(),
+            HeapItemKind::DurationObject => item.cast::<DurationObject>().byte_size(),
             HeapItemKind::MappedArgumentsObject => item.cast::<MappedArgumentsObject>().byte_size(),
             HeapItemKind::UnmappedArgumentsObject => {
                 item.cast::<UnmappedArgumentsObject>().byte_size()
@@ -325,6 +441,7 @@ impl HeapItemDescriptor {
             HeapItemKind::Float32Array => item.cast::<Float32Array>().byte_size(),
             HeapItemKind::Float64Array => item.cast::<Float64Array>().byte_size(),
             HeapItemKind::ArrayBufferObject => item.cast::<ArrayBufferObject>().byte_size(),
+            HeapItemKind::SharedArrayBufferObject => item.cast::<ArrayBufferObject>().byte_size(),
             HeapItemKind::DataViewObject => item.cast::<DataViewObject>().byte_size(),
             HeapItemKind::ArrayIterator => item.cast::<ArrayIterator>().byte_size(),
             HeapItemKind::StringIterator => item.cast::<StringIterator>().byte_size(),
@@ -364,6 +481,7 @@ impl HeapItemDescriptor {
             HeapItemKind::SparseArrayProperties => item.cast::<SparseArrayProperties>().byte_size(),
             HeapItemKind::CompiledRegExpObject => item.cast::<CompiledRegExpObject>().byte_size(),
             HeapItemKind::BoxedValue => item.cast::<BoxedValue>().byte_size(),
+            HeapItemKind::Shape => item.cast::<Shape>().byte_size(),
             HeapItemKind::ObjectNamedPropertiesMap => {
                 NamedPropertiesMapField::byte_size(&item.cast())
             }
@@ -382,6 +500,7 @@ impl HeapItemDescriptor {
             HeapItemKind::InternedStringsSet => InternedStringsSetField::byte_size(&item.cast()),
             HeapItemKind::LexicalNamesMap => LexicalNamesMapField::byte_size(&item.cast()),
             HeapItemKind::ModuleCacheMap => ModuleCacheField::byte_size(&item.cast()),
+            HeapItemKind::ShapeTransitionsMap => ShapeTransitionsField::byte_size(&item.cast()),
             HeapItemKind::ValueArray => value_array_byte_size(item.cast()),
             HeapItemKind::ByteArray => byte_array_byte_size(item.cast()),
             HeapItemKind::U32Array => u32_array_byte_size(item.cast()),
@@ -392,6 +511,8 @@ impl HeapItemDescriptor {
                 item.cast::<FinalizationRegistryCells>().byte_size()
             }
             HeapItemKind::GlobalScopes => item.cast::<GlobalScopes>().byte_size(),
+            HeapItemKind::InlineCacheArray => inline_cache_array_byte_size(item.cast()),
+            HeapItemKind::GlobalCacheArray => global_cache_array_byte_size(item.cast()),
             HeapItemKind::ValueVec => value_vec_byte_size(item.cast()),
             HeapItemKind::Last => unreachable!("No objects are created with this descriptor"),
         }
@@ -448,7 +569,11 @@ impl BaseDescriptors {
 
         macro_rules! ordinary_object_descriptor {
             ($object_kind:expr) => {
-                register_descriptor!($object_kind, OrdinaryObject, DescFlags::IS_OBJECT);
+                register_descriptor!(
+                    $object_kind,
+                    OrdinaryObject,
+                    DescFlags::IS_OBJECT | DescFlags::IS_ORDINARY_OBJECT
+                );
             };
         }
 
@@ -473,6 +598,7 @@ impl BaseDescriptors {
         register_descriptor!(HeapItemKind::ArrayObject, ArrayObject, DescFlags::IS_OBJECT);
         ordinary_object_descriptor!(HeapItemKind::RegExpObject);
         ordinary_object_descriptor!(HeapItemKind::ErrorObject);
+        ordinary_object_descriptor!(HeapItemKind::CallSite);
         ordinary_object_descriptor!(HeapItemKind::DateObject);
         ordinary_object_descriptor!(HeapItemKind::SetObject);
         ordinary_object_descriptor!(HeapItemKind::MapObject);
@@ -480,6 +606,25 @@ impl BaseDescriptors {
         ordinary_object_descriptor!(HeapItemKind::WeakSetObject);
         ordinary_object_descriptor!(HeapItemKind::WeakMapObject);
         ordinary_object_descriptor!(HeapItemKind::FinalizationRegistryObject);
+        ordinary_object_descriptor!(HeapItemKind::CollatorObject);
+        ordinary_object_descriptor!(HeapItemKind::DateTimeFormatObject);
+        ordinary_object_descriptor!(HeapItemKind::NumberFormatObject);
+        ordinary_object_descriptor!(HeapItemKind::PluralRulesObject);
+        ordinary_object_descriptor!(HeapItemKind::ListFormatObject);
+        ordinary_object_descriptor!(HeapItemKind::RelativeTimeFormatObject);
+        ordinary_object_descriptor!(HeapItemKind::SegmenterObject);
+        ordinary_object_descriptor!(HeapItemKind::SegmentsObject);
+        ordinary_object_descriptor!(HeapItemKind::SegmentIterator);
+        ordinary_object_descriptor!(HeapItemKind::LocaleObject);
+        ordinary_object_descriptor!(HeapItemKind::DisplayNamesObject);
+        ordinary_object_descriptor!(HeapItemKind::InstantObject);
+        ordinary_object_descriptor!(HeapItemKind::ZonedDateTimeObject);
+        ordinary_object_descriptor!(HeapItemKind::PlainDateObject);
+        ordinary_object_descriptor!(HeapItemKind::PlainTimeObject);
+        ordinary_object_descriptor!(HeapItemKind::PlainDateTimeObject);
+        ordinary_object_descriptor!(HeapItemKind::PlainYearMonthObject);
+        ordinary_object_descriptor!(HeapItemKind::PlainMonthDayObject);
+        ordinary_object_descriptor!(HeapItemKind::DurationObject);
 
         register_descriptor!(
             HeapItemKind::MappedArgumentsObject,
@@ -526,6 +671,7 @@ impl BaseDescriptors {
         );
 
         ordinary_object_descriptor!(HeapItemKind::ArrayBufferObject);
+        ordinary_object_descriptor!(HeapItemKind::SharedArrayBufferObject);
         ordinary_object_descriptor!(HeapItemKind::DataViewObject);
 
         ordinary_object_descriptor!(HeapItemKind::ArrayIterator);
@@ -582,6 +728,8 @@ impl BaseDescriptors {
 
         other_heap_item_descriptor!(HeapItemKind::BoxedValue);
 
+        other_heap_item_descriptor!(HeapItemKind::Shape);
+
         other_heap_item_descriptor!(HeapItemKind::ObjectNamedPropertiesMap);
         other_heap_item_descriptor!(HeapItemKind::MapObjectValueMap);
         other_heap_item_descriptor!(HeapItemKind::SetObjectValueSet);
@@ -592,6 +740,7 @@ impl BaseDescriptors {
         other_heap_item_descriptor!(HeapItemKind::InternedStringsSet);
         other_heap_item_descriptor!(HeapItemKind::LexicalNamesMap);
         other_heap_item_descriptor!(HeapItemKind::ModuleCacheMap);
+        other_heap_item_descriptor!(HeapItemKind::ShapeTransitionsMap);
 
         other_heap_item_descriptor!(HeapItemKind::ValueArray);
         other_heap_item_descriptor!(HeapItemKind::ByteArray);
@@ -601,6 +750,8 @@ impl BaseDescriptors {
         other_heap_item_descriptor!(HeapItemKind::StackFrameInfoArray);
         other_heap_item_descriptor!(HeapItemKind::FinalizationRegistryCells);
         other_heap_item_descriptor!(HeapItemKind::GlobalScopes);
+        other_heap_item_descriptor!(HeapItemKind::InlineCacheArray);
+        other_heap_item_descriptor!(HeapItemKind::GlobalCacheArray);
 
         other_heap_item_descriptor!(HeapItemKind::ValueVec);
 
@@ -0,0 +1,552 @@
+//! Heap snapshots in the V8 `.heapsnapshot` JSON format, which can be loaded in the Memory tab of
+//! Chrome DevTools.
+//!
+//! The snapshot contains a node for every object in the heap and an edge for every pointer
+//! between objects. Pointers are found with the same `visit_pointers_for_kind` machinery used by
+//! the GC, and are reported as internal edges named after the kind of the target. Property values,
+//! array elements, and scope variables are additionally reported as named edges.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use hashbrown::{HashMap, HashSet};
+use so2js_gc::{GcPtr, GcVisitor};
+
+use super::{
+    bytecode::function::{BytecodeFunction, Closure},
+    class_names::class_name_and_prototype,
+    gc::AnyHeapItem,
+    heap_item_descriptor::HeapItemKind,
+    object_value::ObjectValue,
+    scope::Scope,
+    string_value::StringValue,
+    value::SymbolValue,
+    Context, HeapPtr, PropertyKey, Value,
+};
+
+/// Maximum number of characters of a string's contents included in the name of its node
+const MAX_STRING_NAME_LENGTH: usize = 1024;
+
+/// Maximum number of prototypes searched for a class prototype when naming an object
+const MAX_CLASS_SEARCH_DEPTH: usize = 16;
+
+/// Node types in the order they appear in the snapshot's meta section
+#[derive(Clone, Copy)]
+enum NodeType {
+    Hidden = 0,
+    Array = 1,
+    String = 2,
+    Object = 3,
+    Code = 4,
+    Closure = 5,
+    RegExp = 6,
+    Synthetic = 9,
+    Symbol = 12,
+    BigInt = 13,
+    ObjectShape = 14,
+}
+
+const NODE_TYPE_NAMES: [&str; 15] = [
+    "hidden",
+    "array",
+    "string",
+    "object",
+    "code",
+    "closure",
+    "regexp",
+    "number",
+    "native",
+    "synthetic",
+    "concatenated string",
+    "sliced string",
+    "symbol",
+    "bigint",
+    "object shape",
+];
+
+/// Edge types in the order they appear in the snapshot's meta section
+#[derive(Clone, Copy, PartialEq)]
+enum EdgeType {
+    Context = 0,
+    Element = 1,
+    Property = 2,
+    Internal = 3,
+    Weak = 6,
+}
+
+const EDGE_TYPE_NAMES: [&str; 7] = [
+    "context", "element", "property", "internal", "hidden", "shortcut", "weak",
+];
+
+const NODE_FIELDS: [&str; 7] = [
+    "type",
+    "name",
+    "id",
+    "self_size",
+    "edge_count",
+    "trace_node_id",
+    "detachedness",
+];
+
+const EDGE_FIELDS: [&str; 3] = ["type", "name_or_index", "to_node"];
+
+struct Node {
+    node_type: NodeType,
+    name: usize,
+    id: usize,
+    self_size: usize,
+    edge_count: usize,
+}
+
+struct Edge {
+    edge_type: EdgeType,
+    /// Index of the edge's name in the strings table, or the element index for element edges
+    name_or_index: usize,
+    to_node: usize,
+}
+
+/// Builder for the nodes, edges, and strings tables of a heap snapshot.
+struct HeapSnapshot {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    strings: Vec<String>,
+    string_indices: HashMap<String, usize>,
+    /// Map from the address of a heap item to the index of its node
+    node_indices: HashMap<usize, usize>,
+}
+
+/// Visitor that records every pointer in a heap item
+struct PointerCollector {
+    pointers: Vec<(usize, bool)>,
+}
+
+impl GcVisitor for PointerCollector {
+    fn visit<T>(&mut self, ptr: &mut GcPtr<T>) {
+        self.pointers.push((ptr.as_ptr() as usize, false));
+    }
+
+    fn visit_weak<T>(&mut self, ptr: &mut GcPtr<T>) {
+        self.pointers.push((ptr.as_ptr() as usize, true));
+    }
+}
+
+impl HeapSnapshot {
+    fn new() -> HeapSnapshot {
+        HeapSnapshot {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            strings: Vec::new(),
+            string_indices: HashMap::new(),
+            node_indices: HashMap::new(),
+        }
+    }
+
+    fn intern_string(&mut self, string: String) -> usize {
+        if let Some(index) = self.string_indices.get(&string) {
+            return *index;
+        }
+
+        let index = self.strings.len();
+        self.strings.push(string.clone());
+        self.string_indices.insert(string, index);
+
+        index
+    }
+
+    fn add_node(&mut self, node_type: NodeType, name: String, self_size: usize) -> usize {
+        let name = self.intern_string(name);
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            node_type,
+            name,
+            // Ids of heap objects are odd by convention
+            id: index * 2 + 1,
+            self_size,
+            edge_count: 0,
+        });
+
+        index
+    }
+
+    /// Add an edge from the last node added to the node for the heap item at the given address.
+    /// Returns whether the edge was added.
+    fn add_edge(&mut self, edge_type: EdgeType, name_or_index: usize, address: usize) -> bool {
+        let to_node = match self.node_indices.get(&address) {
+            Some(to_node) => *to_node,
+            None => return false,
+        };
+
+        self.edges.push(Edge {
+            edge_type,
+            name_or_index,
+            to_node,
+        });
+        self.nodes.last_mut().unwrap().edge_count += 1;
+
+        true
+    }
+
+    fn add_named_edge(&mut self, edge_type: EdgeType, name: String, address: usize) -> bool {
+        if !self.node_indices.contains_key(&address) {
+            return false;
+        }
+
+        let name = self.intern_string(name);
+        self.add_edge(edge_type, name, address)
+    }
+
+    /// Add edges for all pointers in a heap item to the last node added. Named edges are added for
+    /// property values, array elements, and scope variables, and all remaining pointers are added
+    /// as internal edges.
+    fn add_item_edges(&mut self, mut item: HeapPtr<AnyHeapItem>) {
+        let kind = item.descriptor().kind();
+        let mut named_targets = HashSet::new();
+
+        if item.descriptor().is_object() {
+            self.add_object_edges(item.cast::<ObjectValue>(), &mut named_targets);
+        } else if kind == HeapItemKind::Scope {
+            self.add_scope_edges(item.cast::<Scope>(), &mut named_targets);
+        }
+
+        let mut collector = PointerCollector {
+            pointers: Vec::new(),
+        };
+        item.visit_pointers_for_kind(&mut collector, kind);
+
+        for (address, is_weak) in collector.pointers {
+            if named_targets.contains(&address) {
+                continue;
+            }
+
+            let edge_type = if is_weak {
+                EdgeType::Weak
+            } else {
+                EdgeType::Internal
+            };
+
+            if !self.node_indices.contains_key(&address) {
+                continue;
+            }
+
+            let target = HeapPtr::<AnyHeapItem>::from_ptr(address as *mut AnyHeapItem);
+            let name = format!("{:?}", target.descriptor().kind());
+            self.add_named_edge(edge_type, name, address);
+        }
+    }
+
+    fn add_object_edges(
+        &mut self,
+        object: HeapPtr<ObjectValue>,
+        named_targets: &mut HashSet<usize>,
+    ) {
+        for (key, property) in object.named_properties().iter_gc_unsafe() {
+            let value = property.value();
+            if !value.is_pointer() {
+                continue;
+            }
+
+            let address = value_address(value);
+            let added = if key.is_array_index() {
+                self.add_edge(EdgeType::Element, key.as_array_index() as usize, address)
+            } else {
+                self.add_named_edge(EdgeType::Property, property_key_name(&key), address)
+            };
+
+            if added {
+                named_targets.insert(address);
+            }
+        }
+
+        if let Some(dense_properties) = object.array_properties().as_dense_opt() {
+            for (index, value) in dense_properties.as_slice().iter().enumerate() {
+                if value.is_pointer()
+                    && self.add_edge(EdgeType::Element, index, value_address(*value))
+                {
+                    named_targets.insert(value_address(*value));
+                }
+            }
+        }
+    }
+
+    fn add_scope_edges(&mut self, scope: HeapPtr<Scope>, named_targets: &mut HashSet<usize>) {
+        let scope_names = scope.scope_names_ptr();
+        for index in 0..scope_names.len() {
+            let value = scope.get_slot(index);
+            if !value.is_pointer() {
+                continue;
+            }
+
+            let name = format!("{}", scope_names.get_slot_name(index));
+            let address = value_address(value);
+            if self.add_named_edge(EdgeType::Context, name, address) {
+                named_targets.insert(address);
+            }
+        }
+    }
+
+    fn write(&self, writer: &mut impl Write) -> fmt::Result {
+        writer.write_str("{\"snapshot\":{\"meta\":{\"node_fields\":")?;
+        write_json_string_array(writer, &NODE_FIELDS)?;
+        writer.write_str(",\"node_types\":[")?;
+        write_json_string_array(writer, &NODE_TYPE_NAMES)?;
+        writer.write_str(",\"string\",\"number\",\"number\",\"number\",\"number\",\"number\"]")?;
+        writer.write_str(",\"edge_fields\":")?;
+        write_json_string_array(writer, &EDGE_FIELDS)?;
+        writer.write_str(",\"edge_types\":[")?;
+        write_json_string_array(writer, &EDGE_TYPE_NAMES)?;
+        writer.write_str(",\"string_or_number\",\"node\"]")?;
+        writer.write_str(
+            ",\"trace_function_info_fields\":[],\"trace_node_fields\":[],\"sample_fields\":[],\
+             \"location_fields\":[]},",
+        )?;
+        write!(
+            writer,
+            "\"node_count\":{},\"edge_count\":{},\"trace_function_count\":0}},",
+            self.nodes.len(),
+            self.edges.len()
+        )?;
+
+        writer.write_str("\n\"nodes\":[")?;
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i != 0 {
+                writer.write_str(",\n")?;
+            }
+
+            write!(
+                writer,
+                "{},{},{},{},{},0,0",
+                node.node_type as usize, node.name, node.id, node.self_size, node.edge_count
+            )?;
+        }
+
+        writer.write_str("],\n\"edges\":[")?;
+        for (i, edge) in self.edges.iter().enumerate() {
+            if i != 0 {
+                writer.write_str(",\n")?;
+            }
+
+            write!(
+                writer,
+                "{},{},{}",
+                edge.edge_type as usize,
+                edge.name_or_index,
+                edge.to_node * NODE_FIELDS.len()
+            )?;
+        }
+
+        writer.write_str(
+            "],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\
+             \n\"strings\":[",
+        )?;
+        for (i, string) in self.strings.iter().enumerate() {
+            if i != 0 {
+                writer.write_str(",\n")?;
+            }
+
+            write_json_string(writer, string)?;
+        }
+
+        writer.write_str("]}\n")
+    }
+}
+
+impl Context {
+    /// Write a snapshot of the heap in the V8 `.heapsnapshot` JSON format, which can be loaded in
+    /// the Memory tab of Chrome DevTools.
+    ///
+    /// Runs a full GC first so that only live objects are included in the snapshot.
+    pub fn write_heap_snapshot(&mut self, writer: &mut impl Write) -> fmt::Result {
+        self.run_gc();
+
+        let mut cx = *self;
+        let mut snapshot = HeapSnapshot::new();
+
+        // The first node is the synthetic root, with an edge to every GC root
+        snapshot.add_node(NodeType::Synthetic, String::from("(GC roots)"), 0);
+
+        // Create a node for every heap item. Nothing is allocated on the heap while the snapshot is
+        // built, so addresses are stable. Visiting pointers does not write to any heap item, so the
+        // write barrier is paused to keep visited objects out of the remembered set.
+        cx.heap.pause_write_barrier();
+
+        let mut items = Vec::new();
+        cx.heap.for_each_object(|header| {
+            items.push((header.object_ptr() as usize, header.total_size()));
+        });
+
+        for (index, (address, _)) in items.iter().enumerate() {
+            snapshot.node_indices.insert(*address, index + 1);
+        }
+
+        let mut root_collector = PointerCollector {
+            pointers: Vec::new(),
+        };
+        self.visit_roots_for_gc(&mut root_collector);
+
+        for (index, (address, _)) in root_collector.pointers.into_iter().enumerate() {
+            snapshot.add_edge(EdgeType::Element, index, address);
+        }
+
+        // Objects are named after the class whose prototype is on their prototype chain
+        let mut class_names = HashMap::new();
+        for (address, _) in &items {
+            let item = HeapPtr::<AnyHeapItem>::from_ptr(*address as *mut AnyHeapItem);
+            if item.descriptor().kind() == HeapItemKind::Closure {
+                if let Some((name, prototype)) = class_name_and_prototype(cx, item.cast()) {
+                    let name = string_name(name);
+                    if !name.is_empty() {
+                        class_names.insert(prototype.as_ptr() as usize, name);
+                    }
+                }
+            }
+        }
+
+        for (address, self_size) in items {
+            let item = HeapPtr::<AnyHeapItem>::from_ptr(address as *mut AnyHeapItem);
+            let (node_type, name) = node_type_and_name(item, &class_names);
+            snapshot.add_node(node_type, name, self_size);
+            snapshot.add_item_edges(item);
+        }
+
+        cx.heap.resume_write_barrier();
+
+        snapshot.write(writer)
+    }
+}
+
+/// The node type and name to use for a heap item. Objects are named after their class, while
+/// non-object heap items are named after their kind.
+fn node_type_and_name(
+    item: HeapPtr<AnyHeapItem>,
+    class_names: &HashMap<usize, String>,
+) -> (NodeType, String) {
+    let kind = item.descriptor().kind();
+    match kind {
+        HeapItemKind::String => (NodeType::String, string_name(item.cast::<StringValue>())),
+        HeapItemKind::Symbol => {
+            let description = item
+                .cast::<SymbolValue>()
+                .description_ptr()
+                .map(|description| format!("{}", description))
+                .unwrap_or_default();
+            (NodeType::Symbol, format!("Symbol({})", description))
+        }
+        HeapItemKind::BigInt => (NodeType::BigInt, String::from("bigint")),
+        HeapItemKind::Closure => {
+            let function = item.cast::<Closure>().function_ptr();
+            let name = function
+                .name_ptr()
+                .map(string_name)
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| String::from("(anonymous)"));
+            (NodeType::Closure, name)
+        }
+        HeapItemKind::BytecodeFunction => {
+            let name = item
+                .cast::<BytecodeFunction>()
+                .name_ptr()
+                .map(string_name)
+                .unwrap_or_default();
+            (NodeType::Code, format!("(code) {}", name))
+        }
+        HeapItemKind::Shape => (NodeType::ObjectShape, String::from("system / Shape")),
+        HeapItemKind::RegExpObject => (NodeType::RegExp, String::from("RegExp")),
+        HeapItemKind::ValueArray
+        | HeapItemKind::ByteArray
+        | HeapItemKind::U32Array
+        | HeapItemKind::ValueVec
+        | HeapItemKind::DenseArrayProperties => (NodeType::Array, format!("({:?})", kind)),
+        _ if item.descriptor().is_object() => {
+            let object = item.cast::<ObjectValue>();
+            let name = class_name(object, class_names).unwrap_or_else(|| default_object_name(kind));
+            (NodeType::Object, name)
+        }
+        _ => (NodeType::Hidden, format!("system / {:?}", kind)),
+    }
+}
+
+/// Find the name of an object's class by searching its prototype chain for the prototype of a
+/// class, given a map from the address of each class prototype to the name of its class.
+fn class_name(
+    object: HeapPtr<ObjectValue>,
+    class_names: &HashMap<usize, String>,
+) -> Option<String> {
+    let mut current = object.prototype();
+    for _ in 0..MAX_CLASS_SEARCH_DEPTH {
+        let prototype = current?;
+        if let Some(name) = class_names.get(&(prototype.as_ptr() as usize)) {
+            return Some(name.clone());
+        }
+
+        current = prototype.prototype();
+    }
+
+    None
+}
+
+fn default_object_name(kind: HeapItemKind) -> String {
+    match kind {
+        HeapItemKind::OrdinaryObject | HeapItemKind::ObjectPrototype => String::from("Object"),
+        HeapItemKind::ArrayObject => String::from("Array"),
+        HeapItemKind::Proxy => String::from("Proxy"),
+        _ => format!("{:?}", kind),
+    }
+}
+
+fn string_name(string: HeapPtr<StringValue>) -> String {
+    if !string.is_flat() {
+        return String::from("(concatenated string)");
+    }
+
+    let contents = format!("{}", string.as_flat());
+    match contents.char_indices().nth(MAX_STRING_NAME_LENGTH) {
+        Some((end, _)) => String::from(&contents[..end]),
+        None => contents,
+    }
+}
+
+fn property_key_name(key: &PropertyKey) -> String {
+    if key.is_symbol() {
+        let description = key
+            .as_symbol()
+            .description_ptr()
+            .map(|description| format!("{}", description))
+            .unwrap_or_default();
+        format!("Symbol({})", description)
+    } else {
+        string_name(key.as_string())
+    }
+}
+
+#[inline]
+fn value_address(value: Value) -> usize {
+    value.as_object().as_ptr() as usize
+}
+
+fn write_json_string_array(writer: &mut impl Write, strings: &[&str]) -> fmt::Result {
+    writer.write_char('[')?;
+    for (i, string) in strings.iter().enumerate() {
+        if i != 0 {
+            writer.write_char(',')?;
+        }
+
+        write_json_string(writer, string)?;
+    }
+    writer.write_char(']')
+}
+
+pub(crate) fn write_json_string(writer: &mut impl Write, string: &str) -> fmt::Result {
+    writer.write_char('"')?;
+    for c in string.chars() {
+        match c {
+            '"' => writer.write_str("\\\"")?,
+            '\\' => writer.write_str("\\\\")?,
+            '\n' => writer.write_str("\\n")?,
+            '\r' => writer.write_str("\\r")?,
+            '\t' => writer.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(writer, "\\u{:04x}", c as u32)?,
+            c => writer.write_char(c)?,
+        }
+    }
+    writer.write_char('"')
+}
@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use so2js_gc::GcCycleStats;
+
+use super::{gc::AnyHeapItem, heap_item_descriptor::HeapItemKind, Context, HeapPtr};
+
+/// A snapshot of the objects in the heap and of recent garbage collection cycles.
+pub struct HeapStatistics {
+    /// Number of bytes allocated in the old generation, including object headers
+    pub bytes_allocated: usize,
+    /// Number of bytes allocated in the nursery, including object headers
+    pub nursery_bytes_allocated: usize,
+    /// Number of objects in the heap, including young objects
+    pub num_objects: usize,
+    pub num_major_gcs: usize,
+    pub num_minor_gcs: usize,
+    /// Longest single GC pause observed so far in milliseconds, for both major and minor GCs
+    pub max_pause_millis: f64,
+    /// Count and size of objects for each kind of heap item with at least one object, sorted by
+    /// total size from largest to smallest.
+    pub kinds: Vec<HeapKindStatistics>,
+    /// Stats for the most recently completed GC cycles, oldest first
+    pub gc_history: Vec<GcCycleStats>,
+}
+
+/// Count and total size of all objects of a single heap item kind.
+#[derive(Clone, Copy, Debug)]
+pub struct HeapKindStatistics {
+    pub kind: HeapItemKind,
+    pub count: usize,
+    /// Total size of all objects of this kind, including object headers
+    pub bytes: usize,
+}
+
+impl HeapStatistics {
+    /// Walk the heap and collect statistics for every allocated object.
+    ///
+    /// Objects that are unreachable but have not been collected yet are included, so a GC should
+    /// be run first for exact counts of live objects.
+    pub fn collect(mut cx: Context) -> HeapStatistics {
+        let mut kinds: Vec<HeapKindStatistics> = Vec::new();
+        let mut num_objects = 0;
+
+        cx.heap.for_each_object(|header| {
+            let item = HeapPtr::<AnyHeapItem>::from_ptr(header.object_ptr() as *mut AnyHeapItem);
+            let kind = item.descriptor().kind();
+
+            let kind_index = kind as usize;
+            if kind_index >= kinds.len() {
+                kinds.resize(
+                    kind_index + 1,
+                    HeapKindStatistics {
+                        kind: HeapItemKind::Last,
+                        count: 0,
+                        bytes: 0,
+                    },
+                );
+            }
+
+            let kind_stats = &mut kinds[kind_index];
+            kind_stats.kind = kind;
+            kind_stats.count += 1;
+            kind_stats.bytes += header.total_size();
+
+            num_objects += 1;
+        });
+
+        kinds.retain(|kind_stats| kind_stats.count > 0);
+        kinds.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+
+        HeapStatistics {
+            bytes_allocated: cx.heap.bytes_allocated(),
+            nursery_bytes_allocated: cx.heap.nursery_bytes_allocated(),
+            num_objects,
+            num_major_gcs: cx.heap.num_major_gcs,
+            num_minor_gcs: cx.heap.num_minor_gcs,
+            max_pause_millis: cx.heap.max_pause_millis,
+            kinds,
+            gc_history: cx.heap.gc_history().iter().copied().collect(),
+        }
+    }
+
+    /// Get the statistics for a single heap item kind, if there are any objects of that kind.
+    pub fn kind(&self, kind: HeapItemKind) -> Option<&HeapKindStatistics> {
+        self.kinds.iter().find(|kind_stats| kind_stats.kind == kind)
+    }
+}
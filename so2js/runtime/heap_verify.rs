@@ -0,0 +1,72 @@
+//! Heap verification, used to find GC bugs such as missing write barriers and missing visits in
+//! tracing code. Only available with the `heap_verify` feature.
+//!
+//! The heap is verified automatically after marking and after every GC cycle, panicking with a
+//! description of the first problem found. It can also be verified on demand with
+//! `Context::verify_heap`.
+
+use alloc::{format, string::String};
+
+use so2js_gc::{HeapVerifyError, HeapVerifyProblem};
+
+use super::{gc::AnyHeapItem, Context, HeapPtr};
+
+impl Context {
+    /// Verify the heap, returning a description of the first problem found.
+    pub fn verify_heap(&mut self) -> Result<(), String> {
+        let heap = &mut self.heap;
+        let cx: *mut Context = self;
+
+        // SAFETY: same aliasing rationale as run_gc.
+        let result = unsafe { heap.verify(&mut *cx) };
+
+        result.map_err(|error| self.describe_heap_verify_error(&error))
+    }
+
+    /// Describe a problem found by the heap verifier, including the kind of the object containing
+    /// the bad pointer and the offset of the pointer within that object.
+    pub fn describe_heap_verify_error(&self, error: &HeapVerifyError) -> String {
+        let problem = match error.problem {
+            HeapVerifyProblem::InvalidHeader => String::from("invalid GC header"),
+            HeapVerifyProblem::InvalidObject(message) => format!("invalid object ({})", message),
+            HeapVerifyProblem::DanglingPointer => {
+                format!("dangling pointer to {:p}", error.target)
+            }
+            HeapVerifyProblem::BlackToWhitePointer => {
+                format!("black object points to white object {:p}", error.target)
+            }
+        };
+
+        let location = match error.source {
+            // The descriptors of invalid objects cannot be trusted
+            Some(source)
+                if matches!(
+                    error.problem,
+                    HeapVerifyProblem::InvalidHeader | HeapVerifyProblem::InvalidObject(_)
+                ) =>
+            {
+                format!("object {:p}", source)
+            }
+            Some(source) => {
+                let item = HeapPtr::<AnyHeapItem>::from_ptr(source as *mut AnyHeapItem);
+                let kind = item.descriptor().kind();
+
+                match error.field_offset() {
+                    Some(offset) => {
+                        format!(
+                            "field at offset {} of {:?} object {:p}",
+                            offset, kind, source
+                        )
+                    }
+                    None => format!("{:?} object {:p}", kind, source),
+                }
+            }
+            None if self.handle_context.contains_handle(error.field) => {
+                format!("StackRootContext handle {:p}", error.field)
+            }
+            None => format!("root {:p}", error.field),
+        };
+
+        format!("heap verification failed: {} in {}", problem, location)
+    }
+}
@@ -0,0 +1,439 @@
+use core::{cmp::Ordering, mem::size_of};
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+};
+
+use icu_collator::{
+    options::{AlternateHandling, CaseLevel, CollatorOptions, Strength},
+    preferences::{CollationCaseFirst, CollationNumericOrdering, CollationType},
+    Collator, CollatorPreferences,
+};
+use icu_locale::{extensions::unicode::key, Locale};
+
+use crate::{
+    cast_from_value_fn,
+    common::icu_data::BakedDataProvider,
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::{FlatString, StringValue},
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale,
+        supported_locales_of, RelevantExtensionKey,
+    },
+    options::{
+        coerce_options_to_object, get_boolean_option, get_enum_option, get_string_option,
+        IntlEnum,
+    },
+};
+
+intl_enum! {
+    pub enum CollatorUsage {
+        Sort => "sort",
+        Search => "search",
+    }
+}
+
+intl_enum! {
+    pub enum CollatorSensitivity {
+        Base => "base",
+        Accent => "accent",
+        Case => "case",
+        Variant => "variant",
+    }
+}
+
+intl_enum! {
+    pub enum CollatorCaseFirst {
+        Upper => "upper",
+        Lower => "lower",
+        False => "false",
+    }
+}
+
+/// The resolved locale and options of a collator. Identifies the ICU collator that is used to
+/// compare strings, so is also used as the key for the cache of ICU collators.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CollatorConfig {
+    pub locale: String,
+    pub usage: CollatorUsage,
+    pub sensitivity: CollatorSensitivity,
+    pub ignore_punctuation: bool,
+    pub collation: String,
+    pub numeric: bool,
+    pub case_first: CollatorCaseFirst,
+}
+
+impl CollatorConfig {
+    /// Create a new ICU collator for this config.
+    fn create_icu_collator(&self) -> Collator {
+        let locale = Locale::try_from_str(&self.locale).unwrap_or_default();
+
+        let mut preferences = CollatorPreferences::from(&locale);
+        preferences.collation_type = match self.collation.as_str() {
+            "emoji" => Some(CollationType::Emoji),
+            "eor" => Some(CollationType::Eor),
+            _ => None,
+        };
+        preferences.numeric_ordering = Some(if self.numeric {
+            CollationNumericOrdering::True
+        } else {
+            CollationNumericOrdering::False
+        });
+        preferences.case_first = Some(match self.case_first {
+            CollatorCaseFirst::Upper => CollationCaseFirst::Upper,
+            CollatorCaseFirst::Lower => CollationCaseFirst::Lower,
+            CollatorCaseFirst::False => CollationCaseFirst::False,
+        });
+
+        let mut options = CollatorOptions::default();
+        options.strength = Some(match self.sensitivity {
+            CollatorSensitivity::Base | CollatorSensitivity::Case => Strength::Primary,
+            CollatorSensitivity::Accent => Strength::Secondary,
+            CollatorSensitivity::Variant => Strength::Tertiary,
+        });
+        if self.sensitivity == CollatorSensitivity::Case {
+            options.case_level = Some(CaseLevel::On);
+        }
+        if self.ignore_punctuation {
+            options.alternate_handling = Some(AlternateHandling::Shifted);
+        }
+
+        // Search collation is a tailoring which may not be available for every locale, in which
+        // case fall back to the standard collation for the locale.
+        if self.usage == CollatorUsage::Search {
+            let mut search_preferences = preferences;
+            search_preferences.collation_type = Some(CollationType::Search);
+
+            if let Ok(collator) =
+                Collator::try_new_unstable(&BakedDataProvider, search_preferences, options)
+            {
+                return collator;
+            }
+        }
+
+        Collator::try_new_unstable(&BakedDataProvider, preferences, options)
+            .or_else(|_| {
+                Collator::try_new_unstable(&BakedDataProvider, Default::default(), options)
+            })
+            .unwrap()
+    }
+
+    /// Return the ICU collator for this config, creating and caching it if necessary.
+    pub fn icu_collator(&self, mut cx: Context) -> Rc<Collator> {
+        if let Some(collator) = cx.intl_cache.collators.get(self) {
+            return collator.clone();
+        }
+
+        let collator = Rc::new(self.create_icu_collator());
+        cx.intl_cache
+            .collators
+            .insert(self.clone(), collator.clone());
+
+        collator
+    }
+
+    /// Compare two strings using the collator for this config.
+    pub fn compare(
+        &self,
+        cx: Context,
+        x: StackRoot<StringValue>,
+        y: StackRoot<StringValue>,
+    ) -> AllocResult<Ordering> {
+        let x = x.to_wtf8_string(cx)?;
+        let y = y.to_wtf8_string(cx)?;
+
+        let collator = self.icu_collator(cx);
+
+        Ok(collator
+            .as_borrowed()
+            .compare_utf8(x.as_bytes(), y.as_bytes()))
+    }
+}
+
+/// Collations that can be requested with the `co` extension key or `collation` option. The
+/// "standard" and "search" collations are not allowed, and the first value is the default.
+pub const SUPPORTED_COLLATIONS: &[&str] = &["default", "emoji", "eor"];
+
+/// InitializeCollator (https://tc39.es/ecma402/#sec-initializecollator)
+///
+/// Resolve the locale and options of a collator from the `locales` and `options` arguments.
+pub fn resolve_collator_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<CollatorConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = coerce_options_to_object(cx, options)?;
+
+    let usage = get_enum_option(cx, options, cx.names.usage(), CollatorUsage::Sort)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let collation = get_string_option(cx, options, cx.names.collation(), &[])?;
+    if let Some(collation) = &collation {
+        if !is_unicode_type_sequence(collation) {
+            return range_error(cx, "invalid collation");
+        }
+    }
+
+    let numeric = get_boolean_option(cx, options, cx.names.numeric())?;
+    let case_first = get_string_option(
+        cx,
+        options,
+        cx.names.case_first(),
+        CollatorCaseFirst::VALUES,
+    )?;
+
+    let relevant_keys = [
+        RelevantExtensionKey {
+            key: key!("co"),
+            option_value: collation,
+            supported_values: |_| SUPPORTED_COLLATIONS,
+        },
+        RelevantExtensionKey {
+            key: key!("kf"),
+            option_value: case_first,
+            supported_values: |_| &["false", "upper", "lower"],
+        },
+        RelevantExtensionKey {
+            key: key!("kn"),
+            option_value: numeric.map(|numeric| numeric.to_string()),
+            supported_values: |_| &["false", "true"],
+        },
+    ];
+
+    let resolved = resolve_locale(cx, &requested_locales, &relevant_keys);
+
+    let case_first =
+        CollatorCaseFirst::from_str(&resolved.values[1]).unwrap_or(CollatorCaseFirst::False);
+    let numeric = resolved.values[2] == "true";
+
+    let sensitivity = get_enum_option(
+        cx,
+        options,
+        cx.names.sensitivity(),
+        CollatorSensitivity::Variant,
+    )?;
+
+    let ignore_punctuation =
+        get_boolean_option(cx, options, cx.names.ignore_punctuation())?.unwrap_or(false);
+
+    Ok(CollatorConfig {
+        locale: resolved.locale,
+        usage,
+        sensitivity,
+        ignore_punctuation,
+        collation: resolved.values[0].clone(),
+        numeric,
+        case_first,
+    })
+}
+
+/// Whether a string matches the Unicode `type` production, i.e. one or more `-` separated
+/// alphanumeric subtags of 3 to 8 characters.
+pub fn is_unicode_type_sequence(string: &str) -> bool {
+    string.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+// Intl.Collator Objects (https://tc39.es/ecma402/#collator-objects)
+extend_object! {
+    pub struct CollatorObject {
+        locale: HeapPtr<FlatString>,
+        usage: CollatorUsage,
+        sensitivity: CollatorSensitivity,
+        ignore_punctuation: bool,
+        collation: HeapPtr<FlatString>,
+        numeric: bool,
+        case_first: CollatorCaseFirst,
+        // The compare function returned by the `compare` getter, created on first access
+        bound_compare: Option<HeapPtr<ObjectValue>>,
+    }
+}
+
+impl CollatorObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &CollatorConfig,
+    ) -> AllocResult<StackRoot<CollatorObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+        let collation = cx.alloc_string(&config.collation)?;
+
+        let mut object =
+            object_create_with_proto::<CollatorObject>(cx, HeapItemKind::CollatorObject, proto)?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.usage, config.usage);
+        set_uninit!(object.sensitivity, config.sensitivity);
+        set_uninit!(object.ignore_punctuation, config.ignore_punctuation);
+        set_uninit!(object.collation, *collation);
+        set_uninit!(object.numeric, config.numeric);
+        set_uninit!(object.case_first, config.case_first);
+        set_uninit!(object.bound_compare, None);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(CollatorObject, "Intl.Collator");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn usage(&self) -> CollatorUsage {
+        self.usage
+    }
+
+    pub fn sensitivity(&self) -> CollatorSensitivity {
+        self.sensitivity
+    }
+
+    pub fn ignore_punctuation(&self) -> bool {
+        self.ignore_punctuation
+    }
+
+    pub fn collation(&self) -> HeapPtr<FlatString> {
+        self.collation
+    }
+
+    pub fn numeric(&self) -> bool {
+        self.numeric
+    }
+
+    pub fn case_first(&self) -> CollatorCaseFirst {
+        self.case_first
+    }
+
+    pub fn bound_compare(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.bound_compare
+    }
+
+    pub fn set_bound_compare(&mut self, bound_compare: HeapPtr<ObjectValue>) {
+        self.bound_compare = Some(bound_compare);
+    }
+
+    pub fn config(&self) -> CollatorConfig {
+        CollatorConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            usage: self.usage,
+            sensitivity: self.sensitivity,
+            ignore_punctuation: self.ignore_punctuation,
+            collation: self.collation.to_wtf8_string().to_string(),
+            numeric: self.numeric,
+            case_first: self.case_first,
+        }
+    }
+}
+
+pub struct CollatorConstructor;
+
+impl CollatorConstructor {
+    /// Properties of the Intl.Collator Constructor (https://tc39.es/ecma402/#sec-properties-of-the-intl-collator-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.collator(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::CollatorPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.Collator (https://tc39.es/ecma402/#sec-intl.collator)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        // Collator may be called as a function, in which case the active function is used
+        let new_target = match cx.current_new_target() {
+            Some(new_target) => new_target,
+            None => cx.current_function(),
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::CollatorPrototype)?;
+        let config = resolve_collator_config(cx, locales, options)?;
+
+        Ok(CollatorObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.Collator.supportedLocalesOf (https://tc39.es/ecma402/#sec-intl.collator.supportedlocalesof)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<CollatorObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<CollatorObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+        visitor.visit_pointer(&mut self.collation);
+        visitor.visit_pointer_opt(&mut self.bound_compare);
+    }
+}
+
+/// Compare two strings using the collator for the given locales and options, as used by
+/// `String.prototype.localeCompare`.
+pub fn compare_strings_with_locales(
+    cx: Context,
+    x: StackRoot<StringValue>,
+    y: StackRoot<StringValue>,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<Ordering> {
+    let config = resolve_collator_config(cx, locales, options)?;
+    Ok(config.compare(cx, x, y)?)
+}
@@ -0,0 +1,178 @@
+use core::cmp::Ordering;
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw, alloc_error::AllocResult,
+        builtin_function::BuiltinFunction, eval_result::EvalResult, function::get_argument,
+        intrinsics::intrinsics::Intrinsic, object_value::ObjectValue,
+        ordinary_object::ordinary_object_create, property::Property, realm::Realm, to_string,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{collator_constructor::CollatorObject, options::IntlEnum};
+
+pub struct CollatorPrototype;
+
+impl CollatorPrototype {
+    /// Properties of the Intl.Collator Prototype Object (https://tc39.es/ecma402/#sec-properties-of-the-intl-collator-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once CollatorConstructor has been created
+        object.intrinsic_getter(cx, cx.names.compare(), Self::get_compare, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.Collator")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Intl.Collator.prototype.compare (https://tc39.es/ecma402/#sec-intl.collator.prototype.compare)
+    pub fn get_compare(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut collator = CollatorObject::cast_from_value(cx, this_value)?;
+
+        if let Some(bound_compare) = collator.bound_compare() {
+            return Ok(bound_compare.to_stack(cx).as_value());
+        }
+
+        let mut bound_compare = BuiltinFunction::create(
+            cx,
+            Self::collator_compare,
+            2,
+            cx.names.empty_string(),
+            cx.current_realm(),
+            None,
+        )?;
+
+        bound_compare.private_element_set(
+            cx,
+            cx.well_known_symbols.intl_object().cast(),
+            collator.as_value(),
+        )?;
+
+        collator.set_bound_compare(*bound_compare);
+
+        Ok(bound_compare.as_value())
+    }
+
+    /// Collator Compare Functions (https://tc39.es/ecma402/#sec-collator-compare-functions)
+    pub fn collator_compare(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let collator = cx
+            .current_function()
+            .private_element_find(cx, cx.well_known_symbols.intl_object().cast())
+            .unwrap()
+            .value()
+            .as_object()
+            .cast::<CollatorObject>();
+
+        let x_arg = get_argument(cx, arguments, 0);
+        let x = to_string(cx, x_arg)?;
+
+        let y_arg = get_argument(cx, arguments, 1);
+        let y = to_string(cx, y_arg)?;
+
+        let comparison = match collator.config().compare(cx, x, y)? {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        Ok(cx.smi(comparison))
+    }
+
+    /// Intl.Collator.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-intl.collator.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let collator = CollatorObject::cast_from_value(cx, this_value)?;
+
+        let object = ordinary_object_create(cx)?;
+
+        let locale = collator.locale().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.locale_(),
+            locale
+        ));
+
+        let usage = cx.alloc_string(collator.usage().as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.usage(),
+            usage
+        ));
+
+        let sensitivity = cx.alloc_string(collator.sensitivity().as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.sensitivity(),
+            sensitivity
+        ));
+
+        let ignore_punctuation = cx.bool(collator.ignore_punctuation());
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.ignore_punctuation(),
+            ignore_punctuation
+        ));
+
+        let collation = collator.collation().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.collation(),
+            collation
+        ));
+
+        let numeric = cx.bool(collator.numeric());
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.numeric(),
+            numeric
+        ));
+
+        let case_first = cx.alloc_string(collator.case_first().as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.case_first(),
+            case_first
+        ));
+
+        Ok(object.as_value())
+    }
+}
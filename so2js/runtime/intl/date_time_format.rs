@@ -0,0 +1,515 @@
+use alloc::{format, rc::Rc, string::String, vec, vec::Vec};
+
+use icu_calendar::{Date, Gregorian};
+use icu_datetime::{
+    fieldsets::{
+        builder::{DateFields, FieldSetBuilder},
+        enums::CompositeDateTimeFieldSet,
+    },
+    options::{Alignment, Length, SubsecondDigits, TimePrecision, YearStyle},
+    parts, DateTimeFormatterPreferences, FixedCalendarDateTimeFormatter,
+};
+use icu_decimal::parts as decimal_parts;
+use icu_locale::Locale;
+use icu_time::{DateTime, Time};
+use writeable::Part;
+
+use crate::{
+    common::{
+        icu_data::BakedDataProvider,
+        time_zone::{TimeZone, TimeZoneOffset},
+    },
+    intl_enum,
+    runtime::{
+        intrinsics::date_object::{
+            date_from_time, hour_from_time, millisecond_from_time, minute_from_time,
+            month_from_time, second_from_time, year_from_time, MS_PER_MINUTE,
+        },
+        Context,
+    },
+};
+
+use super::parts::{join_parts, FormattedPart, PartsCollector, RangePartSource};
+
+intl_enum! {
+    /// The `dateStyle` and `timeStyle` options.
+    pub enum DateTimeStyle {
+        Full => "full",
+        Long => "long",
+        Medium => "medium",
+        Short => "short",
+    }
+}
+
+intl_enum! {
+    pub enum HourCycle {
+        H11 => "h11",
+        H12 => "h12",
+        H23 => "h23",
+        H24 => "h24",
+    }
+}
+
+intl_enum! {
+    /// The styles of the `weekday`, `era`, and `dayPeriod` options.
+    pub enum TextStyle {
+        Narrow => "narrow",
+        Short => "short",
+        Long => "long",
+    }
+}
+
+intl_enum! {
+    /// The styles of the `year`, `day`, `hour`, `minute`, and `second` options.
+    pub enum NumericStyle {
+        Numeric => "numeric",
+        TwoDigit => "2-digit",
+    }
+}
+
+intl_enum! {
+    pub enum MonthStyle {
+        Numeric => "numeric",
+        TwoDigit => "2-digit",
+        Narrow => "narrow",
+        Short => "short",
+        Long => "long",
+    }
+}
+
+intl_enum! {
+    pub enum TimeZoneNameStyle {
+        Short => "short",
+        Long => "long",
+        ShortOffset => "shortOffset",
+        LongOffset => "longOffset",
+        ShortGeneric => "shortGeneric",
+        LongGeneric => "longGeneric",
+    }
+}
+
+/// The date and time component options of a date time format, which are only present if the
+/// component is included in formatted dates.
+///
+/// Table 16: Components of date and time formats (https://tc39.es/ecma402/#table-datetimeformat-components)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DateTimeComponents {
+    pub weekday: Option<TextStyle>,
+    pub era: Option<TextStyle>,
+    pub year: Option<NumericStyle>,
+    pub month: Option<MonthStyle>,
+    pub day: Option<NumericStyle>,
+    pub day_period: Option<TextStyle>,
+    pub hour: Option<NumericStyle>,
+    pub minute: Option<NumericStyle>,
+    pub second: Option<NumericStyle>,
+    pub fractional_second_digits: Option<u8>,
+    pub time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+impl DateTimeComponents {
+    pub fn has_date_fields(&self) -> bool {
+        self.weekday.is_some() || self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    pub fn has_time_fields(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+
+    /// Whether any component was requested, in which case `dateStyle` and `timeStyle` may not be
+    /// used.
+    pub fn has_explicit_format_components(&self) -> bool {
+        self.has_date_fields()
+            || self.has_time_fields()
+            || self.era.is_some()
+            || self.time_zone_name.is_some()
+    }
+}
+
+/// The resolved options of a date time format, other than its locale, calendar, numbering system,
+/// and time zone.
+///
+/// Either the date and time styles or the components are used, but not both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeFormatOptions {
+    /// Only present if the format includes an hour.
+    pub hour_cycle: Option<HourCycle>,
+    pub date_style: Option<DateTimeStyle>,
+    pub time_style: Option<DateTimeStyle>,
+    pub components: DateTimeComponents,
+}
+
+impl DateTimeFormatOptions {
+    /// The style of the time zone name included in formatted dates, if any.
+    fn time_zone_name_style(&self) -> Option<TimeZoneNameStyle> {
+        match self.time_style {
+            Some(DateTimeStyle::Full) => Some(TimeZoneNameStyle::Long),
+            Some(DateTimeStyle::Long) => Some(TimeZoneNameStyle::Short),
+            Some(_) => None,
+            None => self.components.time_zone_name,
+        }
+    }
+}
+
+/// The resolved locale and options of a date time format. Identifies the ICU formatter that is
+/// used to format dates, so is also used as the key for the cache of ICU date time formatters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeFormatConfig {
+    pub locale: String,
+    pub calendar: String,
+    pub numbering_system: String,
+    /// Canonicalized time zone identifier, see `canonicalize_time_zone`.
+    pub time_zone: String,
+    pub options: DateTimeFormatOptions,
+}
+
+impl DateTimeFormatConfig {
+    /// Create the ICU formatter for this config.
+    fn create_icu_formatter(&self, cx: Context) -> DateTimeFormatter {
+        // The formatter uses the calendar, hour cycle, and numbering system that were resolved,
+        // even if they were not requested with keywords.
+        let data_locale = self.locale.split("-u-").next().unwrap();
+        let mut locale_tag = format!("{data_locale}-u-ca-{}", self.calendar);
+        if let Some(hour_cycle) = self.options.hour_cycle {
+            // ICU has no 24 hour cycle starting at 1, so the closest alternative is used
+            let hour_cycle = match hour_cycle {
+                HourCycle::H24 => HourCycle::H23,
+                hour_cycle => hour_cycle,
+            };
+            locale_tag.push_str(&format!("-hc-{}", hour_cycle.as_str()));
+        }
+        locale_tag.push_str(&format!("-nu-{}", self.numbering_system));
+
+        let locale = Locale::try_from_str(&locale_tag).unwrap_or_default();
+        let preferences = DateTimeFormatterPreferences::from(&locale);
+
+        // Missing data results in dates formatting to an empty string rather than an error
+        let icu = self.create_field_set().and_then(|field_set| {
+            FixedCalendarDateTimeFormatter::try_new_unstable(
+                &BakedDataProvider,
+                preferences,
+                field_set,
+            )
+            .ok()
+        });
+
+        DateTimeFormatter {
+            config: self.clone(),
+            icu,
+            // Time zone was already validated when the config was resolved
+            time_zone: TimeZone::from_identifier(cx, &self.time_zone).unwrap_or(TimeZone::Utc),
+        }
+    }
+
+    /// The ICU field set that most closely matches the requested styles or components.
+    fn create_field_set(&self) -> Option<CompositeDateTimeFieldSet> {
+        let options = &self.options;
+        let mut builder = FieldSetBuilder::new();
+
+        if options.date_style.is_some() || options.time_style.is_some() {
+            let style = options.date_style.or(options.time_style).unwrap();
+            builder.length = Some(match style {
+                DateTimeStyle::Full | DateTimeStyle::Long => Length::Long,
+                DateTimeStyle::Medium => Length::Medium,
+                DateTimeStyle::Short => Length::Short,
+            });
+
+            builder.date_fields = options.date_style.map(|style| match style {
+                DateTimeStyle::Full => DateFields::YMDE,
+                _ => DateFields::YMD,
+            });
+
+            builder.time_precision = options.time_style.map(|style| match style {
+                DateTimeStyle::Short => TimePrecision::Minute,
+                _ => TimePrecision::Second,
+            });
+
+            return builder.build_composite_datetime().ok();
+        }
+
+        let components = &options.components;
+        let has_year = components.year.is_some() || components.era.is_some();
+
+        builder.date_fields = match (
+            has_year,
+            components.month.is_some(),
+            components.day.is_some(),
+            components.weekday.is_some(),
+        ) {
+            (false, false, false, false) => None,
+            (false, false, false, true) => Some(DateFields::E),
+            (false, false, true, false) => Some(DateFields::D),
+            (false, false, true, true) => Some(DateFields::DE),
+            (false, true, false, _) => Some(DateFields::M),
+            (false, true, true, false) => Some(DateFields::MD),
+            (false, true, true, true) => Some(DateFields::MDE),
+            (true, false, false, _) => Some(DateFields::Y),
+            (true, true, false, _) => Some(DateFields::YM),
+            (true, _, _, false) => Some(DateFields::YMD),
+            (true, _, _, true) => Some(DateFields::YMDE),
+        };
+
+        // Textual months and weekdays determine the length of the format
+        builder.length = Some(match (components.month, components.weekday) {
+            (Some(MonthStyle::Long), _) => Length::Long,
+            (Some(MonthStyle::Short | MonthStyle::Narrow), _) => Length::Medium,
+            (Some(_), _) => Length::Short,
+            (None, Some(TextStyle::Long)) => Length::Long,
+            (None, Some(TextStyle::Narrow)) => Length::Short,
+            (None, _) => Length::Medium,
+        });
+
+        if has_year {
+            builder.year_style = Some(match components.year {
+                _ if components.era.is_some() => YearStyle::WithEra,
+                Some(NumericStyle::TwoDigit) => YearStyle::Auto,
+                _ => YearStyle::Full,
+            });
+        }
+
+        builder.time_precision = match components.fractional_second_digits {
+            Some(1) => Some(TimePrecision::Subsecond(SubsecondDigits::S1)),
+            Some(2) => Some(TimePrecision::Subsecond(SubsecondDigits::S2)),
+            Some(_) => Some(TimePrecision::Subsecond(SubsecondDigits::S3)),
+            None if components.second.is_some() => Some(TimePrecision::Second),
+            None if components.minute.is_some() => Some(TimePrecision::Minute),
+            None if components.hour.is_some() || components.day_period.is_some() => {
+                Some(TimePrecision::Hour)
+            }
+            None => None,
+        };
+
+        let is_two_digit = [
+            components.day,
+            components.hour,
+            components.minute,
+            components.second,
+        ]
+        .contains(&Some(NumericStyle::TwoDigit));
+        if is_two_digit || components.month == Some(MonthStyle::TwoDigit) {
+            builder.alignment = Some(Alignment::Column);
+        }
+
+        // Not every combination of date fields can be used with a time, in which case the full
+        // date is included instead.
+        builder
+            .clone()
+            .build_composite_datetime()
+            .or_else(|_| {
+                builder.date_fields = Some(DateFields::YMD);
+                builder.build_composite_datetime()
+            })
+            .ok()
+    }
+
+    /// Return the ICU formatter for this config, creating and caching it if necessary.
+    pub fn icu_formatter(&self, mut cx: Context) -> Rc<DateTimeFormatter> {
+        if let Some(formatter) = cx.intl_cache.date_time_formatters.get(self) {
+            return formatter.clone();
+        }
+
+        let formatter = Rc::new(self.create_icu_formatter(cx));
+        cx.intl_cache
+            .date_time_formatters
+            .insert(self.clone(), formatter.clone());
+
+        formatter
+    }
+}
+
+/// The kind of a part written by an ICU date time formatter, determined by the outermost date time
+/// field it was written in.
+fn date_time_part_kind(part_stack: &[Part]) -> &'static str {
+    match part_stack.first() {
+        Some(part) if *part == parts::ERA => "era",
+        Some(part) if *part == parts::YEAR => "year",
+        Some(part) if *part == parts::RELATED_YEAR => "relatedYear",
+        Some(part) if *part == parts::YEAR_NAME => "yearName",
+        Some(part) if *part == parts::MONTH => "month",
+        Some(part) if *part == parts::DAY => "day",
+        Some(part) if *part == parts::WEEKDAY => "weekday",
+        Some(part) if *part == parts::DAY_PERIOD => "dayPeriod",
+        Some(part) if *part == parts::HOUR => "hour",
+        Some(part) if *part == parts::MINUTE => "minute",
+        // Fractional seconds are written as the fraction of a decimal number of seconds
+        Some(part) if *part == parts::SECOND => match part_stack.last() {
+            Some(part) if *part == decimal_parts::DECIMAL => "literal",
+            Some(part) if *part == decimal_parts::FRACTION => "fractionalSecond",
+            _ => "second",
+        },
+        Some(part) if *part == parts::TIME_ZONE_NAME => "timeZoneName",
+        _ => "literal",
+    }
+}
+
+/// The ICU formatter for a date time format config, along with the config itself.
+pub struct DateTimeFormatter {
+    config: DateTimeFormatConfig,
+    icu: Option<FixedCalendarDateTimeFormatter<Gregorian, CompositeDateTimeFieldSet>>,
+    time_zone: TimeZone,
+}
+
+impl DateTimeFormatter {
+    /// FormatDateTimePattern (https://tc39.es/ecma402/#sec-formatdatetimepattern)
+    ///
+    /// The time value must already have been clipped and must not be NaN.
+    pub fn format_to_parts(&self, cx: Context, x: f64) -> Vec<FormattedPart> {
+        let time_zone_offset = self.time_zone.offset_at(cx, x);
+        let local_time = x + time_zone_offset.offset_millis as f64;
+
+        let date = Date::try_new_gregorian(
+            year_from_time(local_time) as i32,
+            month_from_time(local_time) as u8 + 1,
+            date_from_time(local_time) as u8,
+        );
+        let time = Time::try_new(
+            hour_from_time(local_time) as u8,
+            minute_from_time(local_time) as u8,
+            second_from_time(local_time) as u8,
+            millisecond_from_time(local_time) as u32 * 1_000_000,
+        );
+
+        let mut parts = match (&self.icu, date, time) {
+            (Some(formatter), Ok(date), Ok(time)) => {
+                let date_time = DateTime { date, time };
+                PartsCollector::collect(&formatter.format(&date_time), date_time_part_kind)
+            }
+            _ => vec![],
+        };
+
+        if let Some(style) = self.config.options.time_zone_name_style() {
+            if !parts.is_empty() {
+                parts.push(FormattedPart::new("literal", " "));
+            }
+
+            let time_zone_name = self.time_zone_name(style, &time_zone_offset);
+            parts.push(FormattedPart::new("timeZoneName", &time_zone_name));
+        }
+
+        parts
+    }
+
+    /// FormatDateTime (https://tc39.es/ecma402/#sec-formatdatetime)
+    pub fn format(&self, cx: Context, x: f64) -> String {
+        join_parts(&self.format_to_parts(cx, x))
+    }
+
+    /// PartitionDateTimeRangePattern (https://tc39.es/ecma402/#sec-partitiondatetimerangepattern)
+    ///
+    /// The time values must already have been clipped and must not be NaN.
+    pub fn format_range_to_parts(
+        &self,
+        cx: Context,
+        x: f64,
+        y: f64,
+    ) -> Vec<(FormattedPart, RangePartSource)> {
+        let x_parts = self.format_to_parts(cx, x);
+        let y_parts = self.format_to_parts(cx, y);
+
+        // Dates that are equal in every formatted field are only formatted once
+        if x_parts == y_parts {
+            return x_parts
+                .into_iter()
+                .map(|part| (part, RangePartSource::Shared))
+                .collect();
+        }
+
+        let mut parts: Vec<_> = x_parts
+            .into_iter()
+            .map(|part| (part, RangePartSource::StartRange))
+            .collect();
+        parts.push((
+            FormattedPart::new("literal", " – "),
+            RangePartSource::Shared,
+        ));
+        parts.extend(
+            y_parts
+                .into_iter()
+                .map(|part| (part, RangePartSource::EndRange)),
+        );
+
+        parts
+    }
+
+    /// FormatDateTimeRange (https://tc39.es/ecma402/#sec-formatdatetimerange)
+    pub fn format_range(&self, cx: Context, x: f64, y: f64) -> String {
+        self.format_range_to_parts(cx, x, y)
+            .iter()
+            .map(|(part, _)| part.value.as_str())
+            .collect()
+    }
+
+    /// The name of the time zone in the given style. Time zones are named by their offset from
+    /// GMT, e.g. "GMT+1" in short styles and "GMT+01:00" in long styles, except that named time
+    /// zones use their abbreviation in the short style when it is alphabetic, e.g. "EST".
+    fn time_zone_name(&self, style: TimeZoneNameStyle, offset: &TimeZoneOffset) -> String {
+        if self.config.time_zone == "UTC" {
+            return String::from("UTC");
+        }
+
+        if style == TimeZoneNameStyle::Short {
+            if let Some(abbreviation) = &offset.abbreviation {
+                if abbreviation.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+                    return abbreviation.clone();
+                }
+            }
+        }
+
+        let offset_minutes = offset.offset_millis / MS_PER_MINUTE as i64;
+        if offset_minutes == 0 {
+            return String::from("GMT");
+        }
+
+        let sign = if offset_minutes < 0 { '-' } else { '+' };
+        let hours = offset_minutes.abs() / 60;
+        let minutes = offset_minutes.abs() % 60;
+
+        match style {
+            TimeZoneNameStyle::Short
+            | TimeZoneNameStyle::ShortOffset
+            | TimeZoneNameStyle::ShortGeneric => {
+                if minutes == 0 {
+                    format!("GMT{sign}{hours}")
+                } else {
+                    format!("GMT{sign}{hours}:{minutes:02}")
+                }
+            }
+            TimeZoneNameStyle::Long
+            | TimeZoneNameStyle::LongOffset
+            | TimeZoneNameStyle::LongGeneric => format!("GMT{sign}{hours:02}:{minutes:02}"),
+        }
+    }
+}
+
+/// DefaultTimeZone (https://tc39.es/ecma402/#sec-defaulttimezone)
+pub fn default_time_zone(cx: Context) -> String {
+    cx.default_time_zone.identifier()
+}
+
+/// Calendars that can be requested with the `ca` extension key or `calendar` option, where the
+/// first is the default.
+pub fn supported_calendars(_: &str) -> &'static [&'static str] {
+    &["gregory"]
+}
+
+/// Hour cycles that can be requested with the `hc` extension key or `hourCycle` option in a data
+/// locale, where the first is the default hour cycle of the locale.
+pub fn supported_hour_cycles(data_locale: &str) -> &'static [&'static str] {
+    match data_locale {
+        "ar" | "en" | "hi" | "ko" => &["h12", "h11", "h23", "h24"],
+        _ => &["h23", "h11", "h12", "h24"],
+    }
+}
+
+/// The hour cycle used by a data locale when `hour12` is set to the given value.
+pub fn hour_cycle_for_hour12(data_locale: &str, hour12: bool) -> HourCycle {
+    match (data_locale, hour12) {
+        ("ja", true) => HourCycle::H11,
+        (_, true) => HourCycle::H12,
+        (_, false) => HourCycle::H23,
+    }
+}
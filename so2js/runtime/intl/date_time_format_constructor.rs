@@ -0,0 +1,424 @@
+use core::mem::size_of;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use icu_locale::extensions::unicode::key;
+
+use crate::{
+    cast_from_value_fn,
+    common::time_zone::canonicalize_time_zone,
+    extend_object,
+    runtime::{
+        abstract_operations::get,
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        type_utilities::to_string,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    collator_constructor::is_unicode_type_sequence,
+    date_time_format::{
+        default_time_zone, hour_cycle_for_hour12, supported_calendars, supported_hour_cycles,
+        DateTimeComponents, DateTimeFormatConfig, DateTimeFormatOptions, DateTimeStyle, HourCycle,
+        MonthStyle, NumericStyle,
+    },
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+        RelevantExtensionKey,
+    },
+    number_format_constructor::supported_numbering_systems,
+    options::{
+        coerce_options_to_object, get_boolean_option, get_number_option, get_optional_enum_option,
+        get_string_option, IntlEnum,
+    },
+};
+
+/// The kinds of components that must be present in a date time format.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RequiredComponents {
+    Date,
+    Time,
+    Any,
+}
+
+/// The kinds of components that are added to a date time format if no required components were
+/// requested.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DefaultComponents {
+    Date,
+    Time,
+    All,
+}
+
+/// CreateDateTimeFormat (https://tc39.es/ecma402/#sec-createdatetimeformat)
+///
+/// Resolve the locale and options of a date time format from the `locales` and `options`
+/// arguments.
+pub fn resolve_date_time_format_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+    required: RequiredComponents,
+    defaults: DefaultComponents,
+) -> EvalResult<DateTimeFormatConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = coerce_options_to_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let calendar = get_string_option(cx, options, cx.names.calendar(), &[])?;
+    if let Some(calendar) = &calendar {
+        if !is_unicode_type_sequence(calendar) {
+            return range_error(cx, "invalid calendar");
+        }
+    }
+
+    let numbering_system = get_string_option(cx, options, cx.names.numbering_system(), &[])?;
+    if let Some(numbering_system) = &numbering_system {
+        if !is_unicode_type_sequence(numbering_system) {
+            return range_error(cx, "invalid numbering system");
+        }
+    }
+
+    let hour12 = get_boolean_option(cx, options, cx.names.hour12())?;
+    let mut hour_cycle: Option<HourCycle> =
+        get_optional_enum_option(cx, options, cx.names.hour_cycle())?;
+
+    // The hour12 option overrides the hourCycle option
+    if hour12.is_some() {
+        hour_cycle = None;
+    }
+
+    let relevant_keys = [
+        RelevantExtensionKey {
+            key: key!("ca"),
+            option_value: calendar,
+            supported_values: supported_calendars,
+        },
+        RelevantExtensionKey {
+            key: key!("hc"),
+            option_value: hour_cycle.map(|hour_cycle| hour_cycle.as_str().to_string()),
+            supported_values: supported_hour_cycles,
+        },
+        RelevantExtensionKey {
+            key: key!("nu"),
+            option_value: numbering_system,
+            supported_values: supported_numbering_systems,
+        },
+    ];
+
+    let resolved = resolve_locale(cx, &requested_locales, &relevant_keys);
+    let data_locale = resolved.locale.split("-u-").next().unwrap();
+
+    let time_zone_value = get(cx, options, cx.names.time_zone())?;
+    let time_zone = if time_zone_value.is_undefined() {
+        default_time_zone(cx)
+    } else {
+        let time_zone = to_string(cx, time_zone_value)?.format(cx)?;
+        match canonicalize_time_zone(cx, &time_zone) {
+            Some(time_zone) => time_zone,
+            None => return range_error(cx, &format!("invalid time zone {time_zone}")),
+        }
+    };
+
+    // Components are read in the order of Table 16
+    let mut components = DateTimeComponents {
+        weekday: get_optional_enum_option(cx, options, cx.names.weekday())?,
+        era: get_optional_enum_option(cx, options, cx.names.era())?,
+        year: get_optional_enum_option(cx, options, cx.names.year())?,
+        month: get_optional_enum_option(cx, options, cx.names.month())?,
+        day: get_optional_enum_option(cx, options, cx.names.day())?,
+        day_period: get_optional_enum_option(cx, options, cx.names.day_period())?,
+        hour: get_optional_enum_option(cx, options, cx.names.hour())?,
+        minute: get_optional_enum_option(cx, options, cx.names.minute())?,
+        second: get_optional_enum_option(cx, options, cx.names.second())?,
+        fractional_second_digits: get_number_option(
+            cx,
+            options,
+            cx.names.fractional_second_digits(),
+            1.0,
+            3.0,
+        )?
+        .map(|digits| digits as u8),
+        time_zone_name: get_optional_enum_option(cx, options, cx.names.time_zone_name())?,
+    };
+
+    get_string_option(
+        cx,
+        options,
+        cx.names.format_matcher(),
+        &["basic", "best fit"],
+    )?;
+
+    let date_style: Option<DateTimeStyle> =
+        get_optional_enum_option(cx, options, cx.names.date_style())?;
+    let time_style: Option<DateTimeStyle> =
+        get_optional_enum_option(cx, options, cx.names.time_style())?;
+
+    let has_hour = if date_style.is_some() || time_style.is_some() {
+        if components.has_explicit_format_components() {
+            return type_error(
+                cx,
+                "dateStyle and timeStyle cannot be used with date time components",
+            );
+        }
+
+        if required == RequiredComponents::Date && time_style.is_some() {
+            return type_error(cx, "timeStyle cannot be used when formatting a date");
+        }
+
+        if required == RequiredComponents::Time && date_style.is_some() {
+            return type_error(cx, "dateStyle cannot be used when formatting a time");
+        }
+
+        time_style.is_some()
+    } else {
+        let need_defaults = match required {
+            RequiredComponents::Date => !components.has_date_fields(),
+            RequiredComponents::Time => !components.has_time_fields(),
+            RequiredComponents::Any => {
+                !components.has_date_fields() && !components.has_time_fields()
+            }
+        };
+
+        if need_defaults && defaults != DefaultComponents::Time {
+            components.year = Some(NumericStyle::Numeric);
+            components.month = Some(MonthStyle::Numeric);
+            components.day = Some(NumericStyle::Numeric);
+        }
+
+        if need_defaults && defaults != DefaultComponents::Date {
+            components.hour = Some(NumericStyle::Numeric);
+            components.minute = Some(NumericStyle::Numeric);
+            components.second = Some(NumericStyle::Numeric);
+        }
+
+        components.hour.is_some()
+    };
+
+    // The hour cycle is only resolved if the format includes an hour
+    let hour_cycle = if !has_hour {
+        None
+    } else if let Some(hour12) = hour12 {
+        Some(hour_cycle_for_hour12(data_locale, hour12))
+    } else {
+        HourCycle::from_str(&resolved.values[1])
+    };
+
+    Ok(DateTimeFormatConfig {
+        locale: resolved.locale,
+        calendar: resolved.values[0].clone(),
+        numbering_system: resolved.values[2].clone(),
+        time_zone,
+        options: DateTimeFormatOptions {
+            hour_cycle,
+            date_style,
+            time_style,
+            components,
+        },
+    })
+}
+
+// Intl.DateTimeFormat Objects (https://tc39.es/ecma402/#datetimeformat-objects)
+extend_object! {
+    pub struct DateTimeFormatObject {
+        locale: HeapPtr<FlatString>,
+        calendar: HeapPtr<FlatString>,
+        numbering_system: HeapPtr<FlatString>,
+        time_zone: HeapPtr<FlatString>,
+        options: DateTimeFormatOptions,
+        // The format function returned by the `format` getter, created on first access
+        bound_format: Option<HeapPtr<ObjectValue>>,
+    }
+}
+
+impl DateTimeFormatObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &DateTimeFormatConfig,
+    ) -> AllocResult<StackRoot<DateTimeFormatObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+        let calendar = cx.alloc_string(&config.calendar)?;
+        let numbering_system = cx.alloc_string(&config.numbering_system)?;
+        let time_zone = cx.alloc_string(&config.time_zone)?;
+
+        let mut object = object_create_with_proto::<DateTimeFormatObject>(
+            cx,
+            HeapItemKind::DateTimeFormatObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.calendar, *calendar);
+        set_uninit!(object.numbering_system, *numbering_system);
+        set_uninit!(object.time_zone, *time_zone);
+        set_uninit!(object.options, config.options);
+        set_uninit!(object.bound_format, None);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(DateTimeFormatObject, "Intl.DateTimeFormat");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn calendar(&self) -> HeapPtr<FlatString> {
+        self.calendar
+    }
+
+    pub fn numbering_system(&self) -> HeapPtr<FlatString> {
+        self.numbering_system
+    }
+
+    pub fn time_zone(&self) -> HeapPtr<FlatString> {
+        self.time_zone
+    }
+
+    pub fn options(&self) -> &DateTimeFormatOptions {
+        &self.options
+    }
+
+    pub fn bound_format(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.bound_format
+    }
+
+    pub fn set_bound_format(&mut self, bound_format: HeapPtr<ObjectValue>) {
+        self.bound_format = Some(bound_format);
+    }
+
+    pub fn config(&self) -> DateTimeFormatConfig {
+        DateTimeFormatConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            calendar: self.calendar.to_wtf8_string().to_string(),
+            numbering_system: self.numbering_system.to_wtf8_string().to_string(),
+            time_zone: self.time_zone.to_wtf8_string().to_string(),
+            options: self.options,
+        }
+    }
+}
+
+pub struct DateTimeFormatConstructor;
+
+impl DateTimeFormatConstructor {
+    /// Properties of the Intl.DateTimeFormat Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-datetimeformat-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.date_time_format(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::DateTimeFormatPrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.DateTimeFormat (https://tc39.es/ecma402/#sec-intl.datetimeformat)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        // DateTimeFormat may be called as a function, in which case the active function is used
+        let new_target = match cx.current_new_target() {
+            Some(new_target) => new_target,
+            None => cx.current_function(),
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::DateTimeFormatPrototype)?;
+        let config = resolve_date_time_format_config(
+            cx,
+            locales,
+            options,
+            RequiredComponents::Any,
+            DefaultComponents::Date,
+        )?;
+
+        Ok(DateTimeFormatObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.DateTimeFormat.supportedLocalesOf (https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<DateTimeFormatObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<DateTimeFormatObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+        visitor.visit_pointer(&mut self.calendar);
+        visitor.visit_pointer(&mut self.numbering_system);
+        visitor.visit_pointer(&mut self.time_zone);
+        visitor.visit_pointer_opt(&mut self.bound_format);
+    }
+}
+
+/// Format a time value using the date time format for the given locales and options, as used by
+/// the `toLocaleString`, `toLocaleDateString`, and `toLocaleTimeString` methods of Date.
+///
+/// The time value must not be NaN.
+pub fn format_date_with_locales(
+    cx: Context,
+    x: f64,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+    required: RequiredComponents,
+    defaults: DefaultComponents,
+) -> EvalResult<String> {
+    let config = resolve_date_time_format_config(cx, locales, options, required, defaults)?;
+
+    Ok(config.icu_formatter(cx).format(cx, x))
+}
@@ -0,0 +1,327 @@
+use alloc::vec::Vec;
+
+use crate::{
+    common::time::get_current_unix_time,
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        array_object::create_array_from_list,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        intrinsics::{date_object::time_clip, intrinsics::Intrinsic},
+        object_value::ObjectValue,
+        ordinary_object::ordinary_object_create,
+        property::Property,
+        realm::Realm,
+        type_utilities::to_number,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    date_time_format::HourCycle, date_time_format_constructor::DateTimeFormatObject,
+    options::IntlEnum, parts::create_part_object,
+};
+
+pub struct DateTimeFormatPrototype;
+
+impl DateTimeFormatPrototype {
+    /// Properties of the Intl.DateTimeFormat Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-datetimeformat-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once DateTimeFormatConstructor has been created
+        object.intrinsic_getter(cx, cx.names.format(), Self::get_format, realm)?;
+        object.intrinsic_func(cx, cx.names.format_range(), Self::format_range, 2, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_range_to_parts(),
+            Self::format_range_to_parts,
+            2,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_to_parts(),
+            Self::format_to_parts,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.DateTimeFormat")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Intl.DateTimeFormat.prototype.format (https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format)
+    pub fn get_format(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut date_time_format = DateTimeFormatObject::cast_from_value(cx, this_value)?;
+
+        if let Some(bound_format) = date_time_format.bound_format() {
+            return Ok(bound_format.to_stack(cx).as_value());
+        }
+
+        let mut bound_format = BuiltinFunction::create(
+            cx,
+            Self::date_time_format_function,
+            1,
+            cx.names.empty_string(),
+            cx.current_realm(),
+            None,
+        )?;
+
+        bound_format.private_element_set(
+            cx,
+            cx.well_known_symbols.intl_object().cast(),
+            date_time_format.as_value(),
+        )?;
+
+        date_time_format.set_bound_format(*bound_format);
+
+        Ok(bound_format.as_value())
+    }
+
+    /// DateTime Format Functions (https://tc39.es/ecma402/#sec-datetime-format-functions)
+    pub fn date_time_format_function(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time_format = cx
+            .current_function()
+            .private_element_find(cx, cx.well_known_symbols.intl_object().cast())
+            .unwrap()
+            .value()
+            .as_object()
+            .cast::<DateTimeFormatObject>();
+
+        let date = get_argument(cx, arguments, 0);
+        let x = to_date_time_value(cx, date)?;
+
+        let formatted = date_time_format.config().icu_formatter(cx).format(cx, x);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.DateTimeFormat.prototype.formatToParts (https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts)
+    pub fn format_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time_format = DateTimeFormatObject::cast_from_value(cx, this_value)?;
+
+        let date = get_argument(cx, arguments, 0);
+        let x = to_date_time_value(cx, date)?;
+
+        let parts = date_time_format
+            .config()
+            .icu_formatter(cx)
+            .format_to_parts(cx, x);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for part in &parts {
+            part_objects.push(create_part_object(cx, part, None)?);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.DateTimeFormat.prototype.formatRange (https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange)
+    pub fn format_range(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time_format = DateTimeFormatObject::cast_from_value(cx, this_value)?;
+        let (x, y) = get_range_arguments(cx, arguments)?;
+
+        let formatted = date_time_format
+            .config()
+            .icu_formatter(cx)
+            .format_range(cx, x, y);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.DateTimeFormat.prototype.formatRangeToParts (https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRangeToParts)
+    pub fn format_range_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time_format = DateTimeFormatObject::cast_from_value(cx, this_value)?;
+        let (x, y) = get_range_arguments(cx, arguments)?;
+
+        let parts = date_time_format
+            .config()
+            .icu_formatter(cx)
+            .format_range_to_parts(cx, x, y);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for (part, source) in &parts {
+            part_objects.push(create_part_object(cx, part, Some(*source))?);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.DateTimeFormat.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time_format = DateTimeFormatObject::cast_from_value(cx, this_value)?;
+        let options = *date_time_format.options();
+        let components = options.components;
+
+        let object = ordinary_object_create(cx)?;
+
+        macro_rules! add_property {
+            ($name:ident, $value:expr) => {{
+                let value = $value;
+                must!(create_data_property_or_throw(
+                    cx,
+                    object,
+                    cx.names.$name(),
+                    value
+                ));
+            }};
+        }
+
+        macro_rules! add_string_property {
+            ($name:ident, $string:expr) => {
+                add_property!($name, cx.alloc_string($string)?.as_value())
+            };
+        }
+
+        add_property!(locale, date_time_format.locale().to_stack(cx).as_value());
+        add_property!(
+            calendar,
+            date_time_format.calendar().to_stack(cx).as_value()
+        );
+        add_property!(
+            numbering_system,
+            date_time_format.numbering_system().to_stack(cx).as_value()
+        );
+        add_property!(
+            time_zone,
+            date_time_format.time_zone().to_stack(cx).as_value()
+        );
+
+        if let Some(hour_cycle) = options.hour_cycle {
+            add_string_property!(hour_cycle, hour_cycle.as_str());
+
+            let hour12 = matches!(hour_cycle, HourCycle::H11 | HourCycle::H12);
+            add_property!(hour12, cx.bool(hour12));
+        }
+
+        // Components are only reported if dateStyle and timeStyle were not used
+        if let Some(weekday) = components.weekday {
+            add_string_property!(weekday, weekday.as_str());
+        }
+        if let Some(era) = components.era {
+            add_string_property!(era, era.as_str());
+        }
+        if let Some(year) = components.year {
+            add_string_property!(year, year.as_str());
+        }
+        if let Some(month) = components.month {
+            add_string_property!(month, month.as_str());
+        }
+        if let Some(day) = components.day {
+            add_string_property!(day, day.as_str());
+        }
+        if let Some(day_period) = components.day_period {
+            add_string_property!(day_period, day_period.as_str());
+        }
+        if let Some(hour) = components.hour {
+            add_string_property!(hour, hour.as_str());
+        }
+        if let Some(minute) = components.minute {
+            add_string_property!(minute, minute.as_str());
+        }
+        if let Some(second) = components.second {
+            add_string_property!(second, second.as_str());
+        }
+        if let Some(digits) = components.fractional_second_digits {
+            add_property!(fractional_second_digits, cx.smi(digits as i32));
+        }
+        if let Some(time_zone_name) = components.time_zone_name {
+            add_string_property!(time_zone_name, time_zone_name.as_str());
+        }
+
+        if let Some(date_style) = options.date_style {
+            add_string_property!(date_style, date_style.as_str());
+        }
+        if let Some(time_style) = options.time_style {
+            add_string_property!(time_style, time_style.as_str());
+        }
+
+        Ok(object.as_value())
+    }
+}
+
+/// Convert the argument of a format function to a time value, where undefined is the current
+/// time. The time value is clipped and may not be NaN.
+fn to_date_time_value(cx: Context, date: StackRoot<Value>) -> EvalResult<f64> {
+    let time = if date.is_undefined() {
+        get_current_unix_time(&cx)
+    } else {
+        to_number(cx, date)?.as_number()
+    };
+
+    clip_date_time_value(cx, time)
+}
+
+/// TimeClip a time value that is about to be formatted, which must not be NaN.
+fn clip_date_time_value(cx: Context, time: f64) -> EvalResult<f64> {
+    let time = time_clip(time);
+    if time.is_nan() {
+        return range_error(cx, "Invalid time value");
+    }
+
+    Ok(time)
+}
+
+/// Convert the start and end arguments of `formatRange` and `formatRangeToParts` to time values,
+/// neither of which may be NaN.
+fn get_range_arguments(cx: Context, arguments: &[StackRoot<Value>]) -> EvalResult<(f64, f64)> {
+    let start = get_argument(cx, arguments, 0);
+    let end = get_argument(cx, arguments, 1);
+
+    if start.is_undefined() || end.is_undefined() {
+        return type_error(cx, "start and end of range must be defined");
+    }
+
+    let x = to_number(cx, start)?.as_number();
+    let y = to_number(cx, end)?.as_number();
+
+    Ok((clip_date_time_value(cx, x)?, clip_date_time_value(cx, y)?))
+}
@@ -0,0 +1,434 @@
+use core::mem::size_of;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+};
+
+use icu_experimental::{
+    dimension::provider::currency::displayname::CurrencyDisplaynameV1,
+    displaynames::{
+        DisplayNamesOptions, DisplayNamesPreferences, LanguageDisplay, LocaleDisplayNamesFormatter,
+        RegionDisplayNames, ScriptDisplayNames, Style,
+    },
+};
+use icu_locale::{
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::prelude::*;
+
+use crate::{
+    cast_from_value_fn,
+    common::{icu::ICU, icu_data::BakedDataProvider},
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::type_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+    },
+    number_format::is_well_formed_currency_code,
+    options::{get_enum_option, get_optional_enum_option, get_options_object},
+};
+
+intl_enum! {
+    pub enum DisplayNamesStyle {
+        Narrow => "narrow",
+        Short => "short",
+        Long => "long",
+    }
+}
+
+intl_enum! {
+    pub enum DisplayNamesType {
+        Language => "language",
+        Region => "region",
+        Script => "script",
+        Currency => "currency",
+    }
+}
+
+intl_enum! {
+    pub enum DisplayNamesFallback {
+        Code => "code",
+        None => "none",
+    }
+}
+
+intl_enum! {
+    pub enum DisplayNamesLanguageDisplay {
+        Dialect => "dialect",
+        Standard => "standard",
+    }
+}
+
+/// The resolved locale and options of a display names object. Identifies the ICU display names
+/// that are used, so is also used as the key for the cache of ICU display names.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DisplayNamesConfig {
+    pub locale: String,
+    pub style: DisplayNamesStyle,
+    pub display_type: DisplayNamesType,
+    pub fallback: DisplayNamesFallback,
+    pub language_display: DisplayNamesLanguageDisplay,
+}
+
+/// The ICU display names for a type of code.
+pub enum IcuDisplayNames {
+    Language(LocaleDisplayNamesFormatter),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    /// Currency names are looked up directly in the ICU data for each currency
+    Currency(Locale),
+}
+
+impl DisplayNamesConfig {
+    /// Create the ICU display names for this config.
+    fn create_icu_display_names(&self) -> IcuDisplayNames {
+        let locale = Locale::try_from_str(&self.locale).unwrap_or_default();
+
+        let mut options = DisplayNamesOptions::default();
+        options.style = Some(match self.style {
+            DisplayNamesStyle::Narrow => Style::Narrow,
+            DisplayNamesStyle::Short => Style::Short,
+            DisplayNamesStyle::Long => Style::Long,
+        });
+        options.language_display = match self.language_display {
+            DisplayNamesLanguageDisplay::Dialect => LanguageDisplay::Dialect,
+            DisplayNamesLanguageDisplay::Standard => LanguageDisplay::Standard,
+        };
+
+        let preferences = DisplayNamesPreferences::from(&locale);
+
+        match self.display_type {
+            DisplayNamesType::Language => IcuDisplayNames::Language(
+                LocaleDisplayNamesFormatter::try_new_unstable(
+                    &BakedDataProvider,
+                    preferences,
+                    options,
+                )
+                .or_else(|_| {
+                    LocaleDisplayNamesFormatter::try_new_unstable(
+                        &BakedDataProvider,
+                        Default::default(),
+                        options,
+                    )
+                })
+                .unwrap(),
+            ),
+            DisplayNamesType::Region => IcuDisplayNames::Region(
+                RegionDisplayNames::try_new_unstable(&BakedDataProvider, preferences, options)
+                    .or_else(|_| {
+                        RegionDisplayNames::try_new_unstable(
+                            &BakedDataProvider,
+                            Default::default(),
+                            options,
+                        )
+                    })
+                    .unwrap(),
+            ),
+            DisplayNamesType::Script => IcuDisplayNames::Script(
+                ScriptDisplayNames::try_new_unstable(&BakedDataProvider, preferences, options)
+                    .or_else(|_| {
+                        ScriptDisplayNames::try_new_unstable(
+                            &BakedDataProvider,
+                            Default::default(),
+                            options,
+                        )
+                    })
+                    .unwrap(),
+            ),
+            DisplayNamesType::Currency => IcuDisplayNames::Currency(locale),
+        }
+    }
+
+    /// Return the ICU display names for this config, creating and caching them if necessary.
+    pub fn icu_display_names(&self, mut cx: Context) -> Rc<IcuDisplayNames> {
+        if let Some(display_names) = cx.intl_cache.display_names.get(self) {
+            return display_names.clone();
+        }
+
+        let display_names = Rc::new(self.create_icu_display_names());
+        cx.intl_cache
+            .display_names
+            .insert(self.clone(), display_names.clone());
+
+        display_names
+    }
+
+    /// Validate and canonicalize a code for the type of this config, returning None if the code
+    /// is not well-formed.
+    ///
+    /// CanonicalCodeForDisplayNames (https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames)
+    pub fn canonical_code(&self, code: &str) -> Option<String> {
+        match self.display_type {
+            DisplayNamesType::Language => {
+                // Must be a language identifier without any extensions
+                if code.contains('_') || code.eq_ignore_ascii_case("root") {
+                    return None;
+                }
+
+                let id = LanguageIdentifier::try_from_str(code).ok()?;
+                let mut locale = Locale::from(id);
+                ICU.locales.canonicalizer.canonicalize(&mut locale);
+
+                Some(locale.to_string())
+            }
+            DisplayNamesType::Region => Some(Region::try_from_str(code).ok()?.to_string()),
+            DisplayNamesType::Script => Some(Script::try_from_str(code).ok()?.to_string()),
+            DisplayNamesType::Currency => {
+                if is_well_formed_currency_code(code) {
+                    Some(code.to_ascii_uppercase())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// The display name of a canonicalized code, or None if there is no display name for the
+    /// code in this locale.
+    pub fn display_name(&self, cx: Context, code: &str) -> Option<String> {
+        let display_names = self.icu_display_names(cx);
+
+        match display_names.as_ref() {
+            IcuDisplayNames::Language(formatter) => {
+                let locale = Locale::try_from_str(code).ok()?;
+                let name = formatter.of(&locale);
+
+                // The formatter falls back to the code itself when there is no display name
+                if name == code {
+                    None
+                } else {
+                    Some(name.into_owned())
+                }
+            }
+            IcuDisplayNames::Region(region_names) => {
+                let region = Region::try_from_str(code).ok()?;
+                region_names.of(region).map(String::from)
+            }
+            IcuDisplayNames::Script(script_names) => {
+                let script = Script::try_from_str(code).ok()?;
+                script_names.of(script).map(String::from)
+            }
+            IcuDisplayNames::Currency(locale) => currency_display_name(locale, code),
+        }
+    }
+}
+
+/// The display name of a currency in a locale, looked up directly in the ICU currency data.
+fn currency_display_name(locale: &Locale, code: &str) -> Option<String> {
+    let attributes = DataMarkerAttributes::try_from_str(code).ok()?;
+    let data_locale = DataLocale::from(locale);
+
+    let response: DataResponse<CurrencyDisplaynameV1> = BakedDataProvider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &data_locale),
+            ..Default::default()
+        })
+        .ok()?;
+
+    Some(response.payload.get().display_name.to_string())
+}
+
+/// Intl.DisplayNames (https://tc39.es/ecma402/#sec-Intl.DisplayNames)
+///
+/// Resolve the locale and options of a display names object from the `locales` and `options`
+/// arguments.
+pub fn resolve_display_names_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<DisplayNamesConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = get_options_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let resolved = resolve_locale(cx, &requested_locales, &[]);
+
+    let style = get_enum_option(cx, options, cx.names.style(), DisplayNamesStyle::Long)?;
+
+    let display_type = match get_optional_enum_option(cx, options, cx.names.type_())? {
+        Some(display_type) => display_type,
+        None => return type_error(cx, "DisplayNames type option is required"),
+    };
+
+    let fallback = get_enum_option(cx, options, cx.names.fallback(), DisplayNamesFallback::Code)?;
+    let language_display = get_enum_option(
+        cx,
+        options,
+        cx.names.language_display(),
+        DisplayNamesLanguageDisplay::Dialect,
+    )?;
+
+    Ok(DisplayNamesConfig {
+        locale: resolved.locale,
+        style,
+        display_type,
+        fallback,
+        language_display,
+    })
+}
+
+// Intl.DisplayNames Objects (https://tc39.es/ecma402/#intl-displaynames-objects)
+extend_object! {
+    pub struct DisplayNamesObject {
+        locale: HeapPtr<FlatString>,
+        style: DisplayNamesStyle,
+        display_type: DisplayNamesType,
+        fallback: DisplayNamesFallback,
+        language_display: DisplayNamesLanguageDisplay,
+    }
+}
+
+impl DisplayNamesObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &DisplayNamesConfig,
+    ) -> AllocResult<StackRoot<DisplayNamesObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+
+        let mut object = object_create_with_proto::<DisplayNamesObject>(
+            cx,
+            HeapItemKind::DisplayNamesObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.style, config.style);
+        set_uninit!(object.display_type, config.display_type);
+        set_uninit!(object.fallback, config.fallback);
+        set_uninit!(object.language_display, config.language_display);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(DisplayNamesObject, "Intl.DisplayNames");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn style(&self) -> DisplayNamesStyle {
+        self.style
+    }
+
+    pub fn display_type(&self) -> DisplayNamesType {
+        self.display_type
+    }
+
+    pub fn fallback(&self) -> DisplayNamesFallback {
+        self.fallback
+    }
+
+    pub fn language_display(&self) -> DisplayNamesLanguageDisplay {
+        self.language_display
+    }
+
+    pub fn config(&self) -> DisplayNamesConfig {
+        DisplayNamesConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            style: self.style,
+            display_type: self.display_type,
+            fallback: self.fallback,
+            language_display: self.language_display,
+        }
+    }
+}
+
+pub struct DisplayNamesConstructor;
+
+impl DisplayNamesConstructor {
+    /// Properties of the Intl.DisplayNames Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-displaynames-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            2,
+            cx.names.display_names(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::DisplayNamesPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.DisplayNames (https://tc39.es/ecma402/#sec-Intl.DisplayNames)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "DisplayNames constructor must be called with new");
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::DisplayNamesPrototype)?;
+        let config = resolve_display_names_config(cx, locales, options)?;
+
+        Ok(DisplayNamesObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.DisplayNames.supportedLocalesOf (https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<DisplayNamesObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<DisplayNamesObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::format;
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw, alloc_error::AllocResult,
+        error::range_error, eval_result::EvalResult, function::get_argument,
+        intrinsics::intrinsics::Intrinsic, object_value::ObjectValue,
+        ordinary_object::ordinary_object_create, property::Property, realm::Realm,
+        type_utilities::to_string, Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    display_names_constructor::{DisplayNamesFallback, DisplayNamesObject, DisplayNamesType},
+    options::IntlEnum,
+};
+
+pub struct DisplayNamesPrototype;
+
+impl DisplayNamesPrototype {
+    /// Properties of the Intl.DisplayNames Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-displaynames-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once DisplayNamesConstructor has been created
+        object.intrinsic_func(cx, cx.names.of(), Self::of, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.DisplayNames")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.DisplayNames.prototype.of (https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of)
+    pub fn of(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let display_names = DisplayNamesObject::cast_from_value(cx, this_value)?;
+        let config = display_names.config();
+
+        let code = get_argument(cx, arguments, 0);
+        let code = to_string(cx, code)?.format(cx)?;
+
+        let code = match config.canonical_code(&code) {
+            Some(code) => code,
+            None => {
+                return range_error(
+                    cx,
+                    &format!("invalid {} code: {code}", config.display_type.as_str()),
+                )
+            }
+        };
+
+        match config.display_name(cx, &code) {
+            Some(name) => Ok(cx.alloc_string(&name)?.as_value()),
+            None => match config.fallback {
+                DisplayNamesFallback::Code => Ok(cx.alloc_string(&code)?.as_value()),
+                DisplayNamesFallback::None => Ok(cx.undefined()),
+            },
+        }
+    }
+
+    /// Intl.DisplayNames.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let display_names = DisplayNamesObject::cast_from_value(cx, this_value)?;
+
+        let object = ordinary_object_create(cx)?;
+
+        let locale = display_names.locale().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.locale_(),
+            locale
+        ));
+
+        let style = cx.alloc_string(display_names.style().as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.style(),
+            style
+        ));
+
+        let display_type = cx
+            .alloc_string(display_names.display_type().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.type_(),
+            display_type
+        ));
+
+        let fallback = cx
+            .alloc_string(display_names.fallback().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.fallback(),
+            fallback
+        ));
+
+        // Only language display names have a language display option
+        if display_names.display_type() == DisplayNamesType::Language {
+            let language_display = cx
+                .alloc_string(display_names.language_display().as_str())?
+                .as_value();
+            must!(create_data_property_or_throw(
+                cx,
+                object,
+                cx.names.language_display(),
+                language_display
+            ));
+        }
+
+        Ok(object.as_value())
+    }
+}
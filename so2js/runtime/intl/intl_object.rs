@@ -0,0 +1,165 @@
+use alloc::{format, string::ToString, vec::Vec};
+
+use crate::{
+    common::time_zone::available_time_zones,
+    runtime::{
+        alloc_error::AllocResult, array_object::create_array_from_list, error::range_error,
+        eval_result::EvalResult, function::get_argument, intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue, property::Property, realm::Realm, type_utilities::to_string,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    collator_constructor::SUPPORTED_COLLATIONS,
+    date_time_format::supported_calendars,
+    locale::canonicalize_locale_list,
+    number_format::{CURRENCY_CODES, SANCTIONED_SINGLE_UNITS},
+};
+
+/// The Intl Object (https://tc39.es/ecma402/#intl-object)
+pub struct IntlObject;
+
+impl IntlObject {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Intl [ @@toStringTag ] (https://tc39.es/ecma402/#sec-Intl-toStringTag)
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let intl_name_value = cx.names.intl().as_string().into();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(intl_name_value, false, false, true),
+        )?;
+
+        // Constructor Properties of the Intl Object (https://tc39.es/ecma402/#constructor-properties-of-the-intl-object)
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.collator(),
+            realm.get_intrinsic(Intrinsic::CollatorConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.date_time_format(),
+            realm
+                .get_intrinsic(Intrinsic::DateTimeFormatConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.display_names(),
+            realm
+                .get_intrinsic(Intrinsic::DisplayNamesConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.list_format(),
+            realm.get_intrinsic(Intrinsic::ListFormatConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.locale(),
+            realm.get_intrinsic(Intrinsic::LocaleConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.number_format(),
+            realm
+                .get_intrinsic(Intrinsic::NumberFormatConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plural_rules(),
+            realm
+                .get_intrinsic(Intrinsic::PluralRulesConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.relative_time_format(),
+            realm
+                .get_intrinsic(Intrinsic::RelativeTimeFormatConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.segmenter(),
+            realm.get_intrinsic(Intrinsic::SegmenterConstructor).into(),
+        )?;
+
+        // Function Properties of the Intl Object (https://tc39.es/ecma402/#function-properties-of-the-intl-object)
+        object.intrinsic_func(
+            cx,
+            cx.names.get_canonical_locales(),
+            Self::get_canonical_locales,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.supported_values_of(),
+            Self::supported_values_of,
+            1,
+            realm,
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.getCanonicalLocales (https://tc39.es/ecma402/#sec-intl.getcanonicallocales)
+    pub fn get_canonical_locales(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let locales = canonicalize_locale_list(cx, locales)?;
+
+        let mut tags = Vec::with_capacity(locales.len());
+        for locale in locales {
+            tags.push(cx.alloc_string(&locale.to_string())?.as_value());
+        }
+
+        Ok(create_array_from_list(cx, &tags)?.as_value())
+    }
+
+    /// Intl.supportedValuesOf (https://tc39.es/ecma402/#sec-intl.supportedvaluesof)
+    pub fn supported_values_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let key = get_argument(cx, arguments, 0);
+        let key = to_string(cx, key)?.format(cx)?;
+
+        // Each list is sorted in lexicographic code unit order
+        let time_zones;
+        let values: &[&str] = match key.as_str() {
+            "calendar" => supported_calendars(""),
+            // The default collation cannot be requested by name
+            "collation" => &SUPPORTED_COLLATIONS[1..],
+            "currency" => CURRENCY_CODES,
+            "numberingSystem" => &["arab", "deva", "latn"],
+            "timeZone" => {
+                time_zones = available_time_zones();
+                &time_zones
+            }
+            "unit" => SANCTIONED_SINGLE_UNITS,
+            _ => return range_error(cx, &format!("invalid key: {key}")),
+        };
+
+        let mut list = Vec::with_capacity(values.len());
+        for value in values {
+            list.push(cx.alloc_string(value)?.as_value());
+        }
+
+        Ok(create_array_from_list(cx, &list)?.as_value())
+    }
+}
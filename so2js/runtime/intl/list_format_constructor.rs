@@ -0,0 +1,318 @@
+use core::mem::size_of;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use icu_list::{
+    options::{ListFormatterOptions, ListLength},
+    parts, ListFormatter, ListFormatterPreferences,
+};
+use icu_locale::Locale;
+use writeable::{Part, Writeable};
+
+use crate::{
+    cast_from_value_fn,
+    common::icu_data::BakedDataProvider,
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::type_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        iterator::iter_iterator_values,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+    },
+    options::{get_enum_option, get_options_object},
+    parts::{FormattedPart, PartsCollector},
+};
+
+intl_enum! {
+    pub enum ListFormatType {
+        Conjunction => "conjunction",
+        Disjunction => "disjunction",
+        Unit => "unit",
+    }
+}
+
+intl_enum! {
+    pub enum ListFormatStyle {
+        Long => "long",
+        Short => "short",
+        Narrow => "narrow",
+    }
+}
+
+/// The resolved locale and options of a list format. Identifies the ICU list formatter that is
+/// used to format lists, so is also used as the key for the cache of ICU list formatters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ListFormatConfig {
+    pub locale: String,
+    pub list_type: ListFormatType,
+    pub style: ListFormatStyle,
+}
+
+impl ListFormatConfig {
+    /// Create the ICU list formatter for this config.
+    fn create_icu_formatter(&self) -> ListFormatter {
+        let locale = Locale::try_from_str(&self.locale).unwrap_or_default();
+
+        let length = match self.style {
+            ListFormatStyle::Long => ListLength::Wide,
+            ListFormatStyle::Short => ListLength::Short,
+            ListFormatStyle::Narrow => ListLength::Narrow,
+        };
+        let options = ListFormatterOptions::default().with_length(length);
+
+        let create = |preferences: ListFormatterPreferences| match self.list_type {
+            ListFormatType::Conjunction => {
+                ListFormatter::try_new_and_unstable(&BakedDataProvider, preferences, options)
+            }
+            ListFormatType::Disjunction => {
+                ListFormatter::try_new_or_unstable(&BakedDataProvider, preferences, options)
+            }
+            ListFormatType::Unit => {
+                ListFormatter::try_new_unit_unstable(&BakedDataProvider, preferences, options)
+            }
+        };
+
+        create(ListFormatterPreferences::from(&locale))
+            .or_else(|_| create(Default::default()))
+            .unwrap()
+    }
+
+    /// Return the ICU list formatter for this config, creating and caching it if necessary.
+    pub fn icu_formatter(&self, mut cx: Context) -> Rc<ListFormatter> {
+        if let Some(formatter) = cx.intl_cache.list_formatters.get(self) {
+            return formatter.clone();
+        }
+
+        let formatter = Rc::new(self.create_icu_formatter());
+        cx.intl_cache
+            .list_formatters
+            .insert(self.clone(), formatter.clone());
+
+        formatter
+    }
+
+    /// CreatePartsFromList (https://tc39.es/ecma402/#sec-createpartsfromlist)
+    pub fn format_to_parts(&self, cx: Context, list: &[String]) -> Vec<FormattedPart> {
+        let formatter = self.icu_formatter(cx);
+        PartsCollector::collect(&formatter.format(list.iter()), list_part_kind)
+    }
+
+    /// FormatList (https://tc39.es/ecma402/#sec-formatlist)
+    pub fn format(&self, cx: Context, list: &[String]) -> String {
+        let formatter = self.icu_formatter(cx);
+        formatter.format(list.iter()).write_to_string().into_owned()
+    }
+}
+
+/// The kind of a part written by an ICU list formatter, where text outside of any element is a
+/// literal.
+fn list_part_kind(part_stack: &[Part]) -> &'static str {
+    match part_stack.last() {
+        Some(part) if *part == parts::ELEMENT => "element",
+        _ => "literal",
+    }
+}
+
+/// InitializeListFormat (https://tc39.es/ecma402/#sec-Intl.ListFormat)
+///
+/// Resolve the locale and options of a list format from the `locales` and `options` arguments.
+pub fn resolve_list_format_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<ListFormatConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = get_options_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let resolved = resolve_locale(cx, &requested_locales, &[]);
+
+    let list_type = get_enum_option(cx, options, cx.names.type_(), ListFormatType::Conjunction)?;
+    let style = get_enum_option(cx, options, cx.names.style(), ListFormatStyle::Long)?;
+
+    Ok(ListFormatConfig {
+        locale: resolved.locale,
+        list_type,
+        style,
+    })
+}
+
+/// StringListFromIterable (https://tc39.es/ecma402/#sec-createstringlistfromiterable)
+pub fn string_list_from_iterable(
+    cx: Context,
+    iterable: StackRoot<Value>,
+) -> EvalResult<Vec<String>> {
+    let mut list = Vec::new();
+
+    if iterable.is_undefined() {
+        return Ok(list);
+    }
+
+    iter_iterator_values(cx, iterable, &mut |cx, value| {
+        if !value.is_string() {
+            return Some(type_error(cx, "list elements must be strings"));
+        }
+
+        match value.as_string().format(cx) {
+            Ok(string) => {
+                list.push(string);
+                None
+            }
+            Err(error) => Some(Err(error.into())),
+        }
+    })?;
+
+    Ok(list)
+}
+
+// Intl.ListFormat Objects (https://tc39.es/ecma402/#listformat-objects)
+extend_object! {
+    pub struct ListFormatObject {
+        locale: HeapPtr<FlatString>,
+        list_type: ListFormatType,
+        style: ListFormatStyle,
+    }
+}
+
+impl ListFormatObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &ListFormatConfig,
+    ) -> AllocResult<StackRoot<ListFormatObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+
+        let mut object = object_create_with_proto::<ListFormatObject>(
+            cx,
+            HeapItemKind::ListFormatObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.list_type, config.list_type);
+        set_uninit!(object.style, config.style);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(ListFormatObject, "Intl.ListFormat");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn list_type(&self) -> ListFormatType {
+        self.list_type
+    }
+
+    pub fn style(&self) -> ListFormatStyle {
+        self.style
+    }
+
+    pub fn config(&self) -> ListFormatConfig {
+        ListFormatConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            list_type: self.list_type,
+            style: self.style,
+        }
+    }
+}
+
+pub struct ListFormatConstructor;
+
+impl ListFormatConstructor {
+    /// Properties of the Intl.ListFormat Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-listformat-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.list_format(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::ListFormatPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.ListFormat (https://tc39.es/ecma402/#sec-Intl.ListFormat)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "ListFormat constructor must be called with new");
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::ListFormatPrototype)?;
+        let config = resolve_list_format_config(cx, locales, options)?;
+
+        Ok(ListFormatObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.ListFormat.supportedLocalesOf (https://tc39.es/ecma402/#sec-Intl.ListFormat.supportedLocalesOf)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<ListFormatObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<ListFormatObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+    }
+}
@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw, alloc_error::AllocResult,
+        array_object::create_array_from_list, eval_result::EvalResult, function::get_argument,
+        intrinsics::intrinsics::Intrinsic, object_value::ObjectValue,
+        ordinary_object::ordinary_object_create, property::Property, realm::Realm, Context,
+        StackRoot, Value,
+    },
+};
+
+use super::{
+    list_format_constructor::{string_list_from_iterable, ListFormatObject},
+    options::IntlEnum,
+    parts::create_part_object,
+};
+
+pub struct ListFormatPrototype;
+
+impl ListFormatPrototype {
+    /// Properties of the Intl.ListFormat Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-listformat-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once ListFormatConstructor has been created
+        object.intrinsic_func(cx, cx.names.format(), Self::format, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_to_parts(),
+            Self::format_to_parts,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.ListFormat")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.ListFormat.prototype.format (https://tc39.es/ecma402/#sec-Intl.ListFormat.prototype.format)
+    pub fn format(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let list_format = ListFormatObject::cast_from_value(cx, this_value)?;
+
+        let list = get_argument(cx, arguments, 0);
+        let list = string_list_from_iterable(cx, list)?;
+
+        let formatted = list_format.config().format(cx, &list);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.ListFormat.prototype.formatToParts (https://tc39.es/ecma402/#sec-Intl.ListFormat.prototype.formatToParts)
+    pub fn format_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let list_format = ListFormatObject::cast_from_value(cx, this_value)?;
+
+        let list = get_argument(cx, arguments, 0);
+        let list = string_list_from_iterable(cx, list)?;
+
+        let parts = list_format.config().format_to_parts(cx, &list);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for part in &parts {
+            part_objects.push(create_part_object(cx, part, None)?);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.ListFormat.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-Intl.ListFormat.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let list_format = ListFormatObject::cast_from_value(cx, this_value)?;
+
+        let object = ordinary_object_create(cx)?;
+
+        let locale = list_format.locale().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.locale_(),
+            locale
+        ));
+
+        let list_type = cx
+            .alloc_string(list_format.list_type().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.type_(),
+            list_type
+        ));
+
+        let style = cx.alloc_string(list_format.style().as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.style(),
+            style
+        ));
+
+        Ok(object.as_value())
+    }
+}
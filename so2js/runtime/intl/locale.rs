@@ -0,0 +1,314 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use icu_locale::{extensions::unicode::Key, Locale};
+
+use crate::{
+    common::icu::{DEFAULT_LOCALE, ICU},
+    runtime::{
+        abstract_operations::{get, has_property, length_of_array_like},
+        array_object::create_array_from_list,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        heap_item_descriptor::HeapItemKind,
+        object_value::ObjectValue,
+        property_key::PropertyKey,
+        type_utilities::{to_object, to_string},
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    locale_constructor::LocaleObject,
+    options::{coerce_options_to_object, get_string_option},
+};
+
+/// Locales for which ICU data is available. Must match the locales passed to
+/// `icu/generate_data.sh`, sorted so that lookups can use binary search.
+pub const AVAILABLE_LOCALES: &[&str] = &[
+    "ar", "de", "en", "en-GB", "es", "fr", "hi", "it", "ja", "ko", "nl", "pl", "pt", "ru", "sv",
+    "tr", "zh",
+];
+
+fn is_available_locale(locale: &str) -> bool {
+    AVAILABLE_LOCALES.binary_search(&locale).is_ok()
+}
+
+/// Parse and canonicalize a language tag, returning None if it is not a structurally valid
+/// language tag.
+///
+/// IsStructurallyValidLanguageTag (https://tc39.es/ecma402/#sec-isstructurallyvalidlanguagetag)
+/// CanonicalizeUnicodeLocaleId (https://tc39.es/ecma402/#sec-canonicalizeunicodelocaleid)
+pub fn parse_language_tag(tag: &str) -> Option<Locale> {
+    // The grandfathered and private use only tags accepted by ICU are not valid in ECMA-402
+    if tag.starts_with("x-") || tag.starts_with("X-") || tag.contains('_') {
+        return None;
+    }
+
+    let mut locale = Locale::try_from_str(tag).ok()?;
+    ICU.locales.canonicalizer.canonicalize(&mut locale);
+
+    Some(locale)
+}
+
+/// The locale of an Intl.Locale object, or None if the value is not an Intl.Locale object.
+pub fn locale_object_locale(value: StackRoot<Value>) -> Option<Locale> {
+    if !value.is_object() {
+        return None;
+    }
+
+    let object = value.as_object();
+    if object.descriptor().kind() != HeapItemKind::LocaleObject {
+        return None;
+    }
+
+    Some(object.cast::<LocaleObject>().icu_locale())
+}
+
+/// CanonicalizeLocaleList (https://tc39.es/ecma402/#sec-canonicalizelocalelist)
+pub fn canonicalize_locale_list(
+    cx: Context,
+    locales: StackRoot<Value>,
+) -> EvalResult<Vec<Locale>> {
+    let mut seen = Vec::new();
+
+    if locales.is_undefined() {
+        return Ok(seen);
+    }
+
+    // A single string or Intl.Locale object is treated as a list containing only that locale
+    if let Some(locale) = locale_object_locale(locales) {
+        return Ok(vec![locale]);
+    }
+
+    if locales.is_string() {
+        let tag = locales.as_string().format(cx)?;
+        return match parse_language_tag(&tag) {
+            Some(locale) => Ok(vec![locale]),
+            None => range_error(cx, &format!("invalid language tag: {tag}")),
+        };
+    }
+
+    let object = to_object(cx, locales)?;
+    let length = length_of_array_like(cx, object)?;
+
+    // Property key is shared between iterations
+    let mut key = PropertyKey::uninit().to_stack(cx);
+
+    for i in 0..length {
+        key.replace(PropertyKey::from_u64(cx, i)?);
+
+        if !has_property(cx, object, key)? {
+            continue;
+        }
+
+        let value = get(cx, object, key)?;
+        if !value.is_string() && !value.is_object() {
+            return type_error(cx, "locale must be a string or object");
+        }
+
+        let locale = if let Some(locale) = locale_object_locale(value) {
+            locale
+        } else {
+            let tag = to_string(cx, value)?.format(cx)?;
+            match parse_language_tag(&tag) {
+                Some(locale) => locale,
+                None => return range_error(cx, &format!("invalid language tag: {tag}")),
+            }
+        };
+
+        if !seen.contains(&locale) {
+            seen.push(locale);
+        }
+    }
+
+    Ok(seen)
+}
+
+/// The default locale of the host environment, reduced to the best available locale.
+///
+/// DefaultLocale (https://tc39.es/ecma402/#sec-defaultlocale)
+pub fn default_locale(cx: Context) -> String {
+    best_available_locale(&cx.default_locale).unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// BestAvailableLocale (https://tc39.es/ecma402/#sec-bestavailablelocale)
+fn best_available_locale(locale: &Locale) -> Option<String> {
+    let mut candidate = locale.id.to_string();
+
+    loop {
+        if is_available_locale(&candidate) {
+            return Some(candidate);
+        }
+
+        let mut pos = candidate.rfind('-')?;
+
+        // Also remove a preceding single character subtag, e.g. the `x` in `-x-private`
+        if pos >= 2 && candidate.as_bytes()[pos - 2] == b'-' {
+            pos -= 2;
+        }
+
+        candidate.truncate(pos);
+    }
+}
+
+/// The result of matching a list of requested locales against the available locales.
+pub struct LocaleMatch {
+    /// The best available locale, without any extensions.
+    pub data_locale: String,
+    /// The requested locale that was matched, whose Unicode extension keywords may be used by the
+    /// service.
+    pub requested_locale: Option<Locale>,
+}
+
+impl LocaleMatch {
+    /// The value of a Unicode extension keyword in the matched requested locale. A keyword with no
+    /// value is treated as `true`.
+    fn keyword(&self, key: Key) -> Option<String> {
+        let keywords = &self.requested_locale.as_ref()?.extensions.unicode.keywords;
+        let value = keywords.get(&key)?.to_string();
+
+        if value.is_empty() {
+            Some(String::from("true"))
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// LookupMatchingLocaleByPrefix (https://tc39.es/ecma402/#sec-lookupmatchinglocalebyprefix)
+///
+/// Also used for the "best fit" matcher, which is implementation defined.
+pub fn lookup_matcher(cx: Context, requested_locales: &[Locale]) -> LocaleMatch {
+    for locale in requested_locales {
+        if let Some(data_locale) = best_available_locale(locale) {
+            return LocaleMatch {
+                data_locale,
+                requested_locale: Some(locale.clone()),
+            };
+        }
+    }
+
+    LocaleMatch {
+        data_locale: default_locale(cx),
+        requested_locale: None,
+    }
+}
+
+/// A Unicode extension keyword that is relevant to a service, e.g. `kn` for Intl.Collator.
+pub struct RelevantExtensionKey {
+    /// The Unicode extension key.
+    pub key: Key,
+    /// The value passed for this key in the options object, if any.
+    pub option_value: Option<String>,
+    /// The values supported for this key in the given data locale, where the first is the default
+    /// value.
+    pub supported_values: fn(&str) -> &'static [&'static str],
+}
+
+/// The locale and extension keyword values selected by ResolveLocale.
+pub struct ResolvedLocale {
+    /// The resolved locale, including any supported extension keywords that were requested.
+    pub locale: String,
+    /// The resolved value of each relevant extension key, in the order the keys were provided.
+    pub values: Vec<String>,
+}
+
+/// ResolveLocale (https://tc39.es/ecma402/#sec-resolvelocale)
+pub fn resolve_locale(
+    cx: Context,
+    requested_locales: &[Locale],
+    relevant_keys: &[RelevantExtensionKey],
+) -> ResolvedLocale {
+    let locale_match = lookup_matcher(cx, requested_locales);
+
+    let mut values = Vec::with_capacity(relevant_keys.len());
+    let mut supported_extension = String::new();
+
+    for relevant_key in relevant_keys {
+        let supported_values = (relevant_key.supported_values)(&locale_match.data_locale);
+        let mut value = String::from(supported_values[0]);
+        let mut supported_extension_addition = None;
+
+        // Use the value of the keyword in the requested locale if it is supported
+        if let Some(requested_value) = locale_match.keyword(relevant_key.key) {
+            if supported_values.contains(&requested_value.as_str()) {
+                supported_extension_addition = Some(if requested_value == "true" {
+                    format!("-{}", relevant_key.key)
+                } else {
+                    format!("-{}-{}", relevant_key.key, requested_value)
+                });
+                value = requested_value;
+            }
+        }
+
+        // Options override the keyword in the requested locale, in which case the keyword is no
+        // longer reported in the resolved locale.
+        if let Some(option_value) = &relevant_key.option_value {
+            if supported_values.contains(&option_value.as_str()) {
+                if *option_value != value {
+                    supported_extension_addition = None;
+                }
+                value = option_value.clone();
+            }
+        }
+
+        if let Some(addition) = supported_extension_addition {
+            supported_extension.push_str(&addition);
+        }
+
+        values.push(value);
+    }
+
+    let locale = if supported_extension.is_empty() {
+        locale_match.data_locale
+    } else {
+        format!("{}-u{}", locale_match.data_locale, supported_extension)
+    };
+
+    ResolvedLocale { locale, values }
+}
+
+/// Read the `localeMatcher` option, which must be either "lookup" or "best fit". Both matchers
+/// use the lookup algorithm.
+pub fn get_locale_matcher_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<()> {
+    get_string_option(
+        cx,
+        options,
+        cx.names.locale_matcher(),
+        &["lookup", "best fit"],
+    )?;
+
+    Ok(())
+}
+
+/// SupportedLocales (https://tc39.es/ecma402/#sec-supportedlocales)
+///
+/// Shared implementation of the `supportedLocalesOf` function of each Intl constructor.
+pub fn supported_locales_of(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+
+    let options = coerce_options_to_object(cx, options)?;
+    get_locale_matcher_option(cx, options)?;
+
+    let mut supported_locales = Vec::new();
+    for locale in requested_locales {
+        if best_available_locale(&locale).is_some() {
+            let tag = cx.alloc_string(&locale.to_string())?;
+            supported_locales.push(tag.as_value());
+        }
+    }
+
+    Ok(create_array_from_list(cx, &supported_locales)?.as_value())
+}
@@ -0,0 +1,262 @@
+use core::mem::size_of;
+
+use alloc::{format, string::ToString};
+
+use icu_locale::{
+    extensions::unicode::{key, Key, Value as KeywordValue},
+    subtags::{Language, Region, Script},
+    Locale,
+};
+
+use crate::{
+    cast_from_value_fn,
+    common::icu::ICU,
+    extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        property_key::PropertyKey,
+        realm::Realm,
+        string_value::FlatString,
+        type_utilities::to_string,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    collator_constructor::{is_unicode_type_sequence, CollatorCaseFirst},
+    date_time_format::HourCycle,
+    locale::{locale_object_locale, parse_language_tag},
+    options::{coerce_options_to_object, get_boolean_option, get_string_option, IntlEnum},
+};
+
+// Intl.Locale Objects (https://tc39.es/ecma402/#locale-objects)
+extend_object! {
+    pub struct LocaleObject {
+        // Canonicalized language tag, including any Unicode extension keywords
+        locale: HeapPtr<FlatString>,
+    }
+}
+
+impl LocaleObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        locale: &Locale,
+    ) -> AllocResult<StackRoot<LocaleObject>> {
+        let locale = cx.alloc_string(&locale.to_string())?;
+
+        let mut object =
+            object_create_with_proto::<LocaleObject>(cx, HeapItemKind::LocaleObject, proto)?;
+
+        set_uninit!(object.locale, *locale);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(LocaleObject, "Intl.Locale");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    /// The ICU locale for this object, parsed from the stored language tag.
+    pub fn icu_locale(&self) -> Locale {
+        let tag = self.locale.to_wtf8_string().to_string();
+        Locale::try_from_str(&tag).unwrap()
+    }
+}
+
+/// UpdateLanguageId (https://tc39.es/ecma402/#sec-updatelanguageid)
+///
+/// Replace the language, script, and region subtags of a locale with any provided in the options.
+fn update_language_id(
+    cx: Context,
+    locale: &mut Locale,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<()> {
+    let language = get_string_option(cx, options, cx.names.language(), &[])?;
+    let language = match language.as_deref().map(Language::try_from_str) {
+        None => None,
+        Some(Ok(language)) => Some(language),
+        Some(Err(_)) => return range_error(cx, "invalid language subtag"),
+    };
+
+    let script = get_string_option(cx, options, cx.names.script(), &[])?;
+    let script = match script.as_deref().map(Script::try_from_str) {
+        None => None,
+        Some(Ok(script)) => Some(script),
+        Some(Err(_)) => return range_error(cx, "invalid script subtag"),
+    };
+
+    let region = get_string_option(cx, options, cx.names.region(), &[])?;
+    let region = match region.as_deref().map(Region::try_from_str) {
+        None => None,
+        Some(Ok(region)) => Some(region),
+        Some(Err(_)) => return range_error(cx, "invalid region subtag"),
+    };
+
+    if let Some(language) = language {
+        locale.id.language = language;
+    }
+
+    if script.is_some() {
+        locale.id.script = script;
+    }
+
+    if region.is_some() {
+        locale.id.region = region;
+    }
+
+    Ok(())
+}
+
+/// Read an option that is stored as a Unicode extension keyword whose value must be a Unicode
+/// type sequence, e.g. the `calendar` option for the `ca` keyword.
+fn get_type_sequence_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+) -> EvalResult<Option<KeywordValue>> {
+    let value = match get_string_option(cx, options, property, &[])? {
+        None => return Ok(None),
+        Some(value) => value,
+    };
+
+    match KeywordValue::try_from_str(&value) {
+        Ok(keyword_value) if is_unicode_type_sequence(&value) => Ok(Some(keyword_value)),
+        _ => range_error(
+            cx,
+            &format!("invalid value {value} for option {}", property.format(cx)?),
+        ),
+    }
+}
+
+/// Create the locale for an Intl.Locale object from the `tag` and `options` arguments.
+fn create_locale(
+    cx: Context,
+    tag: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<Locale> {
+    if !tag.is_string() && !tag.is_object() {
+        return type_error(cx, "locale tag must be a string or object");
+    }
+
+    let tag = match locale_object_locale(tag) {
+        Some(locale) => locale.to_string(),
+        None => to_string(cx, tag)?.format(cx)?,
+    };
+
+    let options = coerce_options_to_object(cx, options)?;
+
+    let mut locale = match parse_language_tag(&tag) {
+        Some(locale) => locale,
+        None => return range_error(cx, &format!("invalid language tag: {tag}")),
+    };
+
+    update_language_id(cx, &mut locale, options)?;
+
+    // Options for Unicode extension keywords override keywords in the tag
+    let calendar = get_type_sequence_option(cx, options, cx.names.calendar())?;
+    let collation = get_type_sequence_option(cx, options, cx.names.collation())?;
+    let hour_cycle = get_string_option(cx, options, cx.names.hour_cycle(), HourCycle::VALUES)?;
+    let case_first = get_string_option(
+        cx,
+        options,
+        cx.names.case_first(),
+        CollatorCaseFirst::VALUES,
+    )?;
+    let numeric = get_boolean_option(cx, options, cx.names.numeric())?;
+    let numbering_system = get_type_sequence_option(cx, options, cx.names.numbering_system())?;
+
+    let keywords = &mut locale.extensions.unicode.keywords;
+    let mut set_keyword = |key: Key, value: Option<KeywordValue>| {
+        if let Some(value) = value {
+            keywords.set(key, value);
+        }
+    };
+
+    let parse_keyword =
+        |value: Option<&str>| value.and_then(|v| KeywordValue::try_from_str(v).ok());
+
+    set_keyword(key!("ca"), calendar);
+    set_keyword(key!("co"), collation);
+    set_keyword(key!("hc"), parse_keyword(hour_cycle.as_deref()));
+    set_keyword(key!("kf"), parse_keyword(case_first.as_deref()));
+    set_keyword(
+        key!("kn"),
+        parse_keyword(numeric.map(|numeric| if numeric { "true" } else { "false" })),
+    );
+    set_keyword(key!("nu"), numbering_system);
+
+    // Subtags provided in options may need to be canonicalized
+    ICU.locales.canonicalizer.canonicalize(&mut locale);
+
+    Ok(locale)
+}
+
+pub struct LocaleConstructor;
+
+impl LocaleConstructor {
+    /// Properties of the Intl.Locale Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-locale-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            1,
+            cx.names.locale(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::LocalePrototype).into(),
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.Locale (https://tc39.es/ecma402/#sec-Intl.Locale)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Locale constructor must be called with new");
+        };
+
+        let tag = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before the tag and options are read
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::LocalePrototype)?;
+        let locale = create_locale(cx, tag, options)?;
+
+        Ok(LocaleObject::new_with_proto(cx, proto, &locale)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<LocaleObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<LocaleObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+    }
+}
@@ -0,0 +1,222 @@
+use alloc::string::ToString;
+
+use icu_locale::{
+    extensions::unicode::{key, Key},
+    Locale,
+};
+
+use crate::{
+    common::icu::ICU,
+    runtime::{
+        alloc_error::AllocResult, eval_result::EvalResult, intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue, property::Property, realm::Realm, Context, StackRoot, Value,
+    },
+};
+
+use super::locale_constructor::LocaleObject;
+
+pub struct LocalePrototype;
+
+impl LocalePrototype {
+    /// Properties of the Intl.Locale Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-locale-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once LocaleConstructor has been created
+        object.intrinsic_getter(cx, cx.names.base_name(), Self::base_name, realm)?;
+        object.intrinsic_getter(cx, cx.names.calendar(), Self::calendar, realm)?;
+        object.intrinsic_getter(cx, cx.names.case_first(), Self::case_first, realm)?;
+        object.intrinsic_getter(cx, cx.names.collation(), Self::collation, realm)?;
+        object.intrinsic_getter(cx, cx.names.hour_cycle(), Self::hour_cycle, realm)?;
+        object.intrinsic_getter(cx, cx.names.language(), Self::language, realm)?;
+        object.intrinsic_func(cx, cx.names.maximize(), Self::maximize, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.minimize(), Self::minimize, 0, realm)?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.numbering_system(),
+            Self::numbering_system,
+            realm,
+        )?;
+        object.intrinsic_getter(cx, cx.names.numeric(), Self::numeric, realm)?;
+        object.intrinsic_getter(cx, cx.names.region(), Self::region, realm)?;
+        object.intrinsic_getter(cx, cx.names.script(), Self::script, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.Locale")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.Locale.prototype.maximize (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.maximize)
+    pub fn maximize(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        ICU.locales.expander.maximize(&mut locale.id);
+
+        create_locale_object(cx, &locale)
+    }
+
+    /// Intl.Locale.prototype.minimize (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.minimize)
+    pub fn minimize(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        ICU.locales.expander.minimize(&mut locale.id);
+
+        create_locale_object(cx, &locale)
+    }
+
+    /// Intl.Locale.prototype.toString (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.toString)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?;
+        Ok(locale.locale().to_stack(cx).as_value())
+    }
+
+    /// get Intl.Locale.prototype.baseName (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.baseName)
+    pub fn base_name(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        Ok(cx.alloc_string(&locale.id.to_string())?.as_value())
+    }
+
+    /// get Intl.Locale.prototype.calendar (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.calendar)
+    pub fn calendar(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        get_keyword(cx, this_value, key!("ca"))
+    }
+
+    /// get Intl.Locale.prototype.caseFirst (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.caseFirst)
+    pub fn case_first(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        get_keyword(cx, this_value, key!("kf"))
+    }
+
+    /// get Intl.Locale.prototype.collation (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.collation)
+    pub fn collation(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        get_keyword(cx, this_value, key!("co"))
+    }
+
+    /// get Intl.Locale.prototype.hourCycle (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.hourCycle)
+    pub fn hour_cycle(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        get_keyword(cx, this_value, key!("hc"))
+    }
+
+    /// get Intl.Locale.prototype.numeric (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numeric)
+    pub fn numeric(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+
+        // A keyword with no value is true
+        let numeric = match locale.extensions.unicode.keywords.get(&key!("kn")) {
+            Some(value) => matches!(value.to_string().as_str(), "" | "true"),
+            None => false,
+        };
+
+        Ok(cx.bool(numeric))
+    }
+
+    /// get Intl.Locale.prototype.numberingSystem (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numberingSystem)
+    pub fn numbering_system(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        get_keyword(cx, this_value, key!("nu"))
+    }
+
+    /// get Intl.Locale.prototype.language (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.language)
+    pub fn language(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        Ok(cx.alloc_string(locale.id.language.as_str())?.as_value())
+    }
+
+    /// get Intl.Locale.prototype.script (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.script)
+    pub fn script(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        match locale.id.script {
+            Some(script) => Ok(cx.alloc_string(script.as_str())?.as_value()),
+            None => Ok(cx.undefined()),
+        }
+    }
+
+    /// get Intl.Locale.prototype.region (https://tc39.es/ecma402/#sec-Intl.Locale.prototype.region)
+    pub fn region(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+        match locale.id.region {
+            Some(region) => Ok(cx.alloc_string(region.as_str())?.as_value()),
+            None => Ok(cx.undefined()),
+        }
+    }
+}
+
+/// The value of a Unicode extension keyword of an Intl.Locale object, or undefined if the locale
+/// does not have the keyword.
+fn get_keyword(
+    cx: Context,
+    this_value: StackRoot<Value>,
+    key: Key,
+) -> EvalResult<StackRoot<Value>> {
+    let locale = LocaleObject::cast_from_value(cx, this_value)?.icu_locale();
+    match locale.extensions.unicode.keywords.get(&key) {
+        Some(value) => Ok(cx.alloc_string(&value.to_string())?.as_value()),
+        None => Ok(cx.undefined()),
+    }
+}
+
+/// Create a new Intl.Locale object in the current realm.
+fn create_locale_object(cx: Context, locale: &Locale) -> EvalResult<StackRoot<Value>> {
+    let proto = cx.get_intrinsic(Intrinsic::LocalePrototype);
+    Ok(LocaleObject::new_with_proto(cx, proto, locale)?.as_value())
+}
@@ -0,0 +1,61 @@
+use alloc::rc::Rc;
+
+use hashbrown::HashMap;
+use icu_collator::Collator;
+use icu_experimental::relativetime::RelativeTimeFormatter;
+use icu_list::ListFormatter;
+use icu_plurals::{PluralRules, PluralRulesWithRanges};
+
+use collator_constructor::CollatorConfig;
+use date_time_format::{DateTimeFormatConfig, DateTimeFormatter};
+use display_names_constructor::{DisplayNamesConfig, IcuDisplayNames};
+use list_format_constructor::ListFormatConfig;
+use number_format::{NumberFormatConfig, NumberFormatter};
+use plural_rules_constructor::PluralRulesConfig;
+use relative_time_format_constructor::{RelativeTimeFormatConfig, RelativeTimeUnit};
+use segmenter_constructor::{IcuSegmenter, SegmenterConfig};
+
+pub mod collator_constructor;
+pub mod collator_prototype;
+pub mod date_time_format;
+pub mod date_time_format_constructor;
+pub mod date_time_format_prototype;
+pub mod display_names_constructor;
+pub mod display_names_prototype;
+pub mod intl_object;
+pub mod list_format_constructor;
+pub mod list_format_prototype;
+pub mod locale;
+pub mod locale_constructor;
+pub mod locale_prototype;
+pub mod number_format;
+pub mod number_format_constructor;
+pub mod number_format_prototype;
+pub mod options;
+pub mod parts;
+pub mod plural_rules_constructor;
+pub mod plural_rules_prototype;
+pub mod relative_time_format_constructor;
+pub mod relative_time_format_prototype;
+pub mod segment_iterator;
+pub mod segmenter_constructor;
+pub mod segmenter_prototype;
+pub mod segments_object;
+
+/// ICU objects created for Intl services, cached per resolved locale and options since they are
+/// expensive to construct and are shared between Intl objects and locale-sensitive methods.
+///
+/// ICU objects are owned by the context instead of the GC heap, which only stores the resolved
+/// locale and options needed to look them up.
+#[derive(Default)]
+pub struct IntlCache {
+    pub collators: HashMap<CollatorConfig, Rc<Collator>>,
+    pub date_time_formatters: HashMap<DateTimeFormatConfig, Rc<DateTimeFormatter>>,
+    pub display_names: HashMap<DisplayNamesConfig, Rc<IcuDisplayNames>>,
+    pub list_formatters: HashMap<ListFormatConfig, Rc<ListFormatter>>,
+    pub number_formatters: HashMap<NumberFormatConfig, Rc<NumberFormatter>>,
+    pub plural_rules: HashMap<PluralRulesConfig, Rc<PluralRulesWithRanges<PluralRules>>>,
+    pub relative_time_formatters:
+        HashMap<(RelativeTimeFormatConfig, RelativeTimeUnit), Rc<RelativeTimeFormatter>>,
+    pub segmenters: HashMap<SegmenterConfig, Rc<IcuSegmenter>>,
+}
@@ -0,0 +1,1334 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use fixed_decimal::{
+    Decimal, FloatPrecision, RoundingIncrement, Sign, SignDisplay as IcuSignDisplay,
+    SignedRoundingMode, UnsignedRoundingMode,
+};
+use icu_decimal::{
+    options::{DecimalFormatterOptions, GroupingStrategy},
+    parts, DecimalFormatter, DecimalFormatterPreferences,
+};
+use icu_experimental::{
+    compactdecimal::{
+        CompactDecimalFormatter, CompactDecimalFormatterOptions, CompactDecimalFormatterPreferences,
+    },
+    dimension::{
+        currency::{
+            formatter::{CurrencyFormatter, CurrencyFormatterPreferences},
+            long_formatter::LongCurrencyFormatter,
+            options::{CurrencyFormatterOptions, Width as CurrencyWidth},
+            CurrencyCode,
+        },
+        percent::{
+            formatter::{PercentFormatter, PercentFormatterPreferences},
+            options::PercentFormatterOptions,
+        },
+        units::{
+            formatter::{UnitsFormatter, UnitsFormatterPreferences},
+            options::{UnitsFormatterOptions, Width as UnitsWidth},
+        },
+    },
+};
+use icu_locale::Locale;
+use icu_properties::props::GeneralCategory;
+use writeable::{Part, Writeable};
+
+use crate::{
+    common::{
+        icu::ICU,
+        icu_data::BakedDataProvider,
+        unicode::{is_newline, is_whitespace},
+    },
+    intl_enum,
+    runtime::{
+        abstract_operations::get,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        object_value::ObjectValue,
+        type_utilities::{to_number, to_primitive, ToPrimitivePreferredType},
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    options::{default_number_option, get_enum_option, get_number_option},
+    parts::{join_parts, FormattedPart, PartsCollector, RangePartSource},
+};
+
+intl_enum! {
+    pub enum RoundingMode {
+        Ceil => "ceil",
+        Floor => "floor",
+        Expand => "expand",
+        Trunc => "trunc",
+        HalfCeil => "halfCeil",
+        HalfFloor => "halfFloor",
+        HalfExpand => "halfExpand",
+        HalfTrunc => "halfTrunc",
+        HalfEven => "halfEven",
+    }
+}
+
+impl RoundingMode {
+    fn to_icu(self) -> SignedRoundingMode {
+        match self {
+            RoundingMode::Ceil => SignedRoundingMode::Ceil,
+            RoundingMode::Floor => SignedRoundingMode::Floor,
+            RoundingMode::Expand => SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand),
+            RoundingMode::Trunc => SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc),
+            RoundingMode::HalfCeil => SignedRoundingMode::HalfCeil,
+            RoundingMode::HalfFloor => SignedRoundingMode::HalfFloor,
+            RoundingMode::HalfExpand => {
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand)
+            }
+            RoundingMode::HalfTrunc => {
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfTrunc)
+            }
+            RoundingMode::HalfEven => SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+        }
+    }
+}
+
+intl_enum! {
+    pub enum RoundingPriority {
+        Auto => "auto",
+        MorePrecision => "morePrecision",
+        LessPrecision => "lessPrecision",
+    }
+}
+
+intl_enum! {
+    pub enum RoundingType {
+        FractionDigits => "fractionDigits",
+        SignificantDigits => "significantDigits",
+        MorePrecision => "morePrecision",
+        LessPrecision => "lessPrecision",
+    }
+}
+
+intl_enum! {
+    pub enum TrailingZeroDisplay {
+        Auto => "auto",
+        StripIfInteger => "stripIfInteger",
+    }
+}
+
+intl_enum! {
+    pub enum SignDisplay {
+        Auto => "auto",
+        Never => "never",
+        Always => "always",
+        ExceptZero => "exceptZero",
+        Negative => "negative",
+    }
+}
+
+impl SignDisplay {
+    pub fn to_icu(self) -> IcuSignDisplay {
+        match self {
+            SignDisplay::Auto => IcuSignDisplay::Auto,
+            SignDisplay::Never => IcuSignDisplay::Never,
+            SignDisplay::Always => IcuSignDisplay::Always,
+            SignDisplay::ExceptZero => IcuSignDisplay::ExceptZero,
+            SignDisplay::Negative => IcuSignDisplay::Negative,
+        }
+    }
+}
+
+/// Rounding increments allowed by the `roundingIncrement` option.
+const ROUNDING_INCREMENTS: &[u16] = &[
+    1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500, 5000,
+];
+
+/// The resolved digit options of an Intl.NumberFormat or Intl.PluralRules, which determine how a
+/// number is rounded before it is formatted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitOptions {
+    pub minimum_integer_digits: u8,
+    pub minimum_fraction_digits: u8,
+    pub maximum_fraction_digits: u8,
+    pub minimum_significant_digits: u8,
+    pub maximum_significant_digits: u8,
+    pub rounding_increment: u16,
+    pub rounding_mode: RoundingMode,
+    pub rounding_type: RoundingType,
+    pub computed_rounding_priority: RoundingPriority,
+    pub trailing_zero_display: TrailingZeroDisplay,
+}
+
+/// SetNumberFormatDigitOptions (https://tc39.es/ecma402/#sec-setnfdigitoptions)
+pub fn set_number_format_digit_options(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    mnfd_default: u8,
+    mut mxfd_default: u8,
+    is_compact_notation: bool,
+) -> EvalResult<DigitOptions> {
+    let mnid = get_number_option(cx, options, cx.names.minimum_integer_digits(), 1.0, 21.0)?
+        .unwrap_or(1.0);
+
+    let mnfd = get(cx, options, cx.names.minimum_fraction_digits())?;
+    let mxfd = get(cx, options, cx.names.maximum_fraction_digits())?;
+    let mnsd = get(cx, options, cx.names.minimum_significant_digits())?;
+    let mxsd = get(cx, options, cx.names.maximum_significant_digits())?;
+
+    let rounding_increment =
+        get_number_option(cx, options, cx.names.rounding_increment(), 1.0, 5000.0)?.unwrap_or(1.0)
+            as u16;
+    if !ROUNDING_INCREMENTS.contains(&rounding_increment) {
+        return range_error(cx, "invalid roundingIncrement");
+    }
+
+    let rounding_mode = get_enum_option(
+        cx,
+        options,
+        cx.names.rounding_mode(),
+        RoundingMode::HalfExpand,
+    )?;
+    let rounding_priority = get_enum_option(
+        cx,
+        options,
+        cx.names.rounding_priority(),
+        RoundingPriority::Auto,
+    )?;
+    let trailing_zero_display = get_enum_option(
+        cx,
+        options,
+        cx.names.trailing_zero_display(),
+        TrailingZeroDisplay::Auto,
+    )?;
+
+    if rounding_increment != 1 {
+        mxfd_default = mnfd_default;
+    }
+
+    let has_sd = !mnsd.is_undefined() || !mxsd.is_undefined();
+    let has_fd = !mnfd.is_undefined() || !mxfd.is_undefined();
+
+    let mut need_sd = true;
+    let mut need_fd = true;
+
+    if rounding_priority == RoundingPriority::Auto {
+        need_sd = has_sd;
+        if need_sd || (!has_fd && is_compact_notation) {
+            need_fd = false;
+        }
+    }
+
+    let mut digit_options = DigitOptions {
+        minimum_integer_digits: mnid as u8,
+        minimum_fraction_digits: 0,
+        maximum_fraction_digits: 0,
+        minimum_significant_digits: 0,
+        maximum_significant_digits: 0,
+        rounding_increment,
+        rounding_mode,
+        rounding_type: RoundingType::FractionDigits,
+        computed_rounding_priority: RoundingPriority::Auto,
+        trailing_zero_display,
+    };
+
+    if need_sd {
+        if has_sd {
+            let mnsd_key = cx.names.minimum_significant_digits();
+            let mnsd = default_number_option(cx, mnsd, mnsd_key, 1.0, 21.0)?.unwrap_or(1.0);
+
+            let mxsd_key = cx.names.maximum_significant_digits();
+            let mxsd = default_number_option(cx, mxsd, mxsd_key, mnsd, 21.0)?.unwrap_or(21.0);
+
+            digit_options.minimum_significant_digits = mnsd as u8;
+            digit_options.maximum_significant_digits = mxsd as u8;
+        } else {
+            digit_options.minimum_significant_digits = 1;
+            digit_options.maximum_significant_digits = 21;
+        }
+    }
+
+    if need_fd {
+        if has_fd {
+            let mnfd_key = cx.names.minimum_fraction_digits();
+            let mnfd = default_number_option(cx, mnfd, mnfd_key, 0.0, 100.0)?;
+
+            let mxfd_key = cx.names.maximum_fraction_digits();
+            let mxfd = default_number_option(cx, mxfd, mxfd_key, 0.0, 100.0)?;
+
+            let (mnfd, mxfd) = match (mnfd, mxfd) {
+                (None, Some(mxfd)) => ((mnfd_default as f64).min(mxfd), mxfd),
+                (Some(mnfd), None) => (mnfd, (mxfd_default as f64).max(mnfd)),
+                (Some(mnfd), Some(mxfd)) => {
+                    if mnfd > mxfd {
+                        return range_error(
+                            cx,
+                            "minimumFractionDigits is greater than maximumFractionDigits",
+                        );
+                    }
+
+                    (mnfd, mxfd)
+                }
+                (None, None) => unreachable!("has_fd implies a fraction digits option"),
+            };
+
+            digit_options.minimum_fraction_digits = mnfd as u8;
+            digit_options.maximum_fraction_digits = mxfd as u8;
+        } else {
+            digit_options.minimum_fraction_digits = mnfd_default;
+            digit_options.maximum_fraction_digits = mxfd_default;
+        }
+    }
+
+    if !need_sd && !need_fd {
+        digit_options.minimum_fraction_digits = 0;
+        digit_options.maximum_fraction_digits = 0;
+        digit_options.minimum_significant_digits = 1;
+        digit_options.maximum_significant_digits = 2;
+        digit_options.rounding_type = RoundingType::MorePrecision;
+        digit_options.computed_rounding_priority = RoundingPriority::MorePrecision;
+    } else if rounding_priority == RoundingPriority::Auto {
+        digit_options.rounding_type = if has_sd {
+            RoundingType::SignificantDigits
+        } else {
+            RoundingType::FractionDigits
+        };
+    } else {
+        digit_options.rounding_type = match rounding_priority {
+            RoundingPriority::MorePrecision => RoundingType::MorePrecision,
+            _ => RoundingType::LessPrecision,
+        };
+        digit_options.computed_rounding_priority = rounding_priority;
+    }
+
+    if rounding_increment != 1 {
+        if digit_options.rounding_type != RoundingType::FractionDigits {
+            return type_error(cx, "roundingIncrement requires fraction digits rounding");
+        }
+
+        if digit_options.maximum_fraction_digits != digit_options.minimum_fraction_digits {
+            return range_error(
+                cx,
+                "roundingIncrement requires equal minimum and maximum fraction digits",
+            );
+        }
+    }
+
+    Ok(digit_options)
+}
+
+/// A number that has been rounded, along with the magnitude of the digit it was rounded at.
+struct RawFormatResult {
+    decimal: Decimal,
+    rounding_magnitude: i16,
+}
+
+/// ToRawPrecision (https://tc39.es/ecma402/#sec-torawprecision)
+fn to_raw_precision(
+    x: &Decimal,
+    min_precision: u8,
+    max_precision: u8,
+    rounding_mode: SignedRoundingMode,
+) -> RawFormatResult {
+    let mut decimal = x.clone();
+
+    let magnitude = decimal.absolute.nonzero_magnitude_start();
+    decimal.round_with_mode(magnitude - max_precision as i16 + 1, rounding_mode);
+
+    // Rounding may carry into the next magnitude, e.g. 9.99 to 10.0
+    let magnitude = decimal.absolute.nonzero_magnitude_start();
+
+    decimal.absolute.trim_end();
+    decimal
+        .absolute
+        .pad_end(magnitude - min_precision as i16 + 1);
+
+    RawFormatResult {
+        decimal,
+        rounding_magnitude: magnitude - max_precision as i16 + 1,
+    }
+}
+
+/// ToRawFixed (https://tc39.es/ecma402/#sec-torawfixed)
+fn to_raw_fixed(
+    x: &Decimal,
+    min_fraction: u8,
+    max_fraction: u8,
+    rounding_increment: u16,
+    rounding_mode: SignedRoundingMode,
+) -> RawFormatResult {
+    let mut decimal = x.clone();
+
+    // Increments are a power of ten multiple of 1, 2, 5, or 25 in the last fraction digit
+    let mut increment = rounding_increment;
+    let mut shift = 0;
+    while increment % 10 == 0 {
+        increment /= 10;
+        shift += 1;
+    }
+
+    let icu_increment = match increment {
+        2 => RoundingIncrement::MultiplesOf2,
+        5 => RoundingIncrement::MultiplesOf5,
+        25 => RoundingIncrement::MultiplesOf25,
+        _ => RoundingIncrement::MultiplesOf1,
+    };
+
+    let rounding_magnitude = -(max_fraction as i16);
+    decimal.round_with_mode_and_increment(rounding_magnitude + shift, rounding_mode, icu_increment);
+
+    decimal.absolute.trim_end();
+    decimal.absolute.pad_end(-(min_fraction as i16));
+
+    RawFormatResult {
+        decimal,
+        rounding_magnitude,
+    }
+}
+
+impl DigitOptions {
+    /// The digit options of a decimal style number format that was created without any options.
+    pub const DEFAULT: DigitOptions = DigitOptions {
+        minimum_integer_digits: 1,
+        minimum_fraction_digits: 0,
+        maximum_fraction_digits: 3,
+        minimum_significant_digits: 0,
+        maximum_significant_digits: 0,
+        rounding_increment: 1,
+        rounding_mode: RoundingMode::HalfExpand,
+        rounding_type: RoundingType::FractionDigits,
+        computed_rounding_priority: RoundingPriority::Auto,
+        trailing_zero_display: TrailingZeroDisplay::Auto,
+    };
+
+    /// FormatNumericToString (https://tc39.es/ecma402/#sec-formatnumberstring)
+    ///
+    /// Round a finite number according to these digit options. Sign is preserved, including for
+    /// negative zero.
+    pub fn round(&self, x: &Decimal) -> Decimal {
+        let rounding_mode = self.rounding_mode.to_icu();
+
+        let significant_result = || {
+            to_raw_precision(
+                x,
+                self.minimum_significant_digits,
+                self.maximum_significant_digits,
+                rounding_mode,
+            )
+        };
+        let fixed_result = || {
+            to_raw_fixed(
+                x,
+                self.minimum_fraction_digits,
+                self.maximum_fraction_digits,
+                self.rounding_increment,
+                rounding_mode,
+            )
+        };
+
+        let result = match self.rounding_type {
+            RoundingType::SignificantDigits => significant_result(),
+            RoundingType::FractionDigits => fixed_result(),
+            RoundingType::MorePrecision => {
+                let significant = significant_result();
+                let fixed = fixed_result();
+                if significant.rounding_magnitude <= fixed.rounding_magnitude {
+                    significant
+                } else {
+                    fixed
+                }
+            }
+            RoundingType::LessPrecision => {
+                let significant = significant_result();
+                let fixed = fixed_result();
+                if significant.rounding_magnitude <= fixed.rounding_magnitude {
+                    fixed
+                } else {
+                    significant
+                }
+            }
+        };
+
+        let mut decimal = result.decimal;
+
+        if self.trailing_zero_display == TrailingZeroDisplay::StripIfInteger
+            && decimal.absolute.nonzero_magnitude_end() >= 0
+        {
+            decimal.absolute.trim_end();
+        }
+
+        decimal
+            .absolute
+            .pad_start(self.minimum_integer_digits as i16);
+
+        decimal
+    }
+}
+
+/// An Intl mathematical value, which unlike a Number can exactly represent BigInts and decimal
+/// strings.
+#[derive(Clone, Debug)]
+pub enum IntlMathematicalValue {
+    Finite(Decimal),
+    PositiveInfinity,
+    NegativeInfinity,
+    NotANumber,
+}
+
+impl IntlMathematicalValue {
+    pub fn from_f64(number: f64) -> IntlMathematicalValue {
+        if number.is_nan() {
+            IntlMathematicalValue::NotANumber
+        } else if number == f64::INFINITY {
+            IntlMathematicalValue::PositiveInfinity
+        } else if number == f64::NEG_INFINITY {
+            IntlMathematicalValue::NegativeInfinity
+        } else {
+            let mut decimal = Decimal::try_from_f64(number, FloatPrecision::RoundTrip).unwrap();
+            if number.is_sign_negative() {
+                decimal.sign = Sign::Negative;
+            }
+
+            IntlMathematicalValue::Finite(decimal)
+        }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        matches!(self, IntlMathematicalValue::NotANumber)
+    }
+}
+
+/// ToIntlMathematicalValue (https://tc39.es/ecma402/#sec-tointlmathematicalvalue)
+pub fn to_intl_mathematical_value(
+    cx: Context,
+    value: StackRoot<Value>,
+) -> EvalResult<IntlMathematicalValue> {
+    let primitive = to_primitive(cx, value, ToPrimitivePreferredType::Number)?;
+
+    if primitive.is_bigint() {
+        let string = primitive.as_bigint().bigint().to_string();
+        return Ok(IntlMathematicalValue::Finite(
+            Decimal::try_from_str(&string).unwrap(),
+        ));
+    }
+
+    // Decimal strings are converted exactly instead of being rounded to the nearest Number
+    if primitive.is_string() {
+        let string = primitive.as_string().format(cx)?;
+        if let Some(value) = string_to_intl_mathematical_value(&string) {
+            return Ok(value);
+        }
+    }
+
+    let number = to_number(cx, primitive)?.as_number();
+    Ok(IntlMathematicalValue::from_f64(number))
+}
+
+/// Convert a StringNumericLiteral that is a decimal literal to an exact mathematical value.
+/// Returns None for strings that should be converted using ToNumber instead, such as non-decimal
+/// integer literals.
+fn string_to_intl_mathematical_value(string: &str) -> Option<IntlMathematicalValue> {
+    let string = string.trim_matches(|c| is_whitespace(c as u32) || is_newline(c as u32));
+
+    match string {
+        "" => return Some(IntlMathematicalValue::Finite(Decimal::from(0))),
+        "Infinity" | "+Infinity" => return Some(IntlMathematicalValue::PositiveInfinity),
+        "-Infinity" => return Some(IntlMathematicalValue::NegativeInfinity),
+        _ => {}
+    }
+
+    let (is_negative, unsigned) = match string.as_bytes()[0] {
+        b'-' => (true, &string[1..]),
+        b'+' => (false, &string[1..]),
+        _ => (false, string),
+    };
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let exponent = match exponent {
+        None => 0,
+        Some(exponent) => {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+
+            // Exponents too large to represent exactly are left to ToNumber
+            let exponent = exponent.parse::<i16>().ok()?;
+            if exponent.unsigned_abs() > 1000 {
+                return None;
+            }
+
+            exponent
+        }
+    };
+
+    let mut decimal = Decimal::try_from_str(&format!("0{integer}.{fraction}0")).ok()?;
+    decimal.absolute.trim_end();
+    decimal.absolute.trim_start();
+    decimal.absolute.multiply_pow10(exponent);
+
+    if is_negative {
+        decimal.sign = Sign::Negative;
+    }
+
+    Some(IntlMathematicalValue::Finite(decimal))
+}
+
+/// The kind of a part written by an ICU decimal formatter, where text outside of any part is a
+/// literal.
+pub fn number_part_kind(part_stack: &[Part]) -> &'static str {
+    match part_stack.last() {
+        Some(part) if *part == parts::INTEGER => "integer",
+        Some(part) if *part == parts::FRACTION => "fraction",
+        Some(part) if *part == parts::GROUP => "group",
+        Some(part) if *part == parts::DECIMAL => "decimal",
+        Some(part) if *part == parts::MINUS_SIGN => "minusSign",
+        Some(part) if *part == parts::PLUS_SIGN => "plusSign",
+        _ => "literal",
+    }
+}
+
+/// Whether a character only affects the layout of formatted text, so is reported as a literal
+/// when it surrounds an affix.
+fn is_layout_char(c: char) -> bool {
+    c.is_whitespace() || ICU.general_categories.classifier.get(c) == GeneralCategory::Format
+}
+
+/// The text that surrounds the digits of a number formatted by an ICU formatter, e.g. the currency
+/// symbol and space in "US$ 1.00".
+#[derive(Default)]
+pub struct Affixes {
+    pub prefix: Vec<FormattedPart>,
+    pub suffix: Vec<FormattedPart>,
+}
+
+impl Affixes {
+    /// Split the text of a formatted number into the affixes around its digits, where affixes
+    /// have the given part kind. Layout characters at the edges of each affix are reported as
+    /// literals.
+    pub fn from_formatted(formatted: &str, kind: &'static str) -> Affixes {
+        let is_digit =
+            |c: char| ICU.general_categories.classifier.get(c) == GeneralCategory::DecimalNumber;
+
+        let (start, end) = match (formatted.find(is_digit), formatted.rfind(is_digit)) {
+            (Some(start), Some(end)) => {
+                let last_digit_len = formatted[end..].chars().next().unwrap().len_utf8();
+                (start, end + last_digit_len)
+            }
+            _ => return Affixes::default(),
+        };
+
+        Affixes {
+            prefix: Self::affix_parts(&formatted[..start], kind),
+            suffix: Self::affix_parts(&formatted[end..], kind),
+        }
+    }
+
+    fn affix_parts(affix: &str, kind: &'static str) -> Vec<FormattedPart> {
+        let core = affix.trim_matches(is_layout_char);
+        if core.is_empty() {
+            return if affix.is_empty() {
+                vec![]
+            } else {
+                vec![FormattedPart::new("literal", affix)]
+            };
+        }
+
+        let core_start = affix.find(core).unwrap();
+        let leading = &affix[..core_start];
+        let trailing = &affix[core_start + core.len()..];
+
+        let mut parts = vec![];
+        if !leading.is_empty() {
+            parts.push(FormattedPart::new("literal", leading));
+        }
+        parts.push(FormattedPart::new(kind, core));
+        if !trailing.is_empty() {
+            parts.push(FormattedPart::new("literal", trailing));
+        }
+
+        parts
+    }
+}
+
+/// Format a rounded number with an ICU decimal formatter, returning its parts.
+pub fn format_decimal_to_parts(
+    formatter: &DecimalFormatter,
+    decimal: &Decimal,
+) -> Vec<FormattedPart> {
+    PartsCollector::collect(&formatter.format(decimal), number_part_kind)
+}
+
+intl_enum! {
+    pub enum NumberFormatStyle {
+        Decimal => "decimal",
+        Percent => "percent",
+        Currency => "currency",
+        Unit => "unit",
+    }
+}
+
+intl_enum! {
+    pub enum CurrencyDisplay {
+        Code => "code",
+        Symbol => "symbol",
+        NarrowSymbol => "narrowSymbol",
+        Name => "name",
+    }
+}
+
+intl_enum! {
+    pub enum CurrencySign {
+        Standard => "standard",
+        Accounting => "accounting",
+    }
+}
+
+intl_enum! {
+    pub enum UnitDisplay {
+        Short => "short",
+        Narrow => "narrow",
+        Long => "long",
+    }
+}
+
+intl_enum! {
+    pub enum Notation {
+        Standard => "standard",
+        Scientific => "scientific",
+        Engineering => "engineering",
+        Compact => "compact",
+    }
+}
+
+intl_enum! {
+    pub enum CompactDisplay {
+        Short => "short",
+        Long => "long",
+    }
+}
+
+intl_enum! {
+    /// The resolved `useGrouping` option, where `False` is reported as the boolean `false`.
+    pub enum UseGrouping {
+        Min2 => "min2",
+        Auto => "auto",
+        Always => "always",
+        False => "false",
+    }
+}
+
+/// The resolved options of a number format, other than its locale, numbering system, currency,
+/// and unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormatOptions {
+    pub style: NumberFormatStyle,
+    pub currency_display: CurrencyDisplay,
+    pub currency_sign: CurrencySign,
+    pub unit_display: UnitDisplay,
+    pub notation: Notation,
+    pub compact_display: CompactDisplay,
+    pub use_grouping: UseGrouping,
+    pub sign_display: SignDisplay,
+    pub digits: DigitOptions,
+}
+
+/// The resolved locale and options of a number format. Identifies the ICU formatters that are
+/// used to format numbers, so is also used as the key for the cache of ICU number formatters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormatConfig {
+    pub locale: String,
+    pub numbering_system: String,
+    /// Uppercased ISO 4217 currency code, only present for the currency style.
+    pub currency: Option<String>,
+    /// Core unit identifier, only present for the unit style.
+    pub unit: Option<String>,
+    pub options: NumberFormatOptions,
+}
+
+impl NumberFormatConfig {
+    /// Create the ICU formatters for this config.
+    fn create_icu_formatter(&self) -> NumberFormatter {
+        // Formatters use the numbering system that was resolved, even if it was not requested with
+        // a `nu` keyword.
+        let data_locale = self.locale.split("-u-").next().unwrap();
+        let locale_tag = format!("{data_locale}-u-nu-{}", self.numbering_system);
+        let locale = Locale::try_from_str(&locale_tag).unwrap_or_default();
+
+        let mut decimal_options = DecimalFormatterOptions::default();
+        decimal_options.grouping_strategy = Some(match self.options.use_grouping {
+            UseGrouping::Min2 => GroupingStrategy::Min2,
+            UseGrouping::Auto => GroupingStrategy::Auto,
+            UseGrouping::Always => GroupingStrategy::Always,
+            UseGrouping::False => GroupingStrategy::Never,
+        });
+
+        let decimal = DecimalFormatter::try_new_unstable(
+            &BakedDataProvider,
+            DecimalFormatterPreferences::from(&locale),
+            decimal_options,
+        )
+        .or_else(|_| {
+            DecimalFormatter::try_new_unstable(
+                &BakedDataProvider,
+                Default::default(),
+                decimal_options,
+            )
+        })
+        .unwrap();
+
+        let style = self.create_style_formatter(&locale);
+
+        let compact = if self.options.notation == Notation::Compact {
+            let preferences = CompactDecimalFormatterPreferences::from(&locale);
+            let options = CompactDecimalFormatterOptions::default();
+
+            let compact = match self.options.compact_display {
+                CompactDisplay::Short => CompactDecimalFormatter::try_new_short_unstable(
+                    &BakedDataProvider,
+                    preferences,
+                    options,
+                ),
+                CompactDisplay::Long => CompactDecimalFormatter::try_new_long_unstable(
+                    &BakedDataProvider,
+                    preferences,
+                    options,
+                ),
+            };
+
+            compact.ok()
+        } else {
+            None
+        };
+
+        NumberFormatter {
+            config: self.clone(),
+            decimal,
+            style,
+            compact,
+        }
+    }
+
+    /// Create the ICU formatter used to find the affixes for the style of this config. Missing
+    /// data results in no affixes rather than an error.
+    fn create_style_formatter(&self, locale: &Locale) -> Option<StyleFormatter> {
+        match self.options.style {
+            NumberFormatStyle::Decimal => None,
+            NumberFormatStyle::Percent => PercentFormatter::try_new_unstable(
+                &BakedDataProvider,
+                PercentFormatterPreferences::from(locale),
+                PercentFormatterOptions::default(),
+            )
+            .ok()
+            .map(StyleFormatter::Percent),
+            NumberFormatStyle::Currency => {
+                let code = self.currency.as_deref().unwrap_or("XXX");
+                let currency_code = CurrencyCode(code.parse().ok()?);
+
+                if self.options.currency_display == CurrencyDisplay::Name {
+                    return LongCurrencyFormatter::try_new_unstable(
+                        &BakedDataProvider,
+                        CurrencyFormatterPreferences::from(locale),
+                        &currency_code,
+                    )
+                    .ok()
+                    .map(StyleFormatter::LongCurrency);
+                }
+
+                let mut options = CurrencyFormatterOptions::default();
+                options.width = match self.options.currency_display {
+                    CurrencyDisplay::NarrowSymbol => CurrencyWidth::Narrow,
+                    _ => CurrencyWidth::Short,
+                };
+
+                CurrencyFormatter::try_new_unstable(
+                    &BakedDataProvider,
+                    CurrencyFormatterPreferences::from(locale),
+                    options,
+                )
+                .ok()
+                .map(|formatter| StyleFormatter::Currency(formatter, currency_code))
+            }
+            NumberFormatStyle::Unit => {
+                let mut options = UnitsFormatterOptions::default();
+                options.width = match self.options.unit_display {
+                    UnitDisplay::Short => UnitsWidth::Short,
+                    UnitDisplay::Narrow => UnitsWidth::Narrow,
+                    UnitDisplay::Long => UnitsWidth::Long,
+                };
+
+                UnitsFormatter::try_new_unstable(
+                    &BakedDataProvider,
+                    UnitsFormatterPreferences::from(locale),
+                    self.unit.as_deref().unwrap_or_default(),
+                    options,
+                )
+                .ok()
+                .map(StyleFormatter::Unit)
+            }
+        }
+    }
+
+    /// Return the ICU formatter for this config, creating and caching it if necessary.
+    pub fn icu_formatter(&self, mut cx: Context) -> Rc<NumberFormatter> {
+        if let Some(formatter) = cx.intl_cache.number_formatters.get(self) {
+            return formatter.clone();
+        }
+
+        let formatter = Rc::new(self.create_icu_formatter());
+        cx.intl_cache
+            .number_formatters
+            .insert(self.clone(), formatter.clone());
+
+        formatter
+    }
+}
+
+/// An ICU formatter for a number format style, used to find the text around the number.
+enum StyleFormatter {
+    Percent(PercentFormatter<DecimalFormatter>),
+    Currency(CurrencyFormatter, CurrencyCode),
+    LongCurrency(LongCurrencyFormatter),
+    Unit(UnitsFormatter),
+}
+
+impl StyleFormatter {
+    /// The affixes around an unsigned number formatted in this style.
+    fn affixes(&self, decimal: &Decimal) -> Affixes {
+        match self {
+            StyleFormatter::Percent(formatter) => {
+                let formatted = formatter.format(decimal).write_to_string();
+                Affixes::from_formatted(&formatted, "percentSign")
+            }
+            StyleFormatter::Currency(formatter, currency_code) => {
+                let formatted = formatter
+                    .format_fixed_decimal(decimal, *currency_code)
+                    .write_to_string();
+                Affixes::from_formatted(&formatted, "currency")
+            }
+            StyleFormatter::LongCurrency(formatter) => {
+                let formatted = formatter.format_fixed_decimal(decimal).write_to_string();
+                Affixes::from_formatted(&formatted, "currency")
+            }
+            StyleFormatter::Unit(formatter) => {
+                let formatted = formatter.format_fixed_decimal(decimal).write_to_string();
+                Affixes::from_formatted(&formatted, "unit")
+            }
+        }
+    }
+}
+
+/// The ICU formatters for a number format config, along with the config itself.
+pub struct NumberFormatter {
+    config: NumberFormatConfig,
+    decimal: DecimalFormatter,
+    style: Option<StyleFormatter>,
+    compact: Option<CompactDecimalFormatter>,
+}
+
+impl NumberFormatter {
+    /// ComputeExponentForMagnitude (https://tc39.es/ecma402/#sec-computeexponentformagnitude)
+    fn compute_exponent_for_magnitude(&self, magnitude: i16) -> i16 {
+        match self.config.options.notation {
+            Notation::Standard => 0,
+            Notation::Scientific => magnitude,
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+            Notation::Compact => match &self.compact {
+                Some(compact) => compact.compact_exponent_for_magnitude(magnitude) as i16,
+                None => 0,
+            },
+        }
+    }
+
+    /// ComputeExponent (https://tc39.es/ecma402/#sec-computeexponent)
+    ///
+    /// Return the exponent of the power of ten the number is scaled by when it is formatted, along
+    /// with the scaled and rounded number.
+    fn compute_exponent(&self, x: &Decimal) -> (i16, Decimal) {
+        let digits = &self.config.options.digits;
+
+        if x.absolute.is_zero() {
+            return (0, digits.round(x));
+        }
+
+        let magnitude = x.absolute.nonzero_magnitude_start();
+        let exponent = self.compute_exponent_for_magnitude(magnitude);
+
+        let mut scaled = x.clone();
+        scaled.absolute.multiply_pow10(-exponent);
+        let rounded = digits.round(&scaled);
+
+        if rounded.absolute.is_zero()
+            || rounded.absolute.nonzero_magnitude_start() == magnitude - exponent
+        {
+            return (exponent, rounded);
+        }
+
+        // Rounding carried into the next magnitude, which may use a different exponent
+        let exponent = self.compute_exponent_for_magnitude(magnitude + 1);
+
+        let mut scaled = x.clone();
+        scaled.absolute.multiply_pow10(-exponent);
+
+        (exponent, digits.round(&scaled))
+    }
+
+    /// PartitionNumberPattern (https://tc39.es/ecma402/#sec-partitionnumberpattern)
+    pub fn format_to_parts(&self, x: &IntlMathematicalValue) -> Vec<FormattedPart> {
+        let options = &self.config.options;
+
+        let (sign, number_parts, exponent, affix_value) = match x {
+            IntlMathematicalValue::NotANumber => {
+                let number_parts = vec![FormattedPart::new("nan", "NaN")];
+                (Sign::None, number_parts, 0, Decimal::from(0))
+            }
+            IntlMathematicalValue::PositiveInfinity | IntlMathematicalValue::NegativeInfinity => {
+                let mut sign_decimal = Decimal::from(1);
+                if matches!(x, IntlMathematicalValue::NegativeInfinity) {
+                    sign_decimal.sign = Sign::Negative;
+                }
+                sign_decimal.apply_sign_display(options.sign_display.to_icu());
+
+                let number_parts = vec![FormattedPart::new("infinity", "∞")];
+                (sign_decimal.sign, number_parts, 0, Decimal::from(0))
+            }
+            IntlMathematicalValue::Finite(x) => {
+                let mut x = x.clone();
+                if options.style == NumberFormatStyle::Percent {
+                    x.absolute.multiply_pow10(2);
+                }
+
+                let (exponent, mut rounded) = match options.notation {
+                    Notation::Standard => (0, options.digits.round(&x)),
+                    _ => self.compute_exponent(&x),
+                };
+
+                rounded.apply_sign_display(options.sign_display.to_icu());
+                let sign = rounded.sign;
+                rounded.sign = Sign::None;
+
+                let number_parts = format_decimal_to_parts(&self.decimal, &rounded);
+
+                // Affixes are chosen for the unscaled value, e.g. for plural forms
+                let mut affix_value = rounded;
+                affix_value.absolute.multiply_pow10(exponent);
+
+                (sign, number_parts, exponent, affix_value)
+            }
+        };
+
+        let mut parts = vec![];
+
+        // Accounting format wraps negative numbers in parentheses instead of using a minus sign
+        let is_accounting_negative = options.style == NumberFormatStyle::Currency
+            && options.currency_sign == CurrencySign::Accounting
+            && sign == Sign::Negative;
+
+        if is_accounting_negative {
+            parts.push(FormattedPart::new("literal", "("));
+        } else if sign != Sign::None {
+            parts.extend(self.sign_parts(sign));
+        }
+
+        let style_affixes = match &self.style {
+            Some(style) => self.style_affixes(style, &affix_value),
+            None => Affixes::default(),
+        };
+
+        let compact_affixes = match &self.compact {
+            Some(compact) if exponent != 0 => {
+                let formatted = compact.format_fixed_decimal(&affix_value).write_to_string();
+                Affixes::from_formatted(&formatted, "compact")
+            }
+            _ => Affixes::default(),
+        };
+
+        parts.extend(style_affixes.prefix);
+        parts.extend(compact_affixes.prefix);
+        parts.extend(number_parts);
+
+        if matches!(
+            options.notation,
+            Notation::Scientific | Notation::Engineering
+        ) {
+            parts.push(FormattedPart::new("exponentSeparator", "E"));
+            if exponent < 0 {
+                parts.push(FormattedPart::new("exponentMinusSign", "-"));
+            }
+
+            let exponent_digits = Decimal::from(exponent.unsigned_abs() as u32);
+            let exponent_string = self.decimal.format(&exponent_digits).write_to_string();
+            parts.push(FormattedPart::new("exponentInteger", &exponent_string));
+        }
+
+        parts.extend(compact_affixes.suffix);
+        parts.extend(style_affixes.suffix);
+
+        if is_accounting_negative {
+            parts.push(FormattedPart::new("literal", ")"));
+        }
+
+        parts
+    }
+
+    /// The parts for a plus or minus sign in the locale of this formatter.
+    fn sign_parts(&self, sign: Sign) -> Vec<FormattedPart> {
+        let mut signed_zero = Decimal::from(0);
+        signed_zero.sign = sign;
+
+        format_decimal_to_parts(&self.decimal, &signed_zero)
+            .into_iter()
+            .filter(|part| part.kind == "minusSign" || part.kind == "plusSign")
+            .collect()
+    }
+
+    /// The affixes for the style of this formatter around the given number.
+    fn style_affixes(&self, style: &StyleFormatter, decimal: &Decimal) -> Affixes {
+        let mut affixes = style.affixes(decimal);
+
+        // ICU only provides currency symbols and names, so currency codes replace the symbol and
+        // are separated from the number.
+        if self.config.options.currency_display == CurrencyDisplay::Code {
+            let code = self.config.currency.as_deref().unwrap_or_default();
+
+            for part in affixes.prefix.iter_mut().chain(affixes.suffix.iter_mut()) {
+                if part.kind == "currency" {
+                    part.value = code.to_owned();
+                }
+            }
+
+            if affixes
+                .prefix
+                .last()
+                .is_some_and(|part| part.kind == "currency")
+            {
+                affixes.prefix.push(FormattedPart::new("literal", "\u{a0}"));
+            }
+
+            if affixes
+                .suffix
+                .first()
+                .is_some_and(|part| part.kind == "currency")
+            {
+                affixes
+                    .suffix
+                    .insert(0, FormattedPart::new("literal", "\u{a0}"));
+            }
+        }
+
+        affixes
+    }
+
+    /// FormatNumeric (https://tc39.es/ecma402/#sec-formatnumber)
+    pub fn format(&self, x: &IntlMathematicalValue) -> String {
+        join_parts(&self.format_to_parts(x))
+    }
+
+    /// PartitionNumberRangePattern (https://tc39.es/ecma402/#sec-partitionnumberrangepattern)
+    ///
+    /// The values must not be NaN.
+    pub fn format_range_to_parts(
+        &self,
+        x: &IntlMathematicalValue,
+        y: &IntlMathematicalValue,
+    ) -> Vec<(FormattedPart, RangePartSource)> {
+        let x_parts = self.format_to_parts(x);
+        let y_parts = self.format_to_parts(y);
+
+        // FormatApproximately (https://tc39.es/ecma402/#sec-formatapproximately)
+        if x_parts == y_parts {
+            let sign_end = x_parts
+                .iter()
+                .position(|part| part.kind != "minusSign" && part.kind != "plusSign")
+                .unwrap_or(x_parts.len());
+
+            let mut parts = x_parts;
+            parts.insert(sign_end, FormattedPart::new("approximatelySign", "~"));
+
+            return parts
+                .into_iter()
+                .map(|part| (part, RangePartSource::Shared))
+                .collect();
+        }
+
+        // Ranges with affixes are spaced out, e.g. "$3 – $5" compared to "3–5"
+        let has_affixes = x_parts.iter().chain(y_parts.iter()).any(|part| {
+            !matches!(
+                part.kind,
+                "integer" | "group" | "decimal" | "fraction" | "minusSign" | "plusSign"
+            )
+        });
+        let separator = if has_affixes { " – " } else { "–" };
+
+        let mut parts: Vec<_> = x_parts
+            .into_iter()
+            .map(|part| (part, RangePartSource::StartRange))
+            .collect();
+        parts.push((
+            FormattedPart::new("literal", separator),
+            RangePartSource::Shared,
+        ));
+        parts.extend(
+            y_parts
+                .into_iter()
+                .map(|part| (part, RangePartSource::EndRange)),
+        );
+
+        parts
+    }
+
+    /// FormatNumericRange (https://tc39.es/ecma402/#sec-formatnumericrange)
+    pub fn format_range(&self, x: &IntlMathematicalValue, y: &IntlMathematicalValue) -> String {
+        self.format_range_to_parts(x, y)
+            .iter()
+            .map(|(part, _)| part.value.as_str())
+            .collect()
+    }
+}
+
+/// The number of minor unit digits for currencies that do not use 2 digits, from ISO 4217.
+const CURRENCY_DIGITS: &[(&str, u8)] = &[
+    ("BHD", 3),
+    ("BIF", 0),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("GNF", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("UYI", 0),
+    ("UYW", 4),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+];
+
+/// CurrencyDigits (https://tc39.es/ecma402/#sec-currencydigits)
+pub fn currency_digits(currency: &str) -> u8 {
+    match CURRENCY_DIGITS.binary_search_by_key(&currency, |(code, _)| code) {
+        Ok(index) => CURRENCY_DIGITS[index].1,
+        Err(_) => 2,
+    }
+}
+
+/// Currency codes in current use from ISO 4217, sorted so that they can be listed by
+/// `Intl.supportedValuesOf`.
+pub const CURRENCY_CODES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD",
+    "CDF", "CHF", "CLP", "CNY", "COP", "CRC", "CUC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP",
+    "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF",
+    "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD",
+    "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP",
+    "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR",
+    "MVR", "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB",
+    "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD",
+    "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP",
+    "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD",
+    "UYU", "UZS", "VES", "VND", "VUV", "WST", "XAF", "XCD", "XOF", "XPF", "YER", "ZAR", "ZMW",
+    "ZWL",
+];
+
+/// IsWellFormedCurrencyCode (https://tc39.es/ecma402/#sec-iswellformedcurrencycode)
+pub fn is_well_formed_currency_code(currency: &str) -> bool {
+    currency.len() == 3 && currency.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Simple units that may be used in a unit identifier, sorted so that lookups can use binary
+/// search.
+///
+/// Single units sanctioned for use in ECMAScript (https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers)
+pub const SANCTIONED_SINGLE_UNITS: &[&str] = &[
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
+fn is_sanctioned_single_unit_identifier(unit: &str) -> bool {
+    SANCTIONED_SINGLE_UNITS.binary_search(&unit).is_ok()
+}
+
+/// IsWellFormedUnitIdentifier (https://tc39.es/ecma402/#sec-iswellformedunitidentifier)
+pub fn is_well_formed_unit_identifier(unit: &str) -> bool {
+    if is_sanctioned_single_unit_identifier(unit) {
+        return true;
+    }
+
+    match unit.split_once("-per-") {
+        Some((numerator, denominator)) => {
+            is_sanctioned_single_unit_identifier(numerator)
+                && is_sanctioned_single_unit_identifier(denominator)
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,397 @@
+use core::mem::size_of;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use icu_locale::extensions::unicode::key;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        abstract_operations::get,
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        type_utilities::{to_boolean, to_string},
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    collator_constructor::is_unicode_type_sequence,
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+        RelevantExtensionKey,
+    },
+    number_format::{
+        currency_digits, is_well_formed_currency_code, is_well_formed_unit_identifier,
+        set_number_format_digit_options, to_intl_mathematical_value, CompactDisplay,
+        CurrencyDisplay, CurrencySign, Notation, NumberFormatConfig, NumberFormatOptions,
+        NumberFormatStyle, SignDisplay, UnitDisplay, UseGrouping,
+    },
+    options::{coerce_options_to_object, get_enum_option, get_string_option},
+};
+
+/// Numbering systems that can be requested with the `nu` extension key or `numberingSystem`
+/// option in a data locale, where the first is the default.
+pub fn supported_numbering_systems(data_locale: &str) -> &'static [&'static str] {
+    match data_locale {
+        "ar" => &["arab", "latn"],
+        "hi" => &["latn", "deva"],
+        _ => &["latn"],
+    }
+}
+
+/// InitializeNumberFormat (https://tc39.es/ecma402/#sec-initializenumberformat)
+///
+/// Resolve the locale and options of a number format from the `locales` and `options` arguments.
+pub fn resolve_number_format_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<NumberFormatConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = coerce_options_to_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let numbering_system = get_string_option(cx, options, cx.names.numbering_system(), &[])?;
+    if let Some(numbering_system) = &numbering_system {
+        if !is_unicode_type_sequence(numbering_system) {
+            return range_error(cx, "invalid numbering system");
+        }
+    }
+
+    let relevant_keys = [RelevantExtensionKey {
+        key: key!("nu"),
+        option_value: numbering_system,
+        supported_values: supported_numbering_systems,
+    }];
+
+    let resolved = resolve_locale(cx, &requested_locales, &relevant_keys);
+
+    // SetNumberFormatUnitOptions (https://tc39.es/ecma402/#sec-setnumberformatunitoptions)
+    let style = get_enum_option(cx, options, cx.names.style(), NumberFormatStyle::Decimal)?;
+
+    let currency = get_string_option(cx, options, cx.names.currency(), &[])?;
+    match &currency {
+        None if style == NumberFormatStyle::Currency => {
+            return type_error(cx, "currency option is required for currency style");
+        }
+        Some(currency) if !is_well_formed_currency_code(currency) => {
+            return range_error(cx, &format!("invalid currency code {currency}"));
+        }
+        _ => {}
+    }
+
+    let currency_display = get_enum_option(
+        cx,
+        options,
+        cx.names.currency_display(),
+        CurrencyDisplay::Symbol,
+    )?;
+    let currency_sign = get_enum_option(
+        cx,
+        options,
+        cx.names.currency_sign(),
+        CurrencySign::Standard,
+    )?;
+
+    let unit = get_string_option(cx, options, cx.names.unit(), &[])?;
+    match &unit {
+        None if style == NumberFormatStyle::Unit => {
+            return type_error(cx, "unit option is required for unit style");
+        }
+        Some(unit) if !is_well_formed_unit_identifier(unit) => {
+            return range_error(cx, &format!("invalid unit {unit}"));
+        }
+        _ => {}
+    }
+
+    let unit_display = get_enum_option(cx, options, cx.names.unit_display(), UnitDisplay::Short)?;
+
+    let currency = currency
+        .filter(|_| style == NumberFormatStyle::Currency)
+        .map(|currency| currency.to_ascii_uppercase());
+    let unit = unit.filter(|_| style == NumberFormatStyle::Unit);
+
+    let notation = get_enum_option(cx, options, cx.names.notation(), Notation::Standard)?;
+
+    let (mnfd_default, mxfd_default) = match &currency {
+        Some(currency) if notation == Notation::Standard => {
+            let digits = currency_digits(currency);
+            (digits, digits)
+        }
+        _ if style == NumberFormatStyle::Percent => (0, 0),
+        _ => (0, 3),
+    };
+
+    let is_compact = notation == Notation::Compact;
+    let digits =
+        set_number_format_digit_options(cx, options, mnfd_default, mxfd_default, is_compact)?;
+
+    let compact_display = get_enum_option(
+        cx,
+        options,
+        cx.names.compact_display(),
+        CompactDisplay::Short,
+    )?;
+
+    let default_use_grouping = if is_compact {
+        UseGrouping::Min2
+    } else {
+        UseGrouping::Auto
+    };
+    let use_grouping = get_use_grouping_option(cx, options, default_use_grouping)?;
+
+    let sign_display = get_enum_option(cx, options, cx.names.sign_display(), SignDisplay::Auto)?;
+
+    Ok(NumberFormatConfig {
+        locale: resolved.locale,
+        numbering_system: resolved.values[0].clone(),
+        currency,
+        unit,
+        options: NumberFormatOptions {
+            style,
+            currency_display,
+            currency_sign,
+            unit_display,
+            notation,
+            compact_display,
+            use_grouping,
+            sign_display,
+            digits,
+        },
+    })
+}
+
+/// GetBooleanOrStringNumberFormatOption (https://tc39.es/ecma402/#sec-getbooleanorstringnumberformatoption)
+/// for the `useGrouping` option.
+fn get_use_grouping_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    default: UseGrouping,
+) -> EvalResult<UseGrouping> {
+    let value = get(cx, options, cx.names.use_grouping())?;
+    if value.is_undefined() {
+        return Ok(default);
+    }
+
+    if value.is_true() {
+        return Ok(UseGrouping::Always);
+    }
+
+    if !to_boolean(*value) {
+        return Ok(UseGrouping::False);
+    }
+
+    let string = to_string(cx, value)?.format(cx)?;
+    match string.as_str() {
+        "true" | "false" => Ok(default),
+        "min2" => Ok(UseGrouping::Min2),
+        "auto" => Ok(UseGrouping::Auto),
+        "always" => Ok(UseGrouping::Always),
+        _ => range_error(
+            cx,
+            &format!("invalid value {string} for option useGrouping"),
+        ),
+    }
+}
+
+// Intl.NumberFormat Objects (https://tc39.es/ecma402/#numberformat-objects)
+extend_object! {
+    pub struct NumberFormatObject {
+        locale: HeapPtr<FlatString>,
+        numbering_system: HeapPtr<FlatString>,
+        currency: Option<HeapPtr<FlatString>>,
+        unit: Option<HeapPtr<FlatString>>,
+        options: NumberFormatOptions,
+        // The format function returned by the `format` getter, created on first access
+        bound_format: Option<HeapPtr<ObjectValue>>,
+    }
+}
+
+impl NumberFormatObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &NumberFormatConfig,
+    ) -> AllocResult<StackRoot<NumberFormatObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+        let numbering_system = cx.alloc_string(&config.numbering_system)?;
+
+        let currency = match &config.currency {
+            Some(currency) => Some(cx.alloc_string(currency)?),
+            None => None,
+        };
+        let unit = match &config.unit {
+            Some(unit) => Some(cx.alloc_string(unit)?),
+            None => None,
+        };
+
+        let mut object = object_create_with_proto::<NumberFormatObject>(
+            cx,
+            HeapItemKind::NumberFormatObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.numbering_system, *numbering_system);
+        set_uninit!(object.currency, currency.map(|currency| *currency));
+        set_uninit!(object.unit, unit.map(|unit| *unit));
+        set_uninit!(object.options, config.options);
+        set_uninit!(object.bound_format, None);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(NumberFormatObject, "Intl.NumberFormat");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn numbering_system(&self) -> HeapPtr<FlatString> {
+        self.numbering_system
+    }
+
+    pub fn currency(&self) -> Option<HeapPtr<FlatString>> {
+        self.currency
+    }
+
+    pub fn unit(&self) -> Option<HeapPtr<FlatString>> {
+        self.unit
+    }
+
+    pub fn options(&self) -> &NumberFormatOptions {
+        &self.options
+    }
+
+    pub fn bound_format(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.bound_format
+    }
+
+    pub fn set_bound_format(&mut self, bound_format: HeapPtr<ObjectValue>) {
+        self.bound_format = Some(bound_format);
+    }
+
+    pub fn config(&self) -> NumberFormatConfig {
+        NumberFormatConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            numbering_system: self.numbering_system.to_wtf8_string().to_string(),
+            currency: self
+                .currency
+                .map(|currency| currency.to_wtf8_string().to_string()),
+            unit: self.unit.map(|unit| unit.to_wtf8_string().to_string()),
+            options: self.options,
+        }
+    }
+}
+
+pub struct NumberFormatConstructor;
+
+impl NumberFormatConstructor {
+    /// Properties of the Intl.NumberFormat Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-numberformat-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.number_format(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::NumberFormatPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.NumberFormat (https://tc39.es/ecma402/#sec-intl.numberformat)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        // NumberFormat may be called as a function, in which case the active function is used
+        let new_target = match cx.current_new_target() {
+            Some(new_target) => new_target,
+            None => cx.current_function(),
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::NumberFormatPrototype)?;
+        let config = resolve_number_format_config(cx, locales, options)?;
+
+        Ok(NumberFormatObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.NumberFormat.supportedLocalesOf (https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<NumberFormatObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<NumberFormatObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+        visitor.visit_pointer(&mut self.numbering_system);
+        visitor.visit_pointer_opt(&mut self.currency);
+        visitor.visit_pointer_opt(&mut self.unit);
+        visitor.visit_pointer_opt(&mut self.bound_format);
+    }
+}
+
+/// Format a number using the number format for the given locales and options, as used by
+/// `Number.prototype.toLocaleString` and `BigInt.prototype.toLocaleString`.
+pub fn format_number_with_locales(
+    cx: Context,
+    x: StackRoot<Value>,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<String> {
+    let config = resolve_number_format_config(cx, locales, options)?;
+    let x = to_intl_mathematical_value(cx, x)?;
+
+    Ok(config.icu_formatter(cx).format(&x))
+}
@@ -0,0 +1,313 @@
+use alloc::vec::Vec;
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        array_object::create_array_from_list,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::ordinary_object_create,
+        property::Property,
+        realm::Realm,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    number_format::{
+        to_intl_mathematical_value, IntlMathematicalValue, Notation, RoundingType, UseGrouping,
+    },
+    number_format_constructor::NumberFormatObject,
+    options::IntlEnum,
+    parts::create_part_object,
+};
+
+pub struct NumberFormatPrototype;
+
+impl NumberFormatPrototype {
+    /// Properties of the Intl.NumberFormat Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-numberformat-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once NumberFormatConstructor has been created
+        object.intrinsic_getter(cx, cx.names.format(), Self::get_format, realm)?;
+        object.intrinsic_func(cx, cx.names.format_range(), Self::format_range, 2, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_range_to_parts(),
+            Self::format_range_to_parts,
+            2,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_to_parts(),
+            Self::format_to_parts,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.NumberFormat")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Intl.NumberFormat.prototype.format (https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format)
+    pub fn get_format(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut number_format = NumberFormatObject::cast_from_value(cx, this_value)?;
+
+        if let Some(bound_format) = number_format.bound_format() {
+            return Ok(bound_format.to_stack(cx).as_value());
+        }
+
+        let mut bound_format = BuiltinFunction::create(
+            cx,
+            Self::number_format_function,
+            1,
+            cx.names.empty_string(),
+            cx.current_realm(),
+            None,
+        )?;
+
+        bound_format.private_element_set(
+            cx,
+            cx.well_known_symbols.intl_object().cast(),
+            number_format.as_value(),
+        )?;
+
+        number_format.set_bound_format(*bound_format);
+
+        Ok(bound_format.as_value())
+    }
+
+    /// Number Format Functions (https://tc39.es/ecma402/#sec-number-format-functions)
+    pub fn number_format_function(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let number_format = cx
+            .current_function()
+            .private_element_find(cx, cx.well_known_symbols.intl_object().cast())
+            .unwrap()
+            .value()
+            .as_object()
+            .cast::<NumberFormatObject>();
+
+        let value = get_argument(cx, arguments, 0);
+        let x = to_intl_mathematical_value(cx, value)?;
+
+        let formatted = number_format.config().icu_formatter(cx).format(&x);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.NumberFormat.prototype.formatToParts (https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts)
+    pub fn format_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let number_format = NumberFormatObject::cast_from_value(cx, this_value)?;
+
+        let value = get_argument(cx, arguments, 0);
+        let x = to_intl_mathematical_value(cx, value)?;
+
+        let parts = number_format.config().icu_formatter(cx).format_to_parts(&x);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for part in &parts {
+            part_objects.push(create_part_object(cx, part, None)?);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.NumberFormat.prototype.formatRange (https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange)
+    pub fn format_range(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let number_format = NumberFormatObject::cast_from_value(cx, this_value)?;
+        let (x, y) = get_range_arguments(cx, arguments)?;
+
+        let formatted = number_format
+            .config()
+            .icu_formatter(cx)
+            .format_range(&x, &y);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.NumberFormat.prototype.formatRangeToParts (https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts)
+    pub fn format_range_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let number_format = NumberFormatObject::cast_from_value(cx, this_value)?;
+        let (x, y) = get_range_arguments(cx, arguments)?;
+
+        let parts = number_format
+            .config()
+            .icu_formatter(cx)
+            .format_range_to_parts(&x, &y);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for (part, source) in &parts {
+            part_objects.push(create_part_object(cx, part, Some(*source))?);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.NumberFormat.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let number_format = NumberFormatObject::cast_from_value(cx, this_value)?;
+        let options = *number_format.options();
+        let digits = options.digits;
+
+        let object = ordinary_object_create(cx)?;
+
+        macro_rules! add_property {
+            ($name:ident, $value:expr) => {{
+                let value = $value;
+                must!(create_data_property_or_throw(
+                    cx,
+                    object,
+                    cx.names.$name(),
+                    value
+                ));
+            }};
+        }
+
+        macro_rules! add_string_property {
+            ($name:ident, $string:expr) => {
+                add_property!($name, cx.alloc_string($string)?.as_value())
+            };
+        }
+
+        add_property!(locale, number_format.locale().to_stack(cx).as_value());
+        add_property!(
+            numbering_system,
+            number_format.numbering_system().to_stack(cx).as_value()
+        );
+        add_string_property!(style, options.style.as_str());
+
+        if let Some(currency) = number_format.currency() {
+            add_property!(currency, currency.to_stack(cx).as_value());
+            add_string_property!(currency_display, options.currency_display.as_str());
+            add_string_property!(currency_sign, options.currency_sign.as_str());
+        }
+
+        if let Some(unit) = number_format.unit() {
+            add_property!(unit, unit.to_stack(cx).as_value());
+            add_string_property!(unit_display, options.unit_display.as_str());
+        }
+
+        add_property!(
+            minimum_integer_digits,
+            cx.smi(digits.minimum_integer_digits as i32)
+        );
+
+        if digits.rounding_type != RoundingType::SignificantDigits {
+            add_property!(
+                minimum_fraction_digits,
+                cx.smi(digits.minimum_fraction_digits as i32)
+            );
+            add_property!(
+                maximum_fraction_digits,
+                cx.smi(digits.maximum_fraction_digits as i32)
+            );
+        }
+
+        if digits.rounding_type != RoundingType::FractionDigits {
+            add_property!(
+                minimum_significant_digits,
+                cx.smi(digits.minimum_significant_digits as i32)
+            );
+            add_property!(
+                maximum_significant_digits,
+                cx.smi(digits.maximum_significant_digits as i32)
+            );
+        }
+
+        match options.use_grouping {
+            UseGrouping::False => add_property!(use_grouping, cx.bool(false)),
+            use_grouping => add_string_property!(use_grouping, use_grouping.as_str()),
+        }
+
+        add_string_property!(notation, options.notation.as_str());
+
+        if options.notation == Notation::Compact {
+            add_string_property!(compact_display, options.compact_display.as_str());
+        }
+
+        add_string_property!(sign_display, options.sign_display.as_str());
+        add_property!(rounding_increment, cx.smi(digits.rounding_increment as i32));
+        add_string_property!(rounding_mode, digits.rounding_mode.as_str());
+        add_string_property!(
+            rounding_priority,
+            digits.computed_rounding_priority.as_str()
+        );
+        add_string_property!(trailing_zero_display, digits.trailing_zero_display.as_str());
+
+        Ok(object.as_value())
+    }
+}
+
+/// Convert the start and end arguments of `formatRange` and `formatRangeToParts` to mathematical
+/// values, neither of which may be NaN.
+fn get_range_arguments(
+    cx: Context,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<(IntlMathematicalValue, IntlMathematicalValue)> {
+    let start = get_argument(cx, arguments, 0);
+    let end = get_argument(cx, arguments, 1);
+
+    if start.is_undefined() || end.is_undefined() {
+        return type_error(cx, "start and end of range must be defined");
+    }
+
+    let x = to_intl_mathematical_value(cx, start)?;
+    let y = to_intl_mathematical_value(cx, end)?;
+
+    if x.is_nan() || y.is_nan() {
+        return range_error(cx, "start and end of range cannot be NaN");
+    }
+
+    Ok((x, y))
+}
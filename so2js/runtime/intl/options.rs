@@ -0,0 +1,194 @@
+use alloc::{format, string::String};
+
+use crate::runtime::{
+    abstract_operations::get,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    heap_item_descriptor::HeapItemKind,
+    object_value::ObjectValue,
+    ordinary_object::object_create_with_optional_proto,
+    property_key::PropertyKey,
+    type_utilities::{to_boolean, to_number, to_object, to_string},
+    Context, StackRoot, Value,
+};
+
+/// An enum whose variants are the allowed string values of an Intl option.
+pub trait IntlEnum: Copy + Sized {
+    /// The string values of each variant, in declaration order.
+    const VALUES: &'static [&'static str];
+
+    fn from_str(string: &str) -> Option<Self>;
+
+    fn as_str(&self) -> &'static str;
+}
+
+/// Define an enum for an Intl option that can only be one of a fixed set of string values.
+#[macro_export]
+macro_rules! intl_enum {
+    ($(#[$meta:meta])* pub enum $name:ident { $($variant:ident => $string:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $crate::runtime::intl::options::IntlEnum for $name {
+            const VALUES: &'static [&'static str] = &[$($string,)*];
+
+            fn from_str(string: &str) -> Option<Self> {
+                match string {
+                    $($string => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $string,)*
+                }
+            }
+        }
+    };
+}
+
+/// CoerceOptionsToObject (https://tc39.es/ecma402/#sec-coerceoptionstoobject)
+pub fn coerce_options_to_object(
+    cx: Context,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<ObjectValue>> {
+    if options.is_undefined() {
+        let object = object_create_with_optional_proto::<ObjectValue>(
+            cx,
+            HeapItemKind::OrdinaryObject,
+            None,
+        )?;
+        return Ok(object.to_stack(cx));
+    }
+
+    to_object(cx, options)
+}
+
+/// GetOptionsObject (https://tc39.es/ecma402/#sec-getoptionsobject)
+pub fn get_options_object(
+    cx: Context,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<ObjectValue>> {
+    if options.is_undefined() {
+        let object = object_create_with_optional_proto::<ObjectValue>(
+            cx,
+            HeapItemKind::OrdinaryObject,
+            None,
+        )?;
+        return Ok(object.to_stack(cx));
+    }
+
+    if !options.is_object() {
+        return type_error(cx, "options must be an object");
+    }
+
+    Ok(options.as_object())
+}
+
+/// GetOption (https://tc39.es/ecma402/#sec-getoption) for options of type "string".
+///
+/// Returns None if the option is undefined. If `values` is non-empty then the option must be one
+/// of the provided values, otherwise a RangeError is thrown.
+pub fn get_string_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+    values: &[&str],
+) -> EvalResult<Option<String>> {
+    let value = get(cx, options, property)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let string = to_string(cx, value)?.format(cx)?;
+
+    if !values.is_empty() && !values.contains(&string.as_str()) {
+        return range_error(
+            cx,
+            &format!("invalid value {string} for option {}", property.format(cx)?),
+        );
+    }
+
+    Ok(Some(string))
+}
+
+/// GetOption (https://tc39.es/ecma402/#sec-getoption) for options of type "string" whose allowed
+/// values are the variants of an enum. Returns `default` if the option is undefined.
+pub fn get_enum_option<T: IntlEnum>(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+    default: T,
+) -> EvalResult<T> {
+    let value = get_string_option(cx, options, property, T::VALUES)?;
+    Ok(value.and_then(|value| T::from_str(&value)).unwrap_or(default))
+}
+
+/// GetOption (https://tc39.es/ecma402/#sec-getoption) for options of type "string" whose allowed
+/// values are the variants of an enum. Returns None if the option is undefined.
+pub fn get_optional_enum_option<T: IntlEnum>(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+) -> EvalResult<Option<T>> {
+    let value = get_string_option(cx, options, property, T::VALUES)?;
+    Ok(value.and_then(|value| T::from_str(&value)))
+}
+
+/// GetOption (https://tc39.es/ecma402/#sec-getoption) for options of type "boolean".
+///
+/// Returns None if the option is undefined.
+pub fn get_boolean_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+) -> EvalResult<Option<bool>> {
+    let value = get(cx, options, property)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    Ok(Some(to_boolean(*value)))
+}
+
+/// GetNumberOption (https://tc39.es/ecma402/#sec-getnumberoption)
+///
+/// Returns None if the option is undefined, otherwise the option must be an integer in the range
+/// `minimum..=maximum`.
+pub fn get_number_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    property: StackRoot<PropertyKey>,
+    minimum: f64,
+    maximum: f64,
+) -> EvalResult<Option<f64>> {
+    let value = get(cx, options, property)?;
+    default_number_option(cx, value, property, minimum, maximum)
+}
+
+/// DefaultNumberOption (https://tc39.es/ecma402/#sec-defaultnumberoption)
+pub fn default_number_option(
+    cx: Context,
+    value: StackRoot<Value>,
+    property: StackRoot<PropertyKey>,
+    minimum: f64,
+    maximum: f64,
+) -> EvalResult<Option<f64>> {
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let number = to_number(cx, value)?.as_number();
+    if number.is_nan() || number < minimum || number > maximum {
+        return range_error(
+            cx,
+            &format!("option {} is out of range", property.format(cx)?),
+        );
+    }
+
+    Ok(Some(number.floor()))
+}
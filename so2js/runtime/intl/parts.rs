@@ -0,0 +1,146 @@
+use core::fmt;
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use writeable::{Part, PartsWrite, Writeable};
+
+use crate::{
+    intl_enum, must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw, alloc_error::AllocResult,
+        ordinary_object::ordinary_object_create, Context, StackRoot, Value,
+    },
+};
+
+use super::options::IntlEnum;
+
+/// A single part of a formatted value, e.g. `{ type: "integer", value: "123" }`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormattedPart {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl FormattedPart {
+    pub fn new(kind: &'static str, value: &str) -> FormattedPart {
+        FormattedPart {
+            kind,
+            value: value.to_owned(),
+        }
+    }
+}
+
+/// Join the values of formatted parts into a single string.
+pub fn join_parts(parts: &[FormattedPart]) -> String {
+    parts.iter().map(|part| part.value.as_str()).collect()
+}
+
+/// Collects the parts written by an ICU formatter. The kind of each piece of text is determined
+/// from the stack of ICU parts it was written in, which is empty for text outside of any part.
+pub struct PartsCollector {
+    parts: Vec<FormattedPart>,
+    part_stack: Vec<Part>,
+    part_kind: fn(&[Part]) -> &'static str,
+}
+
+impl PartsCollector {
+    pub fn collect(
+        writeable: &impl Writeable,
+        part_kind: fn(&[Part]) -> &'static str,
+    ) -> Vec<FormattedPart> {
+        let mut collector = PartsCollector {
+            parts: Vec::new(),
+            part_stack: Vec::new(),
+            part_kind,
+        };
+        let _ = writeable.write_to_parts(&mut collector);
+        collector.parts
+    }
+}
+
+impl fmt::Write for PartsCollector {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        if string.is_empty() {
+            return Ok(());
+        }
+
+        let kind = (self.part_kind)(&self.part_stack);
+        match self.parts.last_mut() {
+            Some(last) if last.kind == kind => last.value.push_str(string),
+            _ => self.parts.push(FormattedPart::new(kind, string)),
+        }
+
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector {
+    type SubPartsWrite = PartsCollector;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        self.part_stack.push(part);
+
+        // Start a new part even if it has the same kind as the previous part, e.g. the integer
+        // digits on either side of a group separator.
+        let kind = (self.part_kind)(&self.part_stack);
+        self.parts.push(FormattedPart::new(kind, ""));
+
+        let result = f(self);
+        self.part_stack.pop();
+
+        self.parts.retain(|part| !part.value.is_empty());
+
+        result
+    }
+}
+
+intl_enum! {
+    /// The part of a formatted range that a part belongs to.
+    pub enum RangePartSource {
+        StartRange => "startRange",
+        EndRange => "endRange",
+        Shared => "shared",
+    }
+}
+
+/// Create a part object with `type` and `value` properties, as well as a `source` property for
+/// parts of a formatted range.
+pub fn create_part_object(
+    cx: Context,
+    part: &FormattedPart,
+    source: Option<RangePartSource>,
+) -> AllocResult<StackRoot<Value>> {
+    let object = ordinary_object_create(cx)?;
+
+    let kind = cx.alloc_string(part.kind)?.as_value();
+    must!(create_data_property_or_throw(
+        cx,
+        object,
+        cx.names.type_(),
+        kind
+    ));
+
+    let value = cx.alloc_string(&part.value)?.as_value();
+    must!(create_data_property_or_throw(
+        cx,
+        object,
+        cx.names.value(),
+        value
+    ));
+
+    if let Some(source) = source {
+        let source = cx.alloc_string(source.as_str())?.as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.source(),
+            source
+        ));
+    }
+
+    Ok(object.as_value())
+}
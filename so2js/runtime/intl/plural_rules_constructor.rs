@@ -0,0 +1,325 @@
+use core::mem::size_of;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use fixed_decimal::Decimal;
+use icu_locale::Locale;
+use icu_plurals::{PluralCategory, PluralRules, PluralRulesPreferences, PluralRulesWithRanges};
+
+use crate::{
+    cast_from_value_fn,
+    common::icu_data::BakedDataProvider,
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::type_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+    },
+    number_format::{set_number_format_digit_options, DigitOptions, IntlMathematicalValue},
+    options::{coerce_options_to_object, get_enum_option},
+};
+
+intl_enum! {
+    pub enum PluralRuleType {
+        Cardinal => "cardinal",
+        Ordinal => "ordinal",
+    }
+}
+
+/// All plural categories in the order they are reported by `resolvedOptions`.
+const PLURAL_CATEGORIES: &[PluralCategory] = &[
+    PluralCategory::Zero,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Few,
+    PluralCategory::Many,
+    PluralCategory::Other,
+];
+
+/// The string value of a plural category.
+pub fn plural_category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+/// The resolved locale and options of a plural rules object. The locale and type identify the ICU
+/// plural rules that are used, and the config is also used as the key for the cache of ICU
+/// plural rules.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PluralRulesConfig {
+    pub locale: String,
+    pub plural_type: PluralRuleType,
+    pub digits: DigitOptions,
+}
+
+impl PluralRulesConfig {
+    /// Create the ICU plural rules for this config.
+    fn create_icu_plural_rules(&self) -> PluralRulesWithRanges<PluralRules> {
+        let locale = Locale::try_from_str(&self.locale).unwrap_or_default();
+        let preferences = PluralRulesPreferences::from(&locale);
+
+        let create = |preferences| match self.plural_type {
+            PluralRuleType::Cardinal => {
+                PluralRulesWithRanges::try_new_cardinal_unstable(&BakedDataProvider, preferences)
+            }
+            PluralRuleType::Ordinal => {
+                PluralRulesWithRanges::try_new_ordinal_unstable(&BakedDataProvider, preferences)
+            }
+        };
+
+        create(preferences)
+            .or_else(|_| create(Default::default()))
+            .unwrap()
+    }
+
+    /// Return the ICU plural rules for this config, creating and caching them if necessary.
+    pub fn icu_plural_rules(&self, mut cx: Context) -> Rc<PluralRulesWithRanges<PluralRules>> {
+        if let Some(plural_rules) = cx.intl_cache.plural_rules.get(self) {
+            return plural_rules.clone();
+        }
+
+        let plural_rules = Rc::new(self.create_icu_plural_rules());
+        cx.intl_cache
+            .plural_rules
+            .insert(self.clone(), plural_rules.clone());
+
+        plural_rules
+    }
+
+    /// ResolvePlural (https://tc39.es/ecma402/#sec-resolveplural)
+    ///
+    /// Return the plural category of a number along with the number rounded according to the
+    /// digit options, which is None if the number is not finite.
+    pub fn resolve_plural(&self, cx: Context, n: f64) -> (PluralCategory, Option<Decimal>) {
+        let decimal = match IntlMathematicalValue::from_f64(n) {
+            IntlMathematicalValue::Finite(decimal) => decimal,
+            _ => return (PluralCategory::Other, None),
+        };
+
+        let rounded = self.digits.round(&decimal);
+
+        let category = self.icu_plural_rules(cx).rules().category_for(&rounded);
+
+        (category, Some(rounded))
+    }
+
+    /// ResolvePluralRange (https://tc39.es/ecma402/#sec-resolvepluralrange)
+    ///
+    /// Both numbers must not be NaN.
+    pub fn resolve_plural_range(&self, cx: Context, x: f64, y: f64) -> PluralCategory {
+        let (x_category, x_rounded) = self.resolve_plural(cx, x);
+        let (y_category, y_rounded) = self.resolve_plural(cx, y);
+
+        // Numbers that format to the same string use the category of the start of the range
+        let is_same_formatted = match (&x_rounded, &y_rounded) {
+            (Some(x_rounded), Some(y_rounded)) => x_rounded.to_string() == y_rounded.to_string(),
+            (None, None) => x == y,
+            _ => false,
+        };
+
+        if is_same_formatted {
+            return x_category;
+        }
+
+        self.icu_plural_rules(cx)
+            .resolve_range(x_category, y_category)
+    }
+
+    /// The plural categories used by the plural rules for this config, in the order they are
+    /// reported by `resolvedOptions`.
+    pub fn plural_categories(&self, cx: Context) -> Vec<PluralCategory> {
+        let plural_rules = self.icu_plural_rules(cx);
+        let categories = plural_rules.rules().categories().collect::<Vec<_>>();
+
+        PLURAL_CATEGORIES
+            .iter()
+            .copied()
+            .filter(|category| categories.contains(category))
+            .collect()
+    }
+}
+
+/// InitializePluralRules (https://tc39.es/ecma402/#sec-initializepluralrules)
+///
+/// Resolve the locale and options of a plural rules object from the `locales` and `options`
+/// arguments.
+pub fn resolve_plural_rules_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<PluralRulesConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = coerce_options_to_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let plural_type = get_enum_option(cx, options, cx.names.type_(), PluralRuleType::Cardinal)?;
+
+    let digits = set_number_format_digit_options(cx, options, 0, 3, false)?;
+
+    let resolved = resolve_locale(cx, &requested_locales, &[]);
+
+    Ok(PluralRulesConfig {
+        locale: resolved.locale,
+        plural_type,
+        digits,
+    })
+}
+
+// Intl.PluralRules Objects (https://tc39.es/ecma402/#pluralrules-objects)
+extend_object! {
+    pub struct PluralRulesObject {
+        locale: HeapPtr<FlatString>,
+        plural_type: PluralRuleType,
+        digits: DigitOptions,
+    }
+}
+
+impl PluralRulesObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &PluralRulesConfig,
+    ) -> AllocResult<StackRoot<PluralRulesObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+
+        let mut object = object_create_with_proto::<PluralRulesObject>(
+            cx,
+            HeapItemKind::PluralRulesObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.plural_type, config.plural_type);
+        set_uninit!(object.digits, config.digits);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PluralRulesObject, "Intl.PluralRules");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn plural_type(&self) -> PluralRuleType {
+        self.plural_type
+    }
+
+    pub fn digits(&self) -> &DigitOptions {
+        &self.digits
+    }
+
+    pub fn config(&self) -> PluralRulesConfig {
+        PluralRulesConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            plural_type: self.plural_type,
+            digits: self.digits,
+        }
+    }
+}
+
+pub struct PluralRulesConstructor;
+
+impl PluralRulesConstructor {
+    /// Properties of the Intl.PluralRules Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-pluralrules-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.plural_rules(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::PluralRulesPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.PluralRules (https://tc39.es/ecma402/#sec-intl.pluralrules)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "PluralRules constructor must be called with new");
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::PluralRulesPrototype)?;
+        let config = resolve_plural_rules_config(cx, locales, options)?;
+
+        Ok(PluralRulesObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.PluralRules.supportedLocalesOf (https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<PluralRulesObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PluralRulesObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+    }
+}
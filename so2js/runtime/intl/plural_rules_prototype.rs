@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        array_object::create_array_from_list,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::ordinary_object_create,
+        property::Property,
+        realm::Realm,
+        type_utilities::to_number,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    number_format::RoundingType,
+    options::IntlEnum,
+    plural_rules_constructor::{plural_category_name, PluralRulesObject},
+};
+
+pub struct PluralRulesPrototype;
+
+impl PluralRulesPrototype {
+    /// Properties of the Intl.PluralRules Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-pluralrules-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PluralRulesConstructor has been created
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.select(), Self::select, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.select_range(), Self::select_range, 2, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.PluralRules")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.PluralRules.prototype.select (https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select)
+    pub fn select(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plural_rules = PluralRulesObject::cast_from_value(cx, this_value)?;
+
+        let value = get_argument(cx, arguments, 0);
+        let n = to_number(cx, value)?.as_number();
+
+        let (category, _) = plural_rules.config().resolve_plural(cx, n);
+
+        Ok(cx.alloc_string(plural_category_name(category))?.as_value())
+    }
+
+    /// Intl.PluralRules.prototype.selectRange (https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.selectrange)
+    pub fn select_range(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plural_rules = PluralRulesObject::cast_from_value(cx, this_value)?;
+
+        let start = get_argument(cx, arguments, 0);
+        let end = get_argument(cx, arguments, 1);
+
+        if start.is_undefined() || end.is_undefined() {
+            return type_error(cx, "start and end of range must be defined");
+        }
+
+        let x = to_number(cx, start)?.as_number();
+        let y = to_number(cx, end)?.as_number();
+
+        if x.is_nan() || y.is_nan() {
+            return range_error(cx, "start and end of range cannot be NaN");
+        }
+
+        let category = plural_rules.config().resolve_plural_range(cx, x, y);
+
+        Ok(cx.alloc_string(plural_category_name(category))?.as_value())
+    }
+
+    /// Intl.PluralRules.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plural_rules = PluralRulesObject::cast_from_value(cx, this_value)?;
+        let digits = *plural_rules.digits();
+
+        let object = ordinary_object_create(cx)?;
+
+        macro_rules! add_property {
+            ($name:ident, $value:expr) => {{
+                let value = $value;
+                must!(create_data_property_or_throw(
+                    cx,
+                    object,
+                    cx.names.$name(),
+                    value
+                ));
+            }};
+        }
+
+        macro_rules! add_string_property {
+            ($name:ident, $string:expr) => {
+                add_property!($name, cx.alloc_string($string)?.as_value())
+            };
+        }
+
+        add_property!(locale, plural_rules.locale().to_stack(cx).as_value());
+        add_string_property!(type_, plural_rules.plural_type().as_str());
+        add_property!(
+            minimum_integer_digits,
+            cx.smi(digits.minimum_integer_digits as i32)
+        );
+
+        if digits.rounding_type != RoundingType::SignificantDigits {
+            add_property!(
+                minimum_fraction_digits,
+                cx.smi(digits.minimum_fraction_digits as i32)
+            );
+            add_property!(
+                maximum_fraction_digits,
+                cx.smi(digits.maximum_fraction_digits as i32)
+            );
+        }
+
+        if digits.rounding_type != RoundingType::FractionDigits {
+            add_property!(
+                minimum_significant_digits,
+                cx.smi(digits.minimum_significant_digits as i32)
+            );
+            add_property!(
+                maximum_significant_digits,
+                cx.smi(digits.maximum_significant_digits as i32)
+            );
+        }
+
+        let categories = plural_rules.config().plural_categories(cx);
+        let mut category_values = Vec::with_capacity(categories.len());
+        for category in categories {
+            category_values.push(cx.alloc_string(plural_category_name(category))?.as_value());
+        }
+        add_property!(
+            plural_categories,
+            create_array_from_list(cx, &category_values)?.as_value()
+        );
+
+        add_property!(rounding_increment, cx.smi(digits.rounding_increment as i32));
+        add_string_property!(rounding_mode, digits.rounding_mode.as_str());
+        add_string_property!(
+            rounding_priority,
+            digits.computed_rounding_priority.as_str()
+        );
+        add_string_property!(trailing_zero_display, digits.trailing_zero_display.as_str());
+
+        Ok(object.as_value())
+    }
+}
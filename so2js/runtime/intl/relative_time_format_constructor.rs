@@ -0,0 +1,397 @@
+use core::mem::size_of;
+
+use alloc::{
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use fixed_decimal::Decimal;
+use icu_experimental::relativetime::{
+    options::Numeric, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    RelativeTimeFormatterPreferences,
+};
+use icu_locale::{extensions::unicode::key, Locale};
+use writeable::Writeable;
+
+use crate::{
+    cast_from_value_fn,
+    common::icu_data::BakedDataProvider,
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    collator_constructor::is_unicode_type_sequence,
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+        RelevantExtensionKey,
+    },
+    number_format::{number_part_kind, DigitOptions, IntlMathematicalValue},
+    number_format_constructor::supported_numbering_systems,
+    options::{coerce_options_to_object, get_enum_option, get_string_option, IntlEnum},
+    parts::{FormattedPart, PartsCollector},
+};
+
+intl_enum! {
+    pub enum RelativeTimeStyle {
+        Long => "long",
+        Short => "short",
+        Narrow => "narrow",
+    }
+}
+
+intl_enum! {
+    pub enum RelativeTimeNumeric {
+        Always => "always",
+        Auto => "auto",
+    }
+}
+
+intl_enum! {
+    pub enum RelativeTimeUnit {
+        Second => "second",
+        Minute => "minute",
+        Hour => "hour",
+        Day => "day",
+        Week => "week",
+        Month => "month",
+        Quarter => "quarter",
+        Year => "year",
+    }
+}
+
+impl RelativeTimeUnit {
+    /// SingularRelativeTimeUnit (https://tc39.es/ecma402/#sec-singularrelativetimeunit)
+    ///
+    /// Parse a unit which may be either singular or plural.
+    pub fn from_singular_or_plural(unit: &str) -> Option<RelativeTimeUnit> {
+        RelativeTimeUnit::from_str(unit.strip_suffix('s').unwrap_or(unit))
+    }
+}
+
+/// The resolved locale and options of a relative time format. Along with a unit, identifies the
+/// ICU relative time formatter that is used to format values in that unit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RelativeTimeFormatConfig {
+    pub locale: String,
+    pub numbering_system: String,
+    pub style: RelativeTimeStyle,
+    pub numeric: RelativeTimeNumeric,
+}
+
+impl RelativeTimeFormatConfig {
+    /// Create the ICU relative time formatter for a unit in this config.
+    fn create_icu_formatter(&self, unit: RelativeTimeUnit) -> RelativeTimeFormatter {
+        // Formatters use the numbering system that was resolved, even if it was not requested with
+        // a `nu` keyword.
+        let data_locale = self.locale.split("-u-").next().unwrap();
+        let locale_tag = format!("{data_locale}-u-nu-{}", self.numbering_system);
+        let locale = Locale::try_from_str(&locale_tag).unwrap_or_default();
+
+        let create = |preferences: RelativeTimeFormatterPreferences| {
+            let mut options = RelativeTimeFormatterOptions::default();
+            options.numeric = match self.numeric {
+                RelativeTimeNumeric::Always => Numeric::Always,
+                RelativeTimeNumeric::Auto => Numeric::Auto,
+            };
+
+            macro_rules! try_new {
+                ($(($style:ident, $unit:ident) => $constructor:ident,)*) => {
+                    match (self.style, unit) {
+                        $((RelativeTimeStyle::$style, RelativeTimeUnit::$unit) => {
+                            RelativeTimeFormatter::$constructor(
+                                &BakedDataProvider,
+                                preferences,
+                                options,
+                            )
+                        })*
+                    }
+                };
+            }
+
+            try_new! {
+                (Long, Second) => try_new_long_second_unstable,
+                (Long, Minute) => try_new_long_minute_unstable,
+                (Long, Hour) => try_new_long_hour_unstable,
+                (Long, Day) => try_new_long_day_unstable,
+                (Long, Week) => try_new_long_week_unstable,
+                (Long, Month) => try_new_long_month_unstable,
+                (Long, Quarter) => try_new_long_quarter_unstable,
+                (Long, Year) => try_new_long_year_unstable,
+                (Short, Second) => try_new_short_second_unstable,
+                (Short, Minute) => try_new_short_minute_unstable,
+                (Short, Hour) => try_new_short_hour_unstable,
+                (Short, Day) => try_new_short_day_unstable,
+                (Short, Week) => try_new_short_week_unstable,
+                (Short, Month) => try_new_short_month_unstable,
+                (Short, Quarter) => try_new_short_quarter_unstable,
+                (Short, Year) => try_new_short_year_unstable,
+                (Narrow, Second) => try_new_narrow_second_unstable,
+                (Narrow, Minute) => try_new_narrow_minute_unstable,
+                (Narrow, Hour) => try_new_narrow_hour_unstable,
+                (Narrow, Day) => try_new_narrow_day_unstable,
+                (Narrow, Week) => try_new_narrow_week_unstable,
+                (Narrow, Month) => try_new_narrow_month_unstable,
+                (Narrow, Quarter) => try_new_narrow_quarter_unstable,
+                (Narrow, Year) => try_new_narrow_year_unstable,
+            }
+        };
+
+        create(RelativeTimeFormatterPreferences::from(&locale))
+            .or_else(|_| create(Default::default()))
+            .unwrap()
+    }
+
+    /// Return the ICU relative time formatter for a unit in this config, creating and caching it
+    /// if necessary.
+    pub fn icu_formatter(
+        &self,
+        mut cx: Context,
+        unit: RelativeTimeUnit,
+    ) -> Rc<RelativeTimeFormatter> {
+        let cache_key = (self.clone(), unit);
+        if let Some(formatter) = cx.intl_cache.relative_time_formatters.get(&cache_key) {
+            return formatter.clone();
+        }
+
+        let formatter = Rc::new(self.create_icu_formatter(unit));
+        cx.intl_cache
+            .relative_time_formatters
+            .insert(cache_key, formatter.clone());
+
+        formatter
+    }
+
+    /// PartitionRelativeTimePattern (https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern)
+    ///
+    /// The value must be finite.
+    pub fn format_to_parts(
+        &self,
+        cx: Context,
+        value: f64,
+        unit: RelativeTimeUnit,
+    ) -> Vec<FormattedPart> {
+        let formatter = self.icu_formatter(cx, unit);
+        let decimal = round_relative_time_value(value);
+
+        PartsCollector::collect(&formatter.format(decimal), number_part_kind)
+    }
+
+    /// FormatRelativeTime (https://tc39.es/ecma402/#sec-FormatRelativeTime)
+    ///
+    /// The value must be finite.
+    pub fn format(&self, cx: Context, value: f64, unit: RelativeTimeUnit) -> String {
+        let formatter = self.icu_formatter(cx, unit);
+        let decimal = round_relative_time_value(value);
+
+        formatter.format(decimal).write_to_string().into_owned()
+    }
+}
+
+/// Round a finite value using the digit options of the number format used by a relative time
+/// format. Sign is preserved so that negative zero is formatted in the past.
+fn round_relative_time_value(value: f64) -> Decimal {
+    match IntlMathematicalValue::from_f64(value) {
+        IntlMathematicalValue::Finite(decimal) => DigitOptions::DEFAULT.round(&decimal),
+        _ => unreachable!("relative time value must be finite"),
+    }
+}
+
+/// InitializeRelativeTimeFormat (https://tc39.es/ecma402/#sec-InitializeRelativeTimeFormat)
+///
+/// Resolve the locale and options of a relative time format from the `locales` and `options`
+/// arguments.
+pub fn resolve_relative_time_format_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<RelativeTimeFormatConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = coerce_options_to_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let numbering_system = get_string_option(cx, options, cx.names.numbering_system(), &[])?;
+    if let Some(numbering_system) = &numbering_system {
+        if !is_unicode_type_sequence(numbering_system) {
+            return range_error(cx, "invalid numbering system");
+        }
+    }
+
+    let relevant_keys = [RelevantExtensionKey {
+        key: key!("nu"),
+        option_value: numbering_system,
+        supported_values: supported_numbering_systems,
+    }];
+
+    let resolved = resolve_locale(cx, &requested_locales, &relevant_keys);
+
+    let style = get_enum_option(cx, options, cx.names.style(), RelativeTimeStyle::Long)?;
+    let numeric = get_enum_option(cx, options, cx.names.numeric(), RelativeTimeNumeric::Always)?;
+
+    Ok(RelativeTimeFormatConfig {
+        locale: resolved.locale,
+        numbering_system: resolved.values[0].clone(),
+        style,
+        numeric,
+    })
+}
+
+// Intl.RelativeTimeFormat Objects (https://tc39.es/ecma402/#relativetimeformat-objects)
+extend_object! {
+    pub struct RelativeTimeFormatObject {
+        locale: HeapPtr<FlatString>,
+        numbering_system: HeapPtr<FlatString>,
+        style: RelativeTimeStyle,
+        numeric: RelativeTimeNumeric,
+    }
+}
+
+impl RelativeTimeFormatObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &RelativeTimeFormatConfig,
+    ) -> AllocResult<StackRoot<RelativeTimeFormatObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+        let numbering_system = cx.alloc_string(&config.numbering_system)?;
+
+        let mut object = object_create_with_proto::<RelativeTimeFormatObject>(
+            cx,
+            HeapItemKind::RelativeTimeFormatObject,
+            proto,
+        )?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.numbering_system, *numbering_system);
+        set_uninit!(object.style, config.style);
+        set_uninit!(object.numeric, config.numeric);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(RelativeTimeFormatObject, "Intl.RelativeTimeFormat");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn numbering_system(&self) -> HeapPtr<FlatString> {
+        self.numbering_system
+    }
+
+    pub fn style(&self) -> RelativeTimeStyle {
+        self.style
+    }
+
+    pub fn numeric(&self) -> RelativeTimeNumeric {
+        self.numeric
+    }
+
+    pub fn config(&self) -> RelativeTimeFormatConfig {
+        RelativeTimeFormatConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            numbering_system: self.numbering_system.to_wtf8_string().to_string(),
+            style: self.style,
+            numeric: self.numeric,
+        }
+    }
+}
+
+pub struct RelativeTimeFormatConstructor;
+
+impl RelativeTimeFormatConstructor {
+    /// Properties of the Intl.RelativeTimeFormat Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-relativetimeformat-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.relative_time_format(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::RelativeTimeFormatPrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.RelativeTimeFormat (https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "RelativeTimeFormat constructor must be called with new");
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::RelativeTimeFormatPrototype)?;
+        let config = resolve_relative_time_format_config(cx, locales, options)?;
+
+        Ok(RelativeTimeFormatObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.RelativeTimeFormat.supportedLocalesOf (https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.supportedLocalesOf)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<RelativeTimeFormatObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<RelativeTimeFormatObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+        visitor.visit_pointer(&mut self.numbering_system);
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::{format, vec::Vec};
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        array_object::create_array_from_list,
+        error::range_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::ordinary_object_create,
+        property::Property,
+        realm::Realm,
+        type_utilities::{to_number, to_string},
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    options::IntlEnum,
+    parts::create_part_object,
+    relative_time_format_constructor::{RelativeTimeFormatObject, RelativeTimeUnit},
+};
+
+pub struct RelativeTimeFormatPrototype;
+
+impl RelativeTimeFormatPrototype {
+    /// Properties of the Intl.RelativeTimeFormat Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-relativetimeformat-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once RelativeTimeFormatConstructor has been created
+        object.intrinsic_func(cx, cx.names.format(), Self::format, 2, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.format_to_parts(),
+            Self::format_to_parts,
+            2,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.RelativeTimeFormat")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.RelativeTimeFormat.prototype.format (https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format)
+    pub fn format(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let relative_time_format = RelativeTimeFormatObject::cast_from_value(cx, this_value)?;
+        let (value, unit) = get_value_and_unit(cx, arguments)?;
+
+        let formatted = relative_time_format.config().format(cx, value, unit);
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
+    /// Intl.RelativeTimeFormat.prototype.formatToParts (https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts)
+    pub fn format_to_parts(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let relative_time_format = RelativeTimeFormatObject::cast_from_value(cx, this_value)?;
+        let (value, unit) = get_value_and_unit(cx, arguments)?;
+
+        let parts = relative_time_format
+            .config()
+            .format_to_parts(cx, value, unit);
+
+        let mut part_objects = Vec::with_capacity(parts.len());
+        for part in &parts {
+            let part_object = create_part_object(cx, part, None)?;
+
+            // Parts of the formatted number also have the unit they are in
+            if part.kind != "literal" {
+                let unit = cx.alloc_string(unit.as_str())?.as_value();
+                must!(create_data_property_or_throw(
+                    cx,
+                    part_object.as_object(),
+                    cx.names.unit(),
+                    unit
+                ));
+            }
+
+            part_objects.push(part_object);
+        }
+
+        Ok(create_array_from_list(cx, &part_objects)?.as_value())
+    }
+
+    /// Intl.RelativeTimeFormat.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.resolvedOptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let relative_time_format = RelativeTimeFormatObject::cast_from_value(cx, this_value)?;
+
+        let object = ordinary_object_create(cx)?;
+
+        let locale = relative_time_format.locale().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.locale_(),
+            locale
+        ));
+
+        let style = cx
+            .alloc_string(relative_time_format.style().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.style(),
+            style
+        ));
+
+        let numeric = cx
+            .alloc_string(relative_time_format.numeric().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.numeric(),
+            numeric
+        ));
+
+        let numbering_system = relative_time_format
+            .numbering_system()
+            .to_stack(cx)
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.numbering_system(),
+            numbering_system
+        ));
+
+        Ok(object.as_value())
+    }
+}
+
+/// Convert the value and unit arguments of `format` and `formatToParts`. The value must be finite
+/// and the unit must be a singular or plural relative time unit.
+fn get_value_and_unit(
+    cx: Context,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<(f64, RelativeTimeUnit)> {
+    let value = get_argument(cx, arguments, 0);
+    let value = to_number(cx, value)?.as_number();
+
+    let unit = get_argument(cx, arguments, 1);
+    let unit = to_string(cx, unit)?.format(cx)?;
+
+    if !value.is_finite() {
+        return range_error(cx, "relative time value must be finite");
+    }
+
+    match RelativeTimeUnit::from_singular_or_plural(&unit) {
+        Some(unit) => Ok((value, unit)),
+        None => range_error(cx, &format!("invalid relative time unit {unit}")),
+    }
+}
@@ -0,0 +1,121 @@
+use core::mem::size_of;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        error::type_error,
+        eval_result::EvalResult,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        iterator::create_iter_result_object,
+        object_value::ObjectValue,
+        ordinary_object::object_create,
+        property::Property,
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{segmenter_constructor::SegmenterObject, segments_object::create_segment_data_object};
+
+// Segment Iterator Objects (https://tc39.es/ecma402/#sec-segment-iterator-objects)
+extend_object! {
+    pub struct SegmentIterator {
+        segmenter: HeapPtr<SegmenterObject>,
+        string: HeapPtr<FlatString>,
+        // Code unit index of the start of the next segment
+        position: u32,
+    }
+}
+
+impl SegmentIterator {
+    /// CreateSegmentIterator (https://tc39.es/ecma402/#sec-createsegmentiterator)
+    pub fn new(
+        cx: Context,
+        segmenter: StackRoot<SegmenterObject>,
+        string: StackRoot<FlatString>,
+    ) -> AllocResult<StackRoot<SegmentIterator>> {
+        let mut object = object_create::<SegmentIterator>(
+            cx,
+            HeapItemKind::SegmentIterator,
+            Intrinsic::SegmentIteratorPrototype,
+        )?;
+
+        set_uninit!(object.segmenter, *segmenter);
+        set_uninit!(object.string, *string);
+        set_uninit!(object.position, 0);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(SegmentIterator, "Segmenter String Iterator");
+}
+
+/// The %IntlSegmentIteratorPrototype% Object (https://tc39.es/ecma402/#sec-%intlsegmentiteratorprototype%-object)
+pub struct SegmentIteratorPrototype;
+
+impl SegmentIteratorPrototype {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let proto = realm.get_intrinsic(Intrinsic::IteratorPrototype);
+        let mut object = ObjectValue::new(cx, Some(proto), true)?;
+
+        object.intrinsic_func(cx, cx.names.next(), Self::next, 0, realm)?;
+
+        // %IntlSegmentIteratorPrototype% [ %Symbol.toStringTag% ] (https://tc39.es/ecma402/#sec-%intlsegmentiteratorprototype%.%symbol.tostringtag%)
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Segmenter String Iterator")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// %IntlSegmentIteratorPrototype%.next (https://tc39.es/ecma402/#sec-%intlsegmentiteratorprototype%.next)
+    pub fn next(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let mut iterator = SegmentIterator::cast_from_value(cx, this_value)?;
+        let string = iterator.string.to_stack(cx);
+
+        let segment = iterator
+            .segmenter
+            .config()
+            .icu_segmenter(cx)
+            .next_segment(*string, iterator.position);
+
+        match segment {
+            None => Ok(create_iter_result_object(cx, cx.undefined(), true)?),
+            Some(segment) => {
+                iterator.position = segment.end;
+
+                let segment_data = create_segment_data_object(cx, string, segment)?;
+                Ok(create_iter_result_object(
+                    cx,
+                    segment_data.as_value(),
+                    false,
+                )?)
+            }
+        }
+    }
+}
+
+impl HeapItem for HeapPtr<SegmentIterator> {
+    fn byte_size(&self) -> usize {
+        size_of::<SegmentIterator>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.segmenter);
+        visitor.visit_pointer(&mut self.string);
+    }
+}
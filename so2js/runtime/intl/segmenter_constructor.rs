@@ -0,0 +1,380 @@
+use core::mem::size_of;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+};
+
+use icu_locale::Locale;
+use icu_segmenter::{
+    options::{SentenceBreakOptions, WordBreakOptions},
+    GraphemeClusterSegmenter, SentenceSegmenter, WordSegmenter,
+};
+
+use crate::{
+    cast_from_value_fn,
+    common::{icu_data::BakedDataProvider, string::StringWidth},
+    extend_object, intl_enum,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::type_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, get_locale_matcher_option, resolve_locale, supported_locales_of,
+    },
+    options::{get_enum_option, get_options_object},
+};
+
+intl_enum! {
+    pub enum SegmenterGranularity {
+        Grapheme => "grapheme",
+        Word => "word",
+        Sentence => "sentence",
+    }
+}
+
+/// The resolved locale and options of a segmenter. Identifies the ICU segmenter that is used to
+/// find segment boundaries, so is also used as the key for the cache of ICU segmenters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SegmenterConfig {
+    pub locale: String,
+    pub granularity: SegmenterGranularity,
+}
+
+impl SegmenterConfig {
+    /// Create the ICU segmenter for this config.
+    fn create_icu_segmenter(&self) -> IcuSegmenter {
+        let locale = Locale::try_from_str(&self.locale).unwrap_or_default();
+
+        match self.granularity {
+            SegmenterGranularity::Grapheme => IcuSegmenter::Grapheme(
+                GraphemeClusterSegmenter::try_new_unstable(&BakedDataProvider).unwrap(),
+            ),
+            SegmenterGranularity::Word => {
+                let mut options = WordBreakOptions::default();
+                options.content_locale = Some(&locale.id);
+
+                let segmenter = WordSegmenter::try_new_auto_unstable(&BakedDataProvider, options)
+                    .or_else(|_| {
+                        WordSegmenter::try_new_auto_unstable(&BakedDataProvider, Default::default())
+                    })
+                    .unwrap();
+
+                IcuSegmenter::Word(segmenter)
+            }
+            SegmenterGranularity::Sentence => {
+                let mut options = SentenceBreakOptions::default();
+                options.content_locale = Some(&locale.id);
+
+                let segmenter = SentenceSegmenter::try_new_unstable(&BakedDataProvider, options)
+                    .or_else(|_| {
+                        SentenceSegmenter::try_new_unstable(&BakedDataProvider, Default::default())
+                    })
+                    .unwrap();
+
+                IcuSegmenter::Sentence(segmenter)
+            }
+        }
+    }
+
+    /// Return the ICU segmenter for this config, creating and caching it if necessary.
+    pub fn icu_segmenter(&self, mut cx: Context) -> Rc<IcuSegmenter> {
+        if let Some(segmenter) = cx.intl_cache.segmenters.get(self) {
+            return segmenter.clone();
+        }
+
+        let segmenter = Rc::new(self.create_icu_segmenter());
+        cx.intl_cache
+            .segmenters
+            .insert(self.clone(), segmenter.clone());
+
+        segmenter
+    }
+}
+
+/// A segment of a string found by a segmenter, as a range of code unit indices.
+#[derive(Clone, Copy, Debug)]
+pub struct Segment {
+    pub start: u32,
+    pub end: u32,
+    /// Whether the segment is word-like, only present for word granularity.
+    pub is_word_like: Option<bool>,
+}
+
+/// The ICU segmenter for a granularity.
+pub enum IcuSegmenter {
+    Grapheme(GraphemeClusterSegmenter),
+    Word(WordSegmenter),
+    Sentence(SentenceSegmenter),
+}
+
+impl IcuSegmenter {
+    /// Visit the segments of a string in order, starting from the code unit index `start` which
+    /// must be a segment boundary. Stops once the visitor returns false.
+    ///
+    /// Segmentation resumes from `start` instead of the beginning of the string so that iterating
+    /// over all segments of a string is linear. One byte strings are segmented as Latin-1 and two
+    /// byte strings as UTF-16, so strings never need to be converted.
+    fn visit_segments(
+        &self,
+        string: HeapPtr<FlatString>,
+        start: u32,
+        mut visitor: impl FnMut(Segment) -> bool,
+    ) {
+        macro_rules! visit_breakpoints {
+            ($breakpoints:expr, $iter:ident => $is_word_like:expr) => {{
+                let mut $iter = $breakpoints;
+
+                // The first breakpoint is always the start of the text
+                $iter.next();
+
+                let mut segment_start = start;
+                while let Some(breakpoint) = $iter.next() {
+                    let segment_end = start + breakpoint as u32;
+                    let segment = Segment {
+                        start: segment_start,
+                        end: segment_end,
+                        is_word_like: $is_word_like,
+                    };
+
+                    if !visitor(segment) {
+                        return;
+                    }
+
+                    segment_start = segment_end;
+                }
+            }};
+        }
+
+        let start_index = start as usize;
+
+        match (self, string.width()) {
+            (IcuSegmenter::Grapheme(segmenter), StringWidth::OneByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_latin1(&string.as_one_byte_slice()[start_index..]),
+                iter => None
+            ),
+            (IcuSegmenter::Grapheme(segmenter), StringWidth::TwoByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_utf16(&string.as_two_byte_slice()[start_index..]),
+                iter => None
+            ),
+            (IcuSegmenter::Word(segmenter), StringWidth::OneByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_latin1(&string.as_one_byte_slice()[start_index..]),
+                iter => Some(iter.is_word_like())
+            ),
+            (IcuSegmenter::Word(segmenter), StringWidth::TwoByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_utf16(&string.as_two_byte_slice()[start_index..]),
+                iter => Some(iter.is_word_like())
+            ),
+            (IcuSegmenter::Sentence(segmenter), StringWidth::OneByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_latin1(&string.as_one_byte_slice()[start_index..]),
+                iter => None
+            ),
+            (IcuSegmenter::Sentence(segmenter), StringWidth::TwoByte) => visit_breakpoints!(
+                segmenter
+                    .as_borrowed()
+                    .segment_utf16(&string.as_two_byte_slice()[start_index..]),
+                iter => None
+            ),
+        }
+    }
+
+    /// The segment that starts at the code unit index `start`, which must be a segment boundary.
+    /// Returns None if `start` is the end of the string.
+    pub fn next_segment(&self, string: HeapPtr<FlatString>, start: u32) -> Option<Segment> {
+        let mut next_segment = None;
+        self.visit_segments(string, start, |segment| {
+            next_segment = Some(segment);
+            false
+        });
+
+        next_segment
+    }
+
+    /// The segment that contains the code unit at `index`, which must be in bounds.
+    pub fn containing_segment(&self, string: HeapPtr<FlatString>, index: u32) -> Segment {
+        let mut containing_segment = None;
+        self.visit_segments(string, 0, |segment| {
+            if segment.end > index {
+                containing_segment = Some(segment);
+                false
+            } else {
+                true
+            }
+        });
+
+        containing_segment.unwrap()
+    }
+}
+
+/// InitializeSegmenter (https://tc39.es/ecma402/#sec-intl.segmenter)
+///
+/// Resolve the locale and options of a segmenter from the `locales` and `options` arguments.
+pub fn resolve_segmenter_config(
+    cx: Context,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<SegmenterConfig> {
+    let requested_locales = canonicalize_locale_list(cx, locales)?;
+    let options = get_options_object(cx, options)?;
+
+    get_locale_matcher_option(cx, options)?;
+
+    let resolved = resolve_locale(cx, &requested_locales, &[]);
+
+    let granularity = get_enum_option(
+        cx,
+        options,
+        cx.names.granularity(),
+        SegmenterGranularity::Grapheme,
+    )?;
+
+    Ok(SegmenterConfig {
+        locale: resolved.locale,
+        granularity,
+    })
+}
+
+// Intl.Segmenter Objects (https://tc39.es/ecma402/#segmenter-objects)
+extend_object! {
+    pub struct SegmenterObject {
+        locale: HeapPtr<FlatString>,
+        granularity: SegmenterGranularity,
+    }
+}
+
+impl SegmenterObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        config: &SegmenterConfig,
+    ) -> AllocResult<StackRoot<SegmenterObject>> {
+        let locale = cx.alloc_string(&config.locale)?;
+
+        let mut object =
+            object_create_with_proto::<SegmenterObject>(cx, HeapItemKind::SegmenterObject, proto)?;
+
+        set_uninit!(object.locale, *locale);
+        set_uninit!(object.granularity, config.granularity);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(SegmenterObject, "Intl.Segmenter");
+
+    pub fn locale(&self) -> HeapPtr<FlatString> {
+        self.locale
+    }
+
+    pub fn granularity(&self) -> SegmenterGranularity {
+        self.granularity
+    }
+
+    pub fn config(&self) -> SegmenterConfig {
+        SegmenterConfig {
+            locale: self.locale.to_wtf8_string().to_string(),
+            granularity: self.granularity,
+        }
+    }
+}
+
+pub struct SegmenterConstructor;
+
+impl SegmenterConstructor {
+    /// Properties of the Intl.Segmenter Constructor (https://tc39.es/ecma402/#sec-properties-of-intl-segmenter-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.segmenter(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::SegmenterPrototype).into(),
+        )?;
+
+        func.intrinsic_func(
+            cx,
+            cx.names.supported_locales_of(),
+            Self::supported_locales_of,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Intl.Segmenter (https://tc39.es/ecma402/#sec-intl.segmenter)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Segmenter constructor must be called with new");
+        };
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        // Prototype is read from the constructor before any options are read
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::SegmenterPrototype)?;
+        let config = resolve_segmenter_config(cx, locales, options)?;
+
+        Ok(SegmenterObject::new_with_proto(cx, proto, &config)?.as_value())
+    }
+
+    /// Intl.Segmenter.supportedLocalesOf (https://tc39.es/ecma402/#sec-intl.segmenter.supportedlocalesof)
+    pub fn supported_locales_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        supported_locales_of(cx, locales, options)
+    }
+}
+
+impl HeapItem for HeapPtr<SegmenterObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<SegmenterObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.locale);
+    }
+}
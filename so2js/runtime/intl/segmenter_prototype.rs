@@ -0,0 +1,92 @@
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw, alloc_error::AllocResult,
+        eval_result::EvalResult, function::get_argument, intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue, ordinary_object::ordinary_object_create, property::Property,
+        realm::Realm, type_utilities::to_string, Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    options::IntlEnum, segmenter_constructor::SegmenterObject, segments_object::SegmentsObject,
+};
+
+pub struct SegmenterPrototype;
+
+impl SegmenterPrototype {
+    /// Properties of the Intl.Segmenter Prototype Object (https://tc39.es/ecma402/#sec-properties-of-intl-segmenter-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once SegmenterConstructor has been created
+        object.intrinsic_func(
+            cx,
+            cx.names.resolved_options(),
+            Self::resolved_options,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.segment(), Self::segment, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Intl.Segmenter")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Intl.Segmenter.prototype.segment (https://tc39.es/ecma402/#sec-intl.segmenter.prototype.segment)
+    pub fn segment(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let segmenter = SegmenterObject::cast_from_value(cx, this_value)?;
+
+        let string = get_argument(cx, arguments, 0);
+        let string = to_string(cx, string)?.flatten(cx)?;
+
+        Ok(SegmentsObject::new(cx, segmenter, string)?.as_value())
+    }
+
+    /// Intl.Segmenter.prototype.resolvedOptions (https://tc39.es/ecma402/#sec-intl.segmenter.prototype.resolvedoptions)
+    pub fn resolved_options(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let segmenter = SegmenterObject::cast_from_value(cx, this_value)?;
+
+        let object = ordinary_object_create(cx)?;
+
+        let locale = segmenter.locale().to_stack(cx).as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.locale_(),
+            locale
+        ));
+
+        let granularity = cx
+            .alloc_string(segmenter.granularity().as_str())?
+            .as_value();
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.granularity(),
+            granularity
+        ));
+
+        Ok(object.as_value())
+    }
+}
@@ -0,0 +1,180 @@
+use core::mem::size_of;
+
+use crate::{
+    cast_from_value_fn, extend_object, must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        error::type_error,
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{object_create, ordinary_object_create},
+        realm::Realm,
+        string_value::FlatString,
+        type_utilities::to_integer_or_infinity,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    segment_iterator::SegmentIterator,
+    segmenter_constructor::{Segment, SegmenterObject},
+};
+
+// Segments Objects (https://tc39.es/ecma402/#sec-segments-objects)
+extend_object! {
+    pub struct SegmentsObject {
+        segmenter: HeapPtr<SegmenterObject>,
+        string: HeapPtr<FlatString>,
+    }
+}
+
+impl SegmentsObject {
+    /// CreateSegmentsObject (https://tc39.es/ecma402/#sec-createsegmentsobject)
+    pub fn new(
+        cx: Context,
+        segmenter: StackRoot<SegmenterObject>,
+        string: StackRoot<FlatString>,
+    ) -> AllocResult<StackRoot<SegmentsObject>> {
+        let mut object = object_create::<SegmentsObject>(
+            cx,
+            HeapItemKind::SegmentsObject,
+            Intrinsic::SegmentsPrototype,
+        )?;
+
+        set_uninit!(object.segmenter, *segmenter);
+        set_uninit!(object.string, *string);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(SegmentsObject, "Segments");
+
+    pub fn segmenter(&self, cx: Context) -> StackRoot<SegmenterObject> {
+        self.segmenter.to_stack(cx)
+    }
+
+    pub fn string(&self, cx: Context) -> StackRoot<FlatString> {
+        self.string.to_stack(cx)
+    }
+}
+
+/// The %IntlSegmentsPrototype% Object (https://tc39.es/ecma402/#sec-%intlsegmentsprototype%-object)
+pub struct SegmentsPrototype;
+
+impl SegmentsPrototype {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        object.intrinsic_func(cx, cx.names.containing(), Self::containing, 1, realm)?;
+
+        let iterator_key = cx.well_known_symbols.iterator();
+        object.intrinsic_func(cx, iterator_key, Self::iterator, 0, realm)?;
+
+        Ok(object)
+    }
+
+    /// %IntlSegmentsPrototype%.containing (https://tc39.es/ecma402/#sec-%intlsegmentsprototype%.containing)
+    pub fn containing(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let segments = SegmentsObject::cast_from_value(cx, this_value)?;
+        let segmenter = segments.segmenter(cx);
+        let string = segments.string(cx);
+
+        let index = get_argument(cx, arguments, 0);
+        let n = to_integer_or_infinity(cx, index)?;
+
+        if n < 0.0 || n >= string.len() as f64 {
+            return Ok(cx.undefined());
+        }
+
+        let segment = segmenter
+            .config()
+            .icu_segmenter(cx)
+            .containing_segment(*string, n as u32);
+
+        Ok(create_segment_data_object(cx, string, segment)?.as_value())
+    }
+
+    /// %IntlSegmentsPrototype% [ %Symbol.iterator% ] (https://tc39.es/ecma402/#sec-%intlsegmentsprototype%-%symbol.iterator%)
+    pub fn iterator(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let segments = SegmentsObject::cast_from_value(cx, this_value)?;
+        let segmenter = segments.segmenter(cx);
+        let string = segments.string(cx);
+
+        Ok(SegmentIterator::new(cx, segmenter, string)?.as_value())
+    }
+}
+
+/// CreateSegmentDataObject (https://tc39.es/ecma402/#sec-createsegmentdataobject)
+pub fn create_segment_data_object(
+    cx: Context,
+    string: StackRoot<FlatString>,
+    segment: Segment,
+) -> AllocResult<StackRoot<ObjectValue>> {
+    let object = ordinary_object_create(cx)?;
+
+    let segment_string = string.substring(cx, segment.start, segment.end)?.as_value();
+    must!(create_data_property_or_throw(
+        cx,
+        object,
+        cx.names.segment(),
+        segment_string
+    ));
+
+    let index = cx.smi(segment.start as i32);
+    must!(create_data_property_or_throw(
+        cx,
+        object,
+        cx.names.index(),
+        index
+    ));
+
+    must!(create_data_property_or_throw(
+        cx,
+        object,
+        cx.names.input(),
+        string.as_value()
+    ));
+
+    // Only word segmenters report whether segments are word-like
+    if let Some(is_word_like) = segment.is_word_like {
+        let is_word_like = cx.bool(is_word_like);
+        must!(create_data_property_or_throw(
+            cx,
+            object,
+            cx.names.is_word_like(),
+            is_word_like
+        ));
+    }
+
+    Ok(object)
+}
+
+impl HeapItem for HeapPtr<SegmentsObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<SegmentsObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.segmenter);
+        visitor.visit_pointer(&mut self.string);
+    }
+}
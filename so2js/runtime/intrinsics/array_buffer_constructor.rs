@@ -11,15 +11,20 @@ use crate::{
         get,
         heap_item_descriptor::HeapItemKind,
         object_value::ObjectValue,
-        ordinary_object::object_create_from_constructor,
+        ordinary_object::{object_create, object_create_from_constructor},
         realm::Realm,
+        shared_memory::{
+            compare_exchange_seq_cst, copy_unordered, load_seq_cst, load_unordered,
+            read_modify_write_seq_cst, store_seq_cst, store_unordered, AtomicOp, SharedDataBlock,
+        },
         type_utilities::to_index,
         Context, HeapPtr, StackRoot, Value,
     },
     set_uninit,
 };
 use alloc::format;
-use core::mem::size_of;
+use alloc::sync::Arc;
+use core::{mem::size_of, ptr::NonNull};
 
 use super::{intrinsics::Intrinsic, rust_runtime::return_this};
 
@@ -36,6 +41,9 @@ extend_object! {
         // Data block containing array buffer's binary data. Detached array buffers represented as
         // a null data pointer.
         data: Option<HeapPtr<ByteArray>>,
+        // Off-heap data block for SharedArrayBuffers, which is used instead of the data and byte
+        // length fields. Kept alive by the agent.
+        shared_data_block: Option<NonNull<SharedDataBlock>>,
     }
 }
 
@@ -65,6 +73,7 @@ impl ArrayBufferObject {
         set_uninit!(object.byte_length, byte_length);
         set_uninit!(object.max_byte_length, max_byte_length);
         set_uninit!(object.data, None);
+        set_uninit!(object.shared_data_block, None);
 
         if byte_length > MAX_ARRAY_BUFFER_SIZE {
             return range_error(
@@ -114,8 +123,100 @@ impl ArrayBufferObject {
         Ok(object)
     }
 
+    /// AllocateSharedArrayBuffer (https://tc39.es/ecma262/#sec-allocatesharedarraybuffer)
+    pub fn new_shared(
+        mut cx: Context,
+        constructor: StackRoot<ObjectValue>,
+        byte_length: usize,
+        max_byte_length: Option<usize>,
+    ) -> EvalResult<StackRoot<ArrayBufferObject>> {
+        if let Some(max_byte_length) = max_byte_length {
+            if byte_length > max_byte_length {
+                return range_error(cx, "byte length exceeds max byte length");
+            }
+        }
+
+        let mut object = object_create_from_constructor::<ArrayBufferObject>(
+            cx,
+            constructor,
+            HeapItemKind::SharedArrayBufferObject,
+            Intrinsic::SharedArrayBufferPrototype,
+        )?;
+
+        // Temporarily fill default values so object is fully initialized before GC may be triggered
+        set_uninit!(object.byte_length, 0);
+        set_uninit!(object.max_byte_length, max_byte_length);
+        set_uninit!(object.data, None);
+        set_uninit!(object.shared_data_block, None);
+
+        // The entire max byte length is allocated up front
+        let alloc_length = max_byte_length.unwrap_or(byte_length);
+        let shared_data_block = if alloc_length <= MAX_ARRAY_BUFFER_SIZE {
+            SharedDataBlock::new(byte_length, max_byte_length)
+        } else {
+            None
+        };
+
+        let shared_data_block = match shared_data_block {
+            Some(shared_data_block) => shared_data_block,
+            None => {
+                return range_error(
+                    cx,
+                    &format!("cannot allocate shared array buffer of size {alloc_length}"),
+                )
+            }
+        };
+
+        object.shared_data_block = Some(cx.shared_memory.add_data_block(shared_data_block));
+
+        Ok(object.to_stack(cx))
+    }
+
+    /// Create a SharedArrayBuffer for a shared data block that may have come from another agent.
+    pub fn new_shared_from_data_block(
+        mut cx: Context,
+        shared_data_block: Arc<SharedDataBlock>,
+    ) -> AllocResult<StackRoot<ArrayBufferObject>> {
+        let mut object = object_create::<ArrayBufferObject>(
+            cx,
+            HeapItemKind::SharedArrayBufferObject,
+            Intrinsic::SharedArrayBufferPrototype,
+        )?;
+
+        set_uninit!(object.byte_length, 0);
+        set_uninit!(object.max_byte_length, shared_data_block.max_byte_length());
+        set_uninit!(object.data, None);
+
+        let shared_data_block = cx.shared_memory.add_data_block(shared_data_block);
+        set_uninit!(object.shared_data_block, Some(shared_data_block));
+
+        Ok(object.to_stack(cx))
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.shared_data_block.is_some()
+    }
+
+    /// The shared data block of a SharedArrayBuffer.
+    pub fn shared_data_block(&self) -> Option<&SharedDataBlock> {
+        self.shared_data_block
+            .map(|shared_data_block| unsafe { &*shared_data_block.as_ptr() })
+    }
+
+    /// The shared data block of a SharedArrayBuffer, which can be sent to other agents.
+    pub fn shared_data_block_arc(&self, cx: Context) -> Option<Arc<SharedDataBlock>> {
+        self.shared_data_block
+            .map(|shared_data_block| cx.shared_memory.get_data_block(shared_data_block))
+    }
+
+    /// ArrayBufferByteLength (https://tc39.es/ecma262/#sec-arraybufferbytelength)
+    ///
+    /// The byte length of a growable SharedArrayBuffer may be changed by other agents at any time.
     pub fn byte_length(&self) -> usize {
-        self.byte_length
+        match self.shared_data_block() {
+            Some(shared_data_block) => shared_data_block.byte_length(),
+            None => self.byte_length,
+        }
     }
 
     pub fn set_byte_length(&mut self, byte_length: usize) {
@@ -130,8 +231,124 @@ impl ArrayBufferObject {
         self.max_byte_length.is_none()
     }
 
-    pub fn data(&mut self) -> &mut [u8] {
-        self.data.as_mut().unwrap().as_mut_slice()
+    /// Pointer to the start of the data block. The data of SharedArrayBuffers may be concurrently
+    /// accessed by other agents, so it is only ever accessed through this pointer with atomic or
+    /// Unordered accesses, e.g. with `read_value`, `write_value`, or `copy_array_buffer_data`.
+    pub fn data_ptr(&mut self) -> *mut u8 {
+        match self.shared_data_block() {
+            Some(shared_data_block) => shared_data_block.as_ptr(),
+            None => self.data.as_mut().unwrap().as_mut_slice().as_mut_ptr(),
+        }
+    }
+
+    /// Read a value from the data block at a byte index. Accesses to SharedArrayBuffers are
+    /// Unordered (https://tc39.es/ecma262/#sec-memory-model).
+    #[inline]
+    pub fn read_value<T: Copy>(&mut self, byte_index: usize) -> T {
+        let is_shared = self.is_shared();
+
+        unsafe {
+            let byte_ptr = self.data_ptr().add(byte_index);
+
+            if is_shared {
+                load_unordered::<T>(byte_ptr)
+            } else {
+                byte_ptr.cast::<T>().read_unaligned()
+            }
+        }
+    }
+
+    /// Write a value to the data block at a byte index. Accesses to SharedArrayBuffers are
+    /// Unordered (https://tc39.es/ecma262/#sec-memory-model).
+    #[inline]
+    pub fn write_value<T: Copy>(&mut self, byte_index: usize, value: T) {
+        let is_shared = self.is_shared();
+
+        unsafe {
+            let byte_ptr = self.data_ptr().add(byte_index);
+
+            if is_shared {
+                store_unordered::<T>(byte_ptr, value)
+            } else {
+                byte_ptr.cast::<T>().write_unaligned(value)
+            }
+        }
+    }
+
+    /// Sequentially consistent load of the raw bits of an integer element. Other agents cannot
+    /// observe the data of ArrayBuffers that are not shared, so no atomic instructions are needed.
+    pub fn load_atomic(&mut self, byte_index: usize, element_size: usize) -> u64 {
+        let is_shared = self.is_shared();
+
+        unsafe {
+            let byte_ptr = self.data_ptr().add(byte_index);
+
+            if is_shared {
+                load_seq_cst(byte_ptr, element_size)
+            } else {
+                read_element_bits(byte_ptr, element_size)
+            }
+        }
+    }
+
+    /// Sequentially consistent store of the raw bits of an integer element.
+    pub fn store_atomic(&mut self, byte_index: usize, element_size: usize, bits: u64) {
+        let is_shared = self.is_shared();
+
+        unsafe {
+            let byte_ptr = self.data_ptr().add(byte_index);
+
+            if is_shared {
+                store_seq_cst(byte_ptr, element_size, bits)
+            } else {
+                write_element_bits(byte_ptr, element_size, bits)
+            }
+        }
+    }
+
+    /// Atomically apply an operation to an integer element, returning the raw bits of the previous
+    /// value.
+    pub fn read_modify_write_atomic(
+        &mut self,
+        byte_index: usize,
+        element_size: usize,
+        op: AtomicOp,
+        operand: u64,
+    ) -> u64 {
+        if self.is_shared() {
+            unsafe {
+                let byte_ptr = self.data_ptr().add(byte_index);
+                read_modify_write_seq_cst(byte_ptr, element_size, op, operand)
+            }
+        } else {
+            let old_bits = self.load_atomic(byte_index, element_size);
+            self.store_atomic(byte_index, element_size, op.apply(old_bits, operand));
+            old_bits
+        }
+    }
+
+    /// Atomically replace an integer element if it is equal to the expected value, returning the
+    /// raw bits of the previous value.
+    pub fn compare_exchange_atomic(
+        &mut self,
+        byte_index: usize,
+        element_size: usize,
+        expected: u64,
+        replacement: u64,
+    ) -> u64 {
+        if self.is_shared() {
+            unsafe {
+                let byte_ptr = self.data_ptr().add(byte_index);
+                compare_exchange_seq_cst(byte_ptr, element_size, expected, replacement)
+            }
+        } else {
+            let old_bits = self.load_atomic(byte_index, element_size);
+            if old_bits == expected {
+                self.store_atomic(byte_index, element_size, replacement);
+            }
+
+            old_bits
+        }
     }
 
     pub fn data_opt(&self, cx: Context) -> Option<StackRoot<ByteArray>> {
@@ -142,13 +359,16 @@ impl ArrayBufferObject {
         self.data = Some(data);
     }
 
+    /// IsDetachedBuffer (https://tc39.es/ecma262/#sec-isdetachedbuffer)
     pub fn is_detached(&self) -> bool {
-        self.data.is_none()
+        self.data.is_none() && self.shared_data_block.is_none()
     }
 
     /// DetachArrayBuffer (https://tc39.es/ecma262/#sec-detacharraybuffer)
     #[allow(dead_code)]
     pub fn detach(&mut self) {
+        debug_assert!(!self.is_shared());
+
         self.data = None;
         self.byte_length = 0;
 
@@ -275,12 +495,12 @@ pub fn array_buffer_copy_and_detach(
 /// CloneArrayBuffer (https://tc39.es/ecma262/#sec-clonearraybuffer)
 pub fn clone_array_buffer(
     cx: Context,
-    mut source_buffer: StackRoot<ArrayBufferObject>,
+    source_buffer: StackRoot<ArrayBufferObject>,
     source_byte_offset: usize,
     source_length: usize,
 ) -> EvalResult<StackRoot<ArrayBufferObject>> {
     let array_buffer_constructor = cx.get_intrinsic(Intrinsic::ArrayBufferConstructor);
-    let mut target_buffer = ArrayBufferObject::new(
+    let target_buffer = ArrayBufferObject::new(
         cx,
         array_buffer_constructor,
         source_length,
@@ -289,16 +509,44 @@ pub fn clone_array_buffer(
     )?;
 
     // Copy a portion of the source buffer after the given offset to the target buffer
-    let source_buffer_view =
-        &source_buffer.data()[source_byte_offset..(source_byte_offset + source_length)];
-
-    target_buffer.data().copy_from_slice(source_buffer_view);
+    unsafe {
+        copy_array_buffer_data(
+            *source_buffer,
+            source_byte_offset,
+            *target_buffer,
+            0,
+            source_length,
+        )
+    };
 
     Ok(target_buffer)
 }
 
+/// Copy bytes between the data blocks of array buffers. The source and target may be the same
+/// data block, in which case overlapping ranges are copied as if through an intermediate buffer.
+/// Copies to or from SharedArrayBuffers are made of Unordered accesses.
+///
+/// # Safety
+/// Both byte ranges must be within the bounds of their data blocks.
+pub unsafe fn copy_array_buffer_data(
+    mut source_buffer: HeapPtr<ArrayBufferObject>,
+    source_byte_index: usize,
+    mut target_buffer: HeapPtr<ArrayBufferObject>,
+    target_byte_index: usize,
+    byte_length: usize,
+) {
+    let source = source_buffer.data_ptr().add(source_byte_index);
+    let target = target_buffer.data_ptr().add(target_byte_index);
+
+    if source_buffer.is_shared() || target_buffer.is_shared() {
+        copy_unordered(source, target, byte_length)
+    } else {
+        core::ptr::copy(source, target, byte_length)
+    }
+}
+
 /// GetArrayBufferMaxByteLengthOption (https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption)
-fn get_array_buffer_max_byte_length_option(
+pub fn get_array_buffer_max_byte_length_option(
     cx: Context,
     options: StackRoot<Value>,
 ) -> EvalResult<Option<usize>> {
@@ -316,6 +564,28 @@ fn get_array_buffer_max_byte_length_option(
     Ok(Some(to_index(cx, max_byte_length)?))
 }
 
+/// Read the raw bits of an integer element, zero extended.
+unsafe fn read_element_bits(byte_ptr: *const u8, element_size: usize) -> u64 {
+    match element_size {
+        1 => byte_ptr.read() as u64,
+        2 => byte_ptr.cast::<u16>().read_unaligned() as u64,
+        4 => byte_ptr.cast::<u32>().read_unaligned() as u64,
+        8 => byte_ptr.cast::<u64>().read_unaligned(),
+        _ => unreachable!("invalid element size"),
+    }
+}
+
+/// Write the raw bits of an integer element, truncated to the element size.
+unsafe fn write_element_bits(byte_ptr: *mut u8, element_size: usize, bits: u64) {
+    match element_size {
+        1 => byte_ptr.write(bits as u8),
+        2 => byte_ptr.cast::<u16>().write_unaligned(bits as u16),
+        4 => byte_ptr.cast::<u32>().write_unaligned(bits as u32),
+        8 => byte_ptr.cast::<u64>().write_unaligned(bits),
+        _ => unreachable!("invalid element size"),
+    }
+}
+
 #[inline]
 pub fn throw_if_detached(cx: Context, array_buffer: HeapPtr<ArrayBufferObject>) -> EvalResult<()> {
     if array_buffer.is_detached() {
@@ -16,7 +16,9 @@ use crate::runtime::{
 };
 
 use super::{
-    array_buffer_constructor::{array_buffer_copy_and_detach, ArrayBufferObject},
+    array_buffer_constructor::{
+        array_buffer_copy_and_detach, copy_array_buffer_data, ArrayBufferObject,
+    },
     intrinsics::Intrinsic,
 };
 
@@ -145,7 +147,7 @@ impl ArrayBufferPrototype {
 
         unsafe {
             core::ptr::copy(
-                array_buffer.data().as_ptr(),
+                array_buffer.data_ptr(),
                 new_data.as_mut_slice().as_mut_ptr(),
                 old_byte_length.min(new_byte_length),
             )
@@ -174,7 +176,7 @@ impl ArrayBufferPrototype {
         this_value: StackRoot<Value>,
         arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
-        let mut array_buffer = require_array_buffer(cx, this_value, "slice")?;
+        let array_buffer = require_array_buffer(cx, this_value, "slice")?;
 
         throw_if_detached(cx, *array_buffer)?;
 
@@ -220,7 +222,7 @@ impl ArrayBufferPrototype {
         let new_object = construct(cx, constructor, &[new_length_value], None)?;
 
         // Check type of object returned from constructor
-        let mut new_array_buffer = if let Some(array_buffer) = new_object.as_array_buffer() {
+        let new_array_buffer = if let Some(array_buffer) = new_object.as_array_buffer() {
             array_buffer
         } else if new_object.is_shared_array_buffer() {
             return type_error(cx, "constructor cannot return SharedArrayBuffer");
@@ -241,10 +243,13 @@ impl ArrayBufferPrototype {
 
         // Copy data from original array buffer to new array buffer
         unsafe {
-            let source = array_buffer.data().as_ptr().add(start_index as usize);
-            let target = new_array_buffer.data().as_mut_ptr();
-
-            core::ptr::copy_nonoverlapping(source, target, new_length as usize)
+            copy_array_buffer_data(
+                *array_buffer,
+                start_index as usize,
+                *new_array_buffer,
+                0,
+                new_length as usize,
+            )
         }
 
         Ok(new_array_buffer.as_value())
@@ -0,0 +1,557 @@
+use core::{ptr::NonNull, time::Duration};
+
+use crate::{
+    must,
+    runtime::{
+        abstract_operations::create_data_property_or_throw,
+        alloc_error::AllocResult,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        object_value::ObjectValue,
+        ordinary_object::ordinary_object_create,
+        promise_object::PromiseObject,
+        property::Property,
+        realm::Realm,
+        shared_memory::{load_seq_cst, AtomicOp},
+        type_utilities::{
+            is_integral_number, to_bigint, to_index, to_int16, to_int32, to_int8,
+            to_integer_or_infinity, to_number, to_uint16, to_uint32, to_uint8,
+        },
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    intrinsics::Intrinsic,
+    typed_array::{
+        from_big_int64_element, from_big_uint64_element, from_int16_element, from_int32_element,
+        from_int8_element, from_uint16_element, from_uint32_element, from_uint8_element,
+        to_big_int64_element, to_big_uint64_element, ContentType, DynTypedArray, TypedArrayKind,
+    },
+    typed_array_prototype::{
+        is_typed_array_out_of_bounds, make_typed_array_with_buffer_witness_record,
+        typed_array_length, validate_typed_array, TypedArrayWithBufferWitnessRecord,
+    },
+};
+
+/// The Atomics Object (https://tc39.es/ecma262/#sec-atomics-object)
+pub struct AtomicsObject;
+
+impl AtomicsObject {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.and(), Self::and, 3, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.compare_exchange(),
+            Self::compare_exchange,
+            4,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.exchange(), Self::exchange, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.is_lock_free(), Self::is_lock_free, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.load(), Self::load, 2, realm)?;
+        object.intrinsic_func(cx, cx.names.notify(), Self::notify, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.or(), Self::or, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.pause(), Self::pause, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.store(), Self::store, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.sub(), Self::sub, 3, realm)?;
+        object.intrinsic_func(cx, cx.names.wait(), Self::wait, 4, realm)?;
+        object.intrinsic_func(cx, cx.names.wait_async(), Self::wait_async, 4, realm)?;
+        object.intrinsic_func(cx, cx.names.xor(), Self::xor, 3, realm)?;
+
+        // Atomics [ @@toStringTag ] (https://tc39.es/ecma262/#sec-atomics-%symbol.tostringtag%)
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let atomics_name_value = cx.names.atomics().as_string().into();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(atomics_name_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Atomics.add (https://tc39.es/ecma262/#sec-atomics.add)
+    pub fn add(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::Add)
+    }
+
+    /// Atomics.and (https://tc39.es/ecma262/#sec-atomics.and)
+    pub fn and(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::And)
+    }
+
+    /// Atomics.compareExchange (https://tc39.es/ecma262/#sec-atomics.compareexchange)
+    pub fn compare_exchange(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let typed_array_arg = get_argument(cx, arguments, 0);
+        let index_arg = get_argument(cx, arguments, 1);
+        let (typed_array, byte_index) =
+            validate_atomic_access_on_integer_typed_array(cx, typed_array_arg, index_arg, false)?;
+
+        let expected_arg = get_argument(cx, arguments, 2);
+        let expected = to_atomic_operand(cx, typed_array, expected_arg)?;
+
+        let replacement_arg = get_argument(cx, arguments, 3);
+        let replacement = to_atomic_operand(cx, typed_array, replacement_arg)?;
+
+        revalidate_atomic_access(cx, typed_array, byte_index)?;
+
+        let kind = typed_array.kind();
+        let expected_bits = to_element_bits(cx, &kind, expected)?;
+        let replacement_bits = to_element_bits(cx, &kind, replacement)?;
+
+        let old_bits = typed_array.viewed_array_buffer(cx).compare_exchange_atomic(
+            byte_index,
+            typed_array.element_size(),
+            expected_bits,
+            replacement_bits,
+        );
+
+        Ok(from_element_bits(cx, &kind, old_bits)?)
+    }
+
+    /// Atomics.exchange (https://tc39.es/ecma262/#sec-atomics.exchange)
+    pub fn exchange(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::Exchange)
+    }
+
+    /// Atomics.isLockFree (https://tc39.es/ecma262/#sec-atomics.islockfree)
+    pub fn is_lock_free(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let size_arg = get_argument(cx, arguments, 0);
+        let size = to_integer_or_infinity(cx, size_arg)?;
+
+        // All atomic operations on elements of these sizes are lock free
+        let is_lock_free = size == 1.0 || size == 2.0 || size == 4.0 || size == 8.0;
+
+        Ok(cx.bool(is_lock_free))
+    }
+
+    /// Atomics.load (https://tc39.es/ecma262/#sec-atomics.load)
+    pub fn load(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let typed_array_arg = get_argument(cx, arguments, 0);
+        let index_arg = get_argument(cx, arguments, 1);
+        let (typed_array, byte_index) =
+            validate_atomic_access_on_integer_typed_array(cx, typed_array_arg, index_arg, false)?;
+
+        revalidate_atomic_access(cx, typed_array, byte_index)?;
+
+        let bits = typed_array
+            .viewed_array_buffer(cx)
+            .load_atomic(byte_index, typed_array.element_size());
+
+        Ok(from_element_bits(cx, &typed_array.kind(), bits)?)
+    }
+
+    /// Atomics.notify (https://tc39.es/ecma262/#sec-atomics.notify)
+    pub fn notify(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let typed_array_arg = get_argument(cx, arguments, 0);
+        let typed_array_record = validate_integer_typed_array(cx, typed_array_arg, true)?;
+
+        let index_arg = get_argument(cx, arguments, 1);
+        let byte_index = validate_atomic_access(cx, &typed_array_record, index_arg)?;
+
+        // A missing count notifies all waiters
+        let count_arg = get_argument(cx, arguments, 2);
+        let count = if count_arg.is_undefined() {
+            None
+        } else {
+            let count = to_integer_or_infinity(cx, count_arg)?;
+            if count == f64::INFINITY {
+                None
+            } else {
+                Some(f64::max(count, 0.0) as usize)
+            }
+        };
+
+        let array_buffer = typed_array_record.typed_array.viewed_array_buffer(cx);
+        let num_notified = match array_buffer.shared_data_block() {
+            Some(shared_data_block) => shared_data_block.notify(byte_index, count),
+            // Waiters can only be added to shared array buffers
+            None => 0,
+        };
+
+        Ok(Value::from(num_notified).to_stack(cx))
+    }
+
+    /// Atomics.or (https://tc39.es/ecma262/#sec-atomics.or)
+    pub fn or(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::Or)
+    }
+
+    /// Atomics.pause (https://tc39.es/proposal-atomics-microwait/#Atomics.pause)
+    pub fn pause(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let iteration_number = get_argument(cx, arguments, 0);
+        if !iteration_number.is_undefined() && !is_integral_number(*iteration_number) {
+            return type_error(cx, "Atomics.pause expected an integral number");
+        }
+
+        // The iteration number is only a hint, so always pause for a single spin loop iteration
+        core::hint::spin_loop();
+
+        Ok(cx.undefined())
+    }
+
+    /// Atomics.store (https://tc39.es/ecma262/#sec-atomics.store)
+    pub fn store(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let typed_array_arg = get_argument(cx, arguments, 0);
+        let index_arg = get_argument(cx, arguments, 1);
+        let (typed_array, byte_index) =
+            validate_atomic_access_on_integer_typed_array(cx, typed_array_arg, index_arg, false)?;
+
+        let value_arg = get_argument(cx, arguments, 2);
+        let value = to_atomic_operand(cx, typed_array, value_arg)?;
+
+        revalidate_atomic_access(cx, typed_array, byte_index)?;
+
+        let bits = to_element_bits(cx, &typed_array.kind(), value)?;
+        typed_array.viewed_array_buffer(cx).store_atomic(
+            byte_index,
+            typed_array.element_size(),
+            bits,
+        );
+
+        Ok(value)
+    }
+
+    /// Atomics.sub (https://tc39.es/ecma262/#sec-atomics.sub)
+    pub fn sub(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::Sub)
+    }
+
+    /// Atomics.wait (https://tc39.es/ecma262/#sec-atomics.wait)
+    pub fn wait(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        do_wait(cx, arguments, /* is_async */ false)
+    }
+
+    /// Atomics.waitAsync (https://tc39.es/ecma262/#sec-atomics.waitasync)
+    pub fn wait_async(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        do_wait(cx, arguments, /* is_async */ true)
+    }
+
+    /// Atomics.xor (https://tc39.es/ecma262/#sec-atomics.xor)
+    pub fn xor(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        atomic_read_modify_write(cx, arguments, AtomicOp::Xor)
+    }
+}
+
+/// ValidateIntegerTypedArray (https://tc39.es/ecma262/#sec-validateintegertypedarray)
+fn validate_integer_typed_array(
+    cx: Context,
+    value: StackRoot<Value>,
+    waitable: bool,
+) -> EvalResult<TypedArrayWithBufferWitnessRecord> {
+    let typed_array_record = validate_typed_array(cx, value)?;
+
+    let is_valid_kind = match typed_array_record.typed_array.kind() {
+        TypedArrayKind::Int32Array | TypedArrayKind::BigInt64Array => true,
+        TypedArrayKind::Int8Array
+        | TypedArrayKind::UInt8Array
+        | TypedArrayKind::Int16Array
+        | TypedArrayKind::UInt16Array
+        | TypedArrayKind::UInt32Array
+        | TypedArrayKind::BigUInt64Array => !waitable,
+        TypedArrayKind::UInt8ClampedArray
+        | TypedArrayKind::Float16Array
+        | TypedArrayKind::Float32Array
+        | TypedArrayKind::Float64Array => false,
+    };
+
+    if !is_valid_kind {
+        return if waitable {
+            type_error(cx, "expected an Int32Array or BigInt64Array")
+        } else {
+            type_error(cx, "expected an integer typed array")
+        };
+    }
+
+    Ok(typed_array_record)
+}
+
+/// ValidateAtomicAccess (https://tc39.es/ecma262/#sec-validateatomicaccess)
+///
+/// Returns the byte index of the element in the viewed array buffer.
+fn validate_atomic_access(
+    cx: Context,
+    typed_array_record: &TypedArrayWithBufferWitnessRecord,
+    request_index: StackRoot<Value>,
+) -> EvalResult<usize> {
+    let length = typed_array_length(typed_array_record);
+
+    let access_index = to_index(cx, request_index)?;
+    if access_index >= length {
+        return range_error(cx, "index is out of range");
+    }
+
+    let typed_array = typed_array_record.typed_array;
+    Ok(access_index * typed_array.element_size() + typed_array.byte_offset())
+}
+
+/// ValidateAtomicAccessOnIntegerTypedArray (https://tc39.es/ecma262/#sec-validateatomicaccessonintegertypedarray)
+fn validate_atomic_access_on_integer_typed_array(
+    cx: Context,
+    typed_array: StackRoot<Value>,
+    request_index: StackRoot<Value>,
+    waitable: bool,
+) -> EvalResult<(DynTypedArray, usize)> {
+    let typed_array_record = validate_integer_typed_array(cx, typed_array, waitable)?;
+    let byte_index = validate_atomic_access(cx, &typed_array_record, request_index)?;
+
+    Ok((typed_array_record.typed_array, byte_index))
+}
+
+/// RevalidateAtomicAccess (https://tc39.es/ecma262/#sec-revalidateatomicaccess)
+fn revalidate_atomic_access(
+    cx: Context,
+    typed_array: DynTypedArray,
+    byte_index: usize,
+) -> EvalResult<()> {
+    let typed_array_record = make_typed_array_with_buffer_witness_record(typed_array);
+
+    if is_typed_array_out_of_bounds(&typed_array_record) {
+        return type_error(cx, "typed array is out of bounds");
+    }
+
+    // Not out of bounds so buffer must not be detached
+    if byte_index >= typed_array_record.cached_buffer_byte_length.unwrap() {
+        return range_error(cx, "index is out of range");
+    }
+
+    Ok(())
+}
+
+/// Convert an operand to a numeric value of the typed array's content type. Converting the numeric
+/// value to an element does not call user code, so it can be safely done after revalidation.
+fn to_atomic_operand(
+    cx: Context,
+    typed_array: DynTypedArray,
+    value: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    if typed_array.content_type() == ContentType::BigInt {
+        Ok(to_bigint(cx, value)?.into())
+    } else {
+        let integer = to_integer_or_infinity(cx, value)?;
+        Ok(Value::from(integer).to_stack(cx))
+    }
+}
+
+/// Convert a numeric value to the raw bits of an integer element, zero extended.
+fn to_element_bits(cx: Context, kind: &TypedArrayKind, value: StackRoot<Value>) -> EvalResult<u64> {
+    let bits = match kind {
+        TypedArrayKind::Int8Array => to_int8(cx, value)? as u8 as u64,
+        TypedArrayKind::UInt8Array => to_uint8(cx, value)? as u64,
+        TypedArrayKind::Int16Array => to_int16(cx, value)? as u16 as u64,
+        TypedArrayKind::UInt16Array => to_uint16(cx, value)? as u64,
+        TypedArrayKind::Int32Array => to_int32(cx, value)? as u32 as u64,
+        TypedArrayKind::UInt32Array => to_uint32(cx, value)? as u64,
+        TypedArrayKind::BigInt64Array => to_big_int64_element(cx, value)? as u64,
+        TypedArrayKind::BigUInt64Array => to_big_uint64_element(cx, value)?,
+        TypedArrayKind::UInt8ClampedArray
+        | TypedArrayKind::Float16Array
+        | TypedArrayKind::Float32Array
+        | TypedArrayKind::Float64Array => unreachable!("expected integer typed array"),
+    };
+
+    Ok(bits)
+}
+
+/// Convert the raw bits of an integer element to a numeric value.
+fn from_element_bits(
+    cx: Context,
+    kind: &TypedArrayKind,
+    bits: u64,
+) -> AllocResult<StackRoot<Value>> {
+    match kind {
+        TypedArrayKind::Int8Array => from_int8_element(cx, bits as i8),
+        TypedArrayKind::UInt8Array => from_uint8_element(cx, bits as u8),
+        TypedArrayKind::Int16Array => from_int16_element(cx, bits as i16),
+        TypedArrayKind::UInt16Array => from_uint16_element(cx, bits as u16),
+        TypedArrayKind::Int32Array => from_int32_element(cx, bits as i32),
+        TypedArrayKind::UInt32Array => from_uint32_element(cx, bits as u32),
+        TypedArrayKind::BigInt64Array => from_big_int64_element(cx, bits as i64),
+        TypedArrayKind::BigUInt64Array => from_big_uint64_element(cx, bits),
+        TypedArrayKind::UInt8ClampedArray
+        | TypedArrayKind::Float16Array
+        | TypedArrayKind::Float32Array
+        | TypedArrayKind::Float64Array => unreachable!("expected integer typed array"),
+    }
+}
+
+/// AtomicReadModifyWrite (https://tc39.es/ecma262/#sec-atomicreadmodifywrite)
+fn atomic_read_modify_write(
+    cx: Context,
+    arguments: &[StackRoot<Value>],
+    op: AtomicOp,
+) -> EvalResult<StackRoot<Value>> {
+    let typed_array_arg = get_argument(cx, arguments, 0);
+    let index_arg = get_argument(cx, arguments, 1);
+    let (typed_array, byte_index) =
+        validate_atomic_access_on_integer_typed_array(cx, typed_array_arg, index_arg, false)?;
+
+    let value_arg = get_argument(cx, arguments, 2);
+    let value = to_atomic_operand(cx, typed_array, value_arg)?;
+
+    revalidate_atomic_access(cx, typed_array, byte_index)?;
+
+    let kind = typed_array.kind();
+    let operand_bits = to_element_bits(cx, &kind, value)?;
+
+    let old_bits = typed_array
+        .viewed_array_buffer(cx)
+        .read_modify_write_atomic(byte_index, typed_array.element_size(), op, operand_bits);
+
+    Ok(from_element_bits(cx, &kind, old_bits)?)
+}
+
+/// DoWait (https://tc39.es/ecma262/#sec-dowait)
+fn do_wait(
+    mut cx: Context,
+    arguments: &[StackRoot<Value>],
+    is_async: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let typed_array_arg = get_argument(cx, arguments, 0);
+    let typed_array_record = validate_integer_typed_array(cx, typed_array_arg, true)?;
+    let typed_array = typed_array_record.typed_array;
+
+    let array_buffer = typed_array.viewed_array_buffer(cx);
+    if !array_buffer.is_shared() {
+        return type_error(cx, "expected a typed array over a SharedArrayBuffer");
+    }
+
+    let index_arg = get_argument(cx, arguments, 1);
+    let byte_index = validate_atomic_access(cx, &typed_array_record, index_arg)?;
+
+    let value_arg = get_argument(cx, arguments, 2);
+    let expected_bits = if typed_array.kind() == TypedArrayKind::BigInt64Array {
+        to_big_int64_element(cx, value_arg)? as u64
+    } else {
+        to_int32(cx, value_arg)? as u32 as u64
+    };
+
+    // Timeout is in milliseconds, where None represents an infinite timeout
+    let timeout_arg = get_argument(cx, arguments, 3);
+    let timeout_number = to_number(cx, timeout_arg)?.as_number();
+    let timeout = if timeout_number.is_nan() || timeout_number == f64::INFINITY {
+        None
+    } else {
+        let nanos = f64::max(timeout_number, 0.0) * 1_000_000.0;
+        Some(Duration::from_nanos(nanos as u64))
+    };
+
+    if !is_async && !cx.can_block {
+        return type_error(cx, "Atomics.wait cannot block in this agent");
+    }
+
+    // Shared data block is kept alive for the lifetime of the agent
+    let shared_data_block = NonNull::from(array_buffer.shared_data_block().unwrap());
+    let shared_data_block = unsafe { shared_data_block.as_ref() };
+
+    let element_size = typed_array.element_size();
+    let is_equal = || {
+        let element_ptr = unsafe { shared_data_block.as_ptr().add(byte_index) };
+        unsafe { load_seq_cst(element_ptr, element_size) == expected_bits }
+    };
+
+    if !is_async {
+        let result = shared_data_block.wait(byte_index, is_equal, timeout);
+        return Ok(cx.wait_result_value(result).to_stack(cx));
+    }
+
+    // Allocate before adding the waiter so that a pending waiter always has a promise
+    let result_object = ordinary_object_create(cx)?;
+    let promise = PromiseObject::new_pending(cx)?.to_stack(cx);
+
+    let mailbox = cx.shared_memory.mailbox().clone();
+    let (is_async_result, result_value) =
+        match shared_data_block.add_async_waiter(byte_index, is_equal, timeout, &mailbox) {
+            Ok(waiter_id) => {
+                cx.shared_memory.add_async_waiter(
+                    waiter_id,
+                    NonNull::from(shared_data_block),
+                    *promise,
+                    timeout,
+                );
+
+                (true, promise.as_value())
+            }
+            Err(result) => (false, cx.wait_result_value(result).to_stack(cx)),
+        };
+
+    must!(create_data_property_or_throw(
+        cx,
+        result_object,
+        cx.names.async_(),
+        cx.bool(is_async_result)
+    ));
+    must!(create_data_property_or_throw(
+        cx,
+        result_object,
+        cx.names.value(),
+        result_value
+    ));
+
+    Ok(result_object.into())
+}
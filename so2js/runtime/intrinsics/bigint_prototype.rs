@@ -3,6 +3,7 @@ use crate::runtime::{
     error::{range_error, type_error},
     eval_result::EvalResult,
     function::get_argument,
+    intl::number_format_constructor::format_number_with_locales,
     object_value::ObjectValue,
     property::Property,
     realm::Realm,
@@ -25,6 +26,13 @@ impl BigIntPrototype {
         )?;
 
         // Constructor property is added once BigIntConstructor has been created
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
         object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
         object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
 
@@ -39,6 +47,22 @@ impl BigIntPrototype {
         Ok(object)
     }
 
+    /// BigInt.prototype.toLocaleString (https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring)
+    pub fn to_locale_string(
+        mut cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let bigint_value = this_bigint_value(cx, this_value)?;
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        let formatted = format_number_with_locales(cx, bigint_value.into(), locales, options)?;
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
+    }
+
     /// BigInt.prototype.toString (https://tc39.es/ecma262/#sec-bigint.prototype.tostring)
     pub fn to_string(
         mut cx: Context,
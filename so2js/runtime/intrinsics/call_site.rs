@@ -0,0 +1,264 @@
+use core::mem::size_of;
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    parser::loc::find_line_col_for_pos,
+    runtime::{
+        alloc_error::AllocResult,
+        array_object::{create_array_from_list, ArrayObject},
+        bytecode::{function::BytecodeFunction, source_map::BytecodeSourceMap},
+        error::type_error,
+        eval_result::EvalResult,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        object_value::ObjectValue,
+        ordinary_object::object_create,
+        realm::Realm,
+        stack_trace::{HeapStackFrameInfo, StackFrameInfoArray},
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::intrinsics::Intrinsic;
+
+// A single frame of a stack trace, passed to `Error.prepareStackTrace`
+extend_object! {
+    pub struct CallSite {
+        function: HeapPtr<BytecodeFunction>,
+        bytecode_offset: usize,
+        is_constructor: bool,
+        is_async: bool,
+    }
+}
+
+impl CallSite {
+    pub fn new(cx: Context, frame: &HeapStackFrameInfo) -> AllocResult<StackRoot<CallSite>> {
+        let mut object =
+            object_create::<CallSite>(cx, HeapItemKind::CallSite, Intrinsic::CallSitePrototype)?;
+
+        set_uninit!(object.function, frame.function());
+        set_uninit!(object.bytecode_offset, frame.bytecode_offset());
+        set_uninit!(object.is_constructor, frame.is_constructor());
+        set_uninit!(object.is_async, frame.is_async());
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(CallSite, "CallSite");
+
+    /// The 1-indexed line and column of this call site, if the function has source positions.
+    fn line_col(&self, cx: Context) -> AllocResult<Option<(usize, usize)>> {
+        let (source_file, source_map) = match (
+            self.function.source_file_ptr(),
+            self.function.source_map_ptr(),
+        ) {
+            (Some(source_file), Some(source_map)) => (source_file, source_map),
+            _ => return Ok(None),
+        };
+
+        let pos = match BytecodeSourceMap::get_source_position(source_map, self.bytecode_offset) {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+
+        let line_offsets = source_file.to_stack(cx).line_offsets_ptr(cx)?;
+
+        Ok(Some(find_line_col_for_pos(pos, line_offsets.as_slice())))
+    }
+}
+
+/// Create an array of CallSite objects for each frame in a stack trace, from innermost to
+/// outermost.
+pub fn create_call_site_array(
+    cx: Context,
+    stack_frame_info: StackRoot<StackFrameInfoArray>,
+) -> AllocResult<StackRoot<ArrayObject>> {
+    let mut call_sites = Vec::with_capacity(stack_frame_info.len());
+
+    for i in 0..stack_frame_info.len() {
+        let call_site = CallSite::new(cx, &stack_frame_info.as_slice()[i])?;
+        call_sites.push(call_site.as_value());
+    }
+
+    create_array_from_list(cx, &call_sites)
+}
+
+pub struct CallSitePrototype;
+
+impl CallSitePrototype {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        object.intrinsic_func(
+            cx,
+            cx.names.get_column_number(),
+            Self::get_column_number,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.get_file_name(), Self::get_file_name, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.get_function_name(),
+            Self::get_function_name,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.get_line_number(),
+            Self::get_line_number,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.is_async(), Self::is_async, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.is_constructor(),
+            Self::is_constructor,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.is_native(), Self::is_native, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+
+        Ok(object)
+    }
+
+    /// CallSite.prototype.getColumnNumber
+    pub fn get_column_number(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+
+        match call_site.line_col(cx)? {
+            Some((_, column)) => Ok(cx.number(column as f64)),
+            None => Ok(cx.null()),
+        }
+    }
+
+    /// CallSite.prototype.getFileName
+    pub fn get_file_name(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+
+        match call_site.function.source_file_ptr() {
+            Some(source_file) => Ok(source_file.display_name(cx).as_value()),
+            None => Ok(cx.null()),
+        }
+    }
+
+    /// CallSite.prototype.getFunctionName
+    pub fn get_function_name(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+
+        match call_site.function.name(cx) {
+            Some(name) => Ok(name.as_value()),
+            None => Ok(cx.null()),
+        }
+    }
+
+    /// CallSite.prototype.getLineNumber
+    pub fn get_line_number(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+
+        match call_site.line_col(cx)? {
+            Some((line, _)) => Ok(cx.number(line as f64)),
+            None => Ok(cx.null()),
+        }
+    }
+
+    /// CallSite.prototype.isAsync
+    ///
+    /// Async call sites are async functions awaiting the call sites above them.
+    pub fn is_async(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+        Ok(cx.bool(call_site.is_async))
+    }
+
+    /// CallSite.prototype.isConstructor
+    pub fn is_constructor(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+        Ok(cx.bool(call_site.is_constructor))
+    }
+
+    /// CallSite.prototype.isNative
+    ///
+    /// Native call sites are builtin functions which have no source file.
+    pub fn is_native(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+        Ok(cx.bool(call_site.function.source_file_ptr().is_none()))
+    }
+
+    /// CallSite.prototype.toString
+    ///
+    /// Formatted the same as a line of the default stack trace, without the leading `at`.
+    pub fn to_string(
+        mut cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let call_site = CallSite::cast_from_value(cx, this_value)?;
+
+        let name = match call_site.function.name(cx) {
+            Some(name) => name.format(cx)?,
+            None => String::from("<anonymous>"),
+        };
+
+        let location = match call_site.function.source_file_ptr() {
+            Some(source_file) => source_file.display_name(cx).as_string().format(cx)?,
+            None => String::from("<native>"),
+        };
+
+        let prefix = if call_site.is_async { "async " } else { "" };
+        let result = match call_site.line_col(cx)? {
+            Some((line, column)) => format!("{prefix}{name} ({location}:{line}:{column})"),
+            None => format!("{prefix}{name} ({location})"),
+        };
+
+        Ok(cx.alloc_string(&result)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<CallSite> {
+    fn byte_size(&self) -> usize {
+        size_of::<CallSite>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.function);
+    }
+}
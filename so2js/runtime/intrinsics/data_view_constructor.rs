@@ -112,12 +112,10 @@ impl DataViewConstructor {
             return type_error(cx, "first argument must be an array buffer");
         }
 
-        let buffer_object = buffer_argument.as_object();
-        if !buffer_object.is_array_buffer() {
-            return type_error(cx, "first argument must be an array buffer");
-        }
-
-        let buffer_object = buffer_object.cast::<ArrayBufferObject>();
+        let buffer_object = match buffer_argument.as_object().as_any_array_buffer() {
+            Some(buffer_object) => buffer_object,
+            None => return type_error(cx, "first argument must be an array buffer"),
+        };
 
         let offset_arg = get_argument(cx, arguments, 1);
         let offset = to_index(cx, offset_arg)?;
@@ -482,7 +482,7 @@ fn require_is_data_view(
 
 /// GetViewValue (https://tc39.es/ecma262/#sec-getviewvalue)
 #[inline]
-fn get_view_value<T>(
+fn get_view_value<T: Copy>(
     cx: Context,
     this_value: StackRoot<Value>,
     arguments: &[StackRoot<Value>],
@@ -513,7 +513,7 @@ fn get_view_value<T>(
 
     // Read element bytes with correct endianness
     let mut buffer = data_view.viewed_array_buffer_ptr();
-    let element_bytes = unsafe { buffer.data().as_ptr().add(buffer_index).cast::<T>().read() };
+    let element_bytes = buffer.read_value::<T>(buffer_index);
 
     let element = if cfg!(target_endian = "little") {
         if is_little_endian {
@@ -534,7 +534,7 @@ fn get_view_value<T>(
 
 /// SetViewValue (https://tc39.es/ecma262/#sec-setviewvalue)
 #[inline]
-fn set_view_value<T>(
+fn set_view_value<T: Copy>(
     cx: Context,
     this_value: StackRoot<Value>,
     arguments: &[StackRoot<Value>],
@@ -589,10 +589,7 @@ fn set_view_value<T>(
     let buffer_index = get_index + view_offset;
     let mut buffer = data_view.viewed_array_buffer_ptr();
 
-    unsafe {
-        let element_ptr = buffer.data().as_mut_ptr().add(buffer_index).cast::<T>();
-        element_ptr.write(element_bytes)
-    }
+    buffer.write_value::<T>(buffer_index, element_bytes);
 
     Ok(cx.undefined())
 }
@@ -76,7 +76,7 @@ impl DateConstructor {
 
                     if primitive_value.is_string() {
                         let primitive_string = primitive_value.as_string();
-                        parse_string_to_date(primitive_string)?.unwrap_or(f64::NAN)
+                        parse_string_to_date(cx, primitive_string)?.unwrap_or(f64::NAN)
                     } else {
                         to_number(cx, primitive_value)?.as_number()
                     }
@@ -131,7 +131,7 @@ impl DateConstructor {
             let final_time = make_time(hour, minute, second, millisecond);
             let final_date = make_date(final_day, final_time);
 
-            time_clip(utc(final_date))
+            time_clip(utc(cx, final_date))
         };
 
         Ok(
@@ -159,7 +159,7 @@ impl DateConstructor {
         let string_arg = get_argument(cx, arguments, 0);
         let string = to_string(cx, string_arg)?;
 
-        if let Some(date_value) = parse_string_to_date(string)? {
+        if let Some(date_value) = parse_string_to_date(cx, string)? {
             Ok(Value::from(date_value).to_stack(cx))
         } else {
             Ok(cx.nan())
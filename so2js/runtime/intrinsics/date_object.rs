@@ -179,15 +179,23 @@ pub fn week_day(time: f64) -> f64 {
 }
 
 /// LocalTime (https://tc39.es/ecma262/#sec-localtime)
-pub fn local_time(time: f64) -> f64 {
-    // TODO: StackRoot time zones
-    time
+pub fn local_time(cx: Context, time: f64) -> f64 {
+    if !time.is_finite() {
+        return time;
+    }
+
+    let offset = cx.default_time_zone.offset_at(cx, time);
+    time + offset.offset_millis as f64
 }
 
 /// UTC (https://tc39.es/ecma262/#sec-utc-t)
-pub fn utc(time: f64) -> f64 {
-    // TODO: StackRoot time zones
-    time
+pub fn utc(cx: Context, time: f64) -> f64 {
+    if !time.is_finite() {
+        return f64::NAN;
+    }
+
+    let offset = cx.default_time_zone.offset_at_local(cx, time);
+    time - offset.offset_millis as f64
 }
 
 pub fn hour_from_time(time: f64) -> f64 {
@@ -246,7 +254,7 @@ fn floor_div(a: i64, b: i64) -> Option<i64> {
     }
 }
 
-fn is_leap_year(year: i64) -> bool {
+pub fn is_leap_year(year: i64) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
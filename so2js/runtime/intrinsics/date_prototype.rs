@@ -15,7 +15,13 @@ use crate::{
         error::{range_error, type_error},
         function::get_argument,
         get,
+        intl::date_time_format_constructor::{
+            format_date_with_locales, DefaultComponents, RequiredComponents,
+        },
         intrinsics::date_object::{day, make_date, make_full_year, make_time, time_clip},
+        intrinsics::temporal::{
+            instant_constructor::create_temporal_instant, iso::NS_PER_MILLISECOND,
+        },
         object_value::ObjectValue,
         property::Property,
         string_value::StringValue,
@@ -178,6 +184,13 @@ impl DatePrototype {
             realm,
         )?;
         object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_temporal_instant(),
+            Self::to_temporal_instant,
+            0,
+            realm,
+        )?;
         object.intrinsic_func(cx, cx.names.to_utc_string(), Self::to_utc_string, 0, realm)?;
         object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
 
@@ -238,7 +251,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date = date_from_time(local_time(date_value));
+        let date = date_from_time(local_time(cx, date_value));
 
         Ok(Value::from(date).to_stack(cx))
     }
@@ -262,7 +275,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let day = week_day(local_time(date_value));
+        let day = week_day(local_time(cx, date_value));
 
         Ok(Value::from(day).to_stack(cx))
     }
@@ -286,7 +299,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let year = year_from_time(local_time(date_value));
+        let year = year_from_time(local_time(cx, date_value));
 
         Ok(Value::from(year).to_stack(cx))
     }
@@ -310,7 +323,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let hour = hour_from_time(local_time(date_value));
+        let hour = hour_from_time(local_time(cx, date_value));
 
         Ok(Value::from(hour).to_stack(cx))
     }
@@ -334,7 +347,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let millisecond = millisecond_from_time(local_time(date_value));
+        let millisecond = millisecond_from_time(local_time(cx, date_value));
 
         Ok(Value::from(millisecond).to_stack(cx))
     }
@@ -358,7 +371,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let minute = minute_from_time(local_time(date_value));
+        let minute = minute_from_time(local_time(cx, date_value));
 
         Ok(Value::from(minute).to_stack(cx))
     }
@@ -382,7 +395,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let month = month_from_time(local_time(date_value));
+        let month = month_from_time(local_time(cx, date_value));
 
         Ok(Value::from(month).to_stack(cx))
     }
@@ -406,7 +419,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let second = second_from_time(local_time(date_value));
+        let second = second_from_time(local_time(cx, date_value));
 
         Ok(Value::from(second).to_stack(cx))
     }
@@ -440,7 +453,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let timezone_offset = (date_value - local_time(date_value)) / MS_PER_MINUTE;
+        let timezone_offset = (date_value - local_time(cx, date_value)) / MS_PER_MINUTE;
 
         Ok(Value::from(timezone_offset).to_stack(cx))
     }
@@ -656,16 +669,19 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
-        let new_date = time_clip(utc(make_date(
-            make_day(
-                year_from_time(date_value),
-                month_from_time(date_value),
-                date,
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                make_day(
+                    year_from_time(date_value),
+                    month_from_time(date_value),
+                    date,
+                ),
+                time_within_day(date_value),
             ),
-            time_within_day(date_value),
-        )));
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -693,7 +709,7 @@ impl DatePrototype {
         if date_value.is_nan() {
             date_value = 0.0;
         } else {
-            date_value = local_time(date_value);
+            date_value = local_time(cx, date_value);
         }
 
         let month = if arguments.len() >= 2 {
@@ -710,10 +726,10 @@ impl DatePrototype {
             date_from_time(date_value)
         };
 
-        let new_date = time_clip(utc(make_date(
-            make_day(year, month, date),
-            time_within_day(date_value),
-        )));
+        let new_date = time_clip(utc(
+            cx,
+            make_date(make_day(year, month, date), time_within_day(date_value)),
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -766,7 +782,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
         if !has_minutes {
             minutes = minute_from_time(date_value);
@@ -780,10 +796,13 @@ impl DatePrototype {
             milliseconds = millisecond_from_time(date_value);
         }
 
-        let new_date = time_clip(utc(make_date(
-            day(date_value),
-            make_time(hours, minutes, seconds, milliseconds),
-        )));
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                day(date_value),
+                make_time(hours, minutes, seconds, milliseconds),
+            ),
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -812,17 +831,20 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
-        let new_date = time_clip(utc(make_date(
-            day(date_value),
-            make_time(
-                hour_from_time(date_value),
-                minute_from_time(date_value),
-                second_from_time(date_value),
-                milliseconds,
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                day(date_value),
+                make_time(
+                    hour_from_time(date_value),
+                    minute_from_time(date_value),
+                    second_from_time(date_value),
+                    milliseconds,
+                ),
             ),
-        )));
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -867,7 +889,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
         if !has_seconds {
             seconds = second_from_time(date_value);
@@ -877,10 +899,13 @@ impl DatePrototype {
             milliseconds = millisecond_from_time(date_value);
         }
 
-        let new_date = time_clip(utc(make_date(
-            day(date_value),
-            make_time(hour_from_time(date_value), minutes, seconds, milliseconds),
-        )));
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                day(date_value),
+                make_time(hour_from_time(date_value), minutes, seconds, milliseconds),
+            ),
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -917,16 +942,19 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
         if !has_date {
             date = date_from_time(date_value);
         }
 
-        let new_date = time_clip(utc(make_date(
-            make_day(year_from_time(date_value), month, date),
-            time_within_day(date_value),
-        )));
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                make_day(year_from_time(date_value), month, date),
+                time_within_day(date_value),
+            ),
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -963,21 +991,24 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
         if !has_milliseconds {
             milliseconds = millisecond_from_time(date_value);
         }
 
-        let new_date = time_clip(utc(make_date(
-            day(date_value),
-            make_time(
-                hour_from_time(date_value),
-                minute_from_time(date_value),
-                seconds,
-                milliseconds,
+        let new_date = time_clip(utc(
+            cx,
+            make_date(
+                day(date_value),
+                make_time(
+                    hour_from_time(date_value),
+                    minute_from_time(date_value),
+                    seconds,
+                    milliseconds,
+                ),
             ),
-        )));
+        ));
 
         set_date_value(this_value, new_date);
 
@@ -1364,7 +1395,7 @@ impl DatePrototype {
             return Ok(cx.alloc_string("Invalid Date")?.as_value());
         }
 
-        let date_value = local_time(date_value);
+        let date_value = local_time(cx, date_value);
 
         let mut string = String::new();
         date_string(&mut string, date_value);
@@ -1431,11 +1462,11 @@ impl DatePrototype {
         invoke(cx, object.into(), cx.names.to_iso_string(), &[])
     }
 
-    /// Date.prototype.toLocaleDateString (https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring)
+    /// Date.prototype.toLocaleDateString (https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring)
     pub fn to_locale_date_string(
         cx: Context,
         this_value: StackRoot<Value>,
-        _: &[StackRoot<Value>],
+        arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
         let date_value = if let Some(date_value) = this_date_value(this_value) {
             date_value
@@ -1446,14 +1477,20 @@ impl DatePrototype {
             );
         };
 
-        Self::to_date_string_shared(cx, date_value)
+        Self::to_locale_string_shared(
+            cx,
+            date_value,
+            arguments,
+            RequiredComponents::Date,
+            DefaultComponents::Date,
+        )
     }
 
-    /// Date.prototype.toLocaleString (https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
+    /// Date.prototype.toLocaleString (https://tc39.es/ecma402/#sup-date.prototype.tolocalestring)
     pub fn to_locale_string(
         cx: Context,
         this_value: StackRoot<Value>,
-        _: &[StackRoot<Value>],
+        arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
         let date_value = if let Some(date_value) = this_date_value(this_value) {
             date_value
@@ -1464,14 +1501,20 @@ impl DatePrototype {
             );
         };
 
-        Ok(to_date_string(cx, date_value)?.as_value())
+        Self::to_locale_string_shared(
+            cx,
+            date_value,
+            arguments,
+            RequiredComponents::Any,
+            DefaultComponents::All,
+        )
     }
 
-    /// Date.prototype.toLocaleTimeString (https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring)
+    /// Date.prototype.toLocaleTimeString (https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring)
     pub fn to_locale_time_string(
         cx: Context,
         this_value: StackRoot<Value>,
-        _: &[StackRoot<Value>],
+        arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
         let date_value = if let Some(date_value) = this_date_value(this_value) {
             date_value
@@ -1482,7 +1525,33 @@ impl DatePrototype {
             );
         };
 
-        Self::to_time_string_shared(cx, date_value)
+        Self::to_locale_string_shared(
+            cx,
+            date_value,
+            arguments,
+            RequiredComponents::Time,
+            DefaultComponents::Time,
+        )
+    }
+
+    fn to_locale_string_shared(
+        mut cx: Context,
+        date_value: f64,
+        arguments: &[StackRoot<Value>],
+        required: RequiredComponents,
+        defaults: DefaultComponents,
+    ) -> EvalResult<StackRoot<Value>> {
+        if date_value.is_nan() {
+            return Ok(cx.alloc_string("Invalid Date")?.as_value());
+        }
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        let formatted =
+            format_date_with_locales(cx, date_value, locales, options, required, defaults)?;
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
     }
 
     /// Date.prototype.toString (https://tc39.es/ecma262/#sec-date.prototype.tostring)
@@ -1503,6 +1572,30 @@ impl DatePrototype {
         Ok(to_date_string(cx, date_value)?.as_value())
     }
 
+    /// Date.prototype.toTemporalInstant (https://tc39.es/proposal-temporal/#sec-date.prototype.totemporalinstant)
+    pub fn to_temporal_instant(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_value = if let Some(date_value) = this_date_value(this_value) {
+            date_value
+        } else {
+            return type_error(
+                cx,
+                "Date.prototype.toTemporalInstant method must be called on Date object",
+            );
+        };
+
+        if date_value.is_nan() {
+            return range_error(cx, "Invalid Date");
+        }
+
+        let epoch_nanoseconds = date_value as i128 * NS_PER_MILLISECOND;
+
+        Ok(create_temporal_instant(cx, epoch_nanoseconds)?.as_value())
+    }
+
     /// Date.prototype.toTimeString (https://tc39.es/ecma262/#sec-date.prototype.totimestring)
     pub fn to_time_string(
         cx: Context,
@@ -1526,12 +1619,12 @@ impl DatePrototype {
             return Ok(cx.alloc_string("Invalid Date")?.as_value());
         }
 
-        let local_date_value = local_time(date_value);
+        let local_date_value = local_time(cx, date_value);
 
         let mut string = String::new();
 
         time_string(&mut string, local_date_value);
-        time_zone_string(&mut string, date_value);
+        time_zone_string(cx, &mut string, date_value);
 
         Ok(cx.alloc_string(&string)?.as_value())
     }
@@ -1642,7 +1735,7 @@ impl DatePrototype {
             return Ok(cx.nan());
         }
 
-        let short_year = year_from_time(local_time(date_value)) - 1900.0;
+        let short_year = year_from_time(local_time(cx, date_value)) - 1900.0;
 
         Ok(cx.number(short_year))
     }
@@ -1668,14 +1761,14 @@ impl DatePrototype {
         let time = if date_value.is_nan() {
             0.0
         } else {
-            local_time(date_value)
+            local_time(cx, date_value)
         };
 
         let full_year = make_full_year(short_year.as_number());
 
         let day = make_day(full_year, month_from_time(time), date_from_time(time));
         let date = make_date(day, time_within_day(time));
-        let new_date = time_clip(utc(date));
+        let new_date = time_clip(utc(cx, date));
 
         set_date_value(this_value, new_date);
 
@@ -1740,11 +1833,11 @@ fn month_string(time_value: f64) -> &'static str {
 }
 
 /// TimeZoneString (https://tc39.es/ecma262/#sec-timezoneestring)
-fn time_zone_string(string: &mut String, _time_value: f64) {
-    // TODO: StackRoot time zones
-    let offset: f64 = 0.0;
+fn time_zone_string(cx: Context, string: &mut String, time_value: f64) {
+    let time_zone_offset = cx.default_time_zone.offset_at(cx, time_value);
+    let offset = time_zone_offset.offset_millis as f64;
 
-    let sign = if offset.is_sign_positive() { '+' } else { '-' };
+    let sign = if offset.is_sign_negative() { '-' } else { '+' };
     let offset = offset.abs();
 
     let hour = hour_from_time(offset);
@@ -1752,6 +1845,14 @@ fn time_zone_string(string: &mut String, _time_value: f64) {
 
     string.push(sign);
     string.push_str(&format!("{hour:02}{minute:02}"));
+
+    // Time zone name is only included when there is an alphabetic abbreviation, e.g. " (EST)".
+    // Numeric abbreviations like "-03" would only repeat the offset.
+    if let Some(abbreviation) = time_zone_offset.abbreviation {
+        if abbreviation.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            string.push_str(&format!(" ({abbreviation})"));
+        }
+    }
 }
 
 /// ToDateString (https://tc39.es/ecma262/#sec-todatestring)
@@ -1760,14 +1861,14 @@ pub fn to_date_string(mut cx: Context, time_value: f64) -> AllocResult<StackRoot
         return Ok(cx.alloc_string("Invalid Date")?.as_string());
     }
 
-    let local_time_value = local_time(time_value);
+    let local_time_value = local_time(cx, time_value);
 
     let mut string = String::new();
 
     date_string(&mut string, local_time_value);
     string.push(' ');
     time_string(&mut string, local_time_value);
-    time_zone_string(&mut string, time_value);
+    time_zone_string(cx, &mut string, time_value);
 
     Ok(cx.alloc_string(&string)?.as_string())
 }
@@ -1,11 +1,16 @@
 use core::mem::size_of;
 
+use alloc::string::ToString;
+
 use crate::{
     extend_object,
     runtime::{
-        abstract_operations::{create_non_enumerable_data_property_or_throw, get, has_property},
+        abstract_operations::{
+            call, create_non_enumerable_data_property_or_throw, get, has_property,
+        },
         alloc_error::AllocResult,
         builtin_function::BuiltinFunction,
+        error::type_error,
         eval_result::EvalResult,
         function::get_argument,
         gc::{HeapItem, GcVisitorExt},
@@ -14,15 +19,23 @@ use crate::{
         ordinary_object::{object_create, object_create_from_constructor},
         realm::Realm,
         source_file::SourceFile,
-        stack_trace::{create_current_stack_frame_info, create_stack_trace, StackFrameInfoArray},
+        stack_trace::{
+            create_current_stack_frame_info, create_stack_frame_info_array, create_stack_trace,
+            gather_async_stack_frames, gather_current_stack_frames, StackFrameInfoArray,
+        },
         string_value::FlatString,
-        type_utilities::to_string,
+        type_utilities::{is_callable, to_string},
         Context, StackRoot, HeapPtr, Value,
     },
     set_uninit,
 };
 
-use super::intrinsics::Intrinsic;
+use super::{
+    call_site::create_call_site_array, error_prototype::ErrorPrototype, intrinsics::Intrinsic,
+};
+
+/// Default value of `Error.stackTraceLimit`.
+const DEFAULT_STACK_TRACE_LIMIT: i32 = 10;
 
 extend_object! {
     pub struct ErrorObject {
@@ -31,6 +44,9 @@ extend_object! {
         stack_trace_state: StackTraceState,
         // Whether this is a stack overflow error.
         is_stack_overflow: bool,
+        // Result of calling `Error.prepareStackTrace` when the `stack` property was first
+        // accessed, if it was set to a function.
+        prepared_stack_trace: Option<Value>,
     }
 }
 
@@ -100,6 +116,7 @@ impl ErrorObject {
         // Initialize remaining state before collecting stack frame info, as we must ensure all
         // fields are initialized before a GC could potentially occur.
         set_uninit!(error.stack_trace_state, StackTraceState::Uninitialized);
+        set_uninit!(error.prepared_stack_trace, None);
 
         // Collect and cache the minimal stack frame info for the current stack trace
         let stack_frame_info = create_current_stack_frame_info(cx, skip_current_frame)?;
@@ -132,6 +149,77 @@ impl StackRoot<ErrorObject> {
             }
         }
     }
+
+    /// Return the stack trace formatted by `Error.prepareStackTrace`, if it is a function when the
+    /// stack trace is first formatted. The result is cached and returned on later accesses.
+    pub fn get_prepared_stack_trace(
+        &mut self,
+        cx: Context,
+    ) -> EvalResult<Option<StackRoot<Value>>> {
+        if let Some(prepared_stack_trace) = self.prepared_stack_trace {
+            return Ok(Some(prepared_stack_trace.to_stack(cx)));
+        }
+
+        // The default stack trace has already been formatted, so it is used from now on
+        let stack_frame_info = match self.stack_trace_state {
+            StackTraceState::StackFrameInfo(stack_frame_info) => stack_frame_info.to_stack(cx),
+            _ => return Ok(None),
+        };
+
+        let prepared_stack_trace = prepare_stack_trace(cx, self.as_object(), stack_frame_info)?;
+        if let Some(prepared_stack_trace) = prepared_stack_trace {
+            self.prepared_stack_trace = Some(*prepared_stack_trace);
+        }
+
+        Ok(prepared_stack_trace)
+    }
+}
+
+/// Call the user provided `Error.prepareStackTrace` function with the error and an array of
+/// CallSite objects, returning its result. Return None if `Error.prepareStackTrace` is not a
+/// function.
+fn prepare_stack_trace(
+    cx: Context,
+    error: StackRoot<ObjectValue>,
+    stack_frame_info: StackRoot<StackFrameInfoArray>,
+) -> EvalResult<Option<StackRoot<Value>>> {
+    let error_constructor = cx
+        .current_realm()
+        .get_intrinsic(Intrinsic::ErrorConstructor);
+    let prepare_stack_trace = get(cx, error_constructor, cx.names.prepare_stack_trace())?;
+    if !is_callable(prepare_stack_trace) {
+        return Ok(None);
+    }
+
+    let call_sites = create_call_site_array(cx, stack_frame_info)?;
+    let arguments = [error.as_value(), call_sites.as_value()];
+    let result = call(
+        cx,
+        prepare_stack_trace,
+        error_constructor.as_value(),
+        &arguments,
+    )?;
+
+    Ok(Some(result))
+}
+
+/// The maximum number of frames to gather for a stack trace, read from `Error.stackTraceLimit`.
+///
+/// Only a data property is read so that no user code runs while gathering the stack. If the limit
+/// is not a number no frames are gathered.
+pub fn stack_trace_limit(cx: Context) -> usize {
+    let error_constructor = cx
+        .current_realm()
+        .get_intrinsic(Intrinsic::ErrorConstructor);
+    let key = cx.names.stack_trace_limit();
+
+    match error_constructor.get_property(cx, key) {
+        Some(property) if property.value().is_number() => {
+            // Float to int conversion saturates and maps NaN to 0, so infinity means no limit
+            property.value().as_number().max(0.0) as usize
+        }
+        _ => 0,
+    }
 }
 
 pub struct ErrorConstructor;
@@ -155,6 +243,18 @@ impl ErrorConstructor {
         )?;
 
         func.intrinsic_func(cx, cx.names.is_error(), Self::is_error, 1, realm)?;
+        func.intrinsic_func(
+            cx,
+            cx.names.capture_stack_trace(),
+            Self::capture_stack_trace,
+            2,
+            realm,
+        )?;
+        func.intrinsic_data_prop(
+            cx,
+            cx.names.stack_trace_limit(),
+            cx.number(DEFAULT_STACK_TRACE_LIMIT as f64),
+        )?;
 
         Ok(func)
     }
@@ -209,6 +309,62 @@ impl ErrorConstructor {
 
         Ok(cx.bool(arg.as_object().is_error()))
     }
+
+    /// Error.captureStackTrace
+    ///
+    /// Install a `stack` property on the target object containing the current stack trace. If a
+    /// function is provided then all frames above and including the topmost call to that function
+    /// are omitted.
+    pub fn capture_stack_trace(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let target = get_argument(cx, arguments, 0);
+        if !target.is_object() {
+            return type_error(cx, "Error.captureStackTrace target must be an object");
+        }
+        let target = target.as_object();
+
+        let mut frames = gather_current_stack_frames(cx, /* skip_current_frame */ true);
+        gather_async_stack_frames(cx, &mut frames, usize::MAX);
+
+        let constructor_opt = get_argument(cx, arguments, 1);
+        if constructor_opt.is_object() {
+            if let Some(closure) = constructor_opt.as_object().as_closure() {
+                let function = closure.function_ptr();
+                if let Some(index) = frames
+                    .iter()
+                    .position(|frame| frame.function.ptr_eq(&function))
+                {
+                    frames.drain(..=index);
+                }
+            }
+        }
+
+        frames.truncate(stack_trace_limit(cx));
+        let stack_frame_info = create_stack_frame_info_array(cx, &frames)?.to_stack(cx);
+
+        let stack = match prepare_stack_trace(cx, target, stack_frame_info)? {
+            Some(stack) => stack,
+            None => {
+                // Default format starts with the object converted to a string like an error
+                let header = ErrorPrototype::to_string(cx, target.as_value(), &[])?;
+                let mut stack = header.as_string().format(cx)?;
+
+                if !stack_frame_info.as_slice().is_empty() {
+                    stack.push('\n');
+                    stack.push_str(&create_stack_trace(cx, stack_frame_info)?.frames.to_string());
+                }
+
+                cx.alloc_string(&stack)?.as_value()
+            }
+        };
+
+        create_non_enumerable_data_property_or_throw(cx, target, cx.names.stack(), stack)?;
+
+        Ok(cx.undefined())
+    }
 }
 
 /// InstallErrorCause (https://tc39.es/ecma262/#sec-installerrorcause)
@@ -254,5 +410,9 @@ impl HeapItem for HeapPtr<ErrorObject> {
                 }
             }
         }
+
+        if let Some(prepared_stack_trace) = self.prepared_stack_trace.as_mut() {
+            visitor.visit_value(prepared_stack_trace);
+        }
     }
 }
@@ -80,12 +80,20 @@ impl ErrorPrototype {
 
         let mut error = this_value.cast::<ErrorObject>();
 
+        // Use the result of `Error.prepareStackTrace` if one was provided
+        if let Some(stack_trace) = error.get_prepared_stack_trace(cx)? {
+            return Ok(stack_trace);
+        }
+
         // Stack trace starts with error message on one line
         let mut stack_trace = format_error_one_line(cx, error)?;
-        stack_trace.push('\n');
 
-        // Followed by the stack trace
-        stack_trace.push_str(&error.get_stack_trace(cx)?.frames.to_string());
+        // Followed by the stack frames, if any were gathered
+        let frames = error.get_stack_trace(cx)?.frames.to_string();
+        if !frames.is_empty() {
+            stack_trace.push('\n');
+            stack_trace.push_str(&frames);
+        }
 
         Ok(cx.alloc_string(&stack_trace)?.as_value())
     }
@@ -130,6 +130,7 @@ pub fn set_default_global_bindings(cx: Context, realm: StackRoot<Realm>) -> Eval
         intrinsic_prop!(cx.names.reference_error(), ReferenceErrorConstructor);
         intrinsic_prop!(cx.names.regexp(), RegExpConstructor);
         intrinsic_prop!(cx.names.set(), SetConstructor);
+        intrinsic_prop!(cx.names.shared_array_buffer(), SharedArrayBufferConstructor);
         intrinsic_prop!(cx.names.string(), StringConstructor);
         intrinsic_prop!(cx.names.symbol(), SymbolConstructor);
         intrinsic_prop!(cx.names.syntax_error(), SyntaxErrorConstructor);
@@ -144,9 +145,12 @@ pub fn set_default_global_bindings(cx: Context, realm: StackRoot<Realm>) -> Eval
         intrinsic_prop!(cx.names.weak_set(), WeakSetConstructor);
 
         // Other Properties of the Global Object (https://tc39.es/ecma262/#sec-other-properties-of-the-global-object)
+        intrinsic_prop!(cx.names.atomics(), Atomics);
+        intrinsic_prop!(cx.names.intl(), Intl);
         intrinsic_prop!(cx.names.json(), JSON);
         intrinsic_prop!(cx.names.math(), Math);
         intrinsic_prop!(cx.names.reflect(), Reflect);
+        intrinsic_prop!(cx.names.temporal(), Temporal);
 
         // Non-standard, environment specific properties of global object
         Ok(())
@@ -27,10 +27,12 @@ use crate::{
             async_generator_function_prototype::AsyncGeneratorFunctionPrototype,
             async_generator_prototype::AsyncGeneratorPrototype,
             async_iterator_prototype::AsyncIteratorPrototype,
+            atomics_object::AtomicsObject,
             bigint_constructor::BigIntConstructor,
             bigint_prototype::BigIntPrototype,
             boolean_constructor::BooleanConstructor,
             boolean_prototype::BooleanPrototype,
+            call_site::CallSitePrototype,
             data_view_constructor::DataViewConstructor,
             data_view_prototype::DataViewPrototype,
             date_constructor::DateConstructor,
@@ -68,11 +70,30 @@ use crate::{
             set_constructor::SetConstructor,
             set_iterator::SetIteratorPrototype,
             set_prototype::SetPrototype,
+            shared_array_buffer_constructor::SharedArrayBufferConstructor,
+            shared_array_buffer_prototype::SharedArrayBufferPrototype,
             string_constructor::StringConstructor,
             string_iterator::StringIteratorPrototype,
             string_prototype::StringPrototype,
             symbol_constructor::SymbolConstructor,
             symbol_prototype::SymbolPrototype,
+            temporal::{
+                duration_constructor::DurationConstructor, duration_prototype::DurationPrototype,
+                instant_constructor::InstantConstructor, instant_prototype::InstantPrototype,
+                now_object::NowObject, plain_date_constructor::PlainDateConstructor,
+                plain_date_prototype::PlainDatePrototype,
+                plain_date_time_constructor::PlainDateTimeConstructor,
+                plain_date_time_prototype::PlainDateTimePrototype,
+                plain_month_day_constructor::PlainMonthDayConstructor,
+                plain_month_day_prototype::PlainMonthDayPrototype,
+                plain_time_constructor::PlainTimeConstructor,
+                plain_time_prototype::PlainTimePrototype,
+                plain_year_month_constructor::PlainYearMonthConstructor,
+                plain_year_month_prototype::PlainYearMonthPrototype,
+                temporal_object::TemporalObject,
+                zoned_date_time_constructor::ZonedDateTimeConstructor,
+                zoned_date_time_prototype::ZonedDateTimePrototype,
+            },
             typed_array::{
                 BigInt64ArrayConstructor, BigInt64ArrayPrototype, BigUInt64ArrayConstructor,
                 BigUInt64ArrayPrototype, Float16ArrayConstructor, Float16ArrayPrototype,
@@ -92,6 +113,24 @@ use crate::{
             weak_set_constructor::WeakSetConstructor,
             weak_set_prototype::WeakSetPrototype,
         },
+        intl::{
+            collator_constructor::CollatorConstructor, collator_prototype::CollatorPrototype,
+            date_time_format_constructor::DateTimeFormatConstructor,
+            date_time_format_prototype::DateTimeFormatPrototype,
+            display_names_constructor::DisplayNamesConstructor,
+            display_names_prototype::DisplayNamesPrototype, intl_object::IntlObject,
+            list_format_constructor::ListFormatConstructor,
+            list_format_prototype::ListFormatPrototype, locale_constructor::LocaleConstructor,
+            locale_prototype::LocalePrototype, number_format_constructor::NumberFormatConstructor,
+            number_format_prototype::NumberFormatPrototype,
+            plural_rules_constructor::PluralRulesConstructor,
+            plural_rules_prototype::PluralRulesPrototype,
+            relative_time_format_constructor::RelativeTimeFormatConstructor,
+            relative_time_format_prototype::RelativeTimeFormatPrototype,
+            segment_iterator::SegmentIteratorPrototype,
+            segmenter_constructor::SegmenterConstructor, segmenter_prototype::SegmenterPrototype,
+            segments_object::SegmentsPrototype,
+        },
         object_value::ObjectValue,
         ordinary_object::object_create_with_proto,
         property_descriptor::PropertyDescriptor,
@@ -119,6 +158,7 @@ pub enum Intrinsic {
     AsyncGeneratorFunctionPrototype,
     AsyncGeneratorPrototype,
     AsyncIteratorPrototype,
+    Atomics,
     BigInt64ArrayConstructor,
     BigInt64ArrayPrototype,
     BigUInt64ArrayConstructor,
@@ -127,10 +167,19 @@ pub enum Intrinsic {
     BigIntPrototype,
     BooleanConstructor,
     BooleanPrototype,
+    CallSitePrototype,
+    CollatorConstructor,
+    CollatorPrototype,
     DateConstructor,
     DatePrototype,
     DataViewConstructor,
     DataViewPrototype,
+    DateTimeFormatConstructor,
+    DateTimeFormatPrototype,
+    DisplayNamesConstructor,
+    DisplayNamesPrototype,
+    DurationConstructor,
+    DurationPrototype,
     ErrorConstructor,
     ErrorPrototype,
     Eval,
@@ -150,27 +199,48 @@ pub enum Intrinsic {
     GeneratorFunctionPrototype,
     GeneratorPrototype,
     GlobalDeclarationInstantiation,
+    InstantConstructor,
+    InstantPrototype,
     Int8ArrayConstructor,
     Int8ArrayPrototype,
     Int16ArrayConstructor,
     Int16ArrayPrototype,
     Int32ArrayConstructor,
     Int32ArrayPrototype,
+    Intl,
     IteratorConstructor,
     IteratorHelperPrototype,
     IteratorPrototype,
     JSON,
+    ListFormatConstructor,
+    ListFormatPrototype,
+    LocaleConstructor,
+    LocalePrototype,
     MapConstructor,
     MapIteratorPrototype,
     MapPrototype,
     Math,
     NumberConstructor,
+    NumberFormatConstructor,
+    NumberFormatPrototype,
     NumberPrototype,
     ObjectConstructor,
     ObjectPrototype,
     ObjectPrototypeToString,
     ParseFloat,
     ParseInt,
+    PlainDateConstructor,
+    PlainDatePrototype,
+    PlainDateTimeConstructor,
+    PlainDateTimePrototype,
+    PlainMonthDayConstructor,
+    PlainMonthDayPrototype,
+    PlainTimeConstructor,
+    PlainTimePrototype,
+    PlainYearMonthConstructor,
+    PlainYearMonthPrototype,
+    PluralRulesConstructor,
+    PluralRulesPrototype,
     PromiseConstructor,
     PromisePrototype,
     ProxyConstructor,
@@ -182,10 +252,18 @@ pub enum Intrinsic {
     RegExpConstructor,
     RegExpPrototype,
     RegExpStringIteratorPrototype,
+    RelativeTimeFormatConstructor,
+    RelativeTimeFormatPrototype,
     WrapForValidIteratorPrototype,
+    SegmentIteratorPrototype,
+    SegmenterConstructor,
+    SegmenterPrototype,
+    SegmentsPrototype,
     SetConstructor,
     SetIteratorPrototype,
     SetPrototype,
+    SharedArrayBufferConstructor,
+    SharedArrayBufferPrototype,
     StringConstructor,
     StringIteratorPrototype,
     StringPrototype,
@@ -193,6 +271,8 @@ pub enum Intrinsic {
     SymbolPrototype,
     SyntaxErrorConstructor,
     SyntaxErrorPrototype,
+    Temporal,
+    TemporalNow,
     ThrowTypeError,
     TypedArrayConstructor,
     TypedArrayPrototype,
@@ -214,6 +294,8 @@ pub enum Intrinsic {
     WeakRefPrototype,
     WeakSetConstructor,
     WeakSetPrototype,
+    ZonedDateTimeConstructor,
+    ZonedDateTimePrototype,
     Last,
 }
 
@@ -336,6 +418,7 @@ impl Intrinsics {
         register_existing_intrinsic!(ParseInt, create_parse_int(cx, realm)?);
 
         register_intrinsic_pair!(ErrorPrototype, ErrorConstructor);
+        register_intrinsic!(CallSitePrototype, CallSitePrototype);
         register_intrinsic_pair!(BooleanPrototype, BooleanConstructor);
         register_intrinsic_pair!(NumberPrototype, NumberConstructor);
         register_intrinsic_pair!(StringPrototype, StringConstructor);
@@ -346,6 +429,7 @@ impl Intrinsics {
         register_intrinsic_pair!(RegExpPrototype, RegExpConstructor);
         register_intrinsic_pair!(PromisePrototype, PromiseConstructor);
         register_intrinsic_pair!(ArrayBufferPrototype, ArrayBufferConstructor);
+        register_intrinsic_pair!(SharedArrayBufferPrototype, SharedArrayBufferConstructor);
         register_intrinsic_pair!(DataViewPrototype, DataViewConstructor);
         register_intrinsic_pair!(MapPrototype, MapConstructor);
         register_intrinsic_pair!(SetPrototype, SetConstructor);
@@ -464,11 +548,38 @@ impl Intrinsics {
         )?;
 
         // Builtin objects
+        register_intrinsic!(Atomics, AtomicsObject);
         register_intrinsic!(JSON, JSONObject);
         register_intrinsic!(Math, MathObject);
         register_intrinsic!(ProxyConstructor, ProxyConstructor);
         register_intrinsic!(Reflect, ReflectObject);
 
+        // Internationalization
+        register_intrinsic_pair!(CollatorPrototype, CollatorConstructor);
+        register_intrinsic_pair!(DateTimeFormatPrototype, DateTimeFormatConstructor);
+        register_intrinsic_pair!(DisplayNamesPrototype, DisplayNamesConstructor);
+        register_intrinsic_pair!(ListFormatPrototype, ListFormatConstructor);
+        register_intrinsic_pair!(LocalePrototype, LocaleConstructor);
+        register_intrinsic_pair!(NumberFormatPrototype, NumberFormatConstructor);
+        register_intrinsic_pair!(PluralRulesPrototype, PluralRulesConstructor);
+        register_intrinsic_pair!(RelativeTimeFormatPrototype, RelativeTimeFormatConstructor);
+        register_intrinsic_pair!(SegmenterPrototype, SegmenterConstructor);
+        register_intrinsic!(SegmentsPrototype, SegmentsPrototype);
+        register_intrinsic!(SegmentIteratorPrototype, SegmentIteratorPrototype);
+        register_intrinsic!(Intl, IntlObject);
+
+        // Temporal
+        register_intrinsic_pair!(DurationPrototype, DurationConstructor);
+        register_intrinsic_pair!(InstantPrototype, InstantConstructor);
+        register_intrinsic_pair!(PlainDatePrototype, PlainDateConstructor);
+        register_intrinsic_pair!(PlainDateTimePrototype, PlainDateTimeConstructor);
+        register_intrinsic_pair!(PlainMonthDayPrototype, PlainMonthDayConstructor);
+        register_intrinsic_pair!(PlainTimePrototype, PlainTimeConstructor);
+        register_intrinsic_pair!(PlainYearMonthPrototype, PlainYearMonthConstructor);
+        register_intrinsic_pair!(ZonedDateTimePrototype, ZonedDateTimeConstructor);
+        register_intrinsic!(TemporalNow, NowObject);
+        register_intrinsic!(Temporal, TemporalObject);
+
         // Builtin functions
         js_stack_scope!(cx, {
             register_existing_intrinsic!(Eval, create_eval(cx, realm)?);
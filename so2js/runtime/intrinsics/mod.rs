@@ -12,10 +12,12 @@ mod async_generator_function_constructor;
 mod async_generator_function_prototype;
 pub mod async_generator_prototype;
 mod async_iterator_prototype;
+mod atomics_object;
 pub mod bigint_constructor;
 mod bigint_prototype;
 pub mod boolean_constructor;
 mod boolean_prototype;
+pub mod call_site;
 pub mod data_view_constructor;
 mod data_view_prototype;
 mod date_constructor;
@@ -61,11 +63,14 @@ pub mod set_constructor;
 pub mod set_iterator;
 pub mod set_object;
 mod set_prototype;
+mod shared_array_buffer_constructor;
+mod shared_array_buffer_prototype;
 pub mod string_constructor;
 pub mod string_iterator;
 pub mod string_prototype;
 pub mod symbol_constructor;
 mod symbol_prototype;
+pub mod temporal;
 pub mod typed_array;
 mod typed_array_constructor;
 mod typed_array_prototype;
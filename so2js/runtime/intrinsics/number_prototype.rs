@@ -3,6 +3,7 @@ use crate::runtime::{
     error::{range_error, type_error},
     eval_result::EvalResult,
     function::get_argument,
+    intl::number_format_constructor::format_number_with_locales,
     object_value::ObjectValue,
     realm::Realm,
     string_value::FlatString,
@@ -173,13 +174,20 @@ impl NumberPrototype {
         Ok(cx.alloc_string(&m)?.as_value())
     }
 
-    /// Number.prototype.toLocaleString (https://tc39.es/ecma262/#sec-number.prototype.tolocalestring)
+    /// Number.prototype.toLocaleString (https://tc39.es/ecma402/#sup-number.prototype.tolocalestring)
     pub fn to_locale_string(
-        cx: Context,
+        mut cx: Context,
         this_value: StackRoot<Value>,
-        _: &[StackRoot<Value>],
+        arguments: &[StackRoot<Value>],
     ) -> EvalResult<StackRoot<Value>> {
-        Self::to_string(cx, this_value, &[])
+        let number_value = this_number_value(cx, this_value)?.to_stack(cx);
+
+        let locales = get_argument(cx, arguments, 0);
+        let options = get_argument(cx, arguments, 1);
+
+        let formatted = format_number_with_locales(cx, number_value, locales, options)?;
+
+        Ok(cx.alloc_string(&formatted)?.as_value())
     }
 
     /// Number.prototype.toPrecision (https://tc39.es/ecma262/#sec-number.prototype.toprecision)
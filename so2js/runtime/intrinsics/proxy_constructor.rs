@@ -100,7 +100,7 @@ pub fn revoke(mut cx: Context, _: StackRoot<Value>, _: &[StackRoot<Value>]) -> E
 
     // Revoke the proxy object and remove from closure
     if let Some(proxy_object_property) = proxy_object_property {
-        revoke_function.remove_property(cx.well_known_symbols.revocable_proxy());
+        revoke_function.remove_property(cx, cx.well_known_symbols.revocable_proxy())?;
 
         let mut proxy_object = proxy_object_property.value().cast::<ProxyObject>();
         proxy_object.revoke();
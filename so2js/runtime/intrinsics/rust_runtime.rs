@@ -1,8 +1,32 @@
 use crate::{
     runtime::{
-        async_generator_object, bound_function_object::BoundFunctionObject, context::ContextCell,
-        gc_object::GcObject, global_names, module, promise_object::PromiseCapability,
-        test_262_object::Test262Object, Context, EvalResult, StackRoot, Value,
+        async_generator_object,
+        bound_function_object::BoundFunctionObject,
+        context::ContextCell,
+        gc_object::GcObject,
+        global_names,
+        intl::{
+            collator_constructor::CollatorConstructor, collator_prototype::CollatorPrototype,
+            date_time_format_constructor::DateTimeFormatConstructor,
+            date_time_format_prototype::DateTimeFormatPrototype,
+            display_names_constructor::DisplayNamesConstructor,
+            display_names_prototype::DisplayNamesPrototype, intl_object::IntlObject,
+            list_format_constructor::ListFormatConstructor,
+            list_format_prototype::ListFormatPrototype, locale_constructor::LocaleConstructor,
+            locale_prototype::LocalePrototype, number_format_constructor::NumberFormatConstructor,
+            number_format_prototype::NumberFormatPrototype,
+            plural_rules_constructor::PluralRulesConstructor,
+            plural_rules_prototype::PluralRulesPrototype,
+            relative_time_format_constructor::RelativeTimeFormatConstructor,
+            relative_time_format_prototype::RelativeTimeFormatPrototype,
+            segment_iterator::SegmentIteratorPrototype,
+            segmenter_constructor::SegmenterConstructor, segmenter_prototype::SegmenterPrototype,
+            segments_object::SegmentsPrototype,
+        },
+        module,
+        promise_object::PromiseCapability,
+        test_262_object::Test262Object,
+        Context, EvalResult, StackRoot, Value,
     },
     static_assert,
 };
@@ -22,10 +46,12 @@ use super::{
     async_function_constructor::AsyncFunctionConstructor,
     async_generator_function_constructor::AsyncGeneratorFunctionConstructor,
     async_generator_prototype::AsyncGeneratorPrototype,
+    atomics_object::AtomicsObject,
     bigint_constructor::BigIntConstructor,
     bigint_prototype::BigIntPrototype,
     boolean_constructor::BooleanConstructor,
     boolean_prototype::BooleanPrototype,
+    call_site::CallSitePrototype,
     data_view_constructor::DataViewConstructor,
     data_view_prototype::DataViewPrototype,
     date_constructor::DateConstructor,
@@ -65,11 +91,28 @@ use super::{
     set_constructor::SetConstructor,
     set_iterator::SetIteratorPrototype,
     set_prototype::SetPrototype,
+    shared_array_buffer_constructor::SharedArrayBufferConstructor,
+    shared_array_buffer_prototype::SharedArrayBufferPrototype,
     string_constructor::StringConstructor,
     string_iterator::StringIteratorPrototype,
     string_prototype::StringPrototype,
     symbol_constructor::SymbolConstructor,
     symbol_prototype::SymbolPrototype,
+    temporal::{
+        duration_constructor::DurationConstructor, duration_prototype::DurationPrototype,
+        instant_constructor::InstantConstructor, instant_prototype::InstantPrototype,
+        now_object::NowObject, plain_date_constructor::PlainDateConstructor,
+        plain_date_prototype::PlainDatePrototype,
+        plain_date_time_constructor::PlainDateTimeConstructor,
+        plain_date_time_prototype::PlainDateTimePrototype,
+        plain_month_day_constructor::PlainMonthDayConstructor,
+        plain_month_day_prototype::PlainMonthDayPrototype,
+        plain_time_constructor::PlainTimeConstructor, plain_time_prototype::PlainTimePrototype,
+        plain_year_month_constructor::PlainYearMonthConstructor,
+        plain_year_month_prototype::PlainYearMonthPrototype,
+        zoned_date_time_constructor::ZonedDateTimeConstructor,
+        zoned_date_time_prototype::ZonedDateTimePrototype,
+    },
     typed_array::{
         BigInt64ArrayConstructor, BigUInt64ArrayConstructor, Float16ArrayConstructor,
         Float32ArrayConstructor, Float64ArrayConstructor, Int16ArrayConstructor,
@@ -241,10 +284,25 @@ rust_runtime_functions!(
     AsyncGeneratorPrototype::next,
     AsyncGeneratorPrototype::return_,
     AsyncGeneratorPrototype::throw,
+    AtomicsObject::add,
+    AtomicsObject::and,
+    AtomicsObject::compare_exchange,
+    AtomicsObject::exchange,
+    AtomicsObject::is_lock_free,
+    AtomicsObject::load,
+    AtomicsObject::notify,
+    AtomicsObject::or,
+    AtomicsObject::pause,
+    AtomicsObject::store,
+    AtomicsObject::sub,
+    AtomicsObject::wait,
+    AtomicsObject::wait_async,
+    AtomicsObject::xor,
     BigInt64ArrayConstructor::construct,
     BigIntConstructor::construct,
     BigIntConstructor::as_int_n,
     BigIntConstructor::as_uint_n,
+    BigIntPrototype::to_locale_string,
     BigIntPrototype::to_string,
     BigIntPrototype::value_of,
     BigUInt64ArrayConstructor::construct,
@@ -252,6 +310,19 @@ rust_runtime_functions!(
     BooleanPrototype::to_string,
     BooleanPrototype::value_of,
     BoundFunctionObject::call,
+    CallSitePrototype::get_column_number,
+    CallSitePrototype::get_file_name,
+    CallSitePrototype::get_function_name,
+    CallSitePrototype::get_line_number,
+    CallSitePrototype::is_async,
+    CallSitePrototype::is_constructor,
+    CallSitePrototype::is_native,
+    CallSitePrototype::to_string,
+    CollatorConstructor::construct,
+    CollatorConstructor::supported_locales_of,
+    CollatorPrototype::collator_compare,
+    CollatorPrototype::get_compare,
+    CollatorPrototype::resolved_options,
     DataViewConstructor::construct,
     DataViewPrototype::get_big_int64,
     DataViewPrototype::get_big_uint64,
@@ -325,9 +396,49 @@ rust_runtime_functions!(
     DatePrototype::to_locale_time_string,
     DatePrototype::to_primitive,
     DatePrototype::to_string,
+    DatePrototype::to_temporal_instant,
     DatePrototype::to_time_string,
     DatePrototype::to_utc_string,
     DatePrototype::value_of,
+    DateTimeFormatConstructor::construct,
+    DateTimeFormatConstructor::supported_locales_of,
+    DateTimeFormatPrototype::date_time_format_function,
+    DateTimeFormatPrototype::format_range,
+    DateTimeFormatPrototype::format_range_to_parts,
+    DateTimeFormatPrototype::format_to_parts,
+    DateTimeFormatPrototype::get_format,
+    DateTimeFormatPrototype::resolved_options,
+    DisplayNamesConstructor::construct,
+    DisplayNamesConstructor::supported_locales_of,
+    DisplayNamesPrototype::of,
+    DisplayNamesPrototype::resolved_options,
+    DurationConstructor::compare,
+    DurationConstructor::construct,
+    DurationConstructor::from,
+    DurationPrototype::abs,
+    DurationPrototype::add,
+    DurationPrototype::blank,
+    DurationPrototype::days,
+    DurationPrototype::hours,
+    DurationPrototype::microseconds,
+    DurationPrototype::milliseconds,
+    DurationPrototype::minutes,
+    DurationPrototype::months,
+    DurationPrototype::nanoseconds,
+    DurationPrototype::negated,
+    DurationPrototype::round,
+    DurationPrototype::seconds,
+    DurationPrototype::sign,
+    DurationPrototype::subtract,
+    DurationPrototype::to_json,
+    DurationPrototype::to_locale_string,
+    DurationPrototype::to_string,
+    DurationPrototype::total,
+    DurationPrototype::value_of,
+    DurationPrototype::weeks,
+    DurationPrototype::with,
+    DurationPrototype::years,
+    ErrorConstructor::capture_stack_trace,
     ErrorConstructor::construct,
     ErrorConstructor::is_error,
     ErrorPrototype::get_stack,
@@ -361,9 +472,29 @@ rust_runtime_functions!(
     global_object::parse_float,
     global_object::parse_int,
     global_object::unescape,
+    InstantConstructor::compare,
+    InstantConstructor::construct,
+    InstantConstructor::from,
+    InstantConstructor::from_epoch_milliseconds,
+    InstantConstructor::from_epoch_nanoseconds,
+    InstantPrototype::add,
+    InstantPrototype::epoch_milliseconds,
+    InstantPrototype::epoch_nanoseconds,
+    InstantPrototype::equals,
+    InstantPrototype::round,
+    InstantPrototype::since,
+    InstantPrototype::subtract,
+    InstantPrototype::to_json,
+    InstantPrototype::to_locale_string,
+    InstantPrototype::to_string,
+    InstantPrototype::to_zoned_date_time_iso,
+    InstantPrototype::until,
+    InstantPrototype::value_of,
     Int8ArrayConstructor::construct,
     Int16ArrayConstructor::construct,
     Int32ArrayConstructor::construct,
+    IntlObject::get_canonical_locales,
+    IntlObject::supported_values_of,
     intrinsics::throw_type_error,
     IteratorConstructor::construct,
     IteratorConstructor::from,
@@ -386,6 +517,25 @@ rust_runtime_functions!(
     IteratorPrototype::to_array,
     JSONObject::parse,
     JSONObject::stringify,
+    ListFormatConstructor::construct,
+    ListFormatConstructor::supported_locales_of,
+    ListFormatPrototype::format,
+    ListFormatPrototype::format_to_parts,
+    ListFormatPrototype::resolved_options,
+    LocaleConstructor::construct,
+    LocalePrototype::base_name,
+    LocalePrototype::calendar,
+    LocalePrototype::case_first,
+    LocalePrototype::collation,
+    LocalePrototype::hour_cycle,
+    LocalePrototype::language,
+    LocalePrototype::maximize,
+    LocalePrototype::minimize,
+    LocalePrototype::numbering_system,
+    LocalePrototype::numeric,
+    LocalePrototype::region,
+    LocalePrototype::script,
+    LocalePrototype::to_string,
     MapConstructor::construct,
     MapConstructor::group_by,
     MapIteratorPrototype::next,
@@ -477,6 +627,142 @@ rust_runtime_functions!(
     ObjectPrototype::define_setter,
     ObjectPrototype::lookup_getter,
     ObjectPrototype::lookup_setter,
+    PlainDateConstructor::compare,
+    PlainDateConstructor::construct,
+    PlainDateConstructor::from,
+    PlainDatePrototype::add,
+    PlainDatePrototype::calendar_id,
+    PlainDatePrototype::day,
+    PlainDatePrototype::day_of_week,
+    PlainDatePrototype::day_of_year,
+    PlainDatePrototype::days_in_month,
+    PlainDatePrototype::days_in_week,
+    PlainDatePrototype::days_in_year,
+    PlainDatePrototype::equals,
+    PlainDatePrototype::era,
+    PlainDatePrototype::era_year,
+    PlainDatePrototype::in_leap_year,
+    PlainDatePrototype::month,
+    PlainDatePrototype::month_code,
+    PlainDatePrototype::months_in_year,
+    PlainDatePrototype::since,
+    PlainDatePrototype::subtract,
+    PlainDatePrototype::to_json,
+    PlainDatePrototype::to_locale_string,
+    PlainDatePrototype::to_plain_date_time,
+    PlainDatePrototype::to_plain_month_day,
+    PlainDatePrototype::to_plain_year_month,
+    PlainDatePrototype::to_string,
+    PlainDatePrototype::to_zoned_date_time,
+    PlainDatePrototype::until,
+    PlainDatePrototype::value_of,
+    PlainDatePrototype::week_of_year,
+    PlainDatePrototype::with,
+    PlainDatePrototype::with_calendar,
+    PlainDatePrototype::year,
+    PlainDatePrototype::year_of_week,
+    PlainDateTimeConstructor::compare,
+    PlainDateTimeConstructor::construct,
+    PlainDateTimeConstructor::from,
+    PlainDateTimePrototype::add,
+    PlainDateTimePrototype::calendar_id,
+    PlainDateTimePrototype::day,
+    PlainDateTimePrototype::day_of_week,
+    PlainDateTimePrototype::day_of_year,
+    PlainDateTimePrototype::days_in_month,
+    PlainDateTimePrototype::days_in_week,
+    PlainDateTimePrototype::days_in_year,
+    PlainDateTimePrototype::equals,
+    PlainDateTimePrototype::era,
+    PlainDateTimePrototype::era_year,
+    PlainDateTimePrototype::hour,
+    PlainDateTimePrototype::in_leap_year,
+    PlainDateTimePrototype::microsecond,
+    PlainDateTimePrototype::millisecond,
+    PlainDateTimePrototype::minute,
+    PlainDateTimePrototype::month,
+    PlainDateTimePrototype::month_code,
+    PlainDateTimePrototype::months_in_year,
+    PlainDateTimePrototype::nanosecond,
+    PlainDateTimePrototype::round,
+    PlainDateTimePrototype::second,
+    PlainDateTimePrototype::since,
+    PlainDateTimePrototype::subtract,
+    PlainDateTimePrototype::to_json,
+    PlainDateTimePrototype::to_locale_string,
+    PlainDateTimePrototype::to_plain_date,
+    PlainDateTimePrototype::to_plain_time,
+    PlainDateTimePrototype::to_string,
+    PlainDateTimePrototype::to_zoned_date_time,
+    PlainDateTimePrototype::until,
+    PlainDateTimePrototype::value_of,
+    PlainDateTimePrototype::week_of_year,
+    PlainDateTimePrototype::with,
+    PlainDateTimePrototype::with_calendar,
+    PlainDateTimePrototype::with_plain_time,
+    PlainDateTimePrototype::year,
+    PlainDateTimePrototype::year_of_week,
+    PlainMonthDayConstructor::construct,
+    PlainMonthDayConstructor::from,
+    PlainMonthDayPrototype::calendar_id,
+    PlainMonthDayPrototype::day,
+    PlainMonthDayPrototype::equals,
+    PlainMonthDayPrototype::month_code,
+    PlainMonthDayPrototype::to_json,
+    PlainMonthDayPrototype::to_locale_string,
+    PlainMonthDayPrototype::to_plain_date,
+    PlainMonthDayPrototype::to_string,
+    PlainMonthDayPrototype::value_of,
+    PlainMonthDayPrototype::with,
+    PlainTimeConstructor::compare,
+    PlainTimeConstructor::construct,
+    PlainTimeConstructor::from,
+    PlainTimePrototype::add,
+    PlainTimePrototype::equals,
+    PlainTimePrototype::hour,
+    PlainTimePrototype::microsecond,
+    PlainTimePrototype::millisecond,
+    PlainTimePrototype::minute,
+    PlainTimePrototype::nanosecond,
+    PlainTimePrototype::round,
+    PlainTimePrototype::second,
+    PlainTimePrototype::since,
+    PlainTimePrototype::subtract,
+    PlainTimePrototype::to_json,
+    PlainTimePrototype::to_locale_string,
+    PlainTimePrototype::to_string,
+    PlainTimePrototype::until,
+    PlainTimePrototype::value_of,
+    PlainTimePrototype::with,
+    PlainYearMonthConstructor::compare,
+    PlainYearMonthConstructor::construct,
+    PlainYearMonthConstructor::from,
+    PlainYearMonthPrototype::add,
+    PlainYearMonthPrototype::calendar_id,
+    PlainYearMonthPrototype::days_in_month,
+    PlainYearMonthPrototype::days_in_year,
+    PlainYearMonthPrototype::equals,
+    PlainYearMonthPrototype::era,
+    PlainYearMonthPrototype::era_year,
+    PlainYearMonthPrototype::in_leap_year,
+    PlainYearMonthPrototype::month,
+    PlainYearMonthPrototype::month_code,
+    PlainYearMonthPrototype::months_in_year,
+    PlainYearMonthPrototype::since,
+    PlainYearMonthPrototype::subtract,
+    PlainYearMonthPrototype::to_json,
+    PlainYearMonthPrototype::to_locale_string,
+    PlainYearMonthPrototype::to_plain_date,
+    PlainYearMonthPrototype::to_string,
+    PlainYearMonthPrototype::until,
+    PlainYearMonthPrototype::value_of,
+    PlainYearMonthPrototype::with,
+    PlainYearMonthPrototype::year,
+    PluralRulesConstructor::construct,
+    PluralRulesConstructor::supported_locales_of,
+    PluralRulesPrototype::resolved_options,
+    PluralRulesPrototype::select,
+    PluralRulesPrototype::select_range,
     PromiseCapability::executor,
     PromiseConstructor::all,
     PromiseConstructor::all_settled,
@@ -503,11 +789,25 @@ rust_runtime_functions!(
     ProxyConstructor::construct,
     ProxyConstructor::revocable,
     proxy_constructor::revoke,
+    NowObject::instant,
+    NowObject::plain_date_iso,
+    NowObject::plain_date_time_iso,
+    NowObject::plain_time_iso,
+    NowObject::time_zone_id,
+    NowObject::zoned_date_time_iso,
     NumberConstructor::construct,
     NumberConstructor::is_finite,
     NumberConstructor::is_integer,
     NumberConstructor::is_nan,
     NumberConstructor::is_safe_integer,
+    NumberFormatConstructor::construct,
+    NumberFormatConstructor::supported_locales_of,
+    NumberFormatPrototype::format_range,
+    NumberFormatPrototype::format_range_to_parts,
+    NumberFormatPrototype::format_to_parts,
+    NumberFormatPrototype::get_format,
+    NumberFormatPrototype::number_format_function,
+    NumberFormatPrototype::resolved_options,
     NumberPrototype::to_exponential,
     NumberPrototype::to_fixed,
     NumberPrototype::to_locale_string,
@@ -551,6 +851,18 @@ rust_runtime_functions!(
     RegExpPrototype::unicode,
     RegExpPrototype::unicode_sets,
     RegExpStringIteratorPrototype::next,
+    RelativeTimeFormatConstructor::construct,
+    RelativeTimeFormatConstructor::supported_locales_of,
+    RelativeTimeFormatPrototype::format,
+    RelativeTimeFormatPrototype::format_to_parts,
+    RelativeTimeFormatPrototype::resolved_options,
+    SegmentIteratorPrototype::next,
+    SegmenterConstructor::construct,
+    SegmenterConstructor::supported_locales_of,
+    SegmenterPrototype::resolved_options,
+    SegmenterPrototype::segment,
+    SegmentsPrototype::containing,
+    SegmentsPrototype::iterator,
     SetConstructor::construct,
     SetIteratorPrototype::next,
     SetPrototype::add,
@@ -568,6 +880,12 @@ rust_runtime_functions!(
     SetPrototype::symmetric_difference,
     SetPrototype::union,
     SetPrototype::values,
+    SharedArrayBufferConstructor::construct,
+    SharedArrayBufferPrototype::get_byte_length,
+    SharedArrayBufferPrototype::get_growable,
+    SharedArrayBufferPrototype::get_max_byte_length,
+    SharedArrayBufferPrototype::grow,
+    SharedArrayBufferPrototype::slice,
     StringConstructor::construct,
     StringConstructor::from_char_code,
     StringConstructor::from_code_point,
@@ -684,7 +1002,67 @@ rust_runtime_functions!(
     WrapForValidIteratorPrototype::next,
     WrapForValidIteratorPrototype::return_,
     // Non-standard functions
+    ZonedDateTimeConstructor::compare,
+    ZonedDateTimeConstructor::construct,
+    ZonedDateTimeConstructor::from,
+    ZonedDateTimePrototype::add,
+    ZonedDateTimePrototype::calendar_id,
+    ZonedDateTimePrototype::day,
+    ZonedDateTimePrototype::day_of_week,
+    ZonedDateTimePrototype::day_of_year,
+    ZonedDateTimePrototype::days_in_month,
+    ZonedDateTimePrototype::days_in_week,
+    ZonedDateTimePrototype::days_in_year,
+    ZonedDateTimePrototype::epoch_milliseconds,
+    ZonedDateTimePrototype::epoch_nanoseconds,
+    ZonedDateTimePrototype::equals,
+    ZonedDateTimePrototype::era,
+    ZonedDateTimePrototype::era_year,
+    ZonedDateTimePrototype::get_time_zone_transition,
+    ZonedDateTimePrototype::hour,
+    ZonedDateTimePrototype::hours_in_day,
+    ZonedDateTimePrototype::in_leap_year,
+    ZonedDateTimePrototype::microsecond,
+    ZonedDateTimePrototype::millisecond,
+    ZonedDateTimePrototype::minute,
+    ZonedDateTimePrototype::month,
+    ZonedDateTimePrototype::month_code,
+    ZonedDateTimePrototype::months_in_year,
+    ZonedDateTimePrototype::nanosecond,
+    ZonedDateTimePrototype::offset,
+    ZonedDateTimePrototype::offset_nanoseconds,
+    ZonedDateTimePrototype::round,
+    ZonedDateTimePrototype::second,
+    ZonedDateTimePrototype::since,
+    ZonedDateTimePrototype::start_of_day,
+    ZonedDateTimePrototype::subtract,
+    ZonedDateTimePrototype::time_zone_id,
+    ZonedDateTimePrototype::to_instant,
+    ZonedDateTimePrototype::to_json,
+    ZonedDateTimePrototype::to_locale_string,
+    ZonedDateTimePrototype::to_plain_date,
+    ZonedDateTimePrototype::to_plain_date_time,
+    ZonedDateTimePrototype::to_plain_time,
+    ZonedDateTimePrototype::to_string,
+    ZonedDateTimePrototype::until,
+    ZonedDateTimePrototype::value_of,
+    ZonedDateTimePrototype::week_of_year,
+    ZonedDateTimePrototype::with,
+    ZonedDateTimePrototype::with_calendar,
+    ZonedDateTimePrototype::with_plain_time,
+    ZonedDateTimePrototype::with_time_zone,
+    ZonedDateTimePrototype::year,
+    ZonedDateTimePrototype::year_of_week,
     GcObject::run,
+    GcObject::statistics,
+    Test262Object::agent_broadcast,
+    Test262Object::agent_get_report,
+    Test262Object::agent_leaving,
+    Test262Object::agent_monotonic_now,
+    Test262Object::agent_receive_broadcast,
+    Test262Object::agent_report,
+    Test262Object::agent_sleep,
+    Test262Object::agent_start,
     Test262Object::create_realm,
     Test262Object::detach_array_buffer,
     Test262Object::eval_script,
@@ -0,0 +1,62 @@
+use crate::runtime::{
+    alloc_error::AllocResult, builtin_function::BuiltinFunction, error::type_error,
+    eval_result::EvalResult, function::get_argument, object_value::ObjectValue, realm::Realm,
+    type_utilities::to_index, Context, StackRoot, Value,
+};
+
+use super::{
+    array_buffer_constructor::{get_array_buffer_max_byte_length_option, ArrayBufferObject},
+    intrinsics::Intrinsic,
+    rust_runtime::return_this,
+};
+
+pub struct SharedArrayBufferConstructor;
+
+impl SharedArrayBufferConstructor {
+    /// Properties of the SharedArrayBuffer Constructor (https://tc39.es/ecma262/#sec-properties-of-the-sharedarraybuffer-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            1,
+            cx.names.shared_array_buffer(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::SharedArrayBufferPrototype)
+                .into(),
+        )?;
+
+        // get SharedArrayBuffer [ @@species ] (https://tc39.es/ecma262/#sec-sharedarraybuffer-%symbol.species%)
+        let species_key = cx.well_known_symbols.species();
+        func.intrinsic_getter(cx, species_key, return_this, realm)?;
+
+        Ok(func)
+    }
+
+    /// SharedArrayBuffer (https://tc39.es/ecma262/#sec-sharedarraybuffer-length)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "SharedArrayBuffer constructor must be called with new");
+        };
+
+        let byte_length_arg = get_argument(cx, arguments, 0);
+        let byte_length = to_index(cx, byte_length_arg)?;
+
+        let options_arg = get_argument(cx, arguments, 1);
+        let max_byte_length = get_array_buffer_max_byte_length_option(cx, options_arg)?;
+
+        Ok(ArrayBufferObject::new_shared(cx, new_target, byte_length, max_byte_length)?.as_value())
+    }
+}
@@ -0,0 +1,224 @@
+use alloc::format;
+
+use crate::runtime::{
+    abstract_operations::{construct, species_constructor},
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    function::get_argument,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    type_utilities::{to_index, to_integer_or_infinity},
+    Context, EvalResult, StackRoot, Value,
+};
+
+use super::{
+    array_buffer_constructor::{copy_array_buffer_data, ArrayBufferObject},
+    intrinsics::Intrinsic,
+};
+
+pub struct SharedArrayBufferPrototype;
+
+impl SharedArrayBufferPrototype {
+    /// Properties of the SharedArrayBuffer Prototype Object (https://tc39.es/ecma262/#sec-properties-of-the-sharedarraybuffer-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once SharedArrayBufferConstructor has been created
+        object.intrinsic_getter(cx, cx.names.byte_length(), Self::get_byte_length, realm)?;
+        object.intrinsic_func(cx, cx.names.grow(), Self::grow, 1, realm)?;
+        object.intrinsic_getter(cx, cx.names.growable(), Self::get_growable, realm)?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.max_byte_length(),
+            Self::get_max_byte_length,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.slice(), Self::slice, 2, realm)?;
+
+        // SharedArrayBuffer.prototype [ %Symbol.toStringTag% ] (https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype-%symbol.tostringtag%)
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(
+                cx.names.shared_array_buffer().as_string().into(),
+                false,
+                false,
+                true,
+            ),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get SharedArrayBuffer.prototype.byteLength (https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength)
+    pub fn get_byte_length(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let array_buffer = require_shared_array_buffer(cx, this_value, "byteLength")?;
+        Ok(Value::from(array_buffer.byte_length()).to_stack(cx))
+    }
+
+    /// SharedArrayBuffer.prototype.grow (https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.grow)
+    pub fn grow(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let array_buffer = require_shared_array_buffer(cx, this_value, "grow")?;
+
+        let max_byte_length = if let Some(max_byte_length) = array_buffer.max_byte_length() {
+            max_byte_length
+        } else {
+            return type_error(cx, "shared array buffer is not growable");
+        };
+
+        let new_length_arg = get_argument(cx, arguments, 0);
+        let new_byte_length = to_index(cx, new_length_arg)?;
+
+        if new_byte_length > max_byte_length {
+            return range_error(cx, "new length exceeds max byte length");
+        }
+
+        let shared_data_block = array_buffer.shared_data_block().unwrap();
+        if !shared_data_block.grow(new_byte_length) {
+            return range_error(cx, "shared array buffer cannot shrink");
+        }
+
+        Ok(cx.undefined())
+    }
+
+    /// get SharedArrayBuffer.prototype.growable (https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.growable)
+    pub fn get_growable(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let array_buffer = require_shared_array_buffer(cx, this_value, "growable")?;
+        Ok(cx.bool(!array_buffer.is_fixed_length()))
+    }
+
+    /// get SharedArrayBuffer.prototype.maxByteLength (https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.maxbytelength)
+    pub fn get_max_byte_length(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let array_buffer = require_shared_array_buffer(cx, this_value, "maxByteLength")?;
+
+        let max_byte_length = array_buffer
+            .max_byte_length()
+            .unwrap_or(array_buffer.byte_length());
+
+        Ok(Value::from(max_byte_length).to_stack(cx))
+    }
+
+    /// SharedArrayBuffer.prototype.slice (https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice)
+    pub fn slice(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let array_buffer = require_shared_array_buffer(cx, this_value, "slice")?;
+
+        let length = array_buffer.byte_length() as u64;
+
+        // Calculate the start index of the slice
+        let start_arg = get_argument(cx, arguments, 0);
+        let relative_start = to_integer_or_infinity(cx, start_arg)?;
+        let start_index = if relative_start < 0.0 {
+            if relative_start == f64::NEG_INFINITY {
+                0
+            } else {
+                i64::max(length as i64 + relative_start as i64, 0) as u64
+            }
+        } else {
+            u64::min(relative_start as u64, length)
+        };
+
+        // Calculate the end index of the slice
+        let end_argument = get_argument(cx, arguments, 1);
+        let end_index = if !end_argument.is_undefined() {
+            let relative_end = to_integer_or_infinity(cx, end_argument)?;
+
+            if relative_end < 0.0 {
+                if relative_end == f64::NEG_INFINITY {
+                    0
+                } else {
+                    i64::max(length as i64 + relative_end as i64, 0) as u64
+                }
+            } else {
+                u64::min(relative_end as u64, length)
+            }
+        } else {
+            length
+        };
+
+        let new_length = end_index.saturating_sub(start_index);
+        let new_length_value = Value::from(new_length).to_stack(cx);
+
+        // Call species constructor to create new shared array buffer with the given length
+        let constructor = species_constructor(
+            cx,
+            array_buffer.into(),
+            Intrinsic::SharedArrayBufferConstructor,
+        )?;
+        let new_object = construct(cx, constructor, &[new_length_value], None)?;
+
+        // Check type of object returned from constructor
+        let new_array_buffer = if let Some(array_buffer) = new_object.as_shared_array_buffer() {
+            array_buffer
+        } else {
+            return type_error(cx, "expected shared array buffer");
+        };
+
+        let is_same_data_block = core::ptr::eq(
+            new_array_buffer.shared_data_block().unwrap(),
+            array_buffer.shared_data_block().unwrap(),
+        );
+        if is_same_data_block {
+            return type_error(cx, "constructor cannot return same shared array buffer");
+        } else if (new_array_buffer.byte_length() as u64) < new_length {
+            return type_error(cx, "new shared array buffer is too small");
+        }
+
+        // Copy data from original shared array buffer to new shared array buffer. Other agents may
+        // be accessing either data block, so bytes are copied with Unordered accesses.
+        unsafe {
+            copy_array_buffer_data(
+                *array_buffer,
+                start_index as usize,
+                *new_array_buffer,
+                0,
+                new_length as usize,
+            )
+        }
+
+        Ok(new_array_buffer.as_value())
+    }
+}
+
+fn require_shared_array_buffer(
+    cx: Context,
+    value: StackRoot<Value>,
+    method_name: &str,
+) -> EvalResult<StackRoot<ArrayBufferObject>> {
+    if value.is_object() {
+        let object = value.as_object();
+        if let Some(array_buffer) = object.as_shared_array_buffer() {
+            return Ok(array_buffer);
+        }
+    }
+
+    type_error(
+        cx,
+        &format!("SharedArrayBuffer.prototype.{method_name} expected SharedArrayBuffer"),
+    )
+}
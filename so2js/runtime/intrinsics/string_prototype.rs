@@ -17,6 +17,7 @@ use crate::{
         eval_result::EvalResult,
         function::get_argument,
         get,
+        intl::collator_constructor::compare_strings_with_locales,
         intrinsics::{
             intrinsics::Intrinsic,
             regexp_constructor::{regexp_create, RegExpSource},
@@ -429,7 +430,7 @@ impl StringPrototype {
         }
     }
 
-    /// String.prototype.localeCompare (https://tc39.es/ecma262/#sec-string.prototype.localecompare)
+    /// String.prototype.localeCompare (https://tc39.es/ecma402/#sup-String.prototype.localeCompare)
     pub fn locale_compare(
         cx: Context,
         this_value: StackRoot<Value>,
@@ -441,12 +442,11 @@ impl StringPrototype {
         let other_arg = get_argument(cx, arguments, 0);
         let other_string = to_string(cx, other_arg)?;
 
-        let wtf8_string = string.to_wtf8_string(cx)?;
-        let wtf8_other_string = other_string.to_wtf8_string(cx)?;
-        let comparison = ICU
-            .collator
-            .as_borrowed()
-            .compare_utf8(wtf8_string.as_bytes(), wtf8_other_string.as_bytes());
+        let locales = get_argument(cx, arguments, 1);
+        let options = get_argument(cx, arguments, 2);
+
+        let comparison =
+            compare_strings_with_locales(cx, string, other_string, locales, options)?;
 
         let comparison_number = match comparison {
             Ordering::Less => -1,
@@ -0,0 +1,531 @@
+//! Calendars and the reading of calendar fields from property bags. Only the ISO 8601 calendar is
+//! supported.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use crate::runtime::{
+    abstract_operations::get,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    heap_item_descriptor::HeapItemKind,
+    object_value::ObjectValue,
+    property_key::PropertyKey,
+    type_utilities::{to_primitive, ToPrimitivePreferredType},
+    Context, StackRoot, Value,
+};
+
+use super::{
+    iso::{
+        iso_date_within_limits, iso_year_month_within_limits, regulate_iso_date, regulate_time,
+        IsoDate, IsoDateTime, IsoTime, Overflow,
+    },
+    options::{to_integer_with_truncation, to_positive_integer_with_truncation},
+    parser::{parse_calendar_string, parse_date_time_utc_offset, ParsedOffset},
+    time_zone::to_temporal_time_zone_identifier,
+};
+
+/// Identifier of the ISO 8601 calendar, the only supported calendar.
+pub const ISO8601: &str = "iso8601";
+
+/// Whether an object is a Temporal object with a calendar.
+fn has_calendar(object: StackRoot<ObjectValue>) -> bool {
+    matches!(
+        object.descriptor().kind(),
+        HeapItemKind::PlainDateObject
+            | HeapItemKind::PlainDateTimeObject
+            | HeapItemKind::PlainMonthDayObject
+            | HeapItemKind::PlainYearMonthObject
+            | HeapItemKind::ZonedDateTimeObject
+    )
+}
+
+/// CanonicalizeCalendar (https://tc39.es/proposal-temporal/#sec-temporal-canonicalizecalendar)
+pub fn canonicalize_calendar(cx: Context, identifier: &str) -> EvalResult<()> {
+    if !identifier.eq_ignore_ascii_case(ISO8601) {
+        return range_error(cx, &format!("unsupported calendar {identifier}"));
+    }
+
+    Ok(())
+}
+
+/// The calendar argument of a constructor, which must be undefined or a calendar identifier.
+pub fn to_calendar_argument(cx: Context, value: StackRoot<Value>) -> EvalResult<()> {
+    if value.is_undefined() {
+        return Ok(());
+    }
+
+    if !value.is_string() {
+        return type_error(cx, "calendar must be a string");
+    }
+
+    canonicalize_calendar(cx, &value.as_string().format(cx)?)
+}
+
+/// ToTemporalCalendarIdentifier (https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendaridentifier)
+pub fn to_temporal_calendar_identifier(cx: Context, value: StackRoot<Value>) -> EvalResult<()> {
+    if value.is_object() && has_calendar(value.as_object()) {
+        return Ok(());
+    }
+
+    if !value.is_string() {
+        return type_error(cx, "calendar must be a string");
+    }
+
+    let string = value.as_string().format(cx)?;
+    let identifier = parse_calendar_string(&string).unwrap_or(string);
+
+    canonicalize_calendar(cx, &identifier)
+}
+
+/// GetTemporalCalendarIdentifierWithISODefault (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarslotvaluewithisodefault)
+pub fn get_temporal_calendar_identifier_with_iso_default(
+    cx: Context,
+    item: StackRoot<ObjectValue>,
+) -> EvalResult<()> {
+    if has_calendar(item) {
+        return Ok(());
+    }
+
+    let calendar = get(cx, item, cx.names.calendar())?;
+    if calendar.is_undefined() {
+        return Ok(());
+    }
+
+    to_temporal_calendar_identifier(cx, calendar)
+}
+
+/// IsPartialTemporalObject (https://tc39.es/proposal-temporal/#sec-temporal-ispartialtemporalobject)
+///
+/// Throws a TypeError if the value is not a partial object that can be used to update a Temporal
+/// object's fields.
+pub fn check_partial_temporal_object(cx: Context, value: StackRoot<Value>) -> EvalResult<()> {
+    if !value.is_object() {
+        return type_error(cx, "expected an object");
+    }
+
+    let object = value.as_object();
+    if has_calendar(object) || object.descriptor().kind() == HeapItemKind::PlainTimeObject {
+        return type_error(cx, "expected a plain object, not a Temporal object");
+    }
+
+    let calendar = get(cx, object, cx.names.calendar())?;
+    if !calendar.is_undefined() {
+        return type_error(cx, "calendar cannot be changed");
+    }
+
+    let time_zone = get(cx, object, cx.names.time_zone())?;
+    if !time_zone.is_undefined() {
+        return type_error(cx, "timeZone cannot be changed");
+    }
+
+    Ok(())
+}
+
+/// The fields that can be read from a property bag, declared in the order they are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field {
+    Day,
+    Hour,
+    Microsecond,
+    Millisecond,
+    Minute,
+    Month,
+    MonthCode,
+    Nanosecond,
+    Offset,
+    Second,
+    TimeZone,
+    Year,
+}
+
+impl Field {
+    fn key(&self, cx: Context) -> StackRoot<PropertyKey> {
+        match self {
+            Field::Day => cx.names.day(),
+            Field::Hour => cx.names.hour(),
+            Field::Microsecond => cx.names.microsecond(),
+            Field::Millisecond => cx.names.millisecond(),
+            Field::Minute => cx.names.minute(),
+            Field::Month => cx.names.month(),
+            Field::MonthCode => cx.names.month_code(),
+            Field::Nanosecond => cx.names.nanosecond(),
+            Field::Offset => cx.names.offset(),
+            Field::Second => cx.names.second(),
+            Field::TimeZone => cx.names.time_zone(),
+            Field::Year => cx.names.year(),
+        }
+    }
+}
+
+/// Fields of a date.
+pub const DATE_FIELDS: &[Field] = &[Field::Year, Field::Month, Field::MonthCode, Field::Day];
+
+/// Fields of a year and month.
+pub const YEAR_MONTH_FIELDS: &[Field] = &[Field::Year, Field::Month, Field::MonthCode];
+
+/// Fields of a wall-clock time.
+pub const TIME_FIELDS: &[Field] = &[
+    Field::Hour,
+    Field::Minute,
+    Field::Second,
+    Field::Millisecond,
+    Field::Microsecond,
+    Field::Nanosecond,
+];
+
+/// Fields that must be present in a property bag.
+#[derive(Clone, Copy, Debug)]
+pub enum RequiredFields<'a> {
+    /// All listed fields must be present
+    List(&'a [Field]),
+    /// At least one field must be present
+    Partial,
+}
+
+/// A month code such as `M01`, or `M05L` for a leap month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonthCode {
+    pub month: u8,
+    pub is_leap_month: bool,
+}
+
+impl MonthCode {
+    pub fn new(month: u8) -> MonthCode {
+        MonthCode {
+            month,
+            is_leap_month: false,
+        }
+    }
+
+    /// ParseMonthCode (https://tc39.es/proposal-temporal/#sec-temporal-parsemonthcode)
+    pub fn parse(string: &str) -> Option<MonthCode> {
+        let (digits, is_leap_month) = match string.as_bytes() {
+            [b'M', d1, d2] => ([*d1, *d2], false),
+            [b'M', d1, d2, b'L'] => ([*d1, *d2], true),
+            _ => return None,
+        };
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let month = (digits[0] - b'0') * 10 + (digits[1] - b'0');
+        if month == 0 && !is_leap_month {
+            return None;
+        }
+
+        Some(MonthCode {
+            month,
+            is_leap_month,
+        })
+    }
+}
+
+impl fmt::Display for MonthCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = if self.is_leap_month { "L" } else { "" };
+        write!(f, "M{:02}{suffix}", self.month)
+    }
+}
+
+/// A calendar fields record, where absent fields are None.
+///
+/// Calendar Fields Records (https://tc39.es/proposal-temporal/#sec-temporal-calendar-fields-records)
+#[derive(Clone, Debug, Default)]
+pub struct CalendarFields {
+    pub year: Option<f64>,
+    pub month: Option<f64>,
+    pub month_code: Option<MonthCode>,
+    pub day: Option<f64>,
+    pub hour: Option<f64>,
+    pub minute: Option<f64>,
+    pub second: Option<f64>,
+    pub millisecond: Option<f64>,
+    pub microsecond: Option<f64>,
+    pub nanosecond: Option<f64>,
+    /// The offset in nanoseconds
+    pub offset: Option<i128>,
+    pub time_zone: Option<String>,
+}
+
+/// The kind of value that calendar fields are resolved to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldsType {
+    Date,
+    YearMonth,
+    MonthDay,
+}
+
+impl CalendarFields {
+    /// CalendarMergeFields (https://tc39.es/proposal-temporal/#sec-temporal-calendarmergefields)
+    ///
+    /// Fields present in `additional` override fields of this record. The month and month code
+    /// override each other, since either may determine the month.
+    pub fn merge(&self, additional: &CalendarFields) -> CalendarFields {
+        let mut merged = self.clone();
+
+        if additional.month.is_some() || additional.month_code.is_some() {
+            merged.month = additional.month;
+            merged.month_code = additional.month_code;
+        }
+
+        macro_rules! merge_fields {
+            ($($field:ident),*) => {
+                $(
+                    if additional.$field.is_some() {
+                        merged.$field = additional.$field.clone();
+                    }
+                )*
+            };
+        }
+
+        merge_fields!(
+            year,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            offset,
+            time_zone
+        );
+
+        merged
+    }
+
+    /// The time fields of this record, where absent fields are zero.
+    ///
+    /// Returns None if the time is invalid and overflow is reject.
+    pub fn time(&self, overflow: Overflow) -> Option<IsoTime> {
+        let fields = [
+            self.hour,
+            self.minute,
+            self.second,
+            self.millisecond,
+            self.microsecond,
+            self.nanosecond,
+        ];
+
+        regulate_time(fields.map(|field| field.unwrap_or(0.0)), overflow)
+    }
+
+    /// Set the time fields of this record.
+    pub fn set_time(&mut self, time: &IsoTime) {
+        self.hour = Some(time.hour as f64);
+        self.minute = Some(time.minute as f64);
+        self.second = Some(time.second as f64);
+        self.millisecond = Some(time.millisecond as f64);
+        self.microsecond = Some(time.microsecond as f64);
+        self.nanosecond = Some(time.nanosecond as f64);
+    }
+
+    /// CalendarResolveFields (https://tc39.es/proposal-temporal/#sec-temporal-calendarresolvefields)
+    ///
+    /// Check that the fields needed for a type are present, and return the year, month, and day
+    /// if present.
+    fn resolve(&self, cx: Context, fields_type: FieldsType) -> EvalResult<(Option<i64>, i64, i64)> {
+        if self.year.is_none() && fields_type != FieldsType::MonthDay {
+            return type_error(cx, "year is required");
+        }
+
+        if self.day.is_none() && fields_type != FieldsType::YearMonth {
+            return type_error(cx, "day is required");
+        }
+
+        let month = match self.month_code {
+            None => match self.month {
+                None => return type_error(cx, "month or monthCode is required"),
+                Some(month) => month,
+            },
+            Some(month_code) => {
+                if month_code.is_leap_month || month_code.month > 12 {
+                    return range_error(cx, &format!("invalid monthCode {month_code}"));
+                }
+
+                if let Some(month) = self.month {
+                    if month != month_code.month as f64 {
+                        return range_error(cx, "month and monthCode do not agree");
+                    }
+                }
+
+                month_code.month as f64
+            }
+        };
+
+        // Out of range years are clamped to a value that is still out of range, so that they can
+        // be safely converted to integers.
+        let year = self.year.map(|year| year.clamp(-1e9, 1e9) as i64);
+        let day = self.day.unwrap_or(1.0);
+
+        Ok((year, month as i64, day as i64))
+    }
+
+    /// CalendarDateFromFields (https://tc39.es/proposal-temporal/#sec-temporal-calendardatefromfields)
+    pub fn to_date(&self, cx: Context, overflow: Overflow) -> EvalResult<IsoDate> {
+        let (year, month, day) = self.resolve(cx, FieldsType::Date)?;
+        let year = year.unwrap();
+
+        let Some(date) = regulate_iso_date(year, month, day, overflow) else {
+            return range_error(cx, "date is not valid");
+        };
+
+        if !iso_date_within_limits(date) {
+            return range_error(cx, "date is outside the supported range");
+        }
+
+        Ok(date)
+    }
+
+    /// InterpretTemporalDateTimeFields (https://tc39.es/proposal-temporal/#sec-temporal-interprettemporaldatetimefields)
+    pub fn to_date_time(&self, cx: Context, overflow: Overflow) -> EvalResult<IsoDateTime> {
+        let date = self.to_date(cx, overflow)?;
+        let Some(time) = self.time(overflow) else {
+            return range_error(cx, "time is not valid");
+        };
+
+        Ok(IsoDateTime::new(date, time))
+    }
+
+    /// CalendarYearMonthFromFields (https://tc39.es/proposal-temporal/#sec-temporal-calendaryearmonthfromfields)
+    pub fn to_year_month(&self, cx: Context, overflow: Overflow) -> EvalResult<IsoDate> {
+        let (year, month, _) = self.resolve(cx, FieldsType::YearMonth)?;
+        let year = year.unwrap();
+
+        let Some(date) = regulate_iso_date(year, month, 1, overflow) else {
+            return range_error(cx, "year and month are not valid");
+        };
+
+        if !iso_year_month_within_limits(date.year, date.month) {
+            return range_error(cx, "year and month are outside the supported range");
+        }
+
+        Ok(date)
+    }
+
+    /// CalendarMonthDayFromFields (https://tc39.es/proposal-temporal/#sec-temporal-calendarmonthdayfromfields)
+    ///
+    /// The month and day are validated against the year if present, otherwise against a leap
+    /// year. The result uses the reference year 1972.
+    pub fn to_month_day(&self, cx: Context, overflow: Overflow) -> EvalResult<IsoDate> {
+        let (year, month, day) = self.resolve(cx, FieldsType::MonthDay)?;
+
+        let Some(date) = regulate_iso_date(year.unwrap_or(1972), month, day, overflow) else {
+            return range_error(cx, "month and day are not valid");
+        };
+
+        Ok(IsoDate::new(1972, date.month, date.day))
+    }
+}
+
+/// ISODateToFields (https://tc39.es/proposal-temporal/#sec-temporal-isodatetofields)
+pub fn iso_date_to_fields(date: &IsoDate, fields_type: FieldsType) -> CalendarFields {
+    let mut fields = CalendarFields {
+        month_code: Some(MonthCode::new(date.month)),
+        ..Default::default()
+    };
+
+    if fields_type != FieldsType::YearMonth {
+        fields.day = Some(date.day as f64);
+    }
+
+    if fields_type != FieldsType::MonthDay {
+        fields.year = Some(date.year as f64);
+    }
+
+    fields
+}
+
+/// ToMonthCode (https://tc39.es/proposal-temporal/#sec-temporal-tomonthcode)
+fn to_month_code(cx: Context, value: StackRoot<Value>) -> EvalResult<MonthCode> {
+    let primitive = to_primitive(cx, value, ToPrimitivePreferredType::String)?;
+    if !primitive.is_string() {
+        return type_error(cx, "monthCode must be a string");
+    }
+
+    let string = primitive.as_string().format(cx)?;
+    match MonthCode::parse(&string) {
+        Some(month_code) => Ok(month_code),
+        None => range_error(cx, &format!("invalid monthCode {string}")),
+    }
+}
+
+/// ToOffsetString (https://tc39.es/proposal-temporal/#sec-temporal-tooffsetstring)
+///
+/// Returns the offset in nanoseconds.
+fn to_offset_nanoseconds(cx: Context, value: StackRoot<Value>) -> EvalResult<i128> {
+    let primitive = to_primitive(cx, value, ToPrimitivePreferredType::String)?;
+    if !primitive.is_string() {
+        return type_error(cx, "offset must be a string");
+    }
+
+    let string = primitive.as_string().format(cx)?;
+    match parse_date_time_utc_offset(&string) {
+        Some(ParsedOffset::Offset { nanoseconds, .. }) => Ok(nanoseconds as i128),
+        _ => range_error(cx, &format!("invalid offset {string}")),
+    }
+}
+
+/// PrepareCalendarFields (https://tc39.es/proposal-temporal/#sec-temporal-preparecalendarfields)
+///
+/// Read and convert the requested fields from a property bag. Properties are read in
+/// alphabetical order.
+pub fn prepare_calendar_fields(
+    cx: Context,
+    object: StackRoot<ObjectValue>,
+    calendar_field_names: &[Field],
+    non_calendar_field_names: &[Field],
+    required_fields: RequiredFields,
+) -> EvalResult<CalendarFields> {
+    let mut field_names = calendar_field_names
+        .iter()
+        .chain(non_calendar_field_names)
+        .copied()
+        .collect::<Vec<_>>();
+    field_names.sort_by_key(|field| *field as u8);
+
+    let mut result = CalendarFields::default();
+    let mut any_found = false;
+
+    for field in field_names {
+        let value = get(cx, object, field.key(cx))?;
+        if value.is_undefined() {
+            if let RequiredFields::List(required_field_names) = required_fields {
+                if required_field_names.contains(&field) {
+                    return type_error(cx, &format!("{} is required", field.key(cx).format(cx)?));
+                }
+            }
+
+            continue;
+        }
+
+        any_found = true;
+
+        match field {
+            Field::Year => result.year = Some(to_integer_with_truncation(cx, value)?),
+            Field::Month => result.month = Some(to_positive_integer_with_truncation(cx, value)?),
+            Field::MonthCode => result.month_code = Some(to_month_code(cx, value)?),
+            Field::Day => result.day = Some(to_positive_integer_with_truncation(cx, value)?),
+            Field::Hour => result.hour = Some(to_integer_with_truncation(cx, value)?),
+            Field::Minute => result.minute = Some(to_integer_with_truncation(cx, value)?),
+            Field::Second => result.second = Some(to_integer_with_truncation(cx, value)?),
+            Field::Millisecond => result.millisecond = Some(to_integer_with_truncation(cx, value)?),
+            Field::Microsecond => result.microsecond = Some(to_integer_with_truncation(cx, value)?),
+            Field::Nanosecond => result.nanosecond = Some(to_integer_with_truncation(cx, value)?),
+            Field::Offset => result.offset = Some(to_offset_nanoseconds(cx, value)?),
+            Field::TimeZone => {
+                result.time_zone = Some(to_temporal_time_zone_identifier(cx, value)?)
+            }
+        }
+    }
+
+    if let RequiredFields::Partial = required_fields {
+        if !any_found {
+            return type_error(cx, "object must have at least one recognized property");
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,899 @@
+//! Durations and the rounding of durations relative to a date.
+
+use alloc::{format, string::String};
+
+use super::{
+    iso::{
+        add_iso_date, difference_iso_date, difference_iso_date_time, iso_date_time_within_limits,
+        DateDuration, IsoDateTime, Overflow, NS_PER_DAY, NS_PER_SECOND,
+    },
+    rounding::{round_number_to_increment, RoundingMode, Unit, UnsignedRoundingMode},
+};
+
+/// The largest magnitude of a time duration in nanoseconds, which is just under 2^53 seconds.
+pub const MAX_TIME_DURATION: i128 = (1 << 53) * NS_PER_SECOND - 1;
+
+/// A Temporal.Duration's fields. All fields are integers with the same sign.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Duration {
+    pub years: f64,
+    pub months: f64,
+    pub weeks: f64,
+    pub days: f64,
+    pub hours: f64,
+    pub minutes: f64,
+    pub seconds: f64,
+    pub milliseconds: f64,
+    pub microseconds: f64,
+    pub nanoseconds: f64,
+}
+
+impl Duration {
+    pub fn from_fields(fields: [f64; 10]) -> Duration {
+        let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            fields;
+
+        Duration {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        }
+    }
+
+    /// Fields of the duration from largest to smallest unit.
+    pub fn fields(&self) -> [f64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+
+    /// DurationSign (https://tc39.es/proposal-temporal/#sec-temporal-durationsign)
+    pub fn sign(&self) -> i64 {
+        for field in self.fields() {
+            if field < 0.0 {
+                return -1;
+            } else if field > 0.0 {
+                return 1;
+            }
+        }
+
+        0
+    }
+
+    pub fn negated(&self) -> Duration {
+        // Avoid creating negative zero
+        Duration::from_fields(
+            self.fields()
+                .map(|field| if field == 0.0 { 0.0 } else { -field }),
+        )
+    }
+
+    pub fn abs(&self) -> Duration {
+        Duration::from_fields(self.fields().map(f64::abs))
+    }
+
+    /// IsValidDuration (https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration)
+    pub fn is_valid(&self) -> bool {
+        let sign = self.sign();
+        for field in self.fields() {
+            if !field.is_finite() || (field < 0.0 && sign > 0) || (field > 0.0 && sign < 0) {
+                return false;
+            }
+        }
+
+        const MAX_CALENDAR_FIELD: f64 = 4_294_967_296.0;
+        if self.years.abs() >= MAX_CALENDAR_FIELD
+            || self.months.abs() >= MAX_CALENDAR_FIELD
+            || self.weeks.abs() >= MAX_CALENDAR_FIELD
+        {
+            return false;
+        }
+
+        // Each time field must not exceed the maximum on its own, which also guarantees that the
+        // sum of all fields can be computed exactly without overflow.
+        const MAX_SECONDS: f64 = 9_007_199_254_740_992.0;
+        let unit_seconds = [86400.0, 3600.0, 60.0, 1.0, 1e-3, 1e-6, 1e-9];
+        let time_fields = &self.fields()[3..];
+        for (field, seconds) in time_fields.iter().zip(unit_seconds) {
+            if field.abs() * seconds > MAX_SECONDS * 2.0 {
+                return false;
+            }
+        }
+
+        let total = time_duration_from_components(&self.fields()[4..].try_into().unwrap())
+            + self.days as i128 * NS_PER_DAY;
+
+        total.abs() <= MAX_TIME_DURATION
+    }
+
+    /// DefaultTemporalLargestUnit (https://tc39.es/proposal-temporal/#sec-temporal-defaulttemporallargestunit)
+    pub fn default_largest_unit(&self) -> Unit {
+        for (field, unit) in self.fields().iter().zip(Unit::ALL) {
+            if *field != 0.0 {
+                return unit;
+            }
+        }
+
+        Unit::Nanosecond
+    }
+
+    /// The date components of this duration, which must be valid.
+    pub fn date_duration(&self) -> DateDuration {
+        DateDuration::new(
+            self.years as i64,
+            self.months as i64,
+            self.weeks as i64,
+            self.days as i64,
+        )
+    }
+
+    /// The time components of this duration, in nanoseconds. The duration must be valid.
+    pub fn time_duration(&self) -> i128 {
+        time_duration_from_components(&self.fields()[4..].try_into().unwrap())
+    }
+
+    /// TemporalDurationToString (https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationtostring)
+    pub fn to_string(&self, precision: Precision) -> String {
+        let sign = self.sign();
+
+        let mut date_part = String::new();
+        for (field, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if field != 0.0 {
+                date_part.push_str(&format!("{}{designator}", format_integer(field)));
+            }
+        }
+
+        let mut time_part = String::new();
+        for (field, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+            if field != 0.0 {
+                time_part.push_str(&format!("{}{designator}", format_integer(field)));
+            }
+        }
+
+        let zero_minutes_and_higher = self.default_largest_unit() <= Unit::Second;
+        let seconds_duration = time_duration_from_components(&[
+            0.0,
+            0.0,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ])
+        .abs();
+
+        if seconds_duration != 0 || zero_minutes_and_higher || precision != Precision::Auto {
+            let seconds = seconds_duration / NS_PER_SECOND;
+            let sub_seconds =
+                format_fractional_seconds(seconds_duration % NS_PER_SECOND, precision);
+            time_part.push_str(&format!("{seconds}{sub_seconds}S"));
+        }
+
+        let mut result = String::new();
+        if sign < 0 {
+            result.push('-');
+        }
+
+        result.push('P');
+        result.push_str(&date_part);
+
+        if !time_part.is_empty() {
+            result.push('T');
+            result.push_str(&time_part);
+        }
+
+        result
+    }
+}
+
+/// Format the magnitude of an integral field of a duration as a decimal number.
+fn format_integer(value: f64) -> String {
+    format!("{}", value.abs() as u128)
+}
+
+/// The precision of seconds when formatting a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// Omit the seconds
+    Minute,
+    /// As many fractional digits as needed, omitting trailing zeros
+    Auto,
+    /// A fixed number of fractional digits from 0 to 9
+    Digits(u8),
+}
+
+/// FormatFractionalSeconds (https://tc39.es/proposal-temporal/#sec-temporal-formatfractionalseconds)
+pub fn format_fractional_seconds(sub_second_nanoseconds: i128, precision: Precision) -> String {
+    let digits = format!("{sub_second_nanoseconds:09}");
+
+    match precision {
+        Precision::Auto => {
+            if sub_second_nanoseconds == 0 {
+                return String::new();
+            }
+
+            format!(".{}", digits.trim_end_matches('0'))
+        }
+        Precision::Digits(0) | Precision::Minute => String::new(),
+        Precision::Digits(count) => format!(".{}", &digits[..count as usize]),
+    }
+}
+
+/// The precision, rounding unit, and rounding increment used when formatting seconds.
+///
+/// ToSecondsStringPrecisionRecord (https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecisionrecord)
+pub fn seconds_string_precision(
+    smallest_unit: Option<Unit>,
+    fractional_digits: Option<u8>,
+) -> (Precision, Unit, i64) {
+    match smallest_unit {
+        Some(Unit::Minute) => (Precision::Minute, Unit::Minute, 1),
+        Some(Unit::Second) => (Precision::Digits(0), Unit::Second, 1),
+        Some(Unit::Millisecond) => (Precision::Digits(3), Unit::Millisecond, 1),
+        Some(Unit::Microsecond) => (Precision::Digits(6), Unit::Microsecond, 1),
+        Some(Unit::Nanosecond) => (Precision::Digits(9), Unit::Nanosecond, 1),
+        _ => match fractional_digits {
+            None => (Precision::Auto, Unit::Nanosecond, 1),
+            Some(0) => (Precision::Digits(0), Unit::Second, 1),
+            Some(digits @ 1..=3) => (
+                Precision::Digits(digits),
+                Unit::Millisecond,
+                10_i64.pow(3 - digits as u32),
+            ),
+            Some(digits @ 4..=6) => (
+                Precision::Digits(digits),
+                Unit::Microsecond,
+                10_i64.pow(6 - digits as u32),
+            ),
+            Some(digits) => (
+                Precision::Digits(digits),
+                Unit::Nanosecond,
+                10_i64.pow(9 - digits as u32),
+            ),
+        },
+    }
+}
+
+/// TimeDurationFromComponents (https://tc39.es/proposal-temporal/#sec-temporal-timedurationfromcomponents)
+///
+/// Components are the hours, minutes, seconds, milliseconds, microseconds, and nanoseconds, which
+/// must be integers that do not overflow when converted to nanoseconds.
+pub fn time_duration_from_components(components: &[f64; 6]) -> i128 {
+    let [hours, minutes, seconds, milliseconds, microseconds, nanoseconds] = *components;
+
+    hours as i128 * Unit::Hour.nanoseconds()
+        + minutes as i128 * Unit::Minute.nanoseconds()
+        + seconds as i128 * NS_PER_SECOND
+        + milliseconds as i128 * Unit::Millisecond.nanoseconds()
+        + microseconds as i128 * Unit::Microsecond.nanoseconds()
+        + nanoseconds as i128
+}
+
+/// Check that a time duration is within the range of a duration.
+pub fn check_time_duration(time_duration: i128) -> Option<i128> {
+    if time_duration.abs() > MAX_TIME_DURATION {
+        return None;
+    }
+
+    Some(time_duration)
+}
+
+/// RoundTimeDurationToIncrement (https://tc39.es/proposal-temporal/#sec-temporal-roundtimedurationtoincrement)
+pub fn round_time_duration_to_increment(
+    time_duration: i128,
+    increment: i128,
+    rounding_mode: RoundingMode,
+) -> Option<i128> {
+    check_time_duration(round_number_to_increment(
+        time_duration,
+        increment,
+        rounding_mode,
+    ))
+}
+
+/// RoundTimeDuration (https://tc39.es/proposal-temporal/#sec-temporal-roundtimeduration)
+pub fn round_time_duration(
+    time_duration: i128,
+    increment: i64,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<i128> {
+    round_time_duration_to_increment(
+        time_duration,
+        increment as i128 * unit.nanoseconds(),
+        rounding_mode,
+    )
+}
+
+/// Convert the fraction `numerator / denominator` to the closest Number.
+pub fn fraction_to_f64(numerator: i128, denominator: i128) -> f64 {
+    let whole = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    whole as f64 + remainder as f64 / denominator as f64
+}
+
+/// TotalTimeDuration (https://tc39.es/proposal-temporal/#sec-temporal-totaltimeduration)
+pub fn total_time_duration(time_duration: i128, unit: Unit) -> f64 {
+    fraction_to_f64(time_duration, unit.nanoseconds())
+}
+
+/// A duration with its date and time components stored separately, where the time components are
+/// a number of nanoseconds.
+///
+/// Internal Duration Records (https://tc39.es/proposal-temporal/#sec-temporal-internal-duration-records)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InternalDuration {
+    pub date: DateDuration,
+    pub time: i128,
+}
+
+impl InternalDuration {
+    /// CombineDateAndTimeDuration (https://tc39.es/proposal-temporal/#sec-temporal-combinedateandtimeduration)
+    pub fn new(date: DateDuration, time: i128) -> InternalDuration {
+        InternalDuration { date, time }
+    }
+
+    /// ToInternalDurationRecord (https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecord)
+    pub fn from_duration(duration: &Duration) -> InternalDuration {
+        InternalDuration::new(duration.date_duration(), duration.time_duration())
+    }
+
+    /// ToInternalDurationRecordWith24HourDays (https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecordwith24hourdays)
+    pub fn from_duration_with_24_hour_days(duration: &Duration) -> InternalDuration {
+        let time = duration.time_duration() + duration.days as i128 * NS_PER_DAY;
+        let mut date = duration.date_duration();
+        date.days = 0;
+
+        InternalDuration::new(date, time)
+    }
+
+    /// ToDateDurationRecordWithoutTime (https://tc39.es/proposal-temporal/#sec-temporal-todatedurationrecordwithouttime)
+    pub fn to_date_duration_without_time(&self) -> DateDuration {
+        let mut date = self.date;
+        date.days = (self.time / NS_PER_DAY) as i64;
+        date
+    }
+
+    /// InternalDurationSign (https://tc39.es/proposal-temporal/#sec-temporal-internaldurationsign)
+    pub fn sign(&self) -> i64 {
+        match self.date.sign() {
+            0 => self.time.signum() as i64,
+            sign => sign,
+        }
+    }
+
+    /// TemporalDurationFromInternal (https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal)
+    ///
+    /// Returns None if the resulting duration is not valid.
+    pub fn to_duration(&self, largest_unit: Unit) -> Option<Duration> {
+        let sign = self.time.signum();
+
+        // Balance nanoseconds into each larger unit up to the largest unit, in the order
+        // nanoseconds, microseconds, milliseconds, seconds, minutes, hours, and days.
+        let mut values = [self.time.abs(), 0, 0, 0, 0, 0, 0];
+        let balance_units = [
+            (Unit::Microsecond, 1000),
+            (Unit::Millisecond, 1000),
+            (Unit::Second, 1000),
+            (Unit::Minute, 60),
+            (Unit::Hour, 60),
+            (Unit::Day, 24),
+        ];
+
+        for (i, (unit, factor)) in balance_units.into_iter().enumerate() {
+            if largest_unit < unit {
+                break;
+            }
+
+            values[i + 1] = values[i] / factor;
+            values[i] %= factor;
+        }
+
+        let [nanoseconds, microseconds, milliseconds, seconds, minutes, hours, days] = values;
+
+        let duration = Duration {
+            years: self.date.years as f64,
+            months: self.date.months as f64,
+            weeks: self.date.weeks as f64,
+            days: (self.date.days as i128 + days * sign) as f64,
+            hours: (hours * sign) as f64,
+            minutes: (minutes * sign) as f64,
+            seconds: (seconds * sign) as f64,
+            milliseconds: (milliseconds * sign) as f64,
+            microseconds: (microseconds * sign) as f64,
+            nanoseconds: (nanoseconds * sign) as f64,
+        };
+
+        if !duration.is_valid() {
+            return None;
+        }
+
+        // Avoid negative zero fields
+        Some(Duration::from_fields(
+            duration.fields().map(|field| field + 0.0),
+        ))
+    }
+}
+
+/// Converts a date-time to epoch nanoseconds in a time zone when rounding relative to a
+/// ZonedDateTime. Returns None if the result is out of range.
+pub type ZonedEpochNanoseconds<'a> = &'a dyn Fn(IsoDateTime) -> Option<i128>;
+
+/// Epoch nanoseconds of a date-time, either in UTC or in a time zone.
+fn epoch_nanoseconds_for(
+    date_time: IsoDateTime,
+    time_zone: Option<ZonedEpochNanoseconds>,
+) -> Option<i128> {
+    match time_zone {
+        None => Some(date_time.to_utc_epoch_nanoseconds()),
+        Some(time_zone) => time_zone(date_time),
+    }
+}
+
+/// AdjustDateDurationRecord (https://tc39.es/proposal-temporal/#sec-temporal-adjustdatedurationrecord)
+fn adjust_date_duration(
+    date_duration: &DateDuration,
+    days: i64,
+    weeks: Option<i64>,
+    months: Option<i64>,
+) -> DateDuration {
+    DateDuration::new(
+        date_duration.years,
+        months.unwrap_or(date_duration.months),
+        weeks.unwrap_or(date_duration.weeks),
+        days,
+    )
+}
+
+/// Duration Nudge Result Records (https://tc39.es/proposal-temporal/#sec-temporal-duration-nudge-result-records)
+struct NudgeResult {
+    duration: InternalDuration,
+    total: f64,
+    nudged_epoch_nanoseconds: i128,
+    did_expand_calendar_unit: bool,
+}
+
+/// NudgeToCalendarUnit (https://tc39.es/proposal-temporal/#sec-temporal-nudgetocalendarunit)
+#[allow(clippy::too_many_arguments)]
+fn nudge_to_calendar_unit(
+    sign: i64,
+    duration: &InternalDuration,
+    dest_epoch_nanoseconds: i128,
+    date_time: &IsoDateTime,
+    time_zone: Option<ZonedEpochNanoseconds>,
+    increment: i64,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<NudgeResult> {
+    let trunc = |value: i64| {
+        round_number_to_increment(value as i128, increment as i128, RoundingMode::Trunc) as i64
+    };
+
+    let date = &duration.date;
+    let (r1, r2, start_duration, end_duration) = match unit {
+        Unit::Year => {
+            let years = trunc(date.years);
+            let r2 = years + increment * sign;
+            (
+                years,
+                r2,
+                DateDuration::new(years, 0, 0, 0),
+                DateDuration::new(r2, 0, 0, 0),
+            )
+        }
+        Unit::Month => {
+            let months = trunc(date.months);
+            let r2 = months + increment * sign;
+            (
+                months,
+                r2,
+                adjust_date_duration(date, 0, Some(0), Some(months)),
+                adjust_date_duration(date, 0, Some(0), Some(r2)),
+            )
+        }
+        Unit::Week => {
+            let years_months = adjust_date_duration(date, 0, Some(0), None);
+            let weeks_start = add_iso_date(date_time.date, years_months, Overflow::Constrain)?;
+            let weeks_end = weeks_start.add_days(date.days);
+            let until_result = difference_iso_date(&weeks_start, &weeks_end, Unit::Week);
+            let weeks = trunc(date.weeks + until_result.weeks);
+            let r2 = weeks + increment * sign;
+            (
+                weeks,
+                r2,
+                adjust_date_duration(date, 0, Some(weeks), None),
+                adjust_date_duration(date, 0, Some(r2), None),
+            )
+        }
+        _ => {
+            let days = trunc(date.days);
+            let r2 = days + increment * sign;
+            (
+                days,
+                r2,
+                adjust_date_duration(date, days, None, None),
+                adjust_date_duration(date, r2, None, None),
+            )
+        }
+    };
+
+    let start = add_iso_date(date_time.date, start_duration, Overflow::Constrain)?;
+    let end = add_iso_date(date_time.date, end_duration, Overflow::Constrain)?;
+
+    let start_epoch_nanoseconds =
+        epoch_nanoseconds_for(IsoDateTime::new(start, date_time.time), time_zone)?;
+    let end_epoch_nanoseconds =
+        epoch_nanoseconds_for(IsoDateTime::new(end, date_time.time), time_zone)?;
+
+    // The destination must lie between the start and end, which may not hold in time zones with
+    // unusual transitions.
+    let is_between = if sign == 1 {
+        start_epoch_nanoseconds <= dest_epoch_nanoseconds
+            && dest_epoch_nanoseconds <= end_epoch_nanoseconds
+    } else {
+        end_epoch_nanoseconds <= dest_epoch_nanoseconds
+            && dest_epoch_nanoseconds <= start_epoch_nanoseconds
+    };
+
+    if !is_between || start_epoch_nanoseconds == end_epoch_nanoseconds {
+        return None;
+    }
+
+    // Progress towards the end as a fraction, with a positive denominator
+    let mut numerator = dest_epoch_nanoseconds - start_epoch_nanoseconds;
+    let mut denominator = end_epoch_nanoseconds - start_epoch_nanoseconds;
+    if denominator < 0 {
+        numerator = -numerator;
+        denominator = -denominator;
+    }
+
+    let total =
+        r1 as f64 + fraction_to_f64(numerator * increment as i128 * sign as i128, denominator);
+
+    let did_expand_calendar_unit = if numerator == denominator {
+        true
+    } else {
+        // Round the magnitude of the progress through increments of the unit
+        let unsigned_rounding_mode = UnsignedRoundingMode::new(rounding_mode, sign < 0);
+        let r1_increments = r1.abs() as i128 / increment as i128;
+        let rounded = unsigned_rounding_mode.apply(r1_increments, numerator, denominator);
+        rounded != r1_increments
+    };
+
+    let (duration, nudged_epoch_nanoseconds) = if did_expand_calendar_unit {
+        (end_duration, end_epoch_nanoseconds)
+    } else {
+        (start_duration, start_epoch_nanoseconds)
+    };
+
+    Some(NudgeResult {
+        duration: InternalDuration::new(duration, 0),
+        total,
+        nudged_epoch_nanoseconds,
+        did_expand_calendar_unit,
+    })
+}
+
+/// NudgeToZonedTime (https://tc39.es/proposal-temporal/#sec-temporal-nudgetozonedtime)
+fn nudge_to_zoned_time(
+    sign: i64,
+    duration: &InternalDuration,
+    date_time: &IsoDateTime,
+    time_zone: ZonedEpochNanoseconds,
+    increment: i64,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<NudgeResult> {
+    let start = add_iso_date(date_time.date, duration.date, Overflow::Constrain)?;
+    let end = start.add_days(sign);
+
+    let start_epoch_nanoseconds = time_zone(IsoDateTime::new(start, date_time.time))?;
+    let end_epoch_nanoseconds = time_zone(IsoDateTime::new(end, date_time.time))?;
+    let day_span = end_epoch_nanoseconds - start_epoch_nanoseconds;
+
+    let unit_increment = increment as i128 * unit.nanoseconds();
+    let mut rounded_time =
+        round_time_duration_to_increment(duration.time, unit_increment, rounding_mode)?;
+    let beyond_day_span = rounded_time - day_span;
+
+    let (did_round_beyond_day, day_delta, nudged_epoch_nanoseconds) =
+        if beyond_day_span.signum() as i64 != -sign {
+            rounded_time =
+                round_time_duration_to_increment(beyond_day_span, unit_increment, rounding_mode)?;
+            (true, sign, end_epoch_nanoseconds + rounded_time)
+        } else {
+            (false, 0, start_epoch_nanoseconds + rounded_time)
+        };
+
+    let date_duration =
+        adjust_date_duration(&duration.date, duration.date.days + day_delta, None, None);
+
+    Some(NudgeResult {
+        duration: InternalDuration::new(date_duration, rounded_time),
+        total: 0.0,
+        nudged_epoch_nanoseconds,
+        did_expand_calendar_unit: did_round_beyond_day,
+    })
+}
+
+/// NudgeToDayOrTime (https://tc39.es/proposal-temporal/#sec-temporal-nudgetodayortime)
+fn nudge_to_day_or_time(
+    duration: &InternalDuration,
+    dest_epoch_nanoseconds: i128,
+    largest_unit: Unit,
+    increment: i64,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<NudgeResult> {
+    let time_duration =
+        check_time_duration(duration.time + duration.date.days as i128 * NS_PER_DAY)?;
+    let unit_increment = increment as i128 * smallest_unit.nanoseconds();
+    let rounded_time =
+        round_time_duration_to_increment(time_duration, unit_increment, rounding_mode)?;
+    let diff_time = rounded_time - time_duration;
+
+    let whole_days = time_duration / NS_PER_DAY;
+    let rounded_whole_days = rounded_time / NS_PER_DAY;
+    let day_delta = rounded_whole_days - whole_days;
+    let did_expand_days = day_delta.signum() == time_duration.signum();
+    let nudged_epoch_nanoseconds = dest_epoch_nanoseconds + diff_time;
+
+    let (days, remainder) = if largest_unit.is_date_unit() {
+        (rounded_whole_days as i64, rounded_time % NS_PER_DAY)
+    } else {
+        (0, rounded_time)
+    };
+
+    let date_duration = adjust_date_duration(&duration.date, days, None, None);
+
+    Some(NudgeResult {
+        duration: InternalDuration::new(date_duration, remainder),
+        total: 0.0,
+        nudged_epoch_nanoseconds,
+        did_expand_calendar_unit: did_expand_days,
+    })
+}
+
+/// BubbleRelativeDuration (https://tc39.es/proposal-temporal/#sec-temporal-bubblerelativeduration)
+#[allow(clippy::too_many_arguments)]
+fn bubble_relative_duration(
+    sign: i64,
+    mut duration: InternalDuration,
+    nudged_epoch_nanoseconds: i128,
+    date_time: &IsoDateTime,
+    time_zone: Option<ZonedEpochNanoseconds>,
+    largest_unit: Unit,
+    smallest_unit: Unit,
+) -> Option<InternalDuration> {
+    if smallest_unit == largest_unit {
+        return Some(duration);
+    }
+
+    // Units larger than the smallest unit up to the largest unit, from smallest to largest
+    let units = Unit::ALL
+        .iter()
+        .rev()
+        .filter(|unit| **unit > smallest_unit && **unit <= largest_unit);
+
+    for unit in units {
+        if *unit == Unit::Week && largest_unit != Unit::Week {
+            continue;
+        }
+
+        let date = &duration.date;
+        let end_duration = match unit {
+            Unit::Year => DateDuration::new(date.years + sign, 0, 0, 0),
+            Unit::Month => adjust_date_duration(date, 0, Some(0), Some(date.months + sign)),
+            Unit::Week => adjust_date_duration(date, 0, Some(date.weeks + sign), None),
+            _ => unreachable!("only calendar units are bubbled into"),
+        };
+
+        let end = add_iso_date(date_time.date, end_duration, Overflow::Constrain)?;
+        let end_epoch_nanoseconds =
+            epoch_nanoseconds_for(IsoDateTime::new(end, date_time.time), time_zone)?;
+        let beyond_end = nudged_epoch_nanoseconds - end_epoch_nanoseconds;
+
+        if beyond_end.signum() as i64 != -sign {
+            duration = InternalDuration::new(end_duration, 0);
+        } else {
+            break;
+        }
+    }
+
+    Some(duration)
+}
+
+/// RoundRelativeDuration (https://tc39.es/proposal-temporal/#sec-temporal-roundrelativeduration)
+///
+/// Round a duration that starts at a date-time and ends at the destination epoch nanoseconds.
+/// Returns None if a date is out of range.
+#[allow(clippy::too_many_arguments)]
+pub fn round_relative_duration(
+    duration: &InternalDuration,
+    dest_epoch_nanoseconds: i128,
+    date_time: &IsoDateTime,
+    time_zone: Option<ZonedEpochNanoseconds>,
+    largest_unit: Unit,
+    increment: i64,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<InternalDuration> {
+    let irregular_length_unit =
+        smallest_unit.is_calendar_unit() || (time_zone.is_some() && smallest_unit == Unit::Day);
+    let sign = if duration.sign() < 0 { -1 } else { 1 };
+
+    let nudge_result = if irregular_length_unit {
+        nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_nanoseconds,
+            date_time,
+            time_zone,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+    } else if let Some(time_zone) = time_zone {
+        nudge_to_zoned_time(
+            sign,
+            duration,
+            date_time,
+            time_zone,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+    } else {
+        nudge_to_day_or_time(
+            duration,
+            dest_epoch_nanoseconds,
+            largest_unit,
+            increment,
+            smallest_unit,
+            rounding_mode,
+        )?
+    };
+
+    let mut duration = nudge_result.duration;
+    if nudge_result.did_expand_calendar_unit && smallest_unit != Unit::Week {
+        let start_unit = smallest_unit.max(Unit::Day);
+        duration = bubble_relative_duration(
+            sign,
+            duration,
+            nudge_result.nudged_epoch_nanoseconds,
+            date_time,
+            time_zone,
+            largest_unit,
+            start_unit,
+        )?;
+    }
+
+    Some(duration)
+}
+
+/// TotalRelativeDuration (https://tc39.es/proposal-temporal/#sec-temporal-totalrelativeduration)
+pub fn total_relative_duration(
+    duration: &InternalDuration,
+    dest_epoch_nanoseconds: i128,
+    date_time: &IsoDateTime,
+    time_zone: Option<ZonedEpochNanoseconds>,
+    unit: Unit,
+) -> Option<f64> {
+    if unit.is_calendar_unit() || (time_zone.is_some() && unit == Unit::Day) {
+        let sign = if duration.sign() < 0 { -1 } else { 1 };
+        let nudge_result = nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_nanoseconds,
+            date_time,
+            time_zone,
+            1,
+            unit,
+            RoundingMode::Trunc,
+        )?;
+
+        return Some(nudge_result.total);
+    }
+
+    let time_duration =
+        check_time_duration(duration.time + duration.date.days as i128 * NS_PER_DAY)?;
+    Some(total_time_duration(time_duration, unit))
+}
+
+/// DifferencePlainDateTimeWithRounding (https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithrounding)
+///
+/// Returns None if either date-time is outside the supported range.
+pub fn difference_plain_date_time_with_rounding(
+    one: &IsoDateTime,
+    two: &IsoDateTime,
+    largest_unit: Unit,
+    increment: i64,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<InternalDuration> {
+    if one == two {
+        return Some(InternalDuration::default());
+    }
+
+    if !iso_date_time_within_limits(*one) || !iso_date_time_within_limits(*two) {
+        return None;
+    }
+
+    let (date_difference, time_difference) = difference_iso_date_time(one, two, largest_unit);
+    let difference = InternalDuration::new(date_difference, time_difference);
+
+    if smallest_unit == Unit::Nanosecond && increment == 1 {
+        return Some(difference);
+    }
+
+    let dest_epoch_nanoseconds = two.to_utc_epoch_nanoseconds();
+    round_relative_duration(
+        &difference,
+        dest_epoch_nanoseconds,
+        one,
+        None,
+        largest_unit,
+        increment,
+        smallest_unit,
+        rounding_mode,
+    )
+}
+
+/// DifferencePlainDateTimeWithTotal (https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithtotal)
+pub fn difference_plain_date_time_with_total(
+    one: &IsoDateTime,
+    two: &IsoDateTime,
+    unit: Unit,
+) -> Option<f64> {
+    if one == two {
+        return Some(0.0);
+    }
+
+    if !iso_date_time_within_limits(*one) || !iso_date_time_within_limits(*two) {
+        return None;
+    }
+
+    let (date_difference, time_difference) = difference_iso_date_time(one, two, unit);
+    let difference = InternalDuration::new(date_difference, time_difference);
+
+    if unit == Unit::Nanosecond {
+        return Some(time_difference as f64);
+    }
+
+    let dest_epoch_nanoseconds = two.to_utc_epoch_nanoseconds();
+    total_relative_duration(&difference, dest_epoch_nanoseconds, one, None, unit)
+}
+
+/// Round a time duration that is not relative to a date, for Instant and PlainTime differences.
+///
+/// DifferenceInstant (https://tc39.es/proposal-temporal/#sec-temporal-differenceinstant)
+pub fn round_time_difference(
+    time_duration: i128,
+    increment: i64,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> Option<InternalDuration> {
+    let rounded = round_time_duration(time_duration, increment, smallest_unit, rounding_mode)?;
+    Some(InternalDuration::new(DateDuration::default(), rounded))
+}
@@ -0,0 +1,497 @@
+use core::mem::size_of;
+
+use alloc::{format, string::String};
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        abstract_operations::get,
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        property_key::PropertyKey,
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        prepare_calendar_fields, RequiredFields, DATE_FIELDS,
+    },
+    duration::{Duration, InternalDuration, MAX_TIME_DURATION},
+    iso::{add_iso_date, iso_date_within_limits, DateDuration, IsoDate, Overflow, NS_PER_DAY},
+    options::{to_integer_if_integral, DifferenceOperation, Disambiguation, OffsetOption},
+    parser::{parse_duration_string, parse_iso_date_time, ParseGoal, ParsedOffset},
+    rounding::Unit,
+    time_zone::{
+        interpret_iso_date_time_offset, parsed_time_zone_identifier, resolve_time_zone,
+        MatchBehaviour, OffsetBehaviour,
+    },
+    zoned_date_time_constructor::{add_zoned_date_time, ZONED_NON_CALENDAR_FIELDS},
+};
+
+// Temporal.Duration Objects (https://tc39.es/proposal-temporal/#sec-temporal-duration-objects)
+extend_object! {
+    pub struct DurationObject {
+        duration: Duration,
+    }
+}
+
+impl DurationObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        duration: Duration,
+    ) -> AllocResult<StackRoot<DurationObject>> {
+        let mut object =
+            object_create_with_proto::<DurationObject>(cx, HeapItemKind::DurationObject, proto)?;
+
+        set_uninit!(object.duration, duration);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(DurationObject, "Temporal.Duration");
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// CreateTemporalDuration (https://tc39.es/proposal-temporal/#sec-temporal-createtemporalduration)
+pub fn create_temporal_duration(
+    cx: Context,
+    duration: Duration,
+) -> EvalResult<StackRoot<DurationObject>> {
+    if !duration.is_valid() {
+        return range_error(cx, "duration is out of range");
+    }
+
+    let proto = cx.get_intrinsic(Intrinsic::DurationPrototype);
+    Ok(DurationObject::new_with_proto(cx, proto, duration)?)
+}
+
+/// TemporalDurationFromInternal (https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal)
+/// followed by CreateTemporalDuration.
+pub fn create_temporal_duration_from_internal(
+    cx: Context,
+    internal_duration: &InternalDuration,
+    largest_unit: Unit,
+) -> EvalResult<StackRoot<DurationObject>> {
+    match internal_duration.to_duration(largest_unit) {
+        Some(duration) => create_temporal_duration(cx, duration),
+        None => range_error(cx, "duration is out of range"),
+    }
+}
+
+/// Create the result of an `until` or `since` method from the difference between two values,
+/// which is negated for `since`.
+pub fn create_difference_duration(
+    cx: Context,
+    internal_duration: &InternalDuration,
+    largest_unit: Unit,
+    operation: DifferenceOperation,
+) -> EvalResult<StackRoot<Value>> {
+    let Some(mut duration) = internal_duration.to_duration(largest_unit) else {
+        return range_error(cx, "duration is out of range");
+    };
+
+    if operation == DifferenceOperation::Since {
+        duration = duration.negated();
+    }
+
+    Ok(create_temporal_duration(cx, duration)?.as_value())
+}
+
+/// Property names of a duration in the order they are read, with the index of each field.
+fn duration_property_keys(cx: Context) -> [(StackRoot<PropertyKey>, usize); 10] {
+    [
+        (cx.names.days(), 3),
+        (cx.names.hours(), 4),
+        (cx.names.microseconds(), 8),
+        (cx.names.milliseconds(), 7),
+        (cx.names.minutes(), 5),
+        (cx.names.months(), 1),
+        (cx.names.nanoseconds(), 9),
+        (cx.names.seconds(), 6),
+        (cx.names.weeks(), 2),
+        (cx.names.years(), 0),
+    ]
+}
+
+/// ToTemporalPartialDurationRecord (https://tc39.es/proposal-temporal/#sec-temporal-totemporalpartialdurationrecord)
+///
+/// Returns the fields from largest to smallest unit, where absent fields are None.
+pub fn to_temporal_partial_duration_record(
+    cx: Context,
+    value: StackRoot<Value>,
+) -> EvalResult<[Option<f64>; 10]> {
+    if !value.is_object() {
+        return type_error(cx, "duration must be an object");
+    }
+
+    let object = value.as_object();
+    let mut fields = [None; 10];
+
+    for (key, index) in duration_property_keys(cx) {
+        let value = get(cx, object, key)?;
+        if !value.is_undefined() {
+            fields[index] = Some(to_integer_if_integral(cx, value)?);
+        }
+    }
+
+    if fields.iter().all(Option::is_none) {
+        return type_error(cx, "duration must have at least one duration property");
+    }
+
+    Ok(fields)
+}
+
+/// ToTemporalDuration (https://tc39.es/proposal-temporal/#sec-temporal-totemporalduration)
+pub fn to_temporal_duration(cx: Context, value: StackRoot<Value>) -> EvalResult<Duration> {
+    if value.is_object() {
+        if let Some(duration) = value.as_object().as_duration_object() {
+            return Ok(duration.duration());
+        }
+    } else {
+        if !value.is_string() {
+            return type_error(cx, "duration must be a string or object");
+        }
+
+        let string = value.as_string().format(cx)?;
+        return match parse_duration_string(&string) {
+            Some(duration) if duration.is_valid() => Ok(duration),
+            Some(_) => range_error(cx, "duration is out of range"),
+            None => range_error(cx, &format!("invalid duration {string}")),
+        };
+    }
+
+    let partial = to_temporal_partial_duration_record(cx, value)?;
+    let duration = Duration::from_fields(partial.map(|field| field.unwrap_or(0.0)));
+
+    if !duration.is_valid() {
+        return range_error(cx, "duration is out of range");
+    }
+
+    Ok(duration)
+}
+
+/// The `relativeTo` option of Duration methods, which calendar units are relative to.
+pub enum RelativeTo {
+    None,
+    Plain(IsoDate),
+    Zoned {
+        epoch_nanoseconds: i128,
+        time_zone: String,
+    },
+}
+
+/// GetTemporalRelativeToOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalrelativetooption)
+pub fn get_relative_to_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<RelativeTo> {
+    let value = get(cx, options, cx.names.relative_to())?;
+    if value.is_undefined() {
+        return Ok(RelativeTo::None);
+    }
+
+    let mut offset_behaviour = OffsetBehaviour::Option;
+    let mut match_behaviour = MatchBehaviour::Exactly;
+
+    let (date, time, time_zone, offset_nanoseconds) = if value.is_object() {
+        let object = value.as_object();
+
+        if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+            return Ok(RelativeTo::Zoned {
+                epoch_nanoseconds: zoned_date_time.epoch_nanoseconds(),
+                time_zone: zoned_date_time.time_zone_id(),
+            });
+        }
+
+        if let Some(plain_date) = object.as_plain_date_object() {
+            return Ok(RelativeTo::Plain(plain_date.date()));
+        }
+
+        if let Some(plain_date_time) = object.as_plain_date_time_object() {
+            return Ok(RelativeTo::Plain(plain_date_time.date_time().date));
+        }
+
+        get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+
+        let fields = prepare_calendar_fields(
+            cx,
+            object,
+            DATE_FIELDS,
+            ZONED_NON_CALENDAR_FIELDS,
+            RequiredFields::List(&[]),
+        )?;
+
+        let date = fields.to_date(cx, Overflow::Constrain)?;
+        let time = fields.time(Overflow::Constrain).unwrap();
+
+        if fields.offset.is_none() {
+            offset_behaviour = OffsetBehaviour::Wall;
+        }
+
+        (
+            date,
+            Some(time),
+            fields.time_zone,
+            fields.offset.unwrap_or(0),
+        )
+    } else {
+        if !value.is_string() {
+            return type_error(cx, "relativeTo must be a string or object");
+        }
+
+        let string = value.as_string().format(cx)?;
+        let Some(parsed) = parse_iso_date_time(&string, ParseGoal::RelativeTo) else {
+            return range_error(cx, &format!("invalid relativeTo {string}"));
+        };
+
+        let time_zone = match &parsed.time_zone {
+            Some(time_zone) => Some(parsed_time_zone_identifier(cx, time_zone)?),
+            None => None,
+        };
+
+        let offset_nanoseconds = match parsed.offset {
+            Some(ParsedOffset::Utc) => {
+                offset_behaviour = OffsetBehaviour::Exact;
+                0
+            }
+            Some(ParsedOffset::Offset { nanoseconds, .. }) => nanoseconds as i128,
+            None => {
+                offset_behaviour = OffsetBehaviour::Wall;
+                0
+            }
+        };
+
+        match_behaviour = MatchBehaviour::Minutes;
+
+        if let Some(calendar) = &parsed.calendar {
+            canonicalize_calendar(cx, calendar)?;
+        }
+
+        let date = IsoDate::new(parsed.year.unwrap(), parsed.month, parsed.day);
+        (date, parsed.time, time_zone, offset_nanoseconds)
+    };
+
+    let Some(time_zone) = time_zone else {
+        if !iso_date_within_limits(date) {
+            return range_error(cx, "relativeTo is outside the supported range");
+        }
+
+        return Ok(RelativeTo::Plain(date));
+    };
+
+    let epoch_nanoseconds = interpret_iso_date_time_offset(
+        cx,
+        date,
+        time,
+        offset_behaviour,
+        offset_nanoseconds,
+        &resolve_time_zone(cx, &time_zone),
+        Disambiguation::Compatible,
+        OffsetOption::Reject,
+        match_behaviour,
+    )?;
+
+    Ok(RelativeTo::Zoned {
+        epoch_nanoseconds,
+        time_zone,
+    })
+}
+
+/// Add24HourDaysToTimeDuration (https://tc39.es/proposal-temporal/#sec-temporal-add24hourdaystonormalizedtimeduration)
+fn add_24_hour_days_to_time_duration(
+    cx: Context,
+    time_duration: i128,
+    days: i64,
+) -> EvalResult<i128> {
+    let result = time_duration + days as i128 * NS_PER_DAY;
+    if result.abs() > MAX_TIME_DURATION {
+        return range_error(cx, "duration is out of range");
+    }
+
+    Ok(result)
+}
+
+/// DateDurationDays (https://tc39.es/proposal-temporal/#sec-temporal-datedurationdays)
+fn date_duration_days(
+    cx: Context,
+    date_duration: &DateDuration,
+    relative_to: IsoDate,
+) -> EvalResult<i64> {
+    let years_months_weeks = DateDuration::new(
+        date_duration.years,
+        date_duration.months,
+        date_duration.weeks,
+        0,
+    );
+    if years_months_weeks.sign() == 0 {
+        return Ok(date_duration.days);
+    }
+
+    let Some(later) = add_iso_date(relative_to, years_months_weeks, Overflow::Constrain) else {
+        return range_error(cx, "date is outside the supported range");
+    };
+
+    let years_months_weeks_in_days = later.to_epoch_days() - relative_to.to_epoch_days();
+    Ok(date_duration.days + years_months_weeks_in_days)
+}
+
+pub struct DurationConstructor;
+
+impl DurationConstructor {
+    /// Properties of the Temporal.Duration Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-duration-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.duration(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::DurationPrototype).into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.Duration (https://tc39.es/proposal-temporal/#sec-temporal.duration)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Temporal.Duration constructor must be called with new");
+        };
+
+        let mut fields = [0.0; 10];
+        for (i, field) in fields.iter_mut().enumerate() {
+            let argument = get_argument(cx, arguments, i);
+            if !argument.is_undefined() {
+                *field = to_integer_if_integral(cx, argument)?;
+            }
+        }
+
+        let duration = Duration::from_fields(fields);
+        if !duration.is_valid() {
+            return range_error(cx, "duration is out of range");
+        }
+
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::DurationPrototype)?;
+
+        Ok(DurationObject::new_with_proto(cx, proto, duration)?.as_value())
+    }
+
+    /// Temporal.Duration.compare (https://tc39.es/proposal-temporal/#sec-temporal.duration.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_duration(cx, get_argument(cx, arguments, 0))?;
+        let two = to_temporal_duration(cx, get_argument(cx, arguments, 1))?;
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 2))?;
+        let relative_to = get_relative_to_option(cx, options)?;
+
+        if one == two {
+            return Ok(cx.smi(0));
+        }
+
+        let largest_unit_one = one.default_largest_unit();
+        let largest_unit_two = two.default_largest_unit();
+        let duration_one = InternalDuration::from_duration(&one);
+        let duration_two = InternalDuration::from_duration(&two);
+
+        if let RelativeTo::Zoned {
+            epoch_nanoseconds,
+            time_zone,
+        } = &relative_to
+        {
+            if largest_unit_one.is_date_unit() || largest_unit_two.is_date_unit() {
+                let time_zone = resolve_time_zone(cx, time_zone);
+                let after_one = add_zoned_date_time(
+                    cx,
+                    *epoch_nanoseconds,
+                    &time_zone,
+                    &duration_one,
+                    Overflow::Constrain,
+                )?;
+                let after_two = add_zoned_date_time(
+                    cx,
+                    *epoch_nanoseconds,
+                    &time_zone,
+                    &duration_two,
+                    Overflow::Constrain,
+                )?;
+
+                return Ok(cx.smi(after_one.cmp(&after_two) as i32));
+            }
+        }
+
+        let (days_one, days_two) =
+            if largest_unit_one.is_calendar_unit() || largest_unit_two.is_calendar_unit() {
+                let RelativeTo::Plain(relative_to) = relative_to else {
+                    return range_error(cx, "relativeTo is required to compare calendar units");
+                };
+
+                (
+                    date_duration_days(cx, &duration_one.date, relative_to)?,
+                    date_duration_days(cx, &duration_two.date, relative_to)?,
+                )
+            } else {
+                (one.days as i64, two.days as i64)
+            };
+
+        let time_duration_one = add_24_hour_days_to_time_duration(cx, duration_one.time, days_one)?;
+        let time_duration_two = add_24_hour_days_to_time_duration(cx, duration_two.time, days_two)?;
+
+        Ok(cx.smi(time_duration_one.cmp(&time_duration_two) as i32))
+    }
+
+    /// Temporal.Duration.from (https://tc39.es/proposal-temporal/#sec-temporal.duration.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = to_temporal_duration(cx, get_argument(cx, arguments, 0))?;
+        Ok(create_temporal_duration(cx, duration)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<DurationObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<DurationObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
@@ -0,0 +1,613 @@
+use alloc::format;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::options::get_options_object,
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    duration::{
+        check_time_duration, difference_plain_date_time_with_rounding,
+        difference_plain_date_time_with_total, round_time_duration, seconds_string_precision,
+        total_time_duration, Duration, InternalDuration, Precision,
+    },
+    duration_constructor::{
+        create_temporal_duration, create_temporal_duration_from_internal, get_relative_to_option,
+        to_temporal_duration, to_temporal_partial_duration_record, DurationObject, RelativeTo,
+    },
+    iso::{add_iso_date, DateDuration, IsoDate, IsoDateTime, Overflow, MIDNIGHT},
+    options::{
+        get_fractional_second_digits_option, get_rounding_increment_option,
+        get_rounding_mode_option, get_shorthand_options_object, get_unit_valued_option,
+        validate_rounding_increment, validate_unit_value, UnitValue,
+    },
+    rounding::{RoundingMode, Unit, UnitGroup},
+    time_zone::resolve_time_zone,
+    zoned_date_time_constructor::{
+        add_zoned_date_time, difference_zoned_date_time_with_rounding,
+        difference_zoned_date_time_with_total,
+    },
+};
+
+pub struct DurationPrototype;
+
+impl DurationPrototype {
+    /// Properties of the Temporal.Duration Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-duration-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once DurationConstructor has been created
+        object.intrinsic_getter(cx, cx.names.blank(), Self::blank, realm)?;
+        object.intrinsic_getter(cx, cx.names.days(), Self::days, realm)?;
+        object.intrinsic_getter(cx, cx.names.hours(), Self::hours, realm)?;
+        object.intrinsic_getter(cx, cx.names.microseconds(), Self::microseconds, realm)?;
+        object.intrinsic_getter(cx, cx.names.milliseconds(), Self::milliseconds, realm)?;
+        object.intrinsic_getter(cx, cx.names.minutes(), Self::minutes, realm)?;
+        object.intrinsic_getter(cx, cx.names.months(), Self::months, realm)?;
+        object.intrinsic_getter(cx, cx.names.nanoseconds(), Self::nanoseconds, realm)?;
+        object.intrinsic_getter(cx, cx.names.seconds(), Self::seconds, realm)?;
+        object.intrinsic_getter(cx, cx.names.sign(), Self::sign, realm)?;
+        object.intrinsic_getter(cx, cx.names.weeks(), Self::weeks, realm)?;
+        object.intrinsic_getter(cx, cx.names.years(), Self::years, realm)?;
+        object.intrinsic_func(cx, cx.names.abs(), Self::abs, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.negated(), Self::negated, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.round(), Self::round, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.total(), Self::total, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.Duration")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.Duration.prototype.years (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.years)
+    pub fn years(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.years))
+    }
+
+    /// get Temporal.Duration.prototype.months (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.months)
+    pub fn months(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.months))
+    }
+
+    /// get Temporal.Duration.prototype.weeks (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.weeks)
+    pub fn weeks(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.weeks))
+    }
+
+    /// get Temporal.Duration.prototype.days (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.days)
+    pub fn days(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.days))
+    }
+
+    /// get Temporal.Duration.prototype.hours (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.hours)
+    pub fn hours(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.hours))
+    }
+
+    /// get Temporal.Duration.prototype.minutes (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.minutes)
+    pub fn minutes(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.minutes))
+    }
+
+    /// get Temporal.Duration.prototype.seconds (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.seconds)
+    pub fn seconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.seconds))
+    }
+
+    /// get Temporal.Duration.prototype.milliseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.milliseconds)
+    pub fn milliseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.milliseconds))
+    }
+
+    /// get Temporal.Duration.prototype.microseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.microseconds)
+    pub fn microseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.microseconds))
+    }
+
+    /// get Temporal.Duration.prototype.nanoseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.nanoseconds)
+    pub fn nanoseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.number(duration.nanoseconds))
+    }
+
+    /// get Temporal.Duration.prototype.sign (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.sign)
+    pub fn sign(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.smi(duration.sign() as i32))
+    }
+
+    /// get Temporal.Duration.prototype.blank (https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.blank)
+    pub fn blank(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx.bool(duration.sign() == 0))
+    }
+
+    /// Temporal.Duration.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        let partial = to_temporal_partial_duration_record(cx, get_argument(cx, arguments, 0))?;
+
+        let mut fields = duration.fields();
+        for (field, partial_field) in fields.iter_mut().zip(partial) {
+            if let Some(value) = partial_field {
+                *field = value;
+            }
+        }
+
+        Ok(create_temporal_duration(cx, Duration::from_fields(fields))?.as_value())
+    }
+
+    /// Temporal.Duration.prototype.negated (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.negated)
+    pub fn negated(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(create_temporal_duration(cx, duration.negated())?.as_value())
+    }
+
+    /// Temporal.Duration.prototype.abs (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.abs)
+    pub fn abs(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(create_temporal_duration(cx, duration.abs())?.as_value())
+    }
+
+    /// Temporal.Duration.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        add_durations(cx, duration, get_argument(cx, arguments, 0), false)
+    }
+
+    /// Temporal.Duration.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        add_durations(cx, duration, get_argument(cx, arguments, 0), true)
+    }
+
+    /// Temporal.Duration.prototype.round (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.round)
+    pub fn round(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+
+        let round_to = get_argument(cx, arguments, 0);
+        let round_to = get_shorthand_options_object(cx, round_to, cx.names.smallest_unit())?;
+
+        // Options are read in alphabetical order
+        let largest_unit = get_unit_valued_option(cx, round_to, cx.names.largest_unit())?;
+        let relative_to = get_relative_to_option(cx, round_to)?;
+        let rounding_increment = get_rounding_increment_option(cx, round_to)?;
+        let rounding_mode = get_rounding_mode_option(cx, round_to, RoundingMode::HalfExpand)?;
+        let smallest_unit = get_unit_valued_option(cx, round_to, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, largest_unit, UnitGroup::DateTime, &[UnitValue::Auto])?;
+        validate_unit_value(cx, smallest_unit, UnitGroup::DateTime, &[])?;
+
+        let smallest_unit_present = smallest_unit.is_some();
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(unit)) => unit,
+            _ => Unit::Nanosecond,
+        };
+
+        let existing_largest_unit = duration.default_largest_unit();
+        let default_largest_unit = existing_largest_unit.max(smallest_unit);
+
+        let largest_unit_present = largest_unit.is_some();
+        let largest_unit = match largest_unit {
+            Some(UnitValue::Unit(unit)) => unit,
+            _ => default_largest_unit,
+        };
+
+        if !smallest_unit_present && !largest_unit_present {
+            return range_error(cx, "smallestUnit or largestUnit is required");
+        }
+
+        if largest_unit < smallest_unit {
+            return range_error(cx, "largestUnit cannot be smaller than smallestUnit");
+        }
+
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_rounding_increment(cx, rounding_increment, maximum, false)?;
+        }
+
+        if rounding_increment > 1 && largest_unit != smallest_unit && smallest_unit.is_date_unit() {
+            return range_error(
+                cx,
+                "roundingIncrement must be 1 when rounding to a date unit smaller than largestUnit",
+            );
+        }
+
+        match relative_to {
+            RelativeTo::Zoned {
+                epoch_nanoseconds,
+                time_zone,
+            } => {
+                let time_zone = resolve_time_zone(cx, &time_zone);
+                let internal_duration = InternalDuration::from_duration(&duration);
+                let target_nanoseconds = add_zoned_date_time(
+                    cx,
+                    epoch_nanoseconds,
+                    &time_zone,
+                    &internal_duration,
+                    Overflow::Constrain,
+                )?;
+
+                let internal_duration = difference_zoned_date_time_with_rounding(
+                    cx,
+                    epoch_nanoseconds,
+                    target_nanoseconds,
+                    &time_zone,
+                    largest_unit,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                )?;
+
+                // Days are not balanced into larger units without a calendar
+                let largest_unit = if largest_unit.is_date_unit() {
+                    Unit::Hour
+                } else {
+                    largest_unit
+                };
+
+                Ok(
+                    create_temporal_duration_from_internal(cx, &internal_duration, largest_unit)?
+                        .as_value(),
+                )
+            }
+            RelativeTo::Plain(relative_to) => {
+                let (start, end) = plain_relative_date_times(cx, &duration, relative_to)?;
+
+                let Some(internal_duration) = difference_plain_date_time_with_rounding(
+                    &start,
+                    &end,
+                    largest_unit,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                ) else {
+                    return range_error(cx, "date-time is outside the supported range");
+                };
+
+                Ok(
+                    create_temporal_duration_from_internal(cx, &internal_duration, largest_unit)?
+                        .as_value(),
+                )
+            }
+            RelativeTo::None => {
+                if existing_largest_unit.is_calendar_unit() || largest_unit.is_calendar_unit() {
+                    return range_error(cx, "relativeTo is required to round calendar units");
+                }
+
+                // Days are treated as exactly 24 hours
+                let internal_duration =
+                    InternalDuration::from_duration_with_24_hour_days(&duration);
+                let Some(time_duration) = round_time_duration(
+                    internal_duration.time,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                ) else {
+                    return range_error(cx, "duration is out of range");
+                };
+
+                let internal_duration =
+                    InternalDuration::new(DateDuration::default(), time_duration);
+
+                Ok(
+                    create_temporal_duration_from_internal(cx, &internal_duration, largest_unit)?
+                        .as_value(),
+                )
+            }
+        }
+    }
+
+    /// Temporal.Duration.prototype.total (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.total)
+    pub fn total(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+
+        let total_of = get_argument(cx, arguments, 0);
+        let total_of = get_shorthand_options_object(cx, total_of, cx.names.unit())?;
+
+        // Options are read in alphabetical order
+        let relative_to = get_relative_to_option(cx, total_of)?;
+        let unit = get_unit_valued_option(cx, total_of, cx.names.unit())?;
+
+        validate_unit_value(cx, unit, UnitGroup::DateTime, &[])?;
+        let Some(UnitValue::Unit(unit)) = unit else {
+            return range_error(cx, "unit is required");
+        };
+
+        let total = match relative_to {
+            RelativeTo::Zoned {
+                epoch_nanoseconds,
+                time_zone,
+            } => {
+                let time_zone = resolve_time_zone(cx, &time_zone);
+                let internal_duration = InternalDuration::from_duration(&duration);
+                let target_nanoseconds = add_zoned_date_time(
+                    cx,
+                    epoch_nanoseconds,
+                    &time_zone,
+                    &internal_duration,
+                    Overflow::Constrain,
+                )?;
+
+                difference_zoned_date_time_with_total(
+                    cx,
+                    epoch_nanoseconds,
+                    target_nanoseconds,
+                    &time_zone,
+                    unit,
+                )?
+            }
+            RelativeTo::Plain(relative_to) => {
+                let (start, end) = plain_relative_date_times(cx, &duration, relative_to)?;
+
+                let Some(total) = difference_plain_date_time_with_total(&start, &end, unit) else {
+                    return range_error(cx, "date-time is outside the supported range");
+                };
+
+                total
+            }
+            RelativeTo::None => {
+                if duration.default_largest_unit().is_calendar_unit() || unit.is_calendar_unit() {
+                    return range_error(cx, "relativeTo is required to total calendar units");
+                }
+
+                // Days are treated as exactly 24 hours
+                let internal_duration =
+                    InternalDuration::from_duration_with_24_hour_days(&duration);
+                total_time_duration(internal_duration.time, unit)
+            }
+        };
+
+        Ok(cx.number(total))
+    }
+
+    /// Temporal.Duration.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+
+        // Options are read in alphabetical order
+        let digits = get_fractional_second_digits_option(cx, options)?;
+        let rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+        let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(unit @ (Unit::Hour | Unit::Minute))) => {
+                return range_error(cx, &format!("smallestUnit cannot be {}", unit.as_str()));
+            }
+            Some(UnitValue::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        let (precision, unit, increment) = seconds_string_precision(smallest_unit, digits);
+        if unit == Unit::Nanosecond && increment == 1 {
+            return Ok(cx.alloc_string(&duration.to_string(precision))?.as_value());
+        }
+
+        let internal_duration = InternalDuration::from_duration(&duration);
+        let Some(time_duration) =
+            round_time_duration(internal_duration.time, increment, unit, rounding_mode)
+        else {
+            return range_error(cx, "duration is out of range");
+        };
+
+        let internal_duration = InternalDuration::new(internal_duration.date, time_duration);
+        let largest_unit = duration.default_largest_unit().max(Unit::Second);
+        let Some(rounded) = internal_duration.to_duration(largest_unit) else {
+            return range_error(cx, "duration is out of range");
+        };
+
+        Ok(cx.alloc_string(&rounded.to_string(precision))?.as_value())
+    }
+
+    /// Temporal.Duration.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx
+            .alloc_string(&duration.to_string(Precision::Auto))?
+            .as_value())
+    }
+
+    /// Temporal.Duration.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tolocalestring)
+    ///
+    /// Intl.DurationFormat is not supported, so durations are formatted like `toJSON`.
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let duration = DurationObject::cast_from_value(cx, this_value)?.duration();
+        Ok(cx
+            .alloc_string(&duration.to_string(Precision::Auto))?
+            .as_value())
+    }
+
+    /// Temporal.Duration.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(cx, "Temporal.Duration cannot be converted to a primitive")
+    }
+}
+
+/// AddDurations (https://tc39.es/proposal-temporal/#sec-temporal-adddurations)
+fn add_durations(
+    cx: Context,
+    duration: Duration,
+    other: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut other = to_temporal_duration(cx, other)?;
+    if is_subtract {
+        other = other.negated();
+    }
+
+    let largest_unit = duration
+        .default_largest_unit()
+        .max(other.default_largest_unit());
+    if largest_unit.is_calendar_unit() {
+        return range_error(cx, "cannot add durations with calendar units");
+    }
+
+    let one = InternalDuration::from_duration_with_24_hour_days(&duration);
+    let two = InternalDuration::from_duration_with_24_hour_days(&other);
+
+    let Some(time_duration) = check_time_duration(one.time + two.time) else {
+        return range_error(cx, "duration is out of range");
+    };
+
+    let internal_duration = InternalDuration::new(DateDuration::default(), time_duration);
+
+    Ok(create_temporal_duration_from_internal(cx, &internal_duration, largest_unit)?.as_value())
+}
+
+/// The start and end date-times of a duration that is relative to a date.
+///
+/// Time units of the duration are balanced into days which are added along with the date units.
+fn plain_relative_date_times(
+    cx: Context,
+    duration: &Duration,
+    relative_to: IsoDate,
+) -> EvalResult<(IsoDateTime, IsoDateTime)> {
+    let internal_duration = InternalDuration::from_duration_with_24_hour_days(duration);
+    let (days, target_time) = MIDNIGHT.add(internal_duration.time);
+
+    let mut date_duration = internal_duration.date;
+    date_duration.days = days;
+
+    let Some(target_date) = add_iso_date(relative_to, date_duration, Overflow::Constrain) else {
+        return range_error(cx, "date is outside the supported range");
+    };
+
+    Ok((
+        IsoDateTime::new(relative_to, MIDNIGHT),
+        IsoDateTime::new(target_date, target_time),
+    ))
+}
@@ -0,0 +1,194 @@
+//! Formatting of Temporal values as ISO 8601 and RFC 9557 strings.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::runtime::{
+    eval_result::EvalResult,
+    intl::date_time_format_constructor::{
+        resolve_date_time_format_config, DefaultComponents, RequiredComponents,
+    },
+    Context, StackRoot, Value,
+};
+
+use super::{
+    duration::{format_fractional_seconds, Precision},
+    iso::{
+        IsoDate, IsoDateTime, IsoTime, NS_PER_HOUR, NS_PER_MILLISECOND, NS_PER_MINUTE,
+        NS_PER_SECOND,
+    },
+    options::ShowCalendar,
+    rounding::{round_number_to_increment, RoundingMode},
+};
+
+/// PadISOYear (https://tc39.es/proposal-temporal/#sec-temporal-padisoyear)
+pub fn pad_iso_year(year: i64) -> String {
+    if (0..=9999).contains(&year) {
+        return format!("{year:04}");
+    }
+
+    let sign = if year < 0 { '-' } else { '+' };
+    format!("{sign}{:06}", year.abs())
+}
+
+/// FormatTimeString (https://tc39.es/proposal-temporal/#sec-temporal-formattimestring)
+pub fn format_time_string(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    sub_second_nanoseconds: i128,
+    precision: Precision,
+) -> String {
+    if precision == Precision::Minute {
+        return format!("{hour:02}:{minute:02}");
+    }
+
+    let fraction = format_fractional_seconds(sub_second_nanoseconds, precision);
+    format!("{hour:02}:{minute:02}:{second:02}{fraction}")
+}
+
+/// TimeRecordToString (https://tc39.es/proposal-temporal/#sec-temporal-timerecordtostring)
+pub fn time_to_string(time: &IsoTime, precision: Precision) -> String {
+    let sub_second_nanoseconds = time.millisecond as i128 * 1_000_000
+        + time.microsecond as i128 * 1_000
+        + time.nanosecond as i128;
+
+    format_time_string(
+        time.hour,
+        time.minute,
+        time.second,
+        sub_second_nanoseconds,
+        precision,
+    )
+}
+
+/// FormatCalendarAnnotation (https://tc39.es/proposal-temporal/#sec-temporal-formatcalendarannotation)
+///
+/// Only the ISO 8601 calendar is supported.
+pub fn format_calendar_annotation(show_calendar: ShowCalendar) -> &'static str {
+    match show_calendar {
+        ShowCalendar::Never | ShowCalendar::Auto => "",
+        ShowCalendar::Always => "[u-ca=iso8601]",
+        ShowCalendar::Critical => "[!u-ca=iso8601]",
+    }
+}
+
+/// TemporalDateToString (https://tc39.es/proposal-temporal/#sec-temporal-temporaldatetostring)
+pub fn date_to_string(date: &IsoDate, show_calendar: ShowCalendar) -> String {
+    format!(
+        "{}-{:02}-{:02}{}",
+        pad_iso_year(date.year),
+        date.month,
+        date.day,
+        format_calendar_annotation(show_calendar)
+    )
+}
+
+/// ISODateTimeToString (https://tc39.es/proposal-temporal/#sec-temporal-isodatetimetostring)
+pub fn date_time_to_string(
+    date_time: &IsoDateTime,
+    precision: Precision,
+    show_calendar: ShowCalendar,
+) -> String {
+    format!(
+        "{}-{:02}-{:02}T{}{}",
+        pad_iso_year(date_time.date.year),
+        date_time.date.month,
+        date_time.date.day,
+        time_to_string(&date_time.time, precision),
+        format_calendar_annotation(show_calendar)
+    )
+}
+
+/// TemporalYearMonthToString (https://tc39.es/proposal-temporal/#sec-temporal-temporalyearmonthtostring)
+///
+/// The reference day is only shown when the calendar is shown.
+pub fn year_month_to_string(date: &IsoDate, show_calendar: ShowCalendar) -> String {
+    let mut result = format!("{}-{:02}", pad_iso_year(date.year), date.month);
+
+    if matches!(show_calendar, ShowCalendar::Always | ShowCalendar::Critical) {
+        result.push_str(&format!("-{:02}", date.day));
+    }
+
+    result.push_str(format_calendar_annotation(show_calendar));
+    result
+}
+
+/// TemporalMonthDayToString (https://tc39.es/proposal-temporal/#sec-temporal-temporalmonthdaytostring)
+///
+/// The reference year is only shown when the calendar is shown.
+pub fn month_day_to_string(date: &IsoDate, show_calendar: ShowCalendar) -> String {
+    let mut result = String::new();
+
+    if matches!(show_calendar, ShowCalendar::Always | ShowCalendar::Critical) {
+        result.push_str(&format!("{}-", pad_iso_year(date.year)));
+    }
+
+    result.push_str(&format!("{:02}-{:02}", date.month, date.day));
+    result.push_str(format_calendar_annotation(show_calendar));
+    result
+}
+
+/// FormatOffsetTimeZoneIdentifier (https://tc39.es/proposal-temporal/#sec-temporal-formatoffsettimezoneidentifier)
+pub fn format_offset_time_zone_identifier(offset_minutes: i64) -> String {
+    let sign = if offset_minutes >= 0 { '+' } else { '-' };
+    let absolute_minutes = offset_minutes.abs();
+
+    format!(
+        "{sign}{:02}:{:02}",
+        absolute_minutes / 60,
+        absolute_minutes % 60
+    )
+}
+
+/// FormatUTCOffsetNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-formatutcoffsetnanoseconds)
+pub fn format_utc_offset_nanoseconds(offset_nanoseconds: i128) -> String {
+    let sign = if offset_nanoseconds >= 0 { '+' } else { '-' };
+    let absolute_nanoseconds = offset_nanoseconds.abs();
+
+    let hour = (absolute_nanoseconds / NS_PER_HOUR) as u8;
+    let minute = (absolute_nanoseconds / NS_PER_MINUTE % 60) as u8;
+    let second = (absolute_nanoseconds / NS_PER_SECOND % 60) as u8;
+    let sub_second_nanoseconds = absolute_nanoseconds % NS_PER_SECOND;
+
+    let precision = if second == 0 && sub_second_nanoseconds == 0 {
+        Precision::Minute
+    } else {
+        Precision::Auto
+    };
+
+    let time = format_time_string(hour, minute, second, sub_second_nanoseconds, precision);
+    format!("{sign}{time}")
+}
+
+/// FormatDateTimeUTCOffsetRounded (https://tc39.es/proposal-temporal/#sec-temporal-formatdatetimeutcoffsetrounded)
+pub fn format_date_time_utc_offset_rounded(offset_nanoseconds: i128) -> String {
+    let rounded =
+        round_number_to_increment(offset_nanoseconds, NS_PER_MINUTE, RoundingMode::HalfExpand);
+    format_offset_time_zone_identifier((rounded / NS_PER_MINUTE) as i64)
+}
+
+/// Format an instant with a date time format created from the `locales` and `options` arguments
+/// of a `toLocaleString` method.
+///
+/// The time zone overrides the time zone option if provided. Plain Temporal values are formatted
+/// as the instant with the same date and time in UTC.
+pub fn format_with_locales(
+    cx: Context,
+    epoch_nanoseconds: i128,
+    time_zone: Option<&str>,
+    locales: StackRoot<Value>,
+    options: StackRoot<Value>,
+    required: RequiredComponents,
+    defaults: DefaultComponents,
+) -> EvalResult<String> {
+    let mut config = resolve_date_time_format_config(cx, locales, options, required, defaults)?;
+    if let Some(time_zone) = time_zone {
+        config.time_zone = time_zone.to_string();
+    }
+
+    let epoch_milliseconds = epoch_nanoseconds.div_euclid(NS_PER_MILLISECOND) as f64;
+    Ok(config.icu_formatter(cx).format(cx, epoch_milliseconds))
+}
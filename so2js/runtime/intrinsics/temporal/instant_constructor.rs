@@ -0,0 +1,269 @@
+use core::mem::size_of;
+
+use alloc::format;
+use num_bigint::BigInt;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        type_utilities::{
+            is_integral_number, to_bigint, to_number, to_primitive, ToPrimitivePreferredType,
+        },
+        value::BigIntValue,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    iso::{is_valid_epoch_nanoseconds, IsoDate, IsoDateTime, MIDNIGHT, NS_PER_MILLISECOND},
+    parser::{parse_iso_date_time, ParseGoal, ParsedOffset},
+    rounding::{round_number_to_increment_as_if_positive, RoundingMode, Unit},
+    time_zone::check_iso_days_range,
+};
+
+// Temporal.Instant Objects (https://tc39.es/proposal-temporal/#sec-temporal-instant-objects)
+extend_object! {
+    pub struct InstantObject {
+        epoch_nanoseconds: HeapPtr<BigIntValue>,
+    }
+}
+
+impl InstantObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        epoch_nanoseconds: i128,
+    ) -> AllocResult<StackRoot<InstantObject>> {
+        let epoch_nanoseconds = BigIntValue::new(cx, BigInt::from(epoch_nanoseconds))?;
+
+        let mut object =
+            object_create_with_proto::<InstantObject>(cx, HeapItemKind::InstantObject, proto)?;
+
+        set_uninit!(object.epoch_nanoseconds, *epoch_nanoseconds);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(InstantObject, "Temporal.Instant");
+
+    pub fn epoch_nanoseconds(&self) -> i128 {
+        i128::try_from(&self.epoch_nanoseconds.bigint()).unwrap()
+    }
+}
+
+/// CreateTemporalInstant (https://tc39.es/proposal-temporal/#sec-temporal-createtemporalinstant)
+pub fn create_temporal_instant(
+    cx: Context,
+    epoch_nanoseconds: i128,
+) -> AllocResult<StackRoot<InstantObject>> {
+    let proto = cx.get_intrinsic(Intrinsic::InstantPrototype);
+    InstantObject::new_with_proto(cx, proto, epoch_nanoseconds)
+}
+
+/// Convert a BigInt to epoch nanoseconds, throwing a RangeError if out of range.
+pub fn bigint_to_epoch_nanoseconds(
+    cx: Context,
+    bigint: StackRoot<BigIntValue>,
+) -> EvalResult<i128> {
+    match i128::try_from(&bigint.bigint()) {
+        Ok(epoch_nanoseconds) if is_valid_epoch_nanoseconds(epoch_nanoseconds) => {
+            Ok(epoch_nanoseconds)
+        }
+        _ => range_error(cx, "epoch nanoseconds are outside the supported range"),
+    }
+}
+
+/// RoundTemporalInstant (https://tc39.es/proposal-temporal/#sec-temporal-roundtemporalinstant)
+pub fn round_temporal_instant(
+    epoch_nanoseconds: i128,
+    increment: i64,
+    unit: Unit,
+    rounding_mode: RoundingMode,
+) -> i128 {
+    let increment = increment as i128 * unit.nanoseconds();
+    round_number_to_increment_as_if_positive(epoch_nanoseconds, increment, rounding_mode)
+}
+
+/// ToTemporalInstant (https://tc39.es/proposal-temporal/#sec-temporal-totemporalinstant)
+///
+/// Returns the epoch nanoseconds of the instant.
+pub fn to_temporal_instant(cx: Context, item: StackRoot<Value>) -> EvalResult<i128> {
+    let mut item = item;
+
+    if item.is_object() {
+        let object = item.as_object();
+        if let Some(instant) = object.as_instant_object() {
+            return Ok(instant.epoch_nanoseconds());
+        }
+
+        if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+            return Ok(zoned_date_time.epoch_nanoseconds());
+        }
+
+        item = to_primitive(cx, item, ToPrimitivePreferredType::String)?;
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "instant must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let Some(parsed) = parse_iso_date_time(&string, ParseGoal::Instant) else {
+        return range_error(cx, &format!("invalid instant {string}"));
+    };
+
+    let offset_nanoseconds = match parsed.offset {
+        Some(ParsedOffset::Offset { nanoseconds, .. }) => nanoseconds as i128,
+        _ => 0,
+    };
+
+    let date = IsoDate::new(parsed.year.unwrap(), parsed.month, parsed.day);
+    let date_time = IsoDateTime::new(date, parsed.time.unwrap_or(MIDNIGHT));
+
+    let balanced = date_time.add_time(-offset_nanoseconds);
+    check_iso_days_range(cx, balanced.date)?;
+
+    let epoch_nanoseconds = balanced.to_utc_epoch_nanoseconds();
+    if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        return range_error(cx, "instant is outside the supported range");
+    }
+
+    Ok(epoch_nanoseconds)
+}
+
+pub struct InstantConstructor;
+
+impl InstantConstructor {
+    /// Properties of the Temporal.Instant Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-instant-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            1,
+            cx.names.instant(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::InstantPrototype).into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+        func.intrinsic_func(
+            cx,
+            cx.names.from_epoch_milliseconds(),
+            Self::from_epoch_milliseconds,
+            1,
+            realm,
+        )?;
+        func.intrinsic_func(
+            cx,
+            cx.names.from_epoch_nanoseconds(),
+            Self::from_epoch_nanoseconds,
+            1,
+            realm,
+        )?;
+
+        Ok(func)
+    }
+
+    /// Temporal.Instant (https://tc39.es/proposal-temporal/#sec-temporal.instant)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Temporal.Instant constructor must be called with new");
+        };
+
+        let epoch_nanoseconds = to_bigint(cx, get_argument(cx, arguments, 0))?;
+        let epoch_nanoseconds = bigint_to_epoch_nanoseconds(cx, epoch_nanoseconds)?;
+
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::InstantPrototype)?;
+
+        Ok(InstantObject::new_with_proto(cx, proto, epoch_nanoseconds)?.as_value())
+    }
+
+    /// Temporal.Instant.compare (https://tc39.es/proposal-temporal/#sec-temporal.instant.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_instant(cx, get_argument(cx, arguments, 0))?;
+        let two = to_temporal_instant(cx, get_argument(cx, arguments, 1))?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.Instant.from (https://tc39.es/proposal-temporal/#sec-temporal.instant.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let epoch_nanoseconds = to_temporal_instant(cx, get_argument(cx, arguments, 0))?;
+        Ok(create_temporal_instant(cx, epoch_nanoseconds)?.as_value())
+    }
+
+    /// Temporal.Instant.fromEpochMilliseconds (https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochmilliseconds)
+    pub fn from_epoch_milliseconds(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let epoch_milliseconds = to_number(cx, get_argument(cx, arguments, 0))?;
+        if !is_integral_number(*epoch_milliseconds) {
+            return range_error(cx, "epoch milliseconds must be an integer");
+        }
+
+        let epoch_nanoseconds = epoch_milliseconds.as_number() as i128 * NS_PER_MILLISECOND;
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return range_error(cx, "epoch milliseconds are outside the supported range");
+        }
+
+        Ok(create_temporal_instant(cx, epoch_nanoseconds)?.as_value())
+    }
+
+    /// Temporal.Instant.fromEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochnanoseconds)
+    pub fn from_epoch_nanoseconds(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let epoch_nanoseconds = to_bigint(cx, get_argument(cx, arguments, 0))?;
+        let epoch_nanoseconds = bigint_to_epoch_nanoseconds(cx, epoch_nanoseconds)?;
+
+        Ok(create_temporal_instant(cx, epoch_nanoseconds)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<InstantObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<InstantObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.epoch_nanoseconds);
+    }
+}
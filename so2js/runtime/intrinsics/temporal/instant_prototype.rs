@@ -0,0 +1,400 @@
+use alloc::{format, string::String};
+use num_bigint::BigInt;
+
+use crate::runtime::{
+    abstract_operations::get,
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    value::BigIntValue,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    duration::{round_time_difference, seconds_string_precision, Precision},
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{date_time_to_string, format_date_time_utc_offset_rounded, format_with_locales},
+    instant_constructor::{
+        create_temporal_instant, round_temporal_instant, to_temporal_instant, InstantObject,
+    },
+    iso::{is_valid_epoch_nanoseconds, IsoDateTime, NS_PER_DAY, NS_PER_MILLISECOND},
+    options::{
+        get_difference_settings, get_fractional_second_digits_option,
+        get_rounding_increment_option, get_rounding_mode_option, get_shorthand_options_object,
+        get_unit_valued_option, validate_rounding_increment, validate_unit_value,
+        DifferenceOperation, ShowCalendar, UnitValue,
+    },
+    rounding::{RoundingMode, Unit, UnitGroup},
+    time_zone::{get_offset_nanoseconds_for, resolve_time_zone, to_temporal_time_zone_identifier},
+    zoned_date_time_constructor::create_temporal_zoned_date_time,
+};
+
+pub struct InstantPrototype;
+
+impl InstantPrototype {
+    /// Properties of the Temporal.Instant Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-instant-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once InstantConstructor has been created
+        object.intrinsic_getter(
+            cx,
+            cx.names.epoch_milliseconds(),
+            Self::epoch_milliseconds,
+            realm,
+        )?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.epoch_nanoseconds(),
+            Self::epoch_nanoseconds,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.round(), Self::round, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_zoned_date_time_iso(),
+            Self::to_zoned_date_time_iso,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.Instant")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.Instant.prototype.epochMilliseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochmilliseconds)
+    pub fn epoch_milliseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let epoch_milliseconds = instant.epoch_nanoseconds().div_euclid(NS_PER_MILLISECOND);
+
+        Ok(cx.number(epoch_milliseconds as f64))
+    }
+
+    /// get Temporal.Instant.prototype.epochNanoseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochnanoseconds)
+    pub fn epoch_nanoseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let epoch_nanoseconds = BigInt::from(instant.epoch_nanoseconds());
+
+        Ok(BigIntValue::new(cx, epoch_nanoseconds)?.into())
+    }
+
+    /// Temporal.Instant.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        add_duration_to_instant(cx, instant, get_argument(cx, arguments, 0), false)
+    }
+
+    /// Temporal.Instant.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        add_duration_to_instant(cx, instant, get_argument(cx, arguments, 0), true)
+    }
+
+    /// Temporal.Instant.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        difference_temporal_instant(
+            cx,
+            DifferenceOperation::Until,
+            instant,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.Instant.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        difference_temporal_instant(
+            cx,
+            DifferenceOperation::Since,
+            instant,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.Instant.prototype.round (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.round)
+    pub fn round(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+
+        let round_to = get_argument(cx, arguments, 0);
+        let round_to = get_shorthand_options_object(cx, round_to, cx.names.smallest_unit())?;
+
+        // Options are read in alphabetical order
+        let rounding_increment = get_rounding_increment_option(cx, round_to)?;
+        let rounding_mode = get_rounding_mode_option(cx, round_to, RoundingMode::HalfExpand)?;
+        let smallest_unit = get_unit_valued_option(cx, round_to, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let Some(UnitValue::Unit(smallest_unit)) = smallest_unit else {
+            return range_error(cx, "smallestUnit is required");
+        };
+
+        let maximum = (NS_PER_DAY / smallest_unit.nanoseconds()) as i64;
+        validate_rounding_increment(cx, rounding_increment, maximum, true)?;
+
+        let rounded = round_temporal_instant(
+            instant.epoch_nanoseconds(),
+            rounding_increment,
+            smallest_unit,
+            rounding_mode,
+        );
+
+        Ok(create_temporal_instant(cx, rounded)?.as_value())
+    }
+
+    /// Temporal.Instant.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_instant(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(cx.bool(instant.epoch_nanoseconds() == other))
+    }
+
+    /// Temporal.Instant.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+
+        // Options are read in alphabetical order
+        let digits = get_fractional_second_digits_option(cx, options)?;
+        let rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+        let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+        let time_zone = get(cx, options, cx.names.time_zone())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(Unit::Hour)) => {
+                return range_error(cx, "smallestUnit cannot be hour");
+            }
+            Some(UnitValue::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(to_temporal_time_zone_identifier(cx, time_zone)?)
+        };
+
+        let (precision, unit, increment) = seconds_string_precision(smallest_unit, digits);
+        let rounded =
+            round_temporal_instant(instant.epoch_nanoseconds(), increment, unit, rounding_mode);
+
+        let string = temporal_instant_to_string(cx, rounded, time_zone.as_deref(), precision);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.Instant.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+
+        let string = format_with_locales(
+            cx,
+            instant.epoch_nanoseconds(),
+            None,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Any,
+            DefaultComponents::All,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.Instant.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let string =
+            temporal_instant_to_string(cx, instant.epoch_nanoseconds(), None, Precision::Auto);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.Instant.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(cx, "Temporal.Instant cannot be converted to a primitive")
+    }
+
+    /// Temporal.Instant.prototype.toZonedDateTimeISO (https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tozoneddatetimeiso)
+    pub fn to_zoned_date_time_iso(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let instant = InstantObject::cast_from_value(cx, this_value)?;
+        let time_zone = to_temporal_time_zone_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(
+            create_temporal_zoned_date_time(cx, instant.epoch_nanoseconds(), &time_zone)?
+                .as_value(),
+        )
+    }
+}
+
+/// TemporalInstantToString (https://tc39.es/proposal-temporal/#sec-temporal-temporalinstanttostring)
+fn temporal_instant_to_string(
+    cx: Context,
+    epoch_nanoseconds: i128,
+    time_zone: Option<&str>,
+    precision: Precision,
+) -> String {
+    let offset_nanoseconds = match time_zone {
+        None => 0,
+        Some(time_zone) => {
+            get_offset_nanoseconds_for(cx, &resolve_time_zone(cx, time_zone), epoch_nanoseconds)
+        }
+    };
+
+    let date_time = IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds, offset_nanoseconds);
+    let date_time_string = date_time_to_string(&date_time, precision, ShowCalendar::Never);
+
+    let time_zone_string = match time_zone {
+        None => String::from("Z"),
+        Some(_) => format_date_time_utc_offset_rounded(offset_nanoseconds),
+    };
+
+    format!("{date_time_string}{time_zone_string}")
+}
+
+/// AddDurationToInstant (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoinstant)
+fn add_duration_to_instant(
+    cx: Context,
+    instant: StackRoot<InstantObject>,
+    duration_like: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    if duration.default_largest_unit().is_date_unit() {
+        return range_error(cx, "duration cannot have date units");
+    }
+
+    let epoch_nanoseconds = instant.epoch_nanoseconds() + duration.time_duration();
+    if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        return range_error(cx, "instant is outside the supported range");
+    }
+
+    Ok(create_temporal_instant(cx, epoch_nanoseconds)?.as_value())
+}
+
+/// DifferenceTemporalInstant (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalinstant)
+fn difference_temporal_instant(
+    cx: Context,
+    operation: DifferenceOperation,
+    instant: StackRoot<InstantObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let other = to_temporal_instant(cx, other)?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::Time,
+        &[],
+        Unit::Nanosecond,
+        Unit::Second,
+    )?;
+
+    let Some(internal_duration) = round_time_difference(
+        other - instant.epoch_nanoseconds(),
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    ) else {
+        return range_error(cx, "duration is out of range");
+    };
+
+    create_difference_duration(cx, &internal_duration, settings.largest_unit, operation)
+}
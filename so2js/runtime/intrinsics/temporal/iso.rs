@@ -0,0 +1,577 @@
+//! Dates and times in the ISO 8601 calendar, along with the arithmetic on them that is shared by
+//! all Temporal types.
+
+use crate::runtime::intrinsics::date_object::{
+    is_leap_year, year_month_day_to_days_since_unix_epoch,
+};
+
+use super::rounding::{round_number_to_increment, RoundingMode, Unit};
+
+pub const NS_PER_MICROSECOND: i128 = 1_000;
+pub const NS_PER_MILLISECOND: i128 = 1_000_000;
+pub const NS_PER_SECOND: i128 = 1_000_000_000;
+pub const NS_PER_MINUTE: i128 = 60 * NS_PER_SECOND;
+pub const NS_PER_HOUR: i128 = 60 * NS_PER_MINUTE;
+pub const NS_PER_DAY: i128 = 24 * NS_PER_HOUR;
+
+/// The largest and smallest epoch nanoseconds that can be represented by a Temporal.Instant,
+/// which are the same as the limits of a Date.
+pub const NS_MAX_INSTANT: i128 = 100_000_000 * NS_PER_DAY;
+pub const NS_MIN_INSTANT: i128 = -NS_MAX_INSTANT;
+
+/// Behavior when a field of a date or time is out of range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Clamp the field to the nearest valid value
+    Constrain,
+    /// Throw a RangeError
+    Reject,
+}
+
+/// A date in the ISO 8601 calendar. Months and days are 1-indexed.
+///
+/// Fields are ordered so that the derived ordering is chronological.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoDate {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// A wall-clock time. Fields are ordered so that the derived ordering is chronological.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub millisecond: u16,
+    pub microsecond: u16,
+    pub nanosecond: u16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoDateTime {
+    pub date: IsoDate,
+    pub time: IsoTime,
+}
+
+/// A duration made up of only calendar units, where all fields have the same sign.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DateDuration {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+}
+
+impl DateDuration {
+    pub fn new(years: i64, months: i64, weeks: i64, days: i64) -> DateDuration {
+        DateDuration {
+            years,
+            months,
+            weeks,
+            days,
+        }
+    }
+
+    /// DateDurationSign (https://tc39.es/proposal-temporal/#sec-temporal-datedurationsign)
+    pub fn sign(&self) -> i64 {
+        for field in [self.years, self.months, self.weeks, self.days] {
+            if field != 0 {
+                return field.signum();
+            }
+        }
+
+        0
+    }
+
+    pub fn negated(&self) -> DateDuration {
+        DateDuration::new(-self.years, -self.months, -self.weeks, -self.days)
+    }
+}
+
+pub fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn days_in_year(year: i64) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// IsValidISODate (https://tc39.es/proposal-temporal/#sec-temporal-isvalidisodate)
+pub fn is_valid_iso_date(year: i64, month: i64, day: i64) -> bool {
+    (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month as u8) as i64
+}
+
+/// BalanceISOYearMonth (https://tc39.es/proposal-temporal/#sec-temporal-balanceisoyearmonth)
+///
+/// Months are 1-indexed but may be outside the range of a year.
+pub fn balance_iso_year_month(year: i64, month: i64) -> (i64, u8) {
+    let year = year + (month - 1).div_euclid(12);
+    let month = (month - 1).rem_euclid(12) + 1;
+
+    (year, month as u8)
+}
+
+/// ISODateToEpochDays (https://tc39.es/proposal-temporal/#sec-temporal-isodatetoepochdays)
+///
+/// Months are 1-indexed but may be outside the range of a year, and days are not constrained.
+pub fn iso_date_to_epoch_days(year: i64, month: i64, day: i64) -> i64 {
+    let (year, month) = balance_iso_year_month(year, month);
+    year_month_day_to_days_since_unix_epoch(year, month as i64, day).unwrap()
+}
+
+impl IsoDate {
+    pub fn new(year: i64, month: u8, day: u8) -> IsoDate {
+        IsoDate { year, month, day }
+    }
+
+    /// The date a number of days after the Unix epoch.
+    ///
+    /// Uses the algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    pub fn from_epoch_days(epoch_days: i64) -> IsoDate {
+        let days = epoch_days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        IsoDate::new(year, month as u8, day as u8)
+    }
+
+    pub fn to_epoch_days(&self) -> i64 {
+        iso_date_to_epoch_days(self.year, self.month as i64, self.day as i64)
+    }
+
+    /// ISODayOfWeek (https://tc39.es/proposal-temporal/#sec-temporal-isodayofweek)
+    ///
+    /// Monday is 1 and Sunday is 7.
+    pub fn day_of_week(&self) -> u8 {
+        // The Unix epoch was a Thursday
+        ((self.to_epoch_days() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// ISODayOfYear (https://tc39.es/proposal-temporal/#sec-temporal-isodayofyear)
+    pub fn day_of_year(&self) -> u16 {
+        (self.to_epoch_days() - iso_date_to_epoch_days(self.year, 1, 1) + 1) as u16
+    }
+
+    /// ISOWeekOfYear (https://tc39.es/proposal-temporal/#sec-temporal-isoweekofyear)
+    ///
+    /// Returns the week number along with the week-numbering year, which may differ from the
+    /// calendar year at the start and end of the year.
+    pub fn week_of_year(&self) -> (u8, i64) {
+        let day_of_year = self.day_of_year() as i64;
+        let day_of_week = self.day_of_week() as i64;
+
+        // Weeks start on Monday, and the first week of the year contains the first Thursday
+        let week = (day_of_year - day_of_week + 10) / 7;
+
+        if week < 1 {
+            // Last week of the previous year
+            let previous_year_end = IsoDate::new(self.year - 1, 12, 31);
+            return previous_year_end.week_of_year();
+        }
+
+        if week > weeks_in_year(self.year) {
+            return (1, self.year + 1);
+        }
+
+        (week as u8, self.year)
+    }
+
+    /// BalanceISODate (https://tc39.es/proposal-temporal/#sec-temporal-balanceisodate)
+    pub fn balance(year: i64, month: i64, day: i64) -> IsoDate {
+        IsoDate::from_epoch_days(iso_date_to_epoch_days(year, month, day))
+    }
+
+    pub fn add_days(&self, days: i64) -> IsoDate {
+        IsoDate::from_epoch_days(self.to_epoch_days() + days)
+    }
+}
+
+/// Number of ISO weeks in a year, which is 53 if the year starts on a Thursday or is a leap year
+/// starting on a Wednesday, otherwise 52.
+fn weeks_in_year(year: i64) -> i64 {
+    let jan_1 = IsoDate::new(year, 1, 1).day_of_week();
+    if jan_1 == 4 || (jan_1 == 3 && is_leap_year(year)) {
+        53
+    } else {
+        52
+    }
+}
+
+/// RegulateISODate (https://tc39.es/proposal-temporal/#sec-temporal-regulateisodate)
+///
+/// Returns None if the date is invalid and overflow is reject.
+pub fn regulate_iso_date(year: i64, month: i64, day: i64, overflow: Overflow) -> Option<IsoDate> {
+    match overflow {
+        Overflow::Constrain => {
+            let month = month.clamp(1, 12) as u8;
+            let day = day.clamp(1, days_in_month(year, month) as i64) as u8;
+            Some(IsoDate::new(year, month, day))
+        }
+        Overflow::Reject => {
+            if !is_valid_iso_date(year, month, day) {
+                return None;
+            }
+
+            Some(IsoDate::new(year, month as u8, day as u8))
+        }
+    }
+}
+
+/// ISODateWithinLimits (https://tc39.es/proposal-temporal/#sec-temporal-isodatewithinlimits)
+pub fn iso_date_within_limits(date: IsoDate) -> bool {
+    iso_date_time_within_limits(IsoDateTime::new(date, NOON))
+}
+
+/// ISODateTimeWithinLimits (https://tc39.es/proposal-temporal/#sec-temporal-isodatetimewithinlimits)
+pub fn iso_date_time_within_limits(date_time: IsoDateTime) -> bool {
+    // Avoid overflow when computing epoch nanoseconds of far away dates
+    if date_time.date.year.abs() > 300_000 {
+        return false;
+    }
+
+    let epoch_nanoseconds = date_time.to_utc_epoch_nanoseconds();
+
+    epoch_nanoseconds > NS_MIN_INSTANT - NS_PER_DAY
+        && epoch_nanoseconds < NS_MAX_INSTANT + NS_PER_DAY
+}
+
+/// ISOYearMonthWithinLimits (https://tc39.es/proposal-temporal/#sec-temporal-isoyearmonthwithinlimits)
+pub fn iso_year_month_within_limits(year: i64, month: u8) -> bool {
+    if !(-271_821..=275_760).contains(&year) {
+        return false;
+    }
+
+    !((year == -271_821 && month < 4) || (year == 275_760 && month > 9))
+}
+
+/// Whether epoch nanoseconds are within the range of a Temporal.Instant.
+///
+/// IsValidEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-isvalidepochnanoseconds)
+pub fn is_valid_epoch_nanoseconds(epoch_nanoseconds: i128) -> bool {
+    (NS_MIN_INSTANT..=NS_MAX_INSTANT).contains(&epoch_nanoseconds)
+}
+
+pub const MIDNIGHT: IsoTime = IsoTime {
+    hour: 0,
+    minute: 0,
+    second: 0,
+    millisecond: 0,
+    microsecond: 0,
+    nanosecond: 0,
+};
+
+pub const NOON: IsoTime = IsoTime {
+    hour: 12,
+    minute: 0,
+    second: 0,
+    millisecond: 0,
+    microsecond: 0,
+    nanosecond: 0,
+};
+
+impl IsoTime {
+    pub fn new(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+    ) -> IsoTime {
+        IsoTime {
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        }
+    }
+
+    /// The time a number of nanoseconds after midnight, which must be less than a day.
+    pub fn from_nanoseconds(nanoseconds: i128) -> IsoTime {
+        debug_assert!((0..NS_PER_DAY).contains(&nanoseconds));
+
+        IsoTime::new(
+            (nanoseconds / NS_PER_HOUR) as u8,
+            (nanoseconds / NS_PER_MINUTE % 60) as u8,
+            (nanoseconds / NS_PER_SECOND % 60) as u8,
+            (nanoseconds / NS_PER_MILLISECOND % 1000) as u16,
+            (nanoseconds / NS_PER_MICROSECOND % 1000) as u16,
+            (nanoseconds % 1000) as u16,
+        )
+    }
+
+    /// Number of nanoseconds since midnight.
+    pub fn to_nanoseconds(&self) -> i128 {
+        self.hour as i128 * NS_PER_HOUR
+            + self.minute as i128 * NS_PER_MINUTE
+            + self.second as i128 * NS_PER_SECOND
+            + self.millisecond as i128 * NS_PER_MILLISECOND
+            + self.microsecond as i128 * NS_PER_MICROSECOND
+            + self.nanosecond as i128
+    }
+
+    /// BalanceTime (https://tc39.es/proposal-temporal/#sec-temporal-balancetime)
+    ///
+    /// Returns the number of days the time overflows into along with the balanced time.
+    pub fn balance(nanoseconds: i128) -> (i64, IsoTime) {
+        let days = nanoseconds.div_euclid(NS_PER_DAY) as i64;
+        let time = IsoTime::from_nanoseconds(nanoseconds.rem_euclid(NS_PER_DAY));
+
+        (days, time)
+    }
+
+    /// AddTime (https://tc39.es/proposal-temporal/#sec-temporal-addtime)
+    pub fn add(&self, time_duration: i128) -> (i64, IsoTime) {
+        IsoTime::balance(self.to_nanoseconds() + time_duration)
+    }
+
+    /// DifferenceTime (https://tc39.es/proposal-temporal/#sec-temporal-differencetime)
+    pub fn difference(&self, other: &IsoTime) -> i128 {
+        other.to_nanoseconds() - self.to_nanoseconds()
+    }
+
+    /// RoundTime (https://tc39.es/proposal-temporal/#sec-temporal-roundtime)
+    ///
+    /// Returns the number of days the rounded time overflows into along with the rounded time.
+    pub fn round(&self, increment: i64, unit: Unit, rounding_mode: RoundingMode) -> (i64, IsoTime) {
+        let unit_length = unit.nanoseconds();
+        let quantity = self.to_nanoseconds();
+        let rounded =
+            round_number_to_increment(quantity, increment as i128 * unit_length, rounding_mode);
+
+        IsoTime::balance(rounded)
+    }
+}
+
+/// IsValidTime (https://tc39.es/proposal-temporal/#sec-temporal-isvalidtime)
+fn is_valid_time(fields: &[f64; 6]) -> bool {
+    let [hour, minute, second, millisecond, microsecond, nanosecond] = *fields;
+    (0.0..=23.0).contains(&hour)
+        && (0.0..=59.0).contains(&minute)
+        && (0.0..=59.0).contains(&second)
+        && (0.0..=999.0).contains(&millisecond)
+        && (0.0..=999.0).contains(&microsecond)
+        && (0.0..=999.0).contains(&nanosecond)
+}
+
+/// RegulateTime (https://tc39.es/proposal-temporal/#sec-temporal-regulatetime)
+///
+/// Fields are the hour, minute, second, millisecond, microsecond, and nanosecond, which must be
+/// integers. Returns None if the time is invalid and overflow is reject.
+pub fn regulate_time(fields: [f64; 6], overflow: Overflow) -> Option<IsoTime> {
+    let fields = match overflow {
+        Overflow::Constrain => {
+            let [hour, minute, second, millisecond, microsecond, nanosecond] = fields;
+            [
+                hour.clamp(0.0, 23.0),
+                minute.clamp(0.0, 59.0),
+                second.clamp(0.0, 59.0),
+                millisecond.clamp(0.0, 999.0),
+                microsecond.clamp(0.0, 999.0),
+                nanosecond.clamp(0.0, 999.0),
+            ]
+        }
+        Overflow::Reject => {
+            if !is_valid_time(&fields) {
+                return None;
+            }
+
+            fields
+        }
+    };
+
+    Some(IsoTime::new(
+        fields[0] as u8,
+        fields[1] as u8,
+        fields[2] as u8,
+        fields[3] as u16,
+        fields[4] as u16,
+        fields[5] as u16,
+    ))
+}
+
+impl IsoDateTime {
+    pub fn new(date: IsoDate, time: IsoTime) -> IsoDateTime {
+        IsoDateTime { date, time }
+    }
+
+    /// GetUTCEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-getutcepochnanoseconds)
+    pub fn to_utc_epoch_nanoseconds(&self) -> i128 {
+        self.date.to_epoch_days() as i128 * NS_PER_DAY + self.time.to_nanoseconds()
+    }
+
+    /// The date and time at epoch nanoseconds in a time zone with the given offset.
+    ///
+    /// GetISODateTimeFor (https://tc39.es/proposal-temporal/#sec-temporal-getisodatetimefor)
+    pub fn from_epoch_nanoseconds(
+        epoch_nanoseconds: i128,
+        offset_nanoseconds: i128,
+    ) -> IsoDateTime {
+        let local_nanoseconds = epoch_nanoseconds + offset_nanoseconds;
+        let epoch_days = local_nanoseconds.div_euclid(NS_PER_DAY) as i64;
+        let time = IsoTime::from_nanoseconds(local_nanoseconds.rem_euclid(NS_PER_DAY));
+
+        IsoDateTime::new(IsoDate::from_epoch_days(epoch_days), time)
+    }
+
+    /// BalanceISODateTime (https://tc39.es/proposal-temporal/#sec-temporal-balanceisodatetime)
+    ///
+    /// Add a time duration to this date and time.
+    pub fn add_time(&self, time_duration: i128) -> IsoDateTime {
+        let (days, time) = self.time.add(time_duration);
+        IsoDateTime::new(self.date.add_days(days), time)
+    }
+
+    /// RoundISODateTime (https://tc39.es/proposal-temporal/#sec-temporal-roundisodatetime)
+    pub fn round(&self, increment: i64, unit: Unit, rounding_mode: RoundingMode) -> IsoDateTime {
+        if unit == Unit::Day {
+            let quantity = self.time.to_nanoseconds();
+            let rounded = round_number_to_increment(quantity, NS_PER_DAY, rounding_mode);
+            let days = (rounded / NS_PER_DAY) as i64;
+            return IsoDateTime::new(self.date.add_days(days), MIDNIGHT);
+        }
+
+        let (days, time) = self.time.round(increment, unit, rounding_mode);
+        IsoDateTime::new(self.date.add_days(days), time)
+    }
+}
+
+/// AddISODate for the ISO 8601 calendar.
+///
+/// CalendarDateAdd (https://tc39.es/proposal-temporal/#sec-temporal-calendardateadd)
+///
+/// Returns None if the result is invalid and overflow is reject, or the result is outside the
+/// supported range of dates.
+pub fn add_iso_date(date: IsoDate, duration: DateDuration, overflow: Overflow) -> Option<IsoDate> {
+    // Avoid overflow when adding far out of range durations
+    let years = date.year.checked_add(duration.years)?;
+    let months = (date.month as i64).checked_add(duration.months)?;
+    let (year, month) = balance_iso_year_month(years, months);
+    if year.abs() > 1_000_000 {
+        return None;
+    }
+
+    let intermediate = regulate_iso_date(year, month as i64, date.day as i64, overflow)?;
+
+    let days = duration.weeks.checked_mul(7)?.checked_add(duration.days)?;
+    if days.abs() > 1_000_000_000 {
+        return None;
+    }
+
+    let result = intermediate.add_days(days);
+    if !iso_date_within_limits(result) {
+        return None;
+    }
+
+    Some(result)
+}
+
+/// CompareISODate (https://tc39.es/proposal-temporal/#sec-temporal-compareisodate) where the day
+/// of the first date may be past the end of the month.
+fn compare_surpassing(year: i64, month: u8, day: i64, other: &IsoDate) -> core::cmp::Ordering {
+    (year, month, day).cmp(&(other.year, other.month, other.day as i64))
+}
+
+/// ISODateSurpasses (https://tc39.es/proposal-temporal/#sec-temporal-isodatesurpasses)
+///
+/// Whether adding years and months to a date, without constraining the day, goes past another date
+/// in the direction of `sign`.
+fn iso_date_surpasses(sign: i64, date: &IsoDate, months: i64, other: &IsoDate) -> bool {
+    let (year, month) = balance_iso_year_month(date.year, date.month as i64 + months);
+    let comparison = compare_surpassing(year, month, date.day as i64, other) as i64;
+
+    sign * comparison == 1
+}
+
+/// DifferenceISODate for the ISO 8601 calendar.
+///
+/// CalendarDateUntil (https://tc39.es/proposal-temporal/#sec-temporal-calendardateuntil)
+pub fn difference_iso_date(one: &IsoDate, two: &IsoDate, largest_unit: Unit) -> DateDuration {
+    let sign = -(one.cmp(two) as i64);
+    if sign == 0 {
+        return DateDuration::default();
+    }
+
+    if largest_unit == Unit::Year || largest_unit == Unit::Month {
+        // Start from the difference in months and back off until the date is not surpassed
+        let mut months = (two.year - one.year) * 12 + (two.month as i64 - one.month as i64);
+        while months != 0 && iso_date_surpasses(sign, one, months, two) {
+            months -= sign;
+        }
+
+        let (year, month) = balance_iso_year_month(one.year, one.month as i64 + months);
+        let constrained =
+            regulate_iso_date(year, month as i64, one.day as i64, Overflow::Constrain).unwrap();
+        let days = two.to_epoch_days() - constrained.to_epoch_days();
+
+        let (years, months) = if largest_unit == Unit::Year {
+            (months / 12, months % 12)
+        } else {
+            (0, months)
+        };
+
+        return DateDuration::new(years, months, 0, days);
+    }
+
+    let days = two.to_epoch_days() - one.to_epoch_days();
+    if largest_unit == Unit::Week {
+        return DateDuration::new(0, 0, days / 7, days % 7);
+    }
+
+    DateDuration::new(0, 0, 0, days)
+}
+
+/// DifferenceISODateTime (https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime)
+///
+/// Returns the date duration and the time duration in nanoseconds between two date-times.
+pub fn difference_iso_date_time(
+    one: &IsoDateTime,
+    two: &IsoDateTime,
+    largest_unit: Unit,
+) -> (DateDuration, i128) {
+    let mut time_duration = one.time.difference(&two.time);
+    let time_sign = time_duration.signum();
+    let date_sign = -(one.date.cmp(&two.date) as i128);
+
+    // If the time difference has the opposite sign of the date difference then borrow a day
+    let mut adjusted_date = two.date;
+    if time_sign == -date_sign {
+        adjusted_date = adjusted_date.add_days(time_sign as i64);
+        time_duration -= time_sign * NS_PER_DAY;
+    }
+
+    let date_largest_unit = largest_unit.max(Unit::Day);
+    let mut date_difference = difference_iso_date(&one.date, &adjusted_date, date_largest_unit);
+
+    // Days are converted to time if the largest unit is a time unit
+    if largest_unit < Unit::Day {
+        time_duration += date_difference.days as i128 * NS_PER_DAY;
+        date_difference.days = 0;
+    }
+
+    (date_difference, time_duration)
+}
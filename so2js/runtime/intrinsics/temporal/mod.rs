@@ -0,0 +1,26 @@
+pub mod calendar;
+pub mod duration;
+pub mod duration_constructor;
+pub mod duration_prototype;
+pub mod format;
+pub mod instant_constructor;
+pub mod instant_prototype;
+pub mod iso;
+pub mod now_object;
+pub mod options;
+pub mod parser;
+pub mod plain_date_constructor;
+pub mod plain_date_prototype;
+pub mod plain_date_time_constructor;
+pub mod plain_date_time_prototype;
+pub mod plain_month_day_constructor;
+pub mod plain_month_day_prototype;
+pub mod plain_time_constructor;
+pub mod plain_time_prototype;
+pub mod plain_year_month_constructor;
+pub mod plain_year_month_prototype;
+pub mod rounding;
+pub mod temporal_object;
+pub mod time_zone;
+pub mod zoned_date_time_constructor;
+pub mod zoned_date_time_prototype;
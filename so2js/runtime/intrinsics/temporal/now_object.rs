@@ -0,0 +1,163 @@
+use alloc::string::String;
+
+use crate::{
+    common::time::get_current_unix_time,
+    runtime::{
+        alloc_error::AllocResult, eval_result::EvalResult, function::get_argument,
+        intl::date_time_format::default_time_zone, intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue, property::Property, realm::Realm, Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    instant_constructor::create_temporal_instant,
+    iso::{IsoDateTime, NS_PER_MILLISECOND},
+    plain_date_constructor::create_temporal_date,
+    plain_date_time_constructor::create_temporal_date_time,
+    plain_time_constructor::create_temporal_time,
+    time_zone::{get_iso_date_time_for, resolve_time_zone, to_temporal_time_zone_identifier},
+    zoned_date_time_constructor::create_temporal_zoned_date_time,
+};
+
+/// The Temporal.Now Object (https://tc39.es/proposal-temporal/#sec-temporal-now-object)
+pub struct NowObject;
+
+impl NowObject {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        object.intrinsic_func(cx, cx.names.instant_(), Self::instant, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.plain_date_iso(),
+            Self::plain_date_iso,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.plain_date_time_iso(),
+            Self::plain_date_time_iso,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.plain_time_iso(),
+            Self::plain_time_iso,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.time_zone_id(), Self::time_zone_id, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.zoned_date_time_iso(),
+            Self::zoned_date_time_iso,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.Now")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// Temporal.Now.timeZoneId (https://tc39.es/proposal-temporal/#sec-temporal.now.timezoneid)
+    pub fn time_zone_id(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        Ok(cx.alloc_string(&default_time_zone(cx))?.as_value())
+    }
+
+    /// Temporal.Now.instant (https://tc39.es/proposal-temporal/#sec-temporal.now.instant)
+    pub fn instant(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        Ok(create_temporal_instant(cx, system_utc_epoch_nanoseconds(cx))?.as_value())
+    }
+
+    /// Temporal.Now.plainDateTimeISO (https://tc39.es/proposal-temporal/#sec-temporal.now.plaindatetimeiso)
+    pub fn plain_date_time_iso(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time = system_date_time(cx, get_argument(cx, arguments, 0))?;
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+
+    /// Temporal.Now.zonedDateTimeISO (https://tc39.es/proposal-temporal/#sec-temporal.now.zoneddatetimeiso)
+    pub fn zoned_date_time_iso(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time_zone = system_time_zone_identifier(cx, get_argument(cx, arguments, 0))?;
+        let epoch_nanoseconds = system_utc_epoch_nanoseconds(cx);
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone)?.as_value())
+    }
+
+    /// Temporal.Now.plainDateISO (https://tc39.es/proposal-temporal/#sec-temporal.now.plaindateiso)
+    pub fn plain_date_iso(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time = system_date_time(cx, get_argument(cx, arguments, 0))?;
+        Ok(create_temporal_date(cx, date_time.date)?.as_value())
+    }
+
+    /// Temporal.Now.plainTimeISO (https://tc39.es/proposal-temporal/#sec-temporal.now.plaintimeiso)
+    pub fn plain_time_iso(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time = system_date_time(cx, get_argument(cx, arguments, 0))?;
+        Ok(create_temporal_time(cx, date_time.time)?.as_value())
+    }
+}
+
+/// SystemUTCEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds)
+///
+/// The system clock only has millisecond precision.
+fn system_utc_epoch_nanoseconds(cx: Context) -> i128 {
+    get_current_unix_time(&cx) as i128 * NS_PER_MILLISECOND
+}
+
+/// The identifier of the time zone argument, or the system time zone if it is undefined.
+fn system_time_zone_identifier(cx: Context, time_zone: StackRoot<Value>) -> EvalResult<String> {
+    if time_zone.is_undefined() {
+        Ok(default_time_zone(cx))
+    } else {
+        to_temporal_time_zone_identifier(cx, time_zone)
+    }
+}
+
+/// SystemDateTime (https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime)
+fn system_date_time(cx: Context, time_zone: StackRoot<Value>) -> EvalResult<IsoDateTime> {
+    let time_zone = system_time_zone_identifier(cx, time_zone)?;
+    let epoch_nanoseconds = system_utc_epoch_nanoseconds(cx);
+
+    Ok(get_iso_date_time_for(
+        cx,
+        &resolve_time_zone(cx, &time_zone),
+        epoch_nanoseconds,
+    ))
+}
@@ -0,0 +1,455 @@
+//! Reading and validating the options and property values accepted by Temporal methods.
+
+use alloc::format;
+
+use crate::{
+    intl_enum,
+    runtime::{
+        abstract_operations::{create_data_property_or_throw, get},
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        heap_item_descriptor::HeapItemKind,
+        intl::options::{get_enum_option, get_options_object, get_string_option},
+        object_value::ObjectValue,
+        ordinary_object::object_create_with_optional_proto,
+        property_key::PropertyKey,
+        type_utilities::{to_number, to_string},
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    iso::Overflow,
+    rounding::{RoundingMode, Unit, UnitGroup},
+};
+
+intl_enum! {
+    pub enum Disambiguation {
+        Compatible => "compatible",
+        Earlier => "earlier",
+        Later => "later",
+        Reject => "reject",
+    }
+}
+
+intl_enum! {
+    pub enum OffsetOption {
+        Prefer => "prefer",
+        Use => "use",
+        Ignore => "ignore",
+        Reject => "reject",
+    }
+}
+
+intl_enum! {
+    pub enum ShowCalendar {
+        Auto => "auto",
+        Always => "always",
+        Never => "never",
+        Critical => "critical",
+    }
+}
+
+intl_enum! {
+    pub enum ShowTimeZone {
+        Auto => "auto",
+        Never => "never",
+        Critical => "critical",
+    }
+}
+
+intl_enum! {
+    pub enum ShowOffset {
+        Auto => "auto",
+        Never => "never",
+    }
+}
+
+intl_enum! {
+    pub enum Direction {
+        Next => "next",
+        Previous => "previous",
+    }
+}
+
+/// ToIntegerWithTruncation (https://tc39.es/proposal-temporal/#sec-temporal-tointegerwithtruncation)
+pub fn to_integer_with_truncation(cx: Context, value: StackRoot<Value>) -> EvalResult<f64> {
+    let number = to_number(cx, value)?.as_number();
+    if !number.is_finite() {
+        return range_error(cx, "value must be a finite number");
+    }
+
+    // Avoid negative zero
+    Ok(number.trunc() + 0.0)
+}
+
+/// ToPositiveIntegerWithTruncation (https://tc39.es/proposal-temporal/#sec-temporal-topositiveintegerwithtruncation)
+pub fn to_positive_integer_with_truncation(
+    cx: Context,
+    value: StackRoot<Value>,
+) -> EvalResult<f64> {
+    let integer = to_integer_with_truncation(cx, value)?;
+    if integer <= 0.0 {
+        return range_error(cx, "value must be a positive integer");
+    }
+
+    Ok(integer)
+}
+
+/// ToIntegerIfIntegral (https://tc39.es/proposal-temporal/#sec-temporal-tointegerifintegral)
+pub fn to_integer_if_integral(cx: Context, value: StackRoot<Value>) -> EvalResult<f64> {
+    let number = to_number(cx, value)?.as_number();
+    if !number.is_finite() || number.trunc() != number {
+        return range_error(cx, "value must be an integer");
+    }
+
+    Ok(number + 0.0)
+}
+
+/// The options argument of a `round` or `total` method, which may be a string that is shorthand
+/// for an options object with a single option.
+pub fn get_shorthand_options_object(
+    cx: Context,
+    value: StackRoot<Value>,
+    shorthand_key: StackRoot<PropertyKey>,
+) -> EvalResult<StackRoot<ObjectValue>> {
+    if value.is_undefined() {
+        return type_error(cx, "options argument is required");
+    }
+
+    if value.is_string() {
+        let object = object_create_with_optional_proto::<ObjectValue>(
+            cx,
+            HeapItemKind::OrdinaryObject,
+            None,
+        )?
+        .to_stack(cx);
+        create_data_property_or_throw(cx, object, shorthand_key, value)?;
+
+        return Ok(object);
+    }
+
+    get_options_object(cx, value)
+}
+
+/// GetTemporalOverflowOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloverflowoption)
+pub fn get_overflow_option(cx: Context, options: StackRoot<ObjectValue>) -> EvalResult<Overflow> {
+    let value = get_string_option(cx, options, cx.names.overflow(), &["constrain", "reject"])?;
+    if value.as_deref() == Some("reject") {
+        Ok(Overflow::Reject)
+    } else {
+        Ok(Overflow::Constrain)
+    }
+}
+
+/// GetTemporalDisambiguationOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporaldisambiguationoption)
+pub fn get_disambiguation_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<Disambiguation> {
+    get_enum_option(
+        cx,
+        options,
+        cx.names.disambiguation(),
+        Disambiguation::Compatible,
+    )
+}
+
+/// GetTemporalOffsetOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloffsetoption)
+pub fn get_offset_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    fallback: OffsetOption,
+) -> EvalResult<OffsetOption> {
+    get_enum_option(cx, options, cx.names.offset(), fallback)
+}
+
+/// GetTemporalShowCalendarNameOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowcalendarnameoption)
+pub fn get_show_calendar_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<ShowCalendar> {
+    get_enum_option(cx, options, cx.names.calendar_name(), ShowCalendar::Auto)
+}
+
+/// GetTemporalShowTimeZoneNameOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowtimezonenameoption)
+pub fn get_show_time_zone_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<ShowTimeZone> {
+    get_enum_option(cx, options, cx.names.time_zone_name(), ShowTimeZone::Auto)
+}
+
+/// GetTemporalShowOffsetOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowoffsetoption)
+pub fn get_show_offset_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<ShowOffset> {
+    get_enum_option(cx, options, cx.names.offset(), ShowOffset::Auto)
+}
+
+/// GetDirectionOption (https://tc39.es/proposal-temporal/#sec-temporal-getdirectionoption)
+pub fn get_direction_option(cx: Context, options: StackRoot<ObjectValue>) -> EvalResult<Direction> {
+    match get_string_option(cx, options, cx.names.direction(), &["next", "previous"])? {
+        Some(direction) if direction == "next" => Ok(Direction::Next),
+        Some(_) => Ok(Direction::Previous),
+        None => range_error(cx, "direction option is required"),
+    }
+}
+
+/// GetRoundingModeOption (https://tc39.es/proposal-temporal/#sec-temporal-getroundingmodeoption)
+pub fn get_rounding_mode_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    fallback: RoundingMode,
+) -> EvalResult<RoundingMode> {
+    get_enum_option(cx, options, cx.names.rounding_mode(), fallback)
+}
+
+/// GetRoundingIncrementOption (https://tc39.es/proposal-temporal/#sec-temporal-getroundingincrementoption)
+pub fn get_rounding_increment_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<i64> {
+    let value = get(cx, options, cx.names.rounding_increment())?;
+    if value.is_undefined() {
+        return Ok(1);
+    }
+
+    let increment = to_integer_with_truncation(cx, value)?;
+    if !(1.0..=1e9).contains(&increment) {
+        return range_error(cx, "roundingIncrement must be between 1 and 1e9");
+    }
+
+    Ok(increment as i64)
+}
+
+/// GetTemporalFractionalSecondDigitsOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalfractionalseconddigitsoption)
+///
+/// Returns None if the number of digits is "auto".
+pub fn get_fractional_second_digits_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+) -> EvalResult<Option<u8>> {
+    let value = get(cx, options, cx.names.fractional_second_digits())?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    if !value.is_number() {
+        let string = to_string(cx, value)?.format(cx)?;
+        if string != "auto" {
+            return range_error(
+                cx,
+                &format!("invalid value {string} for option fractionalSecondDigits"),
+            );
+        }
+
+        return Ok(None);
+    }
+
+    let number = value.as_number();
+    if !number.is_finite() {
+        return range_error(cx, "fractionalSecondDigits must be a finite number");
+    }
+
+    let digits = number.floor();
+    if !(0.0..=9.0).contains(&digits) {
+        return range_error(cx, "fractionalSecondDigits must be between 0 and 9");
+    }
+
+    Ok(Some(digits as u8))
+}
+
+/// The value of an option that is either a unit or "auto".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitValue {
+    Auto,
+    Unit(Unit),
+}
+
+/// Strings allowed for unit valued options.
+const UNIT_OPTION_VALUES: &[&str] = &[
+    "year",
+    "month",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+    "microsecond",
+    "nanosecond",
+    "years",
+    "months",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+    "milliseconds",
+    "microseconds",
+    "nanoseconds",
+    "auto",
+];
+
+/// GetTemporalUnitValuedOption (https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunitvaluedoption)
+///
+/// Returns None if the option is undefined.
+pub fn get_unit_valued_option(
+    cx: Context,
+    options: StackRoot<ObjectValue>,
+    key: StackRoot<PropertyKey>,
+) -> EvalResult<Option<UnitValue>> {
+    let value = get_string_option(cx, options, key, UNIT_OPTION_VALUES)?;
+
+    Ok(value.map(|value| match Unit::from_str(&value) {
+        Some(unit) => UnitValue::Unit(unit),
+        None => UnitValue::Auto,
+    }))
+}
+
+/// ValidateTemporalUnitValue (https://tc39.es/proposal-temporal/#sec-temporal-validatetemporalunitvaluedoption)
+pub fn validate_unit_value(
+    cx: Context,
+    value: Option<UnitValue>,
+    unit_group: UnitGroup,
+    extra_values: &[UnitValue],
+) -> EvalResult<()> {
+    let value = match value {
+        None => return Ok(()),
+        Some(value) if extra_values.contains(&value) => return Ok(()),
+        Some(value) => value,
+    };
+
+    let is_valid = match value {
+        UnitValue::Auto => false,
+        UnitValue::Unit(unit) if unit.is_date_unit() => {
+            matches!(unit_group, UnitGroup::Date | UnitGroup::DateTime)
+        }
+        UnitValue::Unit(_) => matches!(unit_group, UnitGroup::Time | UnitGroup::DateTime),
+    };
+
+    if !is_valid {
+        let name = match value {
+            UnitValue::Auto => "auto",
+            UnitValue::Unit(unit) => unit.as_str(),
+        };
+        return range_error(
+            cx,
+            &format!("{name} is not a valid unit for this operation"),
+        );
+    }
+
+    Ok(())
+}
+
+/// ValidateTemporalRoundingIncrement (https://tc39.es/proposal-temporal/#sec-temporal-validatetemporalroundingincrement)
+pub fn validate_rounding_increment(
+    cx: Context,
+    increment: i64,
+    dividend: i64,
+    inclusive: bool,
+) -> EvalResult<()> {
+    let maximum = if inclusive { dividend } else { dividend - 1 };
+    if increment > maximum {
+        return range_error(cx, "roundingIncrement is out of range");
+    }
+
+    if dividend % increment != 0 {
+        return range_error(cx, "roundingIncrement must evenly divide the unit");
+    }
+
+    Ok(())
+}
+
+/// NegateRoundingMode (https://tc39.es/proposal-temporal/#sec-temporal-negateroundingmode)
+pub fn negate_rounding_mode(rounding_mode: RoundingMode) -> RoundingMode {
+    match rounding_mode {
+        RoundingMode::Ceil => RoundingMode::Floor,
+        RoundingMode::Floor => RoundingMode::Ceil,
+        RoundingMode::HalfCeil => RoundingMode::HalfFloor,
+        RoundingMode::HalfFloor => RoundingMode::HalfCeil,
+        other => other,
+    }
+}
+
+/// Whether a difference is computed from the receiver to the argument or the reverse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DifferenceOperation {
+    Until,
+    Since,
+}
+
+/// The resolved options of an `until` or `since` method.
+#[derive(Clone, Copy, Debug)]
+pub struct DifferenceSettings {
+    pub largest_unit: Unit,
+    pub smallest_unit: Unit,
+    pub rounding_mode: RoundingMode,
+    pub rounding_increment: i64,
+}
+
+/// GetDifferenceSettings (https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings)
+pub fn get_difference_settings(
+    cx: Context,
+    operation: DifferenceOperation,
+    options: StackRoot<ObjectValue>,
+    unit_group: UnitGroup,
+    disallowed_units: &[Unit],
+    fallback_smallest_unit: Unit,
+    smallest_largest_default_unit: Unit,
+) -> EvalResult<DifferenceSettings> {
+    // Options are read in alphabetical order
+    let largest_unit = get_unit_valued_option(cx, options, cx.names.largest_unit())?;
+    let rounding_increment = get_rounding_increment_option(cx, options)?;
+    let mut rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+    let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+
+    validate_unit_value(cx, largest_unit, unit_group, &[UnitValue::Auto])?;
+
+    let largest_unit = largest_unit.unwrap_or(UnitValue::Auto);
+    if let UnitValue::Unit(unit) = largest_unit {
+        if disallowed_units.contains(&unit) {
+            return range_error(cx, &format!("largestUnit cannot be {}", unit.as_str()));
+        }
+    }
+
+    if operation == DifferenceOperation::Since {
+        rounding_mode = negate_rounding_mode(rounding_mode);
+    }
+
+    validate_unit_value(cx, smallest_unit, unit_group, &[])?;
+
+    let smallest_unit = match smallest_unit {
+        Some(UnitValue::Unit(unit)) => unit,
+        _ => fallback_smallest_unit,
+    };
+    if disallowed_units.contains(&smallest_unit) {
+        return range_error(
+            cx,
+            &format!("smallestUnit cannot be {}", smallest_unit.as_str()),
+        );
+    }
+
+    let default_largest_unit = smallest_largest_default_unit.max(smallest_unit);
+    let largest_unit = match largest_unit {
+        UnitValue::Auto => default_largest_unit,
+        UnitValue::Unit(unit) => unit,
+    };
+
+    if largest_unit < smallest_unit {
+        return range_error(cx, "smallestUnit must not be larger than largestUnit");
+    }
+
+    if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+        validate_rounding_increment(cx, rounding_increment, maximum, false)?;
+    }
+
+    Ok(DifferenceSettings {
+        largest_unit,
+        smallest_unit,
+        rounding_mode,
+        rounding_increment,
+    })
+}
@@ -0,0 +1,774 @@
+//! Parsing of ISO 8601 and RFC 9557 strings accepted by Temporal.
+//!
+//! Temporal ISO 8601 grammar (https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar)
+
+use alloc::string::{String, ToString};
+
+use super::{
+    duration::Duration,
+    iso::{is_valid_iso_date, IsoTime, NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SECOND},
+};
+
+/// The kinds of strings that can be parsed, which determine the allowed forms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseGoal {
+    /// A date and time with a UTC offset or `Z`
+    Instant,
+    /// A date and optional time with a time zone annotation
+    ZonedDateTime,
+    /// A date and optional time, without a `Z` designator
+    DateTime,
+    /// A date-time that may be zoned, for the `relativeTo` option
+    RelativeTo,
+    /// A time, or a date and time
+    Time,
+    /// A year and month, or a date and optional time
+    YearMonth,
+    /// A month and day, or a date and optional time
+    MonthDay,
+}
+
+/// A UTC offset in a date-time string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParsedOffset {
+    /// The UTC designator `Z`
+    Utc,
+    /// A numeric offset in nanoseconds, and whether it was written with seconds
+    Offset { nanoseconds: i64, has_seconds: bool },
+}
+
+/// A time zone in a time zone annotation or time zone identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsedTimeZone {
+    /// An offset in minutes
+    Offset(i64),
+    /// An IANA time zone name
+    Name(String),
+}
+
+/// The result of parsing a date-time string.
+///
+/// ParseISODateTime (https://tc39.es/proposal-temporal/#sec-temporal-parseisodatetime)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedDateTime {
+    /// The year, which is None for month-day strings without a year
+    pub year: Option<i64>,
+    pub month: u8,
+    /// The day, which is 1 for year-month strings without a day
+    pub day: u8,
+    /// The time, which is None if no time was present
+    pub time: Option<IsoTime>,
+    pub offset: Option<ParsedOffset>,
+    pub time_zone: Option<ParsedTimeZone>,
+    pub calendar: Option<String>,
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(string: &'a str) -> Self {
+        Parser {
+            bytes: string.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_any(&mut self, bytes: &[u8]) -> bool {
+        match self.peek() {
+            Some(byte) if bytes.contains(&byte) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek_is_digit(&self) -> bool {
+        self.peek().is_some_and(|byte| byte.is_ascii_digit())
+    }
+
+    /// Parse exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<i64> {
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self.peek().filter(u8::is_ascii_digit)?;
+            value = value * 10 + (byte - b'0') as i64;
+            self.pos += 1;
+        }
+
+        Some(value)
+    }
+
+    /// Parse two digits within an inclusive range.
+    fn two_digits_in_range(&mut self, max: i64) -> Option<i64> {
+        let value = self.digits(2)?;
+        if value > max {
+            return None;
+        }
+
+        Some(value)
+    }
+
+    /// Parse a decimal fraction of 1 to 9 digits after a `.` or `,` separator, returning the
+    /// fraction in nanoseconds. Returns Some(None) if there is no fraction.
+    fn fraction(&mut self) -> Option<Option<i64>> {
+        if !self.eat_any(b".,") {
+            return Some(None);
+        }
+
+        let start = self.pos;
+        let mut nanoseconds = 0;
+        while self.peek_is_digit() {
+            if self.pos - start == 9 {
+                return None;
+            }
+
+            nanoseconds = nanoseconds * 10 + (self.peek().unwrap() - b'0') as i64;
+            self.pos += 1;
+        }
+
+        let num_digits = self.pos - start;
+        if num_digits == 0 {
+            return None;
+        }
+
+        Some(Some(nanoseconds * 10_i64.pow(9 - num_digits as u32)))
+    }
+
+    /// DateYear: four digits, or a sign followed by six digits. Negative zero is not allowed.
+    fn year(&mut self) -> Option<i64> {
+        let sign = match self.peek()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return self.digits(4),
+        };
+
+        self.pos += 1;
+        let year = self.digits(6)?;
+        if sign == -1 && year == 0 {
+            return None;
+        }
+
+        Some(sign * year)
+    }
+
+    fn month(&mut self) -> Option<u8> {
+        let month = self.digits(2)?;
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+
+        Some(month as u8)
+    }
+
+    fn day(&mut self) -> Option<u8> {
+        let day = self.digits(2)?;
+        if !(1..=31).contains(&day) {
+            return None;
+        }
+
+        Some(day as u8)
+    }
+
+    /// Date: a year, month, and day in extended `YYYY-MM-DD` or basic `YYYYMMDD` format.
+    fn date(&mut self) -> Option<(i64, u8, u8)> {
+        let year = self.year()?;
+        let is_extended = self.eat(b'-');
+        let month = self.month()?;
+        if is_extended && !self.eat(b'-') {
+            return None;
+        }
+
+        let day = self.day()?;
+
+        Some((year, month, day))
+    }
+
+    /// Time: an hour with optional minutes, seconds, and fraction, in extended `HH:MM:SS` or basic
+    /// `HHMMSS` format. A leap second of 60 is treated as 59.
+    fn time(&mut self) -> Option<IsoTime> {
+        let hour = self.two_digits_in_range(23)?;
+        let mut minute = 0;
+        let mut second = 0;
+        let mut fraction = None;
+
+        let is_extended = self.peek() == Some(b':');
+        if (is_extended && self.eat(b':')) || (!is_extended && self.peek_is_digit()) {
+            minute = self.two_digits_in_range(59)?;
+
+            if (is_extended && self.eat(b':')) || (!is_extended && self.peek_is_digit()) {
+                second = self.two_digits_in_range(60)?.min(59);
+                fraction = self.fraction()?;
+            }
+        }
+
+        let fraction = fraction.unwrap_or(0);
+
+        Some(IsoTime::new(
+            hour as u8,
+            minute as u8,
+            second as u8,
+            (fraction / 1_000_000) as u16,
+            (fraction / 1_000 % 1_000) as u16,
+            (fraction % 1_000) as u16,
+        ))
+    }
+
+    /// UTCOffset: a sign and hours, with optional minutes, and optional seconds and fraction if
+    /// sub-minute precision is allowed.
+    fn utc_offset(&mut self, allow_sub_minute: bool) -> Option<ParsedOffset> {
+        let sign = match self.peek()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+
+        self.pos += 1;
+
+        let hours = self.two_digits_in_range(23)?;
+        let mut nanoseconds = hours as i128 * NS_PER_HOUR;
+        let mut has_seconds = false;
+
+        let is_extended = self.peek() == Some(b':');
+        if (is_extended && self.eat(b':')) || (!is_extended && self.peek_is_digit()) {
+            nanoseconds += self.two_digits_in_range(59)? as i128 * NS_PER_MINUTE;
+
+            let has_more = (is_extended && self.peek() == Some(b':'))
+                || (!is_extended && self.peek_is_digit());
+            if allow_sub_minute && has_more {
+                if is_extended {
+                    self.pos += 1;
+                }
+
+                nanoseconds += self.two_digits_in_range(59)? as i128 * NS_PER_SECOND;
+                nanoseconds += self.fraction()?.unwrap_or(0) as i128;
+                has_seconds = true;
+            }
+        }
+
+        Some(ParsedOffset::Offset {
+            nanoseconds: (sign * nanoseconds) as i64,
+            has_seconds,
+        })
+    }
+
+    /// DateTimeUTCOffset: `Z` or a UTC offset with optional sub-minute precision.
+    fn date_time_utc_offset(&mut self) -> Option<Option<ParsedOffset>> {
+        if self.eat_any(b"Zz") {
+            return Some(Some(ParsedOffset::Utc));
+        }
+
+        match self.peek() {
+            Some(b'+' | b'-') => Some(Some(self.utc_offset(true)?)),
+            _ => Some(None),
+        }
+    }
+
+    /// Annotations: an optional time zone annotation followed by any number of key-value
+    /// annotations, of which only calendar annotations are recognized.
+    fn annotations(&mut self) -> Option<(Option<ParsedTimeZone>, Option<String>)> {
+        let mut time_zone = None;
+        let mut calendar: Option<String> = None;
+        let mut has_critical_calendar = false;
+        let mut num_calendars = 0;
+        let mut is_first = true;
+
+        while self.eat(b'[') {
+            let is_critical = self.eat(b'!');
+            let start = self.pos;
+            let end = start + self.bytes[start..].iter().position(|byte| *byte == b']')?;
+            let contents = core::str::from_utf8(&self.bytes[start..end]).ok()?;
+            self.pos = end + 1;
+
+            match contents.split_once('=') {
+                Some((key, value)) => {
+                    if !is_annotation_key(key) || !is_annotation_value(value) {
+                        return None;
+                    }
+
+                    if key == "u-ca" {
+                        num_calendars += 1;
+                        has_critical_calendar |= is_critical;
+                        if calendar.is_none() {
+                            calendar = Some(value.to_string());
+                        }
+                    } else if is_critical {
+                        // Unknown critical annotations must be rejected
+                        return None;
+                    }
+                }
+                None => {
+                    // A time zone annotation may only appear before all other annotations
+                    if !is_first {
+                        return None;
+                    }
+
+                    time_zone = Some(parse_time_zone_identifier(contents)?);
+                }
+            }
+
+            is_first = false;
+        }
+
+        if num_calendars > 1 && has_critical_calendar {
+            return None;
+        }
+
+        Some((time_zone, calendar))
+    }
+}
+
+/// AnnotationKey: a lowercase letter or underscore, followed by lowercase letters, digits,
+/// underscores, or hyphens.
+fn is_annotation_key(key: &str) -> bool {
+    let mut bytes = key.bytes();
+    match bytes.next() {
+        Some(byte) if byte.is_ascii_lowercase() || byte == b'_' => {}
+        _ => return false,
+    }
+
+    bytes.all(|byte| {
+        byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_' || byte == b'-'
+    })
+}
+
+/// AnnotationValue: alphanumeric components separated by hyphens.
+fn is_annotation_value(value: &str) -> bool {
+    value.split('-').all(|component| {
+        !component.is_empty() && component.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+/// TimeZoneIANAName: components separated by slashes, where each component starts with a letter,
+/// period, or underscore and is followed by letters, digits, periods, underscores, hyphens, or
+/// plus signs. Components may not be `.` or `..`.
+fn is_time_zone_iana_name(name: &str) -> bool {
+    name.split('/').all(|component| {
+        let mut bytes = component.bytes();
+        let is_valid_leading =
+            matches!(bytes.next(), Some(byte) if byte.is_ascii_alphabetic() || byte == b'.' || byte == b'_');
+
+        is_valid_leading
+            && bytes.all(|byte| {
+                byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-' | b'+')
+            })
+            && component != "."
+            && component != ".."
+    })
+}
+
+/// Parse a time zone identifier, which is either a UTC offset with at most minute precision or an
+/// IANA time zone name. Returns None if the identifier is not syntactically valid.
+///
+/// ParseTimeZoneIdentifier (https://tc39.es/proposal-temporal/#sec-parsetimezoneidentifier)
+pub fn parse_time_zone_identifier(identifier: &str) -> Option<ParsedTimeZone> {
+    if identifier.starts_with(['+', '-']) {
+        let mut parser = Parser::new(identifier);
+        let offset = parser.utc_offset(false)?;
+        if !parser.is_at_end() {
+            return None;
+        }
+
+        let ParsedOffset::Offset { nanoseconds, .. } = offset else {
+            return None;
+        };
+
+        return Some(ParsedTimeZone::Offset(nanoseconds / NS_PER_MINUTE as i64));
+    }
+
+    if !is_time_zone_iana_name(identifier) {
+        return None;
+    }
+
+    Some(ParsedTimeZone::Name(identifier.to_string()))
+}
+
+/// Parse a UTC offset with optional sub-minute precision, returning the offset in nanoseconds.
+///
+/// ParseDateTimeUTCOffset (https://tc39.es/proposal-temporal/#sec-temporal-parsedatetimeutcoffset)
+pub fn parse_date_time_utc_offset(string: &str) -> Option<ParsedOffset> {
+    let mut parser = Parser::new(string);
+    let offset = parser.utc_offset(true)?;
+    if !parser.is_at_end() {
+        return None;
+    }
+
+    Some(offset)
+}
+
+/// Parse a date and optional time, UTC offset, and annotations. The time is required if
+/// `time_required` is set.
+fn parse_annotated_date_time(string: &str, time_required: bool) -> Option<ParsedDateTime> {
+    let mut parser = Parser::new(string);
+    let (year, month, day) = parser.date()?;
+
+    let mut time = None;
+    let mut offset = None;
+    if parser.eat_any(b"Tt ") {
+        time = Some(parser.time()?);
+        offset = parser.date_time_utc_offset()?;
+    } else if time_required {
+        return None;
+    }
+
+    let (time_zone, calendar) = parser.annotations()?;
+    if !parser.is_at_end() || !is_valid_iso_date(year, month as i64, day as i64) {
+        return None;
+    }
+
+    Some(ParsedDateTime {
+        year: Some(year),
+        month,
+        day,
+        time,
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Parse a year and month without a day, e.g. `2021-07`.
+fn parse_annotated_year_month(string: &str) -> Option<ParsedDateTime> {
+    let mut parser = Parser::new(string);
+    let year = parser.year()?;
+    parser.eat(b'-');
+    let month = parser.month()?;
+
+    let (time_zone, calendar) = parser.annotations()?;
+    if !parser.is_at_end() {
+        return None;
+    }
+
+    Some(ParsedDateTime {
+        year: Some(year),
+        month,
+        day: 1,
+        time: None,
+        offset: None,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Parse a month and day without a year, e.g. `07-14` or `--07-14`.
+fn parse_annotated_month_day(string: &str) -> Option<ParsedDateTime> {
+    let mut parser = Parser::new(string);
+    if parser.eat(b'-') && !parser.eat(b'-') {
+        return None;
+    }
+
+    let month = parser.month()?;
+    parser.eat(b'-');
+    let day = parser.day()?;
+
+    let (time_zone, calendar) = parser.annotations()?;
+
+    // Validate the day against a leap year so that February 29 is allowed
+    if !parser.is_at_end() || !is_valid_iso_date(1972, month as i64, day as i64) {
+        return None;
+    }
+
+    Some(ParsedDateTime {
+        year: None,
+        month,
+        day,
+        time: None,
+        offset: None,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Parse a time without a date, with an optional `T` designator.
+fn parse_annotated_time(string: &str) -> Option<ParsedDateTime> {
+    let mut parser = Parser::new(string);
+    let has_designator = parser.eat_any(b"Tt");
+    let time = parser.time()?;
+    let offset = parser.date_time_utc_offset()?;
+    let (time_zone, calendar) = parser.annotations()?;
+    if !parser.is_at_end() {
+        return None;
+    }
+
+    // Times without a designator must not be ambiguous with a year-month or month-day
+    if !has_designator
+        && (parse_annotated_year_month(string).is_some()
+            || parse_annotated_month_day(string).is_some())
+    {
+        return None;
+    }
+
+    Some(ParsedDateTime {
+        year: None,
+        month: 1,
+        day: 1,
+        time: Some(time),
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Whether a calendar annotation is absent or is the ISO 8601 calendar.
+fn is_iso_calendar_annotation(calendar: &Option<String>) -> bool {
+    calendar
+        .as_ref()
+        .is_none_or(|calendar| calendar.eq_ignore_ascii_case("iso8601"))
+}
+
+/// ParseISODateTime (https://tc39.es/proposal-temporal/#sec-temporal-parseisodatetime)
+///
+/// Returns None if the string does not match any of the forms allowed by the goal.
+pub fn parse_iso_date_time(string: &str, goal: ParseGoal) -> Option<ParsedDateTime> {
+    let result = match goal {
+        ParseGoal::Instant => {
+            let result = parse_annotated_date_time(string, true)?;
+            result.offset?;
+            result
+        }
+        ParseGoal::ZonedDateTime => {
+            let result = parse_annotated_date_time(string, false)?;
+            result.time_zone.as_ref()?;
+            result
+        }
+        ParseGoal::DateTime => parse_annotated_date_time(string, false)?,
+        ParseGoal::RelativeTo => {
+            let result = parse_annotated_date_time(string, false)?;
+
+            // The UTC designator is only allowed for zoned date-times
+            if result.offset == Some(ParsedOffset::Utc) && result.time_zone.is_none() {
+                return None;
+            }
+
+            return Some(result);
+        }
+        ParseGoal::Time => {
+            parse_annotated_time(string).or_else(|| parse_annotated_date_time(string, true))?
+        }
+        ParseGoal::YearMonth => match parse_annotated_year_month(string) {
+            Some(result) if is_iso_calendar_annotation(&result.calendar) => result,
+            Some(_) => return None,
+            None => parse_annotated_date_time(string, false)?,
+        },
+        ParseGoal::MonthDay => match parse_annotated_month_day(string) {
+            Some(result) if is_iso_calendar_annotation(&result.calendar) => result,
+            Some(_) => return None,
+            None => parse_annotated_date_time(string, false)?,
+        },
+    };
+
+    // The UTC designator is only allowed for exact times
+    if result.offset == Some(ParsedOffset::Utc)
+        && !matches!(goal, ParseGoal::Instant | ParseGoal::ZonedDateTime)
+    {
+        return None;
+    }
+
+    Some(result)
+}
+
+/// Parse the calendar annotation of a date-time string, or return None if the string is not a
+/// valid date-time string. Strings without a calendar annotation use the ISO 8601 calendar.
+///
+/// ParseTemporalCalendarString (https://tc39.es/proposal-temporal/#sec-temporal-parsetemporalcalendarstring)
+pub fn parse_calendar_string(string: &str) -> Option<String> {
+    let goals = [
+        ParseGoal::ZonedDateTime,
+        ParseGoal::Instant,
+        ParseGoal::DateTime,
+        ParseGoal::Time,
+        ParseGoal::YearMonth,
+        ParseGoal::MonthDay,
+    ];
+
+    let result = goals
+        .iter()
+        .find_map(|goal| parse_iso_date_time(string, *goal))?;
+
+    Some(result.calendar.unwrap_or_else(|| String::from("iso8601")))
+}
+
+/// Parse the time zone of a date-time string, using the time zone annotation if present and
+/// otherwise the UTC designator or offset. Returns None if the string is not a valid date-time
+/// string or has no time zone.
+///
+/// ParseTemporalTimeZoneString (https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimezonestring)
+pub fn parse_time_zone_string(string: &str) -> Option<ParsedTimeZone> {
+    if let Some(time_zone) = parse_time_zone_identifier(string) {
+        return Some(time_zone);
+    }
+
+    let goals = [
+        ParseGoal::ZonedDateTime,
+        ParseGoal::Instant,
+        ParseGoal::DateTime,
+        ParseGoal::Time,
+        ParseGoal::YearMonth,
+        ParseGoal::MonthDay,
+    ];
+
+    let result = goals
+        .iter()
+        .find_map(|goal| parse_iso_date_time(string, *goal))?;
+
+    if let Some(time_zone) = result.time_zone {
+        return Some(time_zone);
+    }
+
+    match result.offset? {
+        ParsedOffset::Utc => Some(ParsedTimeZone::Name(String::from("UTC"))),
+        ParsedOffset::Offset {
+            nanoseconds,
+            has_seconds,
+        } => {
+            if has_seconds {
+                return None;
+            }
+
+            Some(ParsedTimeZone::Offset(nanoseconds / NS_PER_MINUTE as i64))
+        }
+    }
+}
+
+/// Parse a duration decimal number, returning None if there are no digits.
+fn duration_digits(parser: &mut Parser) -> Option<f64> {
+    let start = parser.pos;
+    while parser.peek_is_digit() {
+        parser.pos += 1;
+    }
+
+    if parser.pos == start {
+        return None;
+    }
+
+    let digits = core::str::from_utf8(&parser.bytes[start..parser.pos]).ok()?;
+    digits.parse::<f64>().ok()
+}
+
+/// ParseTemporalDurationString (https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaldurationstring)
+///
+/// Returns None if the string is not a valid duration string. The duration may not be valid.
+pub fn parse_duration_string(string: &str) -> Option<Duration> {
+    let mut parser = Parser::new(string);
+    let sign = if parser.eat(b'-') {
+        -1.0
+    } else {
+        parser.eat(b'+');
+        1.0
+    };
+
+    if !parser.eat_any(b"Pp") {
+        return None;
+    }
+
+    let mut fields = [0.0; 10];
+    let mut has_any_component = false;
+
+    // Date components must appear in order, each at most once
+    let date_designators = [b'Y', b'M', b'W', b'D'];
+    let mut next_date_index = 0;
+    while parser.peek_is_digit() {
+        let value = duration_digits(&mut parser)?;
+        let designator = parser.peek()?.to_ascii_uppercase();
+        let index = date_designators[next_date_index..]
+            .iter()
+            .position(|d| *d == designator)?
+            + next_date_index;
+
+        parser.pos += 1;
+        fields[index] = value;
+        next_date_index = index + 1;
+        has_any_component = true;
+    }
+
+    if parser.eat_any(b"Tt") {
+        let time_designators = [b'H', b'M', b'S'];
+        let mut next_time_index = 0;
+        let mut has_time_component = false;
+
+        while parser.peek_is_digit() {
+            let value = duration_digits(&mut parser)?;
+            let fraction = parser.fraction()?;
+
+            let designator = parser.peek()?.to_ascii_uppercase();
+            let index = time_designators[next_time_index..]
+                .iter()
+                .position(|d| *d == designator)?
+                + next_time_index;
+
+            parser.pos += 1;
+            fields[4 + index] = value;
+            next_time_index = index + 1;
+            has_time_component = true;
+
+            // A fractional component must be the last component, and is balanced into the
+            // smaller units.
+            if let Some(fraction) = fraction {
+                if !parser.is_at_end() {
+                    return None;
+                }
+
+                let unit_nanoseconds = [NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SECOND][index];
+                let fraction_nanoseconds = fraction as i128 * unit_nanoseconds / NS_PER_SECOND;
+
+                let mut remaining = fraction_nanoseconds;
+                for (field_index, nanoseconds) in [
+                    (5, NS_PER_MINUTE),
+                    (6, NS_PER_SECOND),
+                    (7, 1_000_000),
+                    (8, 1_000),
+                    (9, 1),
+                ] {
+                    if field_index <= 4 + index {
+                        continue;
+                    }
+
+                    fields[field_index] = (remaining / nanoseconds) as f64;
+                    remaining %= nanoseconds;
+                }
+            }
+        }
+
+        if !has_time_component {
+            return None;
+        }
+
+        has_any_component = true;
+    }
+
+    if !has_any_component || !parser.is_at_end() {
+        return None;
+    }
+
+    Some(Duration::from_fields(fields.map(|field| {
+        if field == 0.0 {
+            0.0
+        } else {
+            field * sign
+        }
+    })))
+}
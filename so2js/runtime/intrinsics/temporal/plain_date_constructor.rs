@@ -0,0 +1,231 @@
+use core::mem::size_of;
+
+use alloc::format;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        prepare_calendar_fields, to_calendar_argument, RequiredFields, DATE_FIELDS,
+    },
+    iso::{is_valid_iso_date, iso_date_within_limits, IsoDate},
+    options::{get_overflow_option, to_integer_with_truncation},
+    parser::{parse_iso_date_time, ParseGoal},
+};
+
+// Temporal.PlainDate Objects (https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects)
+extend_object! {
+    pub struct PlainDateObject {
+        date: IsoDate,
+    }
+}
+
+impl PlainDateObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        date: IsoDate,
+    ) -> AllocResult<StackRoot<PlainDateObject>> {
+        let mut object =
+            object_create_with_proto::<PlainDateObject>(cx, HeapItemKind::PlainDateObject, proto)?;
+
+        set_uninit!(object.date, date);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PlainDateObject, "Temporal.PlainDate");
+
+    pub fn date(&self) -> IsoDate {
+        self.date
+    }
+}
+
+/// CreateTemporalDate (https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldate)
+pub fn create_temporal_date(cx: Context, date: IsoDate) -> EvalResult<StackRoot<PlainDateObject>> {
+    if !iso_date_within_limits(date) {
+        return range_error(cx, "date is outside the supported range");
+    }
+
+    let proto = cx.get_intrinsic(Intrinsic::PlainDatePrototype);
+    Ok(PlainDateObject::new_with_proto(cx, proto, date)?)
+}
+
+/// ToTemporalDate (https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate)
+///
+/// Options may be undefined. The returned date is within the supported range.
+pub fn to_temporal_date(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<IsoDate> {
+    if item.is_object() {
+        let object = item.as_object();
+
+        let date = if let Some(plain_date) = object.as_plain_date_object() {
+            plain_date.date()
+        } else if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+            zoned_date_time.iso_date_time(cx).date
+        } else if let Some(plain_date_time) = object.as_plain_date_time_object() {
+            plain_date_time.date_time().date
+        } else {
+            get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+            let fields =
+                prepare_calendar_fields(cx, object, DATE_FIELDS, &[], RequiredFields::List(&[]))?;
+
+            let options = get_options_object(cx, options)?;
+            let overflow = get_overflow_option(cx, options)?;
+
+            return fields.to_date(cx, overflow);
+        };
+
+        let options = get_options_object(cx, options)?;
+        get_overflow_option(cx, options)?;
+
+        return Ok(date);
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "PlainDate must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let Some(parsed) = parse_iso_date_time(&string, ParseGoal::DateTime) else {
+        return range_error(cx, &format!("invalid PlainDate {string}"));
+    };
+
+    if let Some(calendar) = &parsed.calendar {
+        canonicalize_calendar(cx, calendar)?;
+    }
+
+    let options = get_options_object(cx, options)?;
+    get_overflow_option(cx, options)?;
+
+    let date = IsoDate::new(parsed.year.unwrap(), parsed.month, parsed.day);
+    if !iso_date_within_limits(date) {
+        return range_error(cx, "date is outside the supported range");
+    }
+
+    Ok(date)
+}
+
+pub struct PlainDateConstructor;
+
+impl PlainDateConstructor {
+    /// Properties of the Temporal.PlainDate Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaindate-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            3,
+            cx.names.plain_date(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::PlainDatePrototype).into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.PlainDate (https://tc39.es/proposal-temporal/#sec-temporal.plaindate)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Temporal.PlainDate constructor must be called with new");
+        };
+
+        let year = to_integer_with_truncation(cx, get_argument(cx, arguments, 0))?;
+        let month = to_integer_with_truncation(cx, get_argument(cx, arguments, 1))?;
+        let day = to_integer_with_truncation(cx, get_argument(cx, arguments, 2))?;
+
+        to_calendar_argument(cx, get_argument(cx, arguments, 3))?;
+
+        if !is_valid_iso_date_fields(year, month, day) {
+            return range_error(cx, "date is not valid");
+        }
+
+        let date = IsoDate::new(year as i64, month as u8, day as u8);
+        if !iso_date_within_limits(date) {
+            return range_error(cx, "date is outside the supported range");
+        }
+
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::PlainDatePrototype)?;
+
+        Ok(PlainDateObject::new_with_proto(cx, proto, date)?.as_value())
+    }
+
+    /// Temporal.PlainDate.compare (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_date(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+        let two = to_temporal_date(cx, get_argument(cx, arguments, 1), cx.undefined())?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.PlainDate.from (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = to_temporal_date(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_date(cx, date)?.as_value())
+    }
+}
+
+/// IsValidISODate (https://tc39.es/proposal-temporal/#sec-temporal-isvalidisodate) for integral
+/// Numbers, which may be too large to convert to integers directly.
+pub fn is_valid_iso_date_fields(year: f64, month: f64, day: f64) -> bool {
+    year.abs() <= 1e9 && is_valid_iso_date(year as i64, month as i64, day as i64)
+}
+
+impl HeapItem for HeapPtr<PlainDateObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PlainDateObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
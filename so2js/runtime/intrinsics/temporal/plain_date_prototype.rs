@@ -0,0 +1,631 @@
+use alloc::string::ToString;
+
+use crate::runtime::{
+    abstract_operations::get,
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{
+        check_partial_temporal_object, iso_date_to_fields, prepare_calendar_fields,
+        to_temporal_calendar_identifier, FieldsType, RequiredFields, DATE_FIELDS, ISO8601,
+    },
+    duration::{round_relative_duration, InternalDuration},
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{date_to_string, format_with_locales},
+    iso::{
+        add_iso_date, days_in_month, days_in_year, difference_iso_date,
+        iso_date_time_within_limits, IsoDate, IsoDateTime, MIDNIGHT,
+    },
+    options::{
+        get_difference_settings, get_overflow_option, get_show_calendar_option,
+        DifferenceOperation, Disambiguation, ShowCalendar,
+    },
+    plain_date_constructor::{create_temporal_date, to_temporal_date, PlainDateObject},
+    plain_date_time_constructor::create_temporal_date_time,
+    plain_month_day_constructor::create_temporal_month_day,
+    plain_time_constructor::{to_temporal_time, to_time_record_or_midnight},
+    plain_year_month_constructor::create_temporal_year_month,
+    rounding::{Unit, UnitGroup},
+    time_zone::{
+        get_epoch_nanoseconds_for, get_start_of_day, resolve_time_zone,
+        to_temporal_time_zone_identifier,
+    },
+    zoned_date_time_constructor::create_temporal_zoned_date_time,
+};
+
+pub struct PlainDatePrototype;
+
+impl PlainDatePrototype {
+    /// Properties of the Temporal.PlainDate Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaindate-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PlainDateConstructor has been created
+        object.intrinsic_getter(cx, cx.names.calendar_id(), Self::calendar_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.day(), Self::day, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_week(), Self::day_of_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_year(), Self::day_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_month(), Self::days_in_month, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_week(), Self::days_in_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_year(), Self::days_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.era(), Self::era, realm)?;
+        object.intrinsic_getter(cx, cx.names.era_year(), Self::era_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.in_leap_year(), Self::in_leap_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.month(), Self::month, realm)?;
+        object.intrinsic_getter(cx, cx.names.month_code(), Self::month_code, realm)?;
+        object.intrinsic_getter(cx, cx.names.months_in_year(), Self::months_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.week_of_year(), Self::week_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year(), Self::year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year_of_week(), Self::year_of_week, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_plain_date_time(),
+            Self::to_plain_date_time,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_plain_month_day(),
+            Self::to_plain_month_day,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_plain_year_month(),
+            Self::to_plain_year_month,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_zoned_date_time(),
+            Self::to_zoned_date_time,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.with_calendar(), Self::with_calendar, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.PlainDate")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.PlainDate.prototype.calendarId (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.calendarid)
+    pub fn calendar_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(ISO8601)?.as_value())
+    }
+
+    /// get Temporal.PlainDate.prototype.era (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.era)
+    ///
+    /// The ISO 8601 calendar has no eras.
+    pub fn era(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainDate.prototype.eraYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.erayear)
+    pub fn era_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainDate.prototype.year (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.year)
+    pub fn year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.year as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.month (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.month)
+    pub fn month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.month as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.monthCode (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.monthcode)
+    pub fn month_code(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        let month_code = iso_date_to_fields(&date, FieldsType::Date)
+            .month_code
+            .unwrap();
+
+        Ok(cx.alloc_string(&month_code.to_string())?.as_value())
+    }
+
+    /// get Temporal.PlainDate.prototype.day (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.day)
+    pub fn day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.day as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.dayOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.dayofweek)
+    pub fn day_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.day_of_week() as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.dayOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.dayofyear)
+    pub fn day_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.day_of_year() as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.weekOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.weekofyear)
+    pub fn week_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.week_of_year().0 as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.yearOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.yearofweek)
+    pub fn year_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.week_of_year().1 as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.daysInWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinweek)
+    pub fn days_in_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(7))
+    }
+
+    /// get Temporal.PlainDate.prototype.daysInMonth (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinmonth)
+    pub fn days_in_month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(days_in_month(date.year, date.month) as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.daysInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinyear)
+    pub fn days_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(days_in_year(date.year) as i32))
+    }
+
+    /// get Temporal.PlainDate.prototype.monthsInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.monthsinyear)
+    pub fn months_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(12))
+    }
+
+    /// get Temporal.PlainDate.prototype.inLeapYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.inleapyear)
+    pub fn in_leap_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.bool(days_in_year(date.year) == 366))
+    }
+
+    /// Temporal.PlainDate.prototype.toPlainYearMonth (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplainyearmonth)
+    pub fn to_plain_year_month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        let year_month = IsoDate::new(date.year, date.month, 1);
+
+        Ok(create_temporal_year_month(cx, year_month)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.toPlainMonthDay (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplainmonthday)
+    pub fn to_plain_month_day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateObject::cast_from_value(cx, this_value)?.date();
+        let month_day = IsoDate::new(1972, date.month, date.day);
+
+        Ok(create_temporal_month_day(cx, month_day)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        add_duration_to_date(
+            cx,
+            plain_date,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            false,
+        )
+    }
+
+    /// Temporal.PlainDate.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        add_duration_to_date(
+            cx,
+            plain_date,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            true,
+        )
+    }
+
+    /// Temporal.PlainDate.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+
+        let date_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, date_like)?;
+
+        let fields = iso_date_to_fields(&plain_date.date(), FieldsType::Date);
+        let partial_fields = prepare_calendar_fields(
+            cx,
+            date_like.as_object(),
+            DATE_FIELDS,
+            &[],
+            RequiredFields::Partial,
+        )?;
+        let fields = fields.merge(&partial_fields);
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let date = fields.to_date(cx, overflow)?;
+
+        Ok(create_temporal_date(cx, date)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.withCalendar (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.withcalendar)
+    pub fn with_calendar(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        to_temporal_calendar_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(create_temporal_date(cx, plain_date.date())?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_date(
+            cx,
+            DifferenceOperation::Until,
+            plain_date,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainDate.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_date(
+            cx,
+            DifferenceOperation::Since,
+            plain_date,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainDate.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_date(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        Ok(cx.bool(plain_date.date() == other))
+    }
+
+    /// Temporal.PlainDate.prototype.toPlainDateTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplaindatetime)
+    pub fn to_plain_date_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let time = to_time_record_or_midnight(cx, get_argument(cx, arguments, 0))?;
+        let date_time = IsoDateTime::new(plain_date.date(), time);
+
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.toZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tozoneddatetime)
+    pub fn to_zoned_date_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let item = get_argument(cx, arguments, 0);
+
+        // The argument is either a time zone, or an object with a time zone and optional time
+        let (time_zone, time) = if item.is_object() {
+            let time_zone_like = get(cx, item.as_object(), cx.names.time_zone())?;
+            if time_zone_like.is_undefined() {
+                (to_temporal_time_zone_identifier(cx, item)?, None)
+            } else {
+                let time_zone = to_temporal_time_zone_identifier(cx, time_zone_like)?;
+                let time = get(cx, item.as_object(), cx.names.plain_time_())?;
+                (time_zone, Some(time))
+            }
+        } else {
+            (to_temporal_time_zone_identifier(cx, item)?, None)
+        };
+
+        let resolved_time_zone = resolve_time_zone(cx, &time_zone);
+
+        let epoch_nanoseconds = match time {
+            Some(time) if !time.is_undefined() => {
+                let time = to_temporal_time(cx, time, cx.undefined())?;
+                let date_time = IsoDateTime::new(plain_date.date(), time);
+                if !iso_date_time_within_limits(date_time) {
+                    return range_error(cx, "date-time is outside the supported range");
+                }
+
+                get_epoch_nanoseconds_for(
+                    cx,
+                    &resolved_time_zone,
+                    date_time,
+                    Disambiguation::Compatible,
+                )?
+            }
+            _ => get_start_of_day(cx, &resolved_time_zone, plain_date.date())?,
+        };
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+        let show_calendar = get_show_calendar_option(cx, options)?;
+
+        let string = date_to_string(&plain_date.date(), show_calendar);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let date_time = IsoDateTime::new(plain_date.date(), MIDNIGHT);
+
+        let string = format_with_locales(
+            cx,
+            date_time.to_utc_epoch_nanoseconds(),
+            Some("UTC"),
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Date,
+            DefaultComponents::Date,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date = PlainDateObject::cast_from_value(cx, this_value)?;
+        let string = date_to_string(&plain_date.date(), ShowCalendar::Auto);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDate.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(cx, "Temporal.PlainDate cannot be converted to a primitive")
+    }
+}
+
+/// AddDurationToDate (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate)
+fn add_duration_to_date(
+    cx: Context,
+    plain_date: StackRoot<PlainDateObject>,
+    duration_like: StackRoot<Value>,
+    options: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    let internal_duration = InternalDuration::from_duration_with_24_hour_days(&duration);
+    let date_duration = internal_duration.to_date_duration_without_time();
+
+    let options = get_options_object(cx, options)?;
+    let overflow = get_overflow_option(cx, options)?;
+
+    let Some(date) = add_iso_date(plain_date.date(), date_duration, overflow) else {
+        return range_error(cx, "date is not valid or outside the supported range");
+    };
+
+    Ok(create_temporal_date(cx, date)?.as_value())
+}
+
+/// DifferenceTemporalPlainDate (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate)
+fn difference_temporal_plain_date(
+    cx: Context,
+    operation: DifferenceOperation,
+    plain_date: StackRoot<PlainDateObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let other = to_temporal_date(cx, other, cx.undefined())?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::Date,
+        &[],
+        Unit::Day,
+        Unit::Day,
+    )?;
+
+    let date = plain_date.date();
+    let date_difference = difference_iso_date(&date, &other, settings.largest_unit);
+    let mut duration = InternalDuration::new(date_difference, 0);
+
+    if settings.smallest_unit != Unit::Day || settings.rounding_increment != 1 {
+        let date_time = IsoDateTime::new(date, MIDNIGHT);
+        let dest_epoch_nanoseconds = IsoDateTime::new(other, MIDNIGHT).to_utc_epoch_nanoseconds();
+
+        let Some(rounded) = round_relative_duration(
+            &duration,
+            dest_epoch_nanoseconds,
+            &date_time,
+            None,
+            settings.largest_unit,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        ) else {
+            return range_error(cx, "date is outside the supported range");
+        };
+
+        duration = rounded;
+    }
+
+    create_difference_duration(cx, &duration, Unit::Day, operation)
+}
@@ -0,0 +1,262 @@
+use core::mem::size_of;
+
+use alloc::format;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        prepare_calendar_fields, to_calendar_argument, RequiredFields, DATE_FIELDS, TIME_FIELDS,
+    },
+    iso::{iso_date_time_within_limits, regulate_time, IsoDate, IsoDateTime, Overflow, MIDNIGHT},
+    options::{get_overflow_option, to_integer_with_truncation},
+    parser::{parse_iso_date_time, ParseGoal},
+    plain_date_constructor::is_valid_iso_date_fields,
+};
+
+// Temporal.PlainDateTime Objects (https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects)
+extend_object! {
+    pub struct PlainDateTimeObject {
+        date_time: IsoDateTime,
+    }
+}
+
+impl PlainDateTimeObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        date_time: IsoDateTime,
+    ) -> AllocResult<StackRoot<PlainDateTimeObject>> {
+        let mut object = object_create_with_proto::<PlainDateTimeObject>(
+            cx,
+            HeapItemKind::PlainDateTimeObject,
+            proto,
+        )?;
+
+        set_uninit!(object.date_time, date_time);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PlainDateTimeObject, "Temporal.PlainDateTime");
+
+    pub fn date_time(&self) -> IsoDateTime {
+        self.date_time
+    }
+}
+
+/// CreateTemporalDateTime (https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldatetime)
+pub fn create_temporal_date_time(
+    cx: Context,
+    date_time: IsoDateTime,
+) -> EvalResult<StackRoot<PlainDateTimeObject>> {
+    if !iso_date_time_within_limits(date_time) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    let proto = cx.get_intrinsic(Intrinsic::PlainDateTimePrototype);
+    Ok(PlainDateTimeObject::new_with_proto(cx, proto, date_time)?)
+}
+
+/// ToTemporalDateTime (https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime)
+///
+/// Options may be undefined.
+pub fn to_temporal_date_time(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<IsoDateTime> {
+    if item.is_object() {
+        let object = item.as_object();
+
+        let date_time = if let Some(plain_date_time) = object.as_plain_date_time_object() {
+            plain_date_time.date_time()
+        } else if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+            zoned_date_time.iso_date_time(cx)
+        } else if let Some(plain_date) = object.as_plain_date_object() {
+            IsoDateTime::new(plain_date.date(), MIDNIGHT)
+        } else {
+            get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+            let fields = prepare_calendar_fields(
+                cx,
+                object,
+                DATE_FIELDS,
+                TIME_FIELDS,
+                RequiredFields::List(&[]),
+            )?;
+
+            let options = get_options_object(cx, options)?;
+            let overflow = get_overflow_option(cx, options)?;
+
+            let date_time = fields.to_date_time(cx, overflow)?;
+            if !iso_date_time_within_limits(date_time) {
+                return range_error(cx, "date-time is outside the supported range");
+            }
+
+            return Ok(date_time);
+        };
+
+        let options = get_options_object(cx, options)?;
+        get_overflow_option(cx, options)?;
+
+        return Ok(date_time);
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "PlainDateTime must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let Some(parsed) = parse_iso_date_time(&string, ParseGoal::DateTime) else {
+        return range_error(cx, &format!("invalid PlainDateTime {string}"));
+    };
+
+    if let Some(calendar) = &parsed.calendar {
+        canonicalize_calendar(cx, calendar)?;
+    }
+
+    let options = get_options_object(cx, options)?;
+    get_overflow_option(cx, options)?;
+
+    let date = IsoDate::new(parsed.year.unwrap(), parsed.month, parsed.day);
+    let date_time = IsoDateTime::new(date, parsed.time.unwrap_or(MIDNIGHT));
+    if !iso_date_time_within_limits(date_time) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    Ok(date_time)
+}
+
+pub struct PlainDateTimeConstructor;
+
+impl PlainDateTimeConstructor {
+    /// Properties of the Temporal.PlainDateTime Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaindatetime-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            3,
+            cx.names.plain_date_time(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::PlainDateTimePrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.PlainDateTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(
+                cx,
+                "Temporal.PlainDateTime constructor must be called with new",
+            );
+        };
+
+        let year = to_integer_with_truncation(cx, get_argument(cx, arguments, 0))?;
+        let month = to_integer_with_truncation(cx, get_argument(cx, arguments, 1))?;
+        let day = to_integer_with_truncation(cx, get_argument(cx, arguments, 2))?;
+
+        let mut time_fields = [0.0; 6];
+        for (i, field) in time_fields.iter_mut().enumerate() {
+            let argument = get_argument(cx, arguments, i + 3);
+            if !argument.is_undefined() {
+                *field = to_integer_with_truncation(cx, argument)?;
+            }
+        }
+
+        to_calendar_argument(cx, get_argument(cx, arguments, 9))?;
+
+        if !is_valid_iso_date_fields(year, month, day) {
+            return range_error(cx, "date is not valid");
+        }
+
+        let Some(time) = regulate_time(time_fields, Overflow::Reject) else {
+            return range_error(cx, "time is not valid");
+        };
+
+        let date = IsoDate::new(year as i64, month as u8, day as u8);
+        let date_time = IsoDateTime::new(date, time);
+        if !iso_date_time_within_limits(date_time) {
+            return range_error(cx, "date-time is outside the supported range");
+        }
+
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::PlainDateTimePrototype)?;
+
+        Ok(PlainDateTimeObject::new_with_proto(cx, proto, date_time)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.compare (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_date_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+        let two = to_temporal_date_time(cx, get_argument(cx, arguments, 1), cx.undefined())?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.PlainDateTime.from (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date_time = to_temporal_date_time(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<PlainDateTimeObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PlainDateTimeObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
@@ -0,0 +1,750 @@
+use alloc::string::ToString;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{
+        check_partial_temporal_object, iso_date_to_fields, prepare_calendar_fields,
+        to_temporal_calendar_identifier, FieldsType, RequiredFields, DATE_FIELDS, ISO8601,
+        TIME_FIELDS,
+    },
+    duration::{
+        difference_plain_date_time_with_rounding, seconds_string_precision, InternalDuration,
+        Precision,
+    },
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{date_time_to_string, format_with_locales},
+    iso::{add_iso_date, days_in_month, days_in_year, iso_date_time_within_limits, IsoDateTime},
+    options::{
+        get_difference_settings, get_disambiguation_option, get_fractional_second_digits_option,
+        get_overflow_option, get_rounding_increment_option, get_rounding_mode_option,
+        get_shorthand_options_object, get_show_calendar_option, get_unit_valued_option,
+        validate_rounding_increment, validate_unit_value, DifferenceOperation, ShowCalendar,
+        UnitValue,
+    },
+    plain_date_constructor::create_temporal_date,
+    plain_date_time_constructor::{
+        create_temporal_date_time, to_temporal_date_time, PlainDateTimeObject,
+    },
+    plain_time_constructor::{create_temporal_time, to_time_record_or_midnight},
+    rounding::{RoundingMode, Unit, UnitGroup},
+    time_zone::{get_epoch_nanoseconds_for, resolve_time_zone, to_temporal_time_zone_identifier},
+    zoned_date_time_constructor::create_temporal_zoned_date_time,
+};
+
+pub struct PlainDateTimePrototype;
+
+impl PlainDateTimePrototype {
+    /// Properties of the Temporal.PlainDateTime Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaindatetime-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PlainDateTimeConstructor has been created
+        object.intrinsic_getter(cx, cx.names.calendar_id(), Self::calendar_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.day(), Self::day, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_week(), Self::day_of_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_year(), Self::day_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_month(), Self::days_in_month, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_week(), Self::days_in_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_year(), Self::days_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.era(), Self::era, realm)?;
+        object.intrinsic_getter(cx, cx.names.era_year(), Self::era_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.hour(), Self::hour, realm)?;
+        object.intrinsic_getter(cx, cx.names.in_leap_year(), Self::in_leap_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.microsecond(), Self::microsecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.millisecond(), Self::millisecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.minute(), Self::minute, realm)?;
+        object.intrinsic_getter(cx, cx.names.month(), Self::month, realm)?;
+        object.intrinsic_getter(cx, cx.names.month_code(), Self::month_code, realm)?;
+        object.intrinsic_getter(cx, cx.names.months_in_year(), Self::months_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.nanosecond(), Self::nanosecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.second(), Self::second, realm)?;
+        object.intrinsic_getter(cx, cx.names.week_of_year(), Self::week_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year(), Self::year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year_of_week(), Self::year_of_week, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.round(), Self::round, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_plain_date(), Self::to_plain_date, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.to_plain_time(), Self::to_plain_time, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_zoned_date_time(),
+            Self::to_zoned_date_time,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.with_calendar(), Self::with_calendar, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.with_plain_time(),
+            Self::with_plain_time,
+            0,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.PlainDateTime")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.PlainDateTime.prototype.calendarId (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.calendarid)
+    pub fn calendar_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(ISO8601)?.as_value())
+    }
+
+    /// get Temporal.PlainDateTime.prototype.era (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.era)
+    ///
+    /// The ISO 8601 calendar has no eras.
+    pub fn era(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainDateTime.prototype.eraYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.erayear)
+    pub fn era_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainDateTime.prototype.year (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.year)
+    pub fn year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.year as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.month (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.month)
+    pub fn month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.month as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.monthCode (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.monthcode)
+    pub fn month_code(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        let month_code = iso_date_to_fields(&date, FieldsType::Date)
+            .month_code
+            .unwrap();
+
+        Ok(cx.alloc_string(&month_code.to_string())?.as_value())
+    }
+
+    /// get Temporal.PlainDateTime.prototype.day (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.day)
+    pub fn day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.day as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.dayOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.dayofweek)
+    pub fn day_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.day_of_week() as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.dayOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.dayofyear)
+    pub fn day_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.day_of_year() as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.weekOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.weekofyear)
+    pub fn week_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.week_of_year().0 as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.yearOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.yearofweek)
+    pub fn year_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(date.week_of_year().1 as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.daysInWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinweek)
+    pub fn days_in_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(7))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.daysInMonth (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinmonth)
+    pub fn days_in_month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(days_in_month(date.year, date.month) as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.daysInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinyear)
+    pub fn days_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.smi(days_in_year(date.year) as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.monthsInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.monthsinyear)
+    pub fn months_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(12))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.inLeapYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.inleapyear)
+    pub fn in_leap_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .date;
+        Ok(cx.bool(days_in_year(date.year) == 366))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.hour (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.hour)
+    pub fn hour(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.hour as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.minute (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.minute)
+    pub fn minute(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.minute as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.second (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.second)
+    pub fn second(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.second as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.millisecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.millisecond)
+    pub fn millisecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.millisecond as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.microsecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.microsecond)
+    pub fn microsecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.microsecond as i32))
+    }
+
+    /// get Temporal.PlainDateTime.prototype.nanosecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.nanosecond)
+    pub fn nanosecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainDateTimeObject::cast_from_value(cx, this_value)?
+            .date_time()
+            .time;
+        Ok(cx.smi(time.nanosecond as i32))
+    }
+
+    /// Temporal.PlainDateTime.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let date_time = plain_date_time.date_time();
+
+        let date_time_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, date_time_like)?;
+
+        let mut fields = iso_date_to_fields(&date_time.date, FieldsType::Date);
+        fields.set_time(&date_time.time);
+
+        let partial_fields = prepare_calendar_fields(
+            cx,
+            date_time_like.as_object(),
+            DATE_FIELDS,
+            TIME_FIELDS,
+            RequiredFields::Partial,
+        )?;
+        let fields = fields.merge(&partial_fields);
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let date_time = fields.to_date_time(cx, overflow)?;
+
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.withPlainTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaintime)
+    pub fn with_plain_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let time = to_time_record_or_midnight(cx, get_argument(cx, arguments, 0))?;
+        let date_time = IsoDateTime::new(plain_date_time.date_time().date, time);
+
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.withCalendar (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withcalendar)
+    pub fn with_calendar(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        to_temporal_calendar_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(create_temporal_date_time(cx, plain_date_time.date_time())?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_date_time(
+            cx,
+            plain_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            false,
+        )
+    }
+
+    /// Temporal.PlainDateTime.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_date_time(
+            cx,
+            plain_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            true,
+        )
+    }
+
+    /// Temporal.PlainDateTime.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_date_time(
+            cx,
+            DifferenceOperation::Until,
+            plain_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainDateTime.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_date_time(
+            cx,
+            DifferenceOperation::Since,
+            plain_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainDateTime.prototype.round (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round)
+    pub fn round(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let round_to = get_argument(cx, arguments, 0);
+        let round_to = get_shorthand_options_object(cx, round_to, cx.names.smallest_unit())?;
+
+        // Options are read in alphabetical order
+        let rounding_increment = get_rounding_increment_option(cx, round_to)?;
+        let rounding_mode = get_rounding_mode_option(cx, round_to, RoundingMode::HalfExpand)?;
+        let smallest_unit = get_unit_valued_option(cx, round_to, cx.names.smallest_unit())?;
+
+        let day = UnitValue::Unit(Unit::Day);
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[day])?;
+        let Some(UnitValue::Unit(smallest_unit)) = smallest_unit else {
+            return range_error(cx, "smallestUnit is required");
+        };
+
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(cx, rounding_increment, maximum, false)?,
+            None => validate_rounding_increment(cx, rounding_increment, 1, true)?,
+        }
+
+        let date_time =
+            plain_date_time
+                .date_time()
+                .round(rounding_increment, smallest_unit, rounding_mode);
+
+        Ok(create_temporal_date_time(cx, date_time)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_date_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        Ok(cx.bool(plain_date_time.date_time() == other))
+    }
+
+    /// Temporal.PlainDateTime.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+
+        // Options are read in alphabetical order
+        let show_calendar = get_show_calendar_option(cx, options)?;
+        let digits = get_fractional_second_digits_option(cx, options)?;
+        let rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+        let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(Unit::Hour)) => {
+                return range_error(cx, "smallestUnit cannot be hour");
+            }
+            Some(UnitValue::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        let (precision, unit, increment) = seconds_string_precision(smallest_unit, digits);
+        let date_time = plain_date_time
+            .date_time()
+            .round(increment, unit, rounding_mode);
+        if !iso_date_time_within_limits(date_time) {
+            return range_error(cx, "date-time is outside the supported range");
+        }
+
+        let string = date_time_to_string(&date_time, precision, show_calendar);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let string = format_with_locales(
+            cx,
+            plain_date_time.date_time().to_utc_epoch_nanoseconds(),
+            Some("UTC"),
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Any,
+            DefaultComponents::All,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let string = date_time_to_string(
+            &plain_date_time.date_time(),
+            Precision::Auto,
+            ShowCalendar::Auto,
+        );
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(
+            cx,
+            "Temporal.PlainDateTime cannot be converted to a primitive",
+        )
+    }
+
+    /// Temporal.PlainDateTime.prototype.toZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tozoneddatetime)
+    pub fn to_zoned_date_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        let time_zone = to_temporal_time_zone_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let disambiguation = get_disambiguation_option(cx, options)?;
+
+        let epoch_nanoseconds = get_epoch_nanoseconds_for(
+            cx,
+            &resolve_time_zone(cx, &time_zone),
+            plain_date_time.date_time(),
+            disambiguation,
+        )?;
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.toPlainDate (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaindate)
+    pub fn to_plain_date(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_date(cx, plain_date_time.date_time().date)?.as_value())
+    }
+
+    /// Temporal.PlainDateTime.prototype.toPlainTime (https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaintime)
+    pub fn to_plain_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_date_time = PlainDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_time(cx, plain_date_time.date_time().time)?.as_value())
+    }
+}
+
+/// AddDurationToDateTime (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime)
+fn add_duration_to_date_time(
+    cx: Context,
+    plain_date_time: StackRoot<PlainDateTimeObject>,
+    duration_like: StackRoot<Value>,
+    options: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    let options = get_options_object(cx, options)?;
+    let overflow = get_overflow_option(cx, options)?;
+
+    let internal_duration = InternalDuration::from_duration_with_24_hour_days(&duration);
+    let date_time = plain_date_time.date_time();
+
+    // Time units are added first, and any overflow into days is added along with the date units
+    let (days, time) = date_time.time.add(internal_duration.time);
+    let mut date_duration = internal_duration.date;
+    date_duration.days = days;
+
+    let Some(date) = add_iso_date(date_time.date, date_duration, overflow) else {
+        return range_error(cx, "date is not valid or outside the supported range");
+    };
+
+    Ok(create_temporal_date_time(cx, IsoDateTime::new(date, time))?.as_value())
+}
+
+/// DifferenceTemporalPlainDateTime (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime)
+fn difference_temporal_plain_date_time(
+    cx: Context,
+    operation: DifferenceOperation,
+    plain_date_time: StackRoot<PlainDateTimeObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let other = to_temporal_date_time(cx, other, cx.undefined())?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Day,
+    )?;
+
+    let Some(internal_duration) = difference_plain_date_time_with_rounding(
+        &plain_date_time.date_time(),
+        &other,
+        settings.largest_unit,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    ) else {
+        return range_error(cx, "date-time is outside the supported range");
+    };
+
+    create_difference_duration(cx, &internal_duration, settings.largest_unit, operation)
+}
@@ -0,0 +1,233 @@
+use core::mem::size_of;
+
+use alloc::format;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        iso_date_to_fields, prepare_calendar_fields, to_calendar_argument, FieldsType,
+        RequiredFields, DATE_FIELDS,
+    },
+    iso::{iso_date_within_limits, IsoDate, Overflow},
+    options::{get_overflow_option, to_integer_with_truncation},
+    parser::{parse_iso_date_time, ParseGoal},
+    plain_date_constructor::is_valid_iso_date_fields,
+};
+
+// Temporal.PlainMonthDay Objects (https://tc39.es/proposal-temporal/#sec-temporal-plainmonthday-objects)
+extend_object! {
+    pub struct PlainMonthDayObject {
+        // The date in the reference year 1972, unless a different reference year was passed to
+        // the constructor
+        date: IsoDate,
+    }
+}
+
+impl PlainMonthDayObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        date: IsoDate,
+    ) -> AllocResult<StackRoot<PlainMonthDayObject>> {
+        let mut object = object_create_with_proto::<PlainMonthDayObject>(
+            cx,
+            HeapItemKind::PlainMonthDayObject,
+            proto,
+        )?;
+
+        set_uninit!(object.date, date);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PlainMonthDayObject, "Temporal.PlainMonthDay");
+
+    pub fn date(&self) -> IsoDate {
+        self.date
+    }
+}
+
+/// CreateTemporalMonthDay (https://tc39.es/proposal-temporal/#sec-temporal-createtemporalmonthday)
+pub fn create_temporal_month_day(
+    cx: Context,
+    date: IsoDate,
+) -> EvalResult<StackRoot<PlainMonthDayObject>> {
+    if !iso_date_within_limits(date) {
+        return range_error(cx, "date is outside the supported range");
+    }
+
+    let proto = cx.get_intrinsic(Intrinsic::PlainMonthDayPrototype);
+    Ok(PlainMonthDayObject::new_with_proto(cx, proto, date)?)
+}
+
+/// ToTemporalMonthDay (https://tc39.es/proposal-temporal/#sec-temporal-totemporalmonthday)
+///
+/// Options may be undefined.
+pub fn to_temporal_month_day(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<IsoDate> {
+    if item.is_object() {
+        let object = item.as_object();
+
+        if let Some(plain_month_day) = object.as_plain_month_day_object() {
+            let options = get_options_object(cx, options)?;
+            get_overflow_option(cx, options)?;
+
+            return Ok(plain_month_day.date());
+        }
+
+        get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+        let fields =
+            prepare_calendar_fields(cx, object, DATE_FIELDS, &[], RequiredFields::List(&[]))?;
+
+        let options = get_options_object(cx, options)?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        return fields.to_month_day(cx, overflow);
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "PlainMonthDay must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let Some(parsed) = parse_iso_date_time(&string, ParseGoal::MonthDay) else {
+        return range_error(cx, &format!("invalid PlainMonthDay {string}"));
+    };
+
+    if let Some(calendar) = &parsed.calendar {
+        canonicalize_calendar(cx, calendar)?;
+    }
+
+    let options = get_options_object(cx, options)?;
+    get_overflow_option(cx, options)?;
+
+    let Some(year) = parsed.year else {
+        return Ok(IsoDate::new(1972, parsed.month, parsed.day));
+    };
+
+    // A full date is converted to the reference year through its fields
+    let date = IsoDate::new(year, parsed.month, parsed.day);
+    if !iso_date_within_limits(date) {
+        return range_error(cx, "date is outside the supported range");
+    }
+
+    iso_date_to_fields(&date, FieldsType::MonthDay).to_month_day(cx, Overflow::Constrain)
+}
+
+pub struct PlainMonthDayConstructor;
+
+impl PlainMonthDayConstructor {
+    /// Properties of the Temporal.PlainMonthDay Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plainmonthday-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            2,
+            cx.names.plain_month_day(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::PlainMonthDayPrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.PlainMonthDay (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(
+                cx,
+                "Temporal.PlainMonthDay constructor must be called with new",
+            );
+        };
+
+        let month = to_integer_with_truncation(cx, get_argument(cx, arguments, 0))?;
+        let day = to_integer_with_truncation(cx, get_argument(cx, arguments, 1))?;
+
+        to_calendar_argument(cx, get_argument(cx, arguments, 2))?;
+
+        let reference_year = get_argument(cx, arguments, 3);
+        let reference_year = if reference_year.is_undefined() {
+            1972.0
+        } else {
+            to_integer_with_truncation(cx, reference_year)?
+        };
+
+        if !is_valid_iso_date_fields(reference_year, month, day) {
+            return range_error(cx, "month and day are not valid");
+        }
+
+        let date = IsoDate::new(reference_year as i64, month as u8, day as u8);
+        if !iso_date_within_limits(date) {
+            return range_error(cx, "date is outside the supported range");
+        }
+
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::PlainMonthDayPrototype)?;
+
+        Ok(PlainMonthDayObject::new_with_proto(cx, proto, date)?.as_value())
+    }
+
+    /// Temporal.PlainMonthDay.from (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = to_temporal_month_day(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_month_day(cx, date)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<PlainMonthDayObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PlainMonthDayObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
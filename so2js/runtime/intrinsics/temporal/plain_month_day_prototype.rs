@@ -0,0 +1,237 @@
+use alloc::string::ToString;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    error::type_error,
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{
+        check_partial_temporal_object, iso_date_to_fields, prepare_calendar_fields, Field,
+        FieldsType, RequiredFields, DATE_FIELDS, ISO8601,
+    },
+    format::{format_with_locales, month_day_to_string},
+    iso::{IsoDateTime, Overflow, MIDNIGHT},
+    options::{get_overflow_option, get_show_calendar_option, ShowCalendar},
+    plain_date_constructor::create_temporal_date,
+    plain_month_day_constructor::{
+        create_temporal_month_day, to_temporal_month_day, PlainMonthDayObject,
+    },
+};
+
+pub struct PlainMonthDayPrototype;
+
+impl PlainMonthDayPrototype {
+    /// Properties of the Temporal.PlainMonthDay Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plainmonthday-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PlainMonthDayConstructor has been created
+        object.intrinsic_getter(cx, cx.names.calendar_id(), Self::calendar_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.day(), Self::day, realm)?;
+        object.intrinsic_getter(cx, cx.names.month_code(), Self::month_code, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_plain_date(), Self::to_plain_date, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.PlainMonthDay")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.PlainMonthDay.prototype.calendarId (https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.calendarid)
+    pub fn calendar_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainMonthDayObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(ISO8601)?.as_value())
+    }
+
+    /// get Temporal.PlainMonthDay.prototype.monthCode (https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.monthcode)
+    pub fn month_code(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainMonthDayObject::cast_from_value(cx, this_value)?.date();
+        let month_code = iso_date_to_fields(&date, FieldsType::MonthDay)
+            .month_code
+            .unwrap();
+
+        Ok(cx.alloc_string(&month_code.to_string())?.as_value())
+    }
+
+    /// get Temporal.PlainMonthDay.prototype.day (https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.day)
+    pub fn day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainMonthDayObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.day as i32))
+    }
+
+    /// Temporal.PlainMonthDay.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+
+        let month_day_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, month_day_like)?;
+
+        let fields = iso_date_to_fields(&plain_month_day.date(), FieldsType::MonthDay);
+        let partial_fields = prepare_calendar_fields(
+            cx,
+            month_day_like.as_object(),
+            DATE_FIELDS,
+            &[],
+            RequiredFields::Partial,
+        )?;
+        let fields = fields.merge(&partial_fields);
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let date = fields.to_month_day(cx, overflow)?;
+
+        Ok(create_temporal_month_day(cx, date)?.as_value())
+    }
+
+    /// Temporal.PlainMonthDay.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_month_day(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        Ok(cx.bool(plain_month_day.date() == other))
+    }
+
+    /// Temporal.PlainMonthDay.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+        let show_calendar = get_show_calendar_option(cx, options)?;
+
+        let string = month_day_to_string(&plain_month_day.date(), show_calendar);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainMonthDay.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+        let date_time = IsoDateTime::new(plain_month_day.date(), MIDNIGHT);
+
+        let string = format_with_locales(
+            cx,
+            date_time.to_utc_epoch_nanoseconds(),
+            Some("UTC"),
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Date,
+            DefaultComponents::Date,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainMonthDay.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+        let string = month_day_to_string(&plain_month_day.date(), ShowCalendar::Auto);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainMonthDay.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(
+            cx,
+            "Temporal.PlainMonthDay cannot be converted to a primitive",
+        )
+    }
+
+    /// Temporal.PlainMonthDay.prototype.toPlainDate (https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.toplaindate)
+    pub fn to_plain_date(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_month_day = PlainMonthDayObject::cast_from_value(cx, this_value)?;
+
+        let item = get_argument(cx, arguments, 0);
+        if !item.is_object() {
+            return type_error(cx, "argument must be an object");
+        }
+
+        let fields = iso_date_to_fields(&plain_month_day.date(), FieldsType::MonthDay);
+        let input_fields = prepare_calendar_fields(
+            cx,
+            item.as_object(),
+            &[Field::Year],
+            &[],
+            RequiredFields::List(&[]),
+        )?;
+        let fields = fields.merge(&input_fields);
+
+        let date = fields.to_date(cx, Overflow::Constrain)?;
+
+        Ok(create_temporal_date(cx, date)?.as_value())
+    }
+}
@@ -0,0 +1,227 @@
+use core::mem::size_of;
+
+use alloc::format;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{prepare_calendar_fields, CalendarFields, RequiredFields, TIME_FIELDS},
+    iso::{regulate_time, IsoTime, Overflow, MIDNIGHT},
+    options::{get_overflow_option, to_integer_with_truncation},
+    parser::{parse_iso_date_time, ParseGoal},
+};
+
+// Temporal.PlainTime Objects (https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects)
+extend_object! {
+    pub struct PlainTimeObject {
+        time: IsoTime,
+    }
+}
+
+impl PlainTimeObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        time: IsoTime,
+    ) -> AllocResult<StackRoot<PlainTimeObject>> {
+        let mut object =
+            object_create_with_proto::<PlainTimeObject>(cx, HeapItemKind::PlainTimeObject, proto)?;
+
+        set_uninit!(object.time, time);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PlainTimeObject, "Temporal.PlainTime");
+
+    pub fn time(&self) -> IsoTime {
+        self.time
+    }
+}
+
+/// CreateTemporalTime (https://tc39.es/proposal-temporal/#sec-temporal-createtemporaltime)
+pub fn create_temporal_time(cx: Context, time: IsoTime) -> AllocResult<StackRoot<PlainTimeObject>> {
+    let proto = cx.get_intrinsic(Intrinsic::PlainTimePrototype);
+    PlainTimeObject::new_with_proto(cx, proto, time)
+}
+
+/// ToTemporalTimeRecord (https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimerecord)
+///
+/// Read the time fields of a property bag, at least one of which must be present.
+pub fn to_temporal_time_record(
+    cx: Context,
+    object: StackRoot<ObjectValue>,
+) -> EvalResult<CalendarFields> {
+    prepare_calendar_fields(cx, object, &[], TIME_FIELDS, RequiredFields::Partial)
+}
+
+/// ToTemporalTime (https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime)
+///
+/// Options may be undefined.
+pub fn to_temporal_time(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<IsoTime> {
+    if item.is_object() {
+        let object = item.as_object();
+
+        let time = if let Some(plain_time) = object.as_plain_time_object() {
+            plain_time.time()
+        } else if let Some(plain_date_time) = object.as_plain_date_time_object() {
+            plain_date_time.date_time().time
+        } else if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+            zoned_date_time.iso_date_time(cx).time
+        } else {
+            let fields = to_temporal_time_record(cx, object)?;
+
+            let options = get_options_object(cx, options)?;
+            let overflow = get_overflow_option(cx, options)?;
+
+            return match fields.time(overflow) {
+                Some(time) => Ok(time),
+                None => range_error(cx, "time is not valid"),
+            };
+        };
+
+        let options = get_options_object(cx, options)?;
+        get_overflow_option(cx, options)?;
+
+        return Ok(time);
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "PlainTime must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let time = match parse_iso_date_time(&string, ParseGoal::Time) {
+        Some(parsed) if parsed.time.is_some() => parsed.time.unwrap(),
+        _ => return range_error(cx, &format!("invalid PlainTime {string}")),
+    };
+
+    let options = get_options_object(cx, options)?;
+    get_overflow_option(cx, options)?;
+
+    Ok(time)
+}
+
+/// ToTimeRecordOrMidnight (https://tc39.es/proposal-temporal/#sec-temporal-totimerecordormidnight)
+pub fn to_time_record_or_midnight(cx: Context, item: StackRoot<Value>) -> EvalResult<IsoTime> {
+    if item.is_undefined() {
+        return Ok(MIDNIGHT);
+    }
+
+    to_temporal_time(cx, item, cx.undefined())
+}
+
+pub struct PlainTimeConstructor;
+
+impl PlainTimeConstructor {
+    /// Properties of the Temporal.PlainTime Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaintime-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            0,
+            cx.names.plain_time(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm.get_intrinsic(Intrinsic::PlainTimePrototype).into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.PlainTime (https://tc39.es/proposal-temporal/#sec-temporal.plaintime)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(cx, "Temporal.PlainTime constructor must be called with new");
+        };
+
+        let mut fields = [0.0; 6];
+        for (i, field) in fields.iter_mut().enumerate() {
+            let argument = get_argument(cx, arguments, i);
+            if !argument.is_undefined() {
+                *field = to_integer_with_truncation(cx, argument)?;
+            }
+        }
+
+        let Some(time) = regulate_time(fields, Overflow::Reject) else {
+            return range_error(cx, "time is not valid");
+        };
+
+        let proto = get_prototype_from_constructor(cx, new_target, Intrinsic::PlainTimePrototype)?;
+
+        Ok(PlainTimeObject::new_with_proto(cx, proto, time)?.as_value())
+    }
+
+    /// Temporal.PlainTime.compare (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+        let two = to_temporal_time(cx, get_argument(cx, arguments, 1), cx.undefined())?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.PlainTime.from (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = to_temporal_time(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_time(cx, time)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<PlainTimeObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PlainTimeObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
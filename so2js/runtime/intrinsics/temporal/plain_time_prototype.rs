@@ -0,0 +1,389 @@
+use crate::runtime::{
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{check_partial_temporal_object, CalendarFields},
+    duration::{round_time_difference, seconds_string_precision, Precision},
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{format_with_locales, time_to_string},
+    iso::{IsoDate, IsoDateTime},
+    options::{
+        get_difference_settings, get_fractional_second_digits_option, get_overflow_option,
+        get_rounding_increment_option, get_rounding_mode_option, get_shorthand_options_object,
+        get_unit_valued_option, validate_rounding_increment, validate_unit_value,
+        DifferenceOperation, UnitValue,
+    },
+    plain_time_constructor::{
+        create_temporal_time, to_temporal_time, to_temporal_time_record, PlainTimeObject,
+    },
+    rounding::{RoundingMode, Unit, UnitGroup},
+};
+
+pub struct PlainTimePrototype;
+
+impl PlainTimePrototype {
+    /// Properties of the Temporal.PlainTime Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plaintime-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PlainTimeConstructor has been created
+        object.intrinsic_getter(cx, cx.names.hour(), Self::hour, realm)?;
+        object.intrinsic_getter(cx, cx.names.microsecond(), Self::microsecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.millisecond(), Self::millisecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.minute(), Self::minute, realm)?;
+        object.intrinsic_getter(cx, cx.names.nanosecond(), Self::nanosecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.second(), Self::second, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.round(), Self::round, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.PlainTime")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.PlainTime.prototype.hour (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.hour)
+    pub fn hour(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.hour as i32))
+    }
+
+    /// get Temporal.PlainTime.prototype.minute (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.minute)
+    pub fn minute(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.minute as i32))
+    }
+
+    /// get Temporal.PlainTime.prototype.second (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.second)
+    pub fn second(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.second as i32))
+    }
+
+    /// get Temporal.PlainTime.prototype.millisecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.millisecond)
+    pub fn millisecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.millisecond as i32))
+    }
+
+    /// get Temporal.PlainTime.prototype.microsecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.microsecond)
+    pub fn microsecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.microsecond as i32))
+    }
+
+    /// get Temporal.PlainTime.prototype.nanosecond (https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.nanosecond)
+    pub fn nanosecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = PlainTimeObject::cast_from_value(cx, this_value)?.time();
+        Ok(cx.smi(time.nanosecond as i32))
+    }
+
+    /// Temporal.PlainTime.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_time(cx, plain_time, get_argument(cx, arguments, 0), false)
+    }
+
+    /// Temporal.PlainTime.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_time(cx, plain_time, get_argument(cx, arguments, 0), true)
+    }
+
+    /// Temporal.PlainTime.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+
+        let time_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, time_like)?;
+
+        let partial_fields = to_temporal_time_record(cx, time_like.as_object())?;
+        let mut fields = CalendarFields::default();
+        fields.set_time(&plain_time.time());
+        let fields = fields.merge(&partial_fields);
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let Some(time) = fields.time(overflow) else {
+            return range_error(cx, "time is not valid");
+        };
+
+        Ok(create_temporal_time(cx, time)?.as_value())
+    }
+
+    /// Temporal.PlainTime.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_time(
+            cx,
+            DifferenceOperation::Until,
+            plain_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainTime.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_time(
+            cx,
+            DifferenceOperation::Since,
+            plain_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainTime.prototype.round (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.round)
+    pub fn round(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+
+        let round_to = get_argument(cx, arguments, 0);
+        let round_to = get_shorthand_options_object(cx, round_to, cx.names.smallest_unit())?;
+
+        // Options are read in alphabetical order
+        let rounding_increment = get_rounding_increment_option(cx, round_to)?;
+        let rounding_mode = get_rounding_mode_option(cx, round_to, RoundingMode::HalfExpand)?;
+        let smallest_unit = get_unit_valued_option(cx, round_to, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let Some(UnitValue::Unit(smallest_unit)) = smallest_unit else {
+            return range_error(cx, "smallestUnit is required");
+        };
+
+        let maximum = smallest_unit.maximum_rounding_increment().unwrap();
+        validate_rounding_increment(cx, rounding_increment, maximum, false)?;
+
+        let (_, time) = plain_time
+            .time()
+            .round(rounding_increment, smallest_unit, rounding_mode);
+
+        Ok(create_temporal_time(cx, time)?.as_value())
+    }
+
+    /// Temporal.PlainTime.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        Ok(cx.bool(plain_time.time() == other))
+    }
+
+    /// Temporal.PlainTime.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+
+        // Options are read in alphabetical order
+        let digits = get_fractional_second_digits_option(cx, options)?;
+        let rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+        let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(Unit::Hour)) => {
+                return range_error(cx, "smallestUnit cannot be hour");
+            }
+            Some(UnitValue::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        let (precision, unit, increment) = seconds_string_precision(smallest_unit, digits);
+        let (_, time) = plain_time.time().round(increment, unit, rounding_mode);
+
+        Ok(cx
+            .alloc_string(&time_to_string(&time, precision))?
+            .as_value())
+    }
+
+    /// Temporal.PlainTime.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+
+        // Format the time on the Unix epoch date
+        let date_time = IsoDateTime::new(IsoDate::new(1970, 1, 1), plain_time.time());
+
+        let string = format_with_locales(
+            cx,
+            date_time.to_utc_epoch_nanoseconds(),
+            Some("UTC"),
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Time,
+            DefaultComponents::Time,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainTime.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_time = PlainTimeObject::cast_from_value(cx, this_value)?;
+        let string = time_to_string(&plain_time.time(), Precision::Auto);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainTime.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(cx, "Temporal.PlainTime cannot be converted to a primitive")
+    }
+}
+
+/// AddDurationToTime (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtotime)
+fn add_duration_to_time(
+    cx: Context,
+    plain_time: StackRoot<PlainTimeObject>,
+    duration_like: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    // Date units of the duration are ignored
+    let (_, time) = plain_time.time().add(duration.time_duration());
+
+    Ok(create_temporal_time(cx, time)?.as_value())
+}
+
+/// DifferenceTemporalPlainTime (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaintime)
+fn difference_temporal_plain_time(
+    cx: Context,
+    operation: DifferenceOperation,
+    plain_time: StackRoot<PlainTimeObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let other = to_temporal_time(cx, other, cx.undefined())?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::Time,
+        &[],
+        Unit::Nanosecond,
+        Unit::Hour,
+    )?;
+
+    let Some(internal_duration) = round_time_difference(
+        plain_time.time().difference(&other),
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    ) else {
+        return range_error(cx, "duration is out of range");
+    };
+
+    create_difference_duration(cx, &internal_duration, settings.largest_unit, operation)
+}
@@ -0,0 +1,246 @@
+use core::mem::size_of;
+
+use alloc::format;
+
+use crate::{
+    cast_from_value_fn, extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        prepare_calendar_fields, to_calendar_argument, RequiredFields, YEAR_MONTH_FIELDS,
+    },
+    iso::{iso_year_month_within_limits, IsoDate},
+    options::{get_overflow_option, to_integer_with_truncation},
+    parser::{parse_iso_date_time, ParseGoal},
+    plain_date_constructor::is_valid_iso_date_fields,
+};
+
+// Temporal.PlainYearMonth Objects (https://tc39.es/proposal-temporal/#sec-temporal-plainyearmonth-objects)
+extend_object! {
+    pub struct PlainYearMonthObject {
+        // The first day of the month, unless a different reference day was passed to the
+        // constructor
+        date: IsoDate,
+    }
+}
+
+impl PlainYearMonthObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        date: IsoDate,
+    ) -> AllocResult<StackRoot<PlainYearMonthObject>> {
+        let mut object = object_create_with_proto::<PlainYearMonthObject>(
+            cx,
+            HeapItemKind::PlainYearMonthObject,
+            proto,
+        )?;
+
+        set_uninit!(object.date, date);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(PlainYearMonthObject, "Temporal.PlainYearMonth");
+
+    pub fn date(&self) -> IsoDate {
+        self.date
+    }
+}
+
+/// CreateTemporalYearMonth (https://tc39.es/proposal-temporal/#sec-temporal-createtemporalyearmonth)
+pub fn create_temporal_year_month(
+    cx: Context,
+    date: IsoDate,
+) -> EvalResult<StackRoot<PlainYearMonthObject>> {
+    if !iso_year_month_within_limits(date.year, date.month) {
+        return range_error(cx, "year and month are outside the supported range");
+    }
+
+    let proto = cx.get_intrinsic(Intrinsic::PlainYearMonthPrototype);
+    Ok(PlainYearMonthObject::new_with_proto(cx, proto, date)?)
+}
+
+/// ToTemporalYearMonth (https://tc39.es/proposal-temporal/#sec-temporal-totemporalyearmonth)
+///
+/// Options may be undefined. The returned year and month are within the supported range.
+pub fn to_temporal_year_month(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<IsoDate> {
+    if item.is_object() {
+        let object = item.as_object();
+
+        if let Some(plain_year_month) = object.as_plain_year_month_object() {
+            let options = get_options_object(cx, options)?;
+            get_overflow_option(cx, options)?;
+
+            return Ok(plain_year_month.date());
+        }
+
+        get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+        let fields = prepare_calendar_fields(
+            cx,
+            object,
+            YEAR_MONTH_FIELDS,
+            &[],
+            RequiredFields::List(&[]),
+        )?;
+
+        let options = get_options_object(cx, options)?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        return fields.to_year_month(cx, overflow);
+    }
+
+    if !item.is_string() {
+        return type_error(cx, "PlainYearMonth must be a string or object");
+    }
+
+    let string = item.as_string().format(cx)?;
+    let Some(parsed) = parse_iso_date_time(&string, ParseGoal::YearMonth) else {
+        return range_error(cx, &format!("invalid PlainYearMonth {string}"));
+    };
+
+    if let Some(calendar) = &parsed.calendar {
+        canonicalize_calendar(cx, calendar)?;
+    }
+
+    let options = get_options_object(cx, options)?;
+    get_overflow_option(cx, options)?;
+
+    let year = parsed.year.unwrap();
+    if !iso_year_month_within_limits(year, parsed.month) {
+        return range_error(cx, "year and month are outside the supported range");
+    }
+
+    // The reference day of the ISO 8601 calendar is always the first day of the month
+    Ok(IsoDate::new(year, parsed.month, 1))
+}
+
+pub struct PlainYearMonthConstructor;
+
+impl PlainYearMonthConstructor {
+    /// Properties of the Temporal.PlainYearMonth Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plainyearmonth-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            2,
+            cx.names.plain_year_month(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::PlainYearMonthPrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.PlainYearMonth (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(
+                cx,
+                "Temporal.PlainYearMonth constructor must be called with new",
+            );
+        };
+
+        let year = to_integer_with_truncation(cx, get_argument(cx, arguments, 0))?;
+        let month = to_integer_with_truncation(cx, get_argument(cx, arguments, 1))?;
+
+        to_calendar_argument(cx, get_argument(cx, arguments, 2))?;
+
+        let reference_day = get_argument(cx, arguments, 3);
+        let reference_day = if reference_day.is_undefined() {
+            1.0
+        } else {
+            to_integer_with_truncation(cx, reference_day)?
+        };
+
+        if !is_valid_iso_date_fields(year, month, reference_day) {
+            return range_error(cx, "year and month are not valid");
+        }
+
+        let date = IsoDate::new(year as i64, month as u8, reference_day as u8);
+        if !iso_year_month_within_limits(date.year, date.month) {
+            return range_error(cx, "year and month are outside the supported range");
+        }
+
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::PlainYearMonthPrototype)?;
+
+        Ok(PlainYearMonthObject::new_with_proto(cx, proto, date)?.as_value())
+    }
+
+    /// Temporal.PlainYearMonth.compare (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let one = to_temporal_year_month(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+        let two = to_temporal_year_month(cx, get_argument(cx, arguments, 1), cx.undefined())?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.PlainYearMonth.from (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = to_temporal_year_month(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_year_month(cx, date)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<PlainYearMonthObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<PlainYearMonthObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+    }
+}
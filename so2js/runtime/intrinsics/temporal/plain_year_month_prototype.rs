@@ -0,0 +1,498 @@
+use alloc::string::ToString;
+
+use crate::runtime::{
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{
+        check_partial_temporal_object, iso_date_to_fields, prepare_calendar_fields, Field,
+        FieldsType, RequiredFields, ISO8601, YEAR_MONTH_FIELDS,
+    },
+    duration::{round_relative_duration, InternalDuration},
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{format_with_locales, year_month_to_string},
+    iso::{
+        add_iso_date, days_in_month, days_in_year, difference_iso_date, DateDuration, IsoDate,
+        IsoDateTime, Overflow, MIDNIGHT,
+    },
+    options::{
+        get_difference_settings, get_overflow_option, get_show_calendar_option,
+        DifferenceOperation, ShowCalendar,
+    },
+    plain_date_constructor::create_temporal_date,
+    plain_year_month_constructor::{
+        create_temporal_year_month, to_temporal_year_month, PlainYearMonthObject,
+    },
+    rounding::{Unit, UnitGroup},
+};
+
+pub struct PlainYearMonthPrototype;
+
+impl PlainYearMonthPrototype {
+    /// Properties of the Temporal.PlainYearMonth Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-plainyearmonth-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once PlainYearMonthConstructor has been created
+        object.intrinsic_getter(cx, cx.names.calendar_id(), Self::calendar_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_month(), Self::days_in_month, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_year(), Self::days_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.era(), Self::era, realm)?;
+        object.intrinsic_getter(cx, cx.names.era_year(), Self::era_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.in_leap_year(), Self::in_leap_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.month(), Self::month, realm)?;
+        object.intrinsic_getter(cx, cx.names.month_code(), Self::month_code, realm)?;
+        object.intrinsic_getter(cx, cx.names.months_in_year(), Self::months_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year(), Self::year, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_plain_date(), Self::to_plain_date, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.PlainYearMonth")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.calendarId (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.calendarid)
+    pub fn calendar_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(ISO8601)?.as_value())
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.era (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.era)
+    ///
+    /// The ISO 8601 calendar has no eras.
+    pub fn era(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.eraYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.erayear)
+    pub fn era_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.year (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.year)
+    pub fn year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.year as i32))
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.month (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.month)
+    pub fn month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(date.month as i32))
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.monthCode (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.monthcode)
+    pub fn month_code(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        let month_code = iso_date_to_fields(&date, FieldsType::YearMonth)
+            .month_code
+            .unwrap();
+
+        Ok(cx.alloc_string(&month_code.to_string())?.as_value())
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.daysInMonth (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.daysinmonth)
+    pub fn days_in_month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(days_in_month(date.year, date.month) as i32))
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.daysInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.daysinyear)
+    pub fn days_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.smi(days_in_year(date.year) as i32))
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.monthsInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.monthsinyear)
+    pub fn months_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(12))
+    }
+
+    /// get Temporal.PlainYearMonth.prototype.inLeapYear (https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.inleapyear)
+    pub fn in_leap_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = PlainYearMonthObject::cast_from_value(cx, this_value)?.date();
+        Ok(cx.bool(days_in_year(date.year) == 366))
+    }
+
+    /// Temporal.PlainYearMonth.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+
+        let year_month_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, year_month_like)?;
+
+        let fields = iso_date_to_fields(&plain_year_month.date(), FieldsType::YearMonth);
+        let partial_fields = prepare_calendar_fields(
+            cx,
+            year_month_like.as_object(),
+            YEAR_MONTH_FIELDS,
+            &[],
+            RequiredFields::Partial,
+        )?;
+        let fields = fields.merge(&partial_fields);
+
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let date = fields.to_year_month(cx, overflow)?;
+
+        Ok(create_temporal_year_month(cx, date)?.as_value())
+    }
+
+    /// Temporal.PlainYearMonth.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        add_duration_to_year_month(
+            cx,
+            plain_year_month,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            false,
+        )
+    }
+
+    /// Temporal.PlainYearMonth.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        add_duration_to_year_month(
+            cx,
+            plain_year_month,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            true,
+        )
+    }
+
+    /// Temporal.PlainYearMonth.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_year_month(
+            cx,
+            DifferenceOperation::Until,
+            plain_year_month,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainYearMonth.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        difference_temporal_plain_year_month(
+            cx,
+            DifferenceOperation::Since,
+            plain_year_month,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.PlainYearMonth.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        let other = to_temporal_year_month(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        Ok(cx.bool(plain_year_month.date() == other))
+    }
+
+    /// Temporal.PlainYearMonth.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+        let show_calendar = get_show_calendar_option(cx, options)?;
+
+        let string = year_month_to_string(&plain_year_month.date(), show_calendar);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainYearMonth.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        let date_time = IsoDateTime::new(plain_year_month.date(), MIDNIGHT);
+
+        let string = format_with_locales(
+            cx,
+            date_time.to_utc_epoch_nanoseconds(),
+            Some("UTC"),
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            RequiredComponents::Date,
+            DefaultComponents::Date,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainYearMonth.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+        let string = year_month_to_string(&plain_year_month.date(), ShowCalendar::Auto);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.PlainYearMonth.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(
+            cx,
+            "Temporal.PlainYearMonth cannot be converted to a primitive",
+        )
+    }
+
+    /// Temporal.PlainYearMonth.prototype.toPlainDate (https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.toplaindate)
+    pub fn to_plain_date(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let plain_year_month = PlainYearMonthObject::cast_from_value(cx, this_value)?;
+
+        let item = get_argument(cx, arguments, 0);
+        if !item.is_object() {
+            return type_error(cx, "argument must be an object");
+        }
+
+        let fields = iso_date_to_fields(&plain_year_month.date(), FieldsType::YearMonth);
+        let input_fields = prepare_calendar_fields(
+            cx,
+            item.as_object(),
+            &[Field::Day],
+            &[],
+            RequiredFields::List(&[]),
+        )?;
+        let fields = fields.merge(&input_fields);
+
+        let date = fields.to_date(cx, Overflow::Constrain)?;
+
+        Ok(create_temporal_date(cx, date)?.as_value())
+    }
+}
+
+/// The first day of a year and month.
+fn first_day_of_month(date: IsoDate) -> IsoDate {
+    IsoDate::new(date.year, date.month, 1)
+}
+
+/// AddDurationToYearMonth (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoyearmonth)
+fn add_duration_to_year_month(
+    cx: Context,
+    plain_year_month: StackRoot<PlainYearMonthObject>,
+    duration_like: StackRoot<Value>,
+    options: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    let options = get_options_object(cx, options)?;
+    let overflow = get_overflow_option(cx, options)?;
+
+    // Durations are added to the first day of the month, or the last day of the month when
+    // subtracting so that subtracting days can reach the previous month.
+    let start_date = first_day_of_month(plain_year_month.date());
+    let start_date = if duration.sign() < 0 {
+        let next_month = DateDuration::new(0, 1, 0, 0);
+        match add_iso_date(start_date, next_month, Overflow::Constrain) {
+            Some(next_month) => next_month.add_days(-1),
+            None => return range_error(cx, "date is outside the supported range"),
+        }
+    } else {
+        start_date
+    };
+
+    let internal_duration = InternalDuration::from_duration_with_24_hour_days(&duration);
+    let date_duration = internal_duration.to_date_duration_without_time();
+
+    let Some(added_date) = add_iso_date(start_date, date_duration, overflow) else {
+        return range_error(cx, "date is not valid or outside the supported range");
+    };
+
+    let fields = iso_date_to_fields(&added_date, FieldsType::YearMonth);
+    let date = fields.to_year_month(cx, overflow)?;
+
+    Ok(create_temporal_year_month(cx, date)?.as_value())
+}
+
+/// DifferenceTemporalPlainYearMonth (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplainyearmonth)
+fn difference_temporal_plain_year_month(
+    cx: Context,
+    operation: DifferenceOperation,
+    plain_year_month: StackRoot<PlainYearMonthObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let other = to_temporal_year_month(cx, other, cx.undefined())?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::Date,
+        &[Unit::Week, Unit::Day],
+        Unit::Month,
+        Unit::Year,
+    )?;
+
+    let date = plain_year_month.date();
+    if date == other {
+        return create_difference_duration(cx, &InternalDuration::default(), Unit::Day, operation);
+    }
+
+    // Differences are computed between the first days of each month
+    let date = first_day_of_month(date);
+    let other = first_day_of_month(other);
+
+    let mut date_difference = difference_iso_date(&date, &other, settings.largest_unit);
+    date_difference.weeks = 0;
+    date_difference.days = 0;
+
+    let mut duration = InternalDuration::new(date_difference, 0);
+
+    if settings.smallest_unit != Unit::Month || settings.rounding_increment != 1 {
+        let date_time = IsoDateTime::new(date, MIDNIGHT);
+        let dest_epoch_nanoseconds = IsoDateTime::new(other, MIDNIGHT).to_utc_epoch_nanoseconds();
+
+        let Some(rounded) = round_relative_duration(
+            &duration,
+            dest_epoch_nanoseconds,
+            &date_time,
+            None,
+            settings.largest_unit,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        ) else {
+            return range_error(cx, "date is outside the supported range");
+        };
+
+        duration = rounded;
+    }
+
+    create_difference_duration(cx, &duration, Unit::Day, operation)
+}
@@ -0,0 +1,223 @@
+//! Temporal units and rounding of quantities to an increment of a unit.
+
+pub use crate::runtime::intl::number_format::RoundingMode;
+
+use super::iso::{
+    NS_PER_DAY, NS_PER_HOUR, NS_PER_MICROSECOND, NS_PER_MILLISECOND, NS_PER_MINUTE, NS_PER_SECOND,
+};
+
+/// Temporal units, where the derived ordering places larger units after smaller units.
+///
+/// Temporal units (https://tc39.es/proposal-temporal/#table-temporal-units)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Whether a unit option accepts date units, time units, or both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+impl Unit {
+    /// All units from largest to smallest.
+    pub const ALL: [Unit; 10] = [
+        Unit::Year,
+        Unit::Month,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+    ];
+
+    /// Parse a unit from its singular or plural name.
+    pub fn from_str(string: &str) -> Option<Unit> {
+        let unit = match string {
+            "year" | "years" => Unit::Year,
+            "month" | "months" => Unit::Month,
+            "week" | "weeks" => Unit::Week,
+            "day" | "days" => Unit::Day,
+            "hour" | "hours" => Unit::Hour,
+            "minute" | "minutes" => Unit::Minute,
+            "second" | "seconds" => Unit::Second,
+            "millisecond" | "milliseconds" => Unit::Millisecond,
+            "microsecond" | "microseconds" => Unit::Microsecond,
+            "nanosecond" | "nanoseconds" => Unit::Nanosecond,
+            _ => return None,
+        };
+
+        Some(unit)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Year => "year",
+            Unit::Month => "month",
+            Unit::Week => "week",
+            Unit::Day => "day",
+            Unit::Hour => "hour",
+            Unit::Minute => "minute",
+            Unit::Second => "second",
+            Unit::Millisecond => "millisecond",
+            Unit::Microsecond => "microsecond",
+            Unit::Nanosecond => "nanosecond",
+        }
+    }
+
+    /// Whether this is a calendar unit, whose length depends on the date it is relative to.
+    ///
+    /// IsCalendarUnit (https://tc39.es/proposal-temporal/#sec-temporal-iscalendarunit)
+    pub fn is_calendar_unit(&self) -> bool {
+        matches!(self, Unit::Year | Unit::Month | Unit::Week)
+    }
+
+    /// Whether this is a date unit. Days are a date unit.
+    ///
+    /// TemporalUnitCategory (https://tc39.es/proposal-temporal/#sec-temporal-temporalunitcategory)
+    pub fn is_date_unit(&self) -> bool {
+        *self >= Unit::Day
+    }
+
+    /// Length of a time unit or a 24 hour day in nanoseconds. Must not be a calendar unit.
+    ///
+    /// Temporal units (https://tc39.es/proposal-temporal/#table-temporal-units)
+    pub fn nanoseconds(&self) -> i128 {
+        match self {
+            Unit::Day => NS_PER_DAY,
+            Unit::Hour => NS_PER_HOUR,
+            Unit::Minute => NS_PER_MINUTE,
+            Unit::Second => NS_PER_SECOND,
+            Unit::Millisecond => NS_PER_MILLISECOND,
+            Unit::Microsecond => NS_PER_MICROSECOND,
+            Unit::Nanosecond => 1,
+            Unit::Year | Unit::Month | Unit::Week => unreachable!("calendar units have no length"),
+        }
+    }
+
+    /// The maximum rounding increment of this unit, which is exclusive unless `inclusive` is set.
+    /// Returns None if there is no maximum.
+    ///
+    /// MaximumTemporalDurationRoundingIncrement (https://tc39.es/proposal-temporal/#sec-temporal-maximumtemporaldurationroundingincrement)
+    pub fn maximum_rounding_increment(&self) -> Option<i64> {
+        match self {
+            Unit::Year | Unit::Month | Unit::Week | Unit::Day => None,
+            Unit::Hour => Some(24),
+            Unit::Minute | Unit::Second => Some(60),
+            Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond => Some(1000),
+        }
+    }
+}
+
+/// A rounding mode that is independent of the sign of the value being rounded.
+///
+/// GetUnsignedRoundingMode (https://tc39.es/ecma402/#sec-getunsignedroundingmode)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnsignedRoundingMode {
+    Infinity,
+    Zero,
+    HalfInfinity,
+    HalfZero,
+    HalfEven,
+}
+
+impl UnsignedRoundingMode {
+    pub fn new(rounding_mode: RoundingMode, is_negative: bool) -> UnsignedRoundingMode {
+        match (rounding_mode, is_negative) {
+            (RoundingMode::Ceil, false)
+            | (RoundingMode::Floor, true)
+            | (RoundingMode::Expand, _) => UnsignedRoundingMode::Infinity,
+            (RoundingMode::Ceil, true)
+            | (RoundingMode::Floor, false)
+            | (RoundingMode::Trunc, _) => UnsignedRoundingMode::Zero,
+            (RoundingMode::HalfCeil, false)
+            | (RoundingMode::HalfFloor, true)
+            | (RoundingMode::HalfExpand, _) => UnsignedRoundingMode::HalfInfinity,
+            (RoundingMode::HalfCeil, true)
+            | (RoundingMode::HalfFloor, false)
+            | (RoundingMode::HalfTrunc, _) => UnsignedRoundingMode::HalfZero,
+            (RoundingMode::HalfEven, _) => UnsignedRoundingMode::HalfEven,
+        }
+    }
+
+    /// ApplyUnsignedRoundingMode (https://tc39.es/ecma402/#sec-applyunsignedroundingmode)
+    ///
+    /// Choose between rounding down to `r1` or up to `r1 + 1`, where the value being rounded is
+    /// `r1` plus the fraction `numerator / denominator`. The fraction must be in [0, 1).
+    pub fn apply(&self, r1: i128, numerator: i128, denominator: i128) -> i128 {
+        if numerator == 0 {
+            return r1;
+        }
+
+        let r2 = r1 + 1;
+        let half_comparison = (2 * numerator).cmp(&denominator);
+
+        match self {
+            UnsignedRoundingMode::Zero => r1,
+            UnsignedRoundingMode::Infinity => r2,
+            _ if half_comparison.is_lt() => r1,
+            _ if half_comparison.is_gt() => r2,
+            UnsignedRoundingMode::HalfZero => r1,
+            UnsignedRoundingMode::HalfInfinity => r2,
+            UnsignedRoundingMode::HalfEven => {
+                if r1 % 2 == 0 {
+                    r1
+                } else {
+                    r2
+                }
+            }
+        }
+    }
+}
+
+/// Round the fraction `numerator / denominator` to an integer, where the denominator is positive.
+pub fn round_fraction(numerator: i128, denominator: i128, rounding_mode: RoundingMode) -> i128 {
+    let is_negative = numerator < 0;
+    let unsigned_rounding_mode = UnsignedRoundingMode::new(rounding_mode, is_negative);
+
+    let magnitude = numerator.abs();
+    let rounded = unsigned_rounding_mode.apply(
+        magnitude / denominator,
+        magnitude % denominator,
+        denominator,
+    );
+
+    if is_negative {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// RoundNumberToIncrement (https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrement)
+pub fn round_number_to_increment(x: i128, increment: i128, rounding_mode: RoundingMode) -> i128 {
+    round_fraction(x, increment, rounding_mode) * increment
+}
+
+/// RoundNumberToIncrementAsIfPositive (https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrementasifpositive)
+pub fn round_number_to_increment_as_if_positive(
+    x: i128,
+    increment: i128,
+    rounding_mode: RoundingMode,
+) -> i128 {
+    let unsigned_rounding_mode = UnsignedRoundingMode::new(rounding_mode, false);
+    let rounded =
+        unsigned_rounding_mode.apply(x.div_euclid(increment), x.rem_euclid(increment), increment);
+
+    rounded * increment
+}
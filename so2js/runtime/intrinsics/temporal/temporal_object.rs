@@ -0,0 +1,85 @@
+use crate::runtime::{
+    alloc_error::AllocResult, intrinsics::intrinsics::Intrinsic, object_value::ObjectValue,
+    property::Property, realm::Realm, Context, StackRoot,
+};
+
+/// The Temporal Object (https://tc39.es/proposal-temporal/#sec-temporal-objects)
+pub struct TemporalObject;
+
+impl TemporalObject {
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Temporal [ %Symbol.toStringTag% ] (https://tc39.es/proposal-temporal/#sec-temporal-%symbol.tostringtag%)
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let temporal_name_value = cx.names.temporal().as_string().into();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(temporal_name_value, false, false, true),
+        )?;
+
+        // Constructor Properties of the Temporal Object (https://tc39.es/proposal-temporal/#sec-constructor-properties-of-the-temporal-object)
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.duration(),
+            realm.get_intrinsic(Intrinsic::DurationConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.instant(),
+            realm.get_intrinsic(Intrinsic::InstantConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plain_date(),
+            realm.get_intrinsic(Intrinsic::PlainDateConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plain_date_time(),
+            realm
+                .get_intrinsic(Intrinsic::PlainDateTimeConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plain_month_day(),
+            realm
+                .get_intrinsic(Intrinsic::PlainMonthDayConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plain_time(),
+            realm.get_intrinsic(Intrinsic::PlainTimeConstructor).into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.plain_year_month(),
+            realm
+                .get_intrinsic(Intrinsic::PlainYearMonthConstructor)
+                .into(),
+        )?;
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.zoned_date_time(),
+            realm
+                .get_intrinsic(Intrinsic::ZonedDateTimeConstructor)
+                .into(),
+        )?;
+
+        // Other Properties of the Temporal Object (https://tc39.es/proposal-temporal/#sec-other-properties-of-the-temporal-object)
+        object.intrinsic_data_prop(
+            cx,
+            cx.names.now(),
+            realm.get_intrinsic(Intrinsic::TemporalNow).into(),
+        )?;
+
+        Ok(object)
+    }
+}
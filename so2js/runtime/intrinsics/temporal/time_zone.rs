@@ -0,0 +1,348 @@
+//! Time zone operations on Temporal values, built on the time zones shared with Date and Intl.
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::{
+    common::time_zone::{
+        available_named_time_zone_identifier, primary_time_zone_identifier, TimeZone,
+    },
+    runtime::{
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        Context, StackRoot, Value,
+    },
+};
+
+use super::{
+    format::format_offset_time_zone_identifier,
+    iso::{
+        is_valid_epoch_nanoseconds, iso_date_time_within_limits, IsoDate, IsoDateTime, IsoTime,
+        MIDNIGHT, NS_PER_DAY, NS_PER_MILLISECOND, NS_PER_MINUTE, NS_PER_SECOND,
+    },
+    options::{Disambiguation, OffsetOption},
+    parser::{parse_time_zone_identifier, parse_time_zone_string, ParsedTimeZone},
+    rounding::{round_number_to_increment, RoundingMode},
+};
+
+/// Resolve the time zone with an identifier that has already been validated.
+pub fn resolve_time_zone(cx: Context, identifier: &str) -> TimeZone {
+    TimeZone::from_identifier(cx, identifier).unwrap_or(TimeZone::Utc)
+}
+
+/// GetOffsetNanosecondsFor (https://tc39.es/proposal-temporal/#sec-temporal-getoffsetnanosecondsfor)
+pub fn get_offset_nanoseconds_for(
+    cx: Context,
+    time_zone: &TimeZone,
+    epoch_nanoseconds: i128,
+) -> i128 {
+    let epoch_millis = epoch_nanoseconds.div_euclid(NS_PER_MILLISECOND) as f64;
+    time_zone.offset_at(cx, epoch_millis).offset_millis as i128 * NS_PER_MILLISECOND
+}
+
+/// GetISODateTimeFor (https://tc39.es/proposal-temporal/#sec-temporal-getisodatetimefor)
+pub fn get_iso_date_time_for(
+    cx: Context,
+    time_zone: &TimeZone,
+    epoch_nanoseconds: i128,
+) -> IsoDateTime {
+    let offset_nanoseconds = get_offset_nanoseconds_for(cx, time_zone, epoch_nanoseconds);
+    IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds, offset_nanoseconds)
+}
+
+/// GetPossibleEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-getpossibleepochnanoseconds)
+///
+/// Returns the instants at which the time zone has a local date-time, in ascending order. There
+/// are no instants if the local time was skipped and two if it was repeated.
+pub fn get_possible_epoch_nanoseconds(
+    cx: Context,
+    time_zone: &TimeZone,
+    date_time: IsoDateTime,
+) -> EvalResult<Vec<i128>> {
+    if !iso_date_time_within_limits(date_time) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    let local_nanoseconds = date_time.to_utc_epoch_nanoseconds();
+
+    let possible_epoch_nanoseconds = match time_zone {
+        TimeZone::Utc => vec![local_nanoseconds],
+        TimeZone::Offset(offset_minutes) => {
+            vec![local_nanoseconds - *offset_minutes as i128 * NS_PER_MINUTE]
+        }
+        TimeZone::Tzif(..) | TimeZone::Host(_) => {
+            // Offsets are always less than a day, so all instants that could have this local time
+            // have one of the offsets a day before or after.
+            let offset_before =
+                get_offset_nanoseconds_for(cx, time_zone, local_nanoseconds - NS_PER_DAY);
+            let offset_after =
+                get_offset_nanoseconds_for(cx, time_zone, local_nanoseconds + NS_PER_DAY);
+
+            let mut candidates = Vec::with_capacity(2);
+            for offset in [offset_before, offset_after] {
+                let candidate = local_nanoseconds - offset;
+                if get_offset_nanoseconds_for(cx, time_zone, candidate) == offset {
+                    candidates.push(candidate);
+                }
+            }
+
+            candidates.sort();
+            candidates.dedup();
+            candidates
+        }
+    };
+
+    for epoch_nanoseconds in &possible_epoch_nanoseconds {
+        if !is_valid_epoch_nanoseconds(*epoch_nanoseconds) {
+            return range_error(cx, "date-time is outside the supported range");
+        }
+    }
+
+    Ok(possible_epoch_nanoseconds)
+}
+
+/// DisambiguatePossibleEpochNanoseconds (https://tc39.es/proposal-temporal/#sec-temporal-disambiguatepossibleepochnanoseconds)
+pub fn disambiguate_possible_epoch_nanoseconds(
+    cx: Context,
+    possible_epoch_nanoseconds: &[i128],
+    time_zone: &TimeZone,
+    date_time: IsoDateTime,
+    disambiguation: Disambiguation,
+) -> EvalResult<i128> {
+    match possible_epoch_nanoseconds {
+        [epoch_nanoseconds] => return Ok(*epoch_nanoseconds),
+        [first, .., last] => {
+            return match disambiguation {
+                Disambiguation::Compatible | Disambiguation::Earlier => Ok(*first),
+                Disambiguation::Later => Ok(*last),
+                Disambiguation::Reject => {
+                    range_error(cx, "date-time is ambiguous in this time zone")
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if disambiguation == Disambiguation::Reject {
+        return range_error(cx, "date-time does not exist in this time zone");
+    }
+
+    // The local time was skipped, so find the length of the gap from the offsets around it
+    let epoch_nanoseconds = date_time.to_utc_epoch_nanoseconds();
+    let day_before = epoch_nanoseconds - NS_PER_DAY;
+    let day_after = epoch_nanoseconds + NS_PER_DAY;
+    if !is_valid_epoch_nanoseconds(day_before) || !is_valid_epoch_nanoseconds(day_after) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    let offset_before = get_offset_nanoseconds_for(cx, time_zone, day_before);
+    let offset_after = get_offset_nanoseconds_for(cx, time_zone, day_after);
+    let gap = offset_after - offset_before;
+
+    if disambiguation == Disambiguation::Earlier {
+        let earlier_date_time = date_time.add_time(-gap);
+        let possible = get_possible_epoch_nanoseconds(cx, time_zone, earlier_date_time)?;
+        return match possible.first() {
+            Some(epoch_nanoseconds) => Ok(*epoch_nanoseconds),
+            None => range_error(cx, "date-time does not exist in this time zone"),
+        };
+    }
+
+    let later_date_time = date_time.add_time(gap);
+    let possible = get_possible_epoch_nanoseconds(cx, time_zone, later_date_time)?;
+    match possible.last() {
+        Some(epoch_nanoseconds) => Ok(*epoch_nanoseconds),
+        None => range_error(cx, "date-time does not exist in this time zone"),
+    }
+}
+
+/// GetEpochNanosecondsFor (https://tc39.es/proposal-temporal/#sec-temporal-getepochnanosecondsfor)
+pub fn get_epoch_nanoseconds_for(
+    cx: Context,
+    time_zone: &TimeZone,
+    date_time: IsoDateTime,
+    disambiguation: Disambiguation,
+) -> EvalResult<i128> {
+    let possible = get_possible_epoch_nanoseconds(cx, time_zone, date_time)?;
+    disambiguate_possible_epoch_nanoseconds(cx, &possible, time_zone, date_time, disambiguation)
+}
+
+/// GetStartOfDay (https://tc39.es/proposal-temporal/#sec-temporal-getstartofday)
+pub fn get_start_of_day(cx: Context, time_zone: &TimeZone, date: IsoDate) -> EvalResult<i128> {
+    let date_time = IsoDateTime::new(date, MIDNIGHT);
+    let possible = get_possible_epoch_nanoseconds(cx, time_zone, date_time)?;
+    if let Some(epoch_nanoseconds) = possible.first() {
+        return Ok(*epoch_nanoseconds);
+    }
+
+    // Midnight was skipped, so the day starts at the transition that skipped it
+    let day_before = date_time.to_utc_epoch_nanoseconds() - NS_PER_DAY;
+    let day_before_seconds = day_before.div_euclid(NS_PER_SECOND) as i64;
+    let Some(transition_seconds) = time_zone.next_transition(cx, day_before_seconds) else {
+        return range_error(cx, "start of day does not exist in this time zone");
+    };
+
+    let epoch_nanoseconds = transition_seconds as i128 * NS_PER_SECOND;
+    if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    Ok(epoch_nanoseconds)
+}
+
+/// CheckISODaysRange (https://tc39.es/proposal-temporal/#sec-temporal-checkisodaysrange)
+pub fn check_iso_days_range(cx: Context, date: IsoDate) -> EvalResult<()> {
+    if date.year.abs() > 300_000 || date.to_epoch_days().abs() > 100_000_000 {
+        return range_error(cx, "date is outside the supported range");
+    }
+
+    Ok(())
+}
+
+/// How the offset of a date-time string or property bag is interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OffsetBehaviour {
+    /// An offset was provided and is handled according to the offset option
+    Option,
+    /// The UTC designator was provided so the date-time is an exact time
+    Exact,
+    /// No offset was provided so the date-time is a wall-clock time
+    Wall,
+}
+
+/// Whether an offset must match a time zone's offset exactly or only when rounded to minutes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchBehaviour {
+    Exactly,
+    Minutes,
+}
+
+/// InterpretISODateTimeOffset (https://tc39.es/proposal-temporal/#sec-temporal-interpretisodatetimeoffset)
+///
+/// A time of None means the start of the day.
+#[allow(clippy::too_many_arguments)]
+pub fn interpret_iso_date_time_offset(
+    cx: Context,
+    date: IsoDate,
+    time: Option<IsoTime>,
+    offset_behaviour: OffsetBehaviour,
+    offset_nanoseconds: i128,
+    time_zone: &TimeZone,
+    disambiguation: Disambiguation,
+    offset_option: OffsetOption,
+    match_behaviour: MatchBehaviour,
+) -> EvalResult<i128> {
+    let Some(time) = time else {
+        return get_start_of_day(cx, time_zone, date);
+    };
+
+    let date_time = IsoDateTime::new(date, time);
+
+    if offset_behaviour == OffsetBehaviour::Wall
+        || (offset_behaviour == OffsetBehaviour::Option && offset_option == OffsetOption::Ignore)
+    {
+        return get_epoch_nanoseconds_for(cx, time_zone, date_time, disambiguation);
+    }
+
+    if offset_behaviour == OffsetBehaviour::Exact || offset_option == OffsetOption::Use {
+        check_iso_days_range(cx, date)?;
+
+        let balanced = date_time.add_time(-offset_nanoseconds);
+        check_iso_days_range(cx, balanced.date)?;
+
+        let epoch_nanoseconds = balanced.to_utc_epoch_nanoseconds();
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return range_error(cx, "date-time is outside the supported range");
+        }
+
+        return Ok(epoch_nanoseconds);
+    }
+
+    check_iso_days_range(cx, date)?;
+
+    let utc_epoch_nanoseconds = date_time.to_utc_epoch_nanoseconds();
+    let possible = get_possible_epoch_nanoseconds(cx, time_zone, date_time)?;
+
+    for candidate in &possible {
+        let candidate_offset = utc_epoch_nanoseconds - candidate;
+        if candidate_offset == offset_nanoseconds {
+            return Ok(*candidate);
+        }
+
+        if match_behaviour == MatchBehaviour::Minutes {
+            let rounded_offset = round_number_to_increment(
+                candidate_offset,
+                NS_PER_MINUTE,
+                RoundingMode::HalfExpand,
+            );
+            if rounded_offset == offset_nanoseconds {
+                return Ok(*candidate);
+            }
+        }
+    }
+
+    if offset_option == OffsetOption::Reject {
+        return range_error(cx, "offset does not match the time zone");
+    }
+
+    disambiguate_possible_epoch_nanoseconds(cx, &possible, time_zone, date_time, disambiguation)
+}
+
+/// Validate a parsed time zone and return its identifier.
+pub fn parsed_time_zone_identifier(cx: Context, time_zone: &ParsedTimeZone) -> EvalResult<String> {
+    match time_zone {
+        ParsedTimeZone::Offset(offset_minutes) => {
+            Ok(format_offset_time_zone_identifier(*offset_minutes))
+        }
+        ParsedTimeZone::Name(name) => match available_named_time_zone_identifier(cx, name) {
+            Some(identifier) => Ok(identifier),
+            None => range_error(cx, &format!("unknown time zone {name}")),
+        },
+    }
+}
+
+/// ToTemporalTimeZoneIdentifier (https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezoneidentifier)
+pub fn to_temporal_time_zone_identifier(
+    cx: Context,
+    value: StackRoot<Value>,
+) -> EvalResult<String> {
+    if value.is_object() {
+        if let Some(zoned_date_time) = value.as_object().as_zoned_date_time_object() {
+            return Ok(zoned_date_time.time_zone_id());
+        }
+    }
+
+    if !value.is_string() {
+        return type_error(cx, "time zone must be a string");
+    }
+
+    let string = value.as_string().format(cx)?;
+    let Some(parsed) = parse_time_zone_string(&string) else {
+        return range_error(cx, &format!("invalid time zone {string}"));
+    };
+
+    parsed_time_zone_identifier(cx, &parsed)
+}
+
+/// TimeZoneEquals (https://tc39.es/proposal-temporal/#sec-temporal-timezoneequals)
+pub fn time_zone_equals(cx: Context, one: &str, two: &str) -> bool {
+    if one == two {
+        return true;
+    }
+
+    match (
+        parse_time_zone_identifier(one),
+        parse_time_zone_identifier(two),
+    ) {
+        (Some(ParsedTimeZone::Offset(one)), Some(ParsedTimeZone::Offset(two))) => one == two,
+        (Some(ParsedTimeZone::Name(one)), Some(ParsedTimeZone::Name(two))) => {
+            match (
+                primary_time_zone_identifier(cx, &one),
+                primary_time_zone_identifier(cx, &two),
+            ) {
+                (Some(one), Some(two)) => one == two,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,545 @@
+use core::mem::size_of;
+
+use alloc::{format, string::String};
+use num_bigint::BigInt;
+
+use crate::{
+    cast_from_value_fn,
+    common::time_zone::TimeZone,
+    extend_object,
+    runtime::{
+        alloc_error::AllocResult,
+        builtin_function::BuiltinFunction,
+        error::{range_error, type_error},
+        eval_result::EvalResult,
+        function::get_argument,
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::HeapItemKind,
+        intl::options::get_options_object,
+        intrinsics::intrinsics::Intrinsic,
+        object_value::ObjectValue,
+        ordinary_object::{get_prototype_from_constructor, object_create_with_proto},
+        realm::Realm,
+        string_value::FlatString,
+        type_utilities::to_bigint,
+        value::BigIntValue,
+        Context, HeapPtr, StackRoot, Value,
+    },
+    set_uninit,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, get_temporal_calendar_identifier_with_iso_default,
+        prepare_calendar_fields, to_calendar_argument, Field, RequiredFields, DATE_FIELDS,
+    },
+    duration::{
+        round_relative_duration, round_time_difference, total_relative_duration,
+        total_time_duration, InternalDuration,
+    },
+    instant_constructor::bigint_to_epoch_nanoseconds,
+    iso::{
+        add_iso_date, difference_iso_date, is_valid_epoch_nanoseconds, iso_date_time_within_limits,
+        DateDuration, IsoDate, IsoDateTime, Overflow,
+    },
+    options::{
+        get_disambiguation_option, get_offset_option, get_overflow_option, Disambiguation,
+        OffsetOption,
+    },
+    parser::{parse_iso_date_time, parse_time_zone_identifier, ParseGoal, ParsedOffset},
+    rounding::{RoundingMode, Unit},
+    time_zone::{
+        get_epoch_nanoseconds_for, get_iso_date_time_for, interpret_iso_date_time_offset,
+        parsed_time_zone_identifier, resolve_time_zone, MatchBehaviour, OffsetBehaviour,
+    },
+};
+
+// Temporal.ZonedDateTime Objects (https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects)
+extend_object! {
+    pub struct ZonedDateTimeObject {
+        epoch_nanoseconds: HeapPtr<BigIntValue>,
+        // Identifier of the time zone, which is either a named time zone or an offset
+        time_zone: HeapPtr<FlatString>,
+    }
+}
+
+impl ZonedDateTimeObject {
+    pub fn new_with_proto(
+        cx: Context,
+        proto: StackRoot<ObjectValue>,
+        epoch_nanoseconds: i128,
+        time_zone: &str,
+    ) -> AllocResult<StackRoot<ZonedDateTimeObject>> {
+        let epoch_nanoseconds = BigIntValue::new(cx, BigInt::from(epoch_nanoseconds))?;
+        let time_zone = cx.alloc_string(time_zone)?;
+
+        let mut object = object_create_with_proto::<ZonedDateTimeObject>(
+            cx,
+            HeapItemKind::ZonedDateTimeObject,
+            proto,
+        )?;
+
+        set_uninit!(object.epoch_nanoseconds, *epoch_nanoseconds);
+        set_uninit!(object.time_zone, *time_zone);
+
+        Ok(object.to_stack(cx))
+    }
+
+    cast_from_value_fn!(ZonedDateTimeObject, "Temporal.ZonedDateTime");
+
+    pub fn epoch_nanoseconds(&self) -> i128 {
+        i128::try_from(&self.epoch_nanoseconds.bigint()).unwrap()
+    }
+
+    pub fn time_zone_id(&self) -> String {
+        self.time_zone.to_string()
+    }
+
+    pub fn time_zone(&self, cx: Context) -> TimeZone {
+        resolve_time_zone(cx, &self.time_zone_id())
+    }
+
+    /// The date and time of this ZonedDateTime in its time zone.
+    pub fn iso_date_time(&self, cx: Context) -> IsoDateTime {
+        get_iso_date_time_for(cx, &self.time_zone(cx), self.epoch_nanoseconds())
+    }
+}
+
+/// CreateTemporalZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-createtemporalzoneddatetime)
+pub fn create_temporal_zoned_date_time(
+    cx: Context,
+    epoch_nanoseconds: i128,
+    time_zone: &str,
+) -> AllocResult<StackRoot<ZonedDateTimeObject>> {
+    let proto = cx.get_intrinsic(Intrinsic::ZonedDateTimePrototype);
+    ZonedDateTimeObject::new_with_proto(cx, proto, epoch_nanoseconds, time_zone)
+}
+
+/// Fields of a ZonedDateTime property bag that are not calendar fields.
+pub const ZONED_NON_CALENDAR_FIELDS: &[Field] = &[
+    Field::Hour,
+    Field::Minute,
+    Field::Second,
+    Field::Millisecond,
+    Field::Microsecond,
+    Field::Nanosecond,
+    Field::Offset,
+    Field::TimeZone,
+];
+
+/// ToTemporalZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime)
+///
+/// Returns the epoch nanoseconds and time zone identifier of the ZonedDateTime. Options may be
+/// undefined.
+pub fn to_temporal_zoned_date_time(
+    cx: Context,
+    item: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<(i128, String)> {
+    let mut offset_behaviour = OffsetBehaviour::Option;
+    let mut match_behaviour = MatchBehaviour::Exactly;
+
+    let (date, time, time_zone, offset_nanoseconds, disambiguation, offset_option) =
+        if item.is_object() {
+            let object = item.as_object();
+
+            if let Some(zoned_date_time) = object.as_zoned_date_time_object() {
+                let options = get_options_object(cx, options)?;
+                get_disambiguation_option(cx, options)?;
+                get_offset_option(cx, options, OffsetOption::Reject)?;
+                get_overflow_option(cx, options)?;
+
+                return Ok((
+                    zoned_date_time.epoch_nanoseconds(),
+                    zoned_date_time.time_zone_id(),
+                ));
+            }
+
+            get_temporal_calendar_identifier_with_iso_default(cx, object)?;
+
+            let fields = prepare_calendar_fields(
+                cx,
+                object,
+                DATE_FIELDS,
+                ZONED_NON_CALENDAR_FIELDS,
+                RequiredFields::List(&[Field::TimeZone]),
+            )?;
+
+            if fields.offset.is_none() {
+                offset_behaviour = OffsetBehaviour::Wall;
+            }
+
+            let options = get_options_object(cx, options)?;
+            let disambiguation = get_disambiguation_option(cx, options)?;
+            let offset_option = get_offset_option(cx, options, OffsetOption::Reject)?;
+            let overflow = get_overflow_option(cx, options)?;
+
+            let date_time = fields.to_date_time(cx, overflow)?;
+
+            (
+                date_time.date,
+                Some(date_time.time),
+                fields.time_zone.unwrap(),
+                fields.offset.unwrap_or(0),
+                disambiguation,
+                offset_option,
+            )
+        } else {
+            if !item.is_string() {
+                return type_error(cx, "ZonedDateTime must be a string or object");
+            }
+
+            let string = item.as_string().format(cx)?;
+            let Some(parsed) = parse_iso_date_time(&string, ParseGoal::ZonedDateTime) else {
+                return range_error(cx, &format!("invalid ZonedDateTime {string}"));
+            };
+
+            let time_zone = parsed_time_zone_identifier(cx, parsed.time_zone.as_ref().unwrap())?;
+
+            let offset_nanoseconds = match parsed.offset {
+                Some(ParsedOffset::Utc) => {
+                    offset_behaviour = OffsetBehaviour::Exact;
+                    0
+                }
+                Some(ParsedOffset::Offset {
+                    nanoseconds,
+                    has_seconds,
+                }) => {
+                    // Offsets written without seconds only need to match when rounded to minutes
+                    if !has_seconds {
+                        match_behaviour = MatchBehaviour::Minutes;
+                    }
+
+                    nanoseconds as i128
+                }
+                None => {
+                    offset_behaviour = OffsetBehaviour::Wall;
+                    0
+                }
+            };
+
+            if let Some(calendar) = &parsed.calendar {
+                canonicalize_calendar(cx, calendar)?;
+            }
+
+            let options = get_options_object(cx, options)?;
+            let disambiguation = get_disambiguation_option(cx, options)?;
+            let offset_option = get_offset_option(cx, options, OffsetOption::Reject)?;
+            get_overflow_option(cx, options)?;
+
+            let date = IsoDate::new(parsed.year.unwrap(), parsed.month, parsed.day);
+
+            (
+                date,
+                parsed.time,
+                time_zone,
+                offset_nanoseconds,
+                disambiguation,
+                offset_option,
+            )
+        };
+
+    let epoch_nanoseconds = interpret_iso_date_time_offset(
+        cx,
+        date,
+        time,
+        offset_behaviour,
+        offset_nanoseconds,
+        &resolve_time_zone(cx, &time_zone),
+        disambiguation,
+        offset_option,
+        match_behaviour,
+    )?;
+
+    Ok((epoch_nanoseconds, time_zone))
+}
+
+/// Validate a time zone identifier passed to the ZonedDateTime constructor, returning the
+/// identifier that is stored.
+fn to_time_zone_argument(cx: Context, value: StackRoot<Value>) -> EvalResult<String> {
+    if !value.is_string() {
+        return type_error(cx, "time zone must be a string");
+    }
+
+    let string = value.as_string().format(cx)?;
+    match parse_time_zone_identifier(&string) {
+        Some(parsed) => parsed_time_zone_identifier(cx, &parsed),
+        None => range_error(cx, &format!("invalid time zone {string}")),
+    }
+}
+
+/// AddInstant (https://tc39.es/proposal-temporal/#sec-temporal-addinstant)
+fn add_instant(cx: Context, epoch_nanoseconds: i128, time_duration: i128) -> EvalResult<i128> {
+    let result = epoch_nanoseconds + time_duration;
+    if !is_valid_epoch_nanoseconds(result) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    Ok(result)
+}
+
+/// AddZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-addzoneddatetime)
+pub fn add_zoned_date_time(
+    cx: Context,
+    epoch_nanoseconds: i128,
+    time_zone: &TimeZone,
+    duration: &InternalDuration,
+    overflow: Overflow,
+) -> EvalResult<i128> {
+    if duration.date.sign() == 0 {
+        return add_instant(cx, epoch_nanoseconds, duration.time);
+    }
+
+    let date_time = get_iso_date_time_for(cx, time_zone, epoch_nanoseconds);
+    let Some(added_date) = add_iso_date(date_time.date, duration.date, overflow) else {
+        return range_error(cx, "date is outside the supported range");
+    };
+
+    let intermediate_date_time = IsoDateTime::new(added_date, date_time.time);
+    if !iso_date_time_within_limits(intermediate_date_time) {
+        return range_error(cx, "date-time is outside the supported range");
+    }
+
+    let intermediate_nanoseconds = get_epoch_nanoseconds_for(
+        cx,
+        time_zone,
+        intermediate_date_time,
+        Disambiguation::Compatible,
+    )?;
+
+    add_instant(cx, intermediate_nanoseconds, duration.time)
+}
+
+/// DifferenceZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetime)
+fn difference_zoned_date_time(
+    cx: Context,
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    largest_unit: Unit,
+) -> EvalResult<InternalDuration> {
+    if one == two {
+        return Ok(InternalDuration::default());
+    }
+
+    let start_date_time = get_iso_date_time_for(cx, time_zone, one);
+    let end_date_time = get_iso_date_time_for(cx, time_zone, two);
+
+    if start_date_time.date == end_date_time.date {
+        return Ok(InternalDuration::new(DateDuration::default(), two - one));
+    }
+
+    let sign: i64 = if two < one { -1 } else { 1 };
+    let max_day_correction = if sign == 1 { 2 } else { 1 };
+
+    // The wall-clock time of the end may be before the start time, in which case the date
+    // difference is a day less. Transitions may require a further day of correction.
+    let mut day_correction = 0;
+    let time_duration = start_date_time.time.difference(&end_date_time.time);
+    if time_duration.signum() as i64 == -sign {
+        day_correction += 1;
+    }
+
+    let mut result = None;
+    while day_correction <= max_day_correction {
+        let intermediate_date = end_date_time.date.add_days(-day_correction * sign);
+        let intermediate_date_time = IsoDateTime::new(intermediate_date, start_date_time.time);
+        let intermediate_nanoseconds = get_epoch_nanoseconds_for(
+            cx,
+            time_zone,
+            intermediate_date_time,
+            Disambiguation::Compatible,
+        )?;
+
+        let time_duration = two - intermediate_nanoseconds;
+        if sign != -(time_duration.signum() as i64) {
+            result = Some((intermediate_date, time_duration));
+            break;
+        }
+
+        day_correction += 1;
+    }
+
+    let Some((intermediate_date, time_duration)) = result else {
+        return range_error(cx, "could not compute the difference between date-times");
+    };
+
+    let date_largest_unit = largest_unit.max(Unit::Day);
+    let date_difference =
+        difference_iso_date(&start_date_time.date, &intermediate_date, date_largest_unit);
+
+    Ok(InternalDuration::new(date_difference, time_duration))
+}
+
+/// DifferenceZonedDateTimeWithRounding (https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithrounding)
+#[allow(clippy::too_many_arguments)]
+pub fn difference_zoned_date_time_with_rounding(
+    cx: Context,
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    largest_unit: Unit,
+    rounding_increment: i64,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+) -> EvalResult<InternalDuration> {
+    let result = if !largest_unit.is_date_unit() {
+        round_time_difference(two - one, rounding_increment, smallest_unit, rounding_mode)
+    } else {
+        let difference = difference_zoned_date_time(cx, one, two, time_zone, largest_unit)?;
+        if smallest_unit == Unit::Nanosecond && rounding_increment == 1 {
+            return Ok(difference);
+        }
+
+        let date_time = get_iso_date_time_for(cx, time_zone, one);
+        let epoch_nanoseconds_for = |date_time| {
+            get_epoch_nanoseconds_for(cx, time_zone, date_time, Disambiguation::Compatible).ok()
+        };
+
+        round_relative_duration(
+            &difference,
+            two,
+            &date_time,
+            Some(&epoch_nanoseconds_for),
+            largest_unit,
+            rounding_increment,
+            smallest_unit,
+            rounding_mode,
+        )
+    };
+
+    match result {
+        Some(duration) => Ok(duration),
+        None => range_error(cx, "duration is out of range"),
+    }
+}
+
+/// DifferenceZonedDateTimeWithTotal (https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithtotal)
+pub fn difference_zoned_date_time_with_total(
+    cx: Context,
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    unit: Unit,
+) -> EvalResult<f64> {
+    if !unit.is_date_unit() {
+        return Ok(total_time_duration(two - one, unit));
+    }
+
+    let difference = difference_zoned_date_time(cx, one, two, time_zone, unit)?;
+    let date_time = get_iso_date_time_for(cx, time_zone, one);
+    let epoch_nanoseconds_for = |date_time| {
+        get_epoch_nanoseconds_for(cx, time_zone, date_time, Disambiguation::Compatible).ok()
+    };
+
+    match total_relative_duration(
+        &difference,
+        two,
+        &date_time,
+        Some(&epoch_nanoseconds_for),
+        unit,
+    ) {
+        Some(total) => Ok(total),
+        None => range_error(cx, "duration is out of range"),
+    }
+}
+
+pub struct ZonedDateTimeConstructor;
+
+impl ZonedDateTimeConstructor {
+    /// Properties of the Temporal.ZonedDateTime Constructor (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-zoneddatetime-constructor)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut func = BuiltinFunction::intrinsic_constructor(
+            cx,
+            Self::construct,
+            2,
+            cx.names.zoned_date_time(),
+            realm,
+            Intrinsic::FunctionPrototype,
+        )?;
+
+        func.intrinsic_frozen_property(
+            cx,
+            cx.names.prototype(),
+            realm
+                .get_intrinsic(Intrinsic::ZonedDateTimePrototype)
+                .into(),
+        )?;
+
+        func.intrinsic_func(cx, cx.names.compare(), Self::compare, 2, realm)?;
+        func.intrinsic_func(cx, cx.names.from(), Self::from, 1, realm)?;
+
+        Ok(func)
+    }
+
+    /// Temporal.ZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime)
+    pub fn construct(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let new_target = if let Some(new_target) = cx.current_new_target() {
+            new_target
+        } else {
+            return type_error(
+                cx,
+                "Temporal.ZonedDateTime constructor must be called with new",
+            );
+        };
+
+        let epoch_nanoseconds = to_bigint(cx, get_argument(cx, arguments, 0))?;
+        let epoch_nanoseconds = bigint_to_epoch_nanoseconds(cx, epoch_nanoseconds)?;
+
+        let time_zone = to_time_zone_argument(cx, get_argument(cx, arguments, 1))?;
+        to_calendar_argument(cx, get_argument(cx, arguments, 2))?;
+
+        let proto =
+            get_prototype_from_constructor(cx, new_target, Intrinsic::ZonedDateTimePrototype)?;
+
+        Ok(
+            ZonedDateTimeObject::new_with_proto(cx, proto, epoch_nanoseconds, &time_zone)?
+                .as_value(),
+        )
+    }
+
+    /// Temporal.ZonedDateTime.compare (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.compare)
+    pub fn compare(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let (one, _) =
+            to_temporal_zoned_date_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+        let (two, _) =
+            to_temporal_zoned_date_time(cx, get_argument(cx, arguments, 1), cx.undefined())?;
+
+        Ok(cx.smi(one.cmp(&two) as i32))
+    }
+
+    /// Temporal.ZonedDateTime.from (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.from)
+    pub fn from(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let (epoch_nanoseconds, time_zone) = to_temporal_zoned_date_time(
+            cx,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )?;
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone)?.as_value())
+    }
+}
+
+impl HeapItem for HeapPtr<ZonedDateTimeObject> {
+    fn byte_size(&self) -> usize {
+        size_of::<ZonedDateTimeObject>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        self.visit_object_pointers(visitor);
+        visitor.visit_pointer(&mut self.epoch_nanoseconds);
+        visitor.visit_pointer(&mut self.time_zone);
+    }
+}
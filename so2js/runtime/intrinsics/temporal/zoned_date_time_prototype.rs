@@ -0,0 +1,1149 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use num_bigint::BigInt;
+
+use crate::runtime::{
+    abstract_operations::get,
+    alloc_error::AllocResult,
+    error::{range_error, type_error},
+    eval_result::EvalResult,
+    function::get_argument,
+    intl::{
+        date_time_format_constructor::{DefaultComponents, RequiredComponents},
+        options::get_options_object,
+    },
+    intrinsics::intrinsics::Intrinsic,
+    object_value::ObjectValue,
+    property::Property,
+    realm::Realm,
+    value::BigIntValue,
+    Context, StackRoot, Value,
+};
+
+use super::{
+    calendar::{
+        check_partial_temporal_object, iso_date_to_fields, prepare_calendar_fields,
+        to_temporal_calendar_identifier, Field, FieldsType, RequiredFields, DATE_FIELDS, ISO8601,
+    },
+    duration::{
+        round_time_difference, round_time_duration_to_increment, seconds_string_precision,
+        total_time_duration, InternalDuration, Precision,
+    },
+    duration_constructor::{create_difference_duration, to_temporal_duration},
+    format::{
+        date_time_to_string, format_calendar_annotation, format_date_time_utc_offset_rounded,
+        format_utc_offset_nanoseconds, format_with_locales,
+    },
+    instant_constructor::{create_temporal_instant, round_temporal_instant},
+    iso::{
+        days_in_month, days_in_year, is_valid_epoch_nanoseconds, IsoDateTime, NS_PER_MILLISECOND,
+        NS_PER_SECOND,
+    },
+    options::{
+        get_difference_settings, get_direction_option, get_disambiguation_option,
+        get_fractional_second_digits_option, get_offset_option, get_overflow_option,
+        get_rounding_increment_option, get_rounding_mode_option, get_shorthand_options_object,
+        get_show_calendar_option, get_show_offset_option, get_show_time_zone_option,
+        get_unit_valued_option, validate_rounding_increment, validate_unit_value,
+        DifferenceOperation, Direction, Disambiguation, OffsetOption, ShowCalendar, ShowOffset,
+        ShowTimeZone, UnitValue,
+    },
+    plain_date_constructor::create_temporal_date,
+    plain_date_time_constructor::create_temporal_date_time,
+    plain_time_constructor::{create_temporal_time, to_temporal_time},
+    rounding::{RoundingMode, Unit, UnitGroup},
+    time_zone::{
+        get_epoch_nanoseconds_for, get_iso_date_time_for, get_offset_nanoseconds_for,
+        get_start_of_day, interpret_iso_date_time_offset, resolve_time_zone, time_zone_equals,
+        to_temporal_time_zone_identifier, MatchBehaviour, OffsetBehaviour,
+    },
+    zoned_date_time_constructor::{
+        add_zoned_date_time, create_temporal_zoned_date_time,
+        difference_zoned_date_time_with_rounding, to_temporal_zoned_date_time, ZonedDateTimeObject,
+    },
+};
+
+/// Fields of a ZonedDateTime property bag passed to `with` that are not calendar fields.
+const WITH_NON_CALENDAR_FIELDS: &[Field] = &[
+    Field::Hour,
+    Field::Minute,
+    Field::Second,
+    Field::Millisecond,
+    Field::Microsecond,
+    Field::Nanosecond,
+    Field::Offset,
+];
+
+pub struct ZonedDateTimePrototype;
+
+impl ZonedDateTimePrototype {
+    /// Properties of the Temporal.ZonedDateTime Prototype Object (https://tc39.es/proposal-temporal/#sec-properties-of-the-temporal-zoneddatetime-prototype-object)
+    pub fn new(cx: Context, realm: StackRoot<Realm>) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        // Constructor property is added once ZonedDateTimeConstructor has been created
+        object.intrinsic_getter(cx, cx.names.calendar_id(), Self::calendar_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.day(), Self::day, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_week(), Self::day_of_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.day_of_year(), Self::day_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_month(), Self::days_in_month, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_week(), Self::days_in_week, realm)?;
+        object.intrinsic_getter(cx, cx.names.days_in_year(), Self::days_in_year, realm)?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.epoch_milliseconds(),
+            Self::epoch_milliseconds,
+            realm,
+        )?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.epoch_nanoseconds(),
+            Self::epoch_nanoseconds,
+            realm,
+        )?;
+        object.intrinsic_getter(cx, cx.names.era(), Self::era, realm)?;
+        object.intrinsic_getter(cx, cx.names.era_year(), Self::era_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.hour(), Self::hour, realm)?;
+        object.intrinsic_getter(cx, cx.names.hours_in_day(), Self::hours_in_day, realm)?;
+        object.intrinsic_getter(cx, cx.names.in_leap_year(), Self::in_leap_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.microsecond(), Self::microsecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.millisecond(), Self::millisecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.minute(), Self::minute, realm)?;
+        object.intrinsic_getter(cx, cx.names.month(), Self::month, realm)?;
+        object.intrinsic_getter(cx, cx.names.month_code(), Self::month_code, realm)?;
+        object.intrinsic_getter(cx, cx.names.months_in_year(), Self::months_in_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.nanosecond(), Self::nanosecond, realm)?;
+        object.intrinsic_getter(cx, cx.names.offset(), Self::offset, realm)?;
+        object.intrinsic_getter(
+            cx,
+            cx.names.offset_nanoseconds(),
+            Self::offset_nanoseconds,
+            realm,
+        )?;
+        object.intrinsic_getter(cx, cx.names.second(), Self::second, realm)?;
+        object.intrinsic_getter(cx, cx.names.time_zone_id(), Self::time_zone_id, realm)?;
+        object.intrinsic_getter(cx, cx.names.week_of_year(), Self::week_of_year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year(), Self::year, realm)?;
+        object.intrinsic_getter(cx, cx.names.year_of_week(), Self::year_of_week, realm)?;
+        object.intrinsic_func(cx, cx.names.add(), Self::add, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.equals(), Self::equals, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.get_time_zone_transition(),
+            Self::get_time_zone_transition,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.round(), Self::round, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.since(), Self::since, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.start_of_day(), Self::start_of_day, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.subtract(), Self::subtract, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.to_instant(), Self::to_instant, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.to_json(), Self::to_json, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_locale_string(),
+            Self::to_locale_string,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_plain_date(), Self::to_plain_date, 0, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.to_plain_date_time(),
+            Self::to_plain_date_time,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, cx.names.to_plain_time(), Self::to_plain_time, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.to_string(), Self::to_string, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.until(), Self::until, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.value_of(), Self::value_of, 0, realm)?;
+        object.intrinsic_func(cx, cx.names.with(), Self::with, 1, realm)?;
+        object.intrinsic_func(cx, cx.names.with_calendar(), Self::with_calendar, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            cx.names.with_plain_time(),
+            Self::with_plain_time,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            cx.names.with_time_zone(),
+            Self::with_time_zone,
+            1,
+            realm,
+        )?;
+
+        // [Symbol.toStringTag] property
+        let to_string_tag_key = cx.well_known_symbols.to_string_tag();
+        let to_string_tag_value = cx.alloc_string("Temporal.ZonedDateTime")?.as_value();
+        object.set_property(
+            cx,
+            to_string_tag_key,
+            Property::data(to_string_tag_value, false, false, true),
+        )?;
+
+        Ok(object)
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.calendarId (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.calendarid)
+    pub fn calendar_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(ISO8601)?.as_value())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.timeZoneId (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezoneid)
+    pub fn time_zone_id(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.alloc_string(&zoned_date_time.time_zone_id())?.as_value())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.era (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.era)
+    ///
+    /// The ISO 8601 calendar has no eras.
+    pub fn era(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.eraYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.erayear)
+    pub fn era_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.undefined())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.year (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.year)
+    pub fn year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.year as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.month (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.month)
+    pub fn month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.month as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.monthCode (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.monthcode)
+    pub fn month_code(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        let month_code = iso_date_to_fields(&date, FieldsType::Date)
+            .month_code
+            .unwrap();
+
+        Ok(cx.alloc_string(&month_code.to_string())?.as_value())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.day (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.day)
+    pub fn day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.day as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.dayOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.dayofweek)
+    pub fn day_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.day_of_week() as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.dayOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.dayofyear)
+    pub fn day_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.day_of_year() as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.weekOfYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.weekofyear)
+    pub fn week_of_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.week_of_year().0 as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.yearOfWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.yearofweek)
+    pub fn year_of_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(date.week_of_year().1 as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.daysInWeek (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinweek)
+    pub fn days_in_week(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(7))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.daysInMonth (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinmonth)
+    pub fn days_in_month(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(days_in_month(date.year, date.month) as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.daysInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinyear)
+    pub fn days_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.smi(days_in_year(date.year) as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.monthsInYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.monthsinyear)
+    pub fn months_in_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(cx.smi(12))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.inLeapYear (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.inleapyear)
+    pub fn in_leap_year(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let date = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .date;
+        Ok(cx.bool(days_in_year(date.year) == 366))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.hour (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hour)
+    pub fn hour(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.hour as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.minute (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.minute)
+    pub fn minute(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.minute as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.second (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.second)
+    pub fn second(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.second as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.millisecond (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.millisecond)
+    pub fn millisecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.millisecond as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.microsecond (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.microsecond)
+    pub fn microsecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.microsecond as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.nanosecond (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.nanosecond)
+    pub fn nanosecond(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let time = ZonedDateTimeObject::cast_from_value(cx, this_value)?
+            .iso_date_time(cx)
+            .time;
+        Ok(cx.smi(time.nanosecond as i32))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.epochMilliseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmilliseconds)
+    pub fn epoch_milliseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let epoch_milliseconds = zoned_date_time
+            .epoch_nanoseconds()
+            .div_euclid(NS_PER_MILLISECOND);
+
+        Ok(cx.number(epoch_milliseconds as f64))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.epochNanoseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochnanoseconds)
+    pub fn epoch_nanoseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let epoch_nanoseconds = BigInt::from(zoned_date_time.epoch_nanoseconds());
+
+        Ok(BigIntValue::new(cx, epoch_nanoseconds)?.into())
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.hoursInDay (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hoursinday)
+    pub fn hours_in_day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let time_zone = zoned_date_time.time_zone(cx);
+
+        let today = zoned_date_time.iso_date_time(cx).date;
+        let tomorrow = today.add_days(1);
+
+        let today_nanoseconds = get_start_of_day(cx, &time_zone, today)?;
+        let tomorrow_nanoseconds = get_start_of_day(cx, &time_zone, tomorrow)?;
+
+        let hours = total_time_duration(tomorrow_nanoseconds - today_nanoseconds, Unit::Hour);
+
+        Ok(cx.number(hours))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.offsetNanoseconds (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offsetnanoseconds)
+    pub fn offset_nanoseconds(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let offset_nanoseconds = get_offset_nanoseconds_for(
+            cx,
+            &zoned_date_time.time_zone(cx),
+            zoned_date_time.epoch_nanoseconds(),
+        );
+
+        Ok(cx.number(offset_nanoseconds as f64))
+    }
+
+    /// get Temporal.ZonedDateTime.prototype.offset (https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offset)
+    pub fn offset(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let offset_nanoseconds = get_offset_nanoseconds_for(
+            cx,
+            &zoned_date_time.time_zone(cx),
+            zoned_date_time.epoch_nanoseconds(),
+        );
+
+        let string = format_utc_offset_nanoseconds(offset_nanoseconds);
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.with (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.with)
+    pub fn with(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let zoned_date_time_like = get_argument(cx, arguments, 0);
+        check_partial_temporal_object(cx, zoned_date_time_like)?;
+
+        let time_zone_id = zoned_date_time.time_zone_id();
+        let time_zone = resolve_time_zone(cx, &time_zone_id);
+        let epoch_nanoseconds = zoned_date_time.epoch_nanoseconds();
+        let offset_nanoseconds = get_offset_nanoseconds_for(cx, &time_zone, epoch_nanoseconds);
+        let date_time = IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds, offset_nanoseconds);
+
+        let mut fields = iso_date_to_fields(&date_time.date, FieldsType::Date);
+        fields.set_time(&date_time.time);
+        fields.offset = Some(offset_nanoseconds);
+
+        let partial_fields = prepare_calendar_fields(
+            cx,
+            zoned_date_time_like.as_object(),
+            DATE_FIELDS,
+            WITH_NON_CALENDAR_FIELDS,
+            RequiredFields::Partial,
+        )?;
+        let fields = fields.merge(&partial_fields);
+
+        // Options are read in alphabetical order
+        let options = get_options_object(cx, get_argument(cx, arguments, 1))?;
+        let disambiguation = get_disambiguation_option(cx, options)?;
+        let offset_option = get_offset_option(cx, options, OffsetOption::Prefer)?;
+        let overflow = get_overflow_option(cx, options)?;
+
+        let date_time = fields.to_date_time(cx, overflow)?;
+
+        let epoch_nanoseconds = interpret_iso_date_time_offset(
+            cx,
+            date_time.date,
+            Some(date_time.time),
+            OffsetBehaviour::Option,
+            fields.offset.unwrap(),
+            &time_zone,
+            disambiguation,
+            offset_option,
+            MatchBehaviour::Exactly,
+        )?;
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone_id)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.withPlainTime (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaintime)
+    pub fn with_plain_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let time_zone_id = zoned_date_time.time_zone_id();
+        let time_zone = resolve_time_zone(cx, &time_zone_id);
+        let date = zoned_date_time.iso_date_time(cx).date;
+
+        let plain_time_like = get_argument(cx, arguments, 0);
+        let epoch_nanoseconds = if plain_time_like.is_undefined() {
+            get_start_of_day(cx, &time_zone, date)?
+        } else {
+            let time = to_temporal_time(cx, plain_time_like, cx.undefined())?;
+            let date_time = IsoDateTime::new(date, time);
+            get_epoch_nanoseconds_for(cx, &time_zone, date_time, Disambiguation::Compatible)?
+        };
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone_id)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.withTimeZone (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withtimezone)
+    pub fn with_time_zone(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let time_zone = to_temporal_time_zone_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(
+            create_temporal_zoned_date_time(cx, zoned_date_time.epoch_nanoseconds(), &time_zone)?
+                .as_value(),
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.withCalendar (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withcalendar)
+    pub fn with_calendar(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        to_temporal_calendar_identifier(cx, get_argument(cx, arguments, 0))?;
+
+        Ok(create_temporal_zoned_date_time(
+            cx,
+            zoned_date_time.epoch_nanoseconds(),
+            &zoned_date_time.time_zone_id(),
+        )?
+        .as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.add (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.add)
+    pub fn add(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_zoned_date_time(
+            cx,
+            zoned_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            false,
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.subtract (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.subtract)
+    pub fn subtract(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        add_duration_to_zoned_date_time(
+            cx,
+            zoned_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+            true,
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.until (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.until)
+    pub fn until(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_zoned_date_time(
+            cx,
+            DifferenceOperation::Until,
+            zoned_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.since (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.since)
+    pub fn since(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        difference_temporal_zoned_date_time(
+            cx,
+            DifferenceOperation::Since,
+            zoned_date_time,
+            get_argument(cx, arguments, 0),
+            get_argument(cx, arguments, 1),
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.round (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.round)
+    pub fn round(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let round_to = get_argument(cx, arguments, 0);
+        let round_to = get_shorthand_options_object(cx, round_to, cx.names.smallest_unit())?;
+
+        // Options are read in alphabetical order
+        let rounding_increment = get_rounding_increment_option(cx, round_to)?;
+        let rounding_mode = get_rounding_mode_option(cx, round_to, RoundingMode::HalfExpand)?;
+        let smallest_unit = get_unit_valued_option(cx, round_to, cx.names.smallest_unit())?;
+
+        let day = UnitValue::Unit(Unit::Day);
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[day])?;
+        let Some(UnitValue::Unit(smallest_unit)) = smallest_unit else {
+            return range_error(cx, "smallestUnit is required");
+        };
+
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(cx, rounding_increment, maximum, false)?,
+            None => validate_rounding_increment(cx, rounding_increment, 1, true)?,
+        }
+
+        let epoch_nanoseconds = zoned_date_time.epoch_nanoseconds();
+        let time_zone_id = zoned_date_time.time_zone_id();
+
+        if smallest_unit == Unit::Nanosecond && rounding_increment == 1 {
+            return Ok(
+                create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone_id)?.as_value(),
+            );
+        }
+
+        let time_zone = resolve_time_zone(cx, &time_zone_id);
+        let date_time = get_iso_date_time_for(cx, &time_zone, epoch_nanoseconds);
+
+        let rounded_nanoseconds = if smallest_unit == Unit::Day {
+            // Days may not be 24 hours long, so round the progress through the day relative to
+            // the length of the day.
+            let start_nanoseconds = get_start_of_day(cx, &time_zone, date_time.date)?;
+            let end_nanoseconds = get_start_of_day(cx, &time_zone, date_time.date.add_days(1))?;
+
+            let day_length = end_nanoseconds - start_nanoseconds;
+            let day_progress = epoch_nanoseconds - start_nanoseconds;
+
+            let Some(rounded_day_progress) =
+                round_time_duration_to_increment(day_progress, day_length, rounding_mode)
+            else {
+                return range_error(cx, "duration is out of range");
+            };
+
+            start_nanoseconds + rounded_day_progress
+        } else {
+            let rounded = date_time.round(rounding_increment, smallest_unit, rounding_mode);
+            let offset_nanoseconds = get_offset_nanoseconds_for(cx, &time_zone, epoch_nanoseconds);
+
+            interpret_iso_date_time_offset(
+                cx,
+                rounded.date,
+                Some(rounded.time),
+                OffsetBehaviour::Option,
+                offset_nanoseconds,
+                &time_zone,
+                Disambiguation::Compatible,
+                OffsetOption::Prefer,
+                MatchBehaviour::Exactly,
+            )?
+        };
+
+        Ok(create_temporal_zoned_date_time(cx, rounded_nanoseconds, &time_zone_id)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.equals (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.equals)
+    pub fn equals(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let (other_nanoseconds, other_time_zone) =
+            to_temporal_zoned_date_time(cx, get_argument(cx, arguments, 0), cx.undefined())?;
+
+        if zoned_date_time.epoch_nanoseconds() != other_nanoseconds {
+            return Ok(cx.bool(false));
+        }
+
+        let is_equal = time_zone_equals(cx, &zoned_date_time.time_zone_id(), &other_time_zone);
+
+        Ok(cx.bool(is_equal))
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toString (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tostring)
+    pub fn to_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let options = get_options_object(cx, get_argument(cx, arguments, 0))?;
+
+        // Options are read in alphabetical order
+        let show_calendar = get_show_calendar_option(cx, options)?;
+        let digits = get_fractional_second_digits_option(cx, options)?;
+        let show_offset = get_show_offset_option(cx, options)?;
+        let rounding_mode = get_rounding_mode_option(cx, options, RoundingMode::Trunc)?;
+        let smallest_unit = get_unit_valued_option(cx, options, cx.names.smallest_unit())?;
+        let show_time_zone = get_show_time_zone_option(cx, options)?;
+
+        validate_unit_value(cx, smallest_unit, UnitGroup::Time, &[])?;
+        let smallest_unit = match smallest_unit {
+            Some(UnitValue::Unit(Unit::Hour)) => {
+                return range_error(cx, "smallestUnit cannot be hour");
+            }
+            Some(UnitValue::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        let (precision, unit, increment) = seconds_string_precision(smallest_unit, digits);
+        let epoch_nanoseconds = round_temporal_instant(
+            zoned_date_time.epoch_nanoseconds(),
+            increment,
+            unit,
+            rounding_mode,
+        );
+
+        let string = temporal_zoned_date_time_to_string(
+            cx,
+            epoch_nanoseconds,
+            &zoned_date_time.time_zone_id(),
+            precision,
+            show_calendar,
+            show_time_zone,
+            show_offset,
+        );
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toLocaleString (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tolocalestring)
+    pub fn to_locale_string(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        // The time zone of the ZonedDateTime is always used, so a time zone option is an error
+        let options = get_argument(cx, arguments, 1);
+        if options.is_object() {
+            let time_zone = get(cx, options.as_object(), cx.names.time_zone())?;
+            if !time_zone.is_undefined() {
+                return type_error(cx, "timeZone option is not allowed");
+            }
+        }
+
+        let string = format_with_locales(
+            cx,
+            zoned_date_time.epoch_nanoseconds(),
+            Some(&zoned_date_time.time_zone_id()),
+            get_argument(cx, arguments, 0),
+            options,
+            RequiredComponents::Any,
+            DefaultComponents::All,
+        )?;
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toJSON (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tojson)
+    pub fn to_json(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        let string = temporal_zoned_date_time_to_string(
+            cx,
+            zoned_date_time.epoch_nanoseconds(),
+            &zoned_date_time.time_zone_id(),
+            Precision::Auto,
+            ShowCalendar::Auto,
+            ShowTimeZone::Auto,
+            ShowOffset::Auto,
+        );
+
+        Ok(cx.alloc_string(&string)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.valueOf (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.valueof)
+    pub fn value_of(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        type_error(
+            cx,
+            "Temporal.ZonedDateTime cannot be converted to a primitive",
+        )
+    }
+
+    /// Temporal.ZonedDateTime.prototype.startOfDay (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.startofday)
+    pub fn start_of_day(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let time_zone_id = zoned_date_time.time_zone_id();
+        let time_zone = resolve_time_zone(cx, &time_zone_id);
+        let date = zoned_date_time.iso_date_time(cx).date;
+
+        let epoch_nanoseconds = get_start_of_day(cx, &time_zone, date)?;
+
+        Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone_id)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.getTimeZoneTransition (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.gettimezonetransition)
+    pub fn get_time_zone_transition(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+
+        let direction = get_argument(cx, arguments, 0);
+        let direction = get_shorthand_options_object(cx, direction, cx.names.direction())?;
+        let direction = get_direction_option(cx, direction)?;
+
+        let time_zone_id = zoned_date_time.time_zone_id();
+        let time_zone = resolve_time_zone(cx, &time_zone_id);
+        let epoch_nanoseconds = zoned_date_time.epoch_nanoseconds();
+
+        // Transitions occur on whole seconds, so search from the second that excludes the
+        // current instant.
+        let transition_seconds = match direction {
+            Direction::Next => {
+                let epoch_seconds = epoch_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+                time_zone.next_transition(cx, epoch_seconds)
+            }
+            Direction::Previous => {
+                let epoch_seconds = -((-epoch_nanoseconds).div_euclid(NS_PER_SECOND)) as i64;
+                time_zone.previous_transition(cx, epoch_seconds)
+            }
+        };
+
+        let Some(transition_seconds) = transition_seconds else {
+            return Ok(cx.null());
+        };
+
+        let transition_nanoseconds = transition_seconds as i128 * NS_PER_SECOND;
+        if !is_valid_epoch_nanoseconds(transition_nanoseconds) {
+            return Ok(cx.null());
+        }
+
+        Ok(create_temporal_zoned_date_time(cx, transition_nanoseconds, &time_zone_id)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toInstant (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toinstant)
+    pub fn to_instant(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_instant(cx, zoned_date_time.epoch_nanoseconds())?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toPlainDate (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindate)
+    pub fn to_plain_date(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_date(cx, zoned_date_time.iso_date_time(cx).date)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toPlainTime (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaintime)
+    pub fn to_plain_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_time(cx, zoned_date_time.iso_date_time(cx).time)?.as_value())
+    }
+
+    /// Temporal.ZonedDateTime.prototype.toPlainDateTime (https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindatetime)
+    pub fn to_plain_date_time(
+        cx: Context,
+        this_value: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let zoned_date_time = ZonedDateTimeObject::cast_from_value(cx, this_value)?;
+        Ok(create_temporal_date_time(cx, zoned_date_time.iso_date_time(cx))?.as_value())
+    }
+}
+
+/// TemporalZonedDateTimeToString (https://tc39.es/proposal-temporal/#sec-temporal-temporalzoneddatetimetostring)
+///
+/// The epoch nanoseconds must already be rounded to the precision.
+fn temporal_zoned_date_time_to_string(
+    cx: Context,
+    epoch_nanoseconds: i128,
+    time_zone_id: &str,
+    precision: Precision,
+    show_calendar: ShowCalendar,
+    show_time_zone: ShowTimeZone,
+    show_offset: ShowOffset,
+) -> String {
+    let time_zone = resolve_time_zone(cx, time_zone_id);
+    let offset_nanoseconds = get_offset_nanoseconds_for(cx, &time_zone, epoch_nanoseconds);
+    let date_time = IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds, offset_nanoseconds);
+
+    let mut result = date_time_to_string(&date_time, precision, ShowCalendar::Never);
+
+    if show_offset != ShowOffset::Never {
+        result.push_str(&format_date_time_utc_offset_rounded(offset_nanoseconds));
+    }
+
+    match show_time_zone {
+        ShowTimeZone::Never => {}
+        ShowTimeZone::Auto => result.push_str(&format!("[{time_zone_id}]")),
+        ShowTimeZone::Critical => result.push_str(&format!("[!{time_zone_id}]")),
+    }
+
+    result.push_str(format_calendar_annotation(show_calendar));
+    result
+}
+
+/// AddDurationToZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime)
+fn add_duration_to_zoned_date_time(
+    cx: Context,
+    zoned_date_time: StackRoot<ZonedDateTimeObject>,
+    duration_like: StackRoot<Value>,
+    options: StackRoot<Value>,
+    is_subtract: bool,
+) -> EvalResult<StackRoot<Value>> {
+    let mut duration = to_temporal_duration(cx, duration_like)?;
+    if is_subtract {
+        duration = duration.negated();
+    }
+
+    let options = get_options_object(cx, options)?;
+    let overflow = get_overflow_option(cx, options)?;
+
+    let time_zone_id = zoned_date_time.time_zone_id();
+    let internal_duration = InternalDuration::from_duration(&duration);
+    let epoch_nanoseconds = add_zoned_date_time(
+        cx,
+        zoned_date_time.epoch_nanoseconds(),
+        &resolve_time_zone(cx, &time_zone_id),
+        &internal_duration,
+        overflow,
+    )?;
+
+    Ok(create_temporal_zoned_date_time(cx, epoch_nanoseconds, &time_zone_id)?.as_value())
+}
+
+/// DifferenceTemporalZonedDateTime (https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime)
+fn difference_temporal_zoned_date_time(
+    cx: Context,
+    operation: DifferenceOperation,
+    zoned_date_time: StackRoot<ZonedDateTimeObject>,
+    other: StackRoot<Value>,
+    options: StackRoot<Value>,
+) -> EvalResult<StackRoot<Value>> {
+    let (other_nanoseconds, other_time_zone) =
+        to_temporal_zoned_date_time(cx, other, cx.undefined())?;
+    let options = get_options_object(cx, options)?;
+    let settings = get_difference_settings(
+        cx,
+        operation,
+        options,
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Hour,
+    )?;
+
+    let epoch_nanoseconds = zoned_date_time.epoch_nanoseconds();
+
+    // Differences in time units do not depend on the time zone
+    if !settings.largest_unit.is_date_unit() {
+        let Some(internal_duration) = round_time_difference(
+            other_nanoseconds - epoch_nanoseconds,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        ) else {
+            return range_error(cx, "duration is out of range");
+        };
+
+        return create_difference_duration(
+            cx,
+            &internal_duration,
+            settings.largest_unit,
+            operation,
+        );
+    }
+
+    let time_zone_id = zoned_date_time.time_zone_id();
+    if !time_zone_equals(cx, &time_zone_id, &other_time_zone) {
+        return range_error(
+            cx,
+            "cannot compute the difference in date units between different time zones",
+        );
+    }
+
+    if epoch_nanoseconds == other_nanoseconds {
+        return create_difference_duration(cx, &InternalDuration::default(), Unit::Hour, operation);
+    }
+
+    let internal_duration = difference_zoned_date_time_with_rounding(
+        cx,
+        epoch_nanoseconds,
+        other_nanoseconds,
+        &resolve_time_zone(cx, &time_zone_id),
+        settings.largest_unit,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )?;
+
+    create_difference_duration(cx, &internal_duration, Unit::Hour, operation)
+}
@@ -246,12 +246,7 @@ macro_rules! create_typed_array_constructor {
                 byte_index: usize,
                 value: $element_type,
             ) {
-                unsafe {
-                    let byte_ptr = array_buffer.data().as_mut_ptr().add(byte_index);
-                    let element_ptr = byte_ptr.cast::<$element_type>();
-
-                    element_ptr.write(value)
-                }
+                array_buffer.write_value::<$element_type>(byte_index, value)
             }
         }
 
@@ -519,10 +514,7 @@ macro_rules! create_typed_array_constructor {
                 mut array_buffer: HeapPtr<ArrayBufferObject>,
                 byte_index: usize,
             ) -> AllocResult<StackRoot<Value>> {
-                let element = unsafe {
-                    let byte_ptr = array_buffer.data().as_ptr().add(byte_index);
-                    byte_ptr.cast::<$element_type>().read()
-                };
+                let element = array_buffer.read_value::<$element_type>(byte_index);
 
                 // May allocate
                 $from_element(cx, element)
@@ -538,16 +530,8 @@ macro_rules! create_typed_array_constructor {
                 // May allocate, so call before accessing array buffer
                 let element_value = $to_element(cx, value)?;
 
-                unsafe {
-                    let byte_ptr = self
-                        .viewed_array_buffer_ptr()
-                        .data()
-                        .as_mut_ptr()
-                        .add(byte_index);
-                    let element_ptr = byte_ptr.cast::<$element_type>();
-
-                    element_ptr.write(element_value)
-                }
+                self.viewed_array_buffer_ptr()
+                    .write_value::<$element_type>(byte_index, element_value);
 
                 Ok(())
             }
@@ -655,7 +639,7 @@ macro_rules! create_typed_array_constructor {
                         proto,
                         argument.as_typed_array(),
                     );
-                } else if let Some(argument) = argument.as_array_buffer() {
+                } else if let Some(argument) = argument.as_any_array_buffer() {
                     let byte_offset = get_argument(cx, arguments, 1);
                     let length = get_argument(cx, arguments, 2);
 
@@ -16,7 +16,7 @@ use crate::{
         function::get_argument,
         get,
         intrinsics::{
-            array_buffer_constructor::clone_array_buffer,
+            array_buffer_constructor::{clone_array_buffer, copy_array_buffer_data},
             array_iterator::{ArrayIterator, ArrayIteratorKind},
         },
         object_value::ObjectValue,
@@ -280,53 +280,38 @@ impl TypedArrayPrototype {
         let from_byte_index = from_start_index * element_size + byte_offset;
         let mut count_bytes = count as u64 * element_size;
 
-        let data_ptr = typed_array.viewed_array_buffer_ptr().data().as_mut_ptr();
+        let array_buffer = typed_array.viewed_array_buffer_ptr();
 
-        // Copy bytes one at a time from from_ptr to to_ptr
-        unsafe {
-            if from_byte_index < to_byte_index && to_byte_index < from_byte_index + count_bytes {
-                let from_byte_index = from_byte_index + count_bytes - 1;
-                let to_byte_index = to_byte_index + count_bytes - 1;
-
-                let mut from_ptr = data_ptr.add(from_byte_index as usize);
-                let mut to_ptr = data_ptr.add(to_byte_index as usize);
-
-                // Copy backwards, so only need to check against `buffer_byte_limit` once at start
-                // of loop.
-                if from_byte_index < buffer_byte_limit && to_byte_index < buffer_byte_limit {
-                    while count_bytes > 0 {
-                        let byte = from_ptr.read();
-                        to_ptr.write(byte);
-
-                        from_ptr = from_ptr.sub(1);
-                        to_ptr = to_ptr.sub(1);
-                        count_bytes -= 1;
-                    }
-                }
-            } else {
-                let mut from_ptr = data_ptr.add(from_byte_index as usize);
-                let mut to_ptr = data_ptr.add(to_byte_index as usize);
-
-                // Calculate the number of bytes that should be left if the from or to byte indices
-                // reach the buffer byte limit.
-                let count_bytes_left = i64::min(
-                    buffer_byte_limit as i64 - from_byte_index as i64,
-                    buffer_byte_limit as i64 - to_byte_index as i64,
-                )
-                .max(0) as u64;
-
-                // We can only copy bytes up until the buffer byte limit is reached.
-                count_bytes = count_bytes.min(count_bytes_left);
+        if from_byte_index < to_byte_index && to_byte_index < from_byte_index + count_bytes {
+            // The ranges overlap and bytes are copied backwards, so only the last byte of each
+            // range needs to be checked against `buffer_byte_limit`.
+            if from_byte_index + count_bytes - 1 >= buffer_byte_limit
+                || to_byte_index + count_bytes - 1 >= buffer_byte_limit
+            {
+                count_bytes = 0;
+            }
+        } else {
+            // Calculate the number of bytes that should be left if the from or to byte indices
+            // reach the buffer byte limit.
+            let count_bytes_left = i64::min(
+                buffer_byte_limit as i64 - from_byte_index as i64,
+                buffer_byte_limit as i64 - to_byte_index as i64,
+            )
+            .max(0) as u64;
 
-                while count_bytes > 0 {
-                    let byte = from_ptr.read();
-                    to_ptr.write(byte);
+            // We can only copy bytes up until the buffer byte limit is reached.
+            count_bytes = count_bytes.min(count_bytes_left);
+        }
 
-                    from_ptr = from_ptr.add(1);
-                    to_ptr = to_ptr.add(1);
-                    count_bytes -= 1;
-                }
-            }
+        // Overlapping ranges are copied as if through an intermediate buffer
+        unsafe {
+            copy_array_buffer_data(
+                array_buffer,
+                from_byte_index as usize,
+                array_buffer,
+                to_byte_index as usize,
+                count_bytes as usize,
+            )
         }
 
         Ok(object.as_value())
@@ -1089,7 +1074,7 @@ impl TypedArrayPrototype {
         target_offset: f64,
         source: DynTypedArray,
     ) -> EvalResult<()> {
-        let mut target_buffer = target.viewed_array_buffer(cx);
+        let target_buffer = target.viewed_array_buffer(cx);
 
         let target_record = make_typed_array_with_buffer_witness_record(target);
         if is_typed_array_out_of_bounds(&target_record) {
@@ -1130,35 +1115,32 @@ impl TypedArrayPrototype {
         let target_byte_index = (target_offset as usize * target_element_size) + target_byte_offset;
         let limit = target_byte_index + (target_element_size * source_length);
 
-        unsafe {
-            let mut from_ptr = source_buffer.data().as_mut_ptr().add(source_byte_index);
-            let mut to_ptr = target_buffer.data().as_mut_ptr().add(target_byte_index);
-            let limit_ptr = target_buffer.data().as_mut_ptr().add(limit);
-
+        if source.kind() != target.kind() {
             let mut from_byte_index = source_byte_index;
             let mut to_byte_index = target_byte_index;
 
-            if source.kind() != target.kind() {
-                // If types are different then can access bytes directly but must convert
-                while to_byte_index < limit {
-                    // Convert between types. May allocate but does not invoke user code.
-                    let element_value =
-                        source.read_element_value(cx, *source_buffer, from_byte_index)?;
+            // If types are different then can access bytes directly but must convert
+            while to_byte_index < limit {
+                // Convert between types. May allocate but does not invoke user code.
+                let element_value =
+                    source.read_element_value(cx, *source_buffer, from_byte_index)?;
 
-                    target.write_element_value(cx, to_byte_index, element_value)?;
+                target.write_element_value(cx, to_byte_index, element_value)?;
 
-                    from_byte_index += source_element_size;
-                    to_byte_index += target_element_size;
-                }
-            } else {
-                // Otherwse copy bytes directly instead of performing any conversions
-                while to_ptr < limit_ptr {
-                    let byte = from_ptr.read();
-                    to_ptr.write(byte);
-
-                    from_ptr = from_ptr.add(1);
-                    to_ptr = to_ptr.add(1);
-                }
+                from_byte_index += source_element_size;
+                to_byte_index += target_element_size;
+            }
+        } else {
+            // Otherwse copy bytes directly instead of performing any conversions. The source was
+            // cloned if it shared a buffer with the target, so the ranges cannot overlap.
+            unsafe {
+                copy_array_buffer_data(
+                    *source_buffer,
+                    source_byte_index,
+                    *target_buffer,
+                    target_byte_index,
+                    limit - target_byte_index,
+                )
             }
         }
 
@@ -1284,17 +1266,28 @@ impl TypedArrayPrototype {
             let source_byte_offset = typed_array.byte_offset();
             let source_byte_index = (start_index as usize) * element_size + source_byte_offset;
             let target_byte_index = new_typed_array.byte_offset();
-
-            unsafe {
-                let mut from_ptr = source_buffer.data().as_mut_ptr().add(source_byte_index);
-                let mut to_ptr = target_buffer.data().as_mut_ptr().add(target_byte_index);
-
-                for _ in 0..(count as usize * element_size) {
-                    let byte = from_ptr.read();
-                    to_ptr.write(byte);
-
-                    from_ptr = from_ptr.add(1);
-                    to_ptr = to_ptr.add(1);
+            let byte_length = count as usize * element_size;
+
+            // Bytes are copied one at a time from the start, which differs from a bulk copy if the
+            // new typed array views the same data block and starts within the copied range.
+            let is_overlapping = source_buffer.data_ptr() == target_buffer.data_ptr()
+                && source_byte_index < target_byte_index
+                && target_byte_index < source_byte_index + byte_length;
+
+            if is_overlapping {
+                for i in 0..byte_length {
+                    let byte = source_buffer.read_value::<u8>(source_byte_index + i);
+                    target_buffer.write_value::<u8>(target_byte_index + i, byte);
+                }
+            } else {
+                unsafe {
+                    copy_array_buffer_data(
+                        *source_buffer,
+                        source_byte_index,
+                        *target_buffer,
+                        target_byte_index,
+                        byte_length,
+                    )
                 }
             }
         }
@@ -1804,7 +1797,7 @@ fn typed_array_create_same_type(
 
 /// ValidateTypedArray (https://tc39.es/ecma262/#sec-validatetypedarray)
 #[inline]
-fn validate_typed_array(
+pub fn validate_typed_array(
     cx: Context,
     value: StackRoot<Value>,
 ) -> EvalResult<TypedArrayWithBufferWitnessRecord> {
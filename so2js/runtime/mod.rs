@@ -16,6 +16,8 @@ pub mod collections;
 pub mod console;
 pub mod context;
 pub mod debug_print;
+pub mod coverage;
+pub mod debugger;
 pub mod error;
 pub mod eval;
 pub mod eval_result;
@@ -26,7 +28,12 @@ pub mod gc_object;
 pub mod generator_object;
 pub mod global_names;
 pub mod heap_item_descriptor;
+pub mod heap_snapshot;
+pub mod heap_statistics;
+#[cfg(feature = "heap_verify")]
+pub mod heap_verify;
 pub mod interned_strings;
+pub mod intl;
 pub mod intrinsics;
 pub mod iterator;
 pub mod module;
@@ -34,6 +41,7 @@ pub mod numeric_constants;
 pub mod numeric_operations;
 pub mod object_value;
 pub mod ordinary_object;
+pub mod profiler;
 pub mod promise_object;
 pub mod property;
 pub mod property_descriptor;
@@ -44,6 +52,8 @@ pub mod regexp;
 pub mod rust_vtables;
 pub mod scope;
 pub mod scope_names;
+pub mod shape;
+pub mod shared_memory;
 pub mod source_file;
 pub mod stack;
 pub mod stack_trace;
@@ -22,15 +22,34 @@ use super::{
     generator_object::GeneratorObject,
     heap_item_descriptor::HeapItemKind,
     intrinsics::{
-        array_buffer_constructor::ArrayBufferObject, bigint_constructor::BigIntObject,
-        boolean_constructor::BooleanObject, data_view_constructor::DataViewObject,
-        date_object::DateObject, error_constructor::ErrorObject,
+        array_buffer_constructor::ArrayBufferObject,
+        bigint_constructor::BigIntObject,
+        boolean_constructor::BooleanObject,
+        data_view_constructor::DataViewObject,
+        date_object::DateObject,
+        error_constructor::ErrorObject,
         finalization_registry_object::FinalizationRegistryObject,
-        iterator_constructor::WrappedValidIterator, iterator_helper_object::IteratorHelperObject,
-        map_object::MapObject, number_constructor::NumberObject, object_prototype::ObjectPrototype,
-        regexp_constructor::RegExpObject, rust_runtime::RustRuntimeFunction, set_object::SetObject,
-        symbol_constructor::SymbolObject, typed_array::DynTypedArray,
-        weak_map_object::WeakMapObject, weak_ref_constructor::WeakRefObject,
+        iterator_constructor::WrappedValidIterator,
+        iterator_helper_object::IteratorHelperObject,
+        map_object::MapObject,
+        number_constructor::NumberObject,
+        object_prototype::ObjectPrototype,
+        regexp_constructor::RegExpObject,
+        rust_runtime::RustRuntimeFunction,
+        set_object::SetObject,
+        symbol_constructor::SymbolObject,
+        temporal::{
+            duration_constructor::DurationObject, instant_constructor::InstantObject,
+            plain_date_constructor::PlainDateObject,
+            plain_date_time_constructor::PlainDateTimeObject,
+            plain_month_day_constructor::PlainMonthDayObject,
+            plain_time_constructor::PlainTimeObject,
+            plain_year_month_constructor::PlainYearMonthObject,
+            zoned_date_time_constructor::ZonedDateTimeObject,
+        },
+        typed_array::DynTypedArray,
+        weak_map_object::WeakMapObject,
+        weak_ref_constructor::WeakRefObject,
         weak_set_object::WeakSetObject,
     },
     promise_object::PromiseObject,
@@ -38,6 +57,7 @@ use super::{
     property_descriptor::PropertyDescriptor,
     property_key::PropertyKey,
     proxy_object::ProxyObject,
+    shape::{Shape, MAX_SHAPE_DELETED_SLOTS, MAX_SHAPE_SLOTS},
     string_object::StringObject,
     type_utilities::is_callable_object,
     value::{SymbolValue, Value},
@@ -66,6 +86,9 @@ macro_rules! extend_object_without_conversions {
             // String and symbol properties by their property key. Includes private properties.
             named_properties: $crate::runtime::HeapPtr<$crate::runtime::collections::BsIndexMap<$crate::runtime::property_key::PropertyKey, $crate::runtime::property::HeapProperty>>,
 
+            // Shape describing the layout of the named properties. None if in dictionary mode.
+            shape: Option<$crate::runtime::HeapPtr<$crate::runtime::shape::Shape>>,
+
             // Array index properties by their property key
             array_properties: $crate::runtime::HeapPtr<$crate::runtime::array_properties::ArrayProperties>,
 
@@ -185,6 +208,7 @@ impl ObjectValue {
         );
         set_uninit!(object.prototype, prototype.map(|p| *p));
         set_uninit!(object.named_properties, cx.default_named_properties);
+        set_uninit!(object.shape, Some(cx.root_shape));
         set_uninit!(object.array_properties, cx.default_array_properties);
         set_uninit!(object.is_extensible_field, is_extensible);
         object.set_uninit_hash_code();
@@ -266,6 +290,21 @@ impl ObjectValue {
         self.named_properties = properties;
     }
 
+    #[inline]
+    pub fn named_properties(&self) -> HeapPtr<NamedPropertiesMap> {
+        self.named_properties
+    }
+
+    #[inline]
+    pub fn shape(&self) -> Option<HeapPtr<Shape>> {
+        self.shape
+    }
+
+    #[inline]
+    pub fn set_shape(&mut self, shape: Option<HeapPtr<Shape>>) {
+        self.shape = shape;
+    }
+
     #[inline]
     pub fn array_properties(&self) -> HeapPtr<ArrayProperties> {
         self.array_properties
@@ -302,9 +341,10 @@ impl ObjectValue {
 }
 
 impl ObjectValue {
+    /// Whether this is an ArrayBuffer or a SharedArrayBuffer, which share the same representation.
     #[inline]
-    pub fn is_shared_array_buffer(&self) -> bool {
-        false
+    pub fn is_any_array_buffer(&self) -> bool {
+        self.is_array_buffer() || self.is_shared_array_buffer()
     }
 
     #[inline]
@@ -338,6 +378,15 @@ impl HeapPtr<ObjectValue> {
 }
 
 impl StackRoot<ObjectValue> {
+    #[inline]
+    pub fn as_any_array_buffer(&self) -> Option<StackRoot<ArrayBufferObject>> {
+        if self.is_any_array_buffer() {
+            Some(self.cast())
+        } else {
+            None
+        }
+    }
+
     /// Cast as a virtual object, allowing virtual methods to be called. Manually constructs a
     /// trait object using the vtable stored in the object descriptor.
     #[inline]
@@ -378,24 +427,64 @@ impl StackRoot<ObjectValue> {
             return Ok(());
         }
 
-        // Safe since insert does allocate on managed heap
-        self.named_properties_field()
+        self.insert_named_property(cx, key, property)
+    }
+
+    /// Insert a named property, overwriting the existing property with that key if one exists.
+    /// Keeps the object's shape in sync with its named properties map.
+    fn insert_named_property(
+        &mut self,
+        cx: Context,
+        key: StackRoot<PropertyKey>,
+        property: Property,
+    ) -> AllocResult<()> {
+        // Safe since insert does not allocate on managed heap
+        let is_overwrite = self
+            .named_properties_field()
             .maybe_grow_for_insertion(cx)?
             .insert_without_growing(*key, property.to_heap());
 
+        let shape = match self.shape {
+            None => return Ok(()),
+            Some(shape) => shape,
+        };
+
+        let named_properties = self.named_properties;
+        if named_properties.num_entries_used() > MAX_SHAPE_SLOTS {
+            self.set_shape(None);
+        } else if named_properties.num_entries_deleted() != shape.num_deleted() {
+            // Deleted entries were dropped when the map grew, so slots have been renumbered
+            let new_shape = Shape::from_named_properties(cx, *self)?;
+            self.set_shape(new_shape);
+        } else if !is_overwrite {
+            let new_shape = shape.to_stack(cx).add_property_transition(cx, key)?;
+            self.set_shape(new_shape);
+        }
+
         Ok(())
     }
 
-    pub fn remove_property(&mut self, key: StackRoot<PropertyKey>) {
+    pub fn remove_property(&mut self, cx: Context, key: StackRoot<PropertyKey>) -> AllocResult<()> {
         if key.is_array_index() {
             let array_index = key.as_array_index();
             self.array_properties.remove_property(array_index);
 
-            return;
+            return Ok(());
         }
 
         // Removal is O(1) but leaves permanent tombstone
-        self.named_properties.remove(&key);
+        let was_removed = self.named_properties.remove(&key);
+
+        if let (true, Some(shape)) = (was_removed, self.shape) {
+            if shape.num_deleted() >= MAX_SHAPE_DELETED_SLOTS {
+                self.set_shape(None);
+            } else {
+                let new_shape = shape.to_stack(cx).delete_property_transition(cx, key)?;
+                self.set_shape(new_shape);
+            }
+        }
+
+        Ok(())
     }
 
     pub fn private_element_set(
@@ -406,12 +495,7 @@ impl StackRoot<ObjectValue> {
     ) -> AllocResult<()> {
         let property_key = PropertyKey::symbol(private_name);
         let property = Property::private_field(value);
-        // Safe since insert does not allocate on managed heap
-        self.named_properties_field()
-            .maybe_grow_for_insertion(cx)?
-            .insert_without_growing(*property_key, property.to_heap());
-
-        Ok(())
+        self.insert_named_property(cx, property_key, property)
     }
 
     /// PrivateFieldAdd (https://tc39.es/ecma262/#sec-privatefieldadd)
@@ -425,11 +509,8 @@ impl StackRoot<ObjectValue> {
         if self.has_private_element(private_name) {
             type_error(cx, "private property already defined")
         } else {
-            // Safe since insert does not allocate on managed heap
             let property_key = PropertyKey::symbol(private_name);
-            self.named_properties_field()
-                .maybe_grow_for_insertion(cx)?
-                .insert_without_growing(*property_key, private_property.to_heap());
+            self.insert_named_property(cx, property_key, private_property)?;
 
             Ok(())
         }
@@ -777,6 +858,7 @@ impl HeapItem for HeapPtr<ObjectValue> {
         visitor.visit_pointer(&mut self.descriptor);
         visitor.visit_pointer_opt(&mut self.prototype);
         visitor.visit_pointer(&mut self.named_properties);
+        visitor.visit_pointer_opt(&mut self.shape);
         visitor.visit_pointer(&mut self.array_properties);
     }
 }
@@ -896,6 +978,12 @@ impl_subtype_casts!(
     is_array_buffer,
     as_array_buffer
 );
+impl_subtype_casts!(
+    ArrayBufferObject,
+    HeapItemKind::SharedArrayBufferObject,
+    is_shared_array_buffer,
+    as_shared_array_buffer
+);
 impl_subtype_casts!(
     DataViewObject,
     HeapItemKind::DataViewObject,
@@ -958,3 +1046,51 @@ impl_subtype_casts!(
     is_iterator_helper_object,
     as_iterator_helper_object
 );
+impl_subtype_casts!(
+    InstantObject,
+    HeapItemKind::InstantObject,
+    is_instant_object,
+    as_instant_object
+);
+impl_subtype_casts!(
+    ZonedDateTimeObject,
+    HeapItemKind::ZonedDateTimeObject,
+    is_zoned_date_time_object,
+    as_zoned_date_time_object
+);
+impl_subtype_casts!(
+    PlainDateObject,
+    HeapItemKind::PlainDateObject,
+    is_plain_date_object,
+    as_plain_date_object
+);
+impl_subtype_casts!(
+    PlainTimeObject,
+    HeapItemKind::PlainTimeObject,
+    is_plain_time_object,
+    as_plain_time_object
+);
+impl_subtype_casts!(
+    PlainDateTimeObject,
+    HeapItemKind::PlainDateTimeObject,
+    is_plain_date_time_object,
+    as_plain_date_time_object
+);
+impl_subtype_casts!(
+    PlainYearMonthObject,
+    HeapItemKind::PlainYearMonthObject,
+    is_plain_year_month_object,
+    as_plain_year_month_object
+);
+impl_subtype_casts!(
+    PlainMonthDayObject,
+    HeapItemKind::PlainMonthDayObject,
+    is_plain_month_day_object,
+    as_plain_month_day_object
+);
+impl_subtype_casts!(
+    DurationObject,
+    HeapItemKind::DurationObject,
+    is_duration_object,
+    as_duration_object
+);
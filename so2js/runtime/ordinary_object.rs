@@ -507,7 +507,7 @@ pub fn ordinary_delete(
         None => Ok(true),
         Some(desc) => {
             if desc.is_configurable() {
-                object.remove_property(key);
+                object.remove_property(cx, key)?;
                 Ok(true)
             } else {
                 Ok(false)
@@ -669,6 +669,7 @@ pub fn object_ordinary_init(
     object.set_descriptor(descriptor);
     object.set_prototype(proto);
     object.set_named_properties(cx.default_named_properties);
+    object.set_shape(Some(cx.root_shape));
     object.set_array_properties(cx.default_array_properties);
     object.set_is_extensible_field(true);
     object.set_uninit_hash_code();
@@ -0,0 +1,551 @@
+//! Sampling CPU profiler.
+//!
+//! A profiler is started with `Context::start_profiler`. While it is running the VM periodically
+//! records the current call stack, and samples are aggregated into a call tree with a node for
+//! every distinct path of functions from the root. Builtins implemented in the Rust runtime have
+//! their own nodes, separate from the JS function that called them.
+//!
+//! The VM only instruments its dispatch loop with profiler hooks when a profiler is running at the
+//! time the dispatch loop is entered, so there is no overhead when not profiling. Samples are taken
+//! between instructions and after each call to a Rust runtime function, so a long running builtin
+//! is attributed to the builtin and not to its caller.
+//!
+//! A finished `Profile` can be written in the V8 `.cpuprofile` JSON format, which can be loaded in
+//! the Performance tab of Chrome DevTools, or as folded stacks for flamegraph tools.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use crate::{js_stack_scope_guard, parser::loc::find_line_col_for_pos};
+
+use super::{
+    alloc_error::AllocResult,
+    bytecode::{function::BytecodeFunction, source_map::BytecodeSourceMap},
+    gc::GcVisitorExt,
+    heap_snapshot::write_json_string,
+    stack_trace::gather_current_stack_frames,
+    Context, HeapPtr, StackRoot,
+};
+
+/// Number of instructions executed between reads of the profiler's clock
+const CLOCK_CHECK_INTERVAL: u32 = 64;
+
+/// Index of the root node of the call tree
+const ROOT_NODE: usize = 0;
+
+/// Index of the node for time spent outside of JS, e.g. in the host between scripts
+const PROGRAM_NODE: usize = 1;
+
+/// A monotonic clock returning the current time in microseconds.
+pub type ProfilerClock = Box<dyn FnMut() -> f64>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileNodeKind {
+    /// The root of the call tree
+    Root,
+    /// Time spent outside of JS
+    Program,
+    /// A function defined in a script or module
+    Script,
+    /// A builtin function implemented in the Rust runtime
+    Builtin,
+}
+
+struct CallTreeNode {
+    /// The function for this node. Only set for script and builtin nodes.
+    function: Option<HeapPtr<BytecodeFunction>>,
+    kind: ProfileNodeKind,
+    children: Vec<usize>,
+    hit_count: u32,
+    /// Number of samples at each bytecode offset in the function
+    offset_ticks: Vec<(usize, u32)>,
+}
+
+impl CallTreeNode {
+    fn new(kind: ProfileNodeKind, function: Option<HeapPtr<BytecodeFunction>>) -> Self {
+        Self {
+            function,
+            kind,
+            children: vec![],
+            hit_count: 0,
+            offset_ticks: vec![],
+        }
+    }
+}
+
+/// All profiler state for a context. Only exists while a profiler is running.
+pub struct ProfilerState {
+    clock: ProfilerClock,
+    /// Time between samples in microseconds
+    sampling_interval: f64,
+    start_time: f64,
+    /// Time that the most recent sample was taken
+    last_sample_time: f64,
+    instructions_until_clock_check: u32,
+    nodes: Vec<CallTreeNode>,
+    /// The node sampled for each sample
+    samples: Vec<usize>,
+    /// Timestamp for each sample
+    timestamps: Vec<f64>,
+}
+
+impl ProfilerState {
+    fn new(sampling_interval: f64, mut clock: ProfilerClock) -> Self {
+        let start_time = clock();
+
+        Self {
+            clock,
+            sampling_interval,
+            start_time,
+            last_sample_time: start_time,
+            instructions_until_clock_check: CLOCK_CHECK_INTERVAL,
+            nodes: vec![
+                CallTreeNode::new(ProfileNodeKind::Root, None),
+                CallTreeNode::new(ProfileNodeKind::Program, None),
+            ],
+            samples: vec![],
+            timestamps: vec![],
+        }
+    }
+
+    /// Find the child of a node for a function, creating it if it does not exist yet.
+    fn child_node(&mut self, parent: usize, function: HeapPtr<BytecodeFunction>) -> usize {
+        let existing_child = self.nodes[parent].children.iter().copied().find(|child| {
+            self.nodes[*child]
+                .function
+                .is_some_and(|child_function| child_function.ptr_eq(&function))
+        });
+
+        if let Some(child) = existing_child {
+            return child;
+        }
+
+        let kind = if function.rust_runtime_function_id().is_some() {
+            ProfileNodeKind::Builtin
+        } else {
+            ProfileNodeKind::Script
+        };
+
+        let child = self.nodes.len();
+        self.nodes.push(CallTreeNode::new(kind, Some(function)));
+        self.nodes[parent].children.push(child);
+
+        child
+    }
+
+    /// Record a sample for a node. The stack is only observed at the end of each sampling interval,
+    /// so each sample is timestamped with the start of the interval that it represents.
+    fn record_sample(&mut self, node: usize, now: f64) {
+        self.nodes[node].hit_count += 1;
+        self.samples.push(node);
+        self.timestamps.push(self.last_sample_time);
+        self.last_sample_time = now;
+    }
+
+    pub fn visit_roots(&mut self, visitor: &mut impl GcVisitorExt) {
+        for node in &mut self.nodes {
+            if let Some(function) = &mut node.function {
+                visitor.visit_pointer(function);
+            }
+        }
+    }
+}
+
+impl Context {
+    /// Start sampling the call stack every `sampling_interval_micros` microseconds, as measured by
+    /// the provided clock. Replaces any profile that is already in progress.
+    ///
+    /// Only takes effect for code that starts executing after the profiler is started.
+    pub fn start_profiler(&mut self, sampling_interval_micros: f64, clock: ProfilerClock) {
+        self.profiler = Some(Box::new(ProfilerState::new(
+            sampling_interval_micros,
+            clock,
+        )));
+    }
+
+    #[inline]
+    pub fn is_profiling(&self) -> bool {
+        self.profiler.is_some()
+    }
+
+    /// Stop the profiler, returning the profile if a profiler was running.
+    pub fn stop_profiler(&mut self) -> AllocResult<Option<Profile>> {
+        match self.profiler.take() {
+            Some(mut state) => {
+                let end_time = (state.clock)();
+                Ok(Some(Profile::new(*self, &state, end_time)?))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Called by the VM before every instruction while a profiler is running.
+#[inline]
+pub fn before_instruction(mut cx: Context) {
+    let state = match &mut cx.profiler {
+        Some(state) => state,
+        None => return,
+    };
+
+    state.instructions_until_clock_check -= 1;
+    if state.instructions_until_clock_check == 0 {
+        state.instructions_until_clock_check = CLOCK_CHECK_INTERVAL;
+        maybe_sample(cx);
+    }
+}
+
+/// Called by the VM after a Rust runtime function returns, while its stack frame is still on the
+/// stack. The clock is always checked, since a single builtin may run for a long time.
+pub fn after_rust_runtime_call(cx: Context) {
+    maybe_sample(cx);
+}
+
+/// Called by the VM when it starts executing with an empty stack. Time since the last sample was
+/// spent outside of JS.
+pub fn on_execution_start(mut cx: Context) {
+    if let Some(state) = &mut cx.profiler {
+        let now = (state.clock)();
+        if now - state.last_sample_time >= state.sampling_interval {
+            state.record_sample(PROGRAM_NODE, now);
+        }
+    }
+}
+
+/// Sample the current stack if the sampling interval has elapsed since the last sample.
+fn maybe_sample(mut cx: Context) {
+    let now = match &mut cx.profiler {
+        Some(state) => {
+            let now = (state.clock)();
+            if now - state.last_sample_time < state.sampling_interval {
+                return;
+            }
+
+            now
+        }
+        None => return,
+    };
+
+    js_stack_scope_guard!(cx);
+
+    let frames = gather_current_stack_frames(cx, false);
+    let state = cx.profiler.as_mut().unwrap();
+
+    // Walk from the outermost frame down to the innermost, finding the node for each frame
+    let mut node = ROOT_NODE;
+    for frame in frames.iter().rev() {
+        node = state.child_node(node, *frame.function);
+    }
+
+    if let Some(innermost_frame) = frames.first() {
+        if innermost_frame.function.source_map_ptr().is_some() {
+            let offset_ticks = &mut state.nodes[node].offset_ticks;
+            match offset_ticks
+                .iter_mut()
+                .find(|(offset, _)| *offset == innermost_frame.bytecode_offset)
+            {
+                Some((_, ticks)) => *ticks += 1,
+                None => offset_ticks.push((innermost_frame.bytecode_offset, 1)),
+            }
+        }
+    } else {
+        node = PROGRAM_NODE;
+    }
+
+    state.record_sample(node, now);
+}
+
+/// A node in the call tree of a profile.
+pub struct ProfileNode {
+    pub kind: ProfileNodeKind,
+    /// Name of the function, empty for anonymous functions
+    pub function_name: String,
+    /// Path of the source file that defines the function, empty if there is no source file
+    pub url: String,
+    /// 1-indexed line and column of the start of the function, if known
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// Indices of the child nodes
+    pub children: Vec<usize>,
+    /// Number of samples where this node was the innermost frame
+    pub hit_count: u32,
+    /// Number of samples at each 1-indexed line where this node was the innermost frame
+    pub line_ticks: Vec<(usize, u32)>,
+}
+
+impl ProfileNode {
+    fn name(&self) -> &str {
+        match self.kind {
+            ProfileNodeKind::Root => "(root)",
+            ProfileNodeKind::Program => "(program)",
+            ProfileNodeKind::Script | ProfileNodeKind::Builtin => {
+                if self.function_name.is_empty() {
+                    "(anonymous)"
+                } else {
+                    &self.function_name
+                }
+            }
+        }
+    }
+}
+
+/// A finished CPU profile.
+pub struct Profile {
+    /// Nodes of the call tree. The first node is the root.
+    pub nodes: Vec<ProfileNode>,
+    /// Start and end time of the profile in microseconds
+    pub start_time: f64,
+    pub end_time: f64,
+    /// Index of the node sampled for each sample
+    pub samples: Vec<usize>,
+    /// Timestamp of each sample in microseconds
+    pub timestamps: Vec<f64>,
+}
+
+impl Profile {
+    fn new(cx: Context, state: &ProfilerState, end_time: f64) -> AllocResult<Profile> {
+        js_stack_scope_guard!(cx);
+
+        // Root all functions first, since resolving source positions may allocate
+        let functions = state
+            .nodes
+            .iter()
+            .map(|node| node.function.map(|function| function.to_stack(cx)))
+            .collect::<Vec<_>>();
+
+        let mut nodes = Vec::with_capacity(state.nodes.len());
+        for (node, function) in state.nodes.iter().zip(functions) {
+            let mut profile_node = ProfileNode {
+                kind: node.kind,
+                function_name: String::new(),
+                url: String::new(),
+                line: None,
+                column: None,
+                children: node.children.clone(),
+                hit_count: node.hit_count,
+                line_ticks: vec![],
+            };
+
+            if let Some(function) = function {
+                resolve_function_info(cx, &mut profile_node, function, &node.offset_ticks)?;
+            }
+
+            nodes.push(profile_node);
+        }
+
+        Ok(Profile {
+            nodes,
+            start_time: state.start_time,
+            end_time,
+            samples: state.samples.clone(),
+            timestamps: state.timestamps.clone(),
+        })
+    }
+
+    /// Write the profile in the V8 `.cpuprofile` JSON format, which can be loaded in the
+    /// Performance tab of Chrome DevTools.
+    pub fn write_cpuprofile(&self, writer: &mut impl Write) -> fmt::Result {
+        writer.write_str("{\"nodes\":[")?;
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i != 0 {
+                writer.write_str(",\n")?;
+            }
+
+            // Node ids are 1-indexed
+            write!(
+                writer,
+                "{{\"id\":{},\"callFrame\":{{\"functionName\":",
+                i + 1
+            )?;
+            match node.kind {
+                ProfileNodeKind::Script | ProfileNodeKind::Builtin => {
+                    write_json_string(writer, &node.function_name)?
+                }
+                ProfileNodeKind::Root | ProfileNodeKind::Program => {
+                    write_json_string(writer, node.name())?
+                }
+            }
+
+            writer.write_str(",\"scriptId\":\"0\",\"url\":")?;
+            write_json_string(writer, &node.url)?;
+
+            // Line and column numbers are 0-indexed, or -1 if unknown
+            let line = node.line.map_or(-1, |line| line as isize - 1);
+            let column = node.column.map_or(-1, |column| column as isize - 1);
+            write!(
+                writer,
+                ",\"lineNumber\":{},\"columnNumber\":{}}},\"hitCount\":{}",
+                line, column, node.hit_count
+            )?;
+
+            if node.kind == ProfileNodeKind::Builtin {
+                writer.write_str(",\"codeType\":\"other\"")?;
+            }
+
+            if !node.children.is_empty() {
+                writer.write_str(",\"children\":[")?;
+                for (j, child) in node.children.iter().enumerate() {
+                    if j != 0 {
+                        writer.write_char(',')?;
+                    }
+
+                    write!(writer, "{}", child + 1)?;
+                }
+                writer.write_char(']')?;
+            }
+
+            if !node.line_ticks.is_empty() {
+                writer.write_str(",\"positionTicks\":[")?;
+                for (j, (line, ticks)) in node.line_ticks.iter().enumerate() {
+                    if j != 0 {
+                        writer.write_char(',')?;
+                    }
+
+                    write!(writer, "{{\"line\":{},\"ticks\":{}}}", line, ticks)?;
+                }
+                writer.write_char(']')?;
+            }
+
+            writer.write_char('}')?;
+        }
+
+        // Times are written as whole microseconds, with each sample's time written as the delta
+        // from the previous sample.
+        write!(
+            writer,
+            "],\n\"startTime\":{},\"endTime\":{},\n\"samples\":[",
+            self.start_time as i64, self.end_time as i64
+        )?;
+        for (i, sample) in self.samples.iter().enumerate() {
+            if i != 0 {
+                writer.write_char(',')?;
+            }
+
+            write!(writer, "{}", sample + 1)?;
+        }
+
+        writer.write_str("],\n\"timeDeltas\":[")?;
+        let mut previous_time = self.start_time as i64;
+        for (i, timestamp) in self.timestamps.iter().enumerate() {
+            if i != 0 {
+                writer.write_char(',')?;
+            }
+
+            let time = *timestamp as i64;
+            write!(writer, "{}", time - previous_time)?;
+            previous_time = time;
+        }
+
+        writer.write_str("]}\n")
+    }
+
+    /// Write the profile as folded stacks, with one line per distinct stack containing the frames
+    /// from outermost to innermost separated by semicolons, followed by the number of samples.
+    ///
+    /// This is the input format of flamegraph tools such as `inferno` and `flamegraph.pl`.
+    pub fn write_folded_stacks(&self, writer: &mut impl Write) -> fmt::Result {
+        let mut path = vec![];
+        self.write_folded_stacks_for_node(writer, ROOT_NODE, &mut path)
+    }
+
+    fn write_folded_stacks_for_node(
+        &self,
+        writer: &mut impl Write,
+        node_index: usize,
+        path: &mut Vec<usize>,
+    ) -> fmt::Result {
+        let node = &self.nodes[node_index];
+
+        // The root node is left out of every stack
+        if node.kind != ProfileNodeKind::Root {
+            path.push(node_index);
+        }
+
+        if node.hit_count != 0 && !path.is_empty() {
+            for (i, frame_index) in path.iter().enumerate() {
+                if i != 0 {
+                    writer.write_char(';')?;
+                }
+
+                self.write_folded_frame(writer, &self.nodes[*frame_index])?;
+            }
+
+            writeln!(writer, " {}", node.hit_count)?;
+        }
+
+        for child in &node.children {
+            self.write_folded_stacks_for_node(writer, *child, path)?;
+        }
+
+        if node.kind != ProfileNodeKind::Root {
+            path.pop();
+        }
+
+        Ok(())
+    }
+
+    fn write_folded_frame(&self, writer: &mut impl Write, node: &ProfileNode) -> fmt::Result {
+        writer.write_str(node.name())?;
+
+        match node.kind {
+            ProfileNodeKind::Builtin => writer.write_str(" [native]"),
+            ProfileNodeKind::Script if !node.url.is_empty() => match node.line {
+                Some(line) => write!(writer, " ({}:{})", node.url, line),
+                None => write!(writer, " ({})", node.url),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Fill in the name and source positions of a node's function.
+fn resolve_function_info(
+    cx: Context,
+    node: &mut ProfileNode,
+    function: StackRoot<BytecodeFunction>,
+    offset_ticks: &[(usize, u32)],
+) -> AllocResult<()> {
+    if let Some(name) = function.name(cx) {
+        node.function_name = name.format(cx)?;
+    }
+
+    let mut source_file = match function.source_file_ptr() {
+        Some(source_file) => source_file.to_stack(cx),
+        None => return Ok(()),
+    };
+
+    node.url = source_file.path(cx).to_string();
+
+    let line_offsets = source_file.line_offsets_ptr(cx)?;
+    let line_offsets = line_offsets.as_slice();
+
+    if let Some(range) = function.source_range() {
+        let (line, column) = find_line_col_for_pos(range.start, line_offsets);
+        node.line = Some(line);
+        node.column = Some(column);
+    }
+
+    if let Some(source_map) = function.source_map_ptr() {
+        for (offset, ticks) in offset_ticks {
+            let position = match BytecodeSourceMap::get_source_position(source_map, *offset) {
+                Some(position) => position,
+                None => continue,
+            };
+
+            let (line, _) = find_line_col_for_pos(position, line_offsets);
+            match node.line_ticks.iter_mut().find(|(l, _)| *l == line) {
+                Some((_, line_ticks)) => *line_ticks += ticks,
+                None => node.line_ticks.push((line, *ticks)),
+            }
+        }
+
+        node.line_ticks.sort_unstable();
+    }
+
+    Ok(())
+}
@@ -148,6 +148,39 @@ impl PromiseObject {
         }
     }
 
+    /// Find the suspended async function or async generator that will be resumed when this
+    /// promise settles, if there is one. Promises derived from this promise with `then` are
+    /// followed until an awaiting function is found.
+    pub fn awaiting_generator_ptr(&self) -> Option<HeapPtr<ObjectValue>> {
+        let mut next_reaction = match self.state {
+            PromiseState::Pending { reactions, .. } => reactions,
+            _ => return None,
+        };
+
+        let mut derived_promise = None;
+
+        while let Some(reaction) = next_reaction {
+            match reaction.handler {
+                ReactionStackRootr::AwaitResume {
+                    suspended_generator,
+                } => return Some(suspended_generator),
+                ReactionStackRootr::Then {
+                    capability: Some(capability),
+                    ..
+                } => {
+                    if derived_promise.is_none() {
+                        derived_promise = capability.promise_ptr().as_promise();
+                    }
+                }
+                ReactionStackRootr::Then { .. } => {}
+            }
+
+            next_reaction = reaction.next;
+        }
+
+        derived_promise.and_then(|promise| promise.awaiting_generator_ptr())
+    }
+
     pub fn set_already_resolved(&mut self, value: bool) {
         match self.state {
             PromiseState::Pending {
@@ -581,6 +614,10 @@ impl PromiseCapability {
         self.promise.unwrap().to_stack(cx)
     }
 
+    pub fn promise_ptr(&self) -> HeapPtr<ObjectValue> {
+        self.promise.unwrap()
+    }
+
     pub fn resolve(&self, cx: Context) -> StackRoot<ObjectValue> {
         self.resolve.as_object().to_stack(cx)
     }
@@ -5,6 +5,7 @@ use crate::runtime::alloc_error::AllocResult;
 use super::{
     accessor::Accessor,
     gc::{GcVisitorExt, StackRoot},
+    heap_item_descriptor::HeapItemKind,
     object_value::ObjectValue,
     value::Value,
     Context,
@@ -206,6 +207,28 @@ impl Property {
 }
 
 impl HeapProperty {
+    #[inline]
+    pub fn value(&self) -> Value {
+        self.value
+    }
+
+    #[inline]
+    pub fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+
+    #[inline]
+    pub fn is_writable(&self) -> bool {
+        self.flags.contains(PropertyFlags::IS_WRITABLE)
+    }
+
+    /// Whether this is a data property. Accessor properties store an Accessor as their value.
+    #[inline]
+    pub fn is_data(&self) -> bool {
+        !(self.value.is_pointer()
+            && self.value.as_pointer().descriptor().kind() == HeapItemKind::Accessor)
+    }
+
     pub fn is_configurable(&self) -> bool {
         self.flags.contains(PropertyFlags::IS_CONFIGURABLE)
     }
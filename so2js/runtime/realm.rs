@@ -129,6 +129,25 @@ impl Realm {
         Some(value)
     }
 
+    /// Get the global scope and slot index that hold a lexical name, along with whether the
+    /// binding is immutable, if the lexical name exists in the realm.
+    pub fn get_lexical_name_location(
+        &self,
+        name: HeapPtr<FlatString>,
+    ) -> Option<(HeapPtr<Scope>, u32, bool)> {
+        let location = self.lexical_names.get(&name)?;
+        let global_scope = self.global_scopes.get(location.global_scope_index);
+
+        Some((global_scope, location.slot_index, location.is_immutable))
+    }
+
+    /// The number of global scopes in this realm. Increases whenever a new script is evaluated,
+    /// which may add lexical names that shadow global object properties.
+    #[inline]
+    pub fn num_global_scopes(&self) -> usize {
+        self.global_scopes.len()
+    }
+
     /// Try to set the value associated with a lexical name in the realms lexical names map.
     /// Return whether the lexical name was found and set.
     pub fn set_lexical_name(
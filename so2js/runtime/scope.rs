@@ -151,6 +151,11 @@ impl Scope {
         self.scope_names
     }
 
+    #[inline]
+    pub fn object_ptr(&self) -> Option<HeapPtr<ObjectValue>> {
+        self.object
+    }
+
     #[inline]
     pub fn get_slot(&self, index: usize) -> Value {
         self.slots.as_slice()[index]
@@ -0,0 +1,277 @@
+use alloc::vec::Vec;
+
+use crate::{
+    runtime::{
+        alloc_error::AllocResult,
+        collections::{BsHashMap, BsHashMapField},
+        gc::{GcVisitorExt, HeapItem},
+        heap_item_descriptor::{HeapItemDescriptor, HeapItemKind},
+        object_value::ObjectValue,
+        Context, HeapPtr, PropertyKey, StackRoot,
+    },
+    set_uninit,
+};
+
+/// Objects with more named property slots than this are moved to dictionary mode.
+pub const MAX_SHAPE_SLOTS: usize = 256;
+
+/// Objects with more deleted named properties than this are moved to dictionary mode.
+pub const MAX_SHAPE_DELETED_SLOTS: usize = 8;
+
+/// Maximum number of transitions out of a single shape. Objects that would need a new transition
+/// from a shape that already has this many are moved to dictionary mode instead, which bounds the
+/// size of the transition tree for objects used as dictionaries.
+pub const MAX_SHAPE_TRANSITIONS: usize = 64;
+
+/// A hidden class describing the layout of an object's named properties.
+///
+/// The named properties of an object are stored in insertion order in a `BsIndexMap`, so the entry
+/// index of a property in that map (its slot) is determined by the sequence of insertions and
+/// deletions that produced the map. A shape identifies that sequence: two objects with the same
+/// shape store the same keys at the same slots. Inline caches key on the shape of the receiver and
+/// can then read or write the property at the cached slot directly.
+///
+/// Shapes form a transition tree rooted at the context's root shape. Adding a property follows the
+/// shared transition for that key, creating it if necessary. Deleting a property leaves a deleted
+/// entry in the named properties map, so the object follows a separate shared delete transition for
+/// that key which records the deletion. When the map is later compacted the object's shape is
+/// rebuilt from the root.
+///
+/// Transitions are held strongly and are never pruned, so the number of transitions out of each
+/// shape is capped at `MAX_SHAPE_TRANSITIONS`. Objects that see too many deletions, that have too
+/// many properties, or that need a transition past the cap switch to dictionary mode, which is
+/// represented by an object having no shape. Dictionary mode objects are never cached.
+///
+/// Shapes only describe which key lives in each slot. Property attributes are stored alongside the
+/// value in the named properties map and must be checked at the time of access.
+#[repr(C)]
+pub struct Shape {
+    descriptor: HeapPtr<HeapItemDescriptor>,
+    /// The shape this shape was transitioned from. None for the root shape.
+    parent: Option<HeapPtr<Shape>>,
+    /// The key that was added to or deleted from the parent shape to create this shape.
+    /// Uninitialized for the root shape.
+    key: PropertyKey,
+    /// Number of slots used by objects with this shape, including deleted slots.
+    num_slots: u32,
+    /// Number of deleted slots in objects with this shape.
+    num_deleted: u32,
+    /// Transitions from this shape to child shapes when a property is added. Lazily created.
+    transitions: Option<HeapPtr<ShapeTransitionsMap>>,
+    /// Transitions from this shape to child shapes when a property is deleted. Lazily created.
+    delete_transitions: Option<HeapPtr<ShapeTransitionsMap>>,
+}
+
+type ShapeTransitionsMap = BsHashMap<PropertyKey, HeapPtr<Shape>>;
+
+impl Shape {
+    fn new(
+        cx: Context,
+        parent: Option<StackRoot<Shape>>,
+        key: Option<StackRoot<PropertyKey>>,
+        num_slots: u32,
+        num_deleted: u32,
+    ) -> AllocResult<HeapPtr<Shape>> {
+        let mut shape = cx.alloc_uninit::<Shape>()?;
+
+        set_uninit!(
+            shape.descriptor,
+            cx.base_descriptors.get(HeapItemKind::Shape)
+        );
+        set_uninit!(shape.parent, parent.map(|p| *p));
+        set_uninit!(shape.key, key.map_or(PropertyKey::uninit(), |key| *key));
+        set_uninit!(shape.num_slots, num_slots);
+        set_uninit!(shape.num_deleted, num_deleted);
+        set_uninit!(shape.transitions, None);
+        set_uninit!(shape.delete_transitions, None);
+
+        Ok(shape)
+    }
+
+    /// Create the root of a shape tree, which describes an object with no named properties.
+    pub fn new_root(cx: Context) -> AllocResult<HeapPtr<Shape>> {
+        Shape::new(cx, None, None, 0, 0)
+    }
+
+    #[inline]
+    pub fn parent(&self) -> Option<HeapPtr<Shape>> {
+        self.parent
+    }
+
+    #[inline]
+    pub fn num_slots(&self) -> usize {
+        self.num_slots as usize
+    }
+
+    #[inline]
+    pub fn num_deleted(&self) -> usize {
+        self.num_deleted as usize
+    }
+
+    /// Number of add and delete transitions out of this shape.
+    fn num_transitions(&self) -> usize {
+        self.transitions.map_or(0, |map| map.len())
+            + self.delete_transitions.map_or(0, |map| map.len())
+    }
+
+    /// Build the shape for the current named properties of an object by replaying its keys from
+    /// the root shape. Only valid if the object's named properties map has no deleted entries.
+    ///
+    /// Returns None if the object should be in dictionary mode instead.
+    pub fn from_named_properties(
+        cx: Context,
+        object: StackRoot<ObjectValue>,
+    ) -> AllocResult<Option<HeapPtr<Shape>>> {
+        let mut keys = Vec::new();
+        object.iter_named_property_keys_gc_unsafe(|key| keys.push(key.to_stack(cx)));
+
+        if keys.len() > MAX_SHAPE_SLOTS {
+            return Ok(None);
+        }
+
+        let mut shape = cx.root_shape.to_stack(cx);
+        for key in keys {
+            match shape.add_property_transition(cx, key)? {
+                Some(next_shape) => shape.replace(next_shape),
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(*shape))
+    }
+}
+
+impl StackRoot<Shape> {
+    fn transitions_field(&self, kind: TransitionKind) -> ShapeTransitionsField {
+        ShapeTransitionsField { shape: *self, kind }
+    }
+
+    /// Return the shape for an object with this shape after a new property with the given key is
+    /// added. Transitions are shared, so adding the same key to the same shape always results in
+    /// the same shape.
+    ///
+    /// Returns None if the object should be moved to dictionary mode instead.
+    pub fn add_property_transition(
+        &mut self,
+        cx: Context,
+        key: StackRoot<PropertyKey>,
+    ) -> AllocResult<Option<HeapPtr<Shape>>> {
+        self.transition(cx, key, TransitionKind::Add)
+    }
+
+    /// Return the shape for an object with this shape after the property with the given key has
+    /// been deleted. Transitions are shared, so deleting the same key from the same shape always
+    /// results in the same shape.
+    ///
+    /// Returns None if the object should be moved to dictionary mode instead.
+    pub fn delete_property_transition(
+        &mut self,
+        cx: Context,
+        key: StackRoot<PropertyKey>,
+    ) -> AllocResult<Option<HeapPtr<Shape>>> {
+        self.transition(cx, key, TransitionKind::Delete)
+    }
+
+    fn transition(
+        &mut self,
+        cx: Context,
+        key: StackRoot<PropertyKey>,
+        kind: TransitionKind,
+    ) -> AllocResult<Option<HeapPtr<Shape>>> {
+        let transitions = match kind {
+            TransitionKind::Add => self.transitions,
+            TransitionKind::Delete => self.delete_transitions,
+        };
+
+        if let Some(transitions) = transitions {
+            if let Some(shape) = transitions.get(&*key) {
+                return Ok(Some(*shape));
+            }
+        }
+
+        if self.num_transitions() >= MAX_SHAPE_TRANSITIONS {
+            return Ok(None);
+        }
+
+        if transitions.is_none() {
+            let transitions =
+                ShapeTransitionsMap::new_initial(cx, HeapItemKind::ShapeTransitionsMap)?;
+            self.transitions_field(kind).set(cx, transitions);
+        }
+
+        let (num_slots, num_deleted) = match kind {
+            TransitionKind::Add => (self.num_slots + 1, self.num_deleted),
+            TransitionKind::Delete => (self.num_slots, self.num_deleted + 1),
+        };
+
+        let new_shape =
+            Shape::new(cx, Some(*self), Some(key), num_slots, num_deleted)?.to_stack(cx);
+
+        // Safe since insert does not allocate on managed heap
+        self.transitions_field(kind)
+            .maybe_grow_for_insertion(cx)?
+            .insert_without_growing(*key, *new_shape);
+
+        Ok(Some(*new_shape))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum TransitionKind {
+    Add,
+    Delete,
+}
+
+pub struct ShapeTransitionsField {
+    shape: StackRoot<Shape>,
+    kind: TransitionKind,
+}
+
+impl BsHashMapField<PropertyKey, HeapPtr<Shape>> for ShapeTransitionsField {
+    fn new_map(&self, cx: Context, capacity: usize) -> AllocResult<HeapPtr<ShapeTransitionsMap>> {
+        ShapeTransitionsMap::new(cx, HeapItemKind::ShapeTransitionsMap, capacity)
+    }
+
+    fn get(&self, _: Context) -> HeapPtr<ShapeTransitionsMap> {
+        match self.kind {
+            TransitionKind::Add => self.shape.transitions.unwrap(),
+            TransitionKind::Delete => self.shape.delete_transitions.unwrap(),
+        }
+    }
+
+    fn set(&mut self, _: Context, map: HeapPtr<ShapeTransitionsMap>) {
+        match self.kind {
+            TransitionKind::Add => self.shape.transitions = Some(map),
+            TransitionKind::Delete => self.shape.delete_transitions = Some(map),
+        }
+    }
+}
+
+impl ShapeTransitionsField {
+    pub fn byte_size(map: &HeapPtr<ShapeTransitionsMap>) -> usize {
+        ShapeTransitionsMap::calculate_size_in_bytes(map.capacity())
+    }
+
+    pub fn visit_pointers(map: &mut HeapPtr<ShapeTransitionsMap>, visitor: &mut impl GcVisitorExt) {
+        map.visit_pointers(visitor);
+
+        for (key, shape) in map.iter_mut_gc_unsafe() {
+            visitor.visit_property_key(key);
+            visitor.visit_pointer(shape);
+        }
+    }
+}
+
+impl HeapItem for HeapPtr<Shape> {
+    fn byte_size(&self) -> usize {
+        core::mem::size_of::<Shape>()
+    }
+
+    fn visit_pointers(&mut self, visitor: &mut impl GcVisitorExt) {
+        visitor.visit_pointer(&mut self.descriptor);
+        visitor.visit_pointer_opt(&mut self.parent);
+        visitor.visit_property_key(&mut self.key);
+        visitor.visit_pointer_opt(&mut self.transitions);
+        visitor.visit_pointer_opt(&mut self.delete_transitions);
+    }
+}
@@ -0,0 +1,757 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    alloc::Layout,
+    mem::{size_of, transmute_copy, MaybeUninit},
+    ptr::NonNull,
+    sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use parking_lot::{Condvar, Mutex};
+
+use super::{gc::GcVisitorExt, promise_object::PromiseObject, Context, HeapPtr, Value};
+
+/// Alignment of shared data blocks, large enough for every element type so that aligned elements
+/// can always be accessed with native atomic instructions.
+const SHARED_DATA_BLOCK_ALIGNMENT: usize = 8;
+
+/// How often an agent with only untimed Atomics.waitAsync waiters checks whether another agent
+/// that could still notify them is alive.
+const ASYNC_WAITER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Unique ids for waiters across all agents, used to find a waiter in its waiter list.
+static NEXT_WAITER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A Shared Data Block (https://tc39.es/ecma262/#sec-data-blocks) backing a SharedArrayBuffer.
+///
+/// Shared data blocks live outside of the GC heap so that they can be shared between agents
+/// running on different threads. The full max byte length is allocated up front, so growing a
+/// block never moves its data while other agents may be accessing it.
+pub struct SharedDataBlock {
+    data: NonNull<u8>,
+    /// Number of bytes allocated for the block.
+    capacity: usize,
+    byte_length: AtomicUsize,
+    /// Fixed length if no max is set.
+    max_byte_length: Option<usize>,
+    /// The WaiterLists for every byte index in the block. Guarded by a single lock which also
+    /// serves as the WaiterList critical section.
+    waiters: Mutex<Vec<Waiter>>,
+}
+
+// The data is only ever accessed through atomic operations or in the unordered accesses allowed by
+// the memory model, and all other state is synchronized.
+unsafe impl Send for SharedDataBlock {}
+unsafe impl Sync for SharedDataBlock {}
+
+/// The result of waiting on a location in a shared data block.
+#[derive(Clone, Copy, PartialEq)]
+pub enum WaitResult {
+    Ok,
+    NotEqual,
+    TimedOut,
+}
+
+struct Waiter {
+    id: u64,
+    byte_index: usize,
+    kind: WaiterKind,
+}
+
+enum WaiterKind {
+    /// An agent suspended in Atomics.wait, resumed by signaling its condition variable.
+    Sync(Arc<Condvar>),
+    /// A promise from Atomics.waitAsync, settled by its agent once notified.
+    Async(Arc<AsyncWaiterMailbox>),
+}
+
+impl SharedDataBlock {
+    /// CreateSharedByteDataBlock (https://tc39.es/ecma262/#sec-createsharedbytedatablock)
+    ///
+    /// Return None if the data block could not be allocated.
+    pub fn new(byte_length: usize, max_byte_length: Option<usize>) -> Option<Arc<SharedDataBlock>> {
+        let capacity = max_byte_length.unwrap_or(byte_length);
+        let data = unsafe { alloc::alloc::alloc_zeroed(Self::layout(capacity)?) };
+
+        Some(Arc::new(SharedDataBlock {
+            data: NonNull::new(data)?,
+            capacity,
+            byte_length: AtomicUsize::new(byte_length),
+            max_byte_length,
+            waiters: Mutex::new(Vec::new()),
+        }))
+    }
+
+    fn layout(capacity: usize) -> Option<Layout> {
+        // Zero sized allocations are not allowed
+        Layout::from_size_align(capacity.max(1), SHARED_DATA_BLOCK_ALIGNMENT).ok()
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.data.as_ptr()
+    }
+
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.byte_length.load(Ordering::SeqCst)
+    }
+
+    #[inline]
+    pub fn max_byte_length(&self) -> Option<usize> {
+        self.max_byte_length
+    }
+
+    /// Grow the block to a new byte length, which must not exceed the max byte length. Return
+    /// false if the new byte length is smaller than the current byte length.
+    ///
+    /// The length is only ever increased with a compare and exchange so that concurrent grows from
+    /// other agents are never lost.
+    pub fn grow(&self, new_byte_length: usize) -> bool {
+        debug_assert!(new_byte_length <= self.capacity);
+
+        let mut current_byte_length = self.byte_length();
+        loop {
+            if new_byte_length == current_byte_length {
+                return true;
+            } else if new_byte_length < current_byte_length {
+                return false;
+            }
+
+            match self.byte_length.compare_exchange(
+                current_byte_length,
+                new_byte_length,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => return true,
+                Err(byte_length) => current_byte_length = byte_length,
+            }
+        }
+    }
+
+    /// The blocking half of DoWait (https://tc39.es/ecma262/#sec-dowait). Suspend this agent until
+    /// the location at the byte index is notified or the timeout elapses. `is_equal` compares the
+    /// expected value to the value in the block, and is called within the critical section so that
+    /// a notification cannot be missed between the comparison and suspending.
+    pub fn wait(
+        &self,
+        byte_index: usize,
+        is_equal: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        let mut waiters = self.waiters.lock();
+
+        if !is_equal() {
+            return WaitResult::NotEqual;
+        }
+
+        let id = next_waiter_id();
+        let condvar = Arc::new(Condvar::new());
+        waiters.push(Waiter {
+            id,
+            byte_index,
+            kind: WaiterKind::Sync(condvar.clone()),
+        });
+
+        loop {
+            let timed_out = match timeout {
+                Some(timeout) => condvar.wait_for(&mut waiters, timeout).timed_out(),
+                None => {
+                    condvar.wait(&mut waiters);
+                    false
+                }
+            };
+
+            // Notified waiters have already been removed from the waiter list by the notifier
+            match waiters.iter().position(|waiter| waiter.id == id) {
+                None => return WaitResult::Ok,
+                Some(index) if timed_out => {
+                    waiters.remove(index);
+                    return WaitResult::TimedOut;
+                }
+                // Otherwise this was a spurious wakeup so keep waiting
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// The non-blocking half of DoWait (https://tc39.es/ecma262/#sec-dowait). Add a waiter whose
+    /// notification will be sent to the mailbox, returning the id of the new waiter. Return the
+    /// result immediately if no waiter needs to be added.
+    pub fn add_async_waiter(
+        &self,
+        byte_index: usize,
+        is_equal: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+        mailbox: &Arc<AsyncWaiterMailbox>,
+    ) -> Result<u64, WaitResult> {
+        let mut waiters = self.waiters.lock();
+
+        if !is_equal() {
+            return Err(WaitResult::NotEqual);
+        } else if timeout == Some(Duration::ZERO) {
+            return Err(WaitResult::TimedOut);
+        }
+
+        let id = next_waiter_id();
+        waiters.push(Waiter {
+            id,
+            byte_index,
+            kind: WaiterKind::Async(mailbox.clone()),
+        });
+
+        Ok(id)
+    }
+
+    /// Remove a waiter from the waiter list. Return false if the waiter was already notified.
+    fn remove_waiter(&self, id: u64) -> bool {
+        let mut waiters = self.waiters.lock();
+
+        match waiters.iter().position(|waiter| waiter.id == id) {
+            Some(index) => {
+                waiters.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Notify up to `count` waiters on the location at the byte index in the order they started
+    /// waiting, returning the number of waiters that were notified. Notify all waiters if no count
+    /// is provided.
+    pub fn notify(&self, byte_index: usize, count: Option<usize>) -> usize {
+        let mut waiters = self.waiters.lock();
+        let mut num_notified = 0;
+
+        waiters.retain(|waiter| {
+            if waiter.byte_index != byte_index || count.is_some_and(|count| num_notified >= count) {
+                return true;
+            }
+
+            num_notified += 1;
+
+            match &waiter.kind {
+                WaiterKind::Sync(condvar) => {
+                    condvar.notify_one();
+                }
+                WaiterKind::Async(mailbox) => mailbox.send(waiter.id),
+            }
+
+            false
+        });
+
+        num_notified
+    }
+}
+
+impl Drop for SharedDataBlock {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.capacity).unwrap();
+        unsafe { alloc::alloc::dealloc(self.data.as_ptr(), layout) }
+    }
+}
+
+fn next_waiter_id() -> u64 {
+    NEXT_WAITER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Receives the notifications for an agent's Atomics.waitAsync waiters, which may be sent from any
+/// agent.
+pub struct AsyncWaiterMailbox {
+    notified_ids: Mutex<Vec<u64>>,
+    condvar: Condvar,
+}
+
+impl AsyncWaiterMailbox {
+    fn new() -> Self {
+        Self {
+            notified_ids: Mutex::new(Vec::new()),
+            condvar: Condvar::new(),
+        }
+    }
+
+    fn send(&self, id: u64) {
+        self.notified_ids.lock().push(id);
+        self.condvar.notify_one();
+    }
+
+    /// Take the ids of all notified waiters, first waiting up to the timeout for a notification if
+    /// there are none. Also return whether the wait timed out.
+    fn receive(&self, timeout: Duration) -> (Vec<u64>, bool) {
+        let mut notified_ids = self.notified_ids.lock();
+
+        let timed_out = notified_ids.is_empty()
+            && self
+                .condvar
+                .wait_for(&mut notified_ids, timeout)
+                .timed_out();
+
+        (core::mem::take(&mut *notified_ids), timed_out)
+    }
+}
+
+/// A pending promise created by Atomics.waitAsync.
+struct AsyncWaiter {
+    id: u64,
+    /// Kept alive by the agent's list of shared data blocks.
+    data_block: NonNull<SharedDataBlock>,
+    promise: HeapPtr<PromiseObject>,
+    /// Time left until the waiter times out, or None if the waiter never times out.
+    remaining_time: Option<Duration>,
+}
+
+/// The shared memory state of a single agent.
+pub struct AgentSharedMemory {
+    /// Every shared data block referenced by a SharedArrayBuffer in this agent. Heap objects are
+    /// never finalized, so shared data blocks are kept alive for the lifetime of the agent.
+    data_blocks: Vec<Arc<SharedDataBlock>>,
+    /// Receives notifications for this agent's async waiters.
+    mailbox: Arc<AsyncWaiterMailbox>,
+    async_waiters: Vec<AsyncWaiter>,
+}
+
+impl AgentSharedMemory {
+    pub fn new() -> Self {
+        Self {
+            data_blocks: Vec::new(),
+            mailbox: Arc::new(AsyncWaiterMailbox::new()),
+            async_waiters: Vec::new(),
+        }
+    }
+
+    /// Keep a shared data block alive for the lifetime of this agent, returning a pointer to the
+    /// block that is valid for as long as the agent.
+    pub fn add_data_block(&mut self, data_block: Arc<SharedDataBlock>) -> NonNull<SharedDataBlock> {
+        let ptr = NonNull::from(&*data_block);
+
+        if !self
+            .data_blocks
+            .iter()
+            .any(|block| Arc::ptr_eq(block, &data_block))
+        {
+            self.data_blocks.push(data_block);
+        }
+
+        ptr
+    }
+
+    /// Get a reference counted shared data block from a pointer returned by `add_data_block`, e.g.
+    /// to share the block with another agent.
+    pub fn get_data_block(&self, ptr: NonNull<SharedDataBlock>) -> Arc<SharedDataBlock> {
+        self.find_data_block(ptr).clone()
+    }
+
+    fn find_data_block(&self, ptr: NonNull<SharedDataBlock>) -> &Arc<SharedDataBlock> {
+        self.data_blocks
+            .iter()
+            .find(|block| core::ptr::eq(Arc::as_ptr(block), ptr.as_ptr()))
+            .expect("shared data block must belong to this agent")
+    }
+
+    pub fn mailbox(&self) -> &Arc<AsyncWaiterMailbox> {
+        &self.mailbox
+    }
+
+    pub fn add_async_waiter(
+        &mut self,
+        id: u64,
+        data_block: NonNull<SharedDataBlock>,
+        promise: HeapPtr<PromiseObject>,
+        timeout: Option<Duration>,
+    ) {
+        self.async_waiters.push(AsyncWaiter {
+            id,
+            data_block,
+            promise,
+            remaining_time: timeout,
+        });
+    }
+
+    /// Whether another agent that could notify the waiter is still alive.
+    fn can_be_notified(&self, waiter: &AsyncWaiter) -> bool {
+        Arc::strong_count(self.find_data_block(waiter.data_block)) > 1
+    }
+
+    pub fn visit_roots(&mut self, visitor: &mut impl GcVisitorExt) {
+        for waiter in &mut self.async_waiters {
+            visitor.visit_pointer(&mut waiter.promise);
+        }
+    }
+}
+
+impl Drop for AgentSharedMemory {
+    fn drop(&mut self) {
+        // Pending waiters of this agent can no longer be notified
+        for waiter in &self.async_waiters {
+            unsafe { waiter.data_block.as_ref() }.remove_waiter(waiter.id);
+        }
+    }
+}
+
+impl Context {
+    /// Wait until at least one of the pending Atomics.waitAsync promises in this agent can be
+    /// settled, then settle them. Return false if there are no pending promises that can ever be
+    /// settled.
+    ///
+    /// Timeouts are only measured while this agent is waiting, so waiters may time out later than
+    /// requested but never earlier.
+    pub fn settle_async_waiters(&mut self) -> bool {
+        loop {
+            let shared_memory = &self.shared_memory;
+            if shared_memory.async_waiters.is_empty() {
+                return false;
+            }
+
+            // Wait until the earliest timeout, or poll for notifications if only waiters without
+            // a timeout remain and another agent could still notify them.
+            let wait_duration = shared_memory
+                .async_waiters
+                .iter()
+                .filter_map(|waiter| waiter.remaining_time)
+                .min();
+            let (wait_duration, can_settle_later) = match wait_duration {
+                Some(wait_duration) => (wait_duration, true),
+                None if shared_memory
+                    .async_waiters
+                    .iter()
+                    .any(|waiter| shared_memory.can_be_notified(waiter)) =>
+                {
+                    (ASYNC_WAITER_POLL_INTERVAL, true)
+                }
+                // Only notifications that have already been sent can settle the remaining waiters
+                None => (Duration::ZERO, false),
+            };
+
+            let (notified_ids, timed_out) = shared_memory.mailbox.receive(wait_duration);
+            let elapsed = if timed_out {
+                wait_duration
+            } else {
+                Duration::ZERO
+            };
+
+            let mut num_settled = 0;
+            let mut i = 0;
+            while i < self.shared_memory.async_waiters.len() {
+                let waiter = &mut self.shared_memory.async_waiters[i];
+
+                let result = if notified_ids.contains(&waiter.id) {
+                    Some(WaitResult::Ok)
+                } else if let Some(remaining_time) = &mut waiter.remaining_time {
+                    *remaining_time = remaining_time.saturating_sub(elapsed);
+
+                    // A waiter that was notified concurrently will be received from the mailbox
+                    let data_block = unsafe { waiter.data_block.as_ref() };
+                    if remaining_time.is_zero() && data_block.remove_waiter(waiter.id) {
+                        Some(WaitResult::TimedOut)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+
+                if let Some(result) = result {
+                    let mut promise = self.shared_memory.async_waiters.remove(i).promise;
+                    let value = self.wait_result_value(result);
+                    promise.resolve(*self, value);
+
+                    num_settled += 1;
+                } else {
+                    i += 1;
+                }
+            }
+
+            if num_settled > 0 {
+                return true;
+            } else if !can_settle_later {
+                return false;
+            }
+        }
+    }
+
+    /// The string value for the result of waiting, e.g. "timed-out".
+    pub fn wait_result_value(&self, result: WaitResult) -> Value {
+        let name = match result {
+            WaitResult::Ok => self.names.ok(),
+            WaitResult::NotEqual => self.names.not_equal(),
+            WaitResult::TimedOut => self.names.timed_out(),
+        };
+
+        *name.as_string().as_value()
+    }
+}
+
+/// Read a value from shared memory with an Unordered access
+/// (https://tc39.es/ecma262/#sec-memory-model). Aligned values are read with a single relaxed
+/// atomic load so that racing with other agents is well defined, while misaligned values are read
+/// byte by byte and may tear.
+///
+/// # Safety
+/// The pointer must be valid for reads of `size_of::<T>()` bytes.
+pub unsafe fn load_unordered<T: Copy>(ptr: *const u8) -> T {
+    let size = size_of::<T>();
+
+    if (ptr as usize) % size == 0 {
+        match size {
+            1 => {
+                return transmute_copy(&AtomicU8::from_ptr(ptr as *mut u8).load(Ordering::Relaxed))
+            }
+            2 => {
+                let atomic = AtomicU16::from_ptr(ptr as *mut u16);
+                return transmute_copy(&atomic.load(Ordering::Relaxed));
+            }
+            4 => {
+                let atomic = AtomicU32::from_ptr(ptr as *mut u32);
+                return transmute_copy(&atomic.load(Ordering::Relaxed));
+            }
+            8 => {
+                let atomic = AtomicU64::from_ptr(ptr as *mut u64);
+                return transmute_copy(&atomic.load(Ordering::Relaxed));
+            }
+            _ => {}
+        }
+    }
+
+    let mut value = MaybeUninit::<T>::uninit();
+    let value_ptr = value.as_mut_ptr().cast::<u8>();
+
+    for i in 0..size {
+        let byte = AtomicU8::from_ptr(ptr.add(i) as *mut u8).load(Ordering::Relaxed);
+        value_ptr.add(i).write(byte);
+    }
+
+    value.assume_init()
+}
+
+/// Write a value to shared memory with an Unordered access
+/// (https://tc39.es/ecma262/#sec-memory-model). See `load_unordered`.
+///
+/// # Safety
+/// The pointer must be valid for writes of `size_of::<T>()` bytes.
+pub unsafe fn store_unordered<T: Copy>(ptr: *mut u8, value: T) {
+    let size = size_of::<T>();
+
+    if (ptr as usize) % size == 0 {
+        match size {
+            1 => {
+                let bits: u8 = transmute_copy(&value);
+                return AtomicU8::from_ptr(ptr).store(bits, Ordering::Relaxed);
+            }
+            2 => {
+                let bits: u16 = transmute_copy(&value);
+                return AtomicU16::from_ptr(ptr.cast()).store(bits, Ordering::Relaxed);
+            }
+            4 => {
+                let bits: u32 = transmute_copy(&value);
+                return AtomicU32::from_ptr(ptr.cast()).store(bits, Ordering::Relaxed);
+            }
+            8 => {
+                let bits: u64 = transmute_copy(&value);
+                return AtomicU64::from_ptr(ptr.cast()).store(bits, Ordering::Relaxed);
+            }
+            _ => {}
+        }
+    }
+
+    let value_ptr = (&value as *const T).cast::<u8>();
+
+    for i in 0..size {
+        AtomicU8::from_ptr(ptr.add(i)).store(value_ptr.add(i).read(), Ordering::Relaxed);
+    }
+}
+
+/// Copy bytes to or from shared memory with Unordered accesses
+/// (https://tc39.es/ecma262/#sec-memory-model). Overlapping ranges are copied as if through an
+/// intermediate buffer. Each access is a relaxed atomic load or store, using whole words when the
+/// source and target have the same alignment, so racing with other agents is well defined.
+///
+/// # Safety
+/// The source must be valid for reads and the target valid for writes of `byte_length` bytes.
+pub unsafe fn copy_unordered(source: *const u8, target: *mut u8, byte_length: usize) {
+    const WORD_SIZE: usize = size_of::<u64>();
+
+    let copy_byte = |i: usize| {
+        let byte = AtomicU8::from_ptr(source.add(i) as *mut u8).load(Ordering::Relaxed);
+        AtomicU8::from_ptr(target.add(i)).store(byte, Ordering::Relaxed);
+    };
+    let copy_word = |i: usize| {
+        let word = AtomicU64::from_ptr(source.add(i) as *mut u64).load(Ordering::Relaxed);
+        AtomicU64::from_ptr(target.add(i).cast()).store(word, Ordering::Relaxed);
+    };
+
+    let is_word_aligned = |i: usize| (source as usize + i) % WORD_SIZE == 0;
+    let use_words = (source as usize) % WORD_SIZE == (target as usize) % WORD_SIZE;
+
+    // Copy backwards if the target overlaps the end of the source, so that source bytes are read
+    // before they are overwritten.
+    let is_backwards = (source as usize) < (target as usize)
+        && (target as usize) < (source as usize) + byte_length;
+
+    if is_backwards {
+        let mut i = byte_length;
+
+        if use_words {
+            while i > 0 && !is_word_aligned(i) {
+                i -= 1;
+                copy_byte(i);
+            }
+
+            while i >= WORD_SIZE {
+                i -= WORD_SIZE;
+                copy_word(i);
+            }
+        }
+
+        while i > 0 {
+            i -= 1;
+            copy_byte(i);
+        }
+    } else {
+        let mut i = 0;
+
+        if use_words {
+            while i < byte_length && !is_word_aligned(i) {
+                copy_byte(i);
+                i += 1;
+            }
+
+            while i + WORD_SIZE <= byte_length {
+                copy_word(i);
+                i += WORD_SIZE;
+            }
+        }
+
+        while i < byte_length {
+            copy_byte(i);
+            i += 1;
+        }
+    }
+}
+
+/// The operation performed by an atomic read-modify-write.
+#[derive(Clone, Copy)]
+pub enum AtomicOp {
+    Add,
+    Sub,
+    And,
+    Or,
+    Xor,
+    Exchange,
+}
+
+impl AtomicOp {
+    /// Apply the operation to the raw bits of an element, truncating to the element size is left
+    /// to the caller.
+    pub fn apply(self, old_bits: u64, operand: u64) -> u64 {
+        match self {
+            AtomicOp::Add => old_bits.wrapping_add(operand),
+            AtomicOp::Sub => old_bits.wrapping_sub(operand),
+            AtomicOp::And => old_bits & operand,
+            AtomicOp::Or => old_bits | operand,
+            AtomicOp::Xor => old_bits ^ operand,
+            AtomicOp::Exchange => operand,
+        }
+    }
+}
+
+/// Call the body with the pointer reinterpreted as an atomic integer of the given element size,
+/// binding the name of the atomic's integer type.
+macro_rules! with_atomic {
+    ($ptr:expr, $element_size:expr, |$atomic:ident: $int:ident| $body:expr) => {
+        match $element_size {
+            1 => {
+                type $int = u8;
+                let $atomic = AtomicU8::from_ptr($ptr);
+                $body
+            }
+            2 => {
+                type $int = u16;
+                let $atomic = AtomicU16::from_ptr($ptr.cast());
+                $body
+            }
+            4 => {
+                type $int = u32;
+                let $atomic = AtomicU32::from_ptr($ptr.cast());
+                $body
+            }
+            8 => {
+                type $int = u64;
+                let $atomic = AtomicU64::from_ptr($ptr.cast());
+                $body
+            }
+            _ => unreachable!("invalid atomic element size"),
+        }
+    };
+}
+
+/// Sequentially consistent load of the raw bits of an integer element, zero extended.
+///
+/// # Safety
+/// The pointer must be valid and aligned to the element size.
+pub unsafe fn load_seq_cst(ptr: *mut u8, element_size: usize) -> u64 {
+    with_atomic!(
+        ptr,
+        element_size,
+        |atomic: Int| atomic.load(Ordering::SeqCst) as u64
+    )
+}
+
+/// Sequentially consistent store of the raw bits of an integer element, truncated to the element
+/// size.
+///
+/// # Safety
+/// The pointer must be valid and aligned to the element size.
+pub unsafe fn store_seq_cst(ptr: *mut u8, element_size: usize, bits: u64) {
+    with_atomic!(ptr, element_size, |atomic: Int| atomic
+        .store(bits as Int, Ordering::SeqCst))
+}
+
+/// Sequentially consistent read-modify-write of an integer element, returning the raw bits of the
+/// previous value.
+///
+/// # Safety
+/// The pointer must be valid and aligned to the element size.
+pub unsafe fn read_modify_write_seq_cst(
+    ptr: *mut u8,
+    element_size: usize,
+    op: AtomicOp,
+    operand: u64,
+) -> u64 {
+    with_atomic!(ptr, element_size, |atomic: Int| {
+        let operand = operand as Int;
+        let old_value = match op {
+            AtomicOp::Add => atomic.fetch_add(operand, Ordering::SeqCst),
+            AtomicOp::Sub => atomic.fetch_sub(operand, Ordering::SeqCst),
+            AtomicOp::And => atomic.fetch_and(operand, Ordering::SeqCst),
+            AtomicOp::Or => atomic.fetch_or(operand, Ordering::SeqCst),
+            AtomicOp::Xor => atomic.fetch_xor(operand, Ordering::SeqCst),
+            AtomicOp::Exchange => atomic.swap(operand, Ordering::SeqCst),
+        };
+
+        old_value as u64
+    })
+}
+
+/// Sequentially consistent compare and exchange of an integer element, returning the raw bits of
+/// the previous value.
+///
+/// # Safety
+/// The pointer must be valid and aligned to the element size.
+pub unsafe fn compare_exchange_seq_cst(
+    ptr: *mut u8,
+    element_size: usize,
+    expected: u64,
+    replacement: u64,
+) -> u64 {
+    with_atomic!(ptr, element_size, |atomic: Int| {
+        match atomic.compare_exchange(
+            expected as Int,
+            replacement as Int,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(old_value) | Err(old_value) => old_value as u64,
+        }
+    })
+}
@@ -6,11 +6,18 @@ use super::{
     Context, StackRoot, HeapPtr,
 };
 use crate::{
+    common::source_map::{
+        decode_data_url, find_source_mapping_url, resolve_path, OriginalPosition, SourceMap,
+    },
     field_offset,
-    parser::{loc::calculate_line_offsets, source::Source},
+    parser::{
+        loc::{calculate_line_offsets, find_line_col_for_pos, Pos},
+        source::Source,
+    },
     runtime::{alloc_error::AllocResult, heap_item_descriptor::HeapItemKind},
     set_uninit,
 };
+use alloc::rc::Rc;
 use alloc::string::String;
 use alloc::string::ToString;
 
@@ -23,6 +30,8 @@ pub struct SourceFile {
     display_name: Option<HeapPtr<FlatString>>,
     /// Lazily generated array of line offsets for the source file
     line_offsets: Option<HeapPtr<LineOffsetArray>>,
+    /// Index of this file's source map in the context's loaded source maps, once it has been loaded
+    source_map_id: Option<u32>,
     /// Inlined source file contents as a WTF8 string
     contents: InlineArray<u8>,
 }
@@ -47,6 +56,7 @@ impl SourceFile {
             cx.base_descriptors.get(HeapItemKind::SourceFile)
         );
         set_uninit!(scope.line_offsets, None);
+        set_uninit!(scope.source_map_id, None);
         set_uninit!(scope.path, *path);
         set_uninit!(scope.display_name, display_name.map(|n| *n));
 
@@ -84,6 +94,83 @@ impl SourceFile {
     pub fn contents_as_slice(&self) -> &[u8] {
         self.contents.as_slice()
     }
+
+    /// The source map for this file, if it has already been loaded by `load_source_map`.
+    pub fn source_map_raw(&self, cx: Context) -> Option<Rc<SourceMap>> {
+        cx.source_maps.get(self.source_map_id? as usize)?.clone()
+    }
+
+    /// Map a position in this file to its position in the original source. Does not allocate, so
+    /// the source map and line offsets must already have been loaded.
+    pub fn original_position_raw(&self, cx: Context, pos: Pos) -> Option<OriginalPosition> {
+        let source_map = self.source_map_raw(cx)?;
+        let (line, column) = self.generated_line_col(pos)?;
+
+        source_map.original_position(line, column)
+    }
+
+    /// Find the original name of the function with the given generated name that starts at a
+    /// position in this file. Does not allocate, so the source map and line offsets must already
+    /// have been loaded.
+    pub fn original_function_name_raw(
+        &self,
+        cx: Context,
+        pos: Pos,
+        generated_name: &str,
+    ) -> Option<String> {
+        let source_map = self.source_map_raw(cx)?;
+        let (line, column) = self.generated_line_col(pos)?;
+        let line_start = self.line_offsets?.as_slice()[line as usize] as usize;
+        let line_contents = &self.contents_as_slice()[line_start..];
+
+        // The function's name is the first named mapping near the start of the function whose
+        // generated text is the generated function name, e.g. `function foo` or `foo() {`.
+        source_map
+            .names_from(line, column)
+            .take(4)
+            .find_map(|(mapped_column, name)| {
+                let offset = utf16_column_to_byte_offset(line_contents, mapped_column as usize);
+                let is_match = line_contents[offset..].starts_with(generated_name.as_bytes());
+                name.filter(|_| is_match).map(|name| name.to_string())
+            })
+    }
+
+    /// The 0-indexed line and UTF-16 column of a position, as used by source maps.
+    fn generated_line_col(&self, pos: Pos) -> Option<(u32, u32)> {
+        let line_offsets = self.line_offsets?;
+        let (line, _) = find_line_col_for_pos(pos, line_offsets.as_slice());
+        let line_start = line_offsets.as_slice()[line - 1] as usize;
+        let column = utf16_length(&self.contents_as_slice()[line_start..pos]);
+
+        Some(((line - 1) as u32, column as u32))
+    }
+}
+
+/// Number of UTF-16 code units needed to encode WTF-8 bytes. Four byte sequences are encoded as
+/// surrogate pairs.
+fn utf16_length(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .map(|byte| match byte {
+            0x80..=0xBF => 0,
+            0xF0..=0xFF => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Byte offset of a UTF-16 column within WTF-8 bytes, clamped to the end of the line.
+fn utf16_column_to_byte_offset(bytes: &[u8], column: usize) -> usize {
+    let mut utf16_column = 0;
+    for (offset, byte) in bytes.iter().enumerate() {
+        if utf16_column >= column || *byte == b'\n' {
+            return offset;
+        }
+
+        utf16_column += utf16_length(&[*byte]);
+    }
+
+    bytes.len()
 }
 
 impl StackRoot<SourceFile> {
@@ -116,6 +203,56 @@ impl StackRoot<SourceFile> {
         let line_contents = &self.contents.as_slice()[start..end];
         Ok(String::from_utf8_lossy(line_contents).to_string())
     }
+
+    /// Load and parse the source map linked by this file's `sourceMappingURL` comment, if source
+    /// maps are enabled. The result is cached for the lifetime of the context, including failure.
+    pub fn load_source_map(&mut self, mut cx: Context) -> Option<Rc<SourceMap>> {
+        if !cx.options.source_maps {
+            return None;
+        }
+
+        if let Some(source_map_id) = self.source_map_id {
+            return cx.source_maps[source_map_id as usize].clone();
+        }
+
+        let source_map = self.read_source_map(cx).map(Rc::new);
+
+        self.source_map_id = Some(cx.source_maps.len() as u32);
+        cx.source_maps.push(source_map.clone());
+
+        source_map
+    }
+
+    fn read_source_map(&self, cx: Context) -> Option<SourceMap> {
+        let url = find_source_mapping_url(self.contents_as_slice())?;
+        let path = self.path(cx).to_string();
+
+        if url.starts_with("data:") {
+            let json = decode_data_url(url)?;
+            return SourceMap::parse(&json, &path).ok();
+        }
+
+        let source_map_path = resolve_path(&path, url);
+        let json = cx.sys.as_ref()?.load_source_map(&source_map_path)?;
+
+        SourceMap::parse(&json, &source_map_path).ok()
+    }
+
+    /// Map a position in this file to its position in the original source, loading the source map
+    /// and line offsets if necessary.
+    pub fn original_position(
+        &mut self,
+        cx: Context,
+        pos: Pos,
+    ) -> AllocResult<Option<OriginalPosition>> {
+        if self.load_source_map(cx).is_none() {
+            return Ok(None);
+        }
+
+        self.line_offsets_ptr(cx)?;
+
+        Ok(self.original_position_raw(cx, pos))
+    }
 }
 
 impl HeapItem for HeapPtr<SourceFile> {
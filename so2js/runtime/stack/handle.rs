@@ -474,6 +474,29 @@ impl StackRootContext {
         }
     }
 
+    /// Whether a pointer is the address of a handle that is currently in use
+    #[cfg(feature = "heap_verify")]
+    pub fn contains_handle(&self, ptr: *const u8) -> bool {
+        let is_between_pointers =
+            |start_ptr: *const StackRootContents, end_ptr: *const StackRootContents| {
+                (start_ptr as *const u8) <= ptr && ptr < (end_ptr as *const u8)
+            };
+
+        let mut current_block = &self.current_block;
+        if is_between_pointers(current_block.start_ptr, self.next_ptr) {
+            return true;
+        }
+
+        while let Some(prev_block) = &current_block.prev_block {
+            current_block = prev_block;
+            if is_between_pointers(current_block.start_ptr, current_block.end_ptr) {
+                return true;
+            }
+        }
+
+        false
+    }
+
     #[cfg(feature = "handle_stats")]
     pub fn handle_stats(&self) -> StackRootStats {
         StackRootStats {
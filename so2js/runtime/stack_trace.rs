@@ -1,20 +1,23 @@
 use super::{
     bytecode::{function::BytecodeFunction, source_map::BytecodeSourceMap},
     collections::BsArray,
-    gc::{HeapItem, GcVisitorExt},
+    gc::{GcVisitorExt, HeapItem},
     heap_item_descriptor::HeapItemKind,
-    intrinsics::{error_constructor::CachedStackTraceInfo, rust_runtime::return_undefined},
+    intrinsics::{
+        error_constructor::{stack_trace_limit, CachedStackTraceInfo},
+        rust_runtime::return_undefined,
+    },
     source_file::SourceFile,
-    Context, StackRoot, HeapPtr,
+    Context, HeapPtr, StackRoot,
 };
 use crate::{
     js_stack_scope_guard, parser::loc::find_line_col_for_pos, runtime::alloc_error::AllocResult,
 };
+use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
-use alloc::format;
 
 /// An array of stack frame entries which contain the information necessary to construct a full
 /// stack trace later if desired.
@@ -28,11 +31,36 @@ pub struct HeapStackFrameInfo {
     ///
     /// Set to 0 if there is no BytecodeSourceMap for this frame.
     bytecode_offset: usize,
+    /// Whether the function was called as a constructor.
+    is_constructor: bool,
+    /// Whether this is an async function awaiting the frames above it, rather than a frame that
+    /// is on the VM stack.
+    is_async: bool,
 }
 
-struct StackFrameInfo {
-    function: StackRoot<BytecodeFunction>,
-    bytecode_offset: usize,
+impl HeapStackFrameInfo {
+    pub fn function(&self) -> HeapPtr<BytecodeFunction> {
+        self.function
+    }
+
+    pub fn bytecode_offset(&self) -> usize {
+        self.bytecode_offset
+    }
+
+    pub fn is_constructor(&self) -> bool {
+        self.is_constructor
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+}
+
+pub(crate) struct StackFrameInfo {
+    pub(crate) function: StackRoot<BytecodeFunction>,
+    pub(crate) bytecode_offset: usize,
+    pub(crate) is_constructor: bool,
+    pub(crate) is_async: bool,
 }
 
 impl StackFrameInfo {
@@ -40,12 +68,18 @@ impl StackFrameInfo {
         HeapStackFrameInfo {
             function: *self.function,
             bytecode_offset: self.bytecode_offset,
+            is_constructor: self.is_constructor,
+            is_async: self.is_async,
         }
     }
 }
 
-/// Gather the stack frame information for each stack frame in the current stack.
-fn gather_current_stack_frames(mut cx: Context, skip_current_frame: bool) -> Vec<StackFrameInfo> {
+/// Gather the stack frame information for each stack frame in the current stack, from innermost
+/// to outermost.
+pub(crate) fn gather_current_stack_frames(
+    mut cx: Context,
+    skip_current_frame: bool,
+) -> Vec<StackFrameInfo> {
     let mut frames = vec![];
 
     // We may want to skip the first stack frame e.g. when in an error constructor
@@ -85,6 +119,8 @@ fn gather_current_stack_frames(mut cx: Context, skip_current_frame: bool) -> Vec
         frames.push(StackFrameInfo {
             function,
             bytecode_offset,
+            is_constructor: stack_frame.is_construct_call(),
+            is_async: false,
         });
 
         // Move to the parent stack frame
@@ -95,7 +131,69 @@ fn gather_current_stack_frames(mut cx: Context, skip_current_frame: bool) -> Vec
     frames
 }
 
-/// Create a StackFrameInfoArray for the current stack.
+/// Gather the stack frame information for the chain of async functions awaiting the async function
+/// or async generator currently being resumed from an `await`, from innermost to outermost. Stops
+/// once `limit` total frames have been gathered.
+///
+/// These callers are no longer on the VM stack, so they are found by following the promise of each
+/// suspended function to the suspended function awaiting it. Nothing is recorded ahead of time, so
+/// async stack traces have no cost until a trace is actually gathered.
+pub(crate) fn gather_async_stack_frames(
+    mut cx: Context,
+    frames: &mut Vec<StackFrameInfo>,
+    limit: usize,
+) {
+    let mut generator_opt = cx.vm().async_resume_generator();
+
+    while let Some(generator) = generator_opt {
+        if frames.len() >= limit {
+            break;
+        }
+
+        // Find the promise that the callers of this async function or generator are waiting on
+        let awaited_promise = if let Some(generator) = generator.as_generator() {
+            generator.async_promise_ptr()
+        } else if let Some(async_generator) = generator.as_async_generator() {
+            async_generator
+                .peek_request_ptr()
+                .and_then(|request| request.capability_ptr().promise_ptr().as_promise())
+        } else {
+            None
+        };
+
+        generator_opt = awaited_promise.and_then(|promise| promise.awaiting_generator_ptr());
+
+        // Awaiting function is suspended, report the location of the await it is suspended at
+        if let Some(awaiting_generator) = generator_opt {
+            let (closure, pc_offset) = if let Some(generator) = awaiting_generator.as_generator() {
+                (generator.closure_ptr(), generator.resume_pc_offset())
+            } else {
+                let async_generator = awaiting_generator.as_async_generator().unwrap();
+                (
+                    async_generator.closure_ptr(),
+                    async_generator.resume_pc_offset(),
+                )
+            };
+
+            let function = closure.function_ptr();
+            let bytecode_offset = if function.source_map_ptr().is_some() {
+                let pc = function.as_ptr().cast::<u8>() as usize + pc_offset;
+                pc - function.bytecode().as_ptr() as usize
+            } else {
+                0
+            };
+
+            frames.push(StackFrameInfo {
+                function: function.to_stack(cx),
+                bytecode_offset,
+                is_constructor: false,
+                is_async: true,
+            });
+        }
+    }
+}
+
+/// Create a StackFrameInfoArray for the current stack, limited to `Error.stackTraceLimit` frames.
 ///
 /// This contains the information necessary to construct a full stack trace for the error if the
 /// `stack` getter is ever called.
@@ -105,8 +203,19 @@ pub fn create_current_stack_frame_info(
 ) -> AllocResult<HeapPtr<StackFrameInfoArray>> {
     js_stack_scope_guard!(cx);
 
-    let frames = gather_current_stack_frames(cx, skip_current_frame);
+    let limit = stack_trace_limit(cx);
+    let mut frames = gather_current_stack_frames(cx, skip_current_frame);
+    gather_async_stack_frames(cx, &mut frames, limit);
+    frames.truncate(limit);
 
+    create_stack_frame_info_array(cx, &frames)
+}
+
+/// Create a StackFrameInfoArray from a list of gathered stack frames.
+pub(crate) fn create_stack_frame_info_array(
+    cx: Context,
+    frames: &[StackFrameInfo],
+) -> AllocResult<HeapPtr<StackFrameInfoArray>> {
     let mut array =
         StackFrameInfoArray::new_uninit(cx, HeapItemKind::StackFrameInfoArray, frames.len())?;
 
@@ -130,48 +239,78 @@ pub fn create_stack_trace(
     let mut first_source_file_line_col = None;
 
     for (i, stack_frame) in stack_frame_info.as_slice().iter().enumerate() {
-        // Each line of the stack trace starts indented
-        stack_trace.push_str("  at ");
+        // Each line of the stack trace starts indented, marking frames of awaiting async functions
+        if stack_frame.is_async {
+            stack_trace.push_str("  at async ");
+        } else {
+            stack_trace.push_str("  at ");
+        }
 
-        // Followed by the name of the function
         let func = stack_frame.function;
+        let source_file = func.source_file_ptr();
+
+        // Find the source position for this frame, if we have source positions for the function
+        let source_position = func.source_map_ptr().and_then(|source_map| {
+            BytecodeSourceMap::get_source_position(source_map, stack_frame.bytecode_offset)
+        });
+
+        // Map the source position back to the original source if the source file has a source
+        // map. Will not allocate since source maps were already loaded in `prepare_for_stack_trace`.
+        let original_position = source_file
+            .zip(source_position)
+            .and_then(|(source_file, pos)| source_file.original_position_raw(cx, pos));
+
+        // Followed by the name of the function, using the original name if one is known
         if let Some(name) = func.name() {
-            stack_trace.push_str(&name.format()?);
+            let name = name.format()?;
+            let original_name = match (source_file, func.source_range()) {
+                (Some(source_file), Some(range)) if original_position.is_some() => {
+                    source_file.original_function_name_raw(cx, range.start, &name)
+                }
+                _ => None,
+            };
+
+            stack_trace.push_str(original_name.as_deref().unwrap_or(&name));
         } else {
             stack_trace.push_str("<anonymous>");
         }
 
         stack_trace.push_str(" (");
 
-        // Followed by the file where the function was defined
-        if let Some(file_name) = func.source_file_ptr().map(|file| file.display_name()) {
-            stack_trace.push_str(&file_name.to_string());
+        if let Some(original_position) = original_position {
+            // Followed by the original file, line, and column
+            stack_trace.push_str(&format!(
+                "{}:{}:{}",
+                original_position.source, original_position.line, original_position.column
+            ));
         } else {
-            stack_trace.push_str("<native>");
-        }
-
-        // If we have source code positions for this function, add the line and column
-        if let Some(source_map) = func.source_map_ptr() {
-            // Map that bytecode offset to a source position using the source map
-            let source_position =
-                BytecodeSourceMap::get_source_position(source_map, stack_frame.bytecode_offset);
+            // Followed by the file where the function was defined
+            if let Some(file_name) = source_file.map(|file| file.display_name()) {
+                stack_trace.push_str(&file_name.to_string());
+            } else {
+                stack_trace.push_str("<native>");
+            }
 
             if let Some(source_position) = source_position {
                 // Get the line and column number for the source position. Will not allocate since
                 // line offsets were already generated in `prepare_for_stack_trace`.
-                let source_file = func.source_file_ptr().unwrap();
-                let line_offsets = source_file.line_offsets_ptr_raw().unwrap();
+                let line_offsets = source_file.unwrap().line_offsets_ptr_raw().unwrap();
                 let (line, column) =
                     find_line_col_for_pos(source_position, line_offsets.as_slice());
 
                 // Append the line and column to the function name
                 stack_trace.push_str(&format!(":{line}:{column}"));
+            }
+        }
 
-                // Save the first source position that is found
-                if first_source_file_line_col.is_none() {
-                    let source_file = func.source_file_ptr().unwrap().to_stack(cx);
-                    first_source_file_line_col = Some((source_file, line, column));
-                }
+        // Save the first source position that is found
+        if let Some(source_position) = source_position {
+            if first_source_file_line_col.is_none() {
+                let source_file = source_file.unwrap();
+                let line_offsets = source_file.line_offsets_ptr_raw().unwrap();
+                let (line, column) =
+                    find_line_col_for_pos(source_position, line_offsets.as_slice());
+                first_source_file_line_col = Some((source_file.to_stack(cx), line, column));
             }
         }
 
@@ -201,12 +340,13 @@ fn prepare_for_stack_trace(
     // StackRoot is shared between iterations
     let mut source_file_handle: StackRoot<SourceFile> = StackRoot::empty(cx);
 
-    // Generate the line offsets for all source files referenced in the stack trace. This may
-    // allocate if line offsets have not been generated yet.
+    // Generate the line offsets and load the source maps for all source files referenced in the
+    // stack trace. This may allocate if line offsets have not been generated yet.
     for i in 0..stack_frame_info.len() {
         if let Some(source_file) = stack_frame_info.as_slice()[i].function.source_file_ptr() {
             source_file_handle.replace(source_file);
             source_file_handle.line_offsets_ptr(cx)?;
+            source_file_handle.load_source_map(cx);
         }
     }
 
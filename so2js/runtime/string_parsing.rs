@@ -18,11 +18,11 @@ use crate::{
 
 use super::{
     intrinsics::date_object::{
-        year_month_day_to_days_since_unix_epoch, MAX_TIME_VALUE, MS_PER_DAY, MS_PER_HOUR,
+        utc, year_month_day_to_days_since_unix_epoch, MAX_TIME_VALUE, MS_PER_DAY, MS_PER_HOUR,
         MS_PER_MINUTE, MS_PER_SECOND,
     },
     string_value::{StringValue, UnsafeCodeUnitIterator},
-    StackRoot,
+    Context, StackRoot,
 };
 
 pub struct StringLexer {
@@ -696,19 +696,22 @@ fn parse_decimal_digits(lexer: &mut StringLexer, num_digits: i32) -> Option<i64>
 /// - Date.prototype.toISOString
 /// - Date.prototype.toString
 /// - Date.prototype.toUTCString
-pub fn parse_string_to_date(string: StackRoot<StringValue>) -> AllocResult<Option<f64>> {
+pub fn parse_string_to_date(
+    cx: Context,
+    string: StackRoot<StringValue>,
+) -> AllocResult<Option<f64>> {
     let lexer = StringLexer::new(string)?;
-    if let Some(date) = parse_string_to_iso_date(lexer) {
+    if let Some(date) = parse_string_to_iso_date(cx, lexer) {
         return Ok(Some(date));
     }
 
     let lexer = StringLexer::new(string)?;
-    Ok(parse_string_to_utc_or_default_date(lexer))
+    Ok(parse_string_to_utc_or_default_date(cx, lexer))
 }
 
 /// Date Time String Format (https://tc39.es/ecma262/#sec-date-time-string-format)
 /// Parse string to a date following the simplified ISO 8601 format
-fn parse_string_to_iso_date(mut lexer: StringLexer) -> Option<f64> {
+fn parse_string_to_iso_date(cx: Context, mut lexer: StringLexer) -> Option<f64> {
     // Parse required year - must be in format:
     //  YYYY
     //  +YYYYYY
@@ -795,9 +798,10 @@ fn parse_string_to_iso_date(mut lexer: StringLexer) -> Option<f64> {
         }
     }
 
-    // Parse optional timezone, calculating timezone offset in milliseconds
+    // Parse optional timezone, calculating timezone offset in milliseconds. Date-time forms
+    // without a timezone are in local time, while date-only forms are in UTC.
     let timezone_offset_milliseconds = if lexer.eat('Z') {
-        0
+        Some(0)
     } else if lexer.current_equals('-') || lexer.current_equals('+') {
         let sign = if lexer.current_equals('+') { 1 } else { -1 };
         lexer.advance();
@@ -812,12 +816,11 @@ fn parse_string_to_iso_date(mut lexer: StringLexer) -> Option<f64> {
         }
 
         // Unchecked operations since ranges have already been validated
-        sign * (timezone_hour * MS_PER_HOUR as i64 + timezone_minute * MS_PER_MINUTE as i64)
+        Some(sign * (timezone_hour * MS_PER_HOUR as i64 + timezone_minute * MS_PER_MINUTE as i64))
     } else if has_time {
-        // TODO: Use current time zone offset
-        0
+        None
     } else {
-        0
+        Some(0)
     };
 
     // Make sure we are at the end of the string
@@ -826,6 +829,7 @@ fn parse_string_to_iso_date(mut lexer: StringLexer) -> Option<f64> {
     }
 
     utc_time_from_full_date_parts(
+        cx,
         years,
         months,
         days,
@@ -839,7 +843,10 @@ fn parse_string_to_iso_date(mut lexer: StringLexer) -> Option<f64> {
 
 /// Callers must ensure that the date parts are in valid ranges so that overflow does not occur
 /// when calculating the time value.
+///
+/// Date parts are in local time if there is no timezone offset.
 fn utc_time_from_full_date_parts(
+    cx: Context,
     year: i64,
     month: i64,
     day: i64,
@@ -847,7 +854,7 @@ fn utc_time_from_full_date_parts(
     minute: i64,
     second: i64,
     millisecond: i64,
-    timezone_offset_milliseconds: i64,
+    timezone_offset_milliseconds: Option<i64>,
 ) -> Option<f64> {
     let date_part_milliseconds =
         year_month_day_to_days_since_unix_epoch(year, month, day).unwrap() * MS_PER_DAY as i64;
@@ -857,7 +864,11 @@ fn utc_time_from_full_date_parts(
         + second * MS_PER_SECOND as i64
         + millisecond;
 
-    let utc_time = date_part_milliseconds + time_part_milliseconds - timezone_offset_milliseconds;
+    let time = date_part_milliseconds + time_part_milliseconds;
+    let utc_time = match timezone_offset_milliseconds {
+        Some(timezone_offset_milliseconds) => time - timezone_offset_milliseconds,
+        None => utc(cx, time as f64) as i64,
+    };
 
     // Check that time value is in range
     if utc_time.abs() > MAX_TIME_VALUE as i64 {
@@ -868,7 +879,7 @@ fn utc_time_from_full_date_parts(
 }
 
 // Parse the string to the date format specified in toString or toUTCString
-fn parse_string_to_utc_or_default_date(mut lexer: StringLexer) -> Option<f64> {
+fn parse_string_to_utc_or_default_date(cx: Context, mut lexer: StringLexer) -> Option<f64> {
     // Both string formats start with the week day
     parse_week_day(&mut lexer)?;
 
@@ -922,7 +933,7 @@ fn parse_string_to_utc_or_default_date(mut lexer: StringLexer) -> Option<f64> {
 
     let timezone_offset_milliseconds = if is_utc_format {
         // End of UTC format
-        0
+        Some(0)
     } else {
         // The toString format always ends with a time zone
         let sign = if lexer.eat('+') {
@@ -940,13 +951,17 @@ fn parse_string_to_utc_or_default_date(mut lexer: StringLexer) -> Option<f64> {
             return None;
         }
 
-        // TODO: Parse time zone names
-        if !lexer.is_end() {
-            return None;
+        // Optional time zone name in parentheses, e.g. " (EST)", which is ignored
+        if lexer.eat(' ') {
+            lexer.expect('(')?;
+            while !lexer.is_end() && !lexer.current_equals(')') {
+                lexer.advance();
+            }
+            lexer.expect(')')?;
         }
 
         // Unchecked operations since ranges have already been validated
-        sign * (timezone_hour * MS_PER_HOUR as i64 + timezone_minute * MS_PER_MINUTE as i64)
+        Some(sign * (timezone_hour * MS_PER_HOUR as i64 + timezone_minute * MS_PER_MINUTE as i64))
     };
 
     // Make sure we are at the end of the string
@@ -955,6 +970,7 @@ fn parse_string_to_utc_or_default_date(mut lexer: StringLexer) -> Option<f64> {
     }
 
     utc_time_from_full_date_parts(
+        cx,
         year,
         month + 1,
         day,
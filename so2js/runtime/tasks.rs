@@ -124,20 +124,26 @@ impl TaskQueue {
 }
 
 impl Context {
-    /// Run all tasks until the task queue is empty.
+    /// Run all tasks until the task queue is empty and no pending Atomics.waitAsync promise can be
+    /// settled.
     pub fn run_all_tasks(&mut self) -> EvalResult<()> {
-        while let Some(task) = self.task_queue().tasks.pop_front() {
-            js_stack_scope!(*self, {
-                match task {
-                    Task::Callback1(task) => task.execute(*self),
-                    Task::AwaitResume(task) => task.execute(*self),
-                    Task::PromiseThenReaction(task) => task.execute(*self),
-                    Task::PromiseThenSettle(task) => task.execute(*self),
-                }
-            })?;
-        }
+        loop {
+            while let Some(task) = self.task_queue().tasks.pop_front() {
+                js_stack_scope!(*self, {
+                    match task {
+                        Task::Callback1(task) => task.execute(*self),
+                        Task::AwaitResume(task) => task.execute(*self),
+                        Task::PromiseThenReaction(task) => task.execute(*self),
+                        Task::PromiseThenSettle(task) => task.execute(*self),
+                    }
+                })?;
+            }
 
-        Ok(())
+            // Settling waiters enqueues more tasks
+            if !self.settle_async_waiters() {
+                return Ok(());
+            }
+        }
     }
 }
 
@@ -196,7 +202,13 @@ impl AwaitResumeTask {
             PromiseReactionKind::Reject => GeneratorCompletionType::Throw,
         };
 
-        if let Some(generator) = generator.as_generator() {
+        // Mark the generator as being resumed so that stack traces can follow its awaiters
+        let prev_resume_generator = cx
+            .vm()
+            .set_async_resume_generator(Some(*generator))
+            .map(|generator| generator.to_stack(cx));
+
+        let result = if let Some(generator) = generator.as_generator() {
             let realm = generator.closure_ptr().function_ptr().realm_ptr();
             cx.with_initial_realm_stack_frame(realm, |mut cx| {
                 cx.vm()
@@ -212,7 +224,12 @@ impl AwaitResumeTask {
                 async_generator_resume(cx, async_generator, completion_value, completion_type)?;
                 Ok(())
             })
-        }
+        };
+
+        cx.vm()
+            .set_async_resume_generator(prev_resume_generator.map(|generator| *generator));
+
+        result
     }
 }
 
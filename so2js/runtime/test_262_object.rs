@@ -4,19 +4,52 @@ use crate::{
     runtime::{alloc_error::AllocResult, bytecode::generator::BytecodeProgramGenerator, get},
 };
 use alloc::rc::Rc;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 
 use super::{
-    abstract_operations::set,
+    abstract_operations::{call, set},
     error::{syntax_error, syntax_parse_error, type_error},
     function::get_argument,
     gc_object::GcObject,
     intrinsics::{array_buffer_constructor::ArrayBufferObject, intrinsics::Intrinsic},
     object_value::ObjectValue,
+    shared_memory::SharedDataBlock,
     string_value::StringValue,
+    type_utilities::{is_callable, to_number, to_string},
     Context, EvalResult, PropertyKey, Realm, StackRoot, Value,
 };
 
+/// Host hooks for the `$262.agent` API, which allows test262 tests to run scripts in other agents
+/// that share memory with the main agent. The host decides how agents are run, e.g. each on its
+/// own thread.
+pub trait Test262Agent {
+    /// Start a new agent that evaluates the source text as a script.
+    fn start(&self, source: String);
+
+    /// Send a shared data block and a number to every started agent. Block until every agent has
+    /// received the broadcast.
+    fn broadcast(&self, data_block: Arc<SharedDataBlock>, number: f64);
+
+    /// Take the oldest report sent by an agent, if any.
+    fn get_report(&self) -> Option<String>;
+
+    /// Block until a broadcast is received. Returns None if no broadcast can ever be received.
+    fn receive_broadcast(&self) -> Option<(Arc<SharedDataBlock>, f64)>;
+
+    /// Send a report to the main agent.
+    fn report(&self, report: String);
+
+    /// Signal that this agent is done with its work.
+    fn leaving(&self);
+
+    /// Suspend this agent for the given number of milliseconds.
+    fn sleep(&self, millis: f64);
+
+    /// Time in milliseconds since an arbitrary point shared by all agents.
+    fn monotonic_now(&self) -> f64;
+}
+
 /// Utility functions used in test262 tests. Must be included in the main library for now so that
 /// builtin functions can be registered.
 pub struct Test262Object;
@@ -53,9 +86,74 @@ impl Test262Object {
 
         object.intrinsic_func(cx, cx.names.gc(), GcObject::run, 0, realm)?;
 
+        let gc_statistics_string = cx.alloc_string("gcStatistics")?.as_string();
+        let gc_statistics_key = PropertyKey::string_handle(cx, gc_statistics_string)?;
+        object.intrinsic_func(cx, gc_statistics_key, GcObject::statistics, 0, realm)?;
+
+        // Agent support is only available if the host provides it
+        if cx.test_262_agent.is_some() {
+            let agent_object = Self::new_agent_object(cx, realm)?;
+
+            let agent_string = cx.alloc_string("agent")?.as_string();
+            let agent_key = PropertyKey::string_handle(cx, agent_string)?;
+            object.intrinsic_data_prop(cx, agent_key, agent_object.into())?;
+        }
+
         Ok(object.to_stack(cx))
     }
 
+    fn new_agent_object(
+        cx: Context,
+        realm: StackRoot<Realm>,
+    ) -> AllocResult<StackRoot<ObjectValue>> {
+        let mut object = ObjectValue::new(
+            cx,
+            Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+            true,
+        )?;
+
+        object.intrinsic_func(cx, string_key(cx, "start")?, Self::agent_start, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            string_key(cx, "broadcast")?,
+            Self::agent_broadcast,
+            2,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            string_key(cx, "getReport")?,
+            Self::agent_get_report,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(
+            cx,
+            string_key(cx, "receiveBroadcast")?,
+            Self::agent_receive_broadcast,
+            1,
+            realm,
+        )?;
+        object.intrinsic_func(cx, string_key(cx, "report")?, Self::agent_report, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            string_key(cx, "leaving")?,
+            Self::agent_leaving,
+            0,
+            realm,
+        )?;
+        object.intrinsic_func(cx, string_key(cx, "sleep")?, Self::agent_sleep, 1, realm)?;
+        object.intrinsic_func(
+            cx,
+            string_key(cx, "monotonicNow")?,
+            Self::agent_monotonic_now,
+            0,
+            realm,
+        )?;
+
+        Ok(object)
+    }
+
     pub fn install(mut cx: Context, realm: StackRoot<Realm>) -> AllocResult<()> {
         js_stack_scope!(cx, {
             // Create the test262 object
@@ -209,7 +307,9 @@ impl Test262Object {
         }
 
         let object = value.as_object();
-        if !object.is_array_buffer() {
+        if object.is_shared_array_buffer() {
+            return type_error(cx, "cannot detach a SharedArrayBuffer");
+        } else if !object.is_array_buffer() {
             return Ok(cx.undefined());
         }
 
@@ -218,6 +318,140 @@ impl Test262Object {
 
         Ok(cx.undefined())
     }
+
+    fn agent(cx: Context) -> EvalResult<Rc<dyn Test262Agent>> {
+        match &cx.test_262_agent {
+            Some(agent) => Ok(agent.clone()),
+            None => type_error(cx, "agents are not supported"),
+        }
+    }
+
+    pub fn agent_start(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let source_arg = get_argument(cx, arguments, 0);
+        let source = to_string(cx, source_arg)?.format(cx)?;
+
+        Self::agent(cx)?.start(source);
+
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_broadcast(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let buffer_arg = get_argument(cx, arguments, 0);
+        let data_block = if buffer_arg.is_object() {
+            buffer_arg
+                .as_object()
+                .as_shared_array_buffer()
+                .and_then(|array_buffer| array_buffer.shared_data_block_arc(cx))
+        } else {
+            None
+        };
+
+        let data_block = match data_block {
+            Some(data_block) => data_block,
+            None => return type_error(cx, "expected SharedArrayBuffer"),
+        };
+
+        let number_arg = get_argument(cx, arguments, 1);
+        let number = to_number(cx, number_arg)?.as_number();
+
+        Self::agent(cx)?.broadcast(data_block, number);
+
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_get_report(
+        mut cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        match Self::agent(cx)?.get_report() {
+            Some(report) => Ok(cx.alloc_string(&report)?.as_string().into()),
+            None => Ok(cx.null()),
+        }
+    }
+
+    pub fn agent_receive_broadcast(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let callback = get_argument(cx, arguments, 0);
+        if !is_callable(callback) {
+            return type_error(cx, "expected function");
+        }
+
+        // Block until the broadcast is received, then pass it to the callback
+        if let Some((data_block, number)) = Self::agent(cx)?.receive_broadcast() {
+            let array_buffer = ArrayBufferObject::new_shared_from_data_block(cx, data_block)?;
+            let number_value = Value::from(number).to_stack(cx);
+
+            call(
+                cx,
+                callback,
+                cx.undefined(),
+                &[array_buffer.as_value(), number_value],
+            )?;
+        }
+
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_report(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let report_arg = get_argument(cx, arguments, 0);
+        let report = to_string(cx, report_arg)?.format(cx)?;
+
+        Self::agent(cx)?.report(report);
+
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_leaving(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        Self::agent(cx)?.leaving();
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_sleep(
+        cx: Context,
+        _: StackRoot<Value>,
+        arguments: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let millis_arg = get_argument(cx, arguments, 0);
+        let millis = to_number(cx, millis_arg)?.as_number();
+
+        Self::agent(cx)?.sleep(millis);
+
+        Ok(cx.undefined())
+    }
+
+    pub fn agent_monotonic_now(
+        cx: Context,
+        _: StackRoot<Value>,
+        _: &[StackRoot<Value>],
+    ) -> EvalResult<StackRoot<Value>> {
+        let now = Self::agent(cx)?.monotonic_now();
+        Ok(Value::from(now).to_stack(cx))
+    }
+}
+
+fn string_key(mut cx: Context, name: &str) -> AllocResult<StackRoot<PropertyKey>> {
+    let string = cx.alloc_string(name)?.as_string();
+    PropertyKey::string_handle(cx, string)
 }
 
 fn test_262_key(mut cx: Context) -> AllocResult<StackRoot<PropertyKey>> {
@@ -2,7 +2,7 @@ use alloc::{rc::Rc, string::ToString};
 
 use crate::runtime::error::syntax_error;
 use crate::{
-    common::wtf_8::Wtf8String,
+    common::{time_zone::TimeZoneOffset, wtf_8::Wtf8String},
     parser::{analyze::analyze, parse_module, source::Source, ParseContext},
     runtime::{
         bytecode::generator::BytecodeProgramGenerator,
@@ -83,4 +83,32 @@ pub trait Sys {
     ) -> EvalResult<StackRoot<Value>> {
         JSONObject::parse(cx, cx.undefined(), &[cx.alloc_string(&string)?.as_value()])
     }
+
+    /// Load the contents of the source map at a path referenced by a `sourceMappingURL` comment,
+    /// resolved against the path of the source file. Inline `data:` URLs never reach this hook.
+    fn load_source_map(&self, _path: &str) -> Option<alloc::string::String> {
+        None
+    }
+
+    /// The identifier of the host's time zone, e.g. "America/New_York" or "+05:30". Local time is
+    /// UTC if the host does not provide a time zone.
+    fn default_time_zone(&self) -> Option<alloc::string::String> {
+        None
+    }
+
+    /// The offset from UTC of a named time zone at an instant in milliseconds since the UNIX
+    /// epoch, or None if the host does not know of the time zone.
+    ///
+    /// Only used for time zones that are not in the embedded time zone database and whose TZif
+    /// data could not be loaded with `load_time_zone_data`.
+    fn time_zone_offset(&self, _time_zone: &str, _epoch_millis: f64) -> Option<TimeZoneOffset> {
+        None
+    }
+
+    /// Load the TZif data (https://www.rfc-editor.org/rfc/rfc8536) for a named time zone, e.g.
+    /// from /usr/share/zoneinfo. Only used for time zones that are not in the embedded time zone
+    /// database.
+    fn load_time_zone_data(&self, _time_zone: &str) -> Option<alloc::vec::Vec<u8>> {
+        None
+    }
 }
@@ -185,7 +185,11 @@ fn bench_program_all_steps(c: &mut Criterion, file: &str, flags: TestFlags) {
         cleanup2_step,
     );
 
-    // Isolate bytecode generation phase
+    bench_program_execute(c, file, flags);
+}
+
+/// Benchmark just the bytecode execution phase.
+fn bench_program_execute(c: &mut Criterion, file: &str, flags: TestFlags) {
     isolated_test(
         c,
         &format!("{file} > execute"),
@@ -240,6 +244,15 @@ pub fn program_benches(c: &mut Criterion) {
     bench_program_parser(c, "fixtures/typescript.js", TestFlags::empty());
 }
 
+/// Microbenchmarks for the execution of small programs.
+pub fn micro_benches(c: &mut Criterion) {
+    bench_program_execute(c, "micro/named_property_monomorphic.js", TestFlags::empty());
+    bench_program_execute(c, "micro/named_property_polymorphic.js", TestFlags::empty());
+    bench_program_execute(c, "micro/named_property_prototype.js", TestFlags::empty());
+    bench_program_execute(c, "micro/named_property_delete.js", TestFlags::empty());
+}
+
 criterion_group!(context, context_benches);
 criterion_group!(program, program_benches);
-criterion_main!(context, program);
+criterion_group!(micro, micro_benches);
+criterion_main!(context, program, micro);
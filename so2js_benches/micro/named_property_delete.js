@@ -0,0 +1,14 @@
+// Named property loads on objects that have had the same property deleted.
+var objects = [];
+for (var i = 0; i < 100; i++) {
+  var object = { a: 0, b: 0, x: i };
+  delete object.a;
+  objects.push(object);
+}
+
+var sum = 0;
+for (var iter = 0; iter < 1000; iter++) {
+  for (var i = 0; i < objects.length; i++) {
+    sum += objects[i].x;
+  }
+}
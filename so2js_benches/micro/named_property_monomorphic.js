@@ -0,0 +1,19 @@
+// Named property loads and stores on objects that all share a single shape.
+function Point(x, y) {
+  this.x = x;
+  this.y = y;
+}
+
+var points = [];
+for (var i = 0; i < 100; i++) {
+  points.push(new Point(i, i + 1));
+}
+
+var sum = 0;
+for (var iter = 0; iter < 1000; iter++) {
+  for (var i = 0; i < points.length; i++) {
+    var point = points[i];
+    point.x = point.y;
+    sum += point.x + point.y;
+  }
+}
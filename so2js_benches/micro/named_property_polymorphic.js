@@ -0,0 +1,25 @@
+// Named property loads on objects with a small number of different shapes.
+var objects = [];
+for (var i = 0; i < 100; i++) {
+  switch (i % 4) {
+    case 0:
+      objects.push({ x: i });
+      break;
+    case 1:
+      objects.push({ a: 0, x: i });
+      break;
+    case 2:
+      objects.push({ a: 0, b: 0, x: i });
+      break;
+    case 3:
+      objects.push({ a: 0, b: 0, c: 0, x: i });
+      break;
+  }
+}
+
+var sum = 0;
+for (var iter = 0; iter < 1000; iter++) {
+  for (var i = 0; i < objects.length; i++) {
+    sum += objects[i].x;
+  }
+}
@@ -0,0 +1,13 @@
+// Named property loads of methods found on the prototype.
+function Counter() {
+  this.count = 0;
+}
+
+Counter.prototype.increment = function () {
+  this.count++;
+};
+
+var counter = new Counter();
+for (var i = 0; i < 100000; i++) {
+  counter.increment();
+}
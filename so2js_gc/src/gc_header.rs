@@ -5,6 +5,9 @@
 
 use core::{alloc::Layout, ptr::NonNull};
 
+#[cfg(feature = "parallel_gc")]
+use core::sync::atomic::{AtomicU8, Ordering};
+
 /// The three colors used in tri-color marking
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -44,6 +47,19 @@ impl Default for GcPhase {
     }
 }
 
+/// Object was allocated in the nursery and has not been promoted to the old generation
+const FLAG_YOUNG: u8 = 1 << 0;
+/// Old object that may contain pointers into the nursery, so is part of the remembered set
+const FLAG_REMEMBERED: u8 = 1 << 1;
+/// Young object that has been copied to the old generation during a minor GC. The new location
+/// of the object is stored in `next_object`.
+const FLAG_FORWARDED: u8 = 1 << 2;
+/// Cell in a page that does not hold an object. The next free cell in the page is stored in
+/// `next_object`.
+const FLAG_FREE: u8 = 1 << 3;
+/// Object in the large object space, which is preceded by a `LargeObjectPrefix`
+const FLAG_LARGE: u8 = 1 << 4;
+
 /// Header prepended to every heap object for GC tracking
 ///
 /// This header is placed immediately before the object data in memory.
@@ -52,11 +68,15 @@ impl Default for GcPhase {
 pub struct GcHeader {
     /// Current color in tri-color marking
     color: GcColor,
+    /// Generational GC state flags
+    flags: u8,
     /// Padding for alignment
-    _padding: [u8; 7],
+    _padding: [u8; 6],
     /// Size of allocation (object size, not including header)
     alloc_size: usize,
-    /// Next object in the all-objects list (for sweeping)
+    /// Next object in the large object list (for sweeping). Objects in pages use this field to link
+    /// free cells, and young objects use it to store their forwarding address once copied during a
+    /// minor GC.
     next_object: Option<NonNull<GcHeader>>,
 }
 
@@ -72,7 +92,8 @@ impl GcHeader {
     pub fn new(alloc_size: usize) -> GcHeader {
         GcHeader {
             color: GcColor::White,
-            _padding: [0; 7],
+            flags: 0,
+            _padding: [0; 6],
             alloc_size,
             next_object: None,
         }
@@ -90,6 +111,167 @@ impl GcHeader {
         self.color = color;
     }
 
+    /// Atomically read the color of the object with the given header, for GC threads that may race
+    /// with other threads on the same header.
+    ///
+    /// # Safety
+    /// The header must be valid, and must only be accessed atomically by other threads.
+    #[cfg(feature = "parallel_gc")]
+    #[inline]
+    pub unsafe fn color_atomic(header: *mut GcHeader) -> GcColor {
+        match Self::atomic_color(header).load(Ordering::Acquire) {
+            0 => GcColor::White,
+            1 => GcColor::Gray,
+            _ => GcColor::Black,
+        }
+    }
+
+    /// Atomically set the color of the object with the given header.
+    ///
+    /// # Safety
+    /// The header must be valid, and must only be accessed atomically by other threads.
+    #[cfg(feature = "parallel_gc")]
+    #[inline]
+    pub unsafe fn set_color_atomic(header: *mut GcHeader, color: GcColor) {
+        Self::atomic_color(header).store(color as u8, Ordering::Release);
+    }
+
+    /// Atomically mark a white object gray. Returns true if this call marked the object, so that
+    /// exactly one of several racing threads claims each object.
+    ///
+    /// # Safety
+    /// The header must be valid, and must only be accessed atomically by other threads.
+    #[cfg(feature = "parallel_gc")]
+    #[inline]
+    pub unsafe fn try_mark_gray_atomic(header: *mut GcHeader) -> bool {
+        Self::atomic_color(header)
+            .compare_exchange(
+                GcColor::White as u8,
+                GcColor::Gray as u8,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+    }
+
+    /// The color field of a header as an atomic. GcColor is repr(u8) so has the same layout.
+    #[cfg(feature = "parallel_gc")]
+    #[inline]
+    unsafe fn atomic_color<'a>(header: *mut GcHeader) -> &'a AtomicU8 {
+        AtomicU8::from_ptr(core::ptr::addr_of_mut!((*header).color) as *mut u8)
+    }
+
+    /// Check if this object is in the nursery
+    #[inline]
+    pub fn is_young(&self) -> bool {
+        self.flags & FLAG_YOUNG != 0
+    }
+
+    /// Mark this object as allocated in the nursery
+    #[inline]
+    pub fn set_young(&mut self) {
+        self.flags |= FLAG_YOUNG;
+    }
+
+    /// Check if this object is in the remembered set
+    #[inline]
+    pub fn is_remembered(&self) -> bool {
+        self.flags & FLAG_REMEMBERED != 0
+    }
+
+    /// Add or remove this object from the remembered set
+    #[inline]
+    pub fn set_remembered(&mut self, is_remembered: bool) {
+        if is_remembered {
+            self.flags |= FLAG_REMEMBERED;
+        } else {
+            self.flags &= !FLAG_REMEMBERED;
+        }
+    }
+
+    /// Check if this object is in the large object space
+    #[inline]
+    pub fn is_large(&self) -> bool {
+        self.flags & FLAG_LARGE != 0
+    }
+
+    /// Mark this object as allocated in the large object space
+    #[inline]
+    pub fn set_large(&mut self) {
+        self.flags |= FLAG_LARGE;
+    }
+
+    /// Read the flags of the object with the given header without creating a reference to the
+    /// header, so that the read does not race with GC threads that atomically update the color.
+    #[inline]
+    unsafe fn read_flags(header: *const GcHeader) -> u8 {
+        core::ptr::addr_of!((*header).flags).read()
+    }
+
+    /// Read the allocation size of the object with the given header without creating a reference
+    /// to the header, so that the read does not race with GC threads that atomically update the
+    /// color.
+    ///
+    /// # Safety
+    /// The header must be valid.
+    #[inline]
+    pub unsafe fn read_alloc_size(header: *const GcHeader) -> usize {
+        core::ptr::addr_of!((*header).alloc_size).read()
+    }
+
+    /// Check if the object with the given header is in the old generation and is not in the
+    /// remembered set. Only the flags of the header are read.
+    ///
+    /// # Safety
+    /// The header must be valid.
+    #[inline]
+    pub unsafe fn is_unremembered_old_object(header: *const GcHeader) -> bool {
+        Self::read_flags(header) & (FLAG_YOUNG | FLAG_REMEMBERED) == 0
+    }
+
+    /// Check if the object with the given header is in the large object space. Only the flags of
+    /// the header are read.
+    ///
+    /// # Safety
+    /// The header must be valid.
+    #[inline]
+    pub unsafe fn is_large_object(header: *const GcHeader) -> bool {
+        Self::read_flags(header) & FLAG_LARGE != 0
+    }
+
+    /// Get the new location of this object's data if it has been copied out of the nursery
+    #[inline]
+    pub fn forwarding_address(&self) -> Option<*mut u8> {
+        if self.flags & FLAG_FORWARDED != 0 {
+            self.next_object
+                .map(|header| unsafe { (*header.as_ptr()).object_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Record that this young object has been copied to the object with the given header
+    #[inline]
+    pub fn set_forwarding_address(&mut self, new_header: NonNull<GcHeader>) {
+        debug_assert!(self.is_young());
+        self.flags |= FLAG_FORWARDED;
+        self.next_object = Some(new_header);
+    }
+
+    /// Check if this is a free cell in a page
+    #[inline]
+    pub fn is_free(&self) -> bool {
+        self.flags & FLAG_FREE != 0
+    }
+
+    /// Turn this header into a free cell, linking it to the next free cell in its page
+    #[inline]
+    pub fn set_free(&mut self, next_free: Option<NonNull<GcHeader>>) {
+        self.color = GcColor::White;
+        self.flags = FLAG_FREE;
+        self.next_object = next_free;
+    }
+
     /// Get the object allocation size (not including header)
     #[inline]
     pub fn alloc_size(&self) -> usize {
@@ -102,13 +284,13 @@ impl GcHeader {
         Self::SIZE + self.alloc_size
     }
 
-    /// Get the next object in the all-objects list
+    /// Get the next object in the large object list
     #[inline]
     pub fn next_object(&self) -> Option<NonNull<GcHeader>> {
         self.next_object
     }
 
-    /// Set the next object in the all-objects list
+    /// Set the next object in the large object list
     #[inline]
     pub fn set_next_object(&mut self, next: Option<NonNull<GcHeader>>) {
         self.next_object = next;
@@ -182,4 +364,49 @@ mod tests {
         assert!(header.is_marked());
         assert!(!header.needs_scanning());
     }
+
+    #[test]
+    fn test_gc_header_generation_flags() {
+        let mut header = GcHeader::new(64);
+        assert!(!header.is_young());
+        assert!(!header.is_remembered());
+
+        // Only old objects that are not yet remembered can be added to the remembered set
+        assert!(unsafe { GcHeader::is_unremembered_old_object(&header) });
+        header.set_remembered(true);
+        assert!(header.is_remembered());
+        assert!(unsafe { !GcHeader::is_unremembered_old_object(&header) });
+        header.set_remembered(false);
+        assert!(!header.is_remembered());
+
+        let mut young_header = GcHeader::new(64);
+        young_header.set_young();
+        assert!(unsafe { !GcHeader::is_unremembered_old_object(&young_header) });
+        assert!(young_header.is_young());
+        assert!(!young_header.is_remembered());
+        assert!(young_header.forwarding_address().is_none());
+
+        young_header.set_forwarding_address(NonNull::from(&mut header));
+        assert_eq!(young_header.forwarding_address(), Some(header.object_ptr()));
+
+        let mut large_header = GcHeader::new(64 * 1024);
+        assert!(!large_header.is_large());
+        large_header.set_large();
+        assert!(large_header.is_large());
+        assert!(unsafe { GcHeader::is_large_object(&large_header) });
+    }
+
+    #[test]
+    fn test_gc_header_free_cell() {
+        let mut header = GcHeader::new(64);
+        header.set_color(GcColor::Black);
+        header.set_remembered(true);
+        assert!(!header.is_free());
+
+        header.set_free(None);
+        assert!(header.is_free());
+        assert!(!header.is_remembered());
+        assert_eq!(header.color(), GcColor::White);
+        assert!(header.next_object().is_none());
+    }
 }
@@ -45,6 +45,20 @@ impl GrayQueue {
         self.queue.pop()
     }
 
+    /// Remove every object from the queue
+    #[inline]
+    #[cfg(feature = "parallel_gc")]
+    pub fn take_all(&mut self) -> Vec<NonNull<GcHeader>> {
+        core::mem::take(&mut self.queue)
+    }
+
+    /// Push every object in the iterator onto the queue
+    #[inline]
+    #[cfg(feature = "parallel_gc")]
+    pub fn extend(&mut self, headers: impl IntoIterator<Item = NonNull<GcHeader>>) {
+        self.queue.extend(headers);
+    }
+
     /// Clear the queue
     #[inline]
     #[allow(dead_code)]
@@ -1,42 +1,91 @@
 //! Heap - GC-managed memory allocator with incremental collection
 //!
 //! Design:
-//! - Uses alloc::alloc for memory allocation
-//! - Maintains a linked list of all allocated objects
+//! - Small objects are allocated in size-class segregated pages, each with its own free list
+//! - Large objects are allocated individually and kept in a linked list
+//! - Sweeping is page-local, and pages that become empty are returned to the system allocator
 //! - Provides incremental tri-color mark-sweep garbage collection
-//! - Allocation-driven GC: each alloc() call advances GC work
+//! - Allocation-driven GC: allocations advance GC work in proportion to their size, with every
+//!   step bounded by a work and time budget (see `GcPacing`)
+//! - Optional generational mode: small objects are bump allocated in a nursery, and survivors of
+//!   a minor GC are copied (promoted) into the mark-sweep old generation
+//! - Optional parallel mode (`parallel_gc` feature): large marking steps are split across threads
+//!   with work stealing, and sweeping runs on a background thread
+//!
+//! Generational mode moves objects, so every pointer to a young object must be visited mutably
+//! during a minor GC. Pointers from old objects to young objects are found through the remembered
+//! set, which contains every old object that has been written to since the last minor GC. Writers
+//! must report these writes with `Heap::write_barrier` or `Heap::remember_written_object`. Writes
+//! are not recorded during a GC, so tracing an object never adds it to the remembered set.
 //!
 //! The runtime provides:
 //! - `GcContext::visit_roots` - enumerate root pointers
 //! - `GcContext::trace_object` - trace pointers within an object
 
-use core::ptr::NonNull;
+use core::{alloc::Layout, ptr::NonNull};
+
+use alloc::{collections::VecDeque, vec::Vec};
 
 use crate::{
     gc_header::{GcColor, GcHeader, GcPhase},
     gray_queue::GrayQueue,
+    nursery::{Nursery, MAX_NURSERY_OBJECT_SIZE},
+    pacing::{GcPacing, TIME_CHECK_INTERVAL},
+    page::{size_class_index, Page, NUM_SIZE_CLASSES},
+    remembered_set::{LargeObjectPrefix, RememberedSet},
+    stats::{GcCycleKind, GcCycleStats, GC_HISTORY_LENGTH},
     visitor::{GcContext, GcVisitor},
     GcPtr,
 };
 
-/// Default number of objects to process per GC step
-const DEFAULT_MARK_STEP_SIZE: usize = 100;
-const DEFAULT_SWEEP_STEP_SIZE: usize = 100;
+#[cfg(feature = "parallel_gc")]
+use crate::parallel::{parallel_mark, BackgroundSweep, ParallelGcOptions, PARALLEL_MARK_MIN_WORK};
 
 /// Default GC threshold (1MB)
 const DEFAULT_GC_THRESHOLD: usize = 1024 * 1024;
 
+/// Pages for a single size class
+struct SizeClassSpace {
+    /// All pages in this size class
+    pages: Vec<NonNull<Page>>,
+    /// Pages that may have free cells. Pages that are waiting to be swept are never in this list,
+    /// so objects allocated during sweeping are never swept in the same cycle.
+    available_pages: Vec<NonNull<Page>>,
+}
+
+const EMPTY_SIZE_CLASS_SPACE: SizeClassSpace = SizeClassSpace {
+    pages: Vec::new(),
+    available_pages: Vec::new(),
+};
+
 /// The managed heap with incremental GC
 pub struct Heap {
-    /// Head of the all-objects linked list
-    all_objects: Option<NonNull<GcHeader>>,
+    /// Pages for small objects in the old generation, indexed by size class
+    size_classes: [SizeClassSpace; NUM_SIZE_CLASSES],
+
+    /// Head of the large object linked list. Only contains objects in the old generation.
+    large_objects: Option<NonNull<GcHeader>>,
 
-    /// Number of bytes currently allocated
+    /// Number of bytes currently allocated in the old generation
     pub bytes_allocated: usize,
 
-    /// Number of objects currently allocated
+    /// Number of objects currently allocated in the old generation
     pub num_objects: usize,
 
+    /// The young generation. None if the heap is not generational.
+    nursery: Option<Nursery>,
+
+    /// Old objects that may contain pointers into the nursery. Only exists if the heap is
+    /// generational. Shared with every page and large object so that writers without access to the
+    /// heap can add objects to it.
+    remembered_set: Option<NonNull<RememberedSet>>,
+
+    /// Number of minor GCs that have been performed
+    pub num_minor_gcs: usize,
+
+    /// Number of bytes promoted to the old generation by the last minor GC
+    pub bytes_promoted_last_minor_gc: usize,
+
     /// Threshold to trigger GC (in bytes)
     gc_threshold: usize,
 
@@ -46,14 +95,52 @@ pub struct Heap {
     /// Current GC phase
     pub phase: GcPhase,
 
-    /// For incremental sweeping: current position in the all-objects list
+    /// For incremental sweeping: pages that have not been fully swept yet, and the index of the
+    /// next cell to sweep in the last page
+    sweep_pages: Vec<NonNull<Page>>,
+    sweep_cell_index: usize,
+
+    /// For incremental sweeping: current position in the large object list
     sweep_prev: Option<NonNull<GcHeader>>,
     sweep_current: Option<NonNull<GcHeader>>,
 
-    /// Stats for current GC cycle
+    /// Stats for the current GC cycle, or the last completed cycle if no GC is in progress
     pub bytes_freed_this_cycle: usize,
     pub objects_freed_this_cycle: usize,
 
+    /// Time spent in GC work for the current major GC cycle, in milliseconds
+    pause_millis_this_cycle: f64,
+
+    /// Longest single pause in the current major GC cycle, in milliseconds
+    max_pause_millis_this_cycle: f64,
+
+    /// Longest single pause of any GC cycle, major or minor, in milliseconds
+    pub max_pause_millis: f64,
+
+    /// Policy for scheduling incremental GC work
+    pacing: GcPacing,
+
+    /// Threads used for marking and sweeping
+    #[cfg(feature = "parallel_gc")]
+    parallel_options: ParallelGcOptions,
+
+    /// The sweep running on a background thread, if any. Its pages and large objects are not
+    /// owned by the heap until it is joined.
+    #[cfg(feature = "parallel_gc")]
+    background_sweep: Option<BackgroundSweep>,
+
+    /// Units of GC work earned by each byte allocated during the current major GC cycle
+    work_per_allocated_byte: f64,
+
+    /// Units of GC work earned by allocations that have not been performed yet
+    allocation_work_credit: f64,
+
+    /// Number of major GCs that have been completed
+    pub num_major_gcs: usize,
+
+    /// Stats for the most recently completed GC cycles, oldest first
+    gc_history: VecDeque<GcCycleStats>,
+
     #[cfg(feature = "gc_stress_test")]
     pub gc_stress_test: bool,
 }
@@ -69,22 +156,59 @@ impl Heap {
     /// Create a new heap
     pub const fn new() -> Heap {
         Heap {
-            all_objects: None,
+            size_classes: [EMPTY_SIZE_CLASS_SPACE; NUM_SIZE_CLASSES],
+            large_objects: None,
             bytes_allocated: 0,
             num_objects: 0,
+            nursery: None,
+            remembered_set: None,
+            num_minor_gcs: 0,
+            bytes_promoted_last_minor_gc: 0,
             gc_threshold: DEFAULT_GC_THRESHOLD,
             gray_queue: GrayQueue::new(),
             phase: GcPhase::Idle,
+            sweep_pages: Vec::new(),
+            sweep_cell_index: 0,
             sweep_prev: None,
             sweep_current: None,
             bytes_freed_this_cycle: 0,
             objects_freed_this_cycle: 0,
+            pause_millis_this_cycle: 0.0,
+            max_pause_millis_this_cycle: 0.0,
+            max_pause_millis: 0.0,
+            pacing: GcPacing::DEFAULT,
+            #[cfg(feature = "parallel_gc")]
+            parallel_options: ParallelGcOptions::DISABLED,
+            #[cfg(feature = "parallel_gc")]
+            background_sweep: None,
+            work_per_allocated_byte: 0.0,
+            allocation_work_credit: 0.0,
+            num_major_gcs: 0,
+            gc_history: VecDeque::new(),
 
             #[cfg(feature = "gc_stress_test")]
             gc_stress_test: false,
         }
     }
 
+    /// Create a new generational heap with a nursery of the given size
+    ///
+    /// Falls back to a non-generational heap if the nursery could not be allocated.
+    pub fn new_generational(nursery_size: usize) -> Heap {
+        let mut heap = Heap::new();
+        heap.nursery = Nursery::new(nursery_size);
+        if heap.nursery.is_some() {
+            heap.remembered_set = Some(RememberedSet::new());
+        }
+        heap
+    }
+
+    /// Check if this heap has a young generation
+    #[inline]
+    pub fn is_generational(&self) -> bool {
+        self.nursery.is_some()
+    }
+
     /// Get current GC phase
     #[inline]
     pub fn phase(&self) -> GcPhase {
@@ -103,6 +227,32 @@ impl Heap {
         matches!(self.phase, GcPhase::RootScanning | GcPhase::Marking)
     }
 
+    /// Get the policy for scheduling incremental GC work
+    #[inline]
+    pub fn pacing(&self) -> &GcPacing {
+        &self.pacing
+    }
+
+    /// Set the policy for scheduling incremental GC work. Allocation-driven pacing for a cycle
+    /// that is already in progress is only updated when the next cycle starts.
+    pub fn set_pacing(&mut self, pacing: GcPacing) {
+        self.pacing = pacing;
+    }
+
+    /// Get the options for parallel marking and background sweeping
+    #[cfg(feature = "parallel_gc")]
+    #[inline]
+    pub fn parallel_options(&self) -> &ParallelGcOptions {
+        &self.parallel_options
+    }
+
+    /// Set the options for parallel marking and background sweeping. A sweep that is already
+    /// running in the background is not affected.
+    #[cfg(feature = "parallel_gc")]
+    pub fn set_parallel_options(&mut self, options: ParallelGcOptions) {
+        self.parallel_options = options;
+    }
+
     /// Allocate memory for an object of type T
     ///
     /// Returns a pointer to uninitialized memory.
@@ -132,11 +282,6 @@ impl Heap {
         size: usize,
         align: usize,
     ) -> AllocResult<GcPtr<T>> {
-        // Advance incremental GC if in progress
-        if self.gc_in_progress() {
-            self.gc_step(ctx);
-        }
-
         // Require payload alignment not to exceed header alignment to avoid UB.
         if align > GcHeader::ALIGN {
             return Err(AllocError);
@@ -144,40 +289,140 @@ impl Heap {
 
         let layout = GcHeader::layout_for_size(size);
 
-        unsafe {
-            // Allocate memory
-            let ptr = alloc::alloc::alloc(layout);
-            if ptr.is_null() {
-                return Err(AllocError);
-            }
+        // Advance incremental GC in proportion to the size of the allocation
+        if self.gc_in_progress() {
+            self.pay_allocation_work(ctx, layout.size());
+        }
 
-            // Initialize GcHeader
-            let header = ptr as *mut GcHeader;
-            header.write(GcHeader::new(size));
-            let header_nn = NonNull::new_unchecked(header);
-
-            // During GC, new objects are BLACK (won't be collected this cycle)
-            // This applies to any GC phase, not just marking, because:
-            // - In marking phase: prevents the object from being missed
-            // - In sweeping phase: prevents the object from being immediately swept
-            if self.gc_in_progress() {
-                (*header).set_color(GcColor::Black);
+        // Collect the nursery before every allocation in stress test mode, so that every object is
+        // moved as soon as possible.
+        #[cfg(feature = "gc_stress_test")]
+        if self.gc_stress_test {
+            self.minor_gc(ctx);
+        }
+
+        // Small objects are allocated in the nursery when one exists. The nursery is not used
+        // during a major GC, so that major GCs never need to consider young objects.
+        if size <= MAX_NURSERY_OBJECT_SIZE && !self.gc_in_progress() {
+            if let Some(ptr) = self.alloc_in_nursery(ctx, layout) {
+                unsafe {
+                    let header = ptr.as_ptr() as *mut GcHeader;
+                    header.write(GcHeader::new(size));
+                    (*header).set_young();
+
+                    let object_ptr = ptr.as_ptr().add(GcHeader::SIZE) as *mut T;
+                    return Ok(GcPtr::from_ptr(object_ptr));
+                }
             }
+        }
 
-            // Link into all-objects list
-            (*header).set_next_object(self.all_objects);
-            self.all_objects = Some(header_nn);
+        unsafe {
+            let header = match self.alloc_in_old_generation(layout, size) {
+                Some(header) => header,
+                None => return Err(AllocError),
+            };
 
-            // Update stats
-            self.bytes_allocated += (*header).total_size();
-            self.num_objects += 1;
+            // Objects allocated directly in the old generation may be initialized with pointers to
+            // young objects without a write barrier, so they start out in the remembered set.
+            RememberedSet::remember(header.as_ptr());
 
             // Return pointer to object data (after header)
-            let object_ptr = ptr.add(GcHeader::SIZE) as *mut T;
+            let object_ptr = (*header.as_ptr()).object_ptr() as *mut T;
             Ok(GcPtr::from_ptr(object_ptr))
         }
     }
 
+    /// Bump allocate space in the nursery, performing a minor GC if the nursery is full. Returns
+    /// None if the heap has no nursery or the allocation does not fit in the nursery.
+    #[inline]
+    fn alloc_in_nursery(
+        &mut self,
+        ctx: &mut impl GcContext,
+        layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        let nursery = self.nursery.as_mut()?;
+        if let Some(ptr) = nursery.try_alloc(layout) {
+            return Some(ptr);
+        }
+
+        if layout.size() > nursery.capacity() {
+            return None;
+        }
+
+        self.minor_gc(ctx);
+
+        self.nursery.as_mut()?.try_alloc(layout)
+    }
+
+    /// Allocate an object in the old generation, either in a page of the matching size class or in
+    /// the large object space. Returns the header of the new object, or None if the allocation
+    /// failed.
+    ///
+    /// # Safety
+    /// `layout` must be the layout for an object of the given size.
+    unsafe fn alloc_in_old_generation(
+        &mut self,
+        layout: Layout,
+        size: usize,
+    ) -> Option<NonNull<GcHeader>> {
+        let size_class_index = size_class_index(layout.size());
+        let header_nn = match size_class_index {
+            Some(size_class_index) => self.alloc_in_page(size_class_index)?,
+            None => LargeObjectPrefix::alloc(layout, self.remembered_set)?,
+        };
+
+        // Initialize GcHeader
+        let header = header_nn.as_ptr();
+        header.write(GcHeader::new(size));
+
+        // While marking, new objects are BLACK so that they are not missed by the current cycle.
+        // Objects allocated while sweeping stay WHITE, since they are only placed in pages that
+        // have already been swept or at the head of the large object list, before the sweep
+        // cursor.
+        if self.gc_in_progress() && self.phase != GcPhase::Sweeping {
+            (*header).set_color(GcColor::Black);
+        }
+
+        // Link large objects into the large object list
+        if size_class_index.is_none() {
+            (*header).set_large();
+            (*header).set_next_object(self.large_objects);
+            self.large_objects = Some(header_nn);
+
+            if self.sweep_current.is_some() && self.sweep_prev.is_none() {
+                self.sweep_prev = Some(header_nn);
+            }
+        }
+
+        // Update stats
+        self.bytes_allocated += (*header).total_size();
+        self.num_objects += 1;
+
+        Some(header_nn)
+    }
+
+    /// Take a free cell from a page in the given size class, allocating a new page if every page is
+    /// full. Returns None if a new page could not be allocated.
+    fn alloc_in_page(&mut self, size_class_index: usize) -> Option<NonNull<GcHeader>> {
+        let space = &mut self.size_classes[size_class_index];
+
+        while let Some(&page) = space.available_pages.last() {
+            if let Some(cell) = unsafe { (*page.as_ptr()).alloc_cell() } {
+                return Some(cell);
+            }
+
+            // Page is full so it is no longer available
+            space.available_pages.pop();
+        }
+
+        let page = Page::new(size_class_index, self.remembered_set)?;
+        unsafe { (*page.as_ptr()).set_index_in_space(space.pages.len()) };
+        space.pages.push(page);
+        space.available_pages.push(page);
+
+        unsafe { (*page.as_ptr()).alloc_cell() }
+    }
+
     /// Check if GC should be triggered
     #[inline]
     pub fn should_gc(&self) -> bool {
@@ -196,6 +441,209 @@ impl Heap {
         self.num_objects
     }
 
+    /// Get the stats for the most recently completed GC cycles, oldest first. At most
+    /// `GC_HISTORY_LENGTH` cycles are kept.
+    #[inline]
+    pub fn gc_history(&self) -> &VecDeque<GcCycleStats> {
+        &self.gc_history
+    }
+
+    /// Record a single GC pause, in milliseconds, for the current major GC cycle
+    fn record_major_gc_pause(&mut self, pause_millis: f64) {
+        self.pause_millis_this_cycle += pause_millis;
+        self.max_pause_millis_this_cycle = self.max_pause_millis_this_cycle.max(pause_millis);
+        self.max_pause_millis = self.max_pause_millis.max(pause_millis);
+    }
+
+    /// Record the stats for a completed GC cycle, evicting the oldest cycle if the history is full
+    fn record_gc_cycle(&mut self, stats: GcCycleStats) {
+        if self.gc_history.len() == GC_HISTORY_LENGTH {
+            self.gc_history.pop_front();
+        }
+
+        self.gc_history.push_back(stats);
+    }
+
+    /// Call a function on the header of every allocated object in the heap, including young
+    /// objects. Objects that are unreachable but have not been freed yet are included.
+    ///
+    /// Waits for a background sweep to finish first. Must not be called during a minor GC.
+    pub fn for_each_object(&mut self, mut f: impl FnMut(&GcHeader)) {
+        #[cfg(feature = "parallel_gc")]
+        self.join_background_sweep();
+
+        for space in &self.size_classes {
+            for page in &space.pages {
+                let page = unsafe { &*page.as_ptr() };
+                for cell_index in 0..page.num_used_cells() {
+                    let header = unsafe { &*page.cell(cell_index).as_ptr() };
+                    if !header.is_free() {
+                        f(header);
+                    }
+                }
+            }
+        }
+
+        let mut current = self.large_objects;
+        while let Some(header_ptr) = current {
+            let header = unsafe { &*header_ptr.as_ptr() };
+            f(header);
+            current = header.next_object();
+        }
+
+        if let Some(nursery) = &self.nursery {
+            nursery.for_each_object(f);
+        }
+    }
+
+    /// Get number of pages currently owned by the heap
+    #[inline]
+    pub fn num_pages(&self) -> usize {
+        self.size_classes
+            .iter()
+            .map(|space| space.pages.len())
+            .sum()
+    }
+
+    /// Get bytes currently allocated in the nursery, including objects that may be dead
+    #[inline]
+    pub fn nursery_bytes_allocated(&self) -> usize {
+        self.nursery
+            .as_ref()
+            .map_or(0, |nursery| nursery.bytes_allocated())
+    }
+
+    /// Get number of old objects in the remembered set
+    #[inline]
+    pub fn num_remembered_objects(&self) -> usize {
+        self.remembered_set.map_or(0, |remembered_set| unsafe {
+            (*remembered_set.as_ptr()).len()
+        })
+    }
+
+    /// Stop recording writes in the remembered set until `resume_write_barrier` is called.
+    ///
+    /// For code that visits objects through mutable references without writing to them, such as
+    /// heap inspectors, so that visiting does not add every object to the remembered set. The
+    /// mutator must not write to the heap while the write barrier is paused.
+    pub fn pause_write_barrier(&mut self) {
+        if let Some(remembered_set) = self.remembered_set {
+            unsafe { (*remembered_set.as_ptr()).pause() };
+        }
+    }
+
+    /// Undo a previous call to `pause_write_barrier`
+    pub fn resume_write_barrier(&mut self) {
+        if let Some(remembered_set) = self.remembered_set {
+            unsafe { (*remembered_set.as_ptr()).resume() };
+        }
+    }
+
+    /// Check if an object is in the nursery
+    #[inline]
+    pub fn is_young<T>(&self, ptr: GcPtr<T>) -> bool {
+        self.nursery
+            .as_ref()
+            .is_some_and(|nursery| nursery.contains(ptr.as_ptr() as *const u8))
+    }
+
+    // ========================================================================
+    // Minor GC API
+    // ========================================================================
+
+    /// Perform a minor GC, copying all live objects out of the nursery into the old generation.
+    ///
+    /// Live young objects are those reachable from the roots or from the remembered set. Every
+    /// pointer to a young object is rewritten to point to the object's new location. After a minor
+    /// GC the nursery is empty and the remembered set is cleared.
+    ///
+    /// Weak pointers to young objects are treated as strong during a minor GC, so young objects
+    /// that are only weakly reachable survive until the next major GC.
+    ///
+    /// Does nothing if the heap has no nursery or if a major GC is in progress, since the nursery is
+    /// always empty during a major GC.
+    pub fn minor_gc(&mut self, ctx: &mut impl GcContext) {
+        if self.gc_in_progress() {
+            return;
+        }
+
+        // Take the nursery so that it can be borrowed alongside the rest of the heap
+        let nursery = match self.nursery.take() {
+            Some(nursery) if !nursery.is_empty() => nursery,
+            nursery => {
+                self.nursery = nursery;
+                return;
+            }
+        };
+
+        let start_time = ctx.current_time_millis();
+        let bytes_allocated_before = self.bytes_allocated;
+
+        // Objects written while they are traced are not added to the remembered set, since the
+        // nursery is empty once the minor GC completes.
+        let remembered_set = match self.remembered_set {
+            Some(remembered_set) => unsafe { &mut *remembered_set.as_ptr() },
+            None => unreachable!("generational heaps have a remembered set"),
+        };
+        remembered_set.pause();
+        let remembered_objects = remembered_set.take();
+
+        {
+            let mut evacuator = Evacuator {
+                heap: self,
+                nursery: &nursery,
+                promoted: Vec::new(),
+            };
+
+            // Promote all young objects directly reachable from the roots
+            ctx.visit_roots(&mut evacuator);
+
+            // Promote all young objects reachable from the remembered set
+            for header_ptr in remembered_objects {
+                unsafe {
+                    let header = &mut *header_ptr.as_ptr();
+                    ctx.trace_object(header.object_ptr(), &mut evacuator);
+                    header.set_remembered(false);
+                }
+            }
+
+            // Transitively promote all young objects reachable from promoted objects
+            while let Some(header_ptr) = evacuator.promoted.pop() {
+                unsafe {
+                    let header = &*header_ptr.as_ptr();
+                    ctx.trace_object(header.object_ptr(), &mut evacuator);
+                }
+            }
+        }
+
+        remembered_set.resume();
+
+        let bytes_promoted = self.bytes_allocated - bytes_allocated_before;
+        let bytes_freed = nursery.bytes_allocated().saturating_sub(bytes_promoted);
+
+        let mut nursery = nursery;
+        nursery.reset();
+        self.nursery = Some(nursery);
+
+        self.num_minor_gcs += 1;
+        self.bytes_promoted_last_minor_gc = bytes_promoted;
+
+        let pause_millis = ctx.current_time_millis() - start_time;
+        self.max_pause_millis = self.max_pause_millis.max(pause_millis);
+
+        self.record_gc_cycle(GcCycleStats {
+            kind: GcCycleKind::Minor,
+            pause_millis,
+            max_pause_millis: pause_millis,
+            bytes_freed,
+            objects_freed: 0,
+            bytes_promoted,
+        });
+
+        #[cfg(feature = "heap_verify")]
+        self.verify_and_report(ctx);
+    }
+
     // ========================================================================
     // Incremental GC API
     // ========================================================================
@@ -220,6 +668,15 @@ impl Heap {
             return;
         }
 
+        // Empty the nursery so that the major GC only needs to consider the old generation. The
+        // nursery stays empty until the cycle completes, so writes do not need to be recorded.
+        self.minor_gc(ctx);
+        if let Some(remembered_set) = self.remembered_set {
+            unsafe { (*remembered_set.as_ptr()).pause() };
+        }
+
+        let start_time = ctx.current_time_millis();
+
         self.phase = GcPhase::RootScanning;
         self.bytes_freed_this_cycle = 0;
         self.objects_freed_this_cycle = 0;
@@ -234,11 +691,25 @@ impl Heap {
 
         // Transition to marking phase
         self.phase = GcPhase::Marking;
+
+        // Every object is marked at most once and swept once. Pace allocation-driven steps so that
+        // this work is done before the heap grows past the allocation headroom.
+        let cycle_work = 2 * self.num_objects;
+        self.work_per_allocated_byte = self
+            .pacing
+            .work_per_allocated_byte(cycle_work, self.bytes_allocated);
+        self.allocation_work_credit = 0.0;
+
+        self.pause_millis_this_cycle = 0.0;
+        self.max_pause_millis_this_cycle = 0.0;
+        self.record_major_gc_pause(ctx.current_time_millis() - start_time);
     }
 
     /// Advance incremental GC by one step
     ///
-    /// Returns true if GC is still in progress, false if complete.
+    /// The step processes at most `GcPacing::step_work_limit` objects and stops early once
+    /// `GcPacing::step_time_limit_millis` has elapsed. Returns true if GC is still in progress,
+    /// false if complete.
     ///
     /// # Arguments
     /// * `ctx` - The runtime context (required during Marking and WeakRefProcessing phases)
@@ -251,6 +722,94 @@ impl Heap {
     /// }
     /// ```
     pub fn gc_step(&mut self, ctx: &mut impl GcContext) -> bool {
+        let time_limit = self.pacing.step_time_limit_millis;
+        self.run_gc_step(ctx, self.pacing.step_work_limit, time_limit, None)
+    }
+
+    /// Perform incremental GC steps until the given deadline, e.g. while the host is idle.
+    ///
+    /// The deadline is in the same time base as `GcContext::current_time_millis`. Each step is
+    /// still bounded by the pacing policy, and the deadline is only checked every
+    /// `TIME_CHECK_INTERVAL` objects. Returns true if GC is still in progress, false if complete.
+    pub fn gc_step_until(&mut self, ctx: &mut impl GcContext, deadline_millis: f64) -> bool {
+        while self.gc_in_progress() && ctx.current_time_millis() < deadline_millis {
+            let time_limit = self.pacing.step_time_limit_millis;
+            self.run_gc_step(
+                ctx,
+                self.pacing.step_work_limit,
+                time_limit,
+                Some(deadline_millis),
+            );
+        }
+
+        self.gc_in_progress()
+    }
+
+    /// Earn work credit for an allocation of the given total size made during a GC cycle, and
+    /// perform a step once at least one object's worth of work is owed.
+    fn pay_allocation_work(&mut self, ctx: &mut impl GcContext, alloc_size: usize) {
+        self.allocation_work_credit += alloc_size as f64 * self.work_per_allocated_byte;
+        if self.allocation_work_credit < 1.0 {
+            return;
+        }
+
+        let work_limit = (self.allocation_work_credit as usize).min(self.pacing.step_work_limit);
+        self.allocation_work_credit -= work_limit as f64;
+
+        let time_limit = self.pacing.step_time_limit_millis;
+        self.run_gc_step(ctx, work_limit, time_limit, None);
+    }
+
+    /// Perform a single timed GC step that processes at most `work_limit` objects, stopping early at
+    /// the deadline or once `time_limit_millis` has elapsed. Records the cycle's stats if the cycle
+    /// completes. Returns true if GC is still in progress.
+    fn run_gc_step(
+        &mut self,
+        ctx: &mut impl GcContext,
+        work_limit: usize,
+        time_limit_millis: Option<f64>,
+        deadline_millis: Option<f64>,
+    ) -> bool {
+        if !self.gc_in_progress() {
+            return false;
+        }
+
+        let start_time = ctx.current_time_millis();
+        let step_deadline = time_limit_millis.map(|limit| start_time + limit);
+        let deadline = match (deadline_millis, step_deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        let in_progress = self.advance_gc(ctx, work_limit.max(1), deadline);
+        self.record_major_gc_pause(ctx.current_time_millis() - start_time);
+
+        if !in_progress {
+            #[cfg(feature = "heap_verify")]
+            self.verify_and_report(ctx);
+
+            self.num_major_gcs += 1;
+            self.record_gc_cycle(GcCycleStats {
+                kind: GcCycleKind::Major,
+                pause_millis: self.pause_millis_this_cycle,
+                max_pause_millis: self.max_pause_millis_this_cycle,
+                bytes_freed: self.bytes_freed_this_cycle,
+                objects_freed: self.objects_freed_this_cycle,
+                bytes_promoted: 0,
+            });
+        }
+
+        in_progress
+    }
+
+    /// Perform the work for a single GC step in the current phase. Returns true if GC is still in
+    /// progress.
+    fn advance_gc(
+        &mut self,
+        ctx: &mut impl GcContext,
+        work_limit: usize,
+        deadline: Option<f64>,
+    ) -> bool {
         match self.phase {
             GcPhase::Idle => false,
             GcPhase::RootScanning => {
@@ -260,20 +819,34 @@ impl Heap {
                 true
             }
             GcPhase::Marking => {
-                self.mark_step(ctx, DEFAULT_MARK_STEP_SIZE);
+                self.mark_step(ctx, work_limit, deadline);
+
+                #[cfg(feature = "heap_verify")]
+                if self.phase == GcPhase::WeakRefProcessing {
+                    self.verify_and_report(ctx);
+                }
+
                 self.phase != GcPhase::Idle
             }
             GcPhase::WeakRefProcessing => {
                 // Process weak refs in one step (usually fast)
                 ctx.process_weak_refs(self);
-                // Start sweeping
-                self.phase = GcPhase::Sweeping;
-                self.sweep_prev = None;
-                self.sweep_current = self.all_objects;
+                self.start_sweep();
                 true
             }
             GcPhase::Sweeping => {
-                self.sweep_step(DEFAULT_SWEEP_STEP_SIZE);
+                // Poll the background sweeper without blocking. Once it has finished, the sweep
+                // completes on the mutator thread.
+                #[cfg(feature = "parallel_gc")]
+                if let Some(sweep) = &self.background_sweep {
+                    if !sweep.is_finished() {
+                        return true;
+                    }
+
+                    self.join_background_sweep();
+                }
+
+                self.sweep_step(ctx, work_limit, deadline);
                 self.phase != GcPhase::Idle
             }
         }
@@ -281,8 +854,29 @@ impl Heap {
 
     /// Perform incremental marking
     ///
-    /// Processes up to `work_limit` gray objects.
-    fn mark_step(&mut self, ctx: &mut impl GcContext, work_limit: usize) {
+    /// Processes up to `work_limit` gray objects, stopping early if the deadline has passed.
+    fn mark_step(&mut self, ctx: &mut impl GcContext, work_limit: usize, deadline: Option<f64>) {
+        // Large steps are split across threads. Only the mutator thread can read the clock, so
+        // steps with a deadline are always marked on the mutator thread.
+        #[cfg(feature = "parallel_gc")]
+        if self.parallel_options.marking_threads > 1
+            && work_limit >= PARALLEL_MARK_MIN_WORK
+            && deadline.is_none()
+        {
+            parallel_mark(
+                &*ctx,
+                &mut self.gray_queue,
+                self.parallel_options.marking_threads,
+                work_limit,
+            );
+
+            if self.gray_queue.is_empty() {
+                self.phase = GcPhase::WeakRefProcessing;
+            }
+
+            return;
+        }
+
         let mut work_done = 0;
 
         while work_done < work_limit {
@@ -302,6 +896,10 @@ impl Heap {
                         ctx.trace_object(object_ptr, &mut marker);
                     }
                     work_done += 1;
+
+                    if Self::is_past_deadline(ctx, work_done, deadline) {
+                        return;
+                    }
                 }
                 None => {
                     // No more gray objects - marking complete
@@ -312,65 +910,198 @@ impl Heap {
         }
     }
 
+    /// Start sweeping every page and the large object list
+    fn start_sweep(&mut self) {
+        self.phase = GcPhase::Sweeping;
+
+        // Pages are made available for allocation again once they have been swept
+        self.sweep_pages.clear();
+        for space in &mut self.size_classes {
+            space.available_pages.clear();
+            self.sweep_pages.extend_from_slice(&space.pages);
+        }
+        self.sweep_cell_index = 0;
+
+        self.sweep_prev = None;
+        self.sweep_current = self.large_objects;
+
+        #[cfg(feature = "parallel_gc")]
+        if self.parallel_options.background_sweeping {
+            // Hand every page and the large object list to the sweeper. If the sweeper thread
+            // cannot be started, sweep incrementally instead.
+            let sweep =
+                unsafe { BackgroundSweep::start(self.sweep_pages.clone(), self.large_objects) };
+            if let Some(sweep) = sweep {
+                self.background_sweep = Some(sweep);
+                self.sweep_pages.clear();
+                self.sweep_current = None;
+                self.large_objects = None;
+            }
+        }
+    }
+
+    /// Wait for the background sweeper to finish if one is running, then return its pages and
+    /// surviving large objects to the heap. Sweeping completes on the next GC step.
+    #[cfg(feature = "parallel_gc")]
+    fn join_background_sweep(&mut self) {
+        let result = match self.background_sweep.take() {
+            Some(sweep) => sweep.join(),
+            None => return,
+        };
+
+        for page in result.pages {
+            unsafe { self.finish_page_sweep(page) };
+        }
+
+        // Large objects allocated during the sweep stay at the head of the list
+        if let Some((head, tail)) = result.large_objects {
+            unsafe { (*tail.as_ptr()).set_next_object(self.large_objects) };
+            self.large_objects = Some(head);
+        }
+
+        self.bytes_allocated -= result.bytes_freed;
+        self.num_objects -= result.objects_freed;
+        self.bytes_freed_this_cycle += result.bytes_freed;
+        self.objects_freed_this_cycle += result.objects_freed;
+    }
+
     /// Perform incremental sweeping
     ///
-    /// Processes up to `work_limit` objects.
-    fn sweep_step(&mut self, work_limit: usize) {
+    /// Processes up to `work_limit` objects, stopping early if the deadline has passed. Free cells
+    /// are skipped and do not count as work.
+    fn sweep_step(&mut self, ctx: &mut impl GcContext, work_limit: usize, deadline: Option<f64>) {
         let mut work_done = 0;
 
         while work_done < work_limit {
-            match self.sweep_current {
-                Some(header_ptr) => {
-                    unsafe {
-                        let header = &mut *header_ptr.as_ptr();
-                        let next = header.next_object();
-
-                        if header.color() == GcColor::White {
-                            // Dead object - unlink and free
-                            match self.sweep_prev {
-                                Some(p) => (*p.as_ptr()).set_next_object(next),
-                                None => self.all_objects = next,
-                            }
-
-                            let layout = GcHeader::layout_for_size(header.alloc_size());
-                            self.bytes_freed_this_cycle += header.total_size();
-                            self.objects_freed_this_cycle += 1;
-
-                            alloc::alloc::dealloc(header_ptr.as_ptr() as *mut u8, layout);
-                            // Don't update sweep_prev
-                        } else {
-                            // Live object - reset to white for next cycle
-                            header.set_color(GcColor::White);
-                            self.sweep_prev = Some(header_ptr);
+            if let Some(&page_ptr) = self.sweep_pages.last() {
+                unsafe {
+                    let page = &mut *page_ptr.as_ptr();
+                    if self.sweep_cell_index >= page.num_used_cells() {
+                        self.sweep_pages.pop();
+                        self.sweep_cell_index = 0;
+                        self.finish_page_sweep(page_ptr);
+                        continue;
+                    }
+
+                    let cell = page.cell(self.sweep_cell_index);
+                    self.sweep_cell_index += 1;
+
+                    let header = &mut *cell.as_ptr();
+                    if header.is_free() {
+                        continue;
+                    }
+
+                    if header.color() == GcColor::White {
+                        // Dead object - return cell to the page's free list
+                        self.record_freed_object(header.total_size());
+                        page.free_cell(cell);
+                    } else {
+                        // Live object - reset to white for next cycle
+                        header.set_color(GcColor::White);
+                    }
+                }
+                work_done += 1;
+
+                if Self::is_past_deadline(ctx, work_done, deadline) {
+                    return;
+                }
+            } else if let Some(header_ptr) = self.sweep_current {
+                unsafe {
+                    let header = &mut *header_ptr.as_ptr();
+                    let next = header.next_object();
+
+                    if header.color() == GcColor::White {
+                        // Dead object - unlink and free
+                        match self.sweep_prev {
+                            Some(p) => (*p.as_ptr()).set_next_object(next),
+                            None => self.large_objects = next,
                         }
 
-                        self.sweep_current = next;
+                        self.record_freed_object(header.total_size());
+
+                        LargeObjectPrefix::free(header_ptr);
+                        // Don't update sweep_prev
+                    } else {
+                        // Live object - reset to white for next cycle
+                        header.set_color(GcColor::White);
+                        self.sweep_prev = Some(header_ptr);
                     }
-                    work_done += 1;
+
+                    self.sweep_current = next;
                 }
-                None => {
-                    // Sweeping complete
-                    self.finish_sweep();
+                work_done += 1;
+
+                if Self::is_past_deadline(ctx, work_done, deadline) {
                     return;
                 }
+            } else {
+                // Sweeping complete
+                self.finish_sweep();
+                return;
+            }
+        }
+    }
+
+    /// Whether a step with the given deadline should stop after processing `work_done` objects. The
+    /// clock is only read every `TIME_CHECK_INTERVAL` objects.
+    #[inline]
+    fn is_past_deadline(ctx: &mut impl GcContext, work_done: usize, deadline: Option<f64>) -> bool {
+        match deadline {
+            Some(deadline) if work_done.is_multiple_of(TIME_CHECK_INTERVAL) => {
+                ctx.current_time_millis() >= deadline
+            }
+            _ => false,
+        }
+    }
+
+    /// Release a page once it has been swept if it no longer holds any objects, otherwise make its
+    /// free cells available for allocation.
+    ///
+    /// # Safety
+    /// The page must be owned by this heap.
+    unsafe fn finish_page_sweep(&mut self, page_ptr: NonNull<Page>) {
+        let page = &*page_ptr.as_ptr();
+        let space = &mut self.size_classes[page.size_class_index()];
+
+        if page.is_empty() {
+            let index = page.index_in_space();
+            debug_assert_eq!(space.pages[index], page_ptr);
+
+            // The last page takes the place of the removed page
+            space.pages.swap_remove(index);
+            if let Some(&moved_page) = space.pages.get(index) {
+                (*moved_page.as_ptr()).set_index_in_space(index);
             }
+
+            Page::release(page_ptr);
+        } else if !page.is_full() {
+            space.available_pages.push(page_ptr);
         }
     }
 
+    /// Update stats for an object freed during sweeping
+    #[inline]
+    fn record_freed_object(&mut self, total_size: usize) {
+        self.bytes_allocated -= total_size;
+        self.num_objects -= 1;
+        self.bytes_freed_this_cycle += total_size;
+        self.objects_freed_this_cycle += 1;
+    }
+
     /// Finish sweeping and reset state
     fn finish_sweep(&mut self) {
-        self.bytes_allocated -= self.bytes_freed_this_cycle;
-        self.num_objects -= self.objects_freed_this_cycle;
-
         // Adjust threshold: GC when we've allocated 2x current live set
         self.gc_threshold = (self.bytes_allocated * 2).max(DEFAULT_GC_THRESHOLD);
 
         // Reset state
         self.phase = GcPhase::Idle;
+        if let Some(remembered_set) = self.remembered_set {
+            unsafe { (*remembered_set.as_ptr()).resume() };
+        }
+        self.sweep_pages.clear();
+        self.sweep_cell_index = 0;
         self.sweep_prev = None;
         self.sweep_current = None;
-        self.bytes_freed_this_cycle = 0;
-        self.objects_freed_this_cycle = 0;
     }
 
     /// Complete GC synchronously
@@ -385,7 +1116,22 @@ impl Heap {
     pub fn finish_gc(&mut self, ctx: &mut impl GcContext) -> usize {
         let mut steps = 0;
         loop {
+            #[cfg(feature = "parallel_gc")]
+            let in_progress = {
+                // Block on the background sweeper rather than polling it
+                self.join_background_sweep();
+
+                // A synchronous collection has no pause budget, so marking uses every thread at once
+                if self.phase == GcPhase::Marking && self.parallel_options.marking_threads > 1 {
+                    self.run_gc_step(ctx, usize::MAX, None, None)
+                } else {
+                    self.gc_step(ctx)
+                }
+            };
+
+            #[cfg(not(feature = "parallel_gc"))]
             let in_progress = self.gc_step(ctx);
+
             steps += 1;
             if !in_progress {
                 break;
@@ -426,23 +1172,53 @@ impl Heap {
         }
     }
 
-    /// Write barrier - call when writing a pointer field
+    /// Write barrier - call when writing a pointer to `target` into a field of `source`
     ///
     /// During marking phase, this ensures the target is marked gray
     /// (Dijkstra-style insertion barrier).
+    ///
+    /// In a generational heap, this adds the source to the remembered set if it is in the old
+    /// generation and the target is in the nursery.
     #[inline]
-    pub fn write_barrier<T>(&mut self, target: GcPtr<T>) {
-        if self.is_marking() && !target.is_dangling() {
-            self.mark_gray_ptr(target);
+    pub fn write_barrier<S, T>(&mut self, source: GcPtr<S>, target: GcPtr<T>) {
+        if target.is_dangling() {
+            return;
         }
+
+        self.write_barrier_raw(source.as_ptr() as *mut u8, target.as_ptr() as *mut u8);
     }
 
-    /// Write barrier for raw pointer
+    /// Write barrier for raw pointers
     #[inline]
-    pub fn write_barrier_raw(&mut self, target: *mut u8) {
-        if self.is_marking() && !target.is_null() {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn write_barrier_raw(&mut self, source: *mut u8, target: *mut u8) {
+        if target.is_null() {
+            return;
+        }
+
+        if self.is_marking() {
             self.mark_gray_raw(target);
         }
+
+        if !source.is_null() && self.is_young(GcPtr::from_ptr(target)) {
+            unsafe { RememberedSet::remember(GcHeader::from_object_ptr(source)) };
+        }
+    }
+
+    /// Conservative generational write barrier for writers that do not know which pointers they
+    /// store, e.g. code with mutable access to an entire object.
+    ///
+    /// Adds the object to the remembered set if it is in the old generation, since it may now
+    /// contain a pointer into the nursery. Does not require access to the heap.
+    ///
+    /// Does nothing during a GC or while the write barrier is paused. Only the object's header
+    /// flags are read in that case, so this is safe to call while GC threads are marking.
+    #[inline]
+    pub fn remember_written_object<T>(object: GcPtr<T>) {
+        if !object.is_dangling() {
+            let header = unsafe { (object.as_ptr() as *mut u8).sub(GcHeader::SIZE) };
+            unsafe { RememberedSet::remember(header as *mut GcHeader) };
+        }
     }
 
     // ========================================================================
@@ -483,6 +1259,96 @@ impl Default for Heap {
     }
 }
 
+impl Drop for Heap {
+    fn drop(&mut self) {
+        #[cfg(feature = "parallel_gc")]
+        self.join_background_sweep();
+
+        for space in &mut self.size_classes {
+            space.available_pages.clear();
+            for page in space.pages.drain(..) {
+                unsafe { Page::release(page) };
+            }
+        }
+
+        let mut current = self.large_objects.take();
+        while let Some(header_ptr) = current {
+            unsafe {
+                current = (*header_ptr.as_ptr()).next_object();
+                LargeObjectPrefix::free(header_ptr);
+            }
+        }
+
+        if let Some(remembered_set) = self.remembered_set.take() {
+            unsafe { RememberedSet::release(remembered_set) };
+        }
+    }
+}
+
+// ============================================================================
+// Evacuator - implements GcVisitor for minor GCs
+// ============================================================================
+
+/// A visitor that copies young objects to the old generation during a minor GC, rewriting each
+/// visited pointer to point to the object's new location.
+struct Evacuator<'a> {
+    heap: &'a mut Heap,
+    nursery: &'a Nursery,
+    /// Promoted objects whose pointers have not been visited yet
+    promoted: Vec<NonNull<GcHeader>>,
+}
+
+impl Evacuator<'_> {
+    /// Return the new location of a young object, copying it to the old generation if it has not
+    /// been copied yet.
+    fn evacuate(&mut self, object_ptr: *mut u8) -> *mut u8 {
+        unsafe {
+            let header = GcHeader::from_object_ptr(object_ptr);
+            if let Some(new_object_ptr) = header.forwarding_address() {
+                return new_object_ptr;
+            }
+
+            let size = header.alloc_size();
+            let layout = GcHeader::layout_for_size(size);
+            let new_header = match self.heap.alloc_in_old_generation(layout, size) {
+                Some(new_header) => new_header,
+                None => alloc::alloc::handle_alloc_error(layout),
+            };
+
+            // The old copy is left intact until the nursery is reset, so objects can still be read
+            // through stale pointers while they are being traced.
+            let new_object_ptr = (*new_header.as_ptr()).object_ptr();
+            core::ptr::copy_nonoverlapping(object_ptr, new_object_ptr, size);
+
+            header.set_forwarding_address(new_header);
+            self.promoted.push(new_header);
+
+            new_object_ptr
+        }
+    }
+}
+
+impl GcVisitor for Evacuator<'_> {
+    fn visit<T>(&mut self, ptr: &mut GcPtr<T>) {
+        if ptr.is_dangling() {
+            return;
+        }
+
+        let object_ptr = ptr.as_ptr() as *mut u8;
+        if !self.nursery.contains(object_ptr) {
+            return;
+        }
+
+        *ptr = GcPtr::from_ptr(self.evacuate(object_ptr) as *mut T);
+    }
+
+    fn visit_weak<T>(&mut self, ptr: &mut GcPtr<T>) {
+        // Weak pointers keep young objects alive until the next major GC, which is the only time
+        // weak references are processed.
+        self.visit(ptr);
+    }
+}
+
 // ============================================================================
 // Marker - implements GcVisitor for the marking phase
 // ============================================================================
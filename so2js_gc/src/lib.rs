@@ -1,6 +1,7 @@
 //! SO2JS Garbage Collector
 //!
-//! An incremental tri-color mark-sweep garbage collector.
+//! An incremental tri-color mark-sweep garbage collector, with an optional copying nursery for
+//! generational collection. Objects are allocated in size-class segregated pages owned by the heap.
 //! This crate provides the core GC infrastructure without depending on the runtime types.
 //!
 //! Key types:
@@ -11,19 +12,42 @@
 //! Key traits:
 //! - `GcVisitor`: Implemented by GC, used by objects to report pointers
 //! - `GcContext`: Implemented by runtime, provides root scanning and object tracing
+//!
+//! Features:
+//! - `parallel_gc`: Mark on multiple threads and sweep on a background thread. Requires `std`.
+//! - `gc_stress_test`: Collect as often as possible to find missing roots and barriers
+//! - `heap_verify`: Verify the heap after marking and after every GC cycle
 
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "parallel_gc")]
+extern crate std;
 
 mod gc_header;
 mod gray_queue;
 mod heap;
+mod nursery;
+mod pacing;
+mod page;
+#[cfg(feature = "parallel_gc")]
+mod parallel;
 mod pointer;
+mod remembered_set;
+mod stats;
+#[cfg(feature = "heap_verify")]
+mod verify;
 mod visitor;
 
 pub use gc_header::{GcColor, GcHeader, GcPhase};
 pub use heap::{AllocError, AllocResult, Heap, Marker};
+pub use nursery::{DEFAULT_NURSERY_SIZE, MAX_NURSERY_OBJECT_SIZE};
+pub use pacing::{GcPacing, MIN_ALLOCATION_HEADROOM, TIME_CHECK_INTERVAL};
+#[cfg(feature = "parallel_gc")]
+pub use parallel::{ParallelGcOptions, PARALLEL_MARK_MIN_WORK};
 pub use pointer::GcPtr;
+pub use stats::{GcCycleKind, GcCycleStats, GC_HISTORY_LENGTH};
+#[cfg(feature = "heap_verify")]
+pub use verify::{HeapVerifyError, HeapVerifyProblem, HeapVerifyResult};
 pub use visitor::{GcContext, GcVisitor};
 
 #[cfg(test)]
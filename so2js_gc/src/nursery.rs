@@ -0,0 +1,125 @@
+//! Nursery - bump-allocated young generation
+//!
+//! New objects are allocated by bumping a cursor through a single contiguous region. Objects in
+//! the nursery are never freed individually. Instead a minor GC copies all live objects out of the
+//! nursery into the old generation, after which the entire nursery is reset at once.
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use crate::gc_header::GcHeader;
+
+/// Default size of the nursery (1MB)
+pub const DEFAULT_NURSERY_SIZE: usize = 1024 * 1024;
+
+/// Objects larger than this are allocated directly in the old generation
+pub const MAX_NURSERY_OBJECT_SIZE: usize = 16 * 1024;
+
+/// Byte pattern written over the nursery after a minor GC in stress test mode, so that stale
+/// pointers into the nursery are more likely to be detected.
+#[cfg(feature = "gc_stress_test")]
+const NURSERY_POISON_BYTE: u8 = 0xAB;
+
+pub struct Nursery {
+    /// Start of the nursery region
+    start: NonNull<u8>,
+    /// Next free byte in the nursery region
+    cursor: *mut u8,
+    /// End of the nursery region (exclusive)
+    end: *mut u8,
+    /// Layout used to allocate the nursery region
+    layout: Layout,
+}
+
+impl Nursery {
+    /// Allocate a new nursery of the given size. Returns None if the nursery region could not be
+    /// allocated.
+    pub fn new(size: usize) -> Option<Nursery> {
+        let layout = Layout::from_size_align(size.max(GcHeader::SIZE), GcHeader::ALIGN).ok()?;
+
+        unsafe {
+            let start = NonNull::new(alloc::alloc::alloc(layout))?;
+
+            Some(Nursery {
+                start,
+                cursor: start.as_ptr(),
+                end: start.as_ptr().add(layout.size()),
+                layout,
+            })
+        }
+    }
+
+    /// Allocate space for an object with the given layout (including its header). Returns None if
+    /// the nursery does not have enough space left.
+    #[inline]
+    pub fn try_alloc(&mut self, layout: Layout) -> Option<NonNull<u8>> {
+        debug_assert!(layout.align() <= GcHeader::ALIGN);
+
+        let remaining = self.end as usize - self.cursor as usize;
+        if layout.size() > remaining {
+            return None;
+        }
+
+        let ptr = self.cursor;
+        self.cursor = unsafe { self.cursor.add(layout.size()) };
+
+        Some(unsafe { NonNull::new_unchecked(ptr) })
+    }
+
+    /// Whether the given pointer points into the nursery region
+    #[inline]
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let addr = ptr as usize;
+        addr >= self.start.as_ptr() as usize && addr < self.end as usize
+    }
+
+    /// Whether no objects have been allocated since the nursery was last reset
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cursor == self.start.as_ptr()
+    }
+
+    /// Number of bytes allocated in the nursery, including object headers
+    #[inline]
+    pub fn bytes_allocated(&self) -> usize {
+        self.cursor as usize - self.start.as_ptr() as usize
+    }
+
+    /// Call a function on the header of every object allocated in the nursery. Must not be called
+    /// during a minor GC, since forwarded objects no longer have valid headers.
+    pub fn for_each_object(&self, mut f: impl FnMut(&GcHeader)) {
+        let mut current = self.start.as_ptr();
+        while current < self.cursor {
+            unsafe {
+                let header = &*(current as *const GcHeader);
+                f(header);
+
+                let layout = GcHeader::layout_for_size(header.alloc_size());
+                current = current.add(layout.size());
+            }
+        }
+    }
+
+    /// Total size of the nursery region
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.layout.size()
+    }
+
+    /// Free all objects in the nursery. Must only be called once all live objects have been
+    /// copied out of the nursery.
+    pub fn reset(&mut self) {
+        #[cfg(feature = "gc_stress_test")]
+        unsafe {
+            let start = self.start.as_ptr();
+            core::ptr::write_bytes(start, NURSERY_POISON_BYTE, self.bytes_allocated());
+        }
+
+        self.cursor = self.start.as_ptr();
+    }
+}
+
+impl Drop for Nursery {
+    fn drop(&mut self) {
+        unsafe { alloc::alloc::dealloc(self.start.as_ptr(), self.layout) }
+    }
+}
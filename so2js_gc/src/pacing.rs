@@ -0,0 +1,54 @@
+//! GC pacing - how much incremental GC work is done and when
+//!
+//! Incremental GC work is counted in objects marked or swept. Work is scheduled in two ways:
+//! - Allocation-driven steps: every allocation made while a GC cycle is in progress earns work
+//!   credit in proportion to its size. The rate is chosen when the cycle starts so that the cycle
+//!   finishes before the heap grows by `allocation_headroom_percent`.
+//! - Explicit steps: `Heap::gc_step` and `Heap::gc_step_until`, e.g. when the host is idle.
+//!
+//! Every step is bounded by both `step_work_limit` and `step_time_limit_millis`, so that a single
+//! pause never exceeds the configured budget by much.
+
+/// Number of objects processed between checks of the step's time limit
+pub const TIME_CHECK_INTERVAL: usize = 16;
+
+/// Smallest allocation headroom in bytes, so that small heaps are not collected in a single step
+pub const MIN_ALLOCATION_HEADROOM: usize = 64 * 1024;
+
+/// Policy for scheduling incremental GC work
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GcPacing {
+    /// Maximum number of objects marked or swept in a single step
+    pub step_work_limit: usize,
+    /// Maximum time spent in a single step in milliseconds, or None for no time limit. The time
+    /// is only checked every `TIME_CHECK_INTERVAL` objects, so a step may overrun slightly.
+    pub step_time_limit_millis: Option<f64>,
+    /// Number of bytes that may be allocated while a GC cycle is in progress, as a percentage of
+    /// the bytes allocated when the cycle started. Smaller values finish cycles sooner at the cost
+    /// of doing more work per allocation.
+    pub allocation_headroom_percent: usize,
+}
+
+impl GcPacing {
+    pub const DEFAULT: GcPacing = GcPacing {
+        step_work_limit: 100,
+        step_time_limit_millis: None,
+        allocation_headroom_percent: 50,
+    };
+
+    /// Number of units of work to do for each byte allocated during a cycle, given an estimate of
+    /// the total work for the cycle and the number of bytes allocated when the cycle started.
+    pub(crate) fn work_per_allocated_byte(&self, cycle_work: usize, bytes_allocated: usize) -> f64 {
+        let headroom = (bytes_allocated / 100)
+            .saturating_mul(self.allocation_headroom_percent)
+            .max(MIN_ALLOCATION_HEADROOM);
+
+        cycle_work as f64 / headroom as f64
+    }
+}
+
+impl Default for GcPacing {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,248 @@
+//! Pages - size-class segregated storage for the old generation
+//!
+//! Small objects in the old generation are stored in fixed size pages. Each page is divided into
+//! cells of a single size class, and each cell holds one object (including its `GcHeader`) or is
+//! free. Free cells are linked into a per-page free list through their headers.
+//!
+//! Pages are aligned to their size, so the page containing a cell can be found by masking the
+//! cell's address.
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use crate::{gc_header::GcHeader, remembered_set::RememberedSet};
+
+/// Size of each page in bytes. Pages are allocated with this alignment.
+pub const PAGE_SIZE: usize = 256 * 1024;
+
+/// Cell sizes for each size class, including the `GcHeader`. Objects whose total size is larger
+/// than the largest size class are allocated in the large object space.
+pub const SIZE_CLASSES: [usize; 31] = [
+    32, 48, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 640, 768, 896, 1024,
+    1280, 1536, 1792, 2048, 2560, 3072, 3584, 4096, 5120, 6144, 7168, 8192,
+];
+
+/// Largest total object size (including the `GcHeader`) that is allocated in a page
+pub const MAX_SMALL_OBJECT_SIZE: usize = SIZE_CLASSES[SIZE_CLASSES.len() - 1];
+
+/// Number of size classes
+pub const NUM_SIZE_CLASSES: usize = SIZE_CLASSES.len();
+
+/// Return the index of the smallest size class that can hold an allocation of the given total
+/// size, or None if the allocation must go in the large object space.
+#[inline]
+pub fn size_class_index(total_size: usize) -> Option<usize> {
+    if total_size > MAX_SMALL_OBJECT_SIZE {
+        return None;
+    }
+
+    Some(SIZE_CLASSES.partition_point(|cell_size| *cell_size < total_size))
+}
+
+/// Header at the start of every page
+#[repr(C)]
+pub struct Page {
+    /// Index of this page's size class
+    size_class_index: usize,
+    /// Size of each cell in this page
+    cell_size: usize,
+    /// Total number of cells in this page
+    num_cells: usize,
+    /// Number of cells that have ever been allocated. Cells past this index have never been used
+    /// and are not in the free list.
+    num_used_cells: usize,
+    /// Number of cells currently holding an object
+    num_allocated: usize,
+    /// Free cells below `num_used_cells`, linked through their headers
+    free_list: Option<NonNull<GcHeader>>,
+    /// The remembered set of the heap that owns this page, if the heap has a nursery
+    remembered_set: Option<NonNull<RememberedSet>>,
+    /// Index of this page in its size class's list of pages, so that the page can be removed from
+    /// the list without searching for it
+    index_in_space: usize,
+}
+
+impl Page {
+    /// Offset of the first cell from the start of the page
+    const CELLS_OFFSET: usize = align_up(core::mem::size_of::<Page>(), GcHeader::ALIGN);
+
+    #[inline]
+    fn layout() -> Layout {
+        Layout::from_size_align(PAGE_SIZE, PAGE_SIZE).unwrap()
+    }
+
+    /// Allocate a new empty page for the given size class, owned by a heap with the given
+    /// remembered set. Returns None if the page could not be allocated.
+    pub fn new(
+        size_class_index: usize,
+        remembered_set: Option<NonNull<RememberedSet>>,
+    ) -> Option<NonNull<Page>> {
+        let cell_size = SIZE_CLASSES[size_class_index];
+
+        unsafe {
+            let page = NonNull::new(alloc::alloc::alloc(Self::layout()) as *mut Page)?;
+            page.as_ptr().write(Page {
+                size_class_index,
+                cell_size,
+                num_cells: (PAGE_SIZE - Self::CELLS_OFFSET) / cell_size,
+                num_used_cells: 0,
+                num_allocated: 0,
+                free_list: None,
+                remembered_set,
+                index_in_space: 0,
+            });
+
+            Some(page)
+        }
+    }
+
+    /// Return a page's memory to the system allocator.
+    ///
+    /// # Safety
+    /// The page must not be used after it is released.
+    pub unsafe fn release(page: NonNull<Page>) {
+        alloc::alloc::dealloc(page.as_ptr() as *mut u8, Self::layout());
+    }
+
+    /// Find the page that contains the given cell
+    #[inline]
+    pub fn for_cell(cell: *const u8) -> NonNull<Page> {
+        let page_addr = (cell as usize) & !(PAGE_SIZE - 1);
+        unsafe { NonNull::new_unchecked(page_addr as *mut Page) }
+    }
+
+    #[inline]
+    pub fn size_class_index(&self) -> usize {
+        self.size_class_index
+    }
+
+    #[inline]
+    pub fn remembered_set(&self) -> Option<NonNull<RememberedSet>> {
+        self.remembered_set
+    }
+
+    #[inline]
+    pub fn index_in_space(&self) -> usize {
+        self.index_in_space
+    }
+
+    #[inline]
+    pub fn set_index_in_space(&mut self, index_in_space: usize) {
+        self.index_in_space = index_in_space;
+    }
+
+    /// Number of cells that may hold objects. Cells at or past this index have never been used.
+    #[inline]
+    pub fn num_used_cells(&self) -> usize {
+        self.num_used_cells
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.free_list.is_none() && self.num_used_cells == self.num_cells
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_allocated == 0
+    }
+
+    /// Get the cell at the given index
+    #[inline]
+    pub fn cell(&self, index: usize) -> NonNull<GcHeader> {
+        debug_assert!(index < self.num_cells);
+        unsafe {
+            let page_start = self as *const Page as *mut u8;
+            let cell = page_start.add(Self::CELLS_OFFSET + index * self.cell_size);
+            NonNull::new_unchecked(cell as *mut GcHeader)
+        }
+    }
+
+    /// Take a free cell from this page, preferring previously freed cells. Returns None if the
+    /// page is full.
+    #[inline]
+    pub fn alloc_cell(&mut self) -> Option<NonNull<GcHeader>> {
+        let cell = if let Some(cell) = self.free_list {
+            self.free_list = unsafe { (*cell.as_ptr()).next_object() };
+            cell
+        } else if self.num_used_cells < self.num_cells {
+            let cell = self.cell(self.num_used_cells);
+            self.num_used_cells += 1;
+            cell
+        } else {
+            return None;
+        };
+
+        self.num_allocated += 1;
+
+        Some(cell)
+    }
+
+    /// Return a cell holding a dead object to the page's free list.
+    ///
+    /// # Safety
+    /// The cell must belong to this page and hold an object.
+    #[inline]
+    pub unsafe fn free_cell(&mut self, cell: NonNull<GcHeader>) {
+        let header = &mut *cell.as_ptr();
+        header.set_free(self.free_list);
+        self.free_list = Some(cell);
+        self.num_allocated -= 1;
+    }
+}
+
+/// Align a value up to the given alignment
+#[inline]
+const fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_class_index() {
+        assert_eq!(size_class_index(1), Some(0));
+        assert_eq!(size_class_index(32), Some(0));
+        assert_eq!(size_class_index(33), Some(1));
+        assert_eq!(
+            size_class_index(MAX_SMALL_OBJECT_SIZE),
+            Some(NUM_SIZE_CLASSES - 1)
+        );
+        assert_eq!(size_class_index(MAX_SMALL_OBJECT_SIZE + 1), None);
+
+        // Every size class can hold a header
+        assert!(SIZE_CLASSES[0] > GcHeader::SIZE);
+        assert!(SIZE_CLASSES.iter().all(|size| size % GcHeader::ALIGN == 0));
+    }
+
+    #[test]
+    fn test_page_alloc_and_free() {
+        let page = Page::new(0, None).unwrap();
+
+        unsafe {
+            let page_ref = &mut *page.as_ptr();
+            let first = page_ref.alloc_cell().unwrap();
+            first.as_ptr().write(GcHeader::new(8));
+            let second = page_ref.alloc_cell().unwrap();
+            second.as_ptr().write(GcHeader::new(8));
+
+            assert!(!page_ref.is_empty());
+            assert_eq!(Page::for_cell(first.as_ptr() as *const u8), page);
+            assert_eq!(
+                second.as_ptr() as usize - first.as_ptr() as usize,
+                SIZE_CLASSES[0]
+            );
+
+            // Freed cells are reused before unused cells
+            page_ref.free_cell(first);
+            assert_eq!(page_ref.alloc_cell(), Some(first));
+
+            page_ref.free_cell(first);
+            page_ref.free_cell(second);
+            assert!(page_ref.is_empty());
+
+            Page::release(page);
+        }
+    }
+}
@@ -0,0 +1,443 @@
+//! Parallel marking and background sweeping
+//!
+//! Only available with the `parallel_gc` feature, since it requires threads from `std`.
+//!
+//! Parallel marking splits the gray queue across worker threads. Each worker marks objects from a
+//! private stack and shares surplus work through a queue that idle workers steal from. Objects are
+//! claimed by atomically coloring them gray, so every object is traced exactly once. Workers call
+//! `GcContext::trace_object` concurrently while the mutator is paused.
+//!
+//! Workers never create references to objects in the heap or to their headers, since other workers
+//! may be reading the same objects and atomically updating the same headers. Instead each worker
+//! copies an object's data into a private buffer and traces the copy. Headers are only accessed
+//! through atomic operations on the color, or through raw reads of fields that do not change
+//! during marking.
+//!
+//! Background sweeping hands every page and the large object list to a sweeper thread when
+//! sweeping starts. The mutator keeps running and allocates in fresh pages while the sweeper frees
+//! white objects, and the swept pages are returned to the heap once the sweeper finishes. The
+//! mutator never touches dead objects, and the sweeper only changes the color of live objects.
+
+use core::{
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use alloc::vec::Vec;
+use std::{sync::Mutex, thread};
+
+use crate::{
+    gc_header::{GcColor, GcHeader},
+    gray_queue::GrayQueue,
+    page::Page,
+    remembered_set::LargeObjectPrefix,
+    visitor::{GcContext, GcVisitor},
+    GcPtr,
+};
+
+/// Smallest work limit of a marking step for it to be split across threads. Smaller steps are
+/// marked on the mutator thread, since starting threads would cost more than the marking itself.
+pub const PARALLEL_MARK_MIN_WORK: usize = 1024;
+
+/// Number of objects a worker keeps in its private stack before sharing half with other workers
+const SHARE_THRESHOLD: usize = 64;
+
+/// Options for parallel marking and background sweeping
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelGcOptions {
+    /// Number of threads that mark in parallel, including the mutator thread. Marking is not
+    /// parallel when this is 1.
+    pub marking_threads: usize,
+    /// Sweep on a background thread instead of in incremental steps on the mutator thread
+    pub background_sweeping: bool,
+}
+
+impl ParallelGcOptions {
+    /// Mark and sweep on the mutator thread only
+    pub const DISABLED: ParallelGcOptions = ParallelGcOptions {
+        marking_threads: 1,
+        background_sweeping: false,
+    };
+
+    /// Mark on every available core and sweep in the background
+    pub fn all_cores() -> ParallelGcOptions {
+        let marking_threads = thread::available_parallelism().map_or(1, |n| n.get());
+        ParallelGcOptions {
+            marking_threads,
+            background_sweeping: true,
+        }
+    }
+}
+
+impl Default for ParallelGcOptions {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+/// Wrapper for heap pointers that are moved to or shared with a GC thread.
+///
+/// Heap pointers are neither `Send` nor `Sync`, but GC threads only access objects in ways that
+/// cannot race with the mutator or with each other, as described in the module docs.
+struct GcThreadSafe<T>(T);
+
+unsafe impl<T> Send for GcThreadSafe<T> {}
+unsafe impl<T> Sync for GcThreadSafe<T> {}
+
+impl<T> GcThreadSafe<T> {
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+// ============================================================================
+// Parallel marking
+// ============================================================================
+
+/// Mark up to `work_limit` objects from the gray queue using `num_threads` threads, one of which is
+/// the current thread. Gray objects that were not marked within the work limit are returned to the
+/// gray queue.
+pub(crate) fn parallel_mark<C: GcContext>(
+    ctx: &C,
+    gray_queue: &mut GrayQueue,
+    num_threads: usize,
+    work_limit: usize,
+) {
+    // Deal the initial gray objects out to every worker
+    let mut shared_queues: Vec<Mutex<Vec<NonNull<GcHeader>>>> =
+        (0..num_threads).map(|_| Mutex::new(Vec::new())).collect();
+    for (index, header) in gray_queue.take_all().into_iter().enumerate() {
+        shared_queues[index % num_threads]
+            .get_mut()
+            .unwrap()
+            .push(header);
+    }
+
+    let state = GcThreadSafe(MarkState {
+        ctx,
+        shared_queues,
+        remaining_work: AtomicUsize::new(work_limit),
+        num_idle: AtomicUsize::new(0),
+    });
+
+    let leftovers = thread::scope(|scope| {
+        let state = &state;
+        let workers: Vec<_> = (1..num_threads)
+            .map(|id| scope.spawn(move || GcThreadSafe(state.0.run_worker(id))))
+            .collect();
+
+        let mut leftovers = state.0.run_worker(0);
+        for worker in workers {
+            let worker_leftovers = worker.join().expect("GC marking thread panicked");
+            leftovers.extend(worker_leftovers.into_inner());
+        }
+
+        leftovers
+    });
+
+    gray_queue.extend(leftovers);
+    for queue in state.into_inner().shared_queues {
+        gray_queue.extend(queue.into_inner().unwrap());
+    }
+}
+
+/// State shared by all workers of a parallel marking step
+struct MarkState<'a, C> {
+    ctx: &'a C,
+    /// Work shared by each worker, which idle workers may steal
+    shared_queues: Vec<Mutex<Vec<NonNull<GcHeader>>>>,
+    /// Number of objects that may still be marked in this step
+    remaining_work: AtomicUsize,
+    /// Number of workers that have run out of work
+    num_idle: AtomicUsize,
+}
+
+impl<C: GcContext> MarkState<'_, C> {
+    /// Mark objects until there is no work left or the work limit is reached. Returns the gray
+    /// objects this worker did not get to.
+    fn run_worker(&self, id: usize) -> Vec<NonNull<GcHeader>> {
+        let mut local = Vec::new();
+        let mut copy_buffer = Vec::new();
+
+        loop {
+            while let Some(header_ptr) = local.pop() {
+                if !self.take_work() {
+                    local.push(header_ptr);
+                    return local;
+                }
+
+                unsafe {
+                    GcHeader::set_color_atomic(header_ptr.as_ptr(), GcColor::Black);
+
+                    let copy_ptr = copy_object(header_ptr.as_ptr(), &mut copy_buffer);
+                    let mut marker = ParallelMarker { local: &mut local };
+                    self.ctx.trace_object(copy_ptr, &mut marker);
+                }
+
+                if local.len() > SHARE_THRESHOLD {
+                    self.share_work(id, &mut local);
+                }
+            }
+
+            if !self.find_work(id, &mut local) {
+                return local;
+            }
+        }
+    }
+
+    /// Claim a unit of work from the step's work limit. Returns false if the limit was reached.
+    fn take_work(&self) -> bool {
+        self.remaining_work
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |work| {
+                work.checked_sub(1)
+            })
+            .is_ok()
+    }
+
+    /// Move the oldest half of the local stack to this worker's shared queue, if the shared queue
+    /// has been emptied by other workers.
+    fn share_work(&self, id: usize, local: &mut Vec<NonNull<GcHeader>>) {
+        let mut queue = self.shared_queues[id].lock().unwrap();
+        if queue.is_empty() {
+            queue.extend(local.drain(..local.len() / 2));
+        }
+    }
+
+    /// Refill the empty local stack from this worker's own shared queue, or by stealing half of
+    /// another worker's shared queue. Waits for work to be shared if there is none. Returns false
+    /// once marking is complete or the work limit is reached.
+    fn find_work(&self, id: usize, local: &mut Vec<NonNull<GcHeader>>) -> bool {
+        loop {
+            if self.take_shared_work(id, local) {
+                return true;
+            }
+
+            // No work anywhere, so wait until another worker shares work or every worker is idle.
+            // Only active workers push to their own shared queues, so once every worker is idle no
+            // more work can appear.
+            self.num_idle.fetch_add(1, Ordering::AcqRel);
+            loop {
+                if self.num_idle.load(Ordering::Acquire) == self.shared_queues.len()
+                    || self.remaining_work.load(Ordering::Relaxed) == 0
+                {
+                    return false;
+                }
+
+                if self.has_shared_work() {
+                    self.num_idle.fetch_sub(1, Ordering::AcqRel);
+                    break;
+                }
+
+                thread::yield_now();
+            }
+        }
+    }
+
+    fn take_shared_work(&self, id: usize, local: &mut Vec<NonNull<GcHeader>>) -> bool {
+        let num_workers = self.shared_queues.len();
+        for offset in 0..num_workers {
+            let victim = (id + offset) % num_workers;
+            let mut queue = self.shared_queues[victim].lock().unwrap();
+            if queue.is_empty() {
+                continue;
+            }
+
+            // Take all of our own work, but only steal half of another worker's
+            let num_taken = if victim == id {
+                queue.len()
+            } else {
+                queue.len().div_ceil(2)
+            };
+            local.extend(queue.drain(..num_taken));
+
+            return true;
+        }
+
+        false
+    }
+
+    fn has_shared_work(&self) -> bool {
+        self.shared_queues
+            .iter()
+            .any(|queue| !queue.lock().unwrap().is_empty())
+    }
+}
+
+/// Copy the data of an object into the buffer behind a fresh header, returning a pointer to the
+/// copied data. Only the object's size is read from its header.
+///
+/// The copy is flagged as young, since it is not part of the old generation and must never be added
+/// to the remembered set.
+///
+/// # Safety
+/// The header must belong to a live object that is not written to while it is copied.
+unsafe fn copy_object(header: *mut GcHeader, buffer: &mut Vec<u64>) -> *mut u8 {
+    let alloc_size = GcHeader::read_alloc_size(header);
+    let layout = GcHeader::layout_for_size(alloc_size);
+
+    // Buffer of words so that the copy is aligned like a heap allocation
+    let num_words = layout.size().div_ceil(core::mem::size_of::<u64>());
+    if buffer.len() < num_words {
+        buffer.resize(num_words, 0);
+    }
+
+    let mut copy_header = GcHeader::new(alloc_size);
+    copy_header.set_young();
+
+    let copy_header_ptr = buffer.as_mut_ptr() as *mut GcHeader;
+    copy_header_ptr.write(copy_header);
+
+    let object_ptr = (header as *const u8).add(GcHeader::SIZE);
+    let copy_ptr = (copy_header_ptr as *mut u8).add(GcHeader::SIZE);
+    core::ptr::copy_nonoverlapping(object_ptr, copy_ptr, alloc_size);
+
+    copy_ptr
+}
+
+/// Visitor for a single parallel marking worker. Gray objects are pushed to the worker's private
+/// stack.
+struct ParallelMarker<'a> {
+    local: &'a mut Vec<NonNull<GcHeader>>,
+}
+
+impl GcVisitor for ParallelMarker<'_> {
+    fn visit<T>(&mut self, ptr: &mut GcPtr<T>) {
+        if ptr.is_dangling() {
+            return;
+        }
+
+        let object_ptr = ptr.as_ptr() as *mut u8;
+        if object_ptr.is_null() {
+            return;
+        }
+
+        unsafe {
+            let header_ptr = object_ptr.sub(GcHeader::SIZE) as *mut GcHeader;
+            if GcHeader::try_mark_gray_atomic(header_ptr) {
+                self.local.push(NonNull::new_unchecked(header_ptr));
+            }
+        }
+    }
+
+    fn visit_weak<T>(&mut self, _ptr: &mut GcPtr<T>) {
+        // Weak pointers are not traced during marking
+    }
+}
+
+// ============================================================================
+// Background sweeping
+// ============================================================================
+
+/// A sweep of the old generation running on a background thread
+pub(crate) struct BackgroundSweep {
+    handle: thread::JoinHandle<GcThreadSafe<SweepResult>>,
+}
+
+/// Everything the background sweeper returns to the heap
+pub(crate) struct SweepResult {
+    /// Every swept page, including pages that are now empty
+    pub pages: Vec<NonNull<Page>>,
+    /// Head and tail of the list of surviving large objects
+    pub large_objects: Option<(NonNull<GcHeader>, NonNull<GcHeader>)>,
+    pub bytes_freed: usize,
+    pub objects_freed: usize,
+}
+
+impl BackgroundSweep {
+    /// Start sweeping the given pages and large object list on a new thread. Returns None if the
+    /// thread could not be started, in which case nothing has been swept.
+    ///
+    /// # Safety
+    /// The heap must not allocate in the pages or access the large object list until the sweep
+    /// has been joined.
+    pub unsafe fn start(
+        pages: Vec<NonNull<Page>>,
+        large_objects: Option<NonNull<GcHeader>>,
+    ) -> Option<BackgroundSweep> {
+        let work = GcThreadSafe((pages, large_objects));
+        let handle = thread::Builder::new()
+            .name("so2js-gc-sweeper".into())
+            .spawn(move || {
+                let (pages, large_objects) = work.into_inner();
+                GcThreadSafe(unsafe { sweep(pages, large_objects) })
+            })
+            .ok()?;
+
+        Some(BackgroundSweep { handle })
+    }
+
+    /// Whether the sweep has finished, so that joining it will not block
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Wait for the sweep to finish
+    pub fn join(self) -> SweepResult {
+        self.handle
+            .join()
+            .expect("GC sweeper thread panicked")
+            .into_inner()
+    }
+}
+
+/// Free every white object in the given pages and large object list, and reset live objects to
+/// white for the next cycle.
+unsafe fn sweep(
+    pages: Vec<NonNull<Page>>,
+    large_objects: Option<NonNull<GcHeader>>,
+) -> SweepResult {
+    let mut bytes_freed = 0;
+    let mut objects_freed = 0;
+
+    for &page_ptr in &pages {
+        let page = &mut *page_ptr.as_ptr();
+        for cell_index in 0..page.num_used_cells() {
+            let cell = page.cell(cell_index);
+            let header = cell.as_ptr();
+            if (*header).is_free() {
+                continue;
+            }
+
+            if GcHeader::color_atomic(header) == GcColor::White {
+                bytes_freed += (*header).total_size();
+                objects_freed += 1;
+                page.free_cell(cell);
+            } else {
+                GcHeader::set_color_atomic(header, GcColor::White);
+            }
+        }
+    }
+
+    // Relink surviving large objects in their original order
+    let mut survivors: Option<(NonNull<GcHeader>, NonNull<GcHeader>)> = None;
+    let mut current = large_objects;
+    while let Some(header_ptr) = current {
+        let header = header_ptr.as_ptr();
+        current = (*header).next_object();
+
+        if GcHeader::color_atomic(header) == GcColor::White {
+            bytes_freed += (*header).total_size();
+            objects_freed += 1;
+
+            LargeObjectPrefix::free(header_ptr);
+        } else {
+            GcHeader::set_color_atomic(header, GcColor::White);
+            (*header).set_next_object(None);
+
+            survivors = match survivors {
+                None => Some((header_ptr, header_ptr)),
+                Some((head, tail)) => {
+                    (*tail.as_ptr()).set_next_object(Some(header_ptr));
+                    Some((head, header_ptr))
+                }
+            };
+        }
+    }
+
+    SweepResult {
+        pages,
+        large_objects: survivors,
+        bytes_freed,
+        objects_freed,
+    }
+}
@@ -0,0 +1,164 @@
+//! Remembered set - old objects that may contain pointers into the nursery
+//!
+//! Every old object that is written to is added to the remembered set the first time it is
+//! written, and a minor GC traces only the objects in the remembered set instead of scanning the
+//! entire old generation.
+//!
+//! The conservative write barrier (`Heap::remember_written_object`) does not have access to the
+//! heap, so the remembered set is found through the object itself. Pages store a pointer to the
+//! remembered set of their heap, and large objects are preceded by a `LargeObjectPrefix` that does
+//! the same.
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{gc_header::GcHeader, page::Page};
+
+pub struct RememberedSet {
+    /// Headers of every old object in the remembered set
+    objects: Vec<NonNull<GcHeader>>,
+    /// Writes are only recorded when there are no pauses. Writes are paused during minor GCs and
+    /// for the entirety of a major GC cycle, since the nursery is empty during a major GC.
+    num_pauses: usize,
+}
+
+impl RememberedSet {
+    /// Allocate a new empty remembered set. The remembered set is owned by the heap and must be
+    /// released with `RememberedSet::release`.
+    pub fn new() -> NonNull<RememberedSet> {
+        let remembered_set = Box::new(RememberedSet {
+            objects: Vec::new(),
+            num_pauses: 0,
+        });
+
+        NonNull::from(Box::leak(remembered_set))
+    }
+
+    /// Free a remembered set allocated with `RememberedSet::new`.
+    ///
+    /// # Safety
+    /// The remembered set must not be used after it is released.
+    pub unsafe fn release(remembered_set: NonNull<RememberedSet>) {
+        drop(Box::from_raw(remembered_set.as_ptr()));
+    }
+
+    /// Number of objects in the remembered set
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Remove every object from the remembered set, returning their headers. The objects are still
+    /// flagged as remembered, so they are not added again until their flag is cleared.
+    #[inline]
+    pub fn take(&mut self) -> Vec<NonNull<GcHeader>> {
+        core::mem::take(&mut self.objects)
+    }
+
+    /// Stop recording writes until a matching call to `resume`
+    #[inline]
+    pub fn pause(&mut self) {
+        self.num_pauses += 1;
+    }
+
+    /// Undo a previous call to `pause`
+    #[inline]
+    pub fn resume(&mut self) {
+        debug_assert!(self.num_pauses > 0);
+        self.num_pauses -= 1;
+    }
+
+    /// Add the object with the given header to the remembered set of its heap if it is in the old
+    /// generation, is not already remembered, and writes are currently being recorded.
+    ///
+    /// Does nothing for objects in heaps without a nursery.
+    ///
+    /// # Safety
+    /// The header must belong to a live object. Only the flags of the header are read before
+    /// checking whether writes are recorded, so this does not race with GC threads that
+    /// atomically update the header's color.
+    #[inline]
+    pub unsafe fn remember(header: *mut GcHeader) {
+        if !GcHeader::is_unremembered_old_object(header) {
+            return;
+        }
+
+        let remembered_set = if GcHeader::is_large_object(header) {
+            LargeObjectPrefix::for_header(header).remembered_set
+        } else {
+            (*Page::for_cell(header as *const u8).as_ptr()).remembered_set()
+        };
+
+        let remembered_set = match remembered_set {
+            Some(remembered_set) => &mut *remembered_set.as_ptr(),
+            None => return,
+        };
+
+        if remembered_set.num_pauses == 0 {
+            (*header).set_remembered(true);
+            remembered_set.objects.push(NonNull::new_unchecked(header));
+        }
+    }
+}
+
+/// Stored immediately before the header of every large object, since large objects do not belong
+/// to a page.
+#[repr(C)]
+pub struct LargeObjectPrefix {
+    /// The remembered set of the heap that owns the object, if the heap has a nursery
+    remembered_set: Option<NonNull<RememberedSet>>,
+}
+
+impl LargeObjectPrefix {
+    const SIZE: usize = core::mem::size_of::<LargeObjectPrefix>();
+
+    /// Layout of a large object's allocation, including the prefix, given the layout of the object
+    /// and its header.
+    #[inline]
+    fn layout(object_layout: Layout) -> Layout {
+        debug_assert_eq!(Self::SIZE % GcHeader::ALIGN, 0);
+        Layout::from_size_align(Self::SIZE + object_layout.size(), object_layout.align()).unwrap()
+    }
+
+    /// Get the prefix of the large object with the given header.
+    ///
+    /// # Safety
+    /// The header must belong to a large object.
+    #[inline]
+    unsafe fn for_header<'a>(header: *const GcHeader) -> &'a LargeObjectPrefix {
+        &*((header as *const u8).sub(Self::SIZE) as *const LargeObjectPrefix)
+    }
+
+    /// Allocate a large object with the given layout (including its header), preceded by a prefix.
+    /// Returns the uninitialized header of the object, or None if the allocation failed.
+    ///
+    /// # Safety
+    /// The header must be initialized and marked as large before the object is used.
+    pub unsafe fn alloc(
+        object_layout: Layout,
+        remembered_set: Option<NonNull<RememberedSet>>,
+    ) -> Option<NonNull<GcHeader>> {
+        let prefix = alloc::alloc::alloc(Self::layout(object_layout)) as *mut LargeObjectPrefix;
+        if prefix.is_null() {
+            return None;
+        }
+
+        prefix.write(LargeObjectPrefix { remembered_set });
+
+        Some(NonNull::new_unchecked(
+            (prefix as *mut u8).add(Self::SIZE) as *mut GcHeader
+        ))
+    }
+
+    /// Free a large object allocated with `LargeObjectPrefix::alloc`.
+    ///
+    /// # Safety
+    /// The header must belong to a large object, and the object must not be used after it is
+    /// freed.
+    pub unsafe fn free(header: NonNull<GcHeader>) {
+        let object_layout = GcHeader::layout_for_size((*header.as_ptr()).alloc_size());
+        let prefix = (header.as_ptr() as *mut u8).sub(Self::SIZE);
+        alloc::alloc::dealloc(prefix, Self::layout(object_layout));
+    }
+}
@@ -0,0 +1,32 @@
+//! GC statistics - history of completed collection cycles
+
+/// Number of completed GC cycles kept in the heap's GC history
+pub const GC_HISTORY_LENGTH: usize = 64;
+
+/// The kind of a garbage collection cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GcCycleKind {
+    /// Minor GC that evacuated the nursery
+    Minor,
+    /// Full mark-sweep collection of the old generation
+    Major,
+}
+
+/// Statistics for a single completed GC cycle
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GcCycleStats {
+    pub kind: GcCycleKind,
+    /// Total time spent in GC work for this cycle in milliseconds, as measured by
+    /// `GcContext::current_time_millis`. Incremental cycles sum the time spent in every step.
+    pub pause_millis: f64,
+    /// Longest single pause of this cycle in milliseconds. Equal to `pause_millis` for minor GCs,
+    /// which are never incremental.
+    pub max_pause_millis: f64,
+    /// Number of bytes freed by this cycle, including object headers
+    pub bytes_freed: usize,
+    /// Number of objects freed by this cycle. Always zero for minor GCs, since dead young objects
+    /// are never visited.
+    pub objects_freed: usize,
+    /// Number of bytes promoted to the old generation. Always zero for major GCs.
+    pub bytes_promoted: usize,
+}
@@ -5,7 +5,16 @@
 use alloc::vec::Vec;
 
 use crate::visitor::{GcContext, GcVisitor};
-use crate::{GcPhase, GcPtr, Heap};
+use crate::{
+    GcCycleKind, GcHeader, GcPacing, GcPhase, GcPtr, Heap, DEFAULT_NURSERY_SIZE, GC_HISTORY_LENGTH,
+    MAX_NURSERY_OBJECT_SIZE, MIN_ALLOCATION_HEADROOM, TIME_CHECK_INTERVAL,
+};
+
+#[cfg(feature = "parallel_gc")]
+use crate::{GcColor, ParallelGcOptions, PARALLEL_MARK_MIN_WORK};
+
+#[cfg(feature = "heap_verify")]
+use crate::{HeapVerifyError, HeapVerifyProblem};
 
 /// A simple test object that can hold references to other objects
 #[repr(C)]
@@ -81,7 +90,7 @@ impl GcContext for TestContext {
         }
     }
 
-    fn trace_object(&mut self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
+    fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
         // Check if this is a WeakRefObject - don't trace weak targets
         for weak_ref in &self.weak_refs {
             if weak_ref.as_ptr() as *mut u8 == object_ptr {
@@ -848,3 +857,1167 @@ fn test_weak_map_key_survives_externally() {
     // Steps: 1 (marking: 2 objects) + 1 (weak refs) + 1 (sweeping)
     assert_eq!(steps, 3, "weak map with rooted key should take 3 steps");
 }
+
+// ============================================================================
+// Page allocator tests
+// ============================================================================
+
+#[test]
+fn test_freed_cells_are_reused() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    let rooted = heap.alloc::<TestObject>(&mut ctx).unwrap();
+    unsafe {
+        rooted.as_ptr().write(TestObject::new(1));
+    }
+    let garbage = heap.alloc::<TestObject>(&mut ctx).unwrap();
+    let garbage_addr = garbage.as_ptr() as usize;
+    ctx.add_root(rooted);
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 1);
+    assert_eq!(heap.num_pages(), 1);
+
+    // The cell of the collected object is reused by the next allocation of the same size class
+    let reused = heap.alloc::<TestObject>(&mut ctx).unwrap();
+    assert_eq!(reused.as_ptr() as usize, garbage_addr);
+    assert_eq!(heap.num_pages(), 1);
+    assert_eq!(rooted.value, 1);
+}
+
+#[test]
+fn test_empty_pages_released() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    // Enough objects to fill several pages
+    for i in 0..20000 {
+        let obj = heap.alloc::<TestObject>(&mut ctx).unwrap();
+        unsafe {
+            obj.as_ptr().write(TestObject::new(i));
+        }
+    }
+
+    assert!(heap.num_pages() > 1);
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    assert_eq!(heap.num_objects(), 0);
+    assert_eq!(heap.bytes_allocated(), 0);
+    assert_eq!(heap.num_pages(), 0);
+}
+
+#[test]
+fn test_empty_pages_released_between_live_pages() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    // Fill several pages, keeping only the first and last objects alive
+    let mut objects = Vec::new();
+    for i in 0..20000 {
+        let obj = heap.alloc::<TestObject>(&mut ctx).unwrap();
+        unsafe {
+            obj.as_ptr().write(TestObject::new(i));
+        }
+        objects.push(obj);
+    }
+
+    assert!(heap.num_pages() > 2);
+    ctx.roots.push(objects[0]);
+    ctx.roots.push(objects[objects.len() - 1]);
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    // Only the pages holding live objects remain, and they can still be released later
+    assert_eq!(heap.num_objects(), 2);
+    assert_eq!(heap.num_pages(), 2);
+
+    ctx.roots.remove(0);
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_pages(), 1);
+
+    ctx.roots.clear();
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_pages(), 0);
+}
+
+#[test]
+fn test_large_object_space() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    let size = 64 * 1024;
+    let _large = heap.alloc_with_size::<u8>(&mut ctx, size, 8).unwrap();
+
+    // Large objects are not allocated in pages
+    assert_eq!(heap.num_pages(), 0);
+    assert_eq!(heap.num_objects(), 1);
+    assert_eq!(heap.bytes_allocated(), GcHeader::SIZE + size);
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    assert_eq!(heap.num_objects(), 0);
+    assert_eq!(heap.bytes_allocated(), 0);
+}
+
+#[test]
+fn test_bytes_allocated_updated_during_sweep() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    for _ in 0..300 {
+        heap.alloc::<TestObject>(&mut ctx).unwrap();
+    }
+
+    let bytes_before = heap.bytes_allocated();
+
+    heap.start_gc(&mut ctx);
+    while heap.phase() != GcPhase::Sweeping {
+        heap.gc_step(&mut ctx);
+    }
+
+    // A single sweep step frees some but not all objects
+    heap.gc_step(&mut ctx);
+    assert!(heap.gc_in_progress());
+    assert!(heap.bytes_allocated() < bytes_before);
+    assert!(heap.num_objects() > 0 && heap.num_objects() < 300);
+
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.bytes_allocated(), 0);
+}
+
+#[test]
+fn test_alloc_during_sweep_survives_next_cycle() {
+    let mut heap = Heap::new();
+    let mut ctx = TestContext::new();
+
+    for _ in 0..300 {
+        heap.alloc::<TestObject>(&mut ctx).unwrap();
+    }
+
+    heap.start_gc(&mut ctx);
+    while heap.phase() != GcPhase::Sweeping {
+        heap.gc_step(&mut ctx);
+    }
+
+    // Allocate small and large objects while sweeping is in progress
+    let obj = heap.alloc::<TestObject>(&mut ctx).unwrap();
+    unsafe {
+        obj.as_ptr().write(TestObject::new(7));
+    }
+    let _large = heap.alloc_with_size::<u8>(&mut ctx, 64 * 1024, 8).unwrap();
+    assert_eq!(heap.phase(), GcPhase::Sweeping);
+    ctx.add_root(obj);
+
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 2);
+
+    // Objects allocated during sweeping are white, so are traced and collected normally in the
+    // next cycle
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 1);
+    assert_eq!(obj.value, 7);
+}
+
+// ============================================================================
+// Generational GC tests
+// ============================================================================
+
+/// Test context for generational heaps. Objects may move during a minor GC, so all pointers are
+/// visited in place.
+struct GenerationalTestContext {
+    roots: Vec<GcPtr<TestObject>>,
+    /// Fake clock that advances by one millisecond every time it is read
+    clock: f64,
+}
+
+impl GenerationalTestContext {
+    fn new() -> Self {
+        GenerationalTestContext {
+            roots: Vec::new(),
+            clock: 0.0,
+        }
+    }
+}
+
+impl GcContext for GenerationalTestContext {
+    fn visit_roots(&mut self, visitor: &mut impl GcVisitor) {
+        for root in &mut self.roots {
+            visitor.visit(root);
+        }
+    }
+
+    fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
+        let object = unsafe { &mut *(object_ptr as *mut TestObject) };
+        visitor.visit_opt(&mut object.next);
+    }
+
+    fn current_time_millis(&mut self) -> f64 {
+        self.clock += 1.0;
+        self.clock
+    }
+}
+
+fn alloc_test_object(
+    heap: &mut Heap,
+    ctx: &mut GenerationalTestContext,
+    value: u64,
+    next: Option<GcPtr<TestObject>>,
+) -> GcPtr<TestObject> {
+    let ptr = heap.alloc::<TestObject>(ctx).unwrap();
+    unsafe {
+        ptr.as_ptr().write(TestObject { value, next });
+    }
+    ptr
+}
+
+/// Collect the values of a linked list of test objects
+fn list_values(head: GcPtr<TestObject>) -> Vec<u64> {
+    let mut values = Vec::new();
+    let mut current = Some(head);
+    while let Some(object) = current {
+        values.push(object.value);
+        current = object.next;
+    }
+    values
+}
+
+#[test]
+fn test_nursery_alloc() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+    assert!(heap.is_generational());
+
+    let ptr = alloc_test_object(&mut heap, &mut ctx, 42, None);
+
+    // Small objects are allocated in the nursery, not the old generation
+    assert!(heap.is_young(ptr));
+    assert_eq!(heap.num_objects(), 0);
+    assert_eq!(heap.bytes_allocated(), 0);
+    assert!(heap.nursery_bytes_allocated() > 0);
+}
+
+#[test]
+fn test_large_object_allocated_in_old_generation() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let ptr = heap
+        .alloc_with_size::<u8>(&mut ctx, MAX_NURSERY_OBJECT_SIZE + 1, 8)
+        .unwrap();
+
+    assert!(!heap.is_young(ptr));
+    assert_eq!(heap.num_objects(), 1);
+    assert_eq!(heap.nursery_bytes_allocated(), 0);
+}
+
+#[test]
+fn test_minor_gc_collects_unreachable() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    for i in 0..100 {
+        alloc_test_object(&mut heap, &mut ctx, i, None);
+    }
+
+    heap.minor_gc(&mut ctx);
+
+    // Nothing was promoted
+    assert_eq!(heap.num_minor_gcs, 1);
+    assert_eq!(heap.num_objects(), 0);
+    assert_eq!(heap.bytes_promoted_last_minor_gc, 0);
+    assert_eq!(heap.nursery_bytes_allocated(), 0);
+}
+
+#[test]
+fn test_minor_gc_promotes_reachable() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let c = alloc_test_object(&mut heap, &mut ctx, 3, None);
+    let b = alloc_test_object(&mut heap, &mut ctx, 2, Some(c));
+    let a = alloc_test_object(&mut heap, &mut ctx, 1, Some(b));
+    alloc_test_object(&mut heap, &mut ctx, 4, None);
+
+    ctx.roots.push(a);
+    heap.minor_gc(&mut ctx);
+
+    // Reachable objects were moved to the old generation and the root was updated
+    let head = ctx.roots[0];
+    assert!(!head.ptr_eq(&a));
+    assert!(!heap.is_young(head));
+    assert!(!heap.is_young(head.next.unwrap()));
+    assert_eq!(list_values(head), [1, 2, 3]);
+    assert_eq!(heap.num_objects(), 3);
+    assert!(heap.bytes_promoted_last_minor_gc > 0);
+}
+
+#[test]
+fn test_minor_gc_shared_object_promoted_once() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let shared = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    let a = alloc_test_object(&mut heap, &mut ctx, 2, Some(shared));
+    let b = alloc_test_object(&mut heap, &mut ctx, 3, Some(shared));
+
+    ctx.roots.push(a);
+    ctx.roots.push(b);
+    heap.minor_gc(&mut ctx);
+
+    // Both pointers to the shared object were rewritten to the same copy
+    assert_eq!(heap.num_objects(), 3);
+    assert!(ctx.roots[0]
+        .next
+        .unwrap()
+        .ptr_eq(&ctx.roots[1].next.unwrap()));
+}
+
+#[test]
+fn test_write_barrier_remembers_old_to_young_pointer() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    // Promote an object to the old generation
+    let old = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    ctx.roots.push(old);
+    heap.minor_gc(&mut ctx);
+    let mut old = ctx.roots[0];
+    assert!(!heap.is_young(old));
+
+    // Store a pointer to a young object that is only reachable from the old object
+    let young = alloc_test_object(&mut heap, &mut ctx, 2, None);
+    old.next = Some(young);
+    heap.write_barrier(old, young);
+
+    heap.minor_gc(&mut ctx);
+
+    let promoted = ctx.roots[0].next.unwrap();
+    assert!(!heap.is_young(promoted));
+    assert_eq!(promoted.value, 2);
+    assert_eq!(heap.num_objects(), 2);
+}
+
+#[test]
+fn test_remember_written_object() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let old = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    ctx.roots.push(old);
+    heap.minor_gc(&mut ctx);
+    let mut old = ctx.roots[0];
+
+    // Conservative barrier does not need to know the stored pointer
+    let young = alloc_test_object(&mut heap, &mut ctx, 2, None);
+    Heap::remember_written_object(old);
+    old.next = Some(young);
+
+    heap.minor_gc(&mut ctx);
+
+    assert_eq!(list_values(ctx.roots[0]), [1, 2]);
+    assert_eq!(heap.num_objects(), 2);
+}
+
+#[test]
+fn test_remembered_set_only_contains_written_objects() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    for i in 0..100 {
+        let object = alloc_test_object(&mut heap, &mut ctx, i, None);
+        ctx.roots.push(object);
+    }
+
+    heap.minor_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 100);
+    assert_eq!(heap.num_remembered_objects(), 0);
+
+    // Each object is only added once, no matter how many times it is written
+    let old = ctx.roots[50];
+    Heap::remember_written_object(old);
+    Heap::remember_written_object(old);
+    assert_eq!(heap.num_remembered_objects(), 1);
+
+    // Young objects are never remembered
+    let young = alloc_test_object(&mut heap, &mut ctx, 100, None);
+    Heap::remember_written_object(young);
+    assert_eq!(heap.num_remembered_objects(), 1);
+
+    // The remembered set is emptied by a minor GC
+    heap.minor_gc(&mut ctx);
+    assert_eq!(heap.num_remembered_objects(), 0);
+}
+
+#[test]
+fn test_remember_large_object() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let young = alloc_test_object(&mut heap, &mut ctx, 2, None);
+
+    // Large objects start out in the remembered set, so they can be initialized with pointers to
+    // young objects without a write barrier
+    let large = heap
+        .alloc_with_size::<TestObject>(&mut ctx, MAX_NURSERY_OBJECT_SIZE + 1, 8)
+        .unwrap();
+    unsafe {
+        large.as_ptr().write(TestObject {
+            value: 1,
+            next: Some(young),
+        })
+    };
+    ctx.roots.push(large);
+    assert_eq!(heap.num_remembered_objects(), 1);
+
+    heap.minor_gc(&mut ctx);
+    assert_eq!(heap.num_remembered_objects(), 0);
+    assert_eq!(list_values(ctx.roots[0]), [1, 2]);
+    assert!(!heap.is_young(ctx.roots[0].next.unwrap()));
+
+    // Large objects are added to the remembered set again when written
+    let mut large = ctx.roots[0];
+    Heap::remember_written_object(large);
+    large.next = Some(alloc_test_object(&mut heap, &mut ctx, 3, None));
+    assert_eq!(heap.num_remembered_objects(), 1);
+
+    heap.minor_gc(&mut ctx);
+    assert_eq!(list_values(ctx.roots[0]), [1, 3]);
+    assert!(!heap.is_young(ctx.roots[0].next.unwrap()));
+}
+
+#[test]
+fn test_writes_not_remembered_during_major_gc() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let object = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    ctx.roots.push(object);
+    heap.minor_gc(&mut ctx);
+
+    // The nursery is empty for the entire major GC, so writes and old allocations are not recorded
+    heap.start_gc(&mut ctx);
+    Heap::remember_written_object(ctx.roots[0]);
+    let during_gc = alloc_test_object(&mut heap, &mut ctx, 2, None);
+    ctx.roots.push(during_gc);
+    assert_eq!(heap.num_remembered_objects(), 0);
+
+    heap.finish_gc(&mut ctx);
+
+    // Writes are recorded again once the major GC completes
+    Heap::remember_written_object(ctx.roots[0]);
+    assert_eq!(heap.num_remembered_objects(), 1);
+}
+
+#[test]
+fn test_pause_write_barrier() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let object = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    ctx.roots.push(object);
+    heap.minor_gc(&mut ctx);
+
+    heap.pause_write_barrier();
+    Heap::remember_written_object(ctx.roots[0]);
+    assert_eq!(heap.num_remembered_objects(), 0);
+    heap.resume_write_barrier();
+
+    Heap::remember_written_object(ctx.roots[0]);
+    assert_eq!(heap.num_remembered_objects(), 1);
+}
+
+#[test]
+fn test_full_nursery_triggers_minor_gc() {
+    let mut heap = Heap::new_generational(4096);
+    let mut ctx = GenerationalTestContext::new();
+
+    // Build a rooted list while also allocating garbage, so the nursery repeatedly fills up
+    let head = alloc_test_object(&mut heap, &mut ctx, 0, None);
+    ctx.roots.push(head);
+
+    for i in 1..=500 {
+        alloc_test_object(&mut heap, &mut ctx, 1000 + i, None);
+
+        // Allocation may move the current head, so only read it once allocation is complete
+        let mut new_head = alloc_test_object(&mut heap, &mut ctx, i, None);
+        new_head.next = Some(ctx.roots[0]);
+        ctx.roots[0] = new_head;
+    }
+
+    assert!(heap.num_minor_gcs > 0);
+
+    let values = list_values(ctx.roots[0]);
+    assert_eq!(values.len(), 501);
+    assert!(values.iter().rev().copied().eq(0..=500));
+}
+
+#[test]
+fn test_major_gc_empties_nursery() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let rooted = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    alloc_test_object(&mut heap, &mut ctx, 2, None);
+    ctx.roots.push(rooted);
+
+    heap.start_gc(&mut ctx);
+    assert_eq!(heap.nursery_bytes_allocated(), 0);
+
+    // Objects allocated during a major GC bypass the nursery
+    let during_gc = alloc_test_object(&mut heap, &mut ctx, 3, None);
+    assert!(!heap.is_young(during_gc));
+
+    heap.finish_gc(&mut ctx);
+
+    // Rooted object and the object allocated during GC survive
+    assert_eq!(heap.num_objects(), 2);
+    assert_eq!(ctx.roots[0].value, 1);
+}
+
+#[cfg(feature = "gc_stress_test")]
+#[test]
+fn test_gc_stress_minor_gc_every_allocation() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    heap.gc_stress_test = true;
+    let mut ctx = GenerationalTestContext::new();
+
+    let head = alloc_test_object(&mut heap, &mut ctx, 0, None);
+    ctx.roots.push(head);
+
+    for i in 1..=100 {
+        // Allocation may move the current head, so only read it once allocation is complete
+        let mut new_head = alloc_test_object(&mut heap, &mut ctx, i, None);
+        new_head.next = Some(ctx.roots[0]);
+        ctx.roots[0] = new_head;
+    }
+
+    // Every allocation after the first performed a minor GC
+    assert_eq!(heap.num_minor_gcs, 100);
+    assert_eq!(heap.num_objects(), 100);
+
+    let values = list_values(ctx.roots[0]);
+    assert!(values.iter().rev().copied().eq(0..=100));
+}
+
+// ============================================================================
+// GC statistics tests
+// ============================================================================
+
+#[test]
+fn test_gc_history_records_major_cycle() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+
+    for i in 0..10 {
+        alloc_test_object(&mut heap, &mut ctx, i, None);
+    }
+    let bytes_before = heap.bytes_allocated();
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    assert_eq!(heap.num_major_gcs, 1);
+    assert_eq!(heap.gc_history().len(), 1);
+
+    let cycle = heap.gc_history()[0];
+    assert_eq!(cycle.kind, GcCycleKind::Major);
+    assert_eq!(cycle.objects_freed, 10);
+    assert_eq!(cycle.bytes_freed, bytes_before);
+    assert_eq!(cycle.bytes_promoted, 0);
+    assert!(cycle.pause_millis > 0.0);
+}
+
+#[test]
+fn test_gc_history_records_minor_cycle() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    let rooted = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    alloc_test_object(&mut heap, &mut ctx, 2, None);
+    ctx.roots.push(rooted);
+
+    heap.minor_gc(&mut ctx);
+
+    let cycle = *heap.gc_history().back().unwrap();
+    assert_eq!(cycle.kind, GcCycleKind::Minor);
+    assert_eq!(cycle.bytes_promoted, heap.bytes_allocated());
+    assert!(cycle.bytes_freed > 0);
+    assert!(cycle.pause_millis > 0.0);
+}
+
+#[test]
+fn test_gc_history_is_bounded() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+
+    for _ in 0..GC_HISTORY_LENGTH + 5 {
+        heap.start_gc(&mut ctx);
+        heap.finish_gc(&mut ctx);
+    }
+
+    assert_eq!(heap.num_major_gcs, GC_HISTORY_LENGTH + 5);
+    assert_eq!(heap.gc_history().len(), GC_HISTORY_LENGTH);
+}
+
+#[test]
+fn test_for_each_object() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = GenerationalTestContext::new();
+
+    // One object in the old generation, one in the nursery, and one in the large object space
+    let old = alloc_test_object(&mut heap, &mut ctx, 1, None);
+    ctx.roots.push(old);
+    heap.minor_gc(&mut ctx);
+    alloc_test_object(&mut heap, &mut ctx, 2, None);
+    heap.alloc_with_size::<u8>(&mut ctx, MAX_NURSERY_OBJECT_SIZE + 1, 8)
+        .unwrap();
+
+    let mut num_objects = 0;
+    let mut total_size = 0;
+    heap.for_each_object(|header| {
+        num_objects += 1;
+        total_size += header.total_size();
+    });
+
+    assert_eq!(num_objects, 3);
+    assert_eq!(
+        total_size,
+        heap.bytes_allocated() + GcHeader::SIZE + core::mem::size_of::<TestObject>()
+    );
+}
+
+// ============================================================================
+// GC pacing tests
+// ============================================================================
+
+/// Allocate unreachable objects and start a GC, stepping until the heap is sweeping
+fn start_sweeping_unreachable_objects(
+    heap: &mut Heap,
+    ctx: &mut GenerationalTestContext,
+    num_objects: u64,
+) {
+    for i in 0..num_objects {
+        alloc_test_object(heap, ctx, i, None);
+    }
+
+    heap.start_gc(ctx);
+    while heap.phase() != GcPhase::Sweeping {
+        heap.gc_step(ctx);
+    }
+}
+
+#[test]
+fn test_step_time_limit() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    start_sweeping_unreachable_objects(&mut heap, &mut ctx, 1000);
+
+    // The fake clock advances on every read, so the step stops after the second time check
+    heap.set_pacing(GcPacing {
+        step_time_limit_millis: Some(2.0),
+        ..GcPacing::DEFAULT
+    });
+    heap.gc_step(&mut ctx);
+
+    assert_eq!(heap.num_objects(), 1000 - 2 * TIME_CHECK_INTERVAL);
+
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 0);
+}
+
+#[test]
+fn test_step_work_limit() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    start_sweeping_unreachable_objects(&mut heap, &mut ctx, 1000);
+
+    heap.set_pacing(GcPacing {
+        step_work_limit: 10,
+        ..GcPacing::DEFAULT
+    });
+    heap.gc_step(&mut ctx);
+
+    assert_eq!(heap.num_objects(), 990);
+}
+
+#[test]
+fn test_allocation_driven_steps_are_paced() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+
+    for i in 0..1000 {
+        alloc_test_object(&mut heap, &mut ctx, i, None);
+    }
+
+    heap.start_gc(&mut ctx);
+
+    // Each allocation only does a small amount of work, but the cycle finishes before the heap
+    // grows past the allocation headroom.
+    let mut num_allocs = 0;
+    let mut bytes_allocated_during_gc = 0;
+    while heap.gc_in_progress() {
+        let object = alloc_test_object(&mut heap, &mut ctx, 0, None);
+        ctx.roots.push(object);
+
+        num_allocs += 1;
+        bytes_allocated_during_gc +=
+            unsafe { GcHeader::from_object_ptr(object.as_ptr()) }.total_size();
+    }
+
+    assert!(num_allocs > 1000 / GcPacing::DEFAULT.step_work_limit);
+    assert!(bytes_allocated_during_gc <= MIN_ALLOCATION_HEADROOM);
+    assert_eq!(heap.num_objects(), num_allocs);
+}
+
+#[test]
+fn test_gc_step_until_deadline() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    start_sweeping_unreachable_objects(&mut heap, &mut ctx, 1000);
+
+    // Not enough time to finish sweeping
+    let deadline = ctx.clock + 3.0;
+    assert!(heap.gc_step_until(&mut ctx, deadline));
+    assert!(heap.num_objects() > 0 && heap.num_objects() < 1000);
+
+    // No work is done once the deadline has passed
+    let num_objects = heap.num_objects();
+    assert!(heap.gc_step_until(&mut ctx, 0.0));
+    assert_eq!(heap.num_objects(), num_objects);
+
+    let deadline = ctx.clock + 1000.0;
+    assert!(!heap.gc_step_until(&mut ctx, deadline));
+    assert_eq!(heap.num_objects(), 0);
+}
+
+#[test]
+fn test_max_pause_recorded() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+
+    for i in 0..1000 {
+        alloc_test_object(&mut heap, &mut ctx, i, None);
+    }
+
+    heap.set_pacing(GcPacing {
+        step_time_limit_millis: Some(1000.0),
+        ..GcPacing::DEFAULT
+    });
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    let cycle = heap.gc_history()[0];
+    assert!(cycle.max_pause_millis > 0.0);
+    assert!(cycle.max_pause_millis < cycle.pause_millis);
+    assert_eq!(heap.max_pause_millis, cycle.max_pause_millis);
+}
+
+// ============================================================================
+// Parallel GC tests
+// ============================================================================
+
+/// Allocate rooted linked lists of test objects, with an unreachable object allocated before each
+/// list element
+#[cfg(feature = "parallel_gc")]
+fn alloc_rooted_lists(
+    heap: &mut Heap,
+    ctx: &mut GenerationalTestContext,
+    num_lists: usize,
+    list_length: u64,
+) {
+    for _ in 0..num_lists {
+        let mut head = None;
+        for i in 0..list_length {
+            alloc_test_object(heap, ctx, i, None);
+            head = Some(alloc_test_object(heap, ctx, i, head));
+        }
+        ctx.roots.push(head.unwrap());
+    }
+}
+
+/// Number of objects reachable from the roots. Lists must not share objects.
+#[cfg(feature = "parallel_gc")]
+fn num_reachable_objects(ctx: &GenerationalTestContext) -> usize {
+    ctx.roots.iter().map(|root| list_values(*root).len()).sum()
+}
+
+#[cfg(feature = "parallel_gc")]
+#[test]
+fn test_parallel_marking() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    heap.set_parallel_options(ParallelGcOptions {
+        marking_threads: 4,
+        background_sweeping: false,
+    });
+
+    alloc_rooted_lists(&mut heap, &mut ctx, 8, 1000);
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    assert_eq!(heap.num_objects(), 8000);
+    for root in &ctx.roots {
+        assert!(list_values(*root).iter().rev().copied().eq(0..1000));
+    }
+}
+
+/// Context that checks that parallel marking only traces copies of objects, never the objects
+/// in the heap. Heap verification traces the objects in the heap, so the check is disabled with
+/// the `heap_verify` feature.
+#[cfg(all(feature = "parallel_gc", not(feature = "heap_verify")))]
+struct CopyCheckingTestContext {
+    inner: GenerationalTestContext,
+    heap_objects: alloc::collections::BTreeSet<usize>,
+    num_traced: core::sync::atomic::AtomicUsize,
+}
+
+#[cfg(all(feature = "parallel_gc", not(feature = "heap_verify")))]
+impl GcContext for CopyCheckingTestContext {
+    fn visit_roots(&mut self, visitor: &mut impl GcVisitor) {
+        self.inner.visit_roots(visitor);
+    }
+
+    fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
+        assert!(!self.heap_objects.contains(&(object_ptr as usize)));
+        self.num_traced
+            .fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        self.inner.trace_object(object_ptr, visitor);
+    }
+}
+
+#[cfg(all(feature = "parallel_gc", not(feature = "heap_verify")))]
+#[test]
+fn test_parallel_marking_traces_copies() {
+    let mut heap = Heap::new();
+    let mut inner = GenerationalTestContext::new();
+    heap.set_parallel_options(ParallelGcOptions {
+        marking_threads: 4,
+        background_sweeping: false,
+    });
+
+    alloc_rooted_lists(&mut heap, &mut inner, 8, 1000);
+
+    let mut heap_objects = alloc::collections::BTreeSet::new();
+    heap.for_each_object(|header| {
+        heap_objects.insert(header.object_ptr() as usize);
+    });
+
+    let mut ctx = CopyCheckingTestContext {
+        inner,
+        heap_objects,
+        num_traced: core::sync::atomic::AtomicUsize::new(0),
+    };
+
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+
+    // Every reachable object was traced through a copy, and the copies reported the real pointers
+    assert_eq!(*ctx.num_traced.get_mut(), 8000);
+    assert_eq!(heap.num_objects(), 8000);
+    for root in &ctx.inner.roots {
+        assert!(list_values(*root).iter().rev().copied().eq(0..1000));
+    }
+}
+
+#[cfg(feature = "parallel_gc")]
+#[test]
+fn test_parallel_marking_work_limit() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    heap.set_parallel_options(ParallelGcOptions {
+        marking_threads: 4,
+        background_sweeping: false,
+    });
+    heap.set_pacing(GcPacing {
+        step_work_limit: PARALLEL_MARK_MIN_WORK,
+        ..GcPacing::DEFAULT
+    });
+
+    alloc_rooted_lists(&mut heap, &mut ctx, 8, 1000);
+
+    // Workers stop once the step's work limit is reached, and unmarked gray objects are kept
+    heap.start_gc(&mut ctx);
+    heap.gc_step(&mut ctx);
+    assert_eq!(heap.phase(), GcPhase::Marking);
+
+    let mut num_black = 0;
+    heap.for_each_object(|header| {
+        if header.color() == GcColor::Black {
+            num_black += 1;
+        }
+    });
+    assert_eq!(num_black, PARALLEL_MARK_MIN_WORK);
+
+    while heap.gc_step(&mut ctx) {}
+    assert_eq!(heap.num_objects(), 8000);
+}
+
+#[cfg(feature = "parallel_gc")]
+#[test]
+fn test_background_sweep() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    heap.set_parallel_options(ParallelGcOptions {
+        marking_threads: 1,
+        background_sweeping: true,
+    });
+
+    // Allocations do not advance the GC, so the sweep only completes in finish_gc
+    heap.set_pacing(GcPacing {
+        allocation_headroom_percent: usize::MAX,
+        ..GcPacing::DEFAULT
+    });
+
+    alloc_rooted_lists(&mut heap, &mut ctx, 4, 100);
+
+    // One reachable and one unreachable large object
+    let large = heap
+        .alloc_with_size::<TestObject>(&mut ctx, 64 * 1024, 8)
+        .unwrap();
+    unsafe { large.as_ptr().write(TestObject::new(7)) };
+    ctx.roots.push(large);
+    heap.alloc_with_size::<u8>(&mut ctx, 64 * 1024, 8).unwrap();
+
+    heap.start_gc(&mut ctx);
+    while heap.phase() != GcPhase::Sweeping {
+        heap.gc_step(&mut ctx);
+    }
+
+    // Allocate small and large objects while the sweeper is running
+    alloc_test_object(&mut heap, &mut ctx, 8, None);
+    heap.alloc_with_size::<u8>(&mut ctx, 64 * 1024, 8).unwrap();
+    assert_eq!(heap.phase(), GcPhase::Sweeping);
+
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 400 + 1 + 2);
+    assert_eq!(heap.gc_history()[0].objects_freed, 400 + 1);
+    assert_eq!(large.value, 7);
+
+    // Objects allocated during the background sweep are white, so are collected in the next cycle
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert_eq!(heap.num_objects(), 400 + 1);
+}
+
+#[cfg(all(feature = "parallel_gc", feature = "gc_stress_test"))]
+#[test]
+fn test_gc_stress_parallel_gc() {
+    let mut heap = Heap::new();
+    let mut ctx = GenerationalTestContext::new();
+    heap.set_parallel_options(ParallelGcOptions {
+        marking_threads: 4,
+        background_sweeping: true,
+    });
+    heap.set_pacing(GcPacing {
+        step_work_limit: PARALLEL_MARK_MIN_WORK,
+        ..GcPacing::DEFAULT
+    });
+
+    for round in 0..20 {
+        alloc_rooted_lists(&mut heap, &mut ctx, 4, 500);
+
+        // Drop the oldest lists so that every cycle has garbage to sweep
+        if ctx.roots.len() > 8 {
+            ctx.roots.drain(..4);
+        }
+
+        // Keep extending the first list while marking and sweeping are in progress
+        heap.start_gc(&mut ctx);
+        while heap.gc_in_progress() {
+            let head = ctx.roots[0];
+            ctx.roots[0] = alloc_test_object(&mut heap, &mut ctx, round, Some(head));
+            heap.gc_step(&mut ctx);
+        }
+
+        // Nothing reachable was freed, and a synchronous cycle frees everything else
+        assert!(heap.num_objects() >= num_reachable_objects(&ctx));
+        heap.start_gc(&mut ctx);
+        heap.finish_gc(&mut ctx);
+        assert_eq!(heap.num_objects(), num_reachable_objects(&ctx));
+    }
+}
+
+// ============================================================================
+// Heap verification tests
+// ============================================================================
+
+/// Test context that records heap verification errors instead of panicking
+#[cfg(feature = "heap_verify")]
+struct VerifyTestContext {
+    roots: Vec<GcPtr<TestObject>>,
+    /// Objects with this value are rejected by `verify_object`
+    invalid_value: Option<u64>,
+    errors: Vec<HeapVerifyError>,
+}
+
+#[cfg(feature = "heap_verify")]
+impl VerifyTestContext {
+    fn new() -> Self {
+        VerifyTestContext {
+            roots: Vec::new(),
+            invalid_value: None,
+            errors: Vec::new(),
+        }
+    }
+
+    fn alloc(
+        &mut self,
+        heap: &mut Heap,
+        value: u64,
+        next: Option<GcPtr<TestObject>>,
+    ) -> GcPtr<TestObject> {
+        let ptr = heap.alloc::<TestObject>(self).unwrap();
+        unsafe {
+            ptr.as_ptr().write(TestObject { value, next });
+        }
+        ptr
+    }
+}
+
+#[cfg(feature = "heap_verify")]
+impl GcContext for VerifyTestContext {
+    fn visit_roots(&mut self, visitor: &mut impl GcVisitor) {
+        for root in &mut self.roots {
+            visitor.visit(root);
+        }
+    }
+
+    fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
+        let object = unsafe { &mut *(object_ptr as *mut TestObject) };
+        visitor.visit_opt(&mut object.next);
+    }
+
+    fn verify_object(
+        &self,
+        object_ptr: *mut u8,
+        _is_allocated: &dyn Fn(*const u8) -> bool,
+    ) -> Result<(), &'static str> {
+        let object = unsafe { &*(object_ptr as *const TestObject) };
+        if Some(object.value) == self.invalid_value {
+            return Err("invalid value");
+        }
+
+        Ok(())
+    }
+
+    fn report_heap_verify_error(&mut self, error: &HeapVerifyError) {
+        self.errors.push(*error);
+    }
+}
+
+#[cfg(feature = "heap_verify")]
+#[test]
+fn test_verify_valid_heap() {
+    let mut heap = Heap::new_generational(DEFAULT_NURSERY_SIZE);
+    let mut ctx = VerifyTestContext::new();
+    heap.set_pacing(GcPacing {
+        step_work_limit: 1,
+        ..GcPacing::DEFAULT
+    });
+
+    for i in 0..10 {
+        let tail = ctx.alloc(&mut heap, i, None);
+        let head = ctx.alloc(&mut heap, i, Some(tail));
+        ctx.roots.push(head);
+        ctx.alloc(&mut heap, i, None);
+    }
+
+    assert_eq!(heap.verify(&mut ctx), Ok(()));
+
+    // The heap is valid in every phase of an incremental cycle
+    heap.start_gc(&mut ctx);
+    while heap.gc_in_progress() {
+        assert_eq!(heap.verify(&mut ctx), Ok(()));
+        heap.gc_step(&mut ctx);
+    }
+
+    heap.minor_gc(&mut ctx);
+    assert_eq!(heap.verify(&mut ctx), Ok(()));
+    assert!(ctx.errors.is_empty());
+}
+
+#[cfg(feature = "heap_verify")]
+#[test]
+fn test_verify_black_to_white_pointer() {
+    let mut heap = Heap::new();
+    let mut ctx = VerifyTestContext::new();
+    heap.set_pacing(GcPacing {
+        step_work_limit: 1,
+        ..GcPacing::DEFAULT
+    });
+
+    let head = ctx.alloc(&mut heap, 1, None);
+    let unrooted = ctx.alloc(&mut heap, 2, None);
+    ctx.roots.push(head);
+
+    // Mark the head black, then store a pointer to a white object without a write barrier
+    heap.start_gc(&mut ctx);
+    heap.gc_step(&mut ctx);
+    assert_eq!(heap.phase(), GcPhase::Marking);
+    unsafe { (*head.as_ptr()).next = Some(unrooted) };
+
+    // The missing barrier is detected once marking completes
+    heap.gc_step(&mut ctx);
+    assert_eq!(heap.phase(), GcPhase::WeakRefProcessing);
+
+    assert_eq!(ctx.errors.len(), 1);
+    let error = ctx.errors[0];
+    assert_eq!(error.problem, HeapVerifyProblem::BlackToWhitePointer);
+    assert_eq!(error.source, Some(head.as_ptr() as *mut u8));
+    assert_eq!(error.target, unrooted.as_ptr() as *mut u8);
+    assert_eq!(error.field_offset(), Some(core::mem::size_of::<u64>()));
+}
+
+#[cfg(feature = "heap_verify")]
+#[test]
+fn test_verify_dangling_root() {
+    let mut heap = Heap::new();
+    let mut ctx = VerifyTestContext::new();
+    ctx.alloc(&mut heap, 1, None);
+
+    // A root that points outside the heap
+    let mut not_allocated = TestObject::new(0);
+    let not_allocated_ptr = GcPtr::from_ptr(&mut not_allocated as *mut TestObject);
+    ctx.roots.push(not_allocated_ptr);
+
+    let error = heap.verify(&mut ctx).unwrap_err();
+    assert_eq!(error.problem, HeapVerifyProblem::DanglingPointer);
+    assert_eq!(error.source, None);
+    assert_eq!(error.target, not_allocated_ptr.as_ptr() as *mut u8);
+    assert_eq!(error.field_offset(), None);
+}
+
+#[cfg(feature = "heap_verify")]
+#[test]
+fn test_verify_invalid_object() {
+    let mut heap = Heap::new();
+    let mut ctx = VerifyTestContext::new();
+
+    let invalid = ctx.alloc(&mut heap, 13, None);
+    let head = ctx.alloc(&mut heap, 1, Some(invalid));
+    ctx.roots.push(head);
+    assert_eq!(heap.verify(&mut ctx), Ok(()));
+
+    ctx.invalid_value = Some(13);
+    let error = heap.verify(&mut ctx).unwrap_err();
+    assert_eq!(
+        error.problem,
+        HeapVerifyProblem::InvalidObject("invalid value")
+    );
+    assert_eq!(error.source, Some(invalid.as_ptr() as *mut u8));
+
+    // Problems found during GC are reported to the context
+    heap.start_gc(&mut ctx);
+    heap.finish_gc(&mut ctx);
+    assert!(!ctx.errors.is_empty());
+}
@@ -0,0 +1,220 @@
+//! Heap verifier - debug checks for heap corruption
+//!
+//! Only available with the `heap_verify` feature. The heap is verified automatically after marking
+//! completes and after every GC cycle, and can be verified on demand with `Heap::verify`.
+//!
+//! The verifier walks every live object, i.e. every object reachable from the roots, and checks
+//! that:
+//! - Every allocated object has a GC header that is consistent with where the object is allocated
+//!   and with the current GC phase
+//! - Every live object is accepted by `GcContext::verify_object`
+//! - Every root and every pointer traced from a live object targets the start of an allocated
+//!   object
+//! - After marking, no black object points to a white object. A black to white pointer means that
+//!   a write barrier is missing, and the white object will be freed while still reachable.
+//!
+//! Weak pointers are not checked, since they may refer to dead objects until weak references are
+//! processed.
+
+use alloc::vec::Vec;
+
+use crate::{
+    gc_header::{GcColor, GcHeader, GcPhase},
+    visitor::{GcContext, GcVisitor},
+    GcPtr, Heap,
+};
+
+/// The kind of problem found by the heap verifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeapVerifyProblem {
+    /// An object's GC header is inconsistent with where the object is allocated or the GC phase
+    InvalidHeader,
+    /// The object was rejected by `GcContext::verify_object`
+    InvalidObject(&'static str),
+    /// A pointer does not point to the start of an allocated object
+    DanglingPointer,
+    /// A black object points to a white object after marking has completed
+    BlackToWhitePointer,
+}
+
+/// A problem found by the heap verifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapVerifyError {
+    pub problem: HeapVerifyProblem,
+    /// The object that contains the bad pointer or is itself invalid. None if the bad pointer is a
+    /// root.
+    pub source: Option<*mut u8>,
+    /// Address of the bad pointer, either within the source object or a root. Null if the source
+    /// object itself is invalid.
+    pub field: *const u8,
+    /// The target of the bad pointer, or the invalid object itself
+    pub target: *mut u8,
+}
+
+impl HeapVerifyError {
+    /// Offset of the bad pointer within the source object, if the pointer is stored in the source
+    pub fn field_offset(&self) -> Option<usize> {
+        let source = self.source?;
+        let offset = (self.field as usize).checked_sub(source as usize)?;
+        let alloc_size = unsafe { GcHeader::from_object_ptr(source).alloc_size() };
+
+        if offset < alloc_size {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+}
+
+/// Result of verifying the heap
+pub type HeapVerifyResult = Result<(), HeapVerifyError>;
+
+/// Sorted addresses of every allocated object
+struct ObjectSet(Vec<usize>);
+
+impl ObjectSet {
+    fn index_of(&self, object_ptr: *const u8) -> Option<usize> {
+        self.0.binary_search(&(object_ptr as usize)).ok()
+    }
+
+    fn contains(&self, object_ptr: *const u8) -> bool {
+        self.index_of(object_ptr).is_some()
+    }
+}
+
+impl Heap {
+    /// Check the heap for corruption, returning the first problem found.
+    ///
+    /// Must not be called during a minor GC.
+    pub fn verify(&mut self, ctx: &mut impl GcContext) -> HeapVerifyResult {
+        let phase = self.phase();
+
+        let mut addresses = Vec::with_capacity(self.num_objects());
+        self.for_each_object(|header| addresses.push(header.object_ptr() as usize));
+
+        for &address in &addresses {
+            let object_ptr = address as *mut u8;
+            let header = unsafe { GcHeader::from_object_ptr(object_ptr) };
+            let is_valid = header.forwarding_address().is_none()
+                && header.is_young() == self.is_young(GcPtr::from_ptr(object_ptr))
+                && (phase != GcPhase::Idle || header.color() == GcColor::White);
+
+            if !is_valid {
+                return Err(HeapVerifyError {
+                    problem: HeapVerifyProblem::InvalidHeader,
+                    source: Some(object_ptr),
+                    field: core::ptr::null(),
+                    target: object_ptr,
+                });
+            }
+        }
+
+        addresses.sort_unstable();
+        let objects = ObjectSet(addresses);
+
+        let mut visitor = VerifyVisitor {
+            is_live: alloc::vec![false; objects.0.len()],
+            objects: &objects,
+            worklist: Vec::new(),
+            source: None,
+            check_black_to_white: false,
+            error: None,
+        };
+
+        ctx.visit_roots(&mut visitor);
+
+        // Objects are checked before they are traced, since tracing an invalid object could read
+        // arbitrary memory.
+        loop {
+            if let Some(error) = visitor.error {
+                return Err(error);
+            }
+
+            let object_ptr = match visitor.worklist.pop() {
+                Some(object_ptr) => object_ptr,
+                None => return Ok(()),
+            };
+
+            ctx.verify_object(object_ptr, &|ptr| objects.contains(ptr))
+                .map_err(|message| HeapVerifyError {
+                    problem: HeapVerifyProblem::InvalidObject(message),
+                    source: Some(object_ptr),
+                    field: core::ptr::null(),
+                    target: object_ptr,
+                })?;
+
+            let header = unsafe { GcHeader::from_object_ptr(object_ptr) };
+            visitor.source = Some(object_ptr);
+            visitor.check_black_to_white =
+                phase == GcPhase::WeakRefProcessing && header.color() == GcColor::Black;
+            ctx.trace_object(object_ptr, &mut visitor);
+        }
+    }
+
+    /// Verify the heap and report any problem to the context
+    pub(crate) fn verify_and_report(&mut self, ctx: &mut impl GcContext) {
+        if let Err(error) = self.verify(ctx) {
+            ctx.report_heap_verify_error(&error);
+        }
+    }
+}
+
+/// Visitor that checks every pointer reported by a root or a traced object, and queues every newly
+/// found live object to be traced.
+struct VerifyVisitor<'a> {
+    objects: &'a ObjectSet,
+    /// Whether each object in the object set has been found to be live
+    is_live: Vec<bool>,
+    /// Live objects that have not been traced yet
+    worklist: Vec<*mut u8>,
+    /// The object being traced, or None if visiting roots
+    source: Option<*mut u8>,
+    /// Whether to check that the traced object does not point to white objects
+    check_black_to_white: bool,
+    /// The first problem found
+    error: Option<HeapVerifyError>,
+}
+
+impl VerifyVisitor<'_> {
+    fn record_error(&mut self, problem: HeapVerifyProblem, field: *const u8, target: *mut u8) {
+        if self.error.is_none() {
+            self.error = Some(HeapVerifyError {
+                problem,
+                source: self.source,
+                field,
+                target,
+            });
+        }
+    }
+}
+
+impl GcVisitor for VerifyVisitor<'_> {
+    fn visit<T>(&mut self, ptr: &mut GcPtr<T>) {
+        if ptr.is_dangling() {
+            return;
+        }
+
+        let field = ptr as *const GcPtr<T> as *const u8;
+        let target = ptr.as_ptr() as *mut u8;
+        let index = match self.objects.index_of(target) {
+            Some(index) => index,
+            None => {
+                self.record_error(HeapVerifyProblem::DanglingPointer, field, target);
+                return;
+            }
+        };
+
+        if self.check_black_to_white
+            && unsafe { GcHeader::from_object_ptr(target) }.color() == GcColor::White
+        {
+            self.record_error(HeapVerifyProblem::BlackToWhitePointer, field, target);
+        }
+
+        if !self.is_live[index] {
+            self.is_live[index] = true;
+            self.worklist.push(target);
+        }
+    }
+
+    fn visit_weak<T>(&mut self, _ptr: &mut GcPtr<T>) {}
+}
@@ -69,7 +69,7 @@ pub trait GcVisitor {
 ///         }
 ///     }
 ///     
-///     fn trace_object(&mut self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
+///     fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor) {
 ///         let header = unsafe { &*(object_ptr as *const HeapItemHeader) };
 ///         match header.class() {
 ///             HeapItemClass::String => { /* strings don't have pointers */ },
@@ -104,7 +104,16 @@ pub trait GcContext {
     /// 1. Determine the object's type (from HeapItemHeader or type tag)
     /// 2. Cast to the concrete type
     /// 3. Call `visit_pointers` on the object
-    fn trace_object(&mut self, object_ptr: *mut u8, visitor: &mut impl GcVisitor);
+    ///
+    /// Tracing must be read-only: it may only read the context and heap objects, and may only
+    /// report pointers through the visitor. It must not write to any object or header.
+    ///
+    /// With the `parallel_gc` feature, `trace_object` is called concurrently from several marking
+    /// threads while the mutator is paused. Marking threads trace a private copy of each object
+    /// instead of the object itself, so implementations may create mutable references to the
+    /// traced object but must not rely on its address or on its header. Implementations must not
+    /// rely on any state that is not safe to read from other threads.
+    fn trace_object(&self, object_ptr: *mut u8, visitor: &mut impl GcVisitor);
 
     /// Process weak references after marking is complete
     ///
@@ -121,4 +130,36 @@ pub trait GcContext {
         // Default: do nothing
         let _ = heap;
     }
+
+    /// Get the current time in milliseconds
+    ///
+    /// Used to measure GC pause durations. Only differences between times are used, so any
+    /// monotonic or wall clock time is acceptable. The default returns 0, so all pauses are
+    /// reported as taking no time.
+    fn current_time_millis(&mut self) -> f64 {
+        0.0
+    }
+
+    /// Check that an object is well formed before it is traced by the heap verifier
+    ///
+    /// `is_allocated` returns whether a pointer is the start of an object allocated in the heap.
+    /// Returns a description of the problem if the object is invalid. The default accepts every
+    /// object.
+    #[cfg(feature = "heap_verify")]
+    fn verify_object(
+        &self,
+        object_ptr: *mut u8,
+        is_allocated: &dyn Fn(*const u8) -> bool,
+    ) -> Result<(), &'static str> {
+        let _ = (object_ptr, is_allocated);
+        Ok(())
+    }
+
+    /// Report a problem found when the heap is verified automatically during GC
+    ///
+    /// The default panics, since the heap is corrupt and continuing is unsafe.
+    #[cfg(feature = "heap_verify")]
+    fn report_heap_verify_error(&mut self, error: &crate::HeapVerifyError) {
+        panic!("heap verification failed: {:?}", error);
+    }
 }
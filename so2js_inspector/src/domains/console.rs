@@ -0,0 +1,240 @@
+//! The Console domain, along with a `console` object that reports messages to the client.
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::json;
+
+use so2js::{
+    js_stack_scope_guard,
+    runtime::{
+        alloc_error::AllocResult, intrinsics::intrinsics::Intrinsic,
+        intrinsics::rust_runtime::RustRuntimeFunction, object_value::ObjectValue,
+        to_console_string, Context, EvalResult, PropertyKey, Realm, StackRoot, Value,
+    },
+};
+
+use crate::{remote_object, session::Session};
+
+use super::{CdpError, CdpResult, Request};
+
+/// Group for the arguments of console calls reported to the client
+pub const CONSOLE_GROUP: &str = "console";
+
+/// Maximum number of messages kept for replaying to clients that enable the Console domain later
+const MAX_CONSOLE_MESSAGES: usize = 1000;
+
+thread_local! {
+    /// Sessions of the inspectors on this thread, keyed by context. Console methods are plain
+    /// functions, so they find their session through the context they are called in.
+    static SESSIONS: RefCell<Vec<(usize, Weak<Session>)>> = RefCell::new(vec![]);
+}
+
+fn context_key(cx: Context) -> usize {
+    cx.as_ptr() as usize
+}
+
+pub fn register_session(cx: Context, session: &Rc<Session>) {
+    SESSIONS.with(|sessions| {
+        let mut sessions = sessions.borrow_mut();
+        sessions.retain(|(key, _)| *key != context_key(cx));
+        sessions.push((context_key(cx), Rc::downgrade(session)));
+    });
+}
+
+pub fn unregister_session(cx: Context) {
+    SESSIONS.with(|sessions| {
+        sessions
+            .borrow_mut()
+            .retain(|(key, _)| *key != context_key(cx));
+    });
+}
+
+fn session_for_context(cx: Context) -> Option<Rc<Session>> {
+    SESSIONS.with(|sessions| {
+        sessions
+            .borrow()
+            .iter()
+            .find(|(key, _)| *key == context_key(cx))
+            .and_then(|(_, session)| session.upgrade())
+    })
+}
+
+pub fn handle(session: &Session, request: &mut Request, method: &str) -> CdpResult {
+    match method {
+        "Console.enable" => {
+            let messages = {
+                let mut state = session.state();
+                state.is_console_enabled = true;
+                state.console_messages.clone()
+            };
+
+            for message in messages {
+                session.send_event("Console.messageAdded", json!({ "message": message }));
+            }
+
+            Ok(json!({}))
+        }
+        "Console.disable" => {
+            session.state().is_console_enabled = false;
+            Ok(json!({}))
+        }
+        "Console.clearMessages" => {
+            session.state().console_messages.clear();
+            remote_object::release_object_group(session, request.cx, CONSOLE_GROUP);
+            Ok(json!({}))
+        }
+        _ => Err(CdpError::method_not_found(method)),
+    }
+}
+
+/// Install a `console` object on the realm's global object.
+pub fn install(mut cx: Context, realm: StackRoot<Realm>) -> AllocResult<()> {
+    js_stack_scope_guard!(cx);
+
+    let mut console = ObjectValue::new(
+        cx,
+        Some(realm.get_intrinsic(Intrinsic::ObjectPrototype)),
+        true,
+    )?;
+
+    let methods: [(&str, RustRuntimeFunction); 5] = [
+        ("debug", debug),
+        ("error", error),
+        ("info", info),
+        ("log", log),
+        ("warn", warn),
+    ];
+
+    for (name, function) in methods.iter() {
+        let name = cx.alloc_string(name)?.as_string();
+        let key = PropertyKey::string(cx, name)?.to_stack(cx);
+        console.intrinsic_func(cx, key, *function, 0, realm)?;
+    }
+
+    let name = cx.alloc_string("console")?.as_string();
+    let key = PropertyKey::string(cx, name)?.to_stack(cx);
+    realm
+        .global_object(cx)
+        .intrinsic_data_prop(cx, key, console.to_stack(cx).into())
+}
+
+fn debug(
+    cx: Context,
+    _: StackRoot<Value>,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    console_call(cx, "debug", arguments)
+}
+
+fn error(
+    cx: Context,
+    _: StackRoot<Value>,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    console_call(cx, "error", arguments)
+}
+
+fn info(
+    cx: Context,
+    _: StackRoot<Value>,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    console_call(cx, "info", arguments)
+}
+
+fn log(
+    cx: Context,
+    _: StackRoot<Value>,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    console_call(cx, "log", arguments)
+}
+
+fn warn(
+    cx: Context,
+    _: StackRoot<Value>,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    console_call(cx, "warning", arguments)
+}
+
+/// Print the arguments of a console call, then report the call to the client.
+fn console_call(
+    cx: Context,
+    call_type: &str,
+    arguments: &[StackRoot<Value>],
+) -> EvalResult<StackRoot<Value>> {
+    let mut formatted = vec![];
+    for argument in arguments {
+        formatted.push(to_console_string(cx, *argument)?);
+    }
+
+    let text = formatted.join(" ");
+    if matches!(call_type, "error" | "warning") {
+        eprintln!("{}", text);
+    } else {
+        println!("{}", text);
+    }
+
+    if let Some(session) = session_for_context(cx) {
+        report_console_call(&session, cx, call_type, arguments, text)?;
+    }
+
+    Ok(cx.undefined())
+}
+
+fn report_console_call(
+    session: &Session,
+    cx: Context,
+    call_type: &str,
+    arguments: &[StackRoot<Value>],
+    text: String,
+) -> AllocResult<()> {
+    let message = json!({ "source": "console-api", "level": call_type, "text": text });
+
+    let (is_runtime_enabled, is_console_enabled) = {
+        let mut state = session.state();
+        if state.console_messages.len() >= MAX_CONSOLE_MESSAGES {
+            state.console_messages.remove(0);
+        }
+
+        state.console_messages.push(message.clone());
+        (state.is_runtime_enabled, state.is_console_enabled)
+    };
+
+    if is_console_enabled {
+        session.send_event("Console.messageAdded", json!({ "message": message }));
+    }
+
+    if is_runtime_enabled {
+        let mut args = vec![];
+        for argument in arguments {
+            args.push(remote_object::to_remote_object(
+                session,
+                cx,
+                *argument,
+                CONSOLE_GROUP,
+            )?);
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0.0, |duration| duration.as_secs_f64() * 1000.0);
+
+        session.send_event(
+            "Runtime.consoleAPICalled",
+            json!({
+                "type": call_type,
+                "args": args,
+                "executionContextId": 1,
+                "timestamp": timestamp,
+            }),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,437 @@
+//! The Debugger domain: scripts, breakpoints, stepping, and inspecting paused call frames.
+
+use regex::Regex;
+use serde_json::{json, Value as JsonValue};
+
+use so2js::runtime::{
+    alloc_error::AllocResult,
+    debugger::{BreakpointId, PauseOnExceptions, PauseReason, PausedState, ResumeAction},
+    error::BsError,
+    scope::ScopeKind,
+    Context,
+};
+
+use crate::{
+    remote_object::{self, BACKTRACE_GROUP},
+    session::{url_to_path, Script, Session},
+};
+
+use super::{CdpError, CdpResult, Request};
+
+/// A breakpoint set by the client. Breakpoints set by URL may apply to several scripts, including
+/// scripts that have not been loaded yet.
+pub struct UrlBreakpoint {
+    id: String,
+    url: Option<String>,
+    url_regex: Option<Regex>,
+    /// 0-indexed line
+    line: usize,
+    /// 0-indexed column
+    column: Option<usize>,
+    /// Breakpoints set in the runtime, one for each source file path the breakpoint applies to
+    pub runtime_ids: Vec<BreakpointId>,
+    paths: Vec<String>,
+}
+
+impl UrlBreakpoint {
+    fn matches(&self, script: &Script) -> bool {
+        if script.url.is_empty() {
+            return false;
+        }
+
+        match (&self.url, &self.url_regex) {
+            (Some(url), _) => *url == script.url,
+            (None, Some(url_regex)) => url_regex.is_match(&script.url),
+            (None, None) => false,
+        }
+    }
+
+    /// Set the breakpoint in the runtime for a source file path, if not already set.
+    fn set_for_path(&mut self, mut cx: Context, path: &str) {
+        if !self.paths.iter().any(|set_path| set_path == path) {
+            let id = cx.set_breakpoint(path, self.line + 1, self.column.map(|column| column + 1));
+            self.runtime_ids.push(id);
+            self.paths.push(path.to_owned());
+        }
+    }
+}
+
+pub fn handle(session: &Session, request: &mut Request, method: &str) -> CdpResult {
+    let mut cx = request.cx;
+
+    match method {
+        "Debugger.enable" => enable(session),
+        "Debugger.disable" => {
+            session.state().is_debugger_enabled = false;
+            Ok(json!({}))
+        }
+        "Debugger.setBreakpointByUrl" => set_breakpoint_by_url(session, request),
+        "Debugger.setBreakpoint" => set_breakpoint(session, request),
+        "Debugger.removeBreakpoint" => remove_breakpoint(session, request),
+        "Debugger.getPossibleBreakpoints" => Ok(json!({ "locations": [] })),
+        "Debugger.setBreakpointsActive" => {
+            session.state().breakpoints_active = request.params["active"].as_bool() == Some(true);
+            Ok(json!({}))
+        }
+        "Debugger.setSkipAllPauses" => {
+            session.state().skip_all_pauses = request.params["skip"].as_bool() == Some(true);
+            Ok(json!({}))
+        }
+        "Debugger.setPauseOnExceptions" => {
+            let pause_on_exceptions = match request.str_param("state")? {
+                "none" => PauseOnExceptions::None,
+                "uncaught" => PauseOnExceptions::Uncaught,
+                "caught" | "all" => PauseOnExceptions::All,
+                _ => return Err(CdpError::invalid_params("Unknown pause on exceptions mode")),
+            };
+
+            cx.set_pause_on_exceptions(pause_on_exceptions);
+            Ok(json!({}))
+        }
+        "Debugger.pause" => {
+            if request.paused.is_none() {
+                cx.request_pause();
+            }
+
+            Ok(json!({}))
+        }
+        "Debugger.resume" => resume(request, ResumeAction::Continue),
+        "Debugger.stepInto" => resume(request, ResumeAction::StepInto),
+        "Debugger.stepOver" => resume(request, ResumeAction::StepOver),
+        "Debugger.stepOut" => resume(request, ResumeAction::StepOut),
+        "Debugger.evaluateOnCallFrame" => evaluate_on_call_frame(session, request),
+        "Debugger.getScriptSource" => {
+            let script_id = request.str_param("scriptId")?;
+            let script = find_script_by_id(session, script_id)?;
+            Ok(json!({ "scriptSource": script.source }))
+        }
+        // Accepted but have no effect
+        "Debugger.setAsyncCallStackDepth"
+        | "Debugger.setBlackboxPatterns"
+        | "Debugger.setBlackboxedRanges" => Ok(json!({})),
+        _ => Err(CdpError::method_not_found(method)),
+    }
+}
+
+fn enable(session: &Session) -> CdpResult {
+    let scripts = {
+        let mut state = session.state();
+        state.is_debugger_enabled = true;
+        state.scripts.clone()
+    };
+
+    for script in scripts {
+        session.send_event("Debugger.scriptParsed", script_parsed_params(&script));
+    }
+
+    Ok(json!({ "debuggerId": "so2js" }))
+}
+
+fn set_breakpoint_by_url(session: &Session, request: &mut Request) -> CdpResult {
+    let cx = request.cx;
+    let params = &request.params;
+
+    let line = params["lineNumber"]
+        .as_u64()
+        .ok_or_else(|| CdpError::invalid_params("Missing lineNumber"))? as usize;
+    let column = params["columnNumber"]
+        .as_u64()
+        .map(|column| column as usize);
+
+    let (url, url_regex) = if let Some(url) = params["url"].as_str() {
+        (Some(url.to_owned()), None)
+    } else if let Some(url_regex) = params["urlRegex"].as_str() {
+        let url_regex =
+            Regex::new(url_regex).map_err(|_| CdpError::invalid_params("Invalid urlRegex"))?;
+        (None, Some(url_regex))
+    } else {
+        return Err(CdpError::invalid_params(
+            "Either url or urlRegex must be specified.",
+        ));
+    };
+
+    let mut breakpoint = UrlBreakpoint {
+        id: new_breakpoint_id(session, line, column),
+        url,
+        url_regex,
+        line,
+        column,
+        runtime_ids: vec![],
+        paths: vec![],
+    };
+
+    // Breakpoints for a URL are set immediately so that they are hit once the script loads
+    if let Some(url) = &breakpoint.url {
+        let path = url_to_path(url).to_owned();
+        breakpoint.set_for_path(cx, &path);
+    }
+
+    let scripts = session.state().scripts.clone();
+    let mut locations = vec![];
+    for script in scripts.iter().filter(|script| breakpoint.matches(script)) {
+        breakpoint.set_for_path(cx, &script.path);
+        locations.push(location(script, line, column));
+    }
+
+    let id = breakpoint.id.clone();
+    session.state().breakpoints.push(breakpoint);
+
+    Ok(json!({ "breakpointId": id, "locations": locations }))
+}
+
+fn set_breakpoint(session: &Session, request: &mut Request) -> CdpResult {
+    let location_param = &request.params["location"];
+    let script_id = location_param["scriptId"]
+        .as_str()
+        .ok_or_else(|| CdpError::invalid_params("Missing scriptId"))?;
+    let line = location_param["lineNumber"]
+        .as_u64()
+        .ok_or_else(|| CdpError::invalid_params("Missing lineNumber"))? as usize;
+    let column = location_param["columnNumber"]
+        .as_u64()
+        .map(|column| column as usize);
+
+    let script = find_script_by_id(session, script_id)?;
+
+    let mut breakpoint = UrlBreakpoint {
+        id: new_breakpoint_id(session, line, column),
+        url: None,
+        url_regex: None,
+        line,
+        column,
+        runtime_ids: vec![],
+        paths: vec![],
+    };
+    breakpoint.set_for_path(request.cx, &script.path);
+
+    let id = breakpoint.id.clone();
+    session.state().breakpoints.push(breakpoint);
+
+    Ok(json!({ "breakpointId": id, "actualLocation": location(&script, line, column) }))
+}
+
+fn remove_breakpoint(session: &Session, request: &mut Request) -> CdpResult {
+    let mut cx = request.cx;
+    let id = request.str_param("breakpointId")?;
+
+    let removed = {
+        let mut state = session.state();
+        let index = state
+            .breakpoints
+            .iter()
+            .position(|breakpoint| breakpoint.id == id);
+        index.map(|index| state.breakpoints.remove(index))
+    };
+
+    for runtime_id in removed
+        .into_iter()
+        .flat_map(|breakpoint| breakpoint.runtime_ids)
+    {
+        cx.remove_breakpoint(runtime_id);
+    }
+
+    Ok(json!({}))
+}
+
+fn new_breakpoint_id(session: &Session, line: usize, column: Option<usize>) -> String {
+    let mut state = session.state();
+    let id = state.next_breakpoint_id;
+    state.next_breakpoint_id += 1;
+
+    format!("{}:{}:{}", id, line, column.unwrap_or(0))
+}
+
+fn resume(request: &mut Request, action: ResumeAction) -> CdpResult {
+    request.paused()?;
+    request.resume = Some(action);
+    Ok(json!({}))
+}
+
+fn evaluate_on_call_frame(session: &Session, request: &mut Request) -> CdpResult {
+    let cx = request.cx;
+    let paused = request.paused()?;
+
+    let frame_index = request
+        .str_param("callFrameId")?
+        .parse::<usize>()
+        .ok()
+        .filter(|index| *index < paused.frames().len())
+        .ok_or_else(|| CdpError::invalid_params("Invalid call frame id"))?;
+    let expression = request.str_param("expression")?;
+
+    let result = paused
+        .evaluate(cx, frame_index, expression)
+        .map_err(BsError::from);
+
+    remote_object::evaluation_result(session, cx, result, &request.params)
+}
+
+fn find_script_by_id(session: &Session, script_id: &str) -> Result<std::rc::Rc<Script>, CdpError> {
+    let state = session.state();
+    let script = state.scripts.iter().find(|script| script.id == script_id);
+    script
+        .cloned()
+        .ok_or_else(|| CdpError::invalid_params("No script for id"))
+}
+
+fn location(script: &Script, line: usize, column: Option<usize>) -> JsonValue {
+    json!({
+        "scriptId": script.id,
+        "lineNumber": line,
+        "columnNumber": column.unwrap_or(0),
+    })
+}
+
+/// Called when a new script is compiled.
+pub fn on_script_parsed(session: &Session, cx: Context, script: &Script) {
+    if !session.state().is_debugger_enabled {
+        return;
+    }
+
+    session.send_event("Debugger.scriptParsed", script_parsed_params(script));
+
+    // Breakpoints set by URL regex must be set in the runtime for each new matching path
+    let mut breakpoints = std::mem::take(&mut session.state().breakpoints);
+    let mut resolved = vec![];
+    for breakpoint in breakpoints
+        .iter_mut()
+        .filter(|breakpoint| breakpoint.matches(script))
+    {
+        breakpoint.set_for_path(cx, &script.path);
+        resolved.push(json!({
+            "breakpointId": breakpoint.id,
+            "location": location(script, breakpoint.line, breakpoint.column),
+        }));
+    }
+
+    session.state().breakpoints.extend(breakpoints);
+
+    for params in resolved {
+        session.send_event("Debugger.breakpointResolved", params);
+    }
+}
+
+fn script_parsed_params(script: &Script) -> JsonValue {
+    let end_line = script.source.matches('\n').count();
+    let end_column = script.source.rsplit('\n').next().map_or(0, str::len);
+
+    json!({
+        "scriptId": script.id,
+        "url": script.url,
+        "startLine": 0,
+        "startColumn": 0,
+        "endLine": end_line,
+        "endColumn": end_column,
+        "executionContextId": 1,
+        "hash": format!("{:016x}", fnv1a_hash(script.source.as_bytes())),
+        "length": script.source.len(),
+        "sourceMapURL": "",
+    })
+}
+
+/// Content hash of a script, used by clients to detect changed scripts.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The params of a `Debugger.paused` event.
+pub fn paused_event(
+    session: &Session,
+    cx: Context,
+    paused: &PausedState,
+) -> AllocResult<JsonValue> {
+    let mut call_frames = vec![];
+
+    for (frame_index, frame) in paused.frames().iter().enumerate() {
+        // Frames without source, such as builtin functions, are not reported
+        let location = match frame.location(cx)? {
+            Some(location) => location,
+            None => continue,
+        };
+        let script = match session.find_script(cx, location.source_file) {
+            Some(script) => script,
+            None => continue,
+        };
+
+        let function_name = match frame.function_name(cx) {
+            Some(name) => name.format(cx)?,
+            None => String::new(),
+        };
+
+        // The innermost function scope holds the frame's locals, while enclosing function and
+        // lexical scopes are closures.
+        let mut scope_chain = vec![];
+        let mut is_in_closure = false;
+        for (scope_index, scope) in frame.scopes(cx).iter().enumerate() {
+            let scope_type = match scope.kind {
+                ScopeKind::Global => "global",
+                ScopeKind::Module => "module",
+                ScopeKind::With => "with",
+                ScopeKind::Lexical if is_in_closure => "closure",
+                ScopeKind::Lexical => "block",
+                ScopeKind::Function if is_in_closure => "closure",
+                ScopeKind::Function => {
+                    is_in_closure = true;
+                    "local"
+                }
+            };
+
+            scope_chain.push(json!({
+                "type": scope_type,
+                "object": {
+                    "type": "object",
+                    "className": "Object",
+                    "description": "Object",
+                    "objectId": format!("scope:{}:{}", frame_index, scope_index),
+                },
+            }));
+        }
+
+        call_frames.push(json!({
+            "callFrameId": frame_index.to_string(),
+            "functionName": function_name,
+            "location": location_in_script(&script, location.line, location.column),
+            "url": script.url,
+            "scopeChain": scope_chain,
+            "this": remote_object::to_remote_object(session, cx, frame.this(cx), BACKTRACE_GROUP)?,
+            "canBeRestarted": false,
+        }));
+    }
+
+    let mut params = json!({ "callFrames": call_frames, "reason": "other", "hitBreakpoints": [] });
+
+    match paused.reason() {
+        PauseReason::Breakpoint(runtime_id) => {
+            let state = session.state();
+            let hit_breakpoints = state
+                .breakpoints
+                .iter()
+                .filter(|breakpoint| breakpoint.runtime_ids.contains(runtime_id))
+                .map(|breakpoint| breakpoint.id.clone())
+                .collect::<Vec<_>>();
+
+            params["hitBreakpoints"] = json!(hit_breakpoints);
+        }
+        PauseReason::Exception { value, is_caught } => {
+            let mut data = remote_object::to_remote_object(session, cx, *value, BACKTRACE_GROUP)?;
+            data["uncaught"] = json!(!is_caught);
+
+            params["reason"] = json!("exception");
+            params["data"] = data;
+        }
+        PauseReason::Step | PauseReason::PauseRequested => {}
+    }
+
+    Ok(params)
+}
+
+/// A location from 1-indexed runtime lines and columns.
+fn location_in_script(script: &Script, line: usize, column: usize) -> JsonValue {
+    location(
+        script,
+        line.saturating_sub(1),
+        Some(column.saturating_sub(1)),
+    )
+}
@@ -0,0 +1,94 @@
+//! Handlers for the commands of each supported CDP domain.
+
+use serde_json::Value as JsonValue;
+
+use so2js::runtime::{
+    alloc_error::AllocError,
+    debugger::{PausedState, ResumeAction},
+    eval_result::EvalError,
+    Context,
+};
+
+use crate::session::Session;
+
+pub mod console;
+pub mod debugger;
+pub mod profiler;
+pub mod runtime;
+
+/// An error response to a command.
+pub struct CdpError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl CdpError {
+    pub fn method_not_found(method: &str) -> CdpError {
+        CdpError {
+            code: -32601,
+            message: format!("'{}' wasn't found", method),
+        }
+    }
+
+    pub fn invalid_params(message: &str) -> CdpError {
+        CdpError {
+            code: -32602,
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn server_error(message: &str) -> CdpError {
+        CdpError {
+            code: -32000,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl From<AllocError> for CdpError {
+    fn from(_: AllocError) -> Self {
+        CdpError::server_error("Ran out of heap memory")
+    }
+}
+
+/// Exceptions thrown while inspecting objects (e.g. by proxy traps) fail the command.
+impl From<EvalError> for CdpError {
+    fn from(_: EvalError) -> Self {
+        CdpError::server_error("Exception thrown while inspecting object")
+    }
+}
+
+pub type CdpResult = Result<JsonValue, CdpError>;
+
+/// A command received from the client.
+pub struct Request<'a> {
+    pub cx: Context,
+    /// The paused state if the command was received while paused
+    pub paused: Option<&'a PausedState>,
+    pub params: JsonValue,
+    /// Set by commands that resume execution
+    pub resume: Option<ResumeAction>,
+}
+
+impl Request<'_> {
+    pub fn str_param(&self, name: &str) -> Result<&str, CdpError> {
+        self.params[name].as_str().ok_or_else(|| {
+            CdpError::invalid_params(&format!("Missing string parameter '{}'", name))
+        })
+    }
+
+    pub fn paused(&self) -> Result<&PausedState, CdpError> {
+        self.paused
+            .ok_or_else(|| CdpError::server_error("Can only perform operation while paused."))
+    }
+}
+
+pub fn dispatch(session: &Session, request: &mut Request, method: &str) -> CdpResult {
+    match method.split_once('.').map(|(domain, _)| domain) {
+        Some("Console") => console::handle(session, request, method),
+        Some("Debugger") => debugger::handle(session, request, method),
+        Some("Profiler") => profiler::handle(session, request, method),
+        Some("Runtime") => runtime::handle(session, request, method),
+        _ => Err(CdpError::method_not_found(method)),
+    }
+}
@@ -0,0 +1,215 @@
+//! The Profiler domain: sampling CPU profiles and precise code coverage.
+
+use std::{sync::OnceLock, time::Instant};
+
+use serde_json::{json, Value as JsonValue};
+
+use so2js::runtime::{
+    coverage::Coverage,
+    profiler::{Profile, ProfileNodeKind},
+};
+
+use crate::session::{path_to_url, Session};
+
+use super::{CdpError, CdpResult, Request};
+
+/// Default time between samples in microseconds
+pub const DEFAULT_SAMPLING_INTERVAL: f64 = 1000.0;
+
+pub fn handle(session: &Session, request: &mut Request, method: &str) -> CdpResult {
+    let mut cx = request.cx;
+
+    match method {
+        "Profiler.enable" | "Profiler.disable" => Ok(json!({})),
+        "Profiler.setSamplingInterval" => {
+            let interval = request.params["interval"]
+                .as_f64()
+                .filter(|interval| *interval > 0.0)
+                .ok_or_else(|| CdpError::invalid_params("Invalid 'interval' parameter"))?;
+
+            session.state().sampling_interval = interval;
+            Ok(json!({}))
+        }
+        "Profiler.start" => {
+            let start = Instant::now();
+            let sampling_interval = session.state().sampling_interval;
+            cx.start_profiler(
+                sampling_interval,
+                Box::new(move || start.elapsed().as_secs_f64() * 1_000_000.0),
+            );
+
+            Ok(json!({}))
+        }
+        "Profiler.stop" => match cx.stop_profiler()? {
+            Some(profile) => Ok(json!({ "profile": profile_json(session, &profile) })),
+            None => Err(CdpError::server_error("No recording profiles found")),
+        },
+        "Profiler.startPreciseCoverage" => {
+            {
+                let mut state = session.state();
+                state.coverage_call_count = request.params["callCount"].as_bool().unwrap_or(false);
+                state.coverage_detailed = request.params["detailed"].as_bool().unwrap_or(false);
+            }
+
+            cx.start_coverage();
+            Ok(json!({ "timestamp": timestamp() }))
+        }
+        "Profiler.takePreciseCoverage" => match cx.take_coverage() {
+            Some(coverage) => Ok(json!({
+                "result": coverage_json(session, &coverage),
+                "timestamp": timestamp(),
+            })),
+            None => Err(CdpError::server_error(
+                "Precise coverage has not been started.",
+            )),
+        },
+        "Profiler.stopPreciseCoverage" => {
+            cx.stop_coverage();
+            Ok(json!({}))
+        }
+        _ => Err(CdpError::method_not_found(method)),
+    }
+}
+
+/// Convert a profile to a CDP `Profiler.Profile`, referring to scripts by the ids reported in
+/// `Debugger.scriptParsed`.
+fn profile_json(session: &Session, profile: &Profile) -> JsonValue {
+    let state = session.state();
+
+    let nodes = profile
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            let function_name = match node.kind {
+                ProfileNodeKind::Root => "(root)",
+                ProfileNodeKind::Program => "(program)",
+                ProfileNodeKind::Script | ProfileNodeKind::Builtin => node.function_name.as_str(),
+            };
+
+            let script = state
+                .scripts
+                .iter()
+                .rev()
+                .find(|script| !node.url.is_empty() && script.path == node.url);
+            let (script_id, url) = match script {
+                Some(script) => (script.id.clone(), script.url.clone()),
+                None if node.url.is_empty() => ("0".to_owned(), String::new()),
+                None => ("0".to_owned(), path_to_url(&node.url)),
+            };
+
+            // Node ids are 1-indexed, while line and column numbers are 0-indexed
+            let mut json = json!({
+                "id": i + 1,
+                "callFrame": {
+                    "functionName": function_name,
+                    "scriptId": script_id,
+                    "url": url,
+                    "lineNumber": node.line.map_or(-1, |line| line as i64 - 1),
+                    "columnNumber": node.column.map_or(-1, |column| column as i64 - 1),
+                },
+                "hitCount": node.hit_count,
+                "children": node.children.iter().map(|child| child + 1).collect::<Vec<_>>(),
+                "positionTicks": node
+                    .line_ticks
+                    .iter()
+                    .map(|(line, ticks)| json!({ "line": line, "ticks": ticks }))
+                    .collect::<Vec<_>>(),
+            });
+
+            if node.kind == ProfileNodeKind::Builtin {
+                json["codeType"] = json!("other");
+            }
+
+            json
+        })
+        .collect::<Vec<_>>();
+
+    let mut previous_time = profile.start_time as i64;
+    let time_deltas = profile
+        .timestamps
+        .iter()
+        .map(|timestamp| {
+            let time = *timestamp as i64;
+            let delta = time - previous_time;
+            previous_time = time;
+            delta
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "nodes": nodes,
+        "startTime": profile.start_time as i64,
+        "endTime": profile.end_time as i64,
+        "samples": profile.samples.iter().map(|sample| sample + 1).collect::<Vec<_>>(),
+        "timeDeltas": time_deltas,
+    })
+}
+
+/// Convert coverage to a list of CDP `Profiler.ScriptCoverage`, respecting the options coverage was
+/// started with.
+fn coverage_json(session: &Session, coverage: &Coverage) -> JsonValue {
+    let state = session.state();
+
+    let scripts = coverage
+        .scripts
+        .iter()
+        .map(|script| {
+            let script_info = state
+                .scripts
+                .iter()
+                .rev()
+                .find(|script_info| script_info.path == script.url);
+            let (script_id, url) = match script_info {
+                Some(script_info) => (script_info.id.clone(), script_info.url.clone()),
+                None => ("0".to_owned(), path_to_url(&script.url)),
+            };
+
+            let functions = script
+                .functions
+                .iter()
+                .map(|function| {
+                    // Only the function range is reported unless block coverage was requested
+                    let ranges = if state.coverage_detailed {
+                        &function.ranges[..]
+                    } else {
+                        &function.ranges[..1]
+                    };
+
+                    let ranges = ranges
+                        .iter()
+                        .map(|range| {
+                            let count = if state.coverage_call_count {
+                                range.count
+                            } else {
+                                range.count.min(1)
+                            };
+
+                            json!({
+                                "startOffset": script.utf16_offset(range.start),
+                                "endOffset": script.utf16_offset(range.end),
+                                "count": count,
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    json!({
+                        "functionName": function.function_name,
+                        "ranges": ranges,
+                        "isBlockCoverage": state.coverage_detailed,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "scriptId": script_id, "url": url, "functions": functions })
+        })
+        .collect::<Vec<_>>();
+
+    JsonValue::Array(scripts)
+}
+
+/// Monotonic timestamp in seconds, as reported by coverage commands.
+fn timestamp() -> f64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64()
+}
@@ -0,0 +1,131 @@
+//! The Runtime domain: evaluating code and inspecting remote objects.
+
+use std::rc::Rc;
+
+use serde_json::json;
+
+use so2js::{
+    common::wtf_8::Wtf8String,
+    parser::source::Source,
+    runtime::{
+        abstract_operations::call, debugger::PausedState, error::BsError, Context, StackRoot, Value,
+    },
+};
+
+use crate::{
+    remote_object::{self, RemoteTarget, DEFAULT_GROUP},
+    session::Session,
+};
+
+use super::{console::CONSOLE_GROUP, CdpError, CdpResult, Request};
+
+/// Path of the scripts created for code evaluated by the client
+const INSPECTOR_SOURCE_PATH: &str = "<inspector>";
+
+pub fn handle(session: &Session, request: &mut Request, method: &str) -> CdpResult {
+    let cx = request.cx;
+
+    match method {
+        "Runtime.enable" => {
+            session.state().is_runtime_enabled = true;
+            session.send_event(
+                "Runtime.executionContextCreated",
+                json!({
+                    "context": {
+                        "id": 1,
+                        "origin": "",
+                        "name": "so2js",
+                        "uniqueId": "1",
+                        "auxData": { "isDefault": true },
+                    },
+                }),
+            );
+
+            Ok(json!({}))
+        }
+        "Runtime.disable" => {
+            session.state().is_runtime_enabled = false;
+            Ok(json!({}))
+        }
+        "Runtime.runIfWaitingForDebugger" => {
+            session.state().is_waiting_for_debugger = false;
+            Ok(json!({}))
+        }
+        "Runtime.evaluate" => {
+            let expression = request.str_param("expression")?;
+            let result = evaluate(cx, request.paused, expression);
+            remote_object::evaluation_result(session, cx, result, &request.params)
+        }
+        "Runtime.callFunctionOn" => call_function_on(session, request),
+        "Runtime.getProperties" => {
+            let object_id = request.str_param("objectId")?;
+            let group = request.params["objectGroup"]
+                .as_str()
+                .unwrap_or(DEFAULT_GROUP);
+
+            remote_object::get_properties(session, cx, request.paused, object_id, group)
+        }
+        "Runtime.releaseObject" => {
+            remote_object::release_object(session, cx, request.str_param("objectId")?);
+            Ok(json!({}))
+        }
+        "Runtime.releaseObjectGroup" => {
+            remote_object::release_object_group(session, cx, request.str_param("objectGroup")?);
+            Ok(json!({}))
+        }
+        "Runtime.discardConsoleEntries" => {
+            session.state().console_messages.clear();
+            remote_object::release_object_group(session, cx, CONSOLE_GROUP);
+            Ok(json!({}))
+        }
+        _ => Err(CdpError::method_not_found(method)),
+    }
+}
+
+/// Evaluate code in the global scope. While paused the code is evaluated in the outermost call
+/// frame, since a new script cannot be run until execution resumes.
+fn evaluate(
+    mut cx: Context,
+    paused: Option<&PausedState>,
+    code: &str,
+) -> Result<StackRoot<Value>, BsError> {
+    match paused {
+        Some(paused) if !paused.frames().is_empty() => paused
+            .evaluate(cx, paused.frames().len() - 1, code)
+            .map_err(BsError::from),
+        _ => {
+            let source = Source::new_for_string(INSPECTOR_SOURCE_PATH, Wtf8String::from_str(code))?;
+            cx.evaluate_script(Rc::new(source))
+        }
+    }
+}
+
+fn call_function_on(session: &Session, request: &mut Request) -> CdpResult {
+    let cx = request.cx;
+    let function_declaration = request.str_param("functionDeclaration")?;
+
+    let this_value = match request.params["objectId"].as_str() {
+        Some(object_id) => match remote_object::resolve_object_id(cx, request.paused, object_id)? {
+            RemoteTarget::Value(value) => value,
+            RemoteTarget::Scope { .. } => {
+                return Err(CdpError::invalid_params("Cannot call functions on scopes"))
+            }
+        },
+        None => cx.undefined(),
+    };
+
+    let mut arguments = vec![];
+    for argument in request.params["arguments"].as_array().into_iter().flatten() {
+        arguments.push(remote_object::from_call_argument(
+            cx,
+            request.paused,
+            argument,
+        )?);
+    }
+
+    let function = evaluate(cx, request.paused, &format!("({})", function_declaration));
+    let result = function
+        .and_then(|function| call(cx, function, this_value, &arguments).map_err(BsError::from));
+
+    remote_object::evaluation_result(session, cx, result, &request.params)
+}
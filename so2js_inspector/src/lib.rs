@@ -0,0 +1,33 @@
+//! A Chrome DevTools Protocol (CDP) inspector for so2js.
+//!
+//! The inspector serves a single debugging target over a WebSocket on localhost, so that Chrome
+//! DevTools, VS Code, or any other CDP client can attach to a running context. The Debugger,
+//! Runtime, Console, and Profiler domains are supported.
+//!
+//! ```ignore
+//! let inspector = Inspector::start(cx, InspectorOptions::default())?;
+//! inspector.install_console(cx, cx.initial_realm())?;
+//! println!("Debugger listening on {}", inspector.websocket_url());
+//!
+//! inspector.wait_for_debugger(cx);
+//! cx.evaluate_script(source)?;
+//! ```
+//!
+//! A context is single threaded, so client messages are only handled on the thread that owns the
+//! context: while execution is paused, while waiting for a debugger, and whenever the embedder
+//! calls `Inspector::poll`. Pause requests from the client are the exception, and take effect
+//! before the next instruction even while JS is running.
+//!
+//! Contexts should be created with the `debug_scopes` option so that every variable can be
+//! inspected and evaluated while paused.
+
+mod domains;
+mod remote_object;
+mod server;
+mod session;
+mod websocket;
+
+#[cfg(test)]
+mod tests;
+
+pub use session::{Inspector, InspectorOptions};
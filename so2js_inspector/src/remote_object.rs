@@ -0,0 +1,445 @@
+//! Conversion between JS values and CDP `Runtime.RemoteObject`s.
+//!
+//! Objects sent to the client are retained by the context until the client releases them, and are
+//! referenced by an object id. Retained objects belong to an object group so that all objects
+//! created for a single purpose can be released together, e.g. all objects in the call frames of a
+//! pause are released when execution resumes.
+//!
+//! Scopes of paused call frames are also exposed as remote objects, but are only valid until
+//! execution resumes. Their object ids have the form `scope:<frame index>:<scope index>`.
+
+use serde_json::{json, Map, Value as JsonValue};
+
+use so2js::runtime::{
+    alloc_error::AllocResult, debugger::PausedState, error::BsError, eval_result::EvalError,
+    heap_item_descriptor::HeapItemKind, intrinsics::json_object::JSONObject,
+    object_value::ObjectValue, to_console_string, type_utilities::to_property_key, Context,
+    StackRoot, Value,
+};
+
+use crate::{domains::CdpError, session::Session};
+
+/// Group for objects referenced by the call frames of the current pause
+pub const BACKTRACE_GROUP: &str = "backtrace";
+
+/// Group for objects that were not given a group by the client
+pub const DEFAULT_GROUP: &str = "";
+
+/// Keep a value alive for the client, returning the object id that refers to it.
+pub fn retain(session: &Session, mut cx: Context, value: StackRoot<Value>, group: &str) -> String {
+    let id = cx.retain_debugger_value(*value);
+    session
+        .state()
+        .object_groups
+        .entry(group.to_owned())
+        .or_default()
+        .push(id);
+
+    id.to_string()
+}
+
+pub fn release_object(session: &Session, mut cx: Context, object_id: &str) {
+    if let Ok(id) = object_id.parse::<usize>() {
+        cx.release_debugger_value(id);
+
+        for ids in session.state().object_groups.values_mut() {
+            ids.retain(|retained_id| *retained_id != id);
+        }
+    }
+}
+
+pub fn release_object_group(session: &Session, mut cx: Context, group: &str) {
+    let ids = session.state().object_groups.remove(group);
+    for id in ids.into_iter().flatten() {
+        cx.release_debugger_value(id);
+    }
+}
+
+/// A value referenced by a remote object id.
+pub enum RemoteTarget {
+    Value(StackRoot<Value>),
+    /// A scope of a paused call frame
+    Scope {
+        frame: usize,
+        scope: usize,
+    },
+}
+
+pub fn resolve_object_id(
+    mut cx: Context,
+    paused: Option<&PausedState>,
+    object_id: &str,
+) -> Result<RemoteTarget, CdpError> {
+    if let Some(scope_id) = object_id.strip_prefix("scope:") {
+        let paused = paused
+            .ok_or_else(|| CdpError::server_error("Scopes are only available while paused"))?;
+
+        let (frame, scope) = scope_id
+            .split_once(':')
+            .and_then(|(frame, scope)| Some((frame.parse().ok()?, scope.parse().ok()?)))
+            .filter(|(frame, _)| *frame < paused.frames().len())
+            .ok_or_else(|| CdpError::server_error("Could not find object with given id"))?;
+
+        return Ok(RemoteTarget::Scope { frame, scope });
+    }
+
+    object_id
+        .parse::<usize>()
+        .ok()
+        .and_then(|id| cx.get_debugger_value(id))
+        .map(RemoteTarget::Value)
+        .ok_or_else(|| CdpError::server_error("Could not find object with given id"))
+}
+
+/// Describe a value as a remote object. Objects, functions and symbols are retained in the given
+/// group.
+pub fn to_remote_object(
+    session: &Session,
+    cx: Context,
+    value: StackRoot<Value>,
+    group: &str,
+) -> AllocResult<JsonValue> {
+    if value.is_undefined() {
+        return Ok(json!({ "type": "undefined" }));
+    } else if value.is_null() {
+        return Ok(json!({ "type": "object", "subtype": "null", "value": null }));
+    } else if value.is_bool() {
+        return Ok(json!({ "type": "boolean", "value": value.as_bool() }));
+    } else if value.is_number() {
+        let description = to_console_string(cx, value)?;
+        return Ok(match number_to_json(value.as_number()) {
+            Some(number) => {
+                json!({ "type": "number", "value": number, "description": description })
+            }
+            None => json!({
+                "type": "number",
+                "unserializableValue": description,
+                "description": description,
+            }),
+        });
+    } else if value.is_string() {
+        return Ok(json!({ "type": "string", "value": to_console_string(cx, value)? }));
+    } else if value.is_bigint() {
+        let description = to_console_string(cx, value)?;
+        return Ok(json!({
+            "type": "bigint",
+            "unserializableValue": description,
+            "description": description,
+        }));
+    } else if value.is_symbol() {
+        return Ok(json!({
+            "type": "symbol",
+            "description": to_console_string(cx, value)?,
+            "objectId": retain(session, cx, value, group),
+        }));
+    }
+
+    let object = value.as_object();
+    let (class_name, subtype) = classify_object(object);
+
+    let mut remote_object = Map::new();
+    if object.is_callable() {
+        remote_object.insert("type".into(), json!("function"));
+        remote_object.insert("className".into(), json!("Function"));
+        remote_object.insert("description".into(), json!("function () { [native code] }"));
+    } else {
+        let description = if subtype == Some("error") {
+            to_console_string(cx, value)?
+        } else {
+            class_name.to_owned()
+        };
+
+        remote_object.insert("type".into(), json!("object"));
+        remote_object.insert("className".into(), json!(class_name));
+        remote_object.insert("description".into(), json!(description));
+
+        if let Some(subtype) = subtype {
+            remote_object.insert("subtype".into(), json!(subtype));
+        }
+    }
+
+    remote_object.insert("objectId".into(), json!(retain(session, cx, value, group)));
+
+    Ok(JsonValue::Object(remote_object))
+}
+
+/// The class name and CDP subtype of an object, determined by the kind of object.
+fn classify_object(object: StackRoot<ObjectValue>) -> (&'static str, Option<&'static str>) {
+    match object.descriptor().kind() {
+        HeapItemKind::ArrayObject => ("Array", Some("array")),
+        HeapItemKind::ErrorObject => ("Error", Some("error")),
+        HeapItemKind::RegExpObject => ("RegExp", Some("regexp")),
+        HeapItemKind::DateObject => ("Date", Some("date")),
+        HeapItemKind::MapObject => ("Map", Some("map")),
+        HeapItemKind::SetObject => ("Set", Some("set")),
+        HeapItemKind::WeakMapObject => ("WeakMap", Some("weakmap")),
+        HeapItemKind::WeakSetObject => ("WeakSet", Some("weakset")),
+        HeapItemKind::WeakRefObject => ("WeakRef", Some("weakref")),
+        HeapItemKind::Proxy => ("Proxy", Some("proxy")),
+        HeapItemKind::Promise => ("Promise", Some("promise")),
+        HeapItemKind::Generator | HeapItemKind::AsyncGenerator => ("Generator", Some("generator")),
+        HeapItemKind::ArrayBufferObject => ("ArrayBuffer", Some("arraybuffer")),
+        HeapItemKind::SharedArrayBufferObject => ("SharedArrayBuffer", Some("arraybuffer")),
+        HeapItemKind::DataViewObject => ("DataView", Some("dataview")),
+        HeapItemKind::Int8Array => ("Int8Array", Some("typedarray")),
+        HeapItemKind::UInt8Array => ("Uint8Array", Some("typedarray")),
+        HeapItemKind::UInt8ClampedArray => ("Uint8ClampedArray", Some("typedarray")),
+        HeapItemKind::Int16Array => ("Int16Array", Some("typedarray")),
+        HeapItemKind::UInt16Array => ("Uint16Array", Some("typedarray")),
+        HeapItemKind::Int32Array => ("Int32Array", Some("typedarray")),
+        HeapItemKind::UInt32Array => ("Uint32Array", Some("typedarray")),
+        HeapItemKind::BigInt64Array => ("BigInt64Array", Some("typedarray")),
+        HeapItemKind::BigUInt64Array => ("BigUint64Array", Some("typedarray")),
+        HeapItemKind::Float16Array => ("Float16Array", Some("typedarray")),
+        HeapItemKind::Float32Array => ("Float32Array", Some("typedarray")),
+        HeapItemKind::Float64Array => ("Float64Array", Some("typedarray")),
+        HeapItemKind::MappedArgumentsObject | HeapItemKind::UnmappedArgumentsObject => {
+            ("Arguments", Some("array"))
+        }
+        HeapItemKind::ModuleNamespaceObject => ("Module", None),
+        _ => ("Object", None),
+    }
+}
+
+/// A number as a JSON number, or None for numbers that JSON cannot represent.
+fn number_to_json(number: f64) -> Option<JsonValue> {
+    if !number.is_finite() || (number == 0.0 && number.is_sign_negative()) {
+        None
+    } else if number.fract() == 0.0 && number.abs() < (1u64 << 53) as f64 {
+        Some(json!(number as i64))
+    } else {
+        Some(json!(number))
+    }
+}
+
+/// Serialize a value as JSON for `returnByValue` requests.
+pub fn to_json_value(cx: Context, value: StackRoot<Value>) -> Result<JsonValue, CdpError> {
+    if value.is_number() {
+        return Ok(number_to_json(value.as_number()).unwrap_or(JsonValue::Null));
+    }
+
+    // Everything else is serialized with JSON.stringify, where values without a JSON
+    // representation become undefined.
+    let json = JSONObject::stringify(cx, cx.undefined(), &[value])?;
+    if json.is_undefined() {
+        return Ok(JsonValue::Null);
+    }
+
+    let json = to_console_string(cx, json)?;
+    serde_json::from_str(&json)
+        .map_err(|_| CdpError::server_error("Object couldn't be returned by value"))
+}
+
+/// Convert a `Runtime.CallArgument` to a value.
+pub fn from_call_argument(
+    mut cx: Context,
+    paused: Option<&PausedState>,
+    argument: &JsonValue,
+) -> Result<StackRoot<Value>, CdpError> {
+    if let Some(object_id) = argument["objectId"].as_str() {
+        return match resolve_object_id(cx, paused, object_id)? {
+            RemoteTarget::Value(value) => Ok(value),
+            RemoteTarget::Scope { .. } => Err(CdpError::invalid_params(
+                "Scopes cannot be passed as arguments",
+            )),
+        };
+    }
+
+    if let Some(unserializable) = argument["unserializableValue"].as_str() {
+        let number = match unserializable {
+            "NaN" => f64::NAN,
+            "Infinity" => f64::INFINITY,
+            "-Infinity" => f64::NEG_INFINITY,
+            "-0" => -0.0,
+            _ => return Err(CdpError::invalid_params("Unsupported unserializable value")),
+        };
+
+        return Ok(cx.number(number));
+    }
+
+    let value = match argument.get("value") {
+        Some(value) => value,
+        None => return Ok(cx.undefined()),
+    };
+
+    Ok(match value {
+        JsonValue::Null => cx.null(),
+        JsonValue::Bool(value) => cx.bool(*value),
+        JsonValue::Number(number) => cx.number(number.as_f64().unwrap_or(f64::NAN)),
+        JsonValue::String(string) => cx.alloc_string(string)?.as_value(),
+        JsonValue::Array(_) | JsonValue::Object(_) => {
+            let json = cx.alloc_string(&value.to_string())?.as_value();
+            JSONObject::parse(cx, cx.undefined(), &[json])?
+        }
+    })
+}
+
+/// The result of `Runtime.getProperties`.
+pub fn get_properties(
+    session: &Session,
+    cx: Context,
+    paused: Option<&PausedState>,
+    object_id: &str,
+    group: &str,
+) -> Result<JsonValue, CdpError> {
+    let (properties, internal_properties) = match resolve_object_id(cx, paused, object_id)? {
+        RemoteTarget::Scope { frame, scope } => {
+            let paused = paused.unwrap();
+            let scopes = paused.frames()[frame].scopes(cx);
+            let scope = scopes
+                .get(scope)
+                .ok_or_else(|| CdpError::server_error("Could not find object with given id"))?;
+
+            let mut properties = vec![];
+            for (name, value) in &scope.variables {
+                let value = match value {
+                    Some(value) => to_remote_object(session, cx, *value, group)?,
+                    None => json!({ "type": "undefined", "description": "<uninitialized>" }),
+                };
+
+                properties.push(json!({
+                    "name": name.to_string(),
+                    "value": value,
+                    "writable": true,
+                    "configurable": false,
+                    "enumerable": true,
+                    "isOwn": true,
+                }));
+            }
+
+            // Global and with scopes also contain the properties of their binding object
+            if let Some(object) = scope.object {
+                properties.extend(object_properties(session, cx, object, group)?.0);
+            }
+
+            (properties, vec![])
+        }
+        RemoteTarget::Value(value) if value.is_object() => {
+            object_properties(session, cx, value.as_object(), group)?
+        }
+        RemoteTarget::Value(_) => (vec![], vec![]),
+    };
+
+    Ok(json!({ "result": properties, "internalProperties": internal_properties }))
+}
+
+/// Own properties and internal properties of an object, as `Runtime.PropertyDescriptor`s and
+/// `Runtime.InternalPropertyDescriptor`s.
+fn object_properties(
+    session: &Session,
+    cx: Context,
+    object: StackRoot<ObjectValue>,
+    group: &str,
+) -> Result<(Vec<JsonValue>, Vec<JsonValue>), CdpError> {
+    let mut properties = vec![];
+
+    for key in object.own_property_keys(cx)? {
+        let property_key = to_property_key(cx, key)?;
+        let desc = match object.get_own_property(cx, property_key)? {
+            Some(desc) => desc,
+            None => continue,
+        };
+
+        let mut property = json!({
+            "name": to_console_string(cx, key)?,
+            "configurable": desc.is_configurable.unwrap_or(false),
+            "enumerable": desc.is_enumerable.unwrap_or(false),
+            "isOwn": true,
+        });
+
+        if let Some(value) = desc.value {
+            property["value"] = to_remote_object(session, cx, value, group)?;
+            property["writable"] = json!(desc.is_writable.unwrap_or(false));
+        }
+
+        if desc.has_get {
+            property["get"] = match desc.get {
+                Some(getter) => to_remote_object(session, cx, getter.into(), group)?,
+                None => json!({ "type": "undefined" }),
+            };
+        }
+
+        if desc.has_set {
+            property["set"] = match desc.set {
+                Some(setter) => to_remote_object(session, cx, setter.into(), group)?,
+                None => json!({ "type": "undefined" }),
+            };
+        }
+
+        if key.is_symbol() {
+            property["symbol"] = to_remote_object(session, cx, key, group)?;
+        }
+
+        properties.push(property);
+    }
+
+    let mut internal_properties = vec![];
+    if let Some(prototype) = object.get_prototype_of(cx)? {
+        internal_properties.push(json!({
+            "name": "[[Prototype]]",
+            "value": to_remote_object(session, cx, prototype.into(), group)?,
+        }));
+    }
+
+    Ok((properties, internal_properties))
+}
+
+/// The result of an evaluation, as the result of `Runtime.evaluate` and similar commands.
+pub fn evaluation_result(
+    session: &Session,
+    cx: Context,
+    result: Result<StackRoot<Value>, BsError>,
+    params: &JsonValue,
+) -> Result<JsonValue, CdpError> {
+    let group = params["objectGroup"].as_str().unwrap_or(DEFAULT_GROUP);
+
+    match result {
+        Ok(value) if params["returnByValue"].as_bool() == Some(true) => {
+            let mut result = to_remote_object(session, cx, value, group)?;
+            result["value"] = to_json_value(cx, value)?;
+            if let Some(object_id) = result.as_object_mut().unwrap().remove("objectId") {
+                release_object(session, cx, object_id.as_str().unwrap());
+            }
+
+            Ok(json!({ "result": result }))
+        }
+        Ok(value) => Ok(json!({ "result": to_remote_object(session, cx, value, group)? })),
+        Err(BsError::Eval(EvalError::Value(exception))) => {
+            let remote_exception = to_remote_object(session, cx, exception, group)?;
+            let text = format!("Uncaught {}", to_console_string(cx, exception)?);
+
+            Ok(json!({
+                "result": remote_exception,
+                "exceptionDetails": {
+                    "exceptionId": 1,
+                    "text": text,
+                    "lineNumber": 0,
+                    "columnNumber": 0,
+                    "exception": remote_exception,
+                },
+            }))
+        }
+        // Parse and analysis errors are reported as a SyntaxError without an exception object
+        Err(error @ (BsError::Parse(_) | BsError::Analyze(_) | BsError::Emit(_))) => {
+            let text = error.format(cx);
+            let exception = json!({
+                "type": "object",
+                "subtype": "error",
+                "className": "SyntaxError",
+                "description": text,
+            });
+
+            Ok(json!({
+                "result": exception,
+                "exceptionDetails": {
+                    "exceptionId": 1,
+                    "text": format!("Uncaught {}", text),
+                    "lineNumber": 0,
+                    "columnNumber": 0,
+                    "exception": exception,
+                },
+            }))
+        }
+        Err(_) => Err(CdpError::server_error("Ran out of heap memory")),
+    }
+}
@@ -0,0 +1,343 @@
+//! The inspector's network server.
+//!
+//! A background thread accepts connections on a localhost port. Plain HTTP requests are answered
+//! with the target discovery endpoints that Chrome DevTools and VS Code poll (`/json/list` and
+//! `/json/version`). WebSocket upgrade requests start a CDP session, and every text message the
+//! client sends is forwarded to the thread that owns the context. Only one session is served at a
+//! time.
+//!
+//! Responses are written directly to the session's socket by the thread that owns the context.
+//!
+//! Any web page open in a browser on the same machine can send requests to localhost, so requests
+//! are only served if their `Host` header names localhost or an IP address, which defeats DNS
+//! rebinding. WebSocket upgrades must also be for the target's randomly generated path and must
+//! not come from a web page, which browsers identify with an `Origin` header.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{IpAddr, Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use so2js::runtime::debugger::PauseRequestHandle;
+
+use crate::websocket::{self, Message, MessageReader};
+
+/// Events sent from the server thread to the thread that owns the context.
+pub enum ServerEvent {
+    Connected,
+    /// A CDP message from the client
+    Message(String),
+    Disconnected,
+}
+
+pub struct Server {
+    address: SocketAddr,
+    target_id: String,
+    events: Receiver<ServerEvent>,
+    /// Socket of the current session, if a client is connected
+    connection: Arc<Mutex<Option<TcpStream>>>,
+}
+
+impl Server {
+    /// Bind to the port on localhost and start accepting connections. Port 0 picks any free port.
+    pub fn start(port: u16, title: &str, pause_handle: PauseRequestHandle) -> io::Result<Server> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let address = listener.local_addr()?;
+        let target_id = random_uuid();
+
+        let (sender, events) = mpsc::channel();
+        let connection = Arc::new(Mutex::new(None));
+
+        let acceptor = Acceptor {
+            address,
+            target_id: target_id.clone(),
+            title: title.to_owned(),
+            sender,
+            connection: connection.clone(),
+            pause_handle,
+        };
+        thread::Builder::new()
+            .name("so2js-inspector".to_owned())
+            .spawn(move || acceptor.run(listener))?;
+
+        Ok(Server {
+            address,
+            target_id,
+            events,
+            connection,
+        })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// The URL of the WebSocket endpoint for the single debugging target.
+    pub fn websocket_url(&self) -> String {
+        format!("ws://{}/{}", self.address, self.target_id)
+    }
+
+    /// Wait for the next event. Returns None if no event arrived before the timeout.
+    pub fn next_event(&self, timeout: Option<Duration>) -> Option<ServerEvent> {
+        match timeout {
+            Some(timeout) => self.events.recv_timeout(timeout).ok(),
+            None => self.events.recv().ok(),
+        }
+    }
+
+    /// Return the next event if one is immediately available.
+    pub fn try_next_event(&self) -> Option<ServerEvent> {
+        self.events.try_recv().ok()
+    }
+
+    /// Send a message to the connected client, if any. Messages to a client that has gone away are
+    /// dropped.
+    pub fn send(&self, message: &str) {
+        let mut connection = self.connection.lock().unwrap();
+        if let Some(stream) = connection.as_mut() {
+            if websocket::write_frame(stream, websocket::OPCODE_TEXT, message.as_bytes(), None)
+                .is_err()
+            {
+                *connection = None;
+            }
+        }
+    }
+}
+
+impl Drop for Server {
+    /// Close the current session. The server thread stops once it next receives a connection.
+    fn drop(&mut self) {
+        if let Some(stream) = self.connection.lock().unwrap().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// State owned by the server thread
+struct Acceptor {
+    address: SocketAddr,
+    target_id: String,
+    title: String,
+    sender: Sender<ServerEvent>,
+    connection: Arc<Mutex<Option<TcpStream>>>,
+    pause_handle: PauseRequestHandle,
+}
+
+impl Acceptor {
+    fn run(&self, listener: TcpListener) {
+        for stream in listener.incoming() {
+            let result = stream.and_then(|stream| self.handle_connection(stream));
+
+            // Stop once the inspector has been dropped
+            if matches!(&result, Err(error) if error.kind() == io::ErrorKind::BrokenPipe) {
+                return;
+            }
+        }
+    }
+
+    fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+
+        // Read the request line and headers
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let path = request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or("/")
+            .to_owned();
+
+        let mut websocket_key = None;
+        let mut host = None;
+        let mut origin = None;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                let name = name.trim();
+                let value = Some(value.trim().to_owned());
+                if name.eq_ignore_ascii_case("sec-websocket-key") {
+                    websocket_key = value;
+                } else if name.eq_ignore_ascii_case("host") {
+                    host = value;
+                } else if name.eq_ignore_ascii_case("origin") {
+                    origin = value;
+                }
+            }
+        }
+
+        if !host.as_deref().is_some_and(is_allowed_host) {
+            return write_error(&mut writer, "403 Forbidden");
+        }
+
+        let websocket_key = match websocket_key {
+            Some(key) => key,
+            None => return self.handle_http_request(&mut writer, &path),
+        };
+
+        if path.strip_prefix('/') != Some(self.target_id.as_str()) {
+            return write_error(&mut writer, "404 Not Found");
+        }
+
+        if origin.is_some_and(|origin| !is_allowed_origin(&origin)) {
+            return write_error(&mut writer, "403 Forbidden");
+        }
+
+        write!(
+            writer,
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+             Sec-WebSocket-Accept: {}\r\n\r\n",
+            websocket::accept_key(&websocket_key)
+        )?;
+
+        *self.connection.lock().unwrap() = Some(writer.try_clone()?);
+        self.send_event(ServerEvent::Connected)?;
+
+        let result = self.run_session(MessageReader::new(reader));
+
+        *self.connection.lock().unwrap() = None;
+        self.send_event(ServerEvent::Disconnected)?;
+
+        // A session ending with an IO error only closes that session
+        match result {
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    fn run_session(&self, mut reader: MessageReader<BufReader<TcpStream>>) -> io::Result<()> {
+        loop {
+            match reader.read_message()? {
+                Message::Text(text) => {
+                    // Pause requests must be handled while JS is running, when the thread that owns
+                    // the context is not processing messages.
+                    if is_pause_request(&text) {
+                        self.pause_handle.request_pause();
+                    }
+
+                    self.send_event(ServerEvent::Message(text))?;
+                }
+                Message::Ping(payload) => {
+                    if let Some(stream) = self.connection.lock().unwrap().as_mut() {
+                        websocket::write_frame(stream, websocket::OPCODE_PONG, &payload, None)?;
+                    }
+                }
+                Message::Binary(_) | Message::Pong => {}
+                Message::Close => {
+                    if let Some(stream) = self.connection.lock().unwrap().as_mut() {
+                        websocket::write_frame(stream, websocket::OPCODE_CLOSE, &[], None)?;
+                    }
+
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn handle_http_request(&self, writer: &mut TcpStream, path: &str) -> io::Result<()> {
+        let websocket_address = format!("{}/{}", self.address, self.target_id);
+
+        let body = match path.trim_end_matches('/') {
+            "/json" | "/json/list" => serde_json::json!([{
+                "description": "so2js instance",
+                "devtoolsFrontendUrl": format!(
+                    "devtools://devtools/bundled/js_app.html?experiments=true&v8only=true&ws={}",
+                    websocket_address
+                ),
+                "id": self.target_id,
+                "title": self.title,
+                "type": "node",
+                "url": "",
+                "webSocketDebuggerUrl": format!("ws://{}", websocket_address),
+            }]),
+            "/json/version" => serde_json::json!({
+                "Browser": concat!("so2js/", env!("CARGO_PKG_VERSION")),
+                "Protocol-Version": "1.3",
+            }),
+            _ => return write_error(writer, "404 Not Found"),
+        };
+
+        let body = body.to_string();
+        write!(
+            writer,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json; charset=UTF-8\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    /// Forward an event to the thread that owns the context. Fails once the inspector is dropped.
+    fn send_event(&self, event: ServerEvent) -> io::Result<()> {
+        self.sender
+            .send(event)
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+/// Write an empty response with an error status and close the connection.
+fn write_error(writer: &mut TcpStream, status: &str) -> io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    )
+}
+
+/// Whether a `Host` header names localhost or an IP address, with an optional port. Any other
+/// host name may have been rebound to localhost by a malicious web page.
+fn is_allowed_host(host: &str) -> bool {
+    // IPv6 addresses are bracketed, e.g. `[::1]:9229`
+    if let Some(rest) = host.strip_prefix('[') {
+        return match rest.split_once(']') {
+            Some((address, port)) => {
+                address.parse::<IpAddr>().is_ok() && (port.is_empty() || port.starts_with(':'))
+            }
+            None => false,
+        };
+    }
+
+    let name = host.split_once(':').map_or(host, |(name, _)| name);
+    name.eq_ignore_ascii_case("localhost") || name.parse::<IpAddr>().is_ok()
+}
+
+/// Whether an `Origin` header is allowed to open a session. Web pages are rejected, while the
+/// Chrome DevTools frontend is allowed. Clients that are not browsers do not send an origin.
+fn is_allowed_origin(origin: &str) -> bool {
+    origin == "devtools://devtools"
+}
+
+/// A random version 4 UUID, used as the unguessable target id.
+fn random_uuid() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn is_pause_request(message: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(message)
+        .is_ok_and(|message| message["method"] == "Debugger.pause")
+}
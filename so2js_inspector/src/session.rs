@@ -0,0 +1,432 @@
+use std::{
+    cell::{RefCell, RefMut},
+    collections::HashMap,
+    io,
+    net::SocketAddr,
+    rc::{Rc, Weak},
+};
+
+use serde_json::{json, Value as JsonValue};
+
+use so2js::{
+    js_stack_scope_guard,
+    runtime::{
+        alloc_error::AllocResult,
+        debugger::{Debugger, PauseOnExceptions, PauseReason, PausedState, ResumeAction},
+        source_file::SourceFile,
+        Context, Realm, StackRoot,
+    },
+};
+
+use crate::{
+    domains::{self, console, debugger::UrlBreakpoint, profiler, Request},
+    remote_object::{self, BACKTRACE_GROUP},
+    server::{Server, ServerEvent},
+};
+
+pub struct InspectorOptions {
+    /// Port on localhost to listen on. Port 0 picks any free port.
+    pub port: u16,
+    /// Title of the debugging target shown by clients
+    pub title: String,
+}
+
+impl Default for InspectorOptions {
+    fn default() -> Self {
+        InspectorOptions {
+            port: 9229,
+            title: String::from("so2js"),
+        }
+    }
+}
+
+/// An inspector attached to a context. Must be dropped before the context is dropped, which
+/// detaches the inspector's debugger from the context.
+pub struct Inspector {
+    cx: Context,
+    session: Rc<Session>,
+}
+
+impl Inspector {
+    /// Attach an inspector to the context and start listening for clients. Replaces any debugger
+    /// that is already attached.
+    pub fn start(mut cx: Context, options: InspectorOptions) -> io::Result<Inspector> {
+        // The server needs a pause handle, which is only available once a debugger is attached
+        cx.attach_debugger(Box::new(InspectorDebugger {
+            session: Weak::new(),
+        }));
+
+        let server = match Server::start(options.port, &options.title, cx.pause_request_handle()) {
+            Ok(server) => server,
+            Err(error) => {
+                cx.detach_debugger();
+                return Err(error);
+            }
+        };
+
+        let session = Rc::new(Session {
+            server,
+            state: RefCell::new(SessionState::new()),
+        });
+
+        cx.attach_debugger(Box::new(InspectorDebugger {
+            session: Rc::downgrade(&session),
+        }));
+        console::register_session(cx, &session);
+
+        Ok(Inspector { cx, session })
+    }
+
+    /// The address the inspector is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.session.server.address()
+    }
+
+    /// The URL that CDP clients connect to.
+    pub fn websocket_url(&self) -> String {
+        self.session.server.websocket_url()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.session.state().is_connected
+    }
+
+    /// Install a `console` object on the realm's global object which reports messages to the
+    /// connected client, in addition to printing them.
+    pub fn install_console(&self, realm: StackRoot<Realm>) -> AllocResult<()> {
+        console::install(self.cx, realm)
+    }
+
+    /// Handle client messages until a client sends `Runtime.runIfWaitingForDebugger`, giving the
+    /// client a chance to set breakpoints before any code runs.
+    pub fn wait_for_debugger(&self) {
+        self.session.state().is_waiting_for_debugger = true;
+
+        while self.session.state().is_waiting_for_debugger {
+            match self.session.server.next_event(None) {
+                Some(event) => {
+                    self.session.handle_event(self.cx, None, event);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Handle all pending client messages without blocking. Must not be called while JS is
+    /// executing on the context.
+    pub fn poll(&self) {
+        while let Some(event) = self.session.server.try_next_event() {
+            self.session.handle_event(self.cx, None, event);
+        }
+    }
+}
+
+impl Drop for Inspector {
+    fn drop(&mut self) {
+        console::unregister_session(self.cx);
+        self.cx.detach_debugger();
+    }
+}
+
+/// The debugger attached to the context, which hands pauses and new scripts to the session.
+struct InspectorDebugger {
+    session: Weak<Session>,
+}
+
+impl Debugger for InspectorDebugger {
+    fn on_pause(&mut self, cx: Context, paused: &PausedState) -> ResumeAction {
+        match self.session.upgrade() {
+            Some(session) => session.on_pause(cx, paused),
+            None => ResumeAction::Continue,
+        }
+    }
+
+    fn on_source_file_loaded(&mut self, cx: Context, source_file: StackRoot<SourceFile>) {
+        if let Some(session) = self.session.upgrade() {
+            session.on_source_file_loaded(cx, source_file);
+        }
+    }
+}
+
+/// A script that was compiled while the inspector was attached.
+pub struct Script {
+    pub id: String,
+    /// Path of the source file
+    pub path: String,
+    /// URL reported to the client. Empty for eval code.
+    pub url: String,
+    pub source: String,
+}
+
+/// State shared between all domains. Never borrowed across calls into the runtime, since the
+/// runtime may call back into the session (e.g. when a console method is called or a new script is
+/// compiled).
+pub struct SessionState {
+    pub is_connected: bool,
+    pub is_waiting_for_debugger: bool,
+    pub is_runtime_enabled: bool,
+    pub is_debugger_enabled: bool,
+    pub is_console_enabled: bool,
+    pub breakpoints_active: bool,
+    pub skip_all_pauses: bool,
+    pub scripts: Vec<Rc<Script>>,
+    pub breakpoints: Vec<UrlBreakpoint>,
+    pub next_breakpoint_id: usize,
+    /// Retained value ids in each object group
+    pub object_groups: HashMap<String, Vec<usize>>,
+    /// Console messages, replayed when the Console domain is enabled
+    pub console_messages: Vec<JsonValue>,
+    /// Time between samples in microseconds for profiles started by the client
+    pub sampling_interval: f64,
+    /// Whether precise coverage reports execution counts, or only whether code was executed
+    pub coverage_call_count: bool,
+    /// Whether precise coverage reports blocks, or only functions
+    pub coverage_detailed: bool,
+}
+
+impl SessionState {
+    fn new() -> SessionState {
+        SessionState {
+            is_connected: false,
+            is_waiting_for_debugger: false,
+            is_runtime_enabled: false,
+            is_debugger_enabled: false,
+            is_console_enabled: false,
+            breakpoints_active: true,
+            skip_all_pauses: false,
+            scripts: vec![],
+            breakpoints: vec![],
+            next_breakpoint_id: 1,
+            object_groups: HashMap::new(),
+            console_messages: vec![],
+            sampling_interval: profiler::DEFAULT_SAMPLING_INTERVAL,
+            coverage_call_count: false,
+            coverage_detailed: false,
+        }
+    }
+}
+
+pub struct Session {
+    server: Server,
+    state: RefCell<SessionState>,
+}
+
+impl Session {
+    pub fn state(&self) -> RefMut<'_, SessionState> {
+        self.state.borrow_mut()
+    }
+
+    pub fn send_event(&self, method: &str, params: JsonValue) {
+        self.send(json!({ "method": method, "params": params }));
+    }
+
+    fn send(&self, message: JsonValue) {
+        self.server.send(&message.to_string());
+    }
+
+    /// Handle an event from the server. Returns how to resume if a client command resumed
+    /// execution while paused.
+    fn handle_event(
+        &self,
+        cx: Context,
+        paused: Option<&PausedState>,
+        event: ServerEvent,
+    ) -> Option<ResumeAction> {
+        match event {
+            ServerEvent::Connected => {
+                self.state().is_connected = true;
+                None
+            }
+            ServerEvent::Message(message) => self.handle_message(cx, paused, &message),
+            ServerEvent::Disconnected => {
+                self.reset(cx);
+                paused.map(|_| ResumeAction::Continue)
+            }
+        }
+    }
+
+    fn handle_message(
+        &self,
+        cx: Context,
+        paused: Option<&PausedState>,
+        message: &str,
+    ) -> Option<ResumeAction> {
+        let message = match serde_json::from_str::<JsonValue>(message) {
+            Ok(message) => message,
+            Err(_) => {
+                self.send(json!({
+                    "error": { "code": -32700, "message": "Message must be a valid JSON" },
+                }));
+                return None;
+            }
+        };
+
+        let id = message["id"].clone();
+        let method = message["method"].as_str().unwrap_or_default();
+
+        let mut request = Request {
+            cx,
+            paused,
+            params: message.get("params").cloned().unwrap_or_else(|| json!({})),
+            resume: None,
+        };
+
+        let result = {
+            js_stack_scope_guard!(cx);
+            domains::dispatch(self, &mut request, method)
+        };
+
+        match result {
+            Ok(result) => self.send(json!({ "id": id, "result": result })),
+            Err(error) => self.send(json!({
+                "id": id,
+                "error": { "code": error.code, "message": error.message },
+            })),
+        }
+
+        request.resume
+    }
+
+    /// Clear all state that belongs to a client once it disconnects.
+    fn reset(&self, mut cx: Context) {
+        let (breakpoints, object_groups) = {
+            let mut state = self.state();
+            state.is_connected = false;
+            state.is_runtime_enabled = false;
+            state.is_debugger_enabled = false;
+            state.is_console_enabled = false;
+            state.breakpoints_active = true;
+            state.skip_all_pauses = false;
+            state.sampling_interval = profiler::DEFAULT_SAMPLING_INTERVAL;
+
+            (
+                std::mem::take(&mut state.breakpoints),
+                std::mem::take(&mut state.object_groups),
+            )
+        };
+
+        for breakpoint in breakpoints {
+            for id in breakpoint.runtime_ids {
+                cx.remove_breakpoint(id);
+            }
+        }
+
+        for id in object_groups.into_values().flatten() {
+            cx.release_debugger_value(id);
+        }
+
+        cx.set_pause_on_exceptions(PauseOnExceptions::None);
+
+        // Discard any profile the client did not stop
+        if cx.is_profiling() {
+            let _ = cx.stop_profiler();
+        }
+
+        cx.stop_coverage();
+    }
+
+    /// Report the pause to the client, then handle client messages until a command resumes
+    /// execution.
+    fn on_pause(&self, cx: Context, paused: &PausedState) -> ResumeAction {
+        {
+            let state = self.state();
+            let is_breakpoint = matches!(paused.reason(), PauseReason::Breakpoint(_));
+
+            if !state.is_connected
+                || !state.is_debugger_enabled
+                || state.skip_all_pauses
+                || (is_breakpoint && !state.breakpoints_active)
+            {
+                return ResumeAction::Continue;
+            }
+        }
+
+        let paused_event = {
+            js_stack_scope_guard!(cx);
+            domains::debugger::paused_event(self, cx, paused)
+        };
+
+        match paused_event {
+            Ok(params) => self.send_event("Debugger.paused", params),
+            Err(_) => return ResumeAction::Continue,
+        }
+
+        loop {
+            let event = match self.server.next_event(None) {
+                Some(event) => event,
+                None => return ResumeAction::Continue,
+            };
+
+            if let Some(action) = self.handle_event(cx, Some(paused), event) {
+                remote_object::release_object_group(self, cx, BACKTRACE_GROUP);
+
+                if self.state().is_connected {
+                    self.send_event("Debugger.resumed", json!({}));
+                }
+
+                return action;
+            }
+        }
+    }
+
+    fn on_source_file_loaded(&self, cx: Context, source_file: StackRoot<SourceFile>) {
+        let path = source_file.path(cx).to_string();
+        let is_eval = source_file.display_name(cx).to_string() == "<eval>";
+
+        let script = {
+            let mut state = self.state();
+            let script = Rc::new(Script {
+                id: (state.scripts.len() + 1).to_string(),
+                url: if is_eval {
+                    String::new()
+                } else {
+                    path_to_url(&path)
+                },
+                path,
+                source: String::from_utf8_lossy(source_file.contents_as_slice()).into_owned(),
+            });
+
+            state.scripts.push(script.clone());
+            script
+        };
+
+        domains::debugger::on_script_parsed(self, cx, &script);
+    }
+
+    /// Find the script for a source file. Eval code shares the path of the code that called eval,
+    /// so the contents are compared as well.
+    pub fn find_script(
+        &self,
+        cx: Context,
+        source_file: StackRoot<SourceFile>,
+    ) -> Option<Rc<Script>> {
+        let path = source_file.path(cx).to_string();
+        let contents = source_file.contents_as_slice();
+
+        let state = self.state();
+        let mut scripts = state
+            .scripts
+            .iter()
+            .rev()
+            .filter(|script| script.path == path);
+
+        scripts
+            .clone()
+            .find(|script| script.source.as_bytes() == contents)
+            .or_else(|| scripts.next())
+            .cloned()
+    }
+}
+
+/// Absolute paths are reported to clients as file URLs.
+pub fn path_to_url(path: &str) -> String {
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        path.to_owned()
+    }
+}
+
+pub fn url_to_path(url: &str) -> &str {
+    url.strip_prefix("file://").unwrap_or(url)
+}
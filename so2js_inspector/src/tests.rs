@@ -0,0 +1,748 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, BufReader, Cursor, Read, Write},
+    net::{SocketAddr, TcpStream},
+    rc::Rc,
+    thread,
+    time::Duration,
+};
+
+use serde_json::{json, Value as JsonValue};
+
+use so2js::{
+    common::{options::OptionsBuilder, wtf_8::Wtf8String},
+    parser::source::Source,
+    runtime::{Context, ContextBuilder},
+};
+
+use crate::{
+    websocket::{self, Message, MessageReader},
+    Inspector, InspectorOptions,
+};
+
+#[test]
+fn websocket_accept_key() {
+    // Example from RFC 6455
+    assert_eq!(
+        websocket::accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+}
+
+#[test]
+fn websocket_frame_round_trip() {
+    for size in [0, 125, 126, 65535, 70000] {
+        let payload = "x".repeat(size);
+
+        for mask in [None, Some([0x12, 0x34, 0x56, 0x78])] {
+            let mut buffer = vec![];
+            websocket::write_frame(
+                &mut buffer,
+                websocket::OPCODE_TEXT,
+                payload.as_bytes(),
+                mask,
+            )
+            .unwrap();
+
+            match MessageReader::new(Cursor::new(buffer))
+                .read_message()
+                .unwrap()
+            {
+                Message::Text(text) => assert_eq!(text, payload),
+                _ => panic!("expected text message"),
+            }
+        }
+    }
+}
+
+#[test]
+fn websocket_fragmented_message() {
+    // A text frame and continuation frame with a ping between them
+    let mut buffer = vec![0x01, 3];
+    buffer.extend_from_slice(b"abc");
+    buffer.extend_from_slice(&[0x89, 0]);
+    buffer.extend_from_slice(&[0x80, 3]);
+    buffer.extend_from_slice(b"def");
+
+    let mut reader = MessageReader::new(Cursor::new(buffer));
+    assert!(matches!(
+        reader.read_message().unwrap(),
+        Message::Ping(payload) if payload.is_empty()
+    ));
+    match reader.read_message().unwrap() {
+        Message::Text(text) => assert_eq!(text, "abcdef"),
+        _ => panic!("expected text message"),
+    }
+}
+
+/// A scripted CDP client.
+struct TestClient {
+    stream: TcpStream,
+    reader: MessageReader<BufReader<TcpStream>>,
+    next_id: u64,
+    /// Events received while waiting for other messages
+    events: VecDeque<JsonValue>,
+}
+
+impl TestClient {
+    fn connect(websocket_url: &str) -> TestClient {
+        let (address, path) = websocket_url
+            .strip_prefix("ws://")
+            .and_then(|rest| rest.split_once('/'))
+            .unwrap();
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(30)))
+            .unwrap();
+
+        write!(
+            stream,
+            "GET /{} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+             Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n",
+            path, address
+        )
+        .unwrap();
+
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let headers = read_http_headers(&mut reader);
+        assert!(headers[0].contains("101"));
+        assert!(headers.contains(&"Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=".to_owned()));
+
+        TestClient {
+            stream,
+            reader: MessageReader::new(reader),
+            next_id: 1,
+            events: VecDeque::new(),
+        }
+    }
+
+    fn send(&mut self, method: &str, params: JsonValue) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let message = json!({ "id": id, "method": method, "params": params }).to_string();
+        websocket::write_frame(
+            &mut self.stream,
+            websocket::OPCODE_TEXT,
+            message.as_bytes(),
+            Some([1, 2, 3, 4]),
+        )
+        .unwrap();
+
+        id
+    }
+
+    fn read(&mut self) -> JsonValue {
+        match self.reader.read_message().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            _ => panic!("expected text message"),
+        }
+    }
+
+    /// Send a command and return the full response.
+    fn call(&mut self, method: &str, params: JsonValue) -> JsonValue {
+        let id = self.send(method, params);
+        loop {
+            let message = self.read();
+            if message["id"] == id {
+                return message;
+            }
+
+            self.events.push_back(message);
+        }
+    }
+
+    /// Send a command and return its result, which must not be an error.
+    fn result(&mut self, method: &str, params: JsonValue) -> JsonValue {
+        let response = self.call(method, params);
+        assert!(
+            response.get("error").is_none(),
+            "{} failed: {}",
+            method,
+            response
+        );
+        response["result"].clone()
+    }
+
+    /// Wait for an event, returning its params.
+    fn wait_for_event(&mut self, method: &str) -> JsonValue {
+        if let Some(index) = self
+            .events
+            .iter()
+            .position(|event| event["method"] == method)
+        {
+            return self.events.remove(index).unwrap()["params"].clone();
+        }
+
+        loop {
+            let message = self.read();
+            if message["method"] == method {
+                return message["params"].clone();
+            }
+
+            self.events.push_back(message);
+        }
+    }
+
+    /// Return the properties of a remote object by name.
+    fn properties(&mut self, object_id: &JsonValue) -> Vec<(String, JsonValue)> {
+        let result = self.result(
+            "Runtime.getProperties",
+            json!({ "objectId": object_id, "ownProperties": true }),
+        );
+
+        result["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|property| {
+                let name = property["name"].as_str().unwrap().to_owned();
+                (name, property["value"].clone())
+            })
+            .collect()
+    }
+}
+
+fn read_http_headers(reader: &mut impl BufRead) -> Vec<String> {
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if line.trim().is_empty() {
+            return headers;
+        }
+
+        headers.push(line.trim().to_owned());
+    }
+}
+
+fn property<'a>(properties: &'a [(String, JsonValue)], name: &str) -> &'a JsonValue {
+    &properties.iter().find(|(key, _)| key == name).unwrap().1
+}
+
+fn create_context() -> Context {
+    let options = OptionsBuilder::new().debug_scopes(true).build();
+    ContextBuilder::new()
+        .set_options(Rc::new(options))
+        .build()
+        .unwrap()
+}
+
+fn evaluate(mut cx: Context, path: &str, code: &str) {
+    let source = Source::new_for_string(path, Wtf8String::from_str(code)).unwrap();
+    if let Err(error) = cx.evaluate_script(Rc::new(source)) {
+        panic!("{}", error.format(cx));
+    }
+}
+
+/// Start an inspector and connect a client on another thread. Once the client sends
+/// `Runtime.runIfWaitingForDebugger` the body is run, after which the inspector handles messages
+/// until the client is done.
+fn run_with_client(client: impl FnOnce(TestClient) + Send + 'static, body: impl FnOnce(Context)) {
+    let cx = create_context();
+    let options = InspectorOptions {
+        port: 0,
+        title: String::from("test"),
+    };
+
+    let inspector = Inspector::start(cx, options).unwrap();
+    inspector.install_console(cx.initial_realm()).unwrap();
+
+    let websocket_url = inspector.websocket_url();
+    let client_thread = thread::spawn(move || client(TestClient::connect(&websocket_url)));
+
+    inspector.wait_for_debugger();
+    body(cx);
+
+    while !client_thread.is_finished() {
+        inspector.poll();
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    drop(inspector);
+    cx.drop();
+
+    if let Err(panic) = client_thread.join() {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+#[test]
+fn http_discovery_endpoints() {
+    let cx = create_context();
+    let options = InspectorOptions {
+        port: 0,
+        title: String::from("test"),
+    };
+    let inspector = Inspector::start(cx, options).unwrap();
+
+    let get = |path: &str| -> JsonValue {
+        let mut stream = TcpStream::connect(inspector.address()).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+
+        let mut reader = BufReader::new(stream);
+        let headers = read_http_headers(&mut reader);
+        assert!(headers[0].contains("200"));
+
+        let mut body = String::new();
+        reader.read_to_string(&mut body).unwrap();
+        serde_json::from_str(&body).unwrap()
+    };
+
+    let targets = get("/json/list");
+    assert_eq!(targets[0]["title"], "test");
+    assert_eq!(
+        targets[0]["webSocketDebuggerUrl"],
+        inspector.websocket_url()
+    );
+
+    let version = get("/json/version");
+    assert_eq!(version["Protocol-Version"], "1.3");
+
+    drop(inspector);
+    cx.drop();
+}
+
+/// Send a raw HTTP request with the given request line and headers, returning the response status
+/// line.
+fn request_status(address: SocketAddr, path: &str, headers: &[&str]) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\n", path).unwrap();
+    for header in headers {
+        write!(stream, "{}\r\n", header).unwrap();
+    }
+    write!(stream, "\r\n").unwrap();
+
+    let mut reader = BufReader::new(stream);
+    read_http_headers(&mut reader).remove(0)
+}
+
+#[test]
+fn rejected_connections() {
+    let cx = create_context();
+    let options = InspectorOptions {
+        port: 0,
+        title: String::from("test"),
+    };
+    let inspector = Inspector::start(cx, options).unwrap();
+    let address = inspector.address();
+
+    // Target ids are random UUIDs
+    let websocket_url = inspector.websocket_url();
+    let target_path = &websocket_url[websocket_url.rfind('/').unwrap()..];
+    let target_id = &target_path[1..];
+    assert_eq!(target_id.len(), 36);
+    assert_eq!(
+        target_id
+            .match_indices('-')
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>(),
+        [8, 13, 18, 23]
+    );
+
+    let host = format!("Host: {}", address);
+    let upgrade = [
+        "Upgrade: websocket",
+        "Connection: Upgrade",
+        "Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==",
+        "Sec-WebSocket-Version: 13",
+    ];
+    let upgrade_with = |extra: &[&str]| -> Vec<String> {
+        extra
+            .iter()
+            .chain(upgrade.iter())
+            .map(|header| header.to_string())
+            .collect()
+    };
+    let status = |path: &str, headers: Vec<String>| -> String {
+        let headers = headers.iter().map(String::as_str).collect::<Vec<_>>();
+        request_status(address, path, &headers)
+    };
+
+    // Upgrades for any path other than the target's
+    assert!(status("/", upgrade_with(&[&host])).contains("404"));
+    assert!(status("/json/list", upgrade_with(&[&host])).contains("404"));
+    let wrong_target = format!("/so2js-{}-{}", std::process::id(), address.port());
+    assert!(status(&wrong_target, upgrade_with(&[&host])).contains("404"));
+
+    // Host names other than localhost and IP addresses, as used by DNS rebinding
+    let rebound_host = format!("Host: attacker.example:{}", address.port());
+    assert!(status(target_path, upgrade_with(&[&rebound_host])).contains("403"));
+    assert!(status("/json/list", vec![rebound_host.clone()]).contains("403"));
+    assert!(status(target_path, upgrade.iter().map(|h| h.to_string()).collect()).contains("403"));
+
+    // Upgrades from web pages
+    let origin = "Origin: https://attacker.example";
+    assert!(status(target_path, upgrade_with(&[&host, origin])).contains("403"));
+    let localhost_origin = format!("Origin: http://{}", address);
+    assert!(status(target_path, upgrade_with(&[&host, &localhost_origin])).contains("403"));
+
+    // Allowed hosts for discovery requests
+    assert!(status("/json/list", vec![String::from("Host: localhost")]).contains("200"));
+    assert!(status("/json/list", vec![String::from("Host: [::1]:9229")]).contains("200"));
+    assert!(status("/json/list", vec![host.clone()]).contains("200"));
+
+    drop(inspector);
+    cx.drop();
+}
+
+#[test]
+fn breakpoint_pause_and_inspect() {
+    const SCRIPT: &str = "function add(a, b) {
+  const sum = a + b;
+  return sum;
+}
+console.log('sum', add(1, 2));
+";
+
+    run_with_client(
+        |mut client| {
+            client.result("Runtime.enable", json!({}));
+            client.wait_for_event("Runtime.executionContextCreated");
+            client.result("Debugger.enable", json!({}));
+
+            let breakpoint = client.result(
+                "Debugger.setBreakpointByUrl",
+                json!({ "url": "file:///test.js", "lineNumber": 1 }),
+            );
+            assert_eq!(breakpoint["locations"], json!([]));
+
+            client.result("Runtime.runIfWaitingForDebugger", json!({}));
+
+            let script = client.wait_for_event("Debugger.scriptParsed");
+            assert_eq!(script["url"], "file:///test.js");
+
+            let resolved = client.wait_for_event("Debugger.breakpointResolved");
+            assert_eq!(resolved["breakpointId"], breakpoint["breakpointId"]);
+
+            let source = client.result(
+                "Debugger.getScriptSource",
+                json!({ "scriptId": script["scriptId"] }),
+            );
+            assert_eq!(source["scriptSource"], SCRIPT);
+
+            // Paused at the breakpoint in `add`
+            let paused = client.wait_for_event("Debugger.paused");
+            assert_eq!(paused["reason"], "other");
+            assert_eq!(
+                paused["hitBreakpoints"],
+                json!([breakpoint["breakpointId"]])
+            );
+
+            let frame = &paused["callFrames"][0];
+            assert_eq!(frame["functionName"], "add");
+            assert_eq!(frame["location"]["scriptId"], script["scriptId"]);
+            assert_eq!(frame["location"]["lineNumber"], 1);
+
+            let result = client.result(
+                "Debugger.evaluateOnCallFrame",
+                json!({ "callFrameId": frame["callFrameId"], "expression": "a * 10 + b" }),
+            );
+            assert_eq!(result["result"]["value"], 12);
+
+            // Locals can be inspected through the local scope
+            let local_scope = frame["scopeChain"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|scope| scope["type"] == "local")
+                .unwrap();
+            let locals = client.properties(&local_scope["object"]["objectId"]);
+            assert_eq!(property(&locals, "a")["value"], 1);
+            assert_eq!(property(&locals, "b")["value"], 2);
+
+            client.result("Debugger.resume", json!({}));
+            client.wait_for_event("Debugger.resumed");
+
+            // Console output is reported with remote object arguments
+            let console_call = client.wait_for_event("Runtime.consoleAPICalled");
+            assert_eq!(console_call["type"], "log");
+            assert_eq!(console_call["args"][0]["value"], "sum");
+            assert_eq!(console_call["args"][1]["value"], 3);
+        },
+        |cx| evaluate(cx, "/test.js", SCRIPT),
+    );
+}
+
+#[test]
+fn stepping_and_pause_on_exceptions() {
+    const SCRIPT: &str = "function thrower() {
+  throw new Error('caught');
+}
+try {
+  thrower();
+} catch (e) {}
+let x = thrower.name.length;
+x = x + 1;
+";
+
+    run_with_client(
+        |mut client| {
+            client.result("Debugger.enable", json!({}));
+            client.result("Debugger.setPauseOnExceptions", json!({ "state": "all" }));
+            client.result("Runtime.runIfWaitingForDebugger", json!({}));
+
+            let paused = client.wait_for_event("Debugger.paused");
+            assert_eq!(paused["reason"], "exception");
+            assert_eq!(paused["data"]["subtype"], "error");
+            assert_eq!(paused["data"]["uncaught"], false);
+            assert_eq!(paused["callFrames"][0]["functionName"], "thrower");
+            assert_eq!(paused["callFrames"][0]["location"]["lineNumber"], 1);
+
+            // Unknown commands are reported as errors, even while paused
+            let unknown = client.call("Debugger.unknownCommand", json!({}));
+            assert_eq!(unknown["error"]["code"], -32601);
+
+            client.result("Debugger.setPauseOnExceptions", json!({ "state": "none" }));
+            client.result(
+                "Debugger.setBreakpointByUrl",
+                json!({ "url": "file:///step.js", "lineNumber": 6 }),
+            );
+            client.result("Debugger.resume", json!({}));
+            client.wait_for_event("Debugger.resumed");
+
+            let paused = client.wait_for_event("Debugger.paused");
+            assert_eq!(paused["callFrames"][0]["location"]["lineNumber"], 6);
+
+            client.result("Debugger.stepOver", json!({}));
+            client.wait_for_event("Debugger.resumed");
+
+            let paused = client.wait_for_event("Debugger.paused");
+            assert_eq!(paused["hitBreakpoints"], json!([]));
+            assert_eq!(paused["callFrames"][0]["location"]["lineNumber"], 7);
+
+            client.result("Debugger.resume", json!({}));
+            client.wait_for_event("Debugger.resumed");
+
+            // Resuming is an error once execution has resumed
+            let response = client.call("Debugger.resume", json!({}));
+            assert!(response.get("error").is_some());
+        },
+        |cx| evaluate(cx, "/step.js", SCRIPT),
+    );
+}
+
+#[test]
+fn runtime_evaluate_and_remote_objects() {
+    run_with_client(
+        |mut client| {
+            client.result("Runtime.enable", json!({}));
+            client.result("Runtime.runIfWaitingForDebugger", json!({}));
+
+            let result = client.result(
+                "Runtime.evaluate",
+                json!({ "expression": "({ x: 1, nested: { y: 'z' }, list: [1, 2] })" }),
+            );
+            let object = &result["result"];
+            assert_eq!(object["type"], "object");
+            assert_eq!(object["className"], "Object");
+
+            let response = client.result(
+                "Runtime.getProperties",
+                json!({ "objectId": object["objectId"], "ownProperties": true }),
+            );
+            assert_eq!(response["internalProperties"][0]["name"], "[[Prototype]]");
+
+            let properties = client.properties(&object["objectId"]);
+            let names = properties
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["x", "nested", "list"]);
+            assert_eq!(property(&properties, "x")["value"], 1);
+            assert_eq!(property(&properties, "list")["subtype"], "array");
+
+            let nested = client.properties(&property(&properties, "nested")["objectId"]);
+            assert_eq!(property(&nested, "y")["value"], "z");
+
+            // Functions can be called on remote objects
+            let result = client.result(
+                "Runtime.callFunctionOn",
+                json!({
+                    "objectId": object["objectId"],
+                    "functionDeclaration": "function (n) { return this.x + n; }",
+                    "arguments": [{ "value": 41 }],
+                    "returnByValue": true,
+                }),
+            );
+            assert_eq!(result["result"]["value"], 42);
+
+            let result = client.result(
+                "Runtime.evaluate",
+                json!({ "expression": "[1, 'two', { three: 3 }]", "returnByValue": true }),
+            );
+            assert_eq!(result["result"]["value"], json!([1, "two", { "three": 3 }]));
+
+            // Released objects can no longer be inspected
+            client.result(
+                "Runtime.releaseObject",
+                json!({ "objectId": object["objectId"] }),
+            );
+            let response = client.call(
+                "Runtime.getProperties",
+                json!({ "objectId": object["objectId"] }),
+            );
+            assert!(response.get("error").is_some());
+
+            // Exceptions and syntax errors are reported as exception details
+            let result = client.result(
+                "Runtime.evaluate",
+                json!({ "expression": "throw new Error('boom')" }),
+            );
+            assert_eq!(result["exceptionDetails"]["exception"]["subtype"], "error");
+
+            let result = client.result("Runtime.evaluate", json!({ "expression": "1 +" }));
+            assert!(result.get("exceptionDetails").is_some());
+
+            // Messages logged before the Console domain was enabled are replayed
+            client.result("Console.enable", json!({}));
+            let message = client.wait_for_event("Console.messageAdded");
+            assert_eq!(message["message"]["text"], "early message 1");
+            assert_eq!(message["message"]["level"], "warning");
+
+            let response = client.call("Unknown.method", json!({}));
+            assert_eq!(response["error"]["code"], -32601);
+        },
+        |cx| evaluate(cx, "/console.js", "console.warn('early message', 1);"),
+    );
+}
+
+#[test]
+fn cpu_profile() {
+    run_with_client(
+        |mut client| {
+            client.result("Profiler.enable", json!({}));
+            client.result("Profiler.setSamplingInterval", json!({ "interval": 100 }));
+            client.result("Profiler.start", json!({}));
+            client.result("Runtime.runIfWaitingForDebugger", json!({}));
+
+            let profile = client.result("Profiler.stop", json!({}))["profile"].clone();
+            let nodes = profile["nodes"].as_array().unwrap();
+            assert_eq!(nodes[0]["callFrame"]["functionName"], "(root)");
+
+            let samples = profile["samples"].as_array().unwrap();
+            assert!(!samples.is_empty());
+            assert_eq!(
+                samples.len(),
+                profile["timeDeltas"].as_array().unwrap().len()
+            );
+
+            // The hot function is attributed to its source position
+            let hot = nodes
+                .iter()
+                .find(|node| node["callFrame"]["functionName"] == "hot")
+                .unwrap();
+            assert_eq!(hot["callFrame"]["url"], "file:///profile.js");
+            assert_eq!(hot["callFrame"]["lineNumber"], 0);
+
+            // Builtins called by the hot function have their own nodes
+            let max = nodes
+                .iter()
+                .find(|node| node["callFrame"]["functionName"] == "max")
+                .unwrap();
+            assert_eq!(max["codeType"], "other");
+            assert!(hot["children"].as_array().unwrap().contains(&max["id"]));
+
+            // Stopping again fails since no profile is being recorded
+            let response = client.call("Profiler.stop", json!({}));
+            assert!(response.get("error").is_some());
+        },
+        |cx| {
+            evaluate(
+                cx,
+                "/profile.js",
+                "function hot() {\n\
+                   let sum = 0;\n\
+                   for (let i = 0; i < 200000; i++) sum += Math.max(i, 1);\n\
+                   return sum;\n\
+                 }\n\
+                 hot();",
+            )
+        },
+    );
+}
+
+#[test]
+fn precise_coverage() {
+    const SOURCE: &str = "function used(x) {\n\
+                            if (x > 1) {\n\
+                              return 'big';\n\
+                            }\n\
+                            return 'small';\n\
+                          }\n\
+                          function unused() {}\n\
+                          used(1); used(2); used(3);";
+
+    run_with_client(
+        |mut client| {
+            client.result(
+                "Profiler.startPreciseCoverage",
+                json!({ "callCount": true, "detailed": true }),
+            );
+            client.result("Runtime.runIfWaitingForDebugger", json!({}));
+
+            let result = client.result("Profiler.takePreciseCoverage", json!({}))["result"].clone();
+            let script = result
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|script| script["url"] == "file:///coverage.js")
+                .unwrap();
+
+            let functions = script["functions"].as_array().unwrap();
+            let function = |name: &str| {
+                functions
+                    .iter()
+                    .find(|function| function["functionName"] == name)
+                    .unwrap()
+                    .clone()
+            };
+            let range = |start: usize, end: usize, count: u32| json!({ "startOffset": start, "endOffset": end, "count": count });
+
+            // The toplevel function is anonymous and covers the entire script
+            assert_eq!(function("")["ranges"][0], range(0, SOURCE.len(), 1));
+
+            // Blocks are only reported when their count differs from the enclosing range
+            let used = function("used");
+            let if_block_start = SOURCE.find("{\nreturn 'big'").unwrap();
+            let if_block_end = SOURCE.find("}\nreturn 'small'").unwrap() + 1;
+            let used_end = SOURCE.find("\nfunction unused").unwrap();
+            assert_eq!(
+                used["ranges"],
+                json!([
+                    range(0, used_end, 3),
+                    range(if_block_start, if_block_end, 2),
+                    range(if_block_end, SOURCE.find("'small';").unwrap() + 8, 1),
+                ])
+            );
+            assert_eq!(used["isBlockCoverage"], true);
+
+            // Functions that never ran only report the function range
+            let unused = function("unused");
+            assert_eq!(unused["ranges"].as_array().unwrap().len(), 1);
+            assert_eq!(unused["ranges"][0]["count"], 0);
+
+            // Taking coverage resets counts
+            let result = client.result("Profiler.takePreciseCoverage", json!({}))["result"].clone();
+            let script = result
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|script| script["url"] == "file:///coverage.js")
+                .unwrap()
+                .clone();
+            assert!(script["functions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|function| function["ranges"][0]["count"] == 0));
+
+            // Taking coverage after it is stopped fails
+            client.result("Profiler.stopPreciseCoverage", json!({}));
+            let response = client.call("Profiler.takePreciseCoverage", json!({}));
+            assert!(response.get("error").is_some());
+        },
+        |cx| evaluate(cx, "/coverage.js", SOURCE),
+    );
+}
@@ -0,0 +1,252 @@
+//! Minimal WebSocket (RFC 6455) framing, enough to exchange CDP text messages with a single client.
+
+use std::io::{self, Read, Write};
+
+/// GUID appended to the client's key when computing the handshake accept key
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Maximum size of a single message. CDP messages are JSON and much smaller in practice.
+const MAX_MESSAGE_SIZE: u64 = 256 * 1024 * 1024;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+pub const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+pub const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+pub const OPCODE_PONG: u8 = 0xA;
+
+/// A complete message, with any fragmented frames joined together.
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong,
+    Close,
+}
+
+/// The value of the Sec-WebSocket-Accept header for a client's Sec-WebSocket-Key.
+pub fn accept_key(client_key: &str) -> String {
+    let mut input = client_key.trim().as_bytes().to_vec();
+    input.extend_from_slice(HANDSHAKE_GUID.as_bytes());
+
+    base64_encode(&sha1(&input))
+}
+
+/// Reads complete messages from a stream. The frames of a fragmented message are kept across
+/// reads, since control frames interleaved with them are returned as soon as they arrive.
+pub struct MessageReader<R: Read> {
+    stream: R,
+    /// Payload of the fragmented message read so far
+    payload: Vec<u8>,
+    /// Opcode of the fragmented message read so far, if any
+    message_opcode: Option<u8>,
+}
+
+impl<R: Read> MessageReader<R> {
+    pub fn new(stream: R) -> Self {
+        MessageReader {
+            stream,
+            payload: vec![],
+            message_opcode: None,
+        }
+    }
+
+    /// Read the next complete message from the stream.
+    pub fn read_message(&mut self) -> io::Result<Message> {
+        loop {
+            let mut header = [0; 2];
+            self.stream.read_exact(&mut header)?;
+
+            let is_final = header[0] & 0x80 != 0;
+            let opcode = header[0] & 0x0F;
+            let is_masked = header[1] & 0x80 != 0;
+
+            let length = match header[1] & 0x7F {
+                126 => {
+                    let mut bytes = [0; 2];
+                    self.stream.read_exact(&mut bytes)?;
+                    u16::from_be_bytes(bytes) as u64
+                }
+                127 => {
+                    let mut bytes = [0; 8];
+                    self.stream.read_exact(&mut bytes)?;
+                    u64::from_be_bytes(bytes)
+                }
+                length => length as u64,
+            };
+
+            if self.payload.len() as u64 + length > MAX_MESSAGE_SIZE {
+                return Err(invalid_data("WebSocket message too large"));
+            }
+
+            let mut mask = [0; 4];
+            if is_masked {
+                self.stream.read_exact(&mut mask)?;
+            }
+
+            let mut frame_payload = vec![0; length as usize];
+            self.stream.read_exact(&mut frame_payload)?;
+
+            if is_masked {
+                for (i, byte) in frame_payload.iter_mut().enumerate() {
+                    *byte ^= mask[i % 4];
+                }
+            }
+
+            // Control frames may be interleaved with the frames of a fragmented message
+            match opcode {
+                OPCODE_CLOSE => return Ok(Message::Close),
+                OPCODE_PING => return Ok(Message::Ping(frame_payload)),
+                OPCODE_PONG => return Ok(Message::Pong),
+                OPCODE_CONTINUATION if self.message_opcode.is_none() => {
+                    return Err(invalid_data("unexpected WebSocket continuation frame"));
+                }
+                OPCODE_CONTINUATION => {}
+                OPCODE_TEXT | OPCODE_BINARY if self.message_opcode.is_some() => {
+                    return Err(invalid_data("expected WebSocket continuation frame"));
+                }
+                OPCODE_TEXT | OPCODE_BINARY => self.message_opcode = Some(opcode),
+                _ => return Err(invalid_data("unknown WebSocket opcode")),
+            }
+
+            self.payload.extend_from_slice(&frame_payload);
+
+            if is_final {
+                break;
+            }
+        }
+
+        let payload = std::mem::take(&mut self.payload);
+        if self.message_opcode.take() == Some(OPCODE_TEXT) {
+            String::from_utf8(payload)
+                .map(Message::Text)
+                .map_err(|_| invalid_data("WebSocket text message is not valid UTF-8"))
+        } else {
+            Ok(Message::Binary(payload))
+        }
+    }
+}
+
+/// Write a single unfragmented frame. Servers send unmasked frames, while clients must mask every
+/// frame they send.
+pub fn write_frame(
+    stream: &mut impl Write,
+    opcode: u8,
+    payload: &[u8],
+    mask: Option<[u8; 4]>,
+) -> io::Result<()> {
+    let mut frame = Vec::with_capacity(payload.len() + 14);
+    frame.push(0x80 | opcode);
+
+    let mask_bit = if mask.is_some() { 0x80 } else { 0 };
+    if payload.len() < 126 {
+        frame.push(mask_bit | payload.len() as u8);
+    } else if payload.len() <= u16::MAX as usize {
+        frame.push(mask_bit | 126);
+        frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    } else {
+        frame.push(mask_bit | 127);
+        frame.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+    }
+
+    match mask {
+        Some(mask) => {
+            frame.extend_from_slice(&mask);
+            frame.extend(
+                payload
+                    .iter()
+                    .enumerate()
+                    .map(|(i, byte)| byte ^ mask[i % 4]),
+            );
+        }
+        None => frame.extend_from_slice(payload),
+    }
+
+    stream.write_all(&frame)?;
+    stream.flush()
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// SHA-1 digest, only used for the WebSocket handshake.
+fn sha1(input: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    // Pad with a single set bit, then zeros, then the message length in bits
+    let mut message = input.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((input.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut words = [0u32; 80];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            words[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in words.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (value, added) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (i, value) in state.iter().enumerate() {
+        digest[(i * 4)..(i * 4 + 4)].copy_from_slice(&value.to_be_bytes());
+    }
+
+    digest
+}
+
+/// Standard base64 encoding with padding.
+fn base64_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = ((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | bytes[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[((bits >> (18 - i * 6)) & 0x3F) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,188 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use so2js::{
+    common::wtf_8::Wtf8String,
+    parser::source::Source,
+    runtime::{
+        debugger::{Debugger, PauseOnExceptions, PauseReason, PausedState, ResumeAction},
+        Context, ContextBuilder,
+    },
+};
+
+const PATH: &str = "test.js";
+
+/// A pause seen by the test debugger
+#[derive(Debug, PartialEq)]
+enum Pause {
+    Breakpoint { line: usize, function: String },
+    Step { line: usize, function: String },
+    Exception { is_caught: bool },
+}
+
+/// Debugger that records every pause and resumes with the next scripted action, continuing once
+/// the script runs out.
+struct TestDebugger {
+    pauses: Rc<RefCell<Vec<Pause>>>,
+    actions: VecDeque<ResumeAction>,
+}
+
+impl Debugger for TestDebugger {
+    fn on_pause(&mut self, cx: Context, paused: &PausedState) -> ResumeAction {
+        let frame = &paused.frames()[0];
+        let line = frame
+            .location(cx)
+            .unwrap()
+            .map_or(0, |location| location.line);
+        let function = match frame.function_name(cx) {
+            Some(name) => name.format(cx).unwrap(),
+            None => String::new(),
+        };
+
+        let pause = match paused.reason() {
+            PauseReason::Breakpoint(_) => Pause::Breakpoint { line, function },
+            PauseReason::Step => Pause::Step { line, function },
+            PauseReason::Exception { is_caught, .. } => Pause::Exception {
+                is_caught: *is_caught,
+            },
+            PauseReason::PauseRequested => panic!("unexpected pause request"),
+        };
+        self.pauses.borrow_mut().push(pause);
+
+        self.actions.pop_front().unwrap_or(ResumeAction::Continue)
+    }
+}
+
+fn attach(cx: &mut Context, actions: &[ResumeAction]) -> Rc<RefCell<Vec<Pause>>> {
+    let pauses = Rc::new(RefCell::new(vec![]));
+    cx.attach_debugger(Box::new(TestDebugger {
+        pauses: pauses.clone(),
+        actions: actions.iter().copied().collect(),
+    }));
+
+    pauses
+}
+
+fn evaluate(mut cx: Context, code: &str) -> bool {
+    let source = Source::new_for_string(PATH, Wtf8String::from_str(code)).unwrap();
+    cx.evaluate_script(Rc::new(source)).is_ok()
+}
+
+const ADD: &str = "function add(a, b) {
+  var sum = a.value + b.value;
+  return sum.toString();
+}
+var x = add({ value: 1 }, { value: 2 }).toString();
+var y = add({ value: 3 }, { value: 4 }).toString();
+";
+
+fn breakpoint(line: usize, function: &str) -> Pause {
+    Pause::Breakpoint {
+        line,
+        function: function.to_string(),
+    }
+}
+
+fn step(line: usize, function: &str) -> Pause {
+    Pause::Step {
+        line,
+        function: function.to_string(),
+    }
+}
+
+#[test]
+fn breakpoints() {
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(&mut cx, &[]);
+
+    let id = cx.set_breakpoint(PATH, 2, None);
+    assert!(evaluate(cx, ADD));
+    assert_eq!(
+        *pauses.borrow(),
+        [breakpoint(2, "add"), breakpoint(2, "add")]
+    );
+
+    // Breakpoints are still hit after a GC drops resolved breakpoint locations
+    pauses.borrow_mut().clear();
+    cx.run_gc();
+    assert!(evaluate(cx, "add({ value: 5 }, { value: 6 });"));
+    assert_eq!(*pauses.borrow(), [breakpoint(2, "add")]);
+
+    // Removed breakpoints are no longer hit
+    pauses.borrow_mut().clear();
+    assert!(cx.remove_breakpoint(id));
+    assert!(!cx.remove_breakpoint(id));
+    assert!(evaluate(cx, "add({ value: 7 }, { value: 8 });"));
+    assert!(pauses.borrow().is_empty());
+}
+
+#[test]
+fn breakpoints_in_other_files_not_hit() {
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(&mut cx, &[]);
+
+    cx.set_breakpoint("other.js", 2, None);
+    assert!(evaluate(cx, ADD));
+    assert!(pauses.borrow().is_empty());
+}
+
+#[test]
+fn step_over_into_and_out() {
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(
+        &mut cx,
+        &[
+            ResumeAction::StepInto,
+            ResumeAction::StepOver,
+            ResumeAction::StepOut,
+            ResumeAction::StepOver,
+        ],
+    );
+
+    cx.set_breakpoint(PATH, 5, None);
+    assert!(evaluate(cx, ADD));
+
+    // Top level code may or may not be named, so only its lines are checked
+    let pauses = pauses.borrow();
+    assert_eq!(pauses.len(), 5);
+    assert!(matches!(&pauses[0], Pause::Breakpoint { line: 5, function } if function != "add"));
+    assert_eq!(pauses[1..3], [step(2, "add"), step(3, "add")]);
+
+    // Stepping out of add pauses in the rest of line 5 in the top level code
+    assert!(matches!(&pauses[3], Pause::Step { line: 5, function } if function != "add"));
+
+    // Stepping over line 6 does not pause within add
+    assert!(matches!(&pauses[4], Pause::Step { line: 6, function } if function != "add"));
+}
+
+#[test]
+fn pause_on_exceptions() {
+    let code = "try { null.value; } catch (e) {}
+                null.value;";
+
+    // Uncaught exceptions only
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(&mut cx, &[]);
+    cx.set_pause_on_exceptions(PauseOnExceptions::Uncaught);
+    assert!(!evaluate(cx, code));
+    assert_eq!(*pauses.borrow(), [Pause::Exception { is_caught: false }]);
+
+    // All exceptions
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(&mut cx, &[]);
+    cx.set_pause_on_exceptions(PauseOnExceptions::All);
+    assert!(!evaluate(cx, code));
+    assert_eq!(
+        *pauses.borrow(),
+        [
+            Pause::Exception { is_caught: true },
+            Pause::Exception { is_caught: false }
+        ]
+    );
+
+    // No exceptions
+    let mut cx = ContextBuilder::new().build().unwrap();
+    let pauses = attach(&mut cx, &[]);
+    cx.set_pause_on_exceptions(PauseOnExceptions::None);
+    assert!(!evaluate(cx, code));
+    assert!(pauses.borrow().is_empty());
+}
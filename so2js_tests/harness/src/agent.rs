@@ -0,0 +1,222 @@
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    sync::{Arc, Condvar, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use so2js::{
+    common::{options::OptionsBuilder, wtf_8::Wtf8String},
+    parser::{self, source::Source, ParseContext},
+    runtime::{
+        shared_memory::SharedDataBlock,
+        test_262_object::{Test262Agent, Test262Object},
+        Context, ContextBuilder,
+    },
+};
+
+use crate::runner::{execute_script_as_bytecode, parse_file, HEAP_SIZE, RUNNER_THREAD_STACK_SIZE};
+
+/// The agents started by a single test. Each agent runs on its own thread with its own context.
+pub struct AgentGroup {
+    state: Mutex<AgentGroupState>,
+    condvar: Condvar,
+    start_time: Instant,
+}
+
+#[derive(Default)]
+struct AgentGroupState {
+    /// The most recent broadcast sent by the main agent.
+    broadcast: Option<(Arc<SharedDataBlock>, f64)>,
+    /// Incremented each time a broadcast is sent.
+    broadcast_id: u64,
+    /// Number of running agents that have not yet received the most recent broadcast.
+    num_pending_receivers: usize,
+    num_running_agents: usize,
+    reports: VecDeque<String>,
+    threads: Vec<JoinHandle<()>>,
+    is_shut_down: bool,
+}
+
+impl AgentGroup {
+    pub fn new() -> Arc<AgentGroup> {
+        Arc::new(AgentGroup {
+            state: Mutex::new(AgentGroupState::default()),
+            condvar: Condvar::new(),
+            start_time: Instant::now(),
+        })
+    }
+
+    /// Wake up all agents waiting for a broadcast and wait for every agent thread to exit.
+    pub fn shut_down(&self) {
+        let threads = {
+            let mut state = self.state.lock().unwrap();
+            state.is_shut_down = true;
+            self.condvar.notify_all();
+
+            std::mem::take(&mut state.threads)
+        };
+
+        for thread in threads {
+            let _ = thread.join();
+        }
+    }
+
+    fn start_agent(self: &Arc<Self>, source: String) {
+        let group = self.clone();
+
+        // Agent is running as soon as it is started so that broadcasts wait for it
+        let mut state = self.state.lock().unwrap();
+        state.num_running_agents += 1;
+        let broadcast_id = state.broadcast_id;
+
+        let thread = thread::Builder::new()
+            .stack_size(RUNNER_THREAD_STACK_SIZE)
+            .spawn(move || {
+                let handle = Rc::new(AgentHandle::new(group.clone(), broadcast_id));
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                    run_agent(handle.clone(), source);
+                }));
+
+                group.finish_agent(&handle);
+            })
+            .unwrap();
+
+        state.threads.push(thread);
+    }
+
+    fn finish_agent(&self, handle: &AgentHandle) {
+        let mut state = self.state.lock().unwrap();
+        state.num_running_agents -= 1;
+
+        // An agent that exits without receiving the broadcast will never receive it
+        if handle.last_broadcast_id.get() < state.broadcast_id {
+            state.num_pending_receivers -= 1;
+        }
+
+        self.condvar.notify_all();
+    }
+}
+
+/// A single agent's view of its agent group.
+struct AgentHandle {
+    group: Arc<AgentGroup>,
+    /// Id of the last broadcast received by this agent.
+    last_broadcast_id: Cell<u64>,
+}
+
+impl AgentHandle {
+    fn new(group: Arc<AgentGroup>, last_broadcast_id: u64) -> AgentHandle {
+        AgentHandle {
+            group,
+            last_broadcast_id: Cell::new(last_broadcast_id),
+        }
+    }
+}
+
+/// Create the agent hooks for the main agent of a test.
+pub fn main_agent(group: Arc<AgentGroup>) -> Rc<dyn Test262Agent> {
+    Rc::new(AgentHandle::new(group, 0))
+}
+
+impl Test262Agent for AgentHandle {
+    fn start(&self, source: String) {
+        self.group.start_agent(source);
+    }
+
+    fn broadcast(&self, data_block: Arc<SharedDataBlock>, number: f64) {
+        let mut state = self.group.state.lock().unwrap();
+        state.broadcast = Some((data_block, number));
+        state.broadcast_id += 1;
+        state.num_pending_receivers = state.num_running_agents;
+        self.group.condvar.notify_all();
+
+        while state.num_pending_receivers > 0 {
+            state = self.group.condvar.wait(state).unwrap();
+        }
+    }
+
+    fn get_report(&self) -> Option<String> {
+        self.group.state.lock().unwrap().reports.pop_front()
+    }
+
+    fn receive_broadcast(&self) -> Option<(Arc<SharedDataBlock>, f64)> {
+        let mut state = self.group.state.lock().unwrap();
+
+        while state.broadcast_id == self.last_broadcast_id.get() {
+            if state.is_shut_down {
+                return None;
+            }
+
+            state = self.group.condvar.wait(state).unwrap();
+        }
+
+        self.last_broadcast_id.set(state.broadcast_id);
+        state.num_pending_receivers -= 1;
+        self.group.condvar.notify_all();
+
+        state.broadcast.clone()
+    }
+
+    fn report(&self, report: String) {
+        self.group.state.lock().unwrap().reports.push_back(report);
+    }
+
+    fn leaving(&self) {}
+
+    fn sleep(&self, millis: f64) {
+        if millis > 0.0 {
+            thread::sleep(Duration::from_secs_f64(
+                millis.min(u32::MAX as f64) / 1000.0,
+            ));
+        }
+    }
+
+    fn monotonic_now(&self) -> f64 {
+        self.group.start_time.elapsed().as_secs_f64() * 1000.0
+    }
+}
+
+/// Evaluate the agent's source in a new context, running until the task queue is empty.
+fn run_agent(handle: Rc<AgentHandle>, source: String) {
+    let options = OptionsBuilder::new().heap_size(HEAP_SIZE).build();
+    let mut cx = ContextBuilder::new()
+        .set_options(Rc::new(options))
+        .build()
+        .unwrap();
+
+    cx.test_262_agent = Some(handle as Rc<dyn Test262Agent>);
+    Test262Object::install(cx, cx.initial_realm()).unwrap();
+
+    let panic_result = panic::catch_unwind(AssertUnwindSafe(|| evaluate_agent_source(cx, source)));
+
+    cx.drop();
+
+    if let Err(err) = panic_result {
+        panic::resume_unwind(err);
+    }
+}
+
+fn evaluate_agent_source(cx: Context, source: String) {
+    let source = match Source::new_for_string("<agent>", Wtf8String::from_string(source)) {
+        Ok(source) => source,
+        Err(_) => return,
+    };
+
+    let mut pcx = ParseContext::new(Rc::new(source));
+    let parse_result = match parse_file(&mut pcx, cx.options.clone(), None) {
+        Ok(parse_result) => parse_result,
+        Err(_) => return,
+    };
+
+    let analyzed_result = match parser::analyze::analyze(parse_result) {
+        Ok(analyzed_result) => analyzed_result,
+        Err(_) => return,
+    };
+
+    // Errors thrown in agents are not observable by the main agent
+    let _ = execute_script_as_bytecode(cx, &analyzed_result);
+}
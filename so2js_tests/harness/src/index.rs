@@ -29,6 +29,12 @@ pub struct Test {
     pub includes: Vec<String>,
     /// Tags for categorizing tests by feature, allows easy filtering by feature
     pub features: Vec<String>,
+    /// Default time zone of the context the test is run in. Tests run in UTC if not specified.
+    #[serde(default)]
+    pub time_zone: Option<String>,
+    /// Run test in an agent that cannot be suspended by Atomics.wait.
+    #[serde(default)]
+    pub cannot_block: bool,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -182,6 +188,7 @@ impl TestIndex {
 
         let mut is_async = false;
         let mut is_raw = false;
+        let mut cannot_block = false;
         let mut includes = vec![];
 
         let mut mode = TestMode::Script;
@@ -204,6 +211,9 @@ impl TestIndex {
                         is_async = true;
                         includes.push("doneprintHandle.js".to_owned());
                     }
+                    "CanBlockIsFalse" => {
+                        cannot_block = true;
+                    }
                     _ => {}
                 }
             }
@@ -222,6 +232,8 @@ impl TestIndex {
             }
         }
 
+        let time_zone = metadata[0]["timeZone"].as_str().map(String::from);
+
         // Make path relative to the suite root.
         let path = test_path.strip_prefix(suite_root).unwrap();
         let path = path.to_string_lossy().into_owned();
@@ -238,6 +250,8 @@ impl TestIndex {
             is_annex_b,
             includes,
             features,
+            time_zone,
+            cannot_block,
         };
 
         self.tests.insert(path, test);
@@ -1,3 +1,4 @@
+mod agent;
 pub mod ignored;
 pub mod index;
 pub mod manifest;
@@ -12,6 +12,7 @@ use std::{
 };
 
 use crate::{
+    agent::{main_agent, AgentGroup},
     ignored::IgnoredIndex,
     index::{ExpectedResult, Test, TestIndex, TestMode, TestPhase},
     manifest::{Suite, SuiteFilter, TestManifest},
@@ -44,10 +45,10 @@ pub struct TestRunner {
 }
 
 // Runner threads have an 8MB stack
-const RUNNER_THREAD_STACK_SIZE: usize = 1 << 23;
+pub(crate) const RUNNER_THREAD_STACK_SIZE: usize = 1 << 23;
 
 /// Size of the heap for each test. Use a small value since most tests do not require a large heap.
-const HEAP_SIZE: usize = 1024 * 1024;
+pub(crate) const HEAP_SIZE: usize = 1024 * 1024;
 
 impl TestRunner {
     pub fn new(
@@ -234,12 +235,24 @@ fn run_single_test(
         .build();
 
     // Each test is executed in its own realm
-    let cx = ContextBuilder::new()
+    let mut cx = ContextBuilder::new()
         .set_options(Rc::new(options))
         .build()
         .unwrap();
     let options = cx.options.clone();
 
+    if let Some(time_zone) = &test.time_zone {
+        assert!(
+            cx.set_default_time_zone(time_zone),
+            "unsupported time zone {time_zone}"
+        );
+    }
+
+    // Agents started by the test share memory with the main agent
+    let agent_group = AgentGroup::new();
+    cx.test_262_agent = Some(main_agent(agent_group.clone()));
+    cx.can_block = !test.cannot_block;
+
     // Each realm has access to the test262 object
     Test262Object::install(cx, cx.initial_realm()).unwrap();
 
@@ -326,6 +339,7 @@ fn run_single_test(
     #[cfg(feature = "handle_stats")]
     println!("{:?}", cx.handle_context.handle_stats());
 
+    agent_group.shut_down();
     cx.drop();
 
     match panic_result {
@@ -385,7 +399,7 @@ fn new_parse_context(
     Ok(ParseContext::new(Rc::new(source)))
 }
 
-fn parse_file<'a>(
+pub(crate) fn parse_file<'a>(
     pcx: &'a mut ParseContext,
     options: Rc<Options>,
     test: Option<&Test>,
@@ -432,7 +446,7 @@ fn load_harness_test_file(cx: Context, test262_root: &str, file: &str) {
     }
 }
 
-fn execute_script_as_bytecode<'a>(
+pub(crate) fn execute_script_as_bytecode<'a>(
     mut cx: Context,
     analyzed_result: &'a parser::analyze::AnalyzedProgramResult<'a>,
 ) -> EvalResult<StackRoot<Value>> {
@@ -0,0 +1,210 @@
+use std::rc::Rc;
+
+use serde_json::Value as JsonValue;
+use so2js::{
+    common::wtf_8::Wtf8String,
+    parser::source::Source,
+    runtime::{Context, ContextBuilder},
+};
+
+/// A parsed heap snapshot, with the fields of each node and edge looked up by name.
+struct Snapshot {
+    json: JsonValue,
+    node_fields: Vec<String>,
+    edge_fields: Vec<String>,
+}
+
+struct Node {
+    node_type: String,
+    name: String,
+    edge_count: usize,
+}
+
+struct Edge {
+    edge_type: String,
+    name: String,
+    to_node: usize,
+}
+
+impl Snapshot {
+    fn take(mut cx: Context) -> Snapshot {
+        let mut output = String::new();
+        cx.write_heap_snapshot(&mut output).unwrap();
+
+        let json: JsonValue = serde_json::from_str(&output).unwrap();
+        let meta = &json["snapshot"]["meta"];
+        let node_fields = string_array(&meta["node_fields"]);
+        let edge_fields = string_array(&meta["edge_fields"]);
+
+        Snapshot {
+            json,
+            node_fields,
+            edge_fields,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.json["snapshot"]["node_count"].as_u64().unwrap() as usize
+    }
+
+    fn edge_count(&self) -> usize {
+        self.json["snapshot"]["edge_count"].as_u64().unwrap() as usize
+    }
+
+    fn string(&self, index: &JsonValue) -> String {
+        let index = index.as_u64().unwrap() as usize;
+        self.json["strings"][index].as_str().unwrap().to_string()
+    }
+
+    fn type_name(&self, kind: &str, index: &JsonValue) -> String {
+        let types = &self.json["snapshot"]["meta"][kind][0];
+        let index = index.as_u64().unwrap() as usize;
+        types[index].as_str().unwrap().to_string()
+    }
+
+    fn nodes(&self) -> Vec<Node> {
+        let values = self.json["nodes"].as_array().unwrap();
+        let field = |name: &str| self.node_fields.iter().position(|f| f == name).unwrap();
+
+        values
+            .chunks(self.node_fields.len())
+            .map(|node| Node {
+                node_type: self.type_name("node_types", &node[field("type")]),
+                name: self.string(&node[field("name")]),
+                edge_count: node[field("edge_count")].as_u64().unwrap() as usize,
+            })
+            .collect()
+    }
+
+    /// The edges of each node, in node order
+    fn edges(&self) -> Vec<Vec<Edge>> {
+        let values = self.json["edges"].as_array().unwrap();
+        let field = |name: &str| self.edge_fields.iter().position(|f| f == name).unwrap();
+
+        let mut edges = values.chunks(self.edge_fields.len()).map(|edge| {
+            let edge_type = self.type_name("edge_types", &edge[field("type")]);
+            let name = if edge_type == "element" {
+                edge[field("name_or_index")].to_string()
+            } else {
+                self.string(&edge[field("name_or_index")])
+            };
+
+            Edge {
+                edge_type,
+                name,
+                to_node: edge[field("to_node")].as_u64().unwrap() as usize / self.node_fields.len(),
+            }
+        });
+
+        self.nodes()
+            .iter()
+            .map(|node| edges.by_ref().take(node.edge_count).collect())
+            .collect()
+    }
+}
+
+fn string_array(value: &JsonValue) -> Vec<String> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|string| string.as_str().unwrap().to_string())
+        .collect()
+}
+
+fn evaluate(mut cx: Context, code: &str) {
+    let source = Source::new_for_string("test.js", Wtf8String::from_str(code)).unwrap();
+    if let Err(error) = cx.evaluate_script(Rc::new(source)) {
+        panic!("{}", error.format(cx));
+    }
+}
+
+#[test]
+fn heap_snapshot_tables() {
+    let cx = ContextBuilder::new().build().unwrap();
+    evaluate(
+        cx,
+        "class Point { constructor(x) { this.pointX = x; } }
+         var points = [];
+         for (var i = 0; i < 5; i++) {
+           points.push(new Point(i));
+         }",
+    );
+
+    let snapshot = Snapshot::take(cx);
+    let nodes = snapshot.nodes();
+    let edges = snapshot.edges();
+
+    // Tables have one entry per node and edge
+    assert_eq!(nodes.len(), snapshot.node_count());
+    assert_eq!(
+        snapshot.json["nodes"].as_array().unwrap().len(),
+        snapshot.node_count() * snapshot.node_fields.len()
+    );
+    assert_eq!(
+        snapshot.json["edges"].as_array().unwrap().len(),
+        snapshot.edge_count() * snapshot.edge_fields.len()
+    );
+    assert_eq!(
+        nodes.iter().map(|node| node.edge_count).sum::<usize>(),
+        snapshot.edge_count()
+    );
+
+    // The first node is the synthetic root
+    assert_eq!(nodes[0].node_type, "synthetic");
+    assert_eq!(nodes[0].name, "(GC roots)");
+    assert!(nodes[0].edge_count > 0);
+
+    // Every edge points to a node in the snapshot
+    for node_edges in &edges {
+        for edge in node_edges {
+            assert!(edge.to_node < nodes.len());
+        }
+    }
+
+    // Instances are tagged with their class name and have a property edge for each field
+    let points = nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.node_type == "object" && node.name == "Point")
+        .collect::<Vec<_>>();
+    assert_eq!(points.len(), 5);
+
+    for (index, _) in points {
+        let property_edges = edges[index]
+            .iter()
+            .filter(|edge| edge.edge_type == "property")
+            .map(|edge| edge.name.as_str())
+            .collect::<Vec<_>>();
+        assert!(property_edges.contains(&"pointX"));
+    }
+
+    // The class constructor is a closure named after the class
+    assert!(nodes
+        .iter()
+        .any(|node| node.node_type == "closure" && node.name == "Point"));
+}
+
+#[test]
+fn heap_snapshot_class_names_cannot_be_spoofed() {
+    let cx = ContextBuilder::new().build().unwrap();
+    evaluate(
+        cx,
+        "class Real {}
+         Real.prototype.constructor = function Spoofed() {};
+         var instance = new Real();
+         var plain = { constructor: function AlsoSpoofed() {} };",
+    );
+
+    let snapshot = Snapshot::take(cx);
+    let nodes = snapshot.nodes();
+    let object_names = nodes
+        .iter()
+        .filter(|node| node.node_type == "object")
+        .map(|node| node.name.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(object_names.contains(&"Real"));
+    assert!(!object_names.contains(&"Spoofed"));
+    assert!(!object_names.contains(&"AlsoSpoofed"));
+}
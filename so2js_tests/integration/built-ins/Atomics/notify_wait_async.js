@@ -0,0 +1,23 @@
+/*---
+description: Atomics.notify wakes Atomics.waitAsync waiters in the order they started waiting.
+flags: [async]
+---*/
+
+var view = new Int32Array(new SharedArrayBuffer(8));
+var order = [];
+
+var first = Atomics.waitAsync(view, 1, 0);
+var second = Atomics.waitAsync(view, 1, 0);
+
+first.value.then((value) => order.push("first " + value));
+second.value.then((value) => order.push("second " + value));
+
+assert.sameValue(Atomics.notify(view, 0), 0);
+assert.sameValue(Atomics.notify(view, 1, 1), 1);
+assert.sameValue(Atomics.notify(view, 1), 1);
+
+Promise.all([first.value, second.value])
+  .then(() => {
+    assert.sameValue(order.join(", "), "first ok, second ok");
+  })
+  .then($DONE, $DONE);
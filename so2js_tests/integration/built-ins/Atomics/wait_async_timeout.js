@@ -0,0 +1,24 @@
+/*---
+description: Atomics.waitAsync promises settle with "timed-out" once the timeout elapses.
+flags: [async]
+---*/
+
+var view = new Int32Array(new SharedArrayBuffer(8));
+
+var notEqual = Atomics.waitAsync(view, 0, 1);
+assert.sameValue(notEqual.async, false);
+assert.sameValue(notEqual.value, "not-equal");
+
+var immediate = Atomics.waitAsync(view, 0, 0, 0);
+assert.sameValue(immediate.async, false);
+assert.sameValue(immediate.value, "timed-out");
+
+var result = Atomics.waitAsync(view, 0, 0, 10);
+assert.sameValue(result.async, true);
+
+result.value
+  .then((value) => {
+    assert.sameValue(value, "timed-out");
+    assert.sameValue(Atomics.notify(view, 0), 0);
+  })
+  .then($DONE, $DONE);
@@ -0,0 +1,18 @@
+/*---
+description: Date local time follows daylight saving time that spans the start of the year
+timeZone: Australia/Sydney
+---*/
+
+assert.sameValue(new Date(2024, 0, 1).getTimezoneOffset(), -660);
+assert.sameValue(new Date(2024, 6, 1).getTimezoneOffset(), -600);
+assert.sameValue(new Date(2024, 11, 31).getTimezoneOffset(), -660);
+
+assert.sameValue(new Date(2100, 0, 1).getTimezoneOffset(), -660);
+assert.sameValue(new Date(2100, 6, 1).getTimezoneOffset(), -600);
+
+var date = new Date(Date.UTC(2024, 0, 1));
+assert.sameValue(date.getHours(), 11);
+assert.sameValue(date.toString(), 'Mon Jan 01 2024 11:00:00 GMT+1100 (AEDT)');
+
+date = new Date(Date.UTC(2024, 6, 1));
+assert.sameValue(date.toString(), 'Mon Jul 01 2024 10:00:00 GMT+1000 (AEST)');
@@ -0,0 +1,67 @@
+/*---
+description: Date local time uses the default time zone, including daylight saving time
+timeZone: America/New_York
+---*/
+
+// Standard time
+var date = new Date(2024, 0, 15, 12, 30);
+assert.sameValue(date.getTime(), Date.UTC(2024, 0, 15, 17, 30));
+assert.sameValue(date.getHours(), 12);
+assert.sameValue(date.getUTCHours(), 17);
+assert.sameValue(date.getTimezoneOffset(), 300);
+assert.sameValue(date.toString(), 'Mon Jan 15 2024 12:30:00 GMT-0500 (EST)');
+assert.sameValue(date.toTimeString(), '12:30:00 GMT-0500 (EST)');
+assert.sameValue(date.toDateString(), 'Mon Jan 15 2024');
+
+// Daylight saving time
+date = new Date(2024, 6, 4, 9);
+assert.sameValue(date.getTime(), Date.UTC(2024, 6, 4, 13));
+assert.sameValue(date.getTimezoneOffset(), 240);
+assert.sameValue(date.toString(), 'Thu Jul 04 2024 09:00:00 GMT-0400 (EDT)');
+
+// Historical rules before the current rules took effect
+assert.sameValue(new Date(0).getHours(), 19);
+assert.sameValue(new Date(0).getDate(), 31);
+assert.sameValue(new Date(0).getTimezoneOffset(), 300);
+assert.sameValue(new Date(1970, 6, 1).getTimezoneOffset(), 240);
+
+// Far future uses the current rules
+assert.sameValue(new Date(2100, 0, 1).getTimezoneOffset(), 300);
+assert.sameValue(new Date(2100, 6, 1).getTimezoneOffset(), 240);
+
+// Local times skipped when clocks are turned forward use the offset before the transition
+date = new Date(2024, 2, 10, 2, 30);
+assert.sameValue(date.getTime(), Date.UTC(2024, 2, 10, 7, 30));
+assert.sameValue(date.getHours(), 3);
+
+// Local times repeated when clocks are turned back are the earlier instant
+date = new Date(2024, 10, 3, 1, 30);
+assert.sameValue(date.getTime(), Date.UTC(2024, 10, 3, 5, 30));
+assert.sameValue(date.getTimezoneOffset(), 240);
+
+date = new Date(Date.UTC(2024, 10, 3, 6, 30));
+assert.sameValue(date.getHours(), 1);
+assert.sameValue(date.getTimezoneOffset(), 300);
+
+// Setters interpret their arguments in local time
+date = new Date(2024, 0, 15);
+date.setHours(23);
+assert.sameValue(date.getDate(), 15);
+assert.sameValue(date.getUTCDate(), 16);
+
+// Date-time strings without an offset are local time, date-only strings are UTC
+assert.sameValue(Date.parse('2024-01-15T12:30:00'), Date.UTC(2024, 0, 15, 17, 30));
+assert.sameValue(Date.parse('2024-01-15T12:30:00Z'), Date.UTC(2024, 0, 15, 12, 30));
+assert.sameValue(Date.parse('2024-01-15'), Date.UTC(2024, 0, 15));
+
+// Strings produced by toString can be parsed
+date = new Date(2024, 6, 4, 9, 15, 30);
+assert.sameValue(Date.parse(date.toString()), date.getTime());
+assert.sameValue(Date.parse('Thu Jul 04 2024 09:15:30 GMT-0400'), date.getTime());
+
+// Intl uses the default time zone
+assert.sameValue(new Intl.DateTimeFormat().resolvedOptions().timeZone, 'America/New_York');
+assert.sameValue(
+  new Date(Date.UTC(2024, 0, 15, 17, 30)).toLocaleTimeString('en', { hour: 'numeric' }),
+  '12 PM',
+);
@@ -0,0 +1,66 @@
+/*---
+description: Stack traces include the async functions awaiting a resumed async function.
+flags: [async]
+---*/
+
+async function inner() {
+  await null;
+  throw new Error('test');
+}
+
+async function middle() {
+  await inner();
+}
+
+async function outer() {
+  await middle();
+}
+
+async function* generator() {
+  await null;
+  yield new Error('generator');
+}
+
+async function consumer() {
+  for await (var error of generator()) {
+    return error;
+  }
+}
+
+(async function testAsyncStackTrace() {
+  try {
+    await outer();
+    throw new Test262Error('expected outer to reject');
+  } catch (error) {
+    var lines = error.stack.split('\n');
+    assert.sameValue(lines[0], 'Error: test');
+    assert.sameValue(lines[1].startsWith('  at inner ('), true);
+    assert.sameValue(lines[2].startsWith('  at async middle ('), true);
+    assert.sameValue(lines[3].startsWith('  at async outer ('), true);
+    assert.sameValue(lines[4].startsWith('  at async testAsyncStackTrace ('), true);
+  }
+})()
+  .then(async function testAsyncGeneratorStackTrace() {
+    var error = await consumer();
+    var lines = error.stack.split('\n');
+    assert.sameValue(lines[1].startsWith('  at generator ('), true);
+    assert.sameValue(lines[2].startsWith('  at async consumer ('), true);
+  })
+  .then(function testAsyncCallSites() {
+    var callSites;
+    Error.prepareStackTrace = (error, sites) => sites;
+
+    return outer().catch((error) => {
+      callSites = error.stack;
+      delete Error.prepareStackTrace;
+
+      assert.sameValue(callSites[0].getFunctionName(), 'inner');
+      assert.sameValue(callSites[0].isAsync(), false);
+      assert.sameValue(callSites[1].getFunctionName(), 'middle');
+      assert.sameValue(callSites[1].isAsync(), true);
+      assert.sameValue(callSites[1].toString().startsWith('async middle ('), true);
+      assert.sameValue(callSites[2].getFunctionName(), 'outer');
+      assert.sameValue(callSites[2].isAsync(), true);
+    });
+  })
+  .then($DONE, $DONE);
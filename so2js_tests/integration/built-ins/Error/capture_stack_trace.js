@@ -0,0 +1,62 @@
+/*---
+description: Error.captureStackTrace installs a stack property and respects Error.stackTraceLimit.
+---*/
+
+function functionNames(stack) {
+  return stack.split('\n').slice(1).map((line) => line.trim().split(' ')[1]);
+}
+
+(function testCaptureOnPlainObject() {
+  function inner() {
+    var object = { name: 'Custom', message: 'message' };
+    Error.captureStackTrace(object);
+    return object;
+  }
+
+  var object = inner();
+  var descriptor = Object.getOwnPropertyDescriptor(object, 'stack');
+
+  assert.sameValue(descriptor.enumerable, false);
+  assert.sameValue(descriptor.writable, true);
+  assert.sameValue(descriptor.configurable, true);
+  assert.sameValue(object.stack.split('\n')[0], 'Custom: message');
+  assert.sameValue(functionNames(object.stack)[0], 'inner');
+})();
+
+(function testOmitFramesAboveFunction() {
+  function MyError() {
+    Error.captureStackTrace(this, MyError);
+  }
+
+  function outer() {
+    return new MyError();
+  }
+
+  var error = outer();
+  assert.sameValue(functionNames(error.stack)[0], 'outer');
+})();
+
+(function testNonObjectTarget() {
+  assert.throws(TypeError, () => Error.captureStackTrace(1));
+})();
+
+(function testStackTraceLimit() {
+  assert.sameValue(Error.stackTraceLimit, 10);
+
+  function recurse(n) {
+    return n === 0 ? new Error('x') : recurse(n - 1);
+  }
+
+  assert.sameValue(functionNames(recurse(20).stack).length, 10);
+
+  Error.stackTraceLimit = 2;
+  assert.sameValue(functionNames(recurse(20).stack).length, 2);
+
+  Error.stackTraceLimit = 0;
+  assert.sameValue(recurse(20).stack, 'Error: x');
+
+  Error.stackTraceLimit = Infinity;
+  assert.sameValue(functionNames(recurse(20).stack).length > 20, true);
+
+  Error.stackTraceLimit = 10;
+})();
@@ -0,0 +1,65 @@
+/*---
+description: Error.prepareStackTrace formats stack traces from structured CallSite objects.
+---*/
+
+(function testPrepareStackTrace() {
+  var receivedError;
+  var receivedCallSites;
+
+  Error.prepareStackTrace = function (error, callSites) {
+    receivedError = error;
+    receivedCallSites = callSites;
+    return 'prepared';
+  };
+
+  function Thrower() {
+    this.error = new Error('test');
+  }
+
+  function outer() {
+    return new Thrower().error;
+  }
+
+  var error = outer();
+
+  // Called lazily on first access, and the result is cached
+  assert.sameValue(receivedError, undefined);
+  assert.sameValue(error.stack, 'prepared');
+  assert.sameValue(receivedError, error);
+
+  Error.prepareStackTrace = () => 'changed';
+  assert.sameValue(error.stack, 'prepared');
+
+  var thrower = receivedCallSites[0];
+  assert.sameValue(thrower.getFunctionName(), 'Thrower');
+  assert.sameValue(thrower.isConstructor(), true);
+  assert.sameValue(thrower.isNative(), false);
+  assert.sameValue(thrower.isAsync(), false);
+  assert.sameValue(thrower.getFileName().endsWith('prepare_stack_trace.js'), true);
+  assert.sameValue(thrower.getLineNumber(), 16);
+  assert.sameValue(typeof thrower.getColumnNumber(), 'number');
+
+  var outerSite = receivedCallSites[1];
+  assert.sameValue(outerSite.getFunctionName(), 'outer');
+  assert.sameValue(outerSite.isConstructor(), false);
+  assert.sameValue(outerSite.getLineNumber(), 20);
+
+  assert.throws(TypeError, () => thrower.getFunctionName.call({}));
+
+  delete Error.prepareStackTrace;
+})();
+
+(function testPrepareStackTraceForCapturedObject() {
+  Error.prepareStackTrace = (object, callSites) => callSites;
+
+  var object = {};
+  function capture() {
+    Error.captureStackTrace(object);
+  }
+  capture();
+
+  assert.sameValue(Array.isArray(object.stack), true);
+  assert.sameValue(object.stack[0].getFunctionName(), 'capture');
+
+  delete Error.prepareStackTrace;
+})();
@@ -0,0 +1,27 @@
+/*---
+description: Stack traces use an inline data URL source map for the original file, position, and name.
+---*/
+
+function a(b) {
+  throw new Error('boom ' + b);
+}
+
+function c() {
+  return a(1);
+}
+
+var error;
+try {
+  c();
+} catch (e) {
+  error = e;
+}
+
+var lines = error.stack.split('\n');
+assert.sameValue(lines[0], 'Error: boom 1');
+assert.sameValue(lines[1], '  at throwingFunction (/src/original.ts:21:5)');
+assert.sameValue(lines[2], '  at callingFunction (/src/original.ts:29:3)');
+assert.sameValue(lines[3].startsWith('  at '), true);
+assert.sameValue(lines[3].endsWith(' (/src/original.ts:41:3)'), true);
+
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIi9zcmMvb3JpZ2luYWwudHMiXSwibmFtZXMiOlsidGhyb3dpbmdGdW5jdGlvbiIsImNhbGxpbmdGdW5jdGlvbiJdLCJtYXBwaW5ncyI6Ijs7OztBQW1CQSxTQUFTQTtBQUNMOzs7QUFPSixTQUFTQztBQUNQOzs7OztBQVlBIn0=
@@ -0,0 +1,36 @@
+/*---
+description: Intl.Collator can be called as a function and has a bound compare getter
+---*/
+
+var collator = Intl.Collator('en');
+assert.sameValue(collator instanceof Intl.Collator, true);
+assert.sameValue(Object.prototype.toString.call(collator), '[object Intl.Collator]');
+assert.sameValue(Object.prototype.toString.call(Intl), '[object Intl]');
+
+// Compare function is bound to the collator and cached
+var compare = collator.compare;
+assert.sameValue(compare, collator.compare);
+assert.sameValue(compare.length, 2);
+assert.sameValue(compare.name, '');
+assert.sameValue(compare('a', 'b'), -1);
+assert.sameValue(compare('b', 'a'), 1);
+assert.sameValue(compare('a', 'a'), 0);
+
+assert.sameValue(['c', 'B', 'a'].sort(compare).join(), 'a,B,c');
+assert.sameValue(
+  ['item10', 'item2', 'item1'].sort(new Intl.Collator('en', { numeric: true }).compare).join(),
+  'item1,item2,item10'
+);
+
+// Compare getter and resolvedOptions require a collator
+assert.throws(TypeError, function () {
+  Object.getOwnPropertyDescriptor(Intl.Collator.prototype, 'compare').get.call({});
+});
+assert.throws(TypeError, function () { Intl.Collator.prototype.resolvedOptions.call({}); });
+
+// String.prototype.localeCompare uses the locales and options arguments
+assert.sameValue('a'.localeCompare('A', 'en', { sensitivity: 'base' }), 0);
+assert.sameValue('a'.localeCompare('A'), -1);
+assert.sameValue('2'.localeCompare('10', 'en', { numeric: true }), -1);
+assert.sameValue('2'.localeCompare('10'), 1);
+assert.throws(RangeError, function () { 'a'.localeCompare('b', 'not a tag'); });
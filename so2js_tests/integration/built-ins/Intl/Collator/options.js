@@ -0,0 +1,51 @@
+/*---
+description: Intl.Collator resolves locales and options and compares strings with them
+---*/
+
+// Sensitivity
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'base' }).compare('a', 'A'), 0);
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'base' }).compare('a', 'á'), 0);
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'accent' }).compare('a', 'A'), 0);
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'accent' }).compare('a', 'á'), -1);
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'case' }).compare('a', 'A'), -1);
+assert.sameValue(new Intl.Collator('en', { sensitivity: 'case' }).compare('a', 'á'), 0);
+assert.sameValue(new Intl.Collator('en').compare('a', 'á'), -1);
+
+// Numeric ordering, from option and from the locale extension
+assert.sameValue(new Intl.Collator('en').compare('2', '10'), 1);
+assert.sameValue(new Intl.Collator('en', { numeric: true }).compare('2', '10'), -1);
+assert.sameValue(new Intl.Collator('en-u-kn').compare('2', '10'), -1);
+
+// Case first
+assert.sameValue(new Intl.Collator('en', { caseFirst: 'upper' }).compare('a', 'A'), 1);
+assert.sameValue(new Intl.Collator('en', { caseFirst: 'lower' }).compare('a', 'A'), -1);
+
+// Ignore punctuation
+assert.sameValue(new Intl.Collator('en', { ignorePunctuation: true }).compare('a-b', 'ab'), 0);
+
+// Resolved options
+var resolved = new Intl.Collator('de-u-kn-kf-upper', { sensitivity: 'base' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'de-u-kf-upper-kn');
+assert.sameValue(resolved.usage, 'sort');
+assert.sameValue(resolved.sensitivity, 'base');
+assert.sameValue(resolved.ignorePunctuation, false);
+assert.sameValue(resolved.collation, 'default');
+assert.sameValue(resolved.numeric, true);
+assert.sameValue(resolved.caseFirst, 'upper');
+
+// Options override locale extension keywords, removing them from the resolved locale
+resolved = new Intl.Collator('en-u-kn', { numeric: false, usage: 'search' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.numeric, false);
+assert.sameValue(resolved.usage, 'search');
+
+// Locale negotiation falls back to a less specific locale
+assert.sameValue(new Intl.Collator('fr-CA').resolvedOptions().locale, 'fr');
+assert.sameValue(new Intl.Collator(['xx', 'es']).resolvedOptions().locale, 'es');
+assert.sameValue(Intl.Collator.supportedLocalesOf(['fr-CA', 'xx', 'en']).join(), 'fr-CA,en');
+
+// Invalid options and language tags
+assert.throws(RangeError, function () { new Intl.Collator('en', { sensitivity: 'none' }); });
+assert.throws(RangeError, function () { new Intl.Collator('en', { usage: 'other' }); });
+assert.throws(RangeError, function () { new Intl.Collator('not a tag'); });
+assert.throws(TypeError, function () { new Intl.Collator([5]); });
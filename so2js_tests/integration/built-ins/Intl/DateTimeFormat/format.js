@@ -0,0 +1,67 @@
+/*---
+description: Intl.DateTimeFormat and Date.prototype.toLocaleString format dates
+---*/
+
+var date = Date.UTC(2020, 0, 15, 13, 5, 9, 123);
+
+var dtf = new Intl.DateTimeFormat('en', { timeZone: 'UTC' });
+assert.sameValue(dtf.format(date), '1/15/2020');
+assert.sameValue(dtf.format(new Date(date)), '1/15/2020');
+assert.sameValue(new Intl.DateTimeFormat('de').format(date), '15.1.2020');
+
+// Format function is bound and cached
+var format = dtf.format;
+assert.sameValue(format, dtf.format);
+assert.sameValue(format(date), '1/15/2020');
+assert.sameValue(format.length, 1);
+assert.sameValue(format.name, '');
+
+// Date and time styles
+function formatStyle(options) {
+  return new Intl.DateTimeFormat('en', options).format(date);
+}
+
+assert.sameValue(formatStyle({ dateStyle: 'full' }), 'Wednesday, January 15, 2020');
+assert.sameValue(formatStyle({ dateStyle: 'long' }), 'January 15, 2020');
+assert.sameValue(formatStyle({ dateStyle: 'medium' }), 'Jan 15, 2020');
+assert.sameValue(formatStyle({ dateStyle: 'short' }), '1/15/20');
+assert.sameValue(formatStyle({ timeStyle: 'short' }), '1:05\u202fPM');
+assert.sameValue(formatStyle({ timeStyle: 'medium' }), '1:05:09\u202fPM');
+assert.sameValue(formatStyle({ timeStyle: 'long' }), '1:05:09\u202fPM UTC');
+
+// Hour cycles
+assert.sameValue(formatStyle({ timeStyle: 'short', hourCycle: 'h23' }), '13:05');
+assert.sameValue(formatStyle({ timeStyle: 'short', hour12: false }), '13:05');
+assert.sameValue(formatStyle({ hour: 'numeric', minute: 'numeric', hour12: false }), '13:05');
+
+// Offset time zones
+assert.sameValue(formatStyle({ timeStyle: 'short', timeZone: '+01:00' }), '2:05\u202fPM');
+assert.sameValue(formatStyle({ timeStyle: 'short', timeZone: '-0530' }), '7:35\u202fAM');
+assert.sameValue(
+  formatStyle({ hour: 'numeric', timeZone: '+01:00', timeZoneName: 'short' }),
+  '2\u202fPM GMT+1',
+);
+
+// Named time zones
+assert.sameValue(formatStyle({ timeStyle: 'short', timeZone: 'America/New_York' }), '8:05\u202fAM');
+assert.sameValue(formatStyle({ timeStyle: 'long', timeZone: 'America/New_York' }), '8:05:09\u202fAM EST');
+assert.sameValue(formatStyle({ timeStyle: 'short', timeZone: 'Asia/Kolkata' }), '6:35\u202fPM');
+assert.sameValue(
+  new Intl.DateTimeFormat('en', { timeStyle: 'long', timeZone: 'America/New_York' }).format(
+    Date.UTC(2020, 6, 15, 13, 5, 9),
+  ),
+  '9:05:09\u202fAM EDT',
+);
+
+// Ranges
+assert.sameValue(dtf.formatRange(date, date + 1000), '1/15/2020');
+assert.sameValue(dtf.formatRange(date, Date.UTC(2020, 1, 1)), '1/15/2020 – 2/1/2020');
+
+// Date locale methods delegate to Intl.DateTimeFormat
+var d = new Date(date);
+assert.sameValue(d.toLocaleString('en', { timeZone: 'UTC' }), '1/15/2020, 1:05:09\u202fPM');
+assert.sameValue(d.toLocaleDateString('en', { timeZone: 'UTC' }), '1/15/2020');
+assert.sameValue(d.toLocaleTimeString('en', { timeZone: 'UTC' }), '1:05:09\u202fPM');
+assert.sameValue(d.toLocaleDateString('en', { month: 'long' }), 'January');
+assert.sameValue(d.toLocaleTimeString('en', { hour: 'numeric' }), '1\u202fPM');
+assert.sameValue(new Date(NaN).toLocaleString('en'), 'Invalid Date');
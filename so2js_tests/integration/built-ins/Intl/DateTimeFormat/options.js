@@ -0,0 +1,87 @@
+/*---
+description: Intl.DateTimeFormat resolves and validates options
+---*/
+
+var options = new Intl.DateTimeFormat('en-US').resolvedOptions();
+assert.sameValue(options.locale, 'en');
+assert.sameValue(options.calendar, 'gregory');
+assert.sameValue(options.numberingSystem, 'latn');
+assert.sameValue(options.timeZone, 'UTC');
+assert.sameValue(options.year, 'numeric');
+assert.sameValue(options.month, 'numeric');
+assert.sameValue(options.day, 'numeric');
+assert.sameValue(options.hour, undefined);
+assert.sameValue(options.hourCycle, undefined);
+assert.sameValue(options.hour12, undefined);
+
+options = new Intl.DateTimeFormat('en', { hour: '2-digit', minute: '2-digit' }).resolvedOptions();
+assert.sameValue(options.year, undefined);
+assert.sameValue(options.hour, '2-digit');
+assert.sameValue(options.minute, '2-digit');
+assert.sameValue(options.hourCycle, 'h12');
+assert.sameValue(options.hour12, true);
+
+options = new Intl.DateTimeFormat('en-u-hc-h23', { timeStyle: 'short' }).resolvedOptions();
+assert.sameValue(options.locale, 'en-u-hc-h23');
+assert.sameValue(options.hourCycle, 'h23');
+assert.sameValue(options.hour12, false);
+assert.sameValue(options.timeStyle, 'short');
+assert.sameValue(options.dateStyle, undefined);
+assert.sameValue(options.hour, undefined);
+
+options = new Intl.DateTimeFormat('en', { timeZone: 'etc/gmt', fractionalSecondDigits: 2 })
+  .resolvedOptions();
+assert.sameValue(options.timeZone, 'UTC');
+assert.sameValue(options.fractionalSecondDigits, 2);
+assert.sameValue(options.year, undefined);
+
+assert.sameValue(new Intl.DateTimeFormat('en', { timeZone: '+0130' }).resolvedOptions().timeZone, '+01:30');
+
+// Named time zones are case normalized, and links are not replaced by the zone they refer to
+function resolvedTimeZone(timeZone) {
+  return new Intl.DateTimeFormat('en', { timeZone: timeZone }).resolvedOptions().timeZone;
+}
+
+assert.sameValue(resolvedTimeZone('america/new_york'), 'America/New_York');
+assert.sameValue(resolvedTimeZone('US/Eastern'), 'US/Eastern');
+assert.sameValue(resolvedTimeZone('Etc/UTC'), 'UTC');
+
+// Styles cannot be combined with components
+assert.throws(TypeError, function () {
+  new Intl.DateTimeFormat('en', { dateStyle: 'short', year: 'numeric' });
+});
+assert.throws(TypeError, function () {
+  new Date(0).toLocaleDateString('en', { timeStyle: 'short' });
+});
+assert.throws(TypeError, function () {
+  new Date(0).toLocaleTimeString('en', { dateStyle: 'short' });
+});
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.DateTimeFormat('en', { month: 'full' });
+});
+assert.throws(RangeError, function () {
+  new Intl.DateTimeFormat('en', { fractionalSecondDigits: 4 });
+});
+assert.throws(RangeError, function () {
+  new Intl.DateTimeFormat('en', { timeZone: 'Not/A_Zone' });
+});
+assert.throws(RangeError, function () {
+  new Intl.DateTimeFormat('en', { timeZone: '+24:00' });
+});
+
+// Invalid time values
+var dtf = new Intl.DateTimeFormat('en');
+assert.throws(RangeError, function () {
+  dtf.format(NaN);
+});
+assert.throws(RangeError, function () {
+  dtf.format(8.64e15 + 1);
+});
+assert.throws(TypeError, function () {
+  dtf.formatRange(0);
+});
+
+assert.sameValue(Object.prototype.toString.call(dtf), '[object Intl.DateTimeFormat]');
+assert.sameValue(Intl.DateTimeFormat.supportedLocalesOf(['en-US', 'tlh']).length, 1);
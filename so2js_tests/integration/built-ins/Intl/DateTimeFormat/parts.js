@@ -0,0 +1,50 @@
+/*---
+description: Intl.DateTimeFormat formats dates and ranges to parts
+---*/
+
+function partsToString(parts) {
+  return parts.map(function (part) { return part.type + ':' + part.value; }).join('|');
+}
+
+function rangePartsToString(parts) {
+  return parts
+    .map(function (part) { return part.type + ':' + part.value + ':' + part.source; })
+    .join('|');
+}
+
+var date = Date.UTC(2020, 0, 15, 13, 5, 9, 123);
+
+var dtf = new Intl.DateTimeFormat('en');
+assert.sameValue(
+  partsToString(dtf.formatToParts(date)),
+  'month:1|literal:/|day:15|literal:/|year:2020',
+);
+
+var time = new Intl.DateTimeFormat('en', {
+  hour: 'numeric',
+  minute: 'numeric',
+  second: 'numeric',
+  fractionalSecondDigits: 3,
+  hourCycle: 'h23',
+});
+assert.sameValue(
+  partsToString(time.formatToParts(date)),
+  'hour:13|literal::|minute:05|literal::|second:09|literal:.|fractionalSecond:123',
+);
+
+var twelveHour = new Intl.DateTimeFormat('en', { hour: 'numeric', timeZoneName: 'short' });
+assert.sameValue(
+  partsToString(twelveHour.formatToParts(date)),
+  'hour:1|literal:\u202f|dayPeriod:PM|literal: |timeZoneName:UTC',
+);
+
+assert.sameValue(
+  rangePartsToString(dtf.formatRangeToParts(date, date)),
+  'month:1:shared|literal:/:shared|day:15:shared|literal:/:shared|year:2020:shared',
+);
+assert.sameValue(
+  rangePartsToString(dtf.formatRangeToParts(date, Date.UTC(2021, 0, 15))),
+  'month:1:startRange|literal:/:startRange|day:15:startRange|literal:/:startRange|' +
+    'year:2020:startRange|literal: – :shared|month:1:endRange|literal:/:endRange|' +
+    'day:15:endRange|literal:/:endRange|year:2021:endRange',
+);
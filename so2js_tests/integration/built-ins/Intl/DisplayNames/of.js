@@ -0,0 +1,59 @@
+/*---
+description: Intl.DisplayNames.prototype.of returns display names of languages, regions, scripts, and currencies
+---*/
+
+function of(locale, options, code) {
+  return new Intl.DisplayNames(locale, options).of(code);
+}
+
+// Languages
+assert.sameValue(of('en', { type: 'language' }, 'fr'), 'French');
+assert.sameValue(of('en', { type: 'language' }, 'en-GB'), 'British English');
+assert.sameValue(
+  of('en', { type: 'language', languageDisplay: 'standard' }, 'en-GB'),
+  'English (United Kingdom)'
+);
+assert.sameValue(of('fr', { type: 'language' }, 'de'), 'allemand');
+
+// Regions
+assert.sameValue(of('en', { type: 'region' }, 'US'), 'United States');
+assert.sameValue(of('en', { type: 'region' }, 'de'), 'Germany');
+assert.sameValue(of('de', { type: 'region' }, 'FR'), 'Frankreich');
+
+// Scripts
+assert.sameValue(of('en', { type: 'script' }, 'Latn'), 'Latin');
+assert.sameValue(of('en', { type: 'script' }, 'cyrl'), 'Cyrillic');
+
+// Currencies
+assert.sameValue(of('en', { type: 'currency' }, 'USD'), 'US Dollar');
+assert.sameValue(of('en', { type: 'currency' }, 'eur'), 'Euro');
+
+// Unknown codes fall back to the canonicalized code unless fallback is none
+assert.sameValue(of('en', { type: 'region' }, 'aa'), 'AA');
+assert.sameValue(of('en', { type: 'region', fallback: 'none' }, 'AA'), undefined);
+assert.sameValue(of('en', { type: 'currency' }, 'xyz'), 'XYZ');
+assert.sameValue(of('en', { type: 'currency', fallback: 'none' }, 'XYZ'), undefined);
+
+// Codes that are not well-formed for the type are rejected
+assert.throws(RangeError, function () {
+  of('en', { type: 'region' }, 'USA');
+});
+assert.throws(RangeError, function () {
+  of('en', { type: 'script' }, 'Lat');
+});
+assert.throws(RangeError, function () {
+  of('en', { type: 'currency' }, 'US');
+});
+assert.throws(RangeError, function () {
+  of('en', { type: 'language' }, 'en_US');
+});
+assert.throws(RangeError, function () {
+  of('en', { type: 'language' }, 'root');
+});
+assert.throws(RangeError, function () {
+  of('en', { type: 'language' }, 'en-u-ca-gregory');
+});
+
+assert.throws(TypeError, function () {
+  Intl.DisplayNames.prototype.of.call({}, 'US');
+});
@@ -0,0 +1,55 @@
+/*---
+description: Intl.DisplayNames validates and resolves its options
+---*/
+
+var resolved = new Intl.DisplayNames('en', { type: 'region' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.style, 'long');
+assert.sameValue(resolved.type, 'region');
+assert.sameValue(resolved.fallback, 'code');
+assert.sameValue(Object.keys(resolved).join(), 'locale,style,type,fallback');
+
+// Only language display names have a languageDisplay option
+resolved = new Intl.DisplayNames('fr-CA', {
+  type: 'language',
+  style: 'short',
+  fallback: 'none',
+}).resolvedOptions();
+assert.sameValue(resolved.locale, 'fr');
+assert.sameValue(resolved.style, 'short');
+assert.sameValue(resolved.fallback, 'none');
+assert.sameValue(resolved.languageDisplay, 'dialect');
+assert.sameValue(Object.keys(resolved).join(), 'locale,style,type,fallback,languageDisplay');
+
+// The type option is required
+assert.throws(TypeError, function () {
+  new Intl.DisplayNames('en');
+});
+assert.throws(TypeError, function () {
+  new Intl.DisplayNames('en', {});
+});
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.DisplayNames('en', { type: 'calendar' });
+});
+assert.throws(RangeError, function () {
+  new Intl.DisplayNames('en', { type: 'region', style: 'full' });
+});
+assert.throws(RangeError, function () {
+  new Intl.DisplayNames('en', { type: 'region', fallback: 'empty' });
+});
+assert.throws(RangeError, function () {
+  new Intl.DisplayNames('en', { type: 'language', languageDisplay: 'long' });
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.DisplayNames('en', { type: 'region' });
+});
+
+assert.sameValue(Intl.DisplayNames.supportedLocalesOf(['fr', 'xx']).join(), 'fr');
+assert.sameValue(
+  Object.prototype.toString.call(new Intl.DisplayNames('en', { type: 'region' })),
+  '[object Intl.DisplayNames]'
+);
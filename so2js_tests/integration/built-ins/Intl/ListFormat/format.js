@@ -0,0 +1,67 @@
+/*---
+description: Intl.ListFormat formats lists of strings as conjunctions, disjunctions, and units
+---*/
+
+function format(locale, options, list) {
+  return new Intl.ListFormat(locale, options).format(list);
+}
+
+var list = ['a', 'b', 'c'];
+
+// English
+assert.sameValue(format('en', {}, list), 'a, b, and c');
+assert.sameValue(format('en', {}, ['a', 'b']), 'a and b');
+assert.sameValue(format('en', {}, ['a']), 'a');
+assert.sameValue(format('en', {}, []), '');
+assert.sameValue(format('en', {}, undefined), '');
+assert.sameValue(format('en', { style: 'short' }, list), 'a, b, & c');
+assert.sameValue(format('en', { type: 'disjunction' }, list), 'a, b, or c');
+assert.sameValue(format('en', { type: 'unit' }, list), 'a, b, c');
+assert.sameValue(format('en', { type: 'unit', style: 'narrow' }, list), 'a b c');
+
+// Other locales
+assert.sameValue(format('ru', {}, list), 'a, b и c');
+assert.sameValue(format('ru', { type: 'disjunction' }, list), 'a, b или c');
+assert.sameValue(format('ja', {}, list), 'a、b、c');
+assert.sameValue(format('ar', {}, ['a', 'b']), 'a وb');
+
+// Any iterable of strings is accepted
+assert.sameValue(format('en', {}, new Set(['x', 'y'])), 'x and y');
+assert.sameValue(format('en', {}, 'ab'), 'a and b');
+
+// Non-string elements are rejected and the iterator is closed
+var closed = false;
+var iterable = {};
+iterable[Symbol.iterator] = function () {
+  return {
+    next: function () {
+      return { value: 1, done: false };
+    },
+    return: function () {
+      closed = true;
+      return {};
+    },
+  };
+};
+
+assert.throws(TypeError, function () {
+  format('en', {}, iterable);
+});
+assert.sameValue(closed, true);
+
+assert.throws(TypeError, function () {
+  format('en', {}, ['a', 1]);
+});
+
+// formatToParts
+function partsToString(parts) {
+  return parts.map(function (part) { return part.type + ':' + part.value; }).join('|');
+}
+
+var lf = new Intl.ListFormat('en');
+assert.sameValue(
+  partsToString(lf.formatToParts(list)),
+  'element:a|literal:, |element:b|literal:, and |element:c',
+);
+assert.sameValue(partsToString(lf.formatToParts(['a'])), 'element:a');
+assert.sameValue(lf.formatToParts([]).length, 0);
@@ -0,0 +1,38 @@
+/*---
+description: Intl.ListFormat validates and resolves its options
+---*/
+
+var resolved = new Intl.ListFormat('en').resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.type, 'conjunction');
+assert.sameValue(resolved.style, 'long');
+assert.sameValue(Object.keys(resolved).join(), 'locale,type,style');
+
+resolved = new Intl.ListFormat('ja-JP', { type: 'unit', style: 'narrow' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'ja');
+assert.sameValue(resolved.type, 'unit');
+assert.sameValue(resolved.style, 'narrow');
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.ListFormat('en', { type: 'and' });
+});
+assert.throws(RangeError, function () {
+  new Intl.ListFormat('en', { style: 'wide' });
+});
+
+// Options must be an object
+assert.throws(TypeError, function () {
+  new Intl.ListFormat('en', 'short');
+});
+assert.throws(TypeError, function () {
+  new Intl.ListFormat('en', null);
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.ListFormat();
+});
+
+assert.sameValue(Intl.ListFormat.supportedLocalesOf(['ru', 'xx']).join(), 'ru');
+assert.sameValue(Object.prototype.toString.call(new Intl.ListFormat()), '[object Intl.ListFormat]');
@@ -0,0 +1,68 @@
+/*---
+description: Intl.Locale parses, canonicalizes, and applies options to language tags
+---*/
+
+assert.sameValue(new Intl.Locale('en').toString(), 'en');
+assert.sameValue(new Intl.Locale('EN-us').toString(), 'en-US');
+assert.sameValue(new Intl.Locale('zh-hant-tw').toString(), 'zh-Hant-TW');
+
+// Deprecated and aliased subtags are canonicalized
+assert.sameValue(new Intl.Locale('iw').toString(), 'he');
+assert.sameValue(new Intl.Locale('en-u-ca-gregory-kn').toString(), 'en-u-ca-gregory-kn');
+
+// Options replace subtags and extension keywords from the tag
+var locale = new Intl.Locale('en-US', { region: 'GB', script: 'Latn' });
+assert.sameValue(locale.toString(), 'en-Latn-GB');
+
+locale = new Intl.Locale('en-u-ca-buddhist', {
+  calendar: 'gregory',
+  collation: 'emoji',
+  hourCycle: 'h23',
+  caseFirst: 'upper',
+  numeric: true,
+  numberingSystem: 'latn',
+});
+assert.sameValue(locale.toString(), 'en-u-ca-gregory-co-emoji-hc-h23-kf-upper-kn-nu-latn');
+
+// An Intl.Locale object may be used as the tag
+assert.sameValue(new Intl.Locale(new Intl.Locale('fr-CA')).toString(), 'fr-CA');
+assert.sameValue(new Intl.Locale(new Intl.Locale('fr-CA'), { region: 'FR' }).toString(), 'fr-FR');
+
+// Intl.Locale objects are accepted in locale lists
+assert.sameValue(new Intl.ListFormat(new Intl.Locale('ja')).resolvedOptions().locale, 'ja');
+assert.sameValue(new Intl.Collator([new Intl.Locale('de')]).resolvedOptions().locale, 'de');
+
+// Invalid tags and options
+assert.throws(RangeError, function () {
+  new Intl.Locale('');
+});
+assert.throws(RangeError, function () {
+  new Intl.Locale('en-');
+});
+assert.throws(RangeError, function () {
+  new Intl.Locale('en', { region: 'GBR' });
+});
+assert.throws(RangeError, function () {
+  new Intl.Locale('en', { language: 'e' });
+});
+assert.throws(RangeError, function () {
+  new Intl.Locale('en', { calendar: 'a' });
+});
+assert.throws(RangeError, function () {
+  new Intl.Locale('en', { hourCycle: 'h25' });
+});
+
+// The tag must be a string or object
+assert.throws(TypeError, function () {
+  new Intl.Locale();
+});
+assert.throws(TypeError, function () {
+  new Intl.Locale(5);
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.Locale('en');
+});
+
+assert.sameValue(Object.prototype.toString.call(new Intl.Locale('en')), '[object Intl.Locale]');
@@ -0,0 +1,37 @@
+/*---
+description: Intl.Locale.prototype getters return the subtags and extension keywords of a locale
+---*/
+
+var locale = new Intl.Locale('sr-Cyrl-RS-u-ca-gregory-co-emoji-hc-h12-kf-lower-kn-nu-latn');
+
+assert.sameValue(locale.baseName, 'sr-Cyrl-RS');
+assert.sameValue(locale.language, 'sr');
+assert.sameValue(locale.script, 'Cyrl');
+assert.sameValue(locale.region, 'RS');
+assert.sameValue(locale.calendar, 'gregory');
+assert.sameValue(locale.collation, 'emoji');
+assert.sameValue(locale.hourCycle, 'h12');
+assert.sameValue(locale.caseFirst, 'lower');
+assert.sameValue(locale.numeric, true);
+assert.sameValue(locale.numberingSystem, 'latn');
+
+// Missing subtags and keywords are undefined
+locale = new Intl.Locale('de');
+assert.sameValue(locale.baseName, 'de');
+assert.sameValue(locale.language, 'de');
+assert.sameValue(locale.script, undefined);
+assert.sameValue(locale.region, undefined);
+assert.sameValue(locale.calendar, undefined);
+assert.sameValue(locale.collation, undefined);
+assert.sameValue(locale.hourCycle, undefined);
+assert.sameValue(locale.caseFirst, undefined);
+assert.sameValue(locale.numeric, false);
+assert.sameValue(locale.numberingSystem, undefined);
+
+assert.sameValue(new Intl.Locale('en-u-kn-false').numeric, false);
+
+// Getters require an Intl.Locale receiver
+var getter = Object.getOwnPropertyDescriptor(Intl.Locale.prototype, 'language').get;
+assert.throws(TypeError, function () {
+  getter.call({});
+});
@@ -0,0 +1,33 @@
+/*---
+description: Intl.Locale.prototype.maximize and minimize add and remove likely subtags
+---*/
+
+function maximize(tag) {
+  return new Intl.Locale(tag).maximize().toString();
+}
+
+function minimize(tag) {
+  return new Intl.Locale(tag).minimize().toString();
+}
+
+assert.sameValue(maximize('en'), 'en-Latn-US');
+assert.sameValue(maximize('zh'), 'zh-Hans-CN');
+assert.sameValue(maximize('zh-TW'), 'zh-Hant-TW');
+assert.sameValue(maximize('und'), 'en-Latn-US');
+assert.sameValue(maximize('ja-u-ca-gregory'), 'ja-Jpan-JP-u-ca-gregory');
+
+assert.sameValue(minimize('en-Latn-US'), 'en');
+assert.sameValue(minimize('zh-Hant-TW'), 'zh-TW');
+assert.sameValue(minimize('en-GB'), 'en-GB');
+assert.sameValue(minimize('ja-Jpan-JP-u-ca-gregory'), 'ja-u-ca-gregory');
+
+// Results are new Intl.Locale objects
+var locale = new Intl.Locale('en');
+var maximized = locale.maximize();
+assert.sameValue(maximized instanceof Intl.Locale, true);
+assert.sameValue(maximized !== locale, true);
+assert.sameValue(locale.toString(), 'en');
+
+assert.throws(TypeError, function () {
+  Intl.Locale.prototype.maximize.call('en');
+});
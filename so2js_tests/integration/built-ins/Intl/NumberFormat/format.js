@@ -0,0 +1,103 @@
+/*---
+description: Intl.NumberFormat formats numbers with the resolved style, notation, and digit options
+---*/
+
+function format(locale, options, value) {
+  return new Intl.NumberFormat(locale, options).format(value);
+}
+
+// Decimal style in different locales
+assert.sameValue(format('en', {}, 1234567.891), '1,234,567.891');
+assert.sameValue(format('de', {}, 1234567.891), '1.234.567,891');
+assert.sameValue(format('en', {}, -0.5), '-0.5');
+assert.sameValue(format('en', {}, NaN), 'NaN');
+assert.sameValue(format('en', {}, Infinity), '∞');
+assert.sameValue(format('en', {}, -Infinity), '-∞');
+
+// Numbering systems
+assert.sameValue(format('ar', {}, 123), '١٢٣');
+assert.sameValue(format('ar-u-nu-latn', {}, 123), '123');
+assert.sameValue(format('ar', { numberingSystem: 'latn' }, 123), '123');
+
+// Percent, currency, and unit styles
+assert.sameValue(format('en', { style: 'percent' }, 0.256), '26%');
+assert.sameValue(format('en', { style: 'currency', currency: 'USD' }, 1234.5), '$1,234.50');
+assert.sameValue(format('en', { style: 'currency', currency: 'jpy' }, 1234.5), '¥1,235');
+assert.sameValue(format('de', { style: 'currency', currency: 'EUR' }, 1234.5), '1.234,50\u00a0€');
+assert.sameValue(
+  format('en', { style: 'currency', currency: 'USD', currencySign: 'accounting' }, -5),
+  '($5.00)',
+);
+assert.sameValue(format('en', { style: 'unit', unit: 'kilometer' }, 5), '5 km');
+
+// Notations
+assert.sameValue(format('en', { notation: 'compact' }, 1234), '1.2K');
+assert.sameValue(format('en', { notation: 'compact' }, 1500000), '1.5M');
+assert.sameValue(format('en', { notation: 'compact' }, 999), '999');
+assert.sameValue(format('en', { notation: 'scientific' }, 123456), '1.235E5');
+assert.sameValue(format('en', { notation: 'scientific' }, 0.00123), '1.23E-3');
+assert.sameValue(format('en', { notation: 'engineering' }, 123456), '123.456E3');
+
+// Fraction and significant digits
+assert.sameValue(format('en', { minimumFractionDigits: 2 }, 5), '5.00');
+assert.sameValue(format('en', { maximumFractionDigits: 1 }, 1.25), '1.3');
+assert.sameValue(format('en', { minimumIntegerDigits: 3 }, 5), '005');
+assert.sameValue(format('en', { maximumSignificantDigits: 3 }, 123456), '123,000');
+assert.sameValue(format('en', { minimumSignificantDigits: 4 }, 1.5), '1.500');
+assert.sameValue(
+  format('en', { maximumFractionDigits: 1, maximumSignificantDigits: 2, roundingPriority: 'morePrecision' }, 1.234),
+  '1.2',
+);
+assert.sameValue(
+  format('en', { maximumFractionDigits: 0, maximumSignificantDigits: 2, roundingPriority: 'lessPrecision' }, 1.5),
+  '2',
+);
+
+// Rounding modes and increments
+assert.sameValue(format('en', { maximumFractionDigits: 0, roundingMode: 'floor' }, 1.9), '1');
+assert.sameValue(format('en', { maximumFractionDigits: 0, roundingMode: 'floor' }, -1.1), '-2');
+assert.sameValue(format('en', { maximumFractionDigits: 0, roundingMode: 'halfEven' }, 2.5), '2');
+assert.sameValue(format('en', { maximumFractionDigits: 0, roundingMode: 'trunc' }, -2.7), '-2');
+assert.sameValue(
+  format('en', { minimumFractionDigits: 2, maximumFractionDigits: 2, roundingIncrement: 5 }, 1.234),
+  '1.25',
+);
+
+// Trailing zeros
+assert.sameValue(format('en', { minimumFractionDigits: 2, trailingZeroDisplay: 'stripIfInteger' }, 5), '5');
+assert.sameValue(format('en', { minimumFractionDigits: 2, trailingZeroDisplay: 'stripIfInteger' }, 5.1), '5.10');
+
+// Grouping
+assert.sameValue(format('en', { useGrouping: false }, 1234), '1234');
+assert.sameValue(format('en', { useGrouping: 'min2' }, 1234), '1234');
+assert.sameValue(format('en', { useGrouping: 'min2' }, 12345), '12,345');
+
+// Sign display
+assert.sameValue(format('en', { signDisplay: 'always' }, 5), '+5');
+assert.sameValue(format('en', { signDisplay: 'never' }, -5), '5');
+assert.sameValue(format('en', { signDisplay: 'exceptZero' }, 0), '0');
+assert.sameValue(format('en', { signDisplay: 'exceptZero' }, -5), '-5');
+assert.sameValue(format('en', {}, -0), '-0');
+assert.sameValue(format('en', { signDisplay: 'negative' }, -0), '0');
+
+// BigInts and decimal strings are formatted exactly
+assert.sameValue(format('en', {}, 12345678901234567890n), '12,345,678,901,234,567,890');
+assert.sameValue(
+  format('en', { maximumFractionDigits: 1 }, '12345678901234567890.5'),
+  '12,345,678,901,234,567,890.5',
+);
+assert.sameValue(format('en', {}, '1e3'), '1,000');
+
+// The format getter returns the same bound function
+var nf = new Intl.NumberFormat('en');
+assert.sameValue(nf.format, nf.format);
+assert.sameValue(nf.format.length, 1);
+assert.sameValue([1000, 2000].map(nf.format).join(' '), '1,000 2,000');
+
+// Number and BigInt toLocaleString delegate to Intl.NumberFormat
+assert.sameValue((1234.5).toLocaleString('en'), '1,234.5');
+assert.sameValue((1234.5).toLocaleString('de'), '1.234,5');
+assert.sameValue((0.5).toLocaleString('en', { style: 'percent' }), '50%');
+assert.sameValue((1234n).toLocaleString('de'), '1.234');
+assert.throws(TypeError, function () { Number.prototype.toLocaleString.call('1'); });
+assert.throws(TypeError, function () { BigInt.prototype.toLocaleString.call(1); });
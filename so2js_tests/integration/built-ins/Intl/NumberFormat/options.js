@@ -0,0 +1,83 @@
+/*---
+description: Intl.NumberFormat validates and resolves its options
+---*/
+
+// Default resolved options
+var resolved = new Intl.NumberFormat('en').resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.numberingSystem, 'latn');
+assert.sameValue(resolved.style, 'decimal');
+assert.sameValue(resolved.minimumIntegerDigits, 1);
+assert.sameValue(resolved.minimumFractionDigits, 0);
+assert.sameValue(resolved.maximumFractionDigits, 3);
+assert.sameValue('minimumSignificantDigits' in resolved, false);
+assert.sameValue(resolved.useGrouping, 'auto');
+assert.sameValue(resolved.notation, 'standard');
+assert.sameValue('compactDisplay' in resolved, false);
+assert.sameValue(resolved.signDisplay, 'auto');
+assert.sameValue(resolved.roundingIncrement, 1);
+assert.sameValue(resolved.roundingMode, 'halfExpand');
+assert.sameValue(resolved.roundingPriority, 'auto');
+assert.sameValue(resolved.trailingZeroDisplay, 'auto');
+
+// Currency options
+resolved = new Intl.NumberFormat('en', { style: 'currency', currency: 'eur' }).resolvedOptions();
+assert.sameValue(resolved.currency, 'EUR');
+assert.sameValue(resolved.currencyDisplay, 'symbol');
+assert.sameValue(resolved.currencySign, 'standard');
+assert.sameValue(resolved.minimumFractionDigits, 2);
+assert.sameValue(resolved.maximumFractionDigits, 2);
+assert.sameValue('unit' in resolved, false);
+
+// Currency is ignored for other styles
+resolved = new Intl.NumberFormat('en', { currency: 'EUR' }).resolvedOptions();
+assert.sameValue('currency' in resolved, false);
+
+// Unit options
+resolved = new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer-per-hour' }).resolvedOptions();
+assert.sameValue(resolved.unit, 'kilometer-per-hour');
+assert.sameValue(resolved.unitDisplay, 'short');
+
+// Compact notation defaults
+resolved = new Intl.NumberFormat('en', { notation: 'compact' }).resolvedOptions();
+assert.sameValue(resolved.compactDisplay, 'short');
+assert.sameValue(resolved.useGrouping, 'min2');
+assert.sameValue(resolved.roundingPriority, 'morePrecision');
+assert.sameValue(resolved.maximumSignificantDigits, 2);
+
+// Significant digits
+resolved = new Intl.NumberFormat('en', { maximumSignificantDigits: 3 }).resolvedOptions();
+assert.sameValue(resolved.minimumSignificantDigits, 1);
+assert.sameValue(resolved.maximumSignificantDigits, 3);
+assert.sameValue('minimumFractionDigits' in resolved, false);
+
+// Boolean and string useGrouping values
+assert.sameValue(new Intl.NumberFormat('en', { useGrouping: false }).resolvedOptions().useGrouping, false);
+assert.sameValue(new Intl.NumberFormat('en', { useGrouping: true }).resolvedOptions().useGrouping, 'always');
+assert.sameValue(new Intl.NumberFormat('en', { useGrouping: 'false' }).resolvedOptions().useGrouping, 'auto');
+
+// Numbering system extension is reported in the locale
+resolved = new Intl.NumberFormat('ar-u-nu-latn').resolvedOptions();
+assert.sameValue(resolved.locale, 'ar-u-nu-latn');
+assert.sameValue(resolved.numberingSystem, 'latn');
+assert.sameValue(new Intl.NumberFormat('ar').resolvedOptions().numberingSystem, 'arab');
+
+// Invalid options
+assert.throws(TypeError, function () { new Intl.NumberFormat('en', { style: 'currency' }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { style: 'currency', currency: 'US' }); });
+assert.throws(TypeError, function () { new Intl.NumberFormat('en', { style: 'unit' }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { style: 'unit', unit: 'furlong' }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { style: 'bogus' }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { minimumFractionDigits: 3, maximumFractionDigits: 1 }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { maximumSignificantDigits: 22 }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { roundingIncrement: 3 }); });
+assert.throws(TypeError, function () { new Intl.NumberFormat('en', { roundingIncrement: 5, maximumSignificantDigits: 2 }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { useGrouping: 'sometimes' }); });
+assert.throws(RangeError, function () { new Intl.NumberFormat('en', { numberingSystem: 'a' }); });
+
+// Constructor may be called as a function
+assert.sameValue(Object.getPrototypeOf(Intl.NumberFormat()), Intl.NumberFormat.prototype);
+assert.sameValue(Object.prototype.toString.call(new Intl.NumberFormat()), '[object Intl.NumberFormat]');
+
+// Supported locales
+assert.sameValue(Intl.NumberFormat.supportedLocalesOf(['de-CH', 'tlh']).join(), 'de-CH');
@@ -0,0 +1,59 @@
+/*---
+description: Intl.NumberFormat formats numbers and ranges to parts
+---*/
+
+function partsToString(parts) {
+  return parts.map(function (part) { return part.type + ':' + part.value; }).join('|');
+}
+
+function rangePartsToString(parts) {
+  return parts
+    .map(function (part) { return part.type + ':' + part.value + ':' + part.source; })
+    .join('|');
+}
+
+var nf = new Intl.NumberFormat('en');
+assert.sameValue(
+  partsToString(nf.formatToParts(-1234.5)),
+  'minusSign:-|integer:1|group:,|integer:234|decimal:.|fraction:5',
+);
+assert.sameValue(partsToString(nf.formatToParts(NaN)), 'nan:NaN');
+assert.sameValue(partsToString(nf.formatToParts(Infinity)), 'infinity:∞');
+
+var currency = new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' });
+assert.sameValue(
+  partsToString(currency.formatToParts(-1)),
+  'minusSign:-|currency:$|integer:1|decimal:.|fraction:00',
+);
+
+var percent = new Intl.NumberFormat('en', { style: 'percent', signDisplay: 'always' });
+assert.sameValue(partsToString(percent.formatToParts(0.5)), 'plusSign:+|integer:50|percentSign:%');
+
+var compact = new Intl.NumberFormat('en', { notation: 'compact' });
+assert.sameValue(partsToString(compact.formatToParts(1200)), 'integer:1|decimal:.|fraction:2|compact:K');
+
+var scientific = new Intl.NumberFormat('en', { notation: 'scientific' });
+assert.sameValue(
+  partsToString(scientific.formatToParts(0.0012)),
+  'integer:1|decimal:.|fraction:2|exponentSeparator:E|exponentMinusSign:-|exponentInteger:3',
+);
+
+// Ranges
+assert.sameValue(nf.formatRange(3, 5), '3–5');
+assert.sameValue(nf.formatRange(5, 5), '~5');
+assert.sameValue(nf.formatRange(-5, 1e3), '-5–1,000');
+assert.sameValue(currency.formatRange(3, 5), '$3.00 – $5.00');
+assert.sameValue(currency.formatRange(2.999, 3.001), '~$3.00');
+assert.sameValue(
+  rangePartsToString(nf.formatRangeToParts(3, 5)),
+  'integer:3:startRange|literal:–:shared|integer:5:endRange',
+);
+assert.sameValue(
+  rangePartsToString(nf.formatRangeToParts(5, 5)),
+  'approximatelySign:~:shared|integer:5:shared',
+);
+
+assert.throws(RangeError, function () { nf.formatRange(NaN, 5); });
+assert.throws(RangeError, function () { nf.formatRangeToParts(5, NaN); });
+assert.throws(TypeError, function () { nf.formatRange(undefined, 5); });
+assert.throws(TypeError, function () { nf.formatRange.call({}, 3, 5); });
@@ -0,0 +1,59 @@
+/*---
+description: Intl.PluralRules validates and resolves its options
+---*/
+
+// Default resolved options
+var resolved = new Intl.PluralRules('en').resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.type, 'cardinal');
+assert.sameValue(resolved.minimumIntegerDigits, 1);
+assert.sameValue(resolved.minimumFractionDigits, 0);
+assert.sameValue(resolved.maximumFractionDigits, 3);
+assert.sameValue('minimumSignificantDigits' in resolved, false);
+assert.sameValue(resolved.roundingIncrement, 1);
+assert.sameValue(resolved.roundingMode, 'halfExpand');
+assert.sameValue(resolved.roundingPriority, 'auto');
+assert.sameValue(resolved.trailingZeroDisplay, 'auto');
+
+// Order of resolved options
+assert.sameValue(
+  Object.keys(resolved).join(),
+  'locale,type,minimumIntegerDigits,minimumFractionDigits,maximumFractionDigits,' +
+    'pluralCategories,roundingIncrement,roundingMode,roundingPriority,trailingZeroDisplay',
+);
+
+// Plural categories of each locale
+function categories(locale, options) {
+  return new Intl.PluralRules(locale, options).resolvedOptions().pluralCategories.join();
+}
+
+assert.sameValue(categories('en'), 'one,other');
+assert.sameValue(categories('en', { type: 'ordinal' }), 'one,two,few,other');
+assert.sameValue(categories('ar'), 'zero,one,two,few,many,other');
+assert.sameValue(categories('ru'), 'one,few,many,other');
+assert.sameValue(categories('ja'), 'other');
+
+// Significant digits
+resolved = new Intl.PluralRules('en', { maximumSignificantDigits: 3 }).resolvedOptions();
+assert.sameValue(resolved.minimumSignificantDigits, 1);
+assert.sameValue(resolved.maximumSignificantDigits, 3);
+assert.sameValue('minimumFractionDigits' in resolved, false);
+
+// Locale fallback
+assert.sameValue(new Intl.PluralRules('ru-RU').resolvedOptions().locale, 'ru');
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.PluralRules('en', { type: 'plural' });
+});
+assert.throws(RangeError, function () {
+  new Intl.PluralRules('en', { minimumFractionDigits: 4, maximumFractionDigits: 2 });
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.PluralRules();
+});
+
+assert.sameValue(Intl.PluralRules.supportedLocalesOf(['ar', 'xx']).join(), 'ar');
+assert.sameValue(Object.prototype.toString.call(new Intl.PluralRules()), '[object Intl.PluralRules]');
@@ -0,0 +1,80 @@
+/*---
+description: Intl.PluralRules selects cardinal and ordinal plural categories
+---*/
+
+function select(locale, options, value) {
+  return new Intl.PluralRules(locale, options).select(value);
+}
+
+// English cardinal
+assert.sameValue(select('en', {}, 0), 'other');
+assert.sameValue(select('en', {}, 1), 'one');
+assert.sameValue(select('en', {}, -1), 'one');
+assert.sameValue(select('en', {}, 2), 'other');
+assert.sameValue(select('en', {}, 1.5), 'other');
+assert.sameValue(select('en', {}, '1'), 'one');
+assert.sameValue(select('en', {}, NaN), 'other');
+assert.sameValue(select('en', {}, Infinity), 'other');
+
+// Visible fraction digits and rounding affect the category
+assert.sameValue(select('en', { minimumFractionDigits: 1 }, 1), 'other');
+assert.sameValue(select('en', { maximumFractionDigits: 0 }, 1.4), 'one');
+assert.sameValue(select('en', { maximumSignificantDigits: 1 }, 1.2), 'one');
+
+// English ordinal
+var ordinal = { type: 'ordinal' };
+assert.sameValue(select('en', ordinal, 1), 'one');
+assert.sameValue(select('en', ordinal, 2), 'two');
+assert.sameValue(select('en', ordinal, 3), 'few');
+assert.sameValue(select('en', ordinal, 4), 'other');
+assert.sameValue(select('en', ordinal, 11), 'other');
+assert.sameValue(select('en', ordinal, 12), 'other');
+assert.sameValue(select('en', ordinal, 21), 'one');
+assert.sameValue(select('en', ordinal, 22), 'two');
+assert.sameValue(select('en', ordinal, 23), 'few');
+
+// Arabic cardinal
+assert.sameValue(select('ar', {}, 0), 'zero');
+assert.sameValue(select('ar', {}, 1), 'one');
+assert.sameValue(select('ar', {}, 2), 'two');
+assert.sameValue(select('ar', {}, 3), 'few');
+assert.sameValue(select('ar', {}, 10), 'few');
+assert.sameValue(select('ar', {}, 11), 'many');
+assert.sameValue(select('ar', {}, 99), 'many');
+assert.sameValue(select('ar', {}, 100), 'other');
+assert.sameValue(select('ar', {}, 103), 'few');
+
+// Russian cardinal
+assert.sameValue(select('ru', {}, 1), 'one');
+assert.sameValue(select('ru', {}, 2), 'few');
+assert.sameValue(select('ru', {}, 5), 'many');
+assert.sameValue(select('ru', {}, 11), 'many');
+assert.sameValue(select('ru', {}, 21), 'one');
+assert.sameValue(select('ru', {}, 22), 'few');
+assert.sameValue(select('ru', {}, 1.5), 'other');
+
+// Japanese has a single category
+assert.sameValue(select('ja', {}, 1), 'other');
+assert.sameValue(select('ja', {}, 2), 'other');
+assert.sameValue(select('ja', ordinal, 1), 'other');
+
+// selectRange
+var en = new Intl.PluralRules('en');
+assert.sameValue(en.selectRange(1, 5), 'other');
+assert.sameValue(en.selectRange(1, 1), 'one');
+assert.sameValue(new Intl.PluralRules('ru').selectRange(1, 2), 'few');
+assert.sameValue(new Intl.PluralRules('ru').selectRange(1, 5), 'many');
+assert.sameValue(new Intl.PluralRules('ja').selectRange(1, 2), 'other');
+
+assert.throws(TypeError, function () {
+  en.selectRange(1);
+});
+assert.throws(TypeError, function () {
+  en.selectRange(undefined, 1);
+});
+assert.throws(RangeError, function () {
+  en.selectRange(NaN, 1);
+});
+assert.throws(RangeError, function () {
+  en.selectRange(1, NaN);
+});
@@ -0,0 +1,78 @@
+/*---
+description: Intl.RelativeTimeFormat formats relative times in each unit
+---*/
+
+function format(locale, options, value, unit) {
+  return new Intl.RelativeTimeFormat(locale, options).format(value, unit);
+}
+
+// English
+assert.sameValue(format('en', {}, 1, 'day'), 'in 1 day');
+assert.sameValue(format('en', {}, 2, 'days'), 'in 2 days');
+assert.sameValue(format('en', {}, -1, 'day'), '1 day ago');
+assert.sameValue(format('en', {}, -0, 'second'), '0 seconds ago');
+assert.sameValue(format('en', {}, 0, 'second'), 'in 0 seconds');
+assert.sameValue(format('en', {}, 1.5, 'hour'), 'in 1.5 hours');
+assert.sameValue(format('en', {}, 1000, 'year'), 'in 1,000 years');
+assert.sameValue(format('en', {}, '3', 'weeks'), 'in 3 weeks');
+assert.sameValue(format('en', { style: 'short' }, 3, 'month'), 'in 3 mo.');
+
+// Numeric auto uses phrases where available
+var auto = { numeric: 'auto' };
+assert.sameValue(format('en', auto, -1, 'day'), 'yesterday');
+assert.sameValue(format('en', auto, 0, 'day'), 'today');
+assert.sameValue(format('en', auto, 1, 'day'), 'tomorrow');
+assert.sameValue(format('en', auto, 2, 'day'), 'in 2 days');
+
+// Russian
+assert.sameValue(format('ru', {}, 1, 'day'), 'через 1 день');
+assert.sameValue(format('ru', {}, 2, 'day'), 'через 2 дня');
+assert.sameValue(format('ru', {}, 5, 'day'), 'через 5 дней');
+assert.sameValue(format('ru', {}, -1, 'day'), '1 день назад');
+assert.sameValue(format('ru', auto, -1, 'day'), 'вчера');
+
+// Japanese
+assert.sameValue(format('ja', {}, 3, 'day'), '3 日後');
+assert.sameValue(format('ja', {}, -3, 'day'), '3 日前');
+assert.sameValue(format('ja', auto, 1, 'day'), '明日');
+
+// Arabic uses Arabic-Indic digits by default
+assert.notSameValue(format('ar', {}, 3, 'day').indexOf('٣'), -1);
+assert.notSameValue(format('ar', { numberingSystem: 'latn' }, 3, 'day').indexOf('3'), -1);
+
+// Invalid values and units
+assert.throws(RangeError, function () {
+  format('en', {}, NaN, 'day');
+});
+assert.throws(RangeError, function () {
+  format('en', {}, Infinity, 'day');
+});
+assert.throws(RangeError, function () {
+  format('en', {}, 1, 'decade');
+});
+assert.throws(RangeError, function () {
+  format('en', {}, 1, 'Day');
+});
+
+// formatToParts
+function partsToString(parts) {
+  return parts
+    .map(function (part) {
+      return part.type + ':' + part.value + ('unit' in part ? ':' + part.unit : '');
+    })
+    .join('|');
+}
+
+var rtf = new Intl.RelativeTimeFormat('en');
+assert.sameValue(
+  partsToString(rtf.formatToParts(100, 'day')),
+  'literal:in |integer:100:day|literal: days',
+);
+assert.sameValue(
+  partsToString(rtf.formatToParts(-1234.5, 'seconds')),
+  'integer:1:second|group:,:second|integer:234:second|decimal:.:second|fraction:5:second|literal: seconds ago',
+);
+assert.sameValue(
+  partsToString(new Intl.RelativeTimeFormat('en', auto).formatToParts(-1, 'day')),
+  'literal:yesterday',
+);
@@ -0,0 +1,47 @@
+/*---
+description: Intl.RelativeTimeFormat validates and resolves its options
+---*/
+
+var resolved = new Intl.RelativeTimeFormat('en').resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.style, 'long');
+assert.sameValue(resolved.numeric, 'always');
+assert.sameValue(resolved.numberingSystem, 'latn');
+assert.sameValue(Object.keys(resolved).join(), 'locale,style,numeric,numberingSystem');
+
+resolved = new Intl.RelativeTimeFormat('ru', { style: 'narrow', numeric: 'auto' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'ru');
+assert.sameValue(resolved.style, 'narrow');
+assert.sameValue(resolved.numeric, 'auto');
+
+// Numbering systems
+assert.sameValue(new Intl.RelativeTimeFormat('ar').resolvedOptions().numberingSystem, 'arab');
+resolved = new Intl.RelativeTimeFormat('ar-u-nu-latn').resolvedOptions();
+assert.sameValue(resolved.locale, 'ar-u-nu-latn');
+assert.sameValue(resolved.numberingSystem, 'latn');
+assert.sameValue(
+  new Intl.RelativeTimeFormat('ar', { numberingSystem: 'latn' }).resolvedOptions().numberingSystem,
+  'latn',
+);
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.RelativeTimeFormat('en', { style: 'wide' });
+});
+assert.throws(RangeError, function () {
+  new Intl.RelativeTimeFormat('en', { numeric: 'never' });
+});
+assert.throws(RangeError, function () {
+  new Intl.RelativeTimeFormat('en', { numberingSystem: 'a' });
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.RelativeTimeFormat();
+});
+
+assert.sameValue(Intl.RelativeTimeFormat.supportedLocalesOf(['ja', 'xx']).join(), 'ja');
+assert.sameValue(
+  Object.prototype.toString.call(new Intl.RelativeTimeFormat()),
+  '[object Intl.RelativeTimeFormat]',
+);
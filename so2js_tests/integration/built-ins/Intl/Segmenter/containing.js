@@ -0,0 +1,47 @@
+/*---
+description: Segments.prototype.containing finds the segment containing a code unit index
+---*/
+
+var string = 'Hello, world!';
+var segments = new Intl.Segmenter('en', { granularity: 'word' }).segment(string);
+
+var data = segments.containing(8);
+assert.sameValue(data.segment, 'world');
+assert.sameValue(data.index, 7);
+assert.sameValue(data.input, string);
+assert.sameValue(data.isWordLike, true);
+
+assert.sameValue(segments.containing(7).segment, 'world');
+assert.sameValue(segments.containing(11).segment, 'world');
+assert.sameValue(segments.containing(5).segment, ',');
+assert.sameValue(segments.containing(5).isWordLike, false);
+assert.sameValue(segments.containing(12).segment, '!');
+
+// Index defaults to 0 and is converted to an integer
+assert.sameValue(segments.containing().segment, 'Hello');
+assert.sameValue(segments.containing(2.9).segment, 'Hello');
+assert.sameValue(segments.containing('8').segment, 'world');
+assert.sameValue(segments.containing(-0.5).segment, 'Hello');
+
+// Out of range indices
+assert.sameValue(segments.containing(-1), undefined);
+assert.sameValue(segments.containing(13), undefined);
+assert.sameValue(segments.containing(Infinity), undefined);
+assert.sameValue(new Intl.Segmenter().segment('').containing(0), undefined);
+
+// Indices inside of a grapheme cluster
+var emoji = 'a👨‍👩‍👧b';
+var graphemes = new Intl.Segmenter('en').segment(emoji);
+assert.sameValue(graphemes.containing(0).segment, 'a');
+assert.sameValue(graphemes.containing(3).segment, '👨‍👩‍👧');
+assert.sameValue(graphemes.containing(3).index, 1);
+assert.sameValue(graphemes.containing(9).segment, 'b');
+
+// Sentences
+var sentences = new Intl.Segmenter('en', { granularity: 'sentence' }).segment('One. Two.');
+assert.sameValue(sentences.containing(6).segment, 'Two.');
+assert.sameValue(sentences.containing(6).index, 5);
+
+assert.throws(TypeError, function () {
+  segments.containing.call({}, 0);
+});
@@ -0,0 +1,42 @@
+/*---
+description: Intl.Segmenter validates and resolves its options
+---*/
+
+var resolved = new Intl.Segmenter('en').resolvedOptions();
+assert.sameValue(resolved.locale, 'en');
+assert.sameValue(resolved.granularity, 'grapheme');
+assert.sameValue(Object.keys(resolved).join(), 'locale,granularity');
+
+resolved = new Intl.Segmenter('ja-JP', { granularity: 'word' }).resolvedOptions();
+assert.sameValue(resolved.locale, 'ja');
+assert.sameValue(resolved.granularity, 'word');
+
+assert.sameValue(new Intl.Segmenter('en', { granularity: 'sentence' }).resolvedOptions().granularity, 'sentence');
+
+// Invalid options
+assert.throws(RangeError, function () {
+  new Intl.Segmenter('en', { granularity: 'line' });
+});
+assert.throws(RangeError, function () {
+  new Intl.Segmenter('en', { localeMatcher: 'exact' });
+});
+
+// Options must be an object
+assert.throws(TypeError, function () {
+  new Intl.Segmenter('en', 'word');
+});
+assert.throws(TypeError, function () {
+  new Intl.Segmenter('en', null);
+});
+
+// Must be called as a constructor
+assert.throws(TypeError, function () {
+  Intl.Segmenter();
+});
+
+assert.throws(TypeError, function () {
+  Intl.Segmenter.prototype.segment.call({}, 'a');
+});
+
+assert.sameValue(Intl.Segmenter.supportedLocalesOf(['ru', 'xx']).join(), 'ru');
+assert.sameValue(Object.prototype.toString.call(new Intl.Segmenter()), '[object Intl.Segmenter]');
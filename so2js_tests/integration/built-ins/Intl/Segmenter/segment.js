@@ -0,0 +1,77 @@
+/*---
+description: Intl.Segmenter splits strings into graphemes, words, and sentences
+---*/
+
+function segments(granularity, string) {
+  var segmenter = new Intl.Segmenter('en', { granularity: granularity });
+  return Array.from(segmenter.segment(string), function (data) {
+    return data.segment;
+  });
+}
+
+// Grapheme clusters in one byte strings
+assert.sameValue(segments('grapheme', 'abc').join('|'), 'a|b|c');
+assert.sameValue(segments('grapheme', 'café').join('|'), 'c|a|f|é');
+assert.sameValue(segments('grapheme', '\r\n').length, 1);
+assert.sameValue(segments('grapheme', '').length, 0);
+
+// Grapheme clusters in two byte strings
+assert.sameValue(segments('grapheme', 'éa').join('|'), 'é|a');
+assert.sameValue(segments('grapheme', '👨‍👩‍👧!').join('|'),
+  '👨‍👩‍👧|!');
+assert.sameValue(segments('grapheme', '🇺🇸🇯🇵').length, 2);
+assert.sameValue(segments('grapheme', '👍🏽').length, 1);
+assert.sameValue(segments('grapheme', 'हिन्दी').join('|'),
+  'हि|न्दी');
+
+// Words
+assert.sameValue(segments('word', 'Hello, world!').join('|'), 'Hello|,| |world|!');
+assert.sameValue(segments('word', "can't stop").join('|'), "can't| |stop");
+assert.sameValue(segments('word', 'café škoda').join('|'), 'café| |škoda');
+
+// Sentences
+assert.sameValue(segments('sentence', 'One. Two? Three!').join('|'), 'One. |Two? |Three!');
+
+// Segment data objects
+var string = 'Hi there';
+var data = Array.from(new Intl.Segmenter('en', { granularity: 'word' }).segment(string));
+assert.sameValue(data.length, 3);
+assert.sameValue(data[0].segment, 'Hi');
+assert.sameValue(data[0].index, 0);
+assert.sameValue(data[0].input, string);
+assert.sameValue(data[0].isWordLike, true);
+assert.sameValue(data[1].segment, ' ');
+assert.sameValue(data[1].index, 2);
+assert.sameValue(data[1].isWordLike, false);
+assert.sameValue(data[2].index, 3);
+assert.sameValue(Object.keys(data[0]).join(), 'segment,index,input,isWordLike');
+
+// Only word granularity reports isWordLike
+data = Array.from(new Intl.Segmenter('en').segment('ab'));
+assert.sameValue(Object.keys(data[0]).join(), 'segment,index,input');
+assert.sameValue('isWordLike' in data[0], false);
+
+// Argument is converted to a string
+assert.sameValue(segments('grapheme', 123).join('|'), '1|2|3');
+assert.sameValue(segments('grapheme', undefined).join(''), 'undefined');
+
+// Each iteration starts from the beginning of the string
+var segmentsObject = new Intl.Segmenter().segment('ab');
+assert.sameValue(Array.from(segmentsObject).length, 2);
+assert.sameValue(Array.from(segmentsObject).length, 2);
+
+// Iterators are independent and resume where they left off
+var iterator = segmentsObject[Symbol.iterator]();
+assert.sameValue(iterator.next().value.segment, 'a');
+assert.sameValue(iterator.next().value.segment, 'b');
+var result = iterator.next();
+assert.sameValue(result.done, true);
+assert.sameValue(result.value, undefined);
+assert.sameValue(iterator.next().done, true);
+
+var iteratorProto = Object.getPrototypeOf(iterator);
+assert.sameValue(Object.getPrototypeOf(iteratorProto), Object.getPrototypeOf(Object.getPrototypeOf([][Symbol.iterator]())));
+assert.sameValue(Object.prototype.toString.call(iterator), '[object Segmenter String Iterator]');
+assert.throws(TypeError, function () {
+  iteratorProto.next.call({});
+});
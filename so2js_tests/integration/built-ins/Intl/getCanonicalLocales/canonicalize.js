@@ -0,0 +1,30 @@
+/*---
+description: Intl.getCanonicalLocales canonicalizes and deduplicates a list of locales
+---*/
+
+function canonical(locales) {
+  return Intl.getCanonicalLocales(locales).join();
+}
+
+assert.sameValue(canonical(undefined), '');
+assert.sameValue(canonical([]), '');
+assert.sameValue(canonical('EN-us'), 'en-US');
+assert.sameValue(canonical(['en-us', 'EN-US', 'fr']), 'en-US,fr');
+assert.sameValue(canonical(['iw', 'zh-hant-tw']), 'he,zh-Hant-TW');
+assert.sameValue(canonical(new Intl.Locale('de-AT')), 'de-AT');
+assert.sameValue(canonical([new Intl.Locale('ja'), 'ja']), 'ja');
+
+// Result is a new array
+var result = Intl.getCanonicalLocales('en');
+assert.sameValue(Array.isArray(result), true);
+
+// Invalid tags and elements
+assert.throws(RangeError, function () {
+  Intl.getCanonicalLocales('en-');
+});
+assert.throws(TypeError, function () {
+  Intl.getCanonicalLocales([5]);
+});
+assert.throws(TypeError, function () {
+  Intl.getCanonicalLocales(null);
+});
@@ -0,0 +1,34 @@
+/*---
+description: Intl.supportedValuesOf returns sorted lists of the supported values for each key
+---*/
+
+var keys = ['calendar', 'collation', 'currency', 'numberingSystem', 'timeZone', 'unit'];
+
+keys.forEach(function (key) {
+  var values = Intl.supportedValuesOf(key);
+  assert.sameValue(Array.isArray(values), true);
+  assert.sameValue(values.length > 0, true);
+
+  // Values are sorted and unique
+  for (var i = 1; i < values.length; i++) {
+    assert.sameValue(values[i - 1] < values[i], true);
+  }
+});
+
+assert.sameValue(Intl.supportedValuesOf('calendar').indexOf('gregory') !== -1, true);
+assert.sameValue(Intl.supportedValuesOf('collation').indexOf('emoji') !== -1, true);
+assert.sameValue(Intl.supportedValuesOf('collation').indexOf('default'), -1);
+assert.sameValue(Intl.supportedValuesOf('currency').indexOf('USD') !== -1, true);
+assert.sameValue(Intl.supportedValuesOf('numberingSystem').indexOf('latn') !== -1, true);
+assert.sameValue(Intl.supportedValuesOf('timeZone').indexOf('UTC') !== -1, true);
+assert.sameValue(Intl.supportedValuesOf('unit').indexOf('meter') !== -1, true);
+
+// Each call returns a new array
+assert.sameValue(Intl.supportedValuesOf('unit') !== Intl.supportedValuesOf('unit'), true);
+
+assert.throws(RangeError, function () {
+  Intl.supportedValuesOf('locale');
+});
+assert.throws(RangeError, function () {
+  Intl.supportedValuesOf();
+});
@@ -0,0 +1,109 @@
+/*---
+description: Bulk copies to and from shared memory copy the same bytes as copies of ordinary arrays.
+---*/
+
+var LENGTH = 37;
+
+function sharedBytes() {
+  var bytes = new Uint8Array(new SharedArrayBuffer(LENGTH));
+  for (var i = 0; i < LENGTH; i++) {
+    bytes[i] = i + 1;
+  }
+
+  return bytes;
+}
+
+function arrayBytes() {
+  var array = [];
+  for (var i = 0; i < LENGTH; i++) {
+    array.push(i + 1);
+  }
+
+  return array;
+}
+
+function assertBytes(actual, expected, message) {
+  assert.sameValue(actual.length, expected.length, message);
+  for (var i = 0; i < expected.length; i++) {
+    assert.sameValue(actual[i], expected[i], message + ' at index ' + i);
+  }
+}
+
+// Overlapping copies in both directions, at offsets with different alignments
+(function testCopyWithin() {
+  var cases = [
+    [0, 1, 30],
+    [1, 0, 30],
+    [3, 11, 26],
+    [11, 3, 34],
+    [8, 0, 16],
+    [0, 8, 24],
+    [5, 5, 20],
+    [30, 0, 37],
+  ];
+
+  for (var [target, start, end] of cases) {
+    var message = 'copyWithin(' + target + ', ' + start + ', ' + end + ')';
+    var bytes = sharedBytes();
+    bytes.copyWithin(target, start, end);
+    assertBytes(bytes, arrayBytes().copyWithin(target, start, end), message);
+  }
+
+  // Copies elements wider than a byte
+  var words = new Uint32Array(new SharedArrayBuffer(40));
+  words.set([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+  words.copyWithin(1, 0, 9);
+  assertBytes(words, [1, 1, 2, 3, 4, 5, 6, 7, 8, 9], 'Uint32Array copyWithin');
+})();
+
+(function testSharedArrayBufferSlice() {
+  var bytes = sharedBytes();
+
+  assertBytes(new Uint8Array(bytes.buffer.slice(3, 30)), arrayBytes().slice(3, 30), 'slice');
+  assertBytes(new Uint8Array(bytes.buffer.slice(0)), arrayBytes(), 'full slice');
+  assertBytes(new Uint8Array(bytes.buffer.slice(-5)), arrayBytes().slice(-5), 'end slice');
+})();
+
+(function testTypedArraySet() {
+  // From an ordinary ArrayBuffer into shared memory
+  var bytes = sharedBytes();
+  var source = new Uint8Array(arrayBytes().map((byte) => byte + 100));
+  bytes.set(source.subarray(0, 20), 7);
+
+  var expected = arrayBytes();
+  for (var i = 0; i < 20; i++) {
+    expected[i + 7] = i + 101;
+  }
+  assertBytes(bytes, expected, 'set from ArrayBuffer');
+
+  // From shared memory into an ordinary ArrayBuffer
+  var target = new Uint8Array(LENGTH);
+  target.set(sharedBytes());
+  assertBytes(target, arrayBytes(), 'set into ArrayBuffer');
+
+  // Overlapping views of the same shared buffer
+  bytes = sharedBytes();
+  bytes.set(bytes.subarray(0, 30), 3);
+  expected = arrayBytes()
+    .slice(0, 3)
+    .concat(arrayBytes().slice(0, 30), [34, 35, 36, 37]);
+  assertBytes(bytes, expected, 'set from same buffer');
+})();
+
+(function testTypedArraySlice() {
+  var bytes = sharedBytes();
+  assertBytes(bytes.slice(5, 33), arrayBytes().slice(5, 33), 'TypedArray slice');
+
+  // Bytes are copied one at a time from the start when the species constructor returns a view
+  // that overlaps the end of the sliced range.
+  bytes = sharedBytes();
+  bytes.constructor = {
+    [Symbol.species]: function (length) {
+      return new Uint8Array(bytes.buffer, 1, length);
+    },
+  };
+
+  bytes.slice(0, 4);
+  var result = new Uint8Array(bytes.buffer, 0, 6);
+  assertBytes(result, [1, 1, 1, 1, 1, 6], 'overlapping TypedArray slice');
+})();
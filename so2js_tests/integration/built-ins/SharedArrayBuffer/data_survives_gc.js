@@ -0,0 +1,22 @@
+/*---
+description: Shared memory is not moved or freed when the SharedArrayBuffer is moved by the GC.
+---*/
+
+var sab = new SharedArrayBuffer(8, { maxByteLength: 16 });
+var view = new Int32Array(sab);
+view[0] = 42;
+
+$262.gc();
+
+assert.sameValue(view[0], 42);
+
+// Views over the same buffer share the same memory after growing
+sab.grow(16);
+$262.gc();
+
+var otherView = new Int32Array(sab);
+otherView[3] = 7;
+
+assert.sameValue(view.length, 4);
+assert.sameValue(view[0], 42);
+assert.sameValue(view[3], 7);
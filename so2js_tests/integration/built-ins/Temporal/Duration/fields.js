@@ -0,0 +1,52 @@
+/*---
+description: Temporal.Duration construction, signs, string conversion and addition
+---*/
+
+var duration = Temporal.Duration.from('P1Y2M3W4DT5H6M7.008009010S');
+assert.sameValue(duration.years, 1);
+assert.sameValue(duration.months, 2);
+assert.sameValue(duration.weeks, 3);
+assert.sameValue(duration.days, 4);
+assert.sameValue(duration.hours, 5);
+assert.sameValue(duration.minutes, 6);
+assert.sameValue(duration.seconds, 7);
+assert.sameValue(duration.milliseconds, 8);
+assert.sameValue(duration.microseconds, 9);
+assert.sameValue(duration.nanoseconds, 10);
+assert.sameValue(duration.sign, 1);
+assert.sameValue(duration.blank, false);
+assert.sameValue(duration.toString(), 'P1Y2M3W4DT5H6M7.00800901S');
+
+var negative = Temporal.Duration.from('-P1Y2M');
+assert.sameValue(negative.sign, -1);
+assert.sameValue(negative.toString(), '-P1Y2M');
+assert.sameValue(negative.negated().toString(), 'P1Y2M');
+assert.sameValue(negative.abs().toString(), 'P1Y2M');
+
+var zero = new Temporal.Duration();
+assert.sameValue(zero.blank, true);
+assert.sameValue(zero.sign, 0);
+assert.sameValue(zero.toString(), 'PT0S');
+assert.sameValue(Object.is(zero.negated().days, 0), true);
+
+assert.throws(RangeError, function () {
+  new Temporal.Duration(1, -1);
+});
+assert.throws(RangeError, function () {
+  new Temporal.Duration(0, 0, 0, 1.5);
+});
+
+assert.sameValue(Temporal.Duration.from({ seconds: 1, milliseconds: 500 }).toString(), 'PT1.5S');
+assert.sameValue(Temporal.Duration.from('PT1.987S').toString({ fractionalSecondDigits: 2 }), 'PT1.98S');
+assert.sameValue(Temporal.Duration.from('PT1.987S').toString({ smallestUnit: 'second', roundingMode: 'halfExpand' }), 'PT2S');
+assert.sameValue(Temporal.Duration.from({ minutes: 90 }).toJSON(), 'PT90M');
+
+assert.sameValue(Temporal.Duration.from('PT1H').add('PT30M').toString(), 'PT1H30M');
+assert.sameValue(Temporal.Duration.from('PT1H').subtract('PT90M').toString(), '-PT30M');
+assert.throws(RangeError, function () {
+  Temporal.Duration.from('P1M').add('P1D');
+});
+
+assert.sameValue(Temporal.Duration.compare('PT1H', 'PT60M'), 0);
+assert.sameValue(Temporal.Duration.compare('P1D', 'PT23H'), 1);
+assert.sameValue(duration.with({ years: 2 }).years, 2);
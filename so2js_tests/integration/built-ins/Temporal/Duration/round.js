@@ -0,0 +1,39 @@
+/*---
+description: Temporal.Duration rounding and totals, with and without a relativeTo date
+---*/
+
+assert.sameValue(Temporal.Duration.from({ hours: 25 }).round({ largestUnit: 'day' }).toString(), 'P1DT1H');
+assert.sameValue(Temporal.Duration.from({ minutes: 90 }).round({ smallestUnit: 'hour' }).toString(), 'PT2H');
+assert.sameValue(
+  Temporal.Duration.from({ minutes: 90 }).round({ smallestUnit: 'hour', roundingMode: 'trunc' }).toString(),
+  'PT1H'
+);
+assert.sameValue(Temporal.Duration.from({ seconds: 3661 }).round({ largestUnit: 'hour' }).toString(), 'PT1H1M1S');
+
+// Calendar units need a relativeTo date
+assert.sameValue(
+  Temporal.Duration.from({ months: 1 }).round({ largestUnit: 'day', relativeTo: '2024-02-01' }).toString(),
+  'P29D'
+);
+assert.sameValue(
+  Temporal.Duration.from({ days: 40 }).round({ largestUnit: 'month', relativeTo: '2024-01-01' }).toString(),
+  'P1M9D'
+);
+assert.throws(RangeError, function () {
+  Temporal.Duration.from({ months: 1 }).round({ largestUnit: 'day' });
+});
+
+// Days are 24 hours without relativeTo, but not across a DST change
+assert.sameValue(
+  Temporal.Duration.from({ hours: 24 })
+    .round({ largestUnit: 'day', relativeTo: '2024-03-10T00:00[America/New_York]' })
+    .toString(),
+  'P1DT1H'
+);
+
+assert.sameValue(Temporal.Duration.from('PT1H30M').total('minute'), 90);
+assert.sameValue(Temporal.Duration.from({ hours: 36 }).total('day'), 1.5);
+assert.sameValue(Temporal.Duration.from({ days: 60 }).total({ unit: 'month', relativeTo: '2024-01-01' }), 2);
+assert.throws(RangeError, function () {
+  Temporal.Duration.from({ months: 1 }).total('day');
+});
@@ -0,0 +1,46 @@
+/*---
+description: Temporal.Instant conversion, arithmetic, rounding and formatting
+---*/
+
+assert.sameValue(Temporal.Instant.fromEpochMilliseconds(0).toString(), '1970-01-01T00:00:00Z');
+assert.sameValue(Temporal.Instant.fromEpochMilliseconds(1).epochNanoseconds, 1000000n);
+assert.sameValue(Temporal.Instant.fromEpochNanoseconds(-1n).toString(), '1969-12-31T23:59:59.999999999Z');
+assert.sameValue(Temporal.Instant.from('2024-03-15T12:00:00+01:00').epochMilliseconds, Date.UTC(2024, 2, 15, 11));
+assert.throws(RangeError, function () {
+  Temporal.Instant.from('2024-03-15T12:00:00');
+});
+assert.throws(RangeError, function () {
+  Temporal.Instant.fromEpochMilliseconds(8.64e15 + 1);
+});
+
+var epoch = new Temporal.Instant(0n);
+assert.sameValue(epoch.add({ hours: 1 }).toString(), '1970-01-01T01:00:00Z');
+assert.sameValue(epoch.subtract({ minutes: 1 }).toString(), '1969-12-31T23:59:00Z');
+assert.throws(RangeError, function () {
+  epoch.add({ days: 1 });
+});
+
+var start = Temporal.Instant.from('2024-01-01T00:00Z');
+assert.sameValue(start.until('2024-01-02T01:00Z').toString(), 'PT90000S');
+assert.sameValue(start.until('2024-01-02T01:00Z', { largestUnit: 'hour' }).toString(), 'PT25H');
+assert.sameValue(start.since('2023-12-31T23:59:30Z').toString(), 'PT30S');
+
+var instant = Temporal.Instant.from('2024-03-15T12:34:56.789Z');
+assert.sameValue(instant.round('second').toString(), '2024-03-15T12:34:57Z');
+assert.sameValue(instant.round({ smallestUnit: 'minute', roundingIncrement: 30 }).toString(), '2024-03-15T12:30:00Z');
+assert.sameValue(instant.toString({ smallestUnit: 'minute' }), '2024-03-15T12:34Z');
+assert.sameValue(instant.toString({ timeZone: 'America/New_York' }), '2024-03-15T08:34:56.789-04:00');
+assert.sameValue(
+  instant.toZonedDateTimeISO('Europe/London').toString(),
+  '2024-03-15T12:34:56.789+00:00[Europe/London]'
+);
+
+assert.sameValue(Temporal.Instant.compare(start, instant), -1);
+assert.sameValue(instant.equals('2024-03-15T13:34:56.789+01:00'), true);
+
+// Date interoperability
+assert.sameValue(new Date(0).toTemporalInstant().toString(), '1970-01-01T00:00:00Z');
+assert.sameValue(new Date(Date.UTC(2024, 2, 15)).toTemporalInstant().equals('2024-03-15T00:00Z'), true);
+assert.throws(RangeError, function () {
+  new Date(NaN).toTemporalInstant();
+});
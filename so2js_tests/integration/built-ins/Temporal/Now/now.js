@@ -0,0 +1,22 @@
+/*---
+description: Temporal.Now returns the current time in the default time zone
+timeZone: America/New_York
+---*/
+
+assert.sameValue(Object.prototype.toString.call(Temporal), '[object Temporal]');
+assert.sameValue(Object.prototype.toString.call(Temporal.Now), '[object Temporal.Now]');
+
+assert.sameValue(Temporal.Now.timeZoneId(), 'America/New_York');
+assert.sameValue(Temporal.Now.zonedDateTimeISO().timeZoneId, 'America/New_York');
+assert.sameValue(Temporal.Now.zonedDateTimeISO('UTC').timeZoneId, 'UTC');
+
+var instant = Temporal.Now.instant();
+assert.sameValue(typeof instant.epochNanoseconds, 'bigint');
+assert.sameValue(Math.abs(instant.epochMilliseconds - Date.now()) < 1000, true);
+
+assert.sameValue(Temporal.Now.plainDateISO('UTC') instanceof Temporal.PlainDate, true);
+assert.sameValue(Temporal.Now.plainTimeISO() instanceof Temporal.PlainTime, true);
+assert.sameValue(Temporal.Now.plainDateTimeISO('+01:00') instanceof Temporal.PlainDateTime, true);
+assert.throws(RangeError, function () {
+  Temporal.Now.plainDateISO('Not/AZone');
+});
@@ -0,0 +1,24 @@
+/*---
+description: Temporal.PlainDate arithmetic constrains or rejects days that do not exist in the result month
+---*/
+
+var date = new Temporal.PlainDate(2024, 1, 31);
+assert.sameValue(date.add({ months: 1 }).toString(), '2024-02-29');
+assert.sameValue(date.add({ years: 1, months: 1 }).toString(), '2025-02-28');
+assert.throws(RangeError, function () {
+  date.add({ months: 1 }, { overflow: 'reject' });
+});
+
+assert.sameValue(Temporal.PlainDate.from('2024-03-15').subtract({ days: 20 }).toString(), '2024-02-24');
+assert.sameValue(Temporal.PlainDate.from('2024-03-15').add({ weeks: -2 }).toString(), '2024-03-01');
+
+// Differences default to days as the largest unit
+var start = Temporal.PlainDate.from('2024-01-15');
+assert.sameValue(start.until('2024-03-20').toString(), 'P65D');
+assert.sameValue(start.until('2024-03-20', { largestUnit: 'month' }).toString(), 'P2M5D');
+assert.sameValue(Temporal.PlainDate.from('2024-03-20').since(start, { largestUnit: 'year' }).toString(), 'P2M5D');
+assert.sameValue(start.until('2025-03-20', { largestUnit: 'year', smallestUnit: 'month' }).toString(), 'P1Y2M');
+
+assert.sameValue(Temporal.PlainDate.compare('2024-01-01', '2023-12-31'), 1);
+assert.sameValue(Temporal.PlainDate.compare('2024-01-01', '2024-01-01'), 0);
+assert.sameValue(start.equals('2024-01-15'), true);
@@ -0,0 +1,50 @@
+/*---
+description: Temporal.PlainDate getters, property bag conversion and string conversion
+---*/
+
+var date = Temporal.PlainDate.from('2024-03-15');
+assert.sameValue(date.calendarId, 'iso8601');
+assert.sameValue(date.year, 2024);
+assert.sameValue(date.month, 3);
+assert.sameValue(date.monthCode, 'M03');
+assert.sameValue(date.day, 15);
+assert.sameValue(date.dayOfWeek, 5);
+assert.sameValue(date.dayOfYear, 75);
+assert.sameValue(date.weekOfYear, 11);
+assert.sameValue(date.yearOfWeek, 2024);
+assert.sameValue(date.daysInWeek, 7);
+assert.sameValue(date.daysInMonth, 31);
+assert.sameValue(date.daysInYear, 366);
+assert.sameValue(date.monthsInYear, 12);
+assert.sameValue(date.inLeapYear, true);
+
+// Property bags are constrained by default
+assert.sameValue(Temporal.PlainDate.from({ year: 2023, month: 2, day: 29 }).toString(), '2023-02-28');
+assert.sameValue(Temporal.PlainDate.from({ year: 2023, monthCode: 'M02', day: 1 }).toString(), '2023-02-01');
+assert.throws(RangeError, function () {
+  Temporal.PlainDate.from({ year: 2023, month: 2, day: 29 }, { overflow: 'reject' });
+});
+assert.throws(TypeError, function () {
+  Temporal.PlainDate.from({ year: 2023, day: 1 });
+});
+
+assert.sameValue(date.with({ day: 31, month: 4 }).toString(), '2024-04-30');
+assert.sameValue(date.toPlainDateTime('12:30').toString(), '2024-03-15T12:30:00');
+assert.sameValue(date.toPlainYearMonth().toString(), '2024-03');
+assert.sameValue(date.toPlainMonthDay().toString(), '03-15');
+
+// String parsing and formatting
+assert.sameValue(Temporal.PlainDate.from('2024-03-15T10:00:00[u-ca=iso8601]').toString(), '2024-03-15');
+assert.sameValue(Temporal.PlainDate.from('20240315').toString(), '2024-03-15');
+assert.sameValue(Temporal.PlainDate.from('+010000-01-01').toString(), '+010000-01-01');
+assert.sameValue(date.toString({ calendarName: 'always' }), '2024-03-15[u-ca=iso8601]');
+assert.sameValue(date.toJSON(), '2024-03-15');
+assert.throws(RangeError, function () {
+  Temporal.PlainDate.from('2024-03-15T10:00Z');
+});
+assert.throws(RangeError, function () {
+  Temporal.PlainDate.from('2024-13-01');
+});
+assert.throws(TypeError, function () {
+  date.valueOf();
+});
@@ -0,0 +1,29 @@
+/*---
+description: Temporal.PlainDateTime arithmetic, rounding and conversion
+---*/
+
+var dateTime = Temporal.PlainDateTime.from('2024-01-31T10:00');
+assert.sameValue(dateTime.add({ months: 1 }).toString(), '2024-02-29T10:00:00');
+assert.sameValue(dateTime.add({ hours: 14 }).toString(), '2024-02-01T00:00:00');
+assert.sameValue(dateTime.subtract({ days: 1, minutes: 1 }).toString(), '2024-01-30T09:59:00');
+
+var start = Temporal.PlainDateTime.from('2024-03-15T10:00');
+assert.sameValue(start.until('2024-03-17T09:00').toString(), 'P1DT23H');
+assert.sameValue(start.until('2024-03-17T09:00', { largestUnit: 'hour' }).toString(), 'PT47H');
+assert.sameValue(start.since('2024-03-14T09:30').toString(), 'P1DT30M');
+
+// Rounding
+var time = Temporal.PlainDateTime.from('2024-03-15T10:30');
+assert.sameValue(time.round('hour').toString(), '2024-03-15T11:00:00');
+assert.sameValue(time.round({ smallestUnit: 'hour', roundingMode: 'floor' }).toString(), '2024-03-15T10:00:00');
+assert.sameValue(Temporal.PlainDateTime.from('2024-03-15T12:00').round('day').toString(), '2024-03-16T00:00:00');
+assert.throws(RangeError, function () {
+  time.round({ smallestUnit: 'minute', roundingIncrement: 7 });
+});
+
+assert.sameValue(start.withPlainTime().toString(), '2024-03-15T00:00:00');
+assert.sameValue(start.toString({ fractionalSecondDigits: 3 }), '2024-03-15T10:00:00.000');
+assert.sameValue(start.toString({ smallestUnit: 'minute' }), '2024-03-15T10:00');
+assert.sameValue(start.toPlainDate().toString(), '2024-03-15');
+assert.sameValue(start.toPlainTime().toString(), '10:00:00');
+assert.sameValue(start.toZonedDateTime('America/New_York').toString(), '2024-03-15T10:00:00-04:00[America/New_York]');
@@ -0,0 +1,20 @@
+/*---
+description: Temporal.PlainMonthDay keeps leap days and constrains them when converted to a date
+---*/
+
+var monthDay = Temporal.PlainMonthDay.from('--02-29');
+assert.sameValue(monthDay.monthCode, 'M02');
+assert.sameValue(monthDay.day, 29);
+assert.sameValue(monthDay.toString(), '02-29');
+assert.sameValue(monthDay.toPlainDate({ year: 2023 }).toString(), '2023-02-28');
+assert.sameValue(monthDay.toPlainDate({ year: 2024 }).toString(), '2024-02-29');
+
+assert.sameValue(Temporal.PlainMonthDay.from({ month: 2, day: 30 }).toString(), '02-29');
+assert.sameValue(Temporal.PlainMonthDay.from({ monthCode: 'M04', day: 31 }).toString(), '04-30');
+assert.throws(RangeError, function () {
+  Temporal.PlainMonthDay.from({ month: 2, day: 30 }, { overflow: 'reject' });
+});
+
+assert.sameValue(monthDay.with({ day: 1 }).toString(), '02-01');
+assert.sameValue(monthDay.equals('02-29'), true);
+assert.sameValue(monthDay.toString({ calendarName: 'always' }), '1972-02-29[u-ca=iso8601]');
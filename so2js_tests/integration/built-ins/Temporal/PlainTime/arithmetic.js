@@ -0,0 +1,28 @@
+/*---
+description: Temporal.PlainTime wraps around midnight and supports rounding and differences
+---*/
+
+assert.sameValue(new Temporal.PlainTime(23, 30).add({ hours: 1 }).toString(), '00:30:00');
+assert.sameValue(new Temporal.PlainTime(0, 15).subtract({ minutes: 30 }).toString(), '23:45:00');
+
+var time = Temporal.PlainTime.from('12:34:56.123456789');
+assert.sameValue(time.hour, 12);
+assert.sameValue(time.minute, 34);
+assert.sameValue(time.second, 56);
+assert.sameValue(time.millisecond, 123);
+assert.sameValue(time.microsecond, 456);
+assert.sameValue(time.nanosecond, 789);
+assert.sameValue(time.toString(), '12:34:56.123456789');
+assert.sameValue(time.toString({ smallestUnit: 'millisecond' }), '12:34:56.123');
+assert.sameValue(time.toString({ fractionalSecondDigits: 0 }), '12:34:56');
+
+assert.sameValue(Temporal.PlainTime.from('10:00').until('12:30').toString(), 'PT2H30M');
+assert.sameValue(Temporal.PlainTime.from('10:00').since('12:30').toString(), '-PT2H30M');
+assert.sameValue(Temporal.PlainTime.from('10:08').round({ smallestUnit: 'minute', roundingIncrement: 15 }).toString(), '10:15:00');
+assert.sameValue(Temporal.PlainTime.from('T10:00').toString(), '10:00:00');
+
+assert.sameValue(Temporal.PlainTime.from({ hour: 25 }).toString(), '23:00:00');
+assert.throws(RangeError, function () {
+  Temporal.PlainTime.from({ hour: 25 }, { overflow: 'reject' });
+});
+assert.sameValue(Temporal.PlainTime.compare('10:00', '09:59:59.999999999'), 1);
@@ -0,0 +1,20 @@
+/*---
+description: Temporal.PlainYearMonth arithmetic and conversion to a date
+---*/
+
+var yearMonth = Temporal.PlainYearMonth.from('2024-02');
+assert.sameValue(yearMonth.year, 2024);
+assert.sameValue(yearMonth.month, 2);
+assert.sameValue(yearMonth.monthCode, 'M02');
+assert.sameValue(yearMonth.daysInMonth, 29);
+assert.sameValue(yearMonth.inLeapYear, true);
+
+assert.sameValue(yearMonth.add({ months: 11 }).toString(), '2025-01');
+assert.sameValue(yearMonth.subtract({ years: 1, months: 2 }).toString(), '2022-12');
+assert.sameValue(yearMonth.until('2025-05').toString(), 'P1Y3M');
+assert.sameValue(yearMonth.since('2025-05', { largestUnit: 'month' }).toString(), '-P15M');
+
+assert.sameValue(yearMonth.toPlainDate({ day: 31 }).toString(), '2024-02-29');
+assert.sameValue(yearMonth.with({ month: 12 }).toString(), '2024-12');
+assert.sameValue(yearMonth.toString({ calendarName: 'always' }), '2024-02-01[u-ca=iso8601]');
+assert.sameValue(Temporal.PlainYearMonth.compare('2024-02', '2024-03'), -1);
@@ -0,0 +1,36 @@
+/*---
+description: Temporal.ZonedDateTime construction, time zone identifiers and conversions
+---*/
+
+var zoned = Temporal.ZonedDateTime.from('2024-03-15T12:00-04:00[America/New_York]');
+assert.sameValue(zoned.timeZoneId, 'America/New_York');
+assert.sameValue(zoned.calendarId, 'iso8601');
+assert.sameValue(zoned.year, 2024);
+assert.sameValue(zoned.hour, 12);
+assert.sameValue(zoned.epochMilliseconds, Date.UTC(2024, 2, 15, 16));
+assert.sameValue(zoned.toInstant().toString(), '2024-03-15T16:00:00Z');
+assert.sameValue(zoned.toPlainDateTime().toString(), '2024-03-15T12:00:00');
+assert.sameValue(zoned.withTimeZone('Europe/London').toString(), '2024-03-15T16:00:00+00:00[Europe/London]');
+assert.sameValue(zoned.withPlainTime('08:15').toString(), '2024-03-15T08:15:00-04:00[America/New_York]');
+assert.sameValue(zoned.with({ month: 1 }).toString(), '2024-01-15T12:00:00-05:00[America/New_York]');
+assert.sameValue(zoned.round('day').toString(), '2024-03-16T00:00:00-04:00[America/New_York]');
+
+// Formatting options
+assert.sameValue(zoned.toString({ offset: 'never' }), '2024-03-15T12:00:00[America/New_York]');
+assert.sameValue(zoned.toString({ timeZoneName: 'critical' }), '2024-03-15T12:00:00-04:00[!America/New_York]');
+assert.sameValue(zoned.toString({ timeZoneName: 'never', calendarName: 'always' }), '2024-03-15T12:00:00-04:00[u-ca=iso8601]');
+
+// UTC and offset time zones
+assert.sameValue(Temporal.ZonedDateTime.from('2024-03-15T12:00Z[UTC]').toString(), '2024-03-15T12:00:00+00:00[UTC]');
+assert.sameValue(new Temporal.ZonedDateTime(0n, '+05:30').toString(), '1970-01-01T05:30:00+05:30[+05:30]');
+assert.sameValue(new Temporal.ZonedDateTime(0n, 'america/new_york').timeZoneId, 'America/New_York');
+assert.throws(RangeError, function () {
+  new Temporal.ZonedDateTime(0n, 'Mars/Olympus_Mons');
+});
+assert.throws(TypeError, function () {
+  new Temporal.ZonedDateTime(0, 'UTC');
+});
+
+assert.sameValue(zoned.equals('2024-03-15T16:00Z[America/New_York]'), true);
+assert.sameValue(zoned.equals('2024-03-15T16:00Z[UTC]'), false);
+assert.sameValue(Temporal.ZonedDateTime.compare(zoned, zoned.withTimeZone('UTC')), 0);
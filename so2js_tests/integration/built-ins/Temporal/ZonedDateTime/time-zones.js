@@ -0,0 +1,66 @@
+/*---
+description: Temporal.ZonedDateTime resolves local times and arithmetic across daylight saving time transitions
+---*/
+
+// Exact time arithmetic skips the missing hour, calendar arithmetic keeps the wall-clock time
+var beforeGap = Temporal.ZonedDateTime.from('2024-03-10T01:30:00-05:00[America/New_York]');
+assert.sameValue(beforeGap.add({ hours: 1 }).toString(), '2024-03-10T03:30:00-04:00[America/New_York]');
+assert.sameValue(beforeGap.add({ days: 1 }).toString(), '2024-03-11T01:30:00-04:00[America/New_York]');
+assert.sameValue(beforeGap.hoursInDay, 23);
+assert.sameValue(beforeGap.offset, '-05:00');
+assert.sameValue(beforeGap.offsetNanoseconds, -5 * 3600 * 1e9);
+
+// Times in the gap
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-03-10T02:30[America/New_York]').toString(),
+  '2024-03-10T03:30:00-04:00[America/New_York]'
+);
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-03-10T02:30[America/New_York]', { disambiguation: 'earlier' }).toString(),
+  '2024-03-10T01:30:00-05:00[America/New_York]'
+);
+assert.throws(RangeError, function () {
+  Temporal.ZonedDateTime.from('2024-03-10T02:30[America/New_York]', { disambiguation: 'reject' });
+});
+
+// Times in the overlap
+assert.sameValue(Temporal.ZonedDateTime.from('2024-11-03T01:30[America/New_York]').offset, '-04:00');
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-11-03T01:30[America/New_York]', { disambiguation: 'later' }).offset,
+  '-05:00'
+);
+assert.sameValue(Temporal.ZonedDateTime.from('2024-11-03T01:30-05:00[America/New_York]').offset, '-05:00');
+assert.sameValue(Temporal.ZonedDateTime.from('2024-11-03T12:00[America/New_York]').hoursInDay, 25);
+
+// Offsets that do not match the time zone
+assert.throws(RangeError, function () {
+  Temporal.ZonedDateTime.from('2024-03-15T12:00+01:00[America/New_York]');
+});
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-03-15T12:00+01:00[America/New_York]', { offset: 'ignore' }).toString(),
+  '2024-03-15T12:00:00-04:00[America/New_York]'
+);
+
+// Differences across the transition
+var start = Temporal.ZonedDateTime.from('2024-03-09T12:00[America/New_York]');
+assert.sameValue(start.until('2024-03-10T12:00[America/New_York]').toString(), 'PT23H');
+assert.sameValue(start.until('2024-03-10T12:00[America/New_York]', { largestUnit: 'day' }).toString(), 'P1D');
+assert.throws(RangeError, function () {
+  start.until(start.withTimeZone('UTC'), { largestUnit: 'day' });
+});
+
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-03-10T12:00[America/New_York]').startOfDay().toString(),
+  '2024-03-10T00:00:00-05:00[America/New_York]'
+);
+
+// Transitions
+var march = Temporal.ZonedDateTime.from('2024-03-01T00:00[America/New_York]');
+assert.sameValue(march.getTimeZoneTransition('next').toString(), '2024-03-10T03:00:00-04:00[America/New_York]');
+assert.sameValue(
+  Temporal.ZonedDateTime.from('2024-03-15T00:00[America/New_York]')
+    .getTimeZoneTransition({ direction: 'previous' })
+    .toString(),
+  '2024-03-10T03:00:00-04:00[America/New_York]'
+);
+assert.sameValue(march.withTimeZone('UTC').getTimeZoneTransition('next'), null);
@@ -0,0 +1,33 @@
+/*---
+description: Heap statistics count the objects of each kind before and after a collection.
+---*/
+
+function mapCount() {
+  var kinds = $262.gcStatistics().kinds;
+  return kinds.MapObject ? kinds.MapObject.count : 0;
+}
+
+$262.gc();
+var initialCount = mapCount();
+
+var maps = [];
+for (var i = 0; i < 1000; i++) {
+  maps.push(new Map());
+}
+
+// Live maps are counted, whether or not they have been collected since they were allocated
+var liveCount = mapCount();
+assert(liveCount >= initialCount + 1000);
+
+$262.gc();
+assert.sameValue(mapCount(), liveCount);
+
+// Unreachable maps are freed by a collection
+maps = null;
+$262.gc();
+assert(mapCount() <= liveCount - 1000);
+
+var stats = $262.gcStatistics();
+assert(stats.numMajorGcs >= 3);
+assert(stats.numObjects > 0);
+assert(stats.history.length > 0);
@@ -0,0 +1,45 @@
+/*---
+description: Large object graphs survive collections intact, including when marked in parallel.
+---*/
+
+function buildTree(depth, value) {
+  if (depth === 0) {
+    return { value, children: [] };
+  }
+
+  return {
+    value,
+    children: [buildTree(depth - 1, value * 2), buildTree(depth - 1, value * 2 + 1)],
+    map: new Map([[value, "v" + value]]),
+  };
+}
+
+function checkTree(tree, depth, value) {
+  assert.sameValue(tree.value, value);
+  if (depth === 0) {
+    assert.sameValue(tree.children.length, 0);
+    return;
+  }
+
+  assert.sameValue(tree.map.get(value), "v" + value);
+  checkTree(tree.children[0], depth - 1, value * 2);
+  checkTree(tree.children[1], depth - 1, value * 2 + 1);
+}
+
+var trees = [];
+for (var i = 0; i < 8; i++) {
+  trees.push(buildTree(10, 1));
+}
+
+$262.gc();
+
+// Mutate the graph between collections so that objects move between old and new generations
+for (var i = 0; i < trees.length; i++) {
+  trees[i].children[0] = buildTree(9, 2);
+}
+
+$262.gc();
+
+for (var i = 0; i < trees.length; i++) {
+  checkTree(trees[i], 10, 1);
+}
@@ -0,0 +1,83 @@
+/*---
+description: Named property loads stay correct as object shapes and prototypes change.
+---*/
+
+function getX(o) {
+  return o.x;
+}
+
+// Monomorphic, then polymorphic over objects with different layouts
+assert.sameValue(getX({ x: 1 }), 1);
+assert.sameValue(getX({ x: 2 }), 2);
+assert.sameValue(getX({ a: 0, x: 3 }), 3);
+assert.sameValue(getX({ a: 0, b: 0, x: 4 }), 4);
+assert.sameValue(getX({ x: 5, a: 0 }), 5);
+
+// Megamorphic
+for (var i = 0; i < 10; i++) {
+  var o = {};
+  o["p" + i] = i;
+  o.x = i;
+  assert.sameValue(getX(o), i);
+}
+
+// Property found on the prototype
+function Point() {}
+Point.prototype.x = 10;
+var p = new Point();
+assert.sameValue(getX(p), 10);
+assert.sameValue(getX(p), 10);
+
+// Prototype property changes value
+Point.prototype.x = 11;
+assert.sameValue(getX(p), 11);
+
+// Prototype property is deleted
+delete Point.prototype.x;
+assert.sameValue(getX(p), undefined);
+
+// Own property shadows prototype property
+Point.prototype.x = 12;
+assert.sameValue(getX(p), 12);
+p.x = 13;
+assert.sameValue(getX(p), 13);
+
+// Prototype is swapped for an object with the same layout
+var q = new Point();
+assert.sameValue(getX(q), 12);
+Object.setPrototypeOf(q, { x: 14 });
+assert.sameValue(getX(q), 14);
+
+// Data property is redefined as an accessor
+var r = { x: 1 };
+assert.sameValue(getX(r), 1);
+Object.defineProperty(r, "x", { get() { return 15; } });
+assert.sameValue(getX(r), 15);
+
+// Deleting and re-adding properties renumbers slots
+var s = { a: 1, b: 2, x: 3 };
+assert.sameValue(getX(s), 3);
+delete s.a;
+assert.sameValue(getX(s), 3);
+delete s.x;
+assert.sameValue(getX(s), undefined);
+s.x = 16;
+assert.sameValue(getX(s), 16);
+for (var j = 0; j < 20; j++) {
+  s["k" + j] = j;
+  delete s["k" + j];
+}
+assert.sameValue(getX(s), 16);
+assert.sameValue(s.b, 2);
+
+// Exotic objects
+var arr = [1, 2, 3];
+arr.x = 17;
+assert.sameValue(getX(arr), 17);
+assert.sameValue(getX(new Proxy({ x: 1 }, { get() { return 18; } })), 18);
+
+// Primitive receivers
+String.prototype.x = 19;
+assert.sameValue(getX("abc"), 19);
+delete String.prototype.x;
+assert.sameValue(getX("abc"), undefined);
@@ -0,0 +1,76 @@
+/*---
+description: Named property stores stay correct as object shapes and attributes change.
+---*/
+
+function setX(o, v) {
+  o.x = v;
+}
+
+function strictSetX(o, v) {
+  "use strict";
+  o.x = v;
+}
+
+// Stores to existing own properties
+var a = { x: 0 };
+setX(a, 1);
+setX(a, 2);
+assert.sameValue(a.x, 2);
+
+// Stores that add properties
+var b = {};
+setX(b, 3);
+assert.sameValue(b.x, 3);
+assert.sameValue(Object.keys(b).join(), "x");
+
+// Property becomes non-writable
+var c = { x: 0 };
+setX(c, 4);
+Object.defineProperty(c, "x", { writable: false });
+setX(c, 5);
+assert.sameValue(c.x, 4);
+assert.throws(TypeError, function() {
+  strictSetX(c, 5);
+});
+
+// Frozen objects
+var d = { x: 0 };
+strictSetX(d, 6);
+Object.freeze(d);
+assert.throws(TypeError, function() {
+  strictSetX(d, 7);
+});
+assert.sameValue(d.x, 6);
+
+// Property is redefined as an accessor
+var e = { x: 0 };
+var stored;
+setX(e, 8);
+Object.defineProperty(e, "x", { set(v) { stored = v; } });
+setX(e, 9);
+assert.sameValue(stored, 9);
+
+// Setter on the prototype is not bypassed
+var proto = { set x(v) { stored = v * 2; } };
+var f = Object.create(proto);
+setX(f, 10);
+assert.sameValue(stored, 20);
+assert.sameValue(Object.getOwnPropertyNames(f).length, 0);
+
+// Non-writable property on the prototype prevents adding an own property
+var g = Object.create(Object.defineProperty({}, "x", { value: 1, writable: false }));
+setX(g, 11);
+assert.sameValue(g.x, 1);
+assert.sameValue(Object.getOwnPropertyNames(g).length, 0);
+
+// Objects with the same layout share cached stores
+var objects = [];
+for (var i = 0; i < 10; i++) {
+  var o = { x: 0, y: 0 };
+  setX(o, i);
+  objects.push(o);
+}
+for (var i = 0; i < 10; i++) {
+  assert.sameValue(objects[i].x, i);
+  assert.sameValue(objects[i].y, 0);
+}
@@ -0,0 +1,64 @@
+/*---
+description: Named property access stays correct when objects share delete transitions or exceed the shape transition limit.
+---*/
+
+function getX(o) {
+  return o.x;
+}
+
+function setX(o, value) {
+  o.x = value;
+}
+
+// Deleting different keys from objects with the same shape results in different layouts
+var deletedA = { a: 1, b: 2, x: 3 };
+var deletedB = { a: 1, b: 2, x: 4 };
+delete deletedA.a;
+delete deletedB.b;
+
+for (var i = 0; i < 3; i++) {
+  assert.sameValue(getX(deletedA), 3);
+  assert.sameValue(getX(deletedB), 4);
+  assert.sameValue(deletedA.b, 2);
+  assert.sameValue(deletedB.a, 1);
+}
+
+// Deleting the same key from objects with the same shape results in the same layout
+var sameDeletes = [];
+for (var i = 0; i < 10; i++) {
+  var o = { a: i, b: i, x: i };
+  delete o.a;
+  sameDeletes.push(o);
+}
+
+for (var i = 0; i < sameDeletes.length; i++) {
+  assert.sameValue(getX(sameDeletes[i]), i);
+  assert.sameValue(sameDeletes[i].a, undefined);
+  assert.sameValue(sameDeletes[i].b, i);
+}
+
+// Re-adding a deleted key appends it in a new slot
+var readded = { a: 1, x: 2 };
+delete readded.a;
+readded.a = 3;
+assert.sameValue(getX(readded), 2);
+assert.sameValue(readded.a, 3);
+assert.sameValue(Object.keys(readded).join(), "x,a");
+
+// Many different first keys exceed the number of transitions out of the empty shape, so later
+// objects are in dictionary mode.
+var dictionaries = [];
+for (var i = 0; i < 200; i++) {
+  var o = {};
+  o["key" + i] = i;
+  o.x = i;
+  dictionaries.push(o);
+}
+
+for (var i = 0; i < dictionaries.length; i++) {
+  assert.sameValue(getX(dictionaries[i]), i);
+  setX(dictionaries[i], i + 1);
+  assert.sameValue(getX(dictionaries[i]), i + 1);
+  assert.sameValue(dictionaries[i]["key" + i], i);
+  assert.sameValue(Object.keys(dictionaries[i]).join(), "key" + i + ",x");
+}
@@ -0,0 +1,108 @@
+/*---
+description: Global variable loads and stores stay correct as globals are redefined and shadowed.
+---*/
+
+var globalVar = 1;
+let globalLet = 2;
+const globalConst = 3;
+
+function getVar() {
+  return globalVar;
+}
+
+function setVar(v) {
+  globalVar = v;
+}
+
+function getLet() {
+  return globalLet;
+}
+
+function setLet(v) {
+  globalLet = v;
+}
+
+function setConst(v) {
+  globalConst = v;
+}
+
+function getShadowed() {
+  return shadowed;
+}
+
+function getUnresolved() {
+  return typeof unresolved;
+}
+
+// Global vars
+assert.sameValue(getVar(), 1);
+setVar(4);
+setVar(5);
+assert.sameValue(getVar(), 5);
+assert.sameValue(globalThis.globalVar, 5);
+
+// Global lexical bindings
+assert.sameValue(getLet(), 2);
+setLet(6);
+setLet(7);
+assert.sameValue(getLet(), 7);
+assert.sameValue(globalLet, 7);
+
+// Assignments to constants always throw
+assert.throws(TypeError, setConst);
+assert.throws(TypeError, setConst);
+assert.sameValue(globalConst, 3);
+
+// Global property becomes an accessor
+globalThis.accessor = 8;
+function getAccessor() {
+  return accessor;
+}
+assert.sameValue(getAccessor(), 8);
+Object.defineProperty(globalThis, "accessor", { get() { return 9; } });
+assert.sameValue(getAccessor(), 9);
+
+// Global property becomes non-writable
+globalThis.readOnly = 10;
+function setReadOnly(v) {
+  readOnly = v;
+}
+setReadOnly(11);
+Object.defineProperty(globalThis, "readOnly", { writable: false });
+setReadOnly(12);
+assert.sameValue(readOnly, 11);
+
+// Global property is deleted and re-added
+globalThis.deleted = 13;
+function getDeleted() {
+  return typeof deleted === "undefined" ? undefined : deleted;
+}
+assert.sameValue(getDeleted(), 13);
+delete globalThis.deleted;
+assert.sameValue(getDeleted(), undefined);
+globalThis.deleted = 14;
+assert.sameValue(getDeleted(), 14);
+
+// Unresolved global becomes defined
+assert.sameValue(getUnresolved(), "undefined");
+globalThis.unresolved = 15;
+assert.sameValue(getUnresolved(), "number");
+
+// Global property is shadowed by a lexical binding declared in a later script
+globalThis.shadowed = 16;
+assert.sameValue(getShadowed(), 16);
+assert.sameValue(getShadowed(), 16);
+$262.evalScript("let shadowed = 17;");
+assert.sameValue(getShadowed(), 17);
+assert.sameValue(globalThis.shadowed, 16);
+
+// Builtins
+function getMath() {
+  return Math;
+}
+var OriginalMath = Math;
+assert.sameValue(getMath(), OriginalMath);
+Math = 18;
+assert.sameValue(getMath(), 18);
+Math = OriginalMath;
+assert.sameValue(getMath(), OriginalMath);
@@ -0,0 +1,346 @@
+use so2js::common::source_map::{
+    decode_data_url, find_source_mapping_url, resolve_path, OriginalPosition, SourceMap,
+    SourceMapError,
+};
+
+const SOURCE_MAP_PATH: &str = "/dir/app.js.map";
+
+/// Parse a regular source map with the given sources, names, and mappings.
+fn parse(sources: &[&str], names: &[&str], mappings: &str) -> Result<SourceMap, SourceMapError> {
+    let json = format!(
+        r#"{{"version": 3, "sources": {:?}, "names": {:?}, "mappings": "{}"}}"#,
+        sources, names, mappings
+    );
+    SourceMap::parse(&json, SOURCE_MAP_PATH)
+}
+
+fn position(source_index: usize, source: &str, line: usize, column: usize) -> OriginalPosition {
+    OriginalPosition {
+        source_index,
+        source: source.to_owned(),
+        line,
+        column,
+        name: None,
+    }
+}
+
+fn named_position(
+    source_index: usize,
+    source: &str,
+    line: usize,
+    column: usize,
+    name: &str,
+) -> OriginalPosition {
+    OriginalPosition {
+        name: Some(name.to_owned()),
+        ..position(source_index, source, line, column)
+    }
+}
+
+#[test]
+fn original_positions() {
+    // Line 0 maps columns 0, 4, and 10. Line 1 is empty. Line 2 maps columns 2 and 8.
+    let map = parse(
+        &["a.js", "b.js"],
+        &["foo", "bar"],
+        "AAAA,IAAIA,MCEHC;;EDGD,MAAMD",
+    )
+    .unwrap();
+
+    assert_eq!(
+        map.original_position(0, 0),
+        Some(position(0, "/dir/a.js", 1, 1))
+    );
+    assert_eq!(
+        map.original_position(0, 3),
+        Some(position(0, "/dir/a.js", 1, 1))
+    );
+    assert_eq!(
+        map.original_position(0, 4),
+        Some(named_position(0, "/dir/a.js", 1, 5, "foo"))
+    );
+    assert_eq!(
+        map.original_position(0, 10),
+        Some(named_position(1, "/dir/b.js", 3, 2, "bar"))
+    );
+    assert_eq!(
+        map.original_position(0, 1000),
+        Some(named_position(1, "/dir/b.js", 3, 2, "bar"))
+    );
+
+    // Positions on unmapped lines or before the first mapping on a line have no original position
+    assert_eq!(map.original_position(1, 0), None);
+    assert_eq!(map.original_position(2, 1), None);
+    assert_eq!(map.original_position(3, 0), None);
+
+    // Fields other than the generated column are relative to the previous segment, even across
+    // lines, and may be negative.
+    assert_eq!(
+        map.original_position(2, 2),
+        Some(position(0, "/dir/a.js", 6, 1))
+    );
+    assert_eq!(
+        map.original_position(2, 8),
+        Some(named_position(0, "/dir/a.js", 6, 7, "foo"))
+    );
+}
+
+#[test]
+fn generated_only_segments() {
+    // A segment with a single field maps the generated position to no original position
+    let map = parse(&["a.js"], &[], "AAAA,I").unwrap();
+
+    assert_eq!(
+        map.original_position(0, 2),
+        Some(position(0, "/dir/a.js", 1, 1))
+    );
+    assert_eq!(map.original_position(0, 4), None);
+}
+
+#[test]
+fn multi_digit_vlq_values() {
+    // 16 needs a continuation digit, and -16 is relative to the previous original column
+    let map = parse(&["a.js"], &[], "gBAAgB,CAAhB").unwrap();
+
+    assert_eq!(map.original_position(0, 15), None);
+    assert_eq!(
+        map.original_position(0, 16),
+        Some(position(0, "/dir/a.js", 1, 17))
+    );
+    assert_eq!(
+        map.original_position(0, 17),
+        Some(position(0, "/dir/a.js", 1, 1))
+    );
+
+    // The largest 32 bit value
+    let map = parse(&["a.js"], &[], "+/////DAA+/////D").unwrap();
+
+    assert_eq!(map.original_position(0, i32::MAX as u32 - 1), None);
+    assert_eq!(
+        map.original_position(0, i32::MAX as u32),
+        Some(position(0, "/dir/a.js", 1, i32::MAX as usize + 1))
+    );
+}
+
+#[test]
+fn malformed_vlq() {
+    let invalid = |mappings| {
+        assert_eq!(
+            parse(&["a.js"], &["foo"], mappings).err(),
+            Some(SourceMapError::InvalidMappings),
+            "{}",
+            mappings
+        )
+    };
+
+    // Not a base64 digit
+    invalid("A!AA");
+    invalid("AA AA");
+
+    // Segment ends with a continuation digit
+    invalid("g");
+    invalid("AAAg");
+
+    // Value larger than 32 bits
+    invalid("gggggggA");
+
+    // Segments must have 1, 4, or 5 fields
+    invalid("AA");
+    invalid("AAA");
+    invalid("AAAAAA");
+}
+
+#[test]
+fn out_of_range_mappings() {
+    let invalid = |mappings| {
+        assert_eq!(
+            parse(&["a.js"], &["foo"], mappings).err(),
+            Some(SourceMapError::InvalidMappings),
+            "{}",
+            mappings
+        )
+    };
+
+    // Negative generated column
+    invalid("D");
+    invalid("EAAA,HAAA");
+
+    // Source index out of range
+    invalid("ACAA");
+    invalid("ADAA");
+    invalid("AAAA;ACAA");
+
+    // Negative original line or column
+    invalid("AADA");
+    invalid("AAAD");
+
+    // Name index out of range
+    invalid("AAAAC");
+    invalid("AAAAD");
+    invalid("AAAAA,AAAAC");
+
+    // No names at all
+    assert_eq!(
+        parse(&["a.js"], &[], "AAAAA").err(),
+        Some(SourceMapError::InvalidMappings)
+    );
+}
+
+#[test]
+fn invalid_source_maps() {
+    let error = |json: &str| SourceMap::parse(json, SOURCE_MAP_PATH).err();
+
+    assert_eq!(error("not json"), Some(SourceMapError::InvalidJson));
+    assert_eq!(
+        error(r#"{"version": 2, "sources": [], "mappings": ""}"#),
+        Some(SourceMapError::InvalidVersion)
+    );
+    assert_eq!(
+        error(r#"{"sources": [], "mappings": ""}"#),
+        Some(SourceMapError::InvalidVersion)
+    );
+    assert_eq!(
+        error(r#"{"version": 3, "mappings": ""}"#),
+        Some(SourceMapError::MissingField("sources"))
+    );
+    assert_eq!(
+        error(r#"{"version": 3, "sources": []}"#),
+        Some(SourceMapError::MissingField("mappings"))
+    );
+    assert_eq!(
+        error(r#"{"version": 3, "sections": [{"map": {}}]}"#),
+        Some(SourceMapError::MissingField("offset"))
+    );
+}
+
+#[test]
+fn index_maps() {
+    let json = r#"{
+        "version": 3,
+        "sections": [
+            {
+                "offset": {"line": 0, "column": 0},
+                "map": {"version": 3, "sources": ["a.js"], "mappings": "AAAA"}
+            },
+            {
+                "offset": {"line": 1, "column": 4},
+                "map": {"version": 3, "sources": ["b.js"], "names": ["x"], "mappings": "AAAAA;EACA"}
+            }
+        ]
+    }"#;
+    let map = SourceMap::parse(json, SOURCE_MAP_PATH).unwrap();
+
+    assert_eq!(
+        map.original_position(0, 0),
+        Some(position(0, "/dir/a.js", 1, 1))
+    );
+
+    // The column offset only applies to the first line of a section
+    assert_eq!(map.original_position(1, 3), None);
+    assert_eq!(
+        map.original_position(1, 4),
+        Some(named_position(1, "/dir/b.js", 1, 1, "x"))
+    );
+    assert_eq!(
+        map.original_position(2, 2),
+        Some(position(1, "/dir/b.js", 2, 1))
+    );
+}
+
+#[test]
+fn source_paths() {
+    let json = r#"{
+        "version": 3,
+        "sourceRoot": "src",
+        "sources": ["a.js", "../b.js", "/abs/c.js", "https://example.com/d.js"],
+        "mappings": "AAAA,CCAA,CCAA,CCAA"
+    }"#;
+    let map = SourceMap::parse(json, SOURCE_MAP_PATH).unwrap();
+    let source = |column| map.original_position(0, column).unwrap().source;
+
+    assert_eq!(source(0), "/dir/src/a.js");
+    assert_eq!(source(1), "/dir/b.js");
+    assert_eq!(source(2), "/abs/c.js");
+    assert_eq!(source(3), "https://example.com/d.js");
+
+    assert_eq!(resolve_path("/dir/sub/app.js", "../a.js"), "/dir/a.js");
+    assert_eq!(resolve_path("/dir/app.js", "./a.js"), "/dir/a.js");
+    assert_eq!(resolve_path("/dir/app.js", "file:///x/y.js"), "/x/y.js");
+    assert_eq!(resolve_path("app.js", "a.js"), "a.js");
+    assert_eq!(resolve_path("app.js", "../a.js"), "../a.js");
+}
+
+#[test]
+fn names_and_source_contents() {
+    let json = r#"{
+        "version": 3,
+        "sources": ["a.js"],
+        "sourcesContent": ["line one\r\nline two"],
+        "names": ["foo", "bar"],
+        "mappings": "AAAA,IAAIA,CAACC"
+    }"#;
+    let map = SourceMap::parse(json, SOURCE_MAP_PATH).unwrap();
+
+    let names = map.names_from(0, 0).collect::<Vec<_>>();
+    assert_eq!(names, [(0, None), (4, Some("foo")), (5, Some("bar"))]);
+
+    let names = map.names_from(0, 4).collect::<Vec<_>>();
+    assert_eq!(names, [(4, Some("foo")), (5, Some("bar"))]);
+    assert_eq!(map.names_from(1, 0).count(), 0);
+
+    assert_eq!(map.source_line(0, 1), Some("line one"));
+    assert_eq!(map.source_line(0, 2), Some("line two"));
+    assert_eq!(map.source_line(0, 0), None);
+    assert_eq!(map.source_line(0, 3), None);
+    assert_eq!(map.source_line(1, 1), None);
+}
+
+#[test]
+fn data_urls() {
+    assert_eq!(
+        decode_data_url("data:application/json;base64,eyJhIjoxfQ=="),
+        Some(r#"{"a":1}"#.to_owned())
+    );
+    assert_eq!(
+        decode_data_url("data:application/json;charset=utf-8;base64,eyJhIjoxfQ"),
+        Some(r#"{"a":1}"#.to_owned())
+    );
+    assert_eq!(
+        decode_data_url("data:application/json,%7B%22a%22%3A1%7D"),
+        Some(r#"{"a":1}"#.to_owned())
+    );
+
+    assert_eq!(decode_data_url("app.js.map"), None);
+    assert_eq!(decode_data_url("data:application/json"), None);
+    assert_eq!(decode_data_url("data:application/json;base64,e!=="), None);
+    assert_eq!(decode_data_url("data:application/json,%7"), None);
+    assert_eq!(decode_data_url("data:application/json,%G0"), None);
+}
+
+#[test]
+fn source_mapping_url_comments() {
+    fn find(contents: &str) -> Option<&str> {
+        find_source_mapping_url(contents.as_bytes())
+    }
+
+    assert_eq!(
+        find("code;\n//# sourceMappingURL=app.js.map\n"),
+        Some("app.js.map")
+    );
+    assert_eq!(
+        find("code;\n//@ sourceMappingURL=app.js.map"),
+        Some("app.js.map")
+    );
+
+    // The last comment wins
+    assert_eq!(
+        find("//# sourceMappingURL=first.map\ncode;\n  //# sourceMappingURL=second.map  \n"),
+        Some("second.map")
+    );
+
+    // The comment must be on its own line and contain a URL without whitespace
+    assert_eq!(find("code; //# sourceMappingURL=app.js.map"), None);
+    assert_eq!(find("//# sourceMappingURL="), None);
+    assert_eq!(find("//# sourceMappingURL=a b"), None);
+    assert_eq!(find("code;"), None);
+}
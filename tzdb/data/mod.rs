@@ -0,0 +1,614 @@
+// @generated by tzdb/generate_data.sh from tzdata 2025b, do not edit.
+
+/// Version of the IANA time zone database.
+pub const TZDB_VERSION: &str = "2025b";
+
+/// TZif data for every zone, concatenated in the order of `ZONES`.
+pub const TZDB_DATA: &[u8] = include_bytes!("tzdb.bin");
+
+/// Zone identifiers along with the start and end of their TZif data in `TZDB_DATA`, sorted
+/// by identifier.
+pub const ZONES: &[(&str, u32, u32)] = &[
+    ("Africa/Abidjan", 0, 130),
+    ("Africa/Accra", 130, 830),
+    ("Africa/Addis_Ababa", 830, 981),
+    ("Africa/Algiers", 981, 1451),
+    ("Africa/Asmara", 1451, 1621),
+    ("Africa/Bamako", 1621, 1779),
+    ("Africa/Bangui", 1779, 1910),
+    ("Africa/Banjul", 1910, 2078),
+    ("Africa/Bissau", 2078, 2227),
+    ("Africa/Blantyre", 2227, 2392),
+    ("Africa/Brazzaville", 2392, 2523),
+    ("Africa/Bujumbura", 2523, 2654),
+    ("Africa/Cairo", 2654, 3963),
+    ("Africa/Casablanca", 3963, 5882),
+    ("Africa/Ceuta", 5882, 6444),
+    ("Africa/Conakry", 6444, 6602),
+    ("Africa/Dakar", 6602, 6751),
+    ("Africa/Dar_es_Salaam", 6751, 6912),
+    ("Africa/Djibouti", 6912, 7043),
+    ("Africa/Douala", 7043, 7174),
+    ("Africa/El_Aaiun", 7174, 9004),
+    ("Africa/Freetown", 9004, 9328),
+    ("Africa/Gaborone", 9328, 9508),
+    ("Africa/Harare", 9508, 9639),
+    ("Africa/Johannesburg", 9639, 9829),
+    ("Africa/Juba", 9829, 10287),
+    ("Africa/Kampala", 10287, 10469),
+    ("Africa/Khartoum", 10469, 10927),
+    ("Africa/Kigali", 10927, 11058),
+    ("Africa/Kinshasa", 11058, 11189),
+    ("Africa/Lagos", 11189, 11369),
+    ("Africa/Libreville", 11369, 11500),
+    ("Africa/Lome", 11500, 11630),
+    ("Africa/Luanda", 11630, 11776),
+    ("Africa/Lubumbashi", 11776, 11926),
+    ("Africa/Lusaka", 11926, 12057),
+    ("Africa/Malabo", 12057, 12207),
+    ("Africa/Maputo", 12207, 12338),
+    ("Africa/Maseru", 12338, 12495),
+    ("Africa/Mbabane", 12495, 12628),
+    ("Africa/Mogadishu", 12628, 12789),
+    ("Africa/Monrovia", 12789, 12953),
+    ("Africa/Nairobi", 12953, 13144),
+    ("Africa/Ndjamena", 13144, 13304),
+    ("Africa/Niamey", 13304, 13473),
+    ("Africa/Nouakchott", 13473, 13631),
+    ("Africa/Ouagadougou", 13631, 13761),
+    ("Africa/Porto-Novo", 13761, 13911),
+    ("Africa/Sao_Tome", 13911, 14084),
+    ("Africa/Tripoli", 14084, 14515),
+    ("Africa/Tunis", 14515, 14964),
+    ("Africa/Windhoek", 14964, 15602),
+    ("America/Adak", 15602, 16571),
+    ("America/Anchorage", 16571, 17548),
+    ("America/Anguilla", 17548, 17678),
+    ("America/Antigua", 17678, 17827),
+    ("America/Araguaina", 17827, 18419),
+    ("America/Argentina/Buenos_Aires", 18419, 19127),
+    ("America/Argentina/Catamarca", 19127, 19835),
+    ("America/Argentina/Cordoba", 19835, 20543),
+    ("America/Argentina/Jujuy", 20543, 21233),
+    ("America/Argentina/La_Rioja", 21233, 21950),
+    ("America/Argentina/Mendoza", 21950, 22658),
+    ("America/Argentina/Rio_Gallegos", 22658, 23366),
+    ("America/Argentina/Salta", 23366, 24056),
+    ("America/Argentina/San_Juan", 24056, 24773),
+    ("America/Argentina/San_Luis", 24773, 25490),
+    ("America/Argentina/Tucuman", 25490, 26216),
+    ("America/Argentina/Ushuaia", 26216, 26924),
+    ("America/Aruba", 26924, 27075),
+    ("America/Asuncion", 27075, 28160),
+    ("America/Atikokan", 28160, 28384),
+    ("America/Bahia", 28384, 29066),
+    ("America/Bahia_Banderas", 29066, 29766),
+    ("America/Barbados", 29766, 30044),
+    ("America/Belem", 30044, 30438),
+    ("America/Belize", 30438, 31483),
+    ("America/Blanc-Sablon", 31483, 31688),
+    ("America/Boa_Vista", 31688, 32118),
+    ("America/Bogota", 32118, 32297),
+    ("America/Boise", 32297, 33296),
+    ("America/Cambridge_Bay", 33296, 34179),
+    ("America/Campo_Grande", 34179, 35131),
+    ("America/Cancun", 35131, 35669),
+    ("America/Caracas", 35669, 35859),
+    ("America/Cayenne", 35859, 36010),
+    ("America/Cayman", 36010, 36159),
+    ("America/Chicago", 36159, 37913),
+    ("America/Chihuahua", 37913, 38604),
+    ("America/Ciudad_Juarez", 38604, 39322),
+    ("America/Costa_Rica", 39322, 39554),
+    ("America/Coyhaique", 39554, 40916),
+    ("America/Creston", 40916, 41074),
+    ("America/Cuiaba", 41074, 42008),
+    ("America/Curacao", 42008, 42159),
+    ("America/Danmarkshavn", 42159, 42606),
+    ("America/Dawson", 42606, 43635),
+    ("America/Dawson_Creek", 43635, 44318),
+    ("America/Denver", 44318, 45360),
+    ("America/Detroit", 45360, 46259),
+    ("America/Dominica", 46259, 46389),
+    ("America/Edmonton", 46389, 47359),
+    ("America/Eirunepe", 47359, 47795),
+    ("America/El_Salvador", 47795, 47971),
+    ("America/Fort_Nelson", 47971, 49419),
+    ("America/Fortaleza", 49419, 49903),
+    ("America/Glace_Bay", 49903, 50783),
+    ("America/Goose_Bay", 50783, 52363),
+    ("America/Grand_Turk", 52363, 53216),
+    ("America/Grenada", 53216, 53346),
+    ("America/Guadeloupe", 53346, 53476),
+    ("America/Guatemala", 53476, 53688),
+    ("America/Guayaquil", 53688, 53867),
+    ("America/Guyana", 53867, 54048),
+    ("America/Halifax", 54048, 55720),
+    ("America/Havana", 55720, 56837),
+    ("America/Hermosillo", 56837, 57095),
+    ("America/Indiana/Indianapolis", 57095, 57626),
+    ("America/Indiana/Knox", 57626, 58642),
+    ("America/Indiana/Marengo", 58642, 59209),
+    ("America/Indiana/Petersburg", 59209, 59892),
+    ("America/Indiana/Tell_City", 59892, 60414),
+    ("America/Indiana/Vevay", 60414, 60783),
+    ("America/Indiana/Vincennes", 60783, 61341),
+    ("America/Indiana/Winamac", 61341, 61953),
+    ("America/Inuvik", 61953, 62770),
+    ("America/Iqaluit", 62770, 63625),
+    ("America/Jamaica", 63625, 63964),
+    ("America/Juneau", 63964, 64930),
+    ("America/Kentucky/Louisville", 64930, 66172),
+    ("America/Kentucky/Monticello", 66172, 67144),
+    ("America/La_Paz", 67144, 67314),
+    ("America/Lima", 67314, 67597),
+    ("America/Los_Angeles", 67597, 68891),
+    ("America/Maceio", 68891, 69393),
+    ("America/Managua", 69393, 69688),
+    ("America/Manaus", 69688, 70100),
+    ("America/Martinique", 70100, 70278),
+    ("America/Matamoros", 70278, 70715),
+    ("America/Mazatlan", 70715, 71405),
+    ("America/Menominee", 71405, 72322),
+    ("America/Merida", 72322, 72976),
+    ("America/Metlakatla", 72976, 73571),
+    ("America/Mexico_City", 73571, 74344),
+    ("America/Miquelon", 74344, 74894),
+    ("America/Moncton", 74894, 76387),
+    ("America/Monterrey", 76387, 77096),
+    ("America/Montevideo", 77096, 78065),
+    ("America/Montserrat", 78065, 78195),
+    ("America/Nassau", 78195, 79201),
+    ("America/New_York", 79201, 80945),
+    ("America/Nome", 80945, 81920),
+    ("America/Noronha", 81920, 82404),
+    ("America/North_Dakota/Beulah", 82404, 83447),
+    ("America/North_Dakota/Center", 83447, 84437),
+    ("America/North_Dakota/New_Salem", 84437, 85427),
+    ("America/Nuuk", 85427, 86392),
+    ("America/Ojinaga", 86392, 87101),
+    ("America/Panama", 87101, 87250),
+    ("America/Paramaribo", 87250, 87437),
+    ("America/Phoenix", 87437, 87677),
+    ("America/Port-au-Prince", 87677, 88242),
+    ("America/Port_of_Spain", 88242, 88372),
+    ("America/Porto_Velho", 88372, 88766),
+    ("America/Puerto_Rico", 88766, 88943),
+    ("America/Punta_Arenas", 88943, 90161),
+    ("America/Rankin_Inlet", 90161, 90968),
+    ("America/Recife", 90968, 91452),
+    ("America/Regina", 91452, 92090),
+    ("America/Resolute", 92090, 92897),
+    ("America/Rio_Branco", 92897, 93315),
+    ("America/Santarem", 93315, 93724),
+    ("America/Santiago", 93724, 95078),
+    ("America/Santo_Domingo", 95078, 95395),
+    ("America/Sao_Paulo", 95395, 96347),
+    ("America/Scoresbysund", 96347, 97331),
+    ("America/Sitka", 97331, 98287),
+    ("America/St_Johns", 98287, 100165),
+    ("America/St_Kitts", 100165, 100295),
+    ("America/St_Lucia", 100295, 100444),
+    ("America/St_Thomas", 100444, 100574),
+    ("America/St_Vincent", 100574, 100723),
+    ("America/Swift_Current", 100723, 101091),
+    ("America/Tegucigalpa", 101091, 101285),
+    ("America/Thule", 101285, 101740),
+    ("America/Tijuana", 101740, 102819),
+    ("America/Toronto", 102819, 104536),
+    ("America/Tortola", 104536, 104666),
+    ("America/Vancouver", 104666, 105996),
+    ("America/Whitehorse", 105996, 107025),
+    ("America/Winnipeg", 107025, 108319),
+    ("America/Yakutat", 108319, 109265),
+    ("Antarctica/Casey", 109265, 109552),
+    ("Antarctica/Davis", 109552, 109749),
+    ("Antarctica/DumontDUrville", 109749, 109901),
+    ("Antarctica/Macquarie", 109901, 110877),
+    ("Antarctica/Mawson", 110877, 111029),
+    ("Antarctica/McMurdo", 111029, 111797),
+    ("Antarctica/Palmer", 111797, 112684),
+    ("Antarctica/Rothera", 112684, 112816),
+    ("Antarctica/Syowa", 112816, 112949),
+    ("Antarctica/Troll", 112949, 113126),
+    ("Antarctica/Vostok", 113126, 113296),
+    ("Asia/Aden", 113296, 113429),
+    ("Asia/Almaty", 113429, 114047),
+    ("Asia/Amman", 114047, 114975),
+    ("Asia/Anadyr", 114975, 115718),
+    ("Asia/Aqtau", 115718, 116324),
+    ("Asia/Aqtobe", 116324, 116939),
+    ("Asia/Ashgabat", 116939, 117314),
+    ("Asia/Atyrau", 117314, 117930),
+    ("Asia/Baghdad", 117930, 118560),
+    ("Asia/Bahrain", 118560, 118733),
+    ("Asia/Baku", 118733, 119477),
+    ("Asia/Bangkok", 119477, 119629),
+    ("Asia/Barnaul", 119629, 120382),
+    ("Asia/Beirut", 120382, 121114),
+    ("Asia/Bishkek", 121114, 121732),
+    ("Asia/Brunei", 121732, 121886),
+    ("Asia/Chita", 121886, 122636),
+    ("Asia/Colombo", 122636, 122883),
+    ("Asia/Damascus", 122883, 124117),
+    ("Asia/Dhaka", 124117, 124348),
+    ("Asia/Dili", 124348, 124518),
+    ("Asia/Dubai", 124518, 124651),
+    ("Asia/Dushanbe", 124651, 125017),
+    ("Asia/Famagusta", 125017, 125957),
+    ("Asia/Gaza", 125957, 128403),
+    ("Asia/Hebron", 128403, 130867),
+    ("Asia/Ho_Chi_Minh", 130867, 131103),
+    ("Asia/Hong_Kong", 131103, 131878),
+    ("Asia/Hovd", 131878, 132472),
+    ("Asia/Irkutsk", 132472, 133232),
+    ("Asia/Jakarta", 133232, 133480),
+    ("Asia/Jayapura", 133480, 133651),
+    ("Asia/Jerusalem", 133651, 134725),
+    ("Asia/Kabul", 134725, 134884),
+    ("Asia/Kamchatka", 134884, 135611),
+    ("Asia/Karachi", 135611, 135877),
+    ("Asia/Kathmandu", 135877, 136038),
+    ("Asia/Khandyga", 136038, 136813),
+    ("Asia/Kolkata", 136813, 137033),
+    ("Asia/Krasnoyarsk", 137033, 137774),
+    ("Asia/Kuala_Lumpur", 137774, 138030),
+    ("Asia/Kuching", 138030, 138350),
+    ("Asia/Kuwait", 138350, 138483),
+    ("Asia/Macau", 138483, 139274),
+    ("Asia/Magadan", 139274, 140025),
+    ("Asia/Makassar", 140025, 140215),
+    ("Asia/Manila", 140215, 140489),
+    ("Asia/Muscat", 140489, 140622),
+    ("Asia/Nicosia", 140622, 141219),
+    ("Asia/Novokuznetsk", 141219, 141945),
+    ("Asia/Novosibirsk", 141945, 142698),
+    ("Asia/Omsk", 142698, 143439),
+    ("Asia/Oral", 143439, 144064),
+    ("Asia/Phnom_Penh", 144064, 144264),
+    ("Asia/Pontianak", 144264, 144511),
+    ("Asia/Pyongyang", 144511, 144694),
+    ("Asia/Qatar", 144694, 144846),
+    ("Asia/Qostanay", 144846, 145470),
+    ("Asia/Qyzylorda", 145470, 146094),
+    ("Asia/Riyadh", 146094, 146227),
+    ("Asia/Sakhalin", 146227, 146982),
+    ("Asia/Samarkand", 146982, 147348),
+    ("Asia/Seoul", 147348, 147763),
+    ("Asia/Shanghai", 147763, 148156),
+    ("Asia/Singapore", 148156, 148412),
+    ("Asia/Srednekolymsk", 148412, 149154),
+    ("Asia/Taipei", 149154, 149665),
+    ("Asia/Tashkent", 149665, 150031),
+    ("Asia/Tbilisi", 150031, 150660),
+    ("Asia/Tehran", 150660, 151472),
+    ("Asia/Thimphu", 151472, 151626),
+    ("Asia/Tokyo", 151626, 151839),
+    ("Asia/Tomsk", 151839, 152592),
+    ("Asia/Ulaanbaatar", 152592, 153186),
+    ("Asia/Urumqi", 153186, 153319),
+    ("Asia/Ust-Nera", 153319, 154090),
+    ("Asia/Vientiane", 154090, 154308),
+    ("Asia/Vladivostok", 154308, 155050),
+    ("Asia/Yakutsk", 155050, 155791),
+    ("Asia/Yangon", 155791, 155978),
+    ("Asia/Yekaterinburg", 155978, 156738),
+    ("Asia/Yerevan", 156738, 157446),
+    ("Atlantic/Azores", 157446, 158847),
+    ("Atlantic/Bermuda", 158847, 159871),
+    ("Atlantic/Canary", 159871, 160349),
+    ("Atlantic/Cape_Verde", 160349, 160524),
+    ("Atlantic/Faroe", 160524, 160965),
+    ("Atlantic/Madeira", 160965, 162337),
+    ("Atlantic/Reykjavik", 162337, 163090),
+    ("Atlantic/South_Georgia", 163090, 163222),
+    ("Atlantic/St_Helena", 163222, 163371),
+    ("Atlantic/Stanley", 163371, 164160),
+    ("Australia/Adelaide", 164160, 165081),
+    ("Australia/Brisbane", 165081, 165370),
+    ("Australia/Broken_Hill", 165370, 166311),
+    ("Australia/Darwin", 166311, 166545),
+    ("Australia/Eucla", 166545, 166859),
+    ("Australia/Hobart", 166859, 167862),
+    ("Australia/Lindeman", 167862, 168187),
+    ("Australia/Lord_Howe", 168187, 168879),
+    ("Australia/Melbourne", 168879, 169783),
+    ("Australia/Perth", 169783, 170089),
+    ("Australia/Sydney", 170089, 170993),
+    ("CET", 170993, 171614),
+    ("CST6CDT", 171614, 172565),
+    ("EET", 172565, 173062),
+    ("EST", 173062, 173173),
+    ("EST5EDT", 173173, 174124),
+    ("Etc/GMT", 174124, 174235),
+    ("Etc/GMT+1", 174235, 174348),
+    ("Etc/GMT+10", 174348, 174462),
+    ("Etc/GMT+11", 174462, 174576),
+    ("Etc/GMT+12", 174576, 174690),
+    ("Etc/GMT+2", 174690, 174803),
+    ("Etc/GMT+3", 174803, 174916),
+    ("Etc/GMT+4", 174916, 175029),
+    ("Etc/GMT+5", 175029, 175142),
+    ("Etc/GMT+6", 175142, 175255),
+    ("Etc/GMT+7", 175255, 175368),
+    ("Etc/GMT+8", 175368, 175481),
+    ("Etc/GMT+9", 175481, 175594),
+    ("Etc/GMT-1", 175594, 175708),
+    ("Etc/GMT-10", 175708, 175823),
+    ("Etc/GMT-11", 175823, 175938),
+    ("Etc/GMT-12", 175938, 176053),
+    ("Etc/GMT-13", 176053, 176168),
+    ("Etc/GMT-14", 176168, 176283),
+    ("Etc/GMT-2", 176283, 176397),
+    ("Etc/GMT-3", 176397, 176511),
+    ("Etc/GMT-4", 176511, 176625),
+    ("Etc/GMT-5", 176625, 176739),
+    ("Etc/GMT-6", 176739, 176853),
+    ("Etc/GMT-7", 176853, 176967),
+    ("Etc/GMT-8", 176967, 177081),
+    ("Etc/GMT-9", 177081, 177195),
+    ("Etc/UTC", 177195, 177306),
+    ("Europe/Amsterdam", 177306, 178377),
+    ("Europe/Andorra", 178377, 178766),
+    ("Europe/Astrakhan", 178766, 179492),
+    ("Europe/Athens", 179492, 180174),
+    ("Europe/Belgrade", 180174, 180652),
+    ("Europe/Berlin", 180652, 181357),
+    ("Europe/Brussels", 181357, 182460),
+    ("Europe/Bucharest", 182460, 183121),
+    ("Europe/Budapest", 183121, 183887),
+    ("Europe/Chisinau", 183887, 184642),
+    ("Europe/Copenhagen", 184642, 185265),
+    ("Europe/Dublin", 185265, 186761),
+    ("Europe/Gibraltar", 186761, 187981),
+    ("Europe/Guernsey", 187981, 189592),
+    ("Europe/Helsinki", 189592, 190073),
+    ("Europe/Isle_of_Man", 190073, 191672),
+    ("Europe/Istanbul", 191672, 192872),
+    ("Europe/Jersey", 192872, 194483),
+    ("Europe/Kaliningrad", 194483, 195387),
+    ("Europe/Kirov", 195387, 196122),
+    ("Europe/Kyiv", 196122, 196680),
+    ("Europe/Lisbon", 196680, 198143),
+    ("Europe/Ljubljana", 198143, 198621),
+    ("Europe/London", 198621, 200220),
+    ("Europe/Luxembourg", 200220, 201307),
+    ("Europe/Madrid", 201307, 202204),
+    ("Europe/Malta", 202204, 203132),
+    ("Europe/Minsk", 203132, 203940),
+    ("Europe/Monaco", 203940, 205054),
+    ("Europe/Moscow", 205054, 205962),
+    ("Europe/Oslo", 205962, 206638),
+    ("Europe/Paris", 206638, 207743),
+    ("Europe/Prague", 207743, 208466),
+    ("Europe/Riga", 208466, 209160),
+    ("Europe/Rome", 209160, 210107),
+    ("Europe/Samara", 210107, 210839),
+    ("Europe/Sarajevo", 210839, 211317),
+    ("Europe/Saratov", 211317, 212043),
+    ("Europe/Simferopol", 212043, 212908),
+    ("Europe/Skopje", 212908, 213386),
+    ("Europe/Sofia", 213386, 213978),
+    ("Europe/Stockholm", 213978, 214475),
+    ("Europe/Tallinn", 214475, 215150),
+    ("Europe/Tirane", 215150, 215754),
+    ("Europe/Ulyanovsk", 215754, 216514),
+    ("Europe/Vaduz", 216514, 216992),
+    ("Europe/Vienna", 216992, 217650),
+    ("Europe/Vilnius", 217650, 218326),
+    ("Europe/Volgograd", 218326, 219079),
+    ("Europe/Warsaw", 219079, 220002),
+    ("Europe/Zagreb", 220002, 220480),
+    ("Europe/Zurich", 220480, 220977),
+    ("Factory", 220977, 221090),
+    ("HST", 221090, 221202),
+    ("Indian/Antananarivo", 221202, 221362),
+    ("Indian/Chagos", 221362, 221514),
+    ("Indian/Christmas", 221514, 221647),
+    ("Indian/Cocos", 221647, 221787),
+    ("Indian/Comoro", 221787, 221918),
+    ("Indian/Kerguelen", 221918, 222051),
+    ("Indian/Mahe", 222051, 222184),
+    ("Indian/Maldives", 222184, 222336),
+    ("Indian/Mauritius", 222336, 222515),
+    ("Indian/Mayotte", 222515, 222646),
+    ("Indian/Reunion", 222646, 222779),
+    ("MET", 222779, 223400),
+    ("MST", 223400, 223511),
+    ("MST7MDT", 223511, 224462),
+    ("PST8PDT", 224462, 225413),
+    ("Pacific/Apia", 225413, 225820),
+    ("Pacific/Auckland", 225820, 226863),
+    ("Pacific/Bougainville", 226863, 227064),
+    ("Pacific/Chatham", 227064, 227872),
+    ("Pacific/Chuuk", 227872, 228067),
+    ("Pacific/Easter", 228067, 229241),
+    ("Pacific/Efate", 229241, 229583),
+    ("Pacific/Fakaofo", 229583, 229736),
+    ("Pacific/Fiji", 229736, 230132),
+    ("Pacific/Funafuti", 230132, 230266),
+    ("Pacific/Galapagos", 230266, 230441),
+    ("Pacific/Gambier", 230441, 230573),
+    ("Pacific/Guadalcanal", 230573, 230707),
+    ("Pacific/Guam", 230707, 231057),
+    ("Pacific/Honolulu", 231057, 231278),
+    ("Pacific/Kanton", 231278, 231450),
+    ("Pacific/Kiritimati", 231450, 231624),
+    ("Pacific/Kosrae", 231624, 231866),
+    ("Pacific/Kwajalein", 231866, 232085),
+    ("Pacific/Majuro", 232085, 232303),
+    ("Pacific/Marquesas", 232303, 232442),
+    ("Pacific/Midway", 232442, 232611),
+    ("Pacific/Nauru", 232611, 232794),
+    ("Pacific/Niue", 232794, 232948),
+    ("Pacific/Norfolk", 232948, 233195),
+    ("Pacific/Noumea", 233195, 233393),
+    ("Pacific/Pago_Pago", 233393, 233539),
+    ("Pacific/Palau", 233539, 233687),
+    ("Pacific/Pitcairn", 233687, 233840),
+    ("Pacific/Pohnpei", 233840, 234054),
+    ("Pacific/Port_Moresby", 234054, 234208),
+    ("Pacific/Rarotonga", 234208, 234614),
+    ("Pacific/Saipan", 234614, 234955),
+    ("Pacific/Tahiti", 234955, 235088),
+    ("Pacific/Tarawa", 235088, 235222),
+    ("Pacific/Tongatapu", 235222, 235459),
+    ("Pacific/Wake", 235459, 235593),
+    ("Pacific/Wallis", 235593, 235727),
+    ("WET", 235727, 236221),
+];
+
+/// Links from an alias identifier to the identifier of a zone, sorted by alias.
+pub const LINKS: &[(&str, &str)] = &[
+    ("Africa/Asmera", "Africa/Nairobi"),
+    ("Africa/Timbuktu", "Africa/Abidjan"),
+    ("America/Argentina/ComodRivadavia", "America/Argentina/Catamarca"),
+    ("America/Atka", "America/Adak"),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Catamarca", "America/Argentina/Catamarca"),
+    ("America/Coral_Harbour", "America/Panama"),
+    ("America/Cordoba", "America/Argentina/Cordoba"),
+    ("America/Ensenada", "America/Tijuana"),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis"),
+    ("America/Godthab", "America/Nuuk"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Jujuy", "America/Argentina/Jujuy"),
+    ("America/Knox_IN", "America/Indiana/Knox"),
+    ("America/Kralendijk", "America/Puerto_Rico"),
+    ("America/Louisville", "America/Kentucky/Louisville"),
+    ("America/Lower_Princes", "America/Puerto_Rico"),
+    ("America/Marigot", "America/Puerto_Rico"),
+    ("America/Mendoza", "America/Argentina/Mendoza"),
+    ("America/Montreal", "America/Toronto"),
+    ("America/Nipigon", "America/Toronto"),
+    ("America/Pangnirtung", "America/Iqaluit"),
+    ("America/Porto_Acre", "America/Rio_Branco"),
+    ("America/Rainy_River", "America/Winnipeg"),
+    ("America/Rosario", "America/Argentina/Cordoba"),
+    ("America/Santa_Isabel", "America/Tijuana"),
+    ("America/Shiprock", "America/Denver"),
+    ("America/St_Barthelemy", "America/Puerto_Rico"),
+    ("America/Thunder_Bay", "America/Toronto"),
+    ("America/Virgin", "America/Puerto_Rico"),
+    ("America/Yellowknife", "America/Edmonton"),
+    ("Antarctica/South_Pole", "Pacific/Auckland"),
+    ("Arctic/Longyearbyen", "Europe/Berlin"),
+    ("Asia/Ashkhabad", "Asia/Ashgabat"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Choibalsan", "Asia/Ulaanbaatar"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Dacca", "Asia/Dhaka"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Kashgar", "Asia/Urumqi"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Macao", "Asia/Macau"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem"),
+    ("Asia/Thimbu", "Asia/Thimphu"),
+    ("Asia/Ujung_Pandang", "Asia/Makassar"),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar"),
+    ("Atlantic/Faeroe", "Atlantic/Faroe"),
+    ("Atlantic/Jan_Mayen", "Europe/Berlin"),
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/Currie", "Australia/Hobart"),
+    ("Australia/LHI", "Australia/Lord_Howe"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/North", "Australia/Darwin"),
+    ("Australia/Queensland", "Australia/Brisbane"),
+    ("Australia/South", "Australia/Adelaide"),
+    ("Australia/Tasmania", "Australia/Hobart"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Australia/West", "Australia/Perth"),
+    ("Australia/Yancowinna", "Australia/Broken_Hill"),
+    ("Brazil/Acre", "America/Rio_Branco"),
+    ("Brazil/DeNoronha", "America/Noronha"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Brazil/West", "America/Manaus"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Newfoundland", "America/St_Johns"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Canada/Saskatchewan", "America/Regina"),
+    ("Canada/Yukon", "America/Whitehorse"),
+    ("Chile/Continental", "America/Santiago"),
+    ("Chile/EasterIsland", "Pacific/Easter"),
+    ("Cuba", "America/Havana"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Etc/GMT+0", "Etc/GMT"),
+    ("Etc/GMT-0", "Etc/GMT"),
+    ("Etc/GMT0", "Etc/GMT"),
+    ("Etc/Greenwich", "Etc/GMT"),
+    ("Etc/UCT", "Etc/UTC"),
+    ("Etc/Universal", "Etc/UTC"),
+    ("Etc/Zulu", "Etc/UTC"),
+    ("Europe/Belfast", "Europe/London"),
+    ("Europe/Bratislava", "Europe/Prague"),
+    ("Europe/Busingen", "Europe/Zurich"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("Europe/Mariehamn", "Europe/Helsinki"),
+    ("Europe/Nicosia", "Asia/Nicosia"),
+    ("Europe/Podgorica", "Europe/Belgrade"),
+    ("Europe/San_Marino", "Europe/Rome"),
+    ("Europe/Tiraspol", "Europe/Chisinau"),
+    ("Europe/Uzhgorod", "Europe/Kyiv"),
+    ("Europe/Vatican", "Europe/Rome"),
+    ("Europe/Zaporozhye", "Europe/Kyiv"),
+    ("GB", "Europe/London"),
+    ("GB-Eire", "Europe/London"),
+    ("GMT", "Etc/GMT"),
+    ("GMT+0", "Etc/GMT"),
+    ("GMT-0", "Etc/GMT"),
+    ("GMT0", "Etc/GMT"),
+    ("Greenwich", "Etc/GMT"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iceland", "Africa/Abidjan"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Jamaica", "America/Jamaica"),
+    ("Japan", "Asia/Tokyo"),
+    ("Kwajalein", "Pacific/Kwajalein"),
+    ("Libya", "Africa/Tripoli"),
+    ("Mexico/BajaNorte", "America/Tijuana"),
+    ("Mexico/BajaSur", "America/Mazatlan"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("NZ", "Pacific/Auckland"),
+    ("NZ-CHAT", "Pacific/Chatham"),
+    ("Navajo", "America/Denver"),
+    ("PRC", "Asia/Shanghai"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Johnston", "Pacific/Honolulu"),
+    ("Pacific/Ponape", "Pacific/Guadalcanal"),
+    ("Pacific/Samoa", "Pacific/Pago_Pago"),
+    ("Pacific/Truk", "Pacific/Port_Moresby"),
+    ("Pacific/Yap", "Pacific/Port_Moresby"),
+    ("Poland", "Europe/Warsaw"),
+    ("Portugal", "Europe/Lisbon"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("UCT", "Etc/UTC"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Aleutian", "America/Adak"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/East-Indiana", "America/Indiana/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Indiana-Starke", "America/Indiana/Knox"),
+    ("US/Michigan", "America/Detroit"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("US/Samoa", "Pacific/Pago_Pago"),
+    ("UTC", "Etc/UTC"),
+    ("Universal", "Etc/UTC"),
+    ("W-SU", "Europe/Moscow"),
+    ("Zulu", "Etc/UTC"),
+];
@@ -0,0 +1,61 @@
+#!/bin/bash
+
+# This script is used to generate the embedded IANA time zone database from the compact tzdata.zi
+# source distributed with the tzdb (or installed at /usr/share/zoneinfo/tzdata.zi on most systems).
+#
+# Usage: ./generate_data.sh [path/to/tzdata.zi]
+
+set -e
+
+CURRENT_DIR=$(cd "$(dirname "$0")" && pwd)
+DATA_DIR="$CURRENT_DIR/data"
+TZDATA_ZI="${1:-/usr/share/zoneinfo/tzdata.zi}"
+
+BUILD_DIR=$(mktemp -d)
+trap 'rm -rf "$BUILD_DIR"' EXIT
+
+# The first line of tzdata.zi has the form "# version 2025b"
+VERSION=$(head -n 1 "$TZDATA_ZI" | awk '{print $3}')
+
+# Compile all zones to TZif files. Slim files omit transitions that can be computed from the
+# POSIX TZ string footer, which so2js evaluates for times after the last transition.
+zic -b slim -d "$BUILD_DIR" "$TZDATA_ZI"
+
+mkdir -p "$DATA_DIR"
+BLOB="$DATA_DIR/tzdb.bin"
+MOD="$DATA_DIR/mod.rs"
+
+: > "$BLOB"
+
+{
+  echo "// @generated by tzdb/generate_data.sh from tzdata $VERSION, do not edit."
+  echo
+  echo "/// Version of the IANA time zone database."
+  echo "pub const TZDB_VERSION: &str = \"$VERSION\";"
+  echo
+  echo "/// TZif data for every zone, concatenated in the order of \`ZONES\`."
+  echo "pub const TZDB_DATA: &[u8] = include_bytes!(\"tzdb.bin\");"
+  echo
+  echo "/// Zone identifiers along with the start and end of their TZif data in \`TZDB_DATA\`, sorted"
+  echo "/// by identifier."
+  echo "pub const ZONES: &[(&str, u32, u32)] = &["
+
+  OFFSET=0
+  for ZONE in $(awk '$1 == "Z" {print $2}' "$TZDATA_ZI" | LC_ALL=C sort); do
+    SIZE=$(stat -c %s "$BUILD_DIR/$ZONE")
+    cat "$BUILD_DIR/$ZONE" >> "$BLOB"
+    echo "    (\"$ZONE\", $OFFSET, $((OFFSET + SIZE))),"
+    OFFSET=$((OFFSET + SIZE))
+  done
+
+  echo "];"
+  echo
+  echo "/// Links from an alias identifier to the identifier of a zone, sorted by alias."
+  echo "pub const LINKS: &[(&str, &str)] = &["
+
+  awk '$1 == "L" {print $3, $2}' "$TZDATA_ZI" | LC_ALL=C sort | while read -r ALIAS TARGET; do
+    echo "    (\"$ALIAS\", \"$TARGET\"),"
+  done
+
+  echo "];"
+} > "$MOD"